@@ -0,0 +1,20 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+. ci/utils.sh
+
+begin_group "Fetch dependencies"
+cargo fetch --locked
+end_group
+
+begin_group "Run clippy"
+cargo clippy --frozen -p fpmath --all-targets --features bf16 -- -D warnings
+end_group
+
+begin_group "Build"
+cargo build --frozen -p fpmath --all-targets --features bf16
+end_group
+
+begin_group "Test"
+cargo test --frozen -p fpmath --features bf16
+end_group
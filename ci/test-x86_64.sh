@@ -0,0 +1,21 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+. ci/utils.sh
+
+begin_group "Fetch dependencies"
+cargo fetch --locked
+end_group
+
+begin_group "Test with run time detection"
+cargo test --frozen -p fpmath --features std
+end_group
+
+begin_group "Test with AVX2"
+RUSTFLAGS="-C target-feature=+avx2" cargo test --frozen -p fpmath --features std
+end_group
+
+begin_group "Run clippy with AVX-512"
+RUSTFLAGS="-C target-feature=+avx2,+avx512f,+avx512vl" \
+  cargo clippy --frozen -p fpmath --all-targets -- -D warnings
+end_group
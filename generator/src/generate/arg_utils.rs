@@ -68,6 +68,36 @@ where
     }
 }
 
+pub(super) fn parse_4_args<T0, T1, T2, T3>(args: &[&str]) -> Result<(T0, T1, T2, T3), String>
+where
+    T0: std::str::FromStr,
+    <T0 as std::str::FromStr>::Err: std::fmt::Display,
+    T1: std::str::FromStr,
+    <T1 as std::str::FromStr>::Err: std::fmt::Display,
+    T2: std::str::FromStr,
+    <T2 as std::str::FromStr>::Err: std::fmt::Display,
+    T3: std::str::FromStr,
+    <T3 as std::str::FromStr>::Err: std::fmt::Display,
+{
+    if let [arg1, arg2, arg3, arg4] = args {
+        let v1 = arg1
+            .parse()
+            .map_err(|e| format!("failed to parse first argument {arg1:?}: {e}"))?;
+        let v2 = arg2
+            .parse()
+            .map_err(|e| format!("failed to parse second argument {arg2:?}: {e}"))?;
+        let v3 = arg3
+            .parse()
+            .map_err(|e| format!("failed to parse third argument {arg3:?}: {e}"))?;
+        let v4 = arg4
+            .parse()
+            .map_err(|e| format!("failed to parse fourth argument {arg4:?}: {e}"))?;
+        Ok((v1, v2, v3, v4))
+    } else {
+        Err(format!("expected 4 arguments, found {}", args.len()))
+    }
+}
+
 pub(super) fn parse_5_args<T0, T1, T2, T3, T4>(
     args: &[&str],
 ) -> Result<(T0, T1, T2, T3, T4), String>
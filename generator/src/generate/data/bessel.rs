@@ -0,0 +1,246 @@
+use super::super::{FloatKind, arg_utils, julia, render_const, split_hi_lo};
+
+pub(in super::super) fn gen_consts(args: &[&str]) -> Result<String, String> {
+    let (fkind, name): (FloatKind, String) = arg_utils::parse_2_args(args)?;
+    let aux_prec = fkind.rug_aux_prec();
+
+    let pi = rug::Float::with_val(aux_prec, rug::float::Constant::Pi);
+    let tmp = match name.as_str() {
+        // π/4
+        "FRAC_PI_4" => pi / 4u8,
+        // 2/π
+        "FRAC_2_PI" => 2u8 / pi,
+        // √(2/π)
+        "SQRT_FRAC_2_PI" => (2u8 / pi).sqrt(),
+        _ => return Err(format!("unknown constant {name:?}")),
+    };
+
+    let mut out = String::new();
+
+    let (hi, lo) = split_hi_lo(tmp, fkind.float_prec());
+    render_const(fkind, &format!("{name}_HI"), hi, &mut out);
+    render_const(fkind, &format!("{name}_LO"), lo, &mut out);
+
+    Ok(out)
+}
+
+pub(in super::super) fn gen_j0_small_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, poly_deg): (_, i32) = arg_utils::parse_2_args(args)?;
+
+    // (j0(x) - 1 + x^2/4 - x^4/64) / x^6, as a function of x^2
+    let func = "(SpecialFunctions.besselj0(sqrt(x)) - 1 + x / 4 - x^2 / 64) / x^3";
+
+    Ok(gen_j_small_poly(fkind, func, poly_deg, 3))
+}
+
+pub(in super::super) fn gen_j1_small_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, poly_deg): (_, i32) = arg_utils::parse_2_args(args)?;
+
+    // (j1(x) / x - 1/2 + x^2/16) / x^4, as a function of x^2
+    let func = "(SpecialFunctions.besselj1(sqrt(x)) / sqrt(x) - BigFloat(1) / 2 + x / 16) / x^2";
+
+    Ok(gen_j_small_poly(fkind, func, poly_deg, 2))
+}
+
+pub(in super::super) fn gen_y0_small_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, poly_deg): (_, i32) = arg_utils::parse_2_args(args)?;
+
+    // y0(x) - 2/π * ln(x) * j0(x), as a function of x^2
+    let func = "let t = sqrt(x); \
+        SpecialFunctions.bessely0(t) - 2 / BigFloat(pi) * log(t) * SpecialFunctions.besselj0(t) \
+        end";
+
+    Ok(gen_y_small_poly(fkind, func, poly_deg))
+}
+
+pub(in super::super) fn gen_y1_small_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, poly_deg): (_, i32) = arg_utils::parse_2_args(args)?;
+
+    // (y1(x) - 2/π * (ln(x) * j1(x) - 1/x)) / x, as a function of x^2
+    let func = "let t = sqrt(x); \
+        (SpecialFunctions.bessely1(t) - 2 / BigFloat(pi) * (log(t) * SpecialFunctions.besselj1(t) - 1 / t)) / t \
+        end";
+
+    Ok(gen_y_small_poly(fkind, func, poly_deg))
+}
+
+fn gen_j_small_poly(fkind: FloatKind, func: &str, poly_deg: i32, i_off: i32) -> String {
+    let wfunc = "1";
+    let range = (1.0e-30, 4.0);
+
+    let coeffs = julia::run_remez(func, wfunc, range, poly_deg);
+
+    let mut out = String::new();
+
+    // The first coefficient is split in hi and lo parts
+    for (i, coeff_value) in (i_off..).zip(coeffs) {
+        if i == i_off {
+            let (hi, lo) = split_hi_lo(coeff_value, fkind.float_prec());
+            render_const(fkind, &format!("K{i}_HI"), hi, &mut out);
+            render_const(fkind, &format!("K{i}_LO"), lo, &mut out);
+        } else {
+            render_const(fkind, &format!("K{i}"), coeff_value, &mut out);
+        }
+    }
+
+    out
+}
+
+fn gen_y_small_poly(fkind: FloatKind, func: &str, poly_deg: i32) -> String {
+    let wfunc = "1";
+    let range = (1.0e-30, 4.0);
+
+    let coeffs = julia::run_remez(func, wfunc, range, poly_deg);
+
+    let mut out = String::new();
+
+    // The first three coefficients are split in hi and lo parts
+    for (i, coeff_value) in (0..).zip(coeffs) {
+        if i < 3 {
+            let (hi, lo) = split_hi_lo(coeff_value, fkind.float_prec());
+            render_const(fkind, &format!("K{i}_HI"), hi, &mut out);
+            render_const(fkind, &format!("K{i}_LO"), lo, &mut out);
+        } else {
+            render_const(fkind, &format!("K{i}"), coeff_value, &mut out);
+        }
+    }
+
+    out
+}
+
+/// Generates a Julia expression that evaluates to the amplitude
+/// (normalized to 1 at infinity) and the phase (relative to
+/// `x - (2*nu+1)*π/4`) of the Bessel functions of order `nu`.
+fn amp_phase_code(nu: u8, x: &str, phase: bool) -> String {
+    let result = if phase {
+        "atan(y * cos(c) - j * sin(c), j * cos(c) + y * sin(c))"
+    } else {
+        "sqrt(BigFloat(pi) * t / 2) * sqrt(j^2 + y^2)"
+    };
+    format!(
+        "let t = {x}, \
+        j = SpecialFunctions.besselj{nu}(t), \
+        y = SpecialFunctions.bessely{nu}(t), \
+        c = t - {} * BigFloat(pi) / 4; \
+        {result} \
+        end",
+        2 * nu + 1,
+    )
+}
+
+fn parse_nu(nu: u8) -> Result<u8, String> {
+    if matches!(nu, 0 | 1) {
+        Ok(nu)
+    } else {
+        Err("order must be 0 or 1".into())
+    }
+}
+
+pub(in super::super) fn gen_asympt_amp_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, nu, poly_deg, range_start, range_end): (_, u8, i32, f64, f64) =
+        arg_utils::parse_5_args(args)?;
+    let nu = parse_nu(nu)?;
+
+    let mut out = String::new();
+
+    // amplitude - 1, as a function of 1/x, centered on the middle of the range
+    let mid = (1.0 / range_start + 1.0 / range_end) / 2.0;
+    let amp = amp_phase_code(nu, &format!("1 / (x + {mid})"), false);
+    let func = format!("{amp} - 1");
+    let wfunc = "1";
+    let range = (1.0 / range_end - mid, 1.0 / range_start - mid);
+
+    julia::run_and_render_remez(fkind, &func, wfunc, range, poly_deg, 0, "A", &mut out);
+
+    Ok(out)
+}
+
+pub(in super::super) fn gen_asympt_phase_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, nu, poly_deg, range_start, range_end): (FloatKind, u8, i32, f64, f64) =
+        arg_utils::parse_5_args(args)?;
+    let nu = parse_nu(nu)?;
+    let aux_prec = fkind.rug_aux_prec();
+
+    let mut out = String::new();
+
+    // phase, as a function of 1/x, centered on the middle of the range
+    let mid = (1.0 / range_start + 1.0 / range_end) / 2.0;
+
+    // The value at the center is split in hi and lo parts
+    let t = rug::Float::with_val(aux_prec, mid).recip();
+    let (j, y) = if nu == 0 {
+        (t.clone().j0(), t.clone().y0())
+    } else {
+        (t.clone().j1(), t.clone().y1())
+    };
+    let c = t - rug::Float::with_val(aux_prec, rug::float::Constant::Pi) * (2 * nu + 1) / 4u8;
+    let (sin_c, cos_c) = c.sin_cos(rug::Float::new(aux_prec));
+    let tmp = (y.clone() * &cos_c - j.clone() * &sin_c).atan2(&(j * &cos_c + y * &sin_c));
+    let (hi, lo) = split_hi_lo(tmp, fkind.float_prec());
+    render_const(fkind, "K0_HI", hi, &mut out);
+    render_const(fkind, "K0_LO", lo, &mut out);
+
+    // Avoid evaluating 0/0 at the center
+    let phase = amp_phase_code(nu, "1 / (z + BigFloat(MID))", true);
+    let phase_mid = amp_phase_code(nu, "1 / BigFloat(MID)", true);
+    let func =
+        format!("let z = iszero(x) ? BigFloat(\"1e-200\") : x; ({phase} - {phase_mid}) / z end")
+            .replace("MID", &mid.to_string());
+    let wfunc = "1";
+    let range = (1.0 / range_end - mid, 1.0 / range_start - mid);
+
+    let coeffs = julia::run_remez(&func, wfunc, range, poly_deg - 1);
+
+    // The first coefficient is split in hi and lo parts
+    for (i, coeff_value) in (1..).zip(coeffs) {
+        if i == 1 {
+            let (hi, lo) = split_hi_lo(coeff_value, fkind.float_prec());
+            render_const(fkind, "K1_HI", hi, &mut out);
+            render_const(fkind, "K1_LO", lo, &mut out);
+        } else {
+            render_const(fkind, &format!("K{i}"), coeff_value, &mut out);
+        }
+    }
+
+    Ok(out)
+}
+
+pub(in super::super) fn gen_asympt_amp_tail_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, nu, poly_deg, range_start): (_, u8, i32, f64) = arg_utils::parse_4_args(args)?;
+    let nu = parse_nu(nu)?;
+
+    let mut out = String::new();
+
+    // (amplitude - 1) * x^2, as a function of 1/x^2
+    let amp = amp_phase_code(nu, "1 / sqrt(x)", false);
+    let func = format!("({amp} - 1) / x");
+    let wfunc = "1";
+    let range = (1.0e-8, 1.0 / (range_start * range_start));
+
+    julia::run_and_render_remez(fkind, &func, wfunc, range, poly_deg, 1, "A", &mut out);
+
+    Ok(out)
+}
+
+pub(in super::super) fn gen_asympt_phase_tail_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, nu, poly_deg, range_start): (_, u8, i32, f64) = arg_utils::parse_4_args(args)?;
+    let nu = parse_nu(nu)?;
+
+    let mut out = String::new();
+
+    // (phase * x - c) * x^2, as a function of 1/x^2,
+    // where c = -1/8 for order 0 and c = 3/8 for order 1
+    let c = if nu == 0 {
+        "(-BigFloat(1) / 8)"
+    } else {
+        "(BigFloat(3) / 8)"
+    };
+    let phase = amp_phase_code(nu, "1 / sqrt(x)", true);
+    let func = format!("({phase} / sqrt(x) - {c}) / x");
+    let wfunc = "1";
+    let range = (1.0e-8, 1.0 / (range_start * range_start));
+
+    julia::run_and_render_remez(fkind, &func, wfunc, range, poly_deg, 1, "P", &mut out);
+
+    Ok(out)
+}
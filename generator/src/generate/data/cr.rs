@@ -0,0 +1,60 @@
+use std::fmt::Write as _;
+
+use super::super::arg_utils;
+
+pub(in super::super) fn gen_consts(args: &[&str]) -> Result<String, String> {
+    arg_utils::expect_0_args(args)?;
+
+    let mut out = String::new();
+
+    let num_words = 33;
+    let prec = num_words * 32 + 32;
+
+    let ln_2 = rug::Float::with_val(prec, rug::float::Constant::Log2);
+    let ln_10 = rug::Float::with_val(prec, 10).ln();
+    let pi = rug::Float::with_val(prec, rug::float::Constant::Pi);
+
+    render_big_const("LN_2", ln_2.clone(), num_words, &mut out);
+    render_big_const("LN_10", ln_10.clone(), num_words, &mut out);
+    render_big_const("LOG2_E", 1u8 / ln_2, num_words, &mut out);
+    render_big_const("LOG10_E", 1u8 / ln_10, num_words, &mut out);
+    render_big_const("FRAC_PI_2", pi / 2u8, num_words, &mut out);
+
+    Ok(out)
+}
+
+fn render_big_const(name: &str, value: rug::Float, num_words: u32, out: &mut String) {
+    // value = 0.words * 2^exp, with the highest bit of words[0] set
+    let exp = value.get_exp().unwrap();
+    let mut tmp = value >> exp;
+
+    writeln!(out, "const {name}: BigConst = BigConst {{").unwrap();
+    writeln!(out, "    exp: {exp},").unwrap();
+    out.push_str("    words: &[\n");
+
+    let words_per_line = 6;
+    for i in 0..num_words {
+        if (i % words_per_line) == 0 {
+            out.push_str("        ");
+        } else {
+            out.push(' ');
+        }
+        tmp <<= 32;
+        let word = tmp
+            .to_integer_round(rug::float::Round::Zero)
+            .unwrap()
+            .0
+            .to_u32()
+            .unwrap();
+        write!(out, "0x{word:08X},").unwrap();
+        tmp -= word;
+        if (i % words_per_line) == (words_per_line - 1) {
+            out.push('\n');
+        }
+    }
+    if (num_words % words_per_line) != 0 {
+        out.push('\n');
+    }
+    out.push_str("    ],\n");
+    out.push_str("};\n");
+}
@@ -0,0 +1,32 @@
+use super::super::{FloatKind, arg_utils, render_const, split_hi_lo};
+
+pub(in super::super) fn gen_consts(args: &[&str]) -> Result<String, String> {
+    let fkind: FloatKind = arg_utils::parse_1_arg(args)?;
+    let aux_prec = fkind.rug_aux_prec();
+
+    let mut out = String::new();
+
+    // Leave enough zero bits so multiplying by any exponent is exact
+    let split_prec = match fkind {
+        FloatKind::F16 => 6,
+        FloatKind::F32 => 16,
+        FloatKind::F64 => 40,
+        FloatKind::F128 => 98,
+    };
+
+    // ln(2)
+    let tmp = rug::Float::with_val(aux_prec * 3, rug::float::Constant::Log2);
+    let (hi, tmp) = split_hi_lo(tmp, split_prec);
+    let (mi, lo) = split_hi_lo(tmp, split_prec);
+    render_const(fkind, "DD_LN_2_HI", hi, &mut out);
+    render_const(fkind, "DD_LN_2_MI", mi, &mut out);
+    render_const(fkind, "DD_LN_2_LO", lo, &mut out);
+
+    // ln(10)
+    let tmp = rug::Float::with_val(aux_prec * 3, 10).ln();
+    let (hi, lo) = split_hi_lo(tmp, fkind.float_prec());
+    render_const(fkind, "DD_LN_10_HI", hi, &mut out);
+    render_const(fkind, "DD_LN_10_LO", lo, &mut out);
+
+    Ok(out)
+}
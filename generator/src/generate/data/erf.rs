@@ -0,0 +1,81 @@
+use super::super::{FloatKind, arg_utils, julia, render_const, split_hi_lo};
+
+pub(in super::super) fn gen_consts(args: &[&str]) -> Result<String, String> {
+    let fkind: FloatKind = arg_utils::parse_1_arg(args)?;
+    let aux_prec = fkind.rug_aux_prec();
+
+    let mut out = String::new();
+
+    // 1/√π
+    let tmp = rug::Float::with_val(aux_prec, rug::float::Constant::Pi)
+        .sqrt()
+        .recip();
+    let (hi, lo) = split_hi_lo(tmp, fkind.float_prec());
+    render_const(fkind, "FRAC_1_SQRT_PI_HI", hi, &mut out);
+    render_const(fkind, "FRAC_1_SQRT_PI_LO", lo, &mut out);
+
+    Ok(out)
+}
+
+pub(in super::super) fn gen_erf_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, poly_deg, range_end): (_, _, f64) = arg_utils::parse_3_args(args)?;
+
+    let mut out = String::new();
+
+    // erf(x) / x - 1, as a function of x^2
+    let func = "SpecialFunctions.erf(sqrt(x)) / sqrt(x) - 1";
+    let wfunc = "1";
+    let range = (1.0e-100, range_end * range_end);
+
+    julia::run_and_render_remez(fkind, func, wfunc, range, poly_deg, 0, "K", &mut out);
+
+    Ok(out)
+}
+
+pub(in super::super) fn gen_erfc_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, poly_deg, range_start, range_end): (FloatKind, i32, f64, f64) =
+        arg_utils::parse_4_args(args)?;
+    let aux_prec = fkind.rug_aux_prec();
+
+    let mut out = String::new();
+
+    // erfc(x) * exp(x^2), centered on the middle of the range
+    let mid = (range_start + range_end) / 2.0;
+
+    // The value at the center is split in hi and lo parts
+    let tmp = rug::Float::with_val(aux_prec, mid);
+    let tmp = tmp.clone().square().exp() * tmp.erfc();
+    let (hi, lo) = split_hi_lo(tmp, fkind.float_prec());
+    render_const(fkind, "K0_HI", hi, &mut out);
+    render_const(fkind, "K0_LO", lo, &mut out);
+
+    let func = format!(
+        "(exp((x + {mid})^2) * SpecialFunctions.erfc(x + {mid}) - exp(BigFloat({mid})^2) * SpecialFunctions.erfc(BigFloat({mid}))) / x"
+    );
+    let wfunc = "1";
+    let range = (range_start - mid, range_end - mid);
+
+    julia::run_and_render_remez(fkind, &func, wfunc, range, poly_deg - 1, 1, "K", &mut out);
+
+    Ok(out)
+}
+
+pub(in super::super) fn gen_erfc_asympt_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, poly_deg, range_start, range_end): (_, _, f64, f64) =
+        arg_utils::parse_4_args(args)?;
+
+    let mut out = String::new();
+
+    // (√π * x * erfc(x) * exp(x^2) - 1) * x^2, as a function of 1 / x^2
+    let func =
+        "(sqrt(BigFloat(pi)) * exp(1 / x) * SpecialFunctions.erfc(1 / sqrt(x)) / sqrt(x) - 1) / x";
+    let wfunc = "x / (1 + x * fx)";
+    let range = (
+        1.0 / (range_end * range_end),
+        1.0 / (range_start * range_start),
+    );
+
+    julia::run_and_render_remez(fkind, func, wfunc, range, poly_deg, 0, "K", &mut out);
+
+    Ok(out)
+}
@@ -0,0 +1,48 @@
+use super::super::{arg_utils, julia};
+
+pub(in super::super) fn gen_erfinv_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, poly_deg, range_end): (_, i32, f64) = arg_utils::parse_3_args(args)?;
+
+    let mut out = String::new();
+
+    // erfinv(x) / x, as a function of x^2
+    let func = "SpecialFunctions.erfinv(sqrt(x)) / sqrt(x)";
+    let wfunc = "1 / fx";
+    let range = (1.0e-100, range_end * range_end);
+
+    julia::run_and_render_remez(fkind, func, wfunc, range, poly_deg, 0, "K", &mut out);
+
+    Ok(out)
+}
+
+pub(in super::super) fn gen_erfcinv_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, poly_deg, range_start, range_end): (_, i32, f64, f64) =
+        arg_utils::parse_4_args(args)?;
+
+    let mut out = String::new();
+
+    // erfcinv(exp(-t^2)), as a function of t
+    let func = "SpecialFunctions.erfcinv(exp(-x^2))";
+    let wfunc = "1 / fx";
+    let range = (range_start, range_end);
+
+    julia::run_and_render_remez(fkind, func, wfunc, range, poly_deg, 0, "K", &mut out);
+
+    Ok(out)
+}
+
+pub(in super::super) fn gen_erfcinv_asympt_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, poly_deg, range_start, range_end): (_, i32, f64, f64) =
+        arg_utils::parse_4_args(args)?;
+
+    let mut out = String::new();
+
+    // erfcinv(exp(-t^2)) / t, as a function of 1/t
+    let func = "SpecialFunctions.erfcinv(exp(-1 / x^2)) * x";
+    let wfunc = "1 / fx";
+    let range = (1.0 / range_end, 1.0 / range_start);
+
+    julia::run_and_render_remez(fkind, func, wfunc, range, poly_deg, 0, "K", &mut out);
+
+    Ok(out)
+}
@@ -12,6 +12,12 @@ pub(in super::super) fn gen_consts(args: &[&str]) -> Result<String, String> {
     render_const(fkind, "HALF_LN_2_PI_HI", hi, &mut out);
     render_const(fkind, "HALF_LN_2_PI_LO", lo, &mut out);
 
+    // Euler-Mascheroni constant
+    let tmp = rug::Float::with_val(aux_prec, rug::float::Constant::Euler);
+    let (hi, lo) = split_hi_lo(tmp, fkind.float_prec());
+    render_const(fkind, "EULER_GAMMA_HI", hi, &mut out);
+    render_const(fkind, "EULER_GAMMA_LO", lo, &mut out);
+
     Ok(out)
 }
 
@@ -49,3 +55,151 @@ pub(in super::super) fn gen_special_poly(args: &[&str]) -> Result<String, String
 
     Ok(out)
 }
+
+pub(in super::super) fn gen_inc_gamma_temme_coeffs(args: &[&str]) -> Result<String, String> {
+    let (fkind, num_k, max_deg): (FloatKind, usize, usize) = arg_utils::parse_3_args(args)?;
+    let prec = fkind.rug_aux_prec() + 128;
+
+    let mut out = String::new();
+
+    // Temme's uniform asymptotic expansion:
+    // Q(a, x) = erfc(η * √(a / 2)) / 2 + exp(-a * η^2 / 2) / √(2πa) * sum(C_k(η) / a^k)
+    // where
+    // * μ = x / a - 1
+    // * η^2 / 2 = μ - ln(1 + μ)
+    // * sign(η) = sign(μ)
+    // * C_0(η) = 1 / μ - 1 / η
+    // * C_k(η) = C'_(k-1)(η) / η + (-1)^k * γ_k / μ
+    // * γ_k are the coefficients of the Stirling series
+    //   Γ(a) ~= √(2π) * a^(a - 1/2) * e^(-a) * sum(γ_k / a^k)
+    //
+    // Each C_k(η) is calculated as a power series of η.
+    let n = max_deg + 2 * num_k + 4;
+
+    // μ as a power series of η, the coefficient of η^(i + 1) in
+    // μ - ln(1 + μ) depends on the coefficient of η^i in μ with a
+    // factor of 1
+    let mut mu = vec![rug::Float::new(prec); n + 1];
+    mu[1] += 1u8;
+    for i in 2..=n {
+        let f = x_sub_ln_1p_series(&mu, n + 2);
+        mu[i] = -f[i + 1].clone();
+    }
+
+    // 1 / μ = (1 / η) * (1 / g), where g = μ / η
+    let g = &mu[1..];
+    let mut g_inv = vec![rug::Float::new(prec); g.len()];
+    g_inv[0] += 1u8;
+    for i in 1..g.len() {
+        let mut tmp = rug::Float::new(prec);
+        for j in 1..=i {
+            tmp -= g[j].clone() * &g_inv[i - j];
+        }
+        g_inv[i] = tmp;
+    }
+
+    let gamma = stirling_coeffs(num_k, prec);
+
+    // C_0(η) = (1 / g - 1) / η
+    let mut c = g_inv[1..].to_vec();
+    for (k, gamma_k) in gamma.iter().enumerate().take(num_k) {
+        if k != 0 {
+            // C'_(k-1)(η) / η, whose 1 / η term cancels with the one of
+            // (-1)^k * γ_k / μ
+            let mut next = Vec::with_capacity(c.len() - 2);
+            for i in 0..(c.len() - 2) {
+                let mut tmp = c[i + 2].clone() * (i + 2) as u32;
+                let gk = gamma_k.clone() * &g_inv[i + 1];
+                if k % 2 == 0 {
+                    tmp += gk;
+                } else {
+                    tmp -= gk;
+                }
+                next.push(tmp);
+            }
+            c = next;
+        }
+
+        for (i, coeff) in c.iter().take(max_deg - k).enumerate() {
+            render_const(fkind, &format!("C{k}_{i}"), coeff.clone(), &mut out);
+        }
+    }
+
+    Ok(out)
+}
+
+/// Calculates the power series of `μ - ln(1 + μ)` from the power series
+/// of `μ`, truncated to `len` terms. `μ` must not have a constant term.
+fn x_sub_ln_1p_series(mu: &[rug::Float], len: usize) -> Vec<rug::Float> {
+    let prec = mu[0].prec();
+
+    // μ - ln(1 + μ) = sum((-1)^j * μ^j / j), j >= 2
+    let mut r = vec![rug::Float::new(prec); len];
+    let mut p = series_mul(mu, mu, len);
+    for j in 2..len {
+        for (r_i, p_i) in r.iter_mut().zip(p.iter()) {
+            if j % 2 == 0 {
+                *r_i += p_i.clone() / j as u32;
+            } else {
+                *r_i -= p_i.clone() / j as u32;
+            }
+        }
+        p = series_mul(&p, mu, len);
+    }
+    r
+}
+
+/// Multiplies two power series, truncated to `len` terms
+fn series_mul(a: &[rug::Float], b: &[rug::Float], len: usize) -> Vec<rug::Float> {
+    let prec = a[0].prec();
+    let mut r = vec![rug::Float::new(prec); len];
+    for (i, a_i) in a.iter().enumerate().take(len) {
+        for (j, b_j) in b.iter().enumerate().take(len - i) {
+            r[i + j] += a_i.clone() * b_j;
+        }
+    }
+    r
+}
+
+/// Calculates the coefficients `γ_k` of the Stirling series for
+/// `0 <= k <= n`, as the exponential of
+/// `ln(Γ(a)) - ln(√(2π) * a^(a - 1/2) * e^(-a)) ~= sum(B_2j / (2j * (2j - 1) * a^(2j - 1)))`
+fn stirling_coeffs(n: usize, prec: u32) -> Vec<rug::Float> {
+    let len = n + 1;
+
+    // B_2j / (2j)! = (-1)^(j + 1) * 2 * ζ(2j) / (2π)^(2j)
+    let two_pi = rug::Float::with_val(prec, rug::float::Constant::Pi) * 2u8;
+    let two_pi_sq = two_pi.square();
+    let mut two_pi_pow = rug::Float::with_val(prec, 1u8);
+    let mut s = vec![rug::Float::new(prec); len];
+    for j in 1u32.. {
+        let i = (2 * j - 1) as usize;
+        if i >= len {
+            break;
+        }
+        two_pi_pow *= &two_pi_sq;
+        let zeta = rug::Float::with_val(prec, rug::Float::zeta_u(2 * j));
+        // B_2j / (2j * (2j - 1)) = B_2j / (2j)! * (2j - 2)!
+        let fact = rug::Float::with_val(prec, rug::Float::factorial(2 * j - 2));
+        let mut tmp = zeta * 2u8 / &two_pi_pow * fact;
+        if j % 2 == 0 {
+            tmp = -tmp;
+        }
+        s[i] = tmp;
+    }
+
+    // exp(s) = sum(s^i / i!)
+    let mut r = vec![rug::Float::new(prec); len];
+    r[0] += 1u8;
+    let mut term = r.clone();
+    for i in 1..len {
+        term = series_mul(&term, &s, len);
+        for t in term.iter_mut() {
+            *t /= i as u32;
+        }
+        for (r_j, t_j) in r.iter_mut().zip(term.iter()) {
+            *r_j += t_j;
+        }
+    }
+    r
+}
@@ -0,0 +1,59 @@
+use super::super::{FloatKind, arg_utils, render_const, split_hi_lo};
+
+pub(in super::super) fn gen_consts(args: &[&str]) -> Result<String, String> {
+    let fkind: FloatKind = arg_utils::parse_1_arg(args)?;
+    let aux_prec = fkind.rug_aux_prec();
+
+    let mut out = String::new();
+
+    // 1/e
+    let tmp = rug::Float::with_val(aux_prec, 1u8).exp().recip();
+    let (hi, lo) = split_hi_lo(tmp, fkind.float_prec());
+    render_const(fkind, "FRAC_1_E_HI", hi, &mut out);
+    render_const(fkind, "FRAC_1_E_LO", lo, &mut out);
+
+    Ok(out)
+}
+
+pub(in super::super) fn gen_branch_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, poly_deg): (FloatKind, usize) = arg_utils::parse_2_args(args)?;
+    let aux_prec = fkind.rug_aux_prec();
+
+    let mut out = String::new();
+
+    // Coefficients of the series of W(x) around the branch point, as a
+    // function of p = sqrt(2 * (e * x + 1)), calculated with the recurrence
+    // from "On the Lambert W function" (Corless et al., 1996):
+    //  * μ_k = (k - 1) / (k + 1) * (μ_(k-2) / 2 + α_(k-2) / 4)
+    //          - α_k / 2 - μ_(k-1) / (k + 1)
+    //  * α_k = sum(μ_j * μ_(k+1-j), j = 2..k-1)
+    //  * μ_0 = -1, μ_1 = 1, α_0 = 2, α_1 = -1
+    let mut mu = vec![
+        rug::Float::with_val(aux_prec, -1),
+        rug::Float::with_val(aux_prec, 1),
+    ];
+    let mut alpha = vec![
+        rug::Float::with_val(aux_prec, 2),
+        rug::Float::with_val(aux_prec, -1),
+    ];
+    for k in 2..=poly_deg {
+        let mut a = rug::Float::with_val(aux_prec, 0);
+        for j in 2..k {
+            a += mu[j].clone() * &mu[k + 1 - j];
+        }
+        alpha.push(a);
+
+        let kf = k as u32;
+        let t1 = (mu[k - 2].clone() / 2u8 + alpha[k - 2].clone() / 4u8) * (kf - 1) / (kf + 1);
+        let t2 = alpha[k].clone() / 2u8;
+        let t3 = mu[k - 1].clone() / (kf + 1);
+        mu.push(t1 - t2 - t3);
+    }
+
+    // The first two coefficients (-1 and 1) are not included
+    for (k, coeff) in mu.into_iter().enumerate().skip(2) {
+        render_const(fkind, &format!("K{k}"), coeff, &mut out);
+    }
+
+    Ok(out)
+}
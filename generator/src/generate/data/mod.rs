@@ -1,14 +1,21 @@
 pub(super) mod asin_acos;
 pub(super) mod atan;
+pub(super) mod bessel;
 pub(super) mod cbrt;
+pub(super) mod cr;
 pub(super) mod div_pi;
+pub(super) mod double_float;
+pub(super) mod erf;
+pub(super) mod erfinv;
 pub(super) mod exp;
 pub(super) mod exp10;
 pub(super) mod exp2;
 pub(super) mod gamma;
+pub(super) mod lambert_w;
 pub(super) mod ln;
 pub(super) mod log10;
 pub(super) mod log2;
+pub(super) mod polygamma;
 pub(super) mod rad_to_deg;
 pub(super) mod reduce_90_deg;
 pub(super) mod reduce_half_mul_pi;
@@ -0,0 +1,108 @@
+use super::super::{FloatKind, arg_utils, julia, render_const, split_hi_lo};
+
+pub(in super::super) fn gen_consts(args: &[&str]) -> Result<String, String> {
+    let fkind: FloatKind = arg_utils::parse_1_arg(args)?;
+    let aux_prec = fkind.rug_aux_prec();
+
+    let mut out = String::new();
+
+    // Positive root of the digamma function
+    let root = digamma_root(aux_prec);
+    let (hi, lo) = split_hi_lo(root, fkind.float_prec());
+    render_const(fkind, "ROOT_HI", hi, &mut out);
+    render_const(fkind, "ROOT_LO", lo, &mut out);
+
+    Ok(out)
+}
+
+pub(in super::super) fn gen_digamma_root_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, poly_deg, range_end): (FloatKind, i32, f64) = arg_utils::parse_3_args(args)?;
+
+    let root = digamma_root(1024).to_string_radix(10, None);
+
+    // ψ(x0 + x) / x, where x0 is the positive root
+    // Avoid evaluating 0/0 at the center
+    let func = format!(
+        "let z = iszero(x) ? BigFloat(\"1e-200\") : x; \
+        SpecialFunctions.digamma(BigFloat(\"{root}\") + z) / z \
+        end"
+    );
+    let wfunc = "1 / fx";
+    let range = (-range_end, range_end);
+
+    let coeffs = julia::run_remez(&func, wfunc, range, poly_deg);
+
+    let mut out = String::new();
+
+    // The first coefficient is split in hi and lo parts
+    for (i, coeff_value) in (1..).zip(coeffs) {
+        if i == 1 {
+            let (hi, lo) = split_hi_lo(coeff_value, fkind.float_prec());
+            render_const(fkind, "K1_HI", hi, &mut out);
+            render_const(fkind, "K1_LO", lo, &mut out);
+        } else {
+            render_const(fkind, &format!("K{i}"), coeff_value, &mut out);
+        }
+    }
+
+    Ok(out)
+}
+
+pub(in super::super) fn gen_digamma_asympt_poly(args: &[&str]) -> Result<String, String> {
+    let (fkind, poly_deg, range_start): (_, i32, f64) = arg_utils::parse_3_args(args)?;
+
+    let mut out = String::new();
+
+    // (ln(t) - 1/(2*t) - ψ(t)) * t^2, as a function of 1/t^2
+    let func = "let t = 1 / sqrt(x); \
+        (log(t) - 1 / (2 * t) - SpecialFunctions.digamma(t)) * t^2 \
+        end";
+    let wfunc = "1 / fx";
+    let range = (1.0e-100, 1.0 / (range_start * range_start));
+
+    julia::run_and_render_remez(fkind, func, wfunc, range, poly_deg, 0, "K", &mut out);
+
+    Ok(out)
+}
+
+pub(in super::super) fn gen_em_coeffs(args: &[&str]) -> Result<String, String> {
+    let (fkind, num_coeffs): (FloatKind, u32) = arg_utils::parse_2_args(args)?;
+    let aux_prec = fkind.rug_aux_prec();
+
+    let mut out = String::new();
+
+    // B_2j / (2j)! = (-1)^(j + 1) * 2 * ζ(2j) / (2π)^(2j)
+    let two_pi = rug::Float::with_val(aux_prec, rug::float::Constant::Pi) * 2u8;
+    let two_pi_sq = two_pi.square();
+    let mut two_pi_pow = rug::Float::with_val(aux_prec, 1u8);
+    for j in 1..=num_coeffs {
+        two_pi_pow *= &two_pi_sq;
+        let zeta = rug::Float::with_val(aux_prec, rug::Float::zeta_u(2 * j));
+        let mut tmp = zeta * 2u8 / &two_pi_pow;
+        if j % 2 == 0 {
+            tmp = -tmp;
+        }
+        render_const(fkind, &format!("B{j}"), tmp, &mut out);
+    }
+
+    Ok(out)
+}
+
+/// Calculates the positive root of the digamma function with the
+/// secant method
+fn digamma_root(prec: u32) -> rug::Float {
+    let mut x0 = rug::Float::with_val(prec, 1.4);
+    let mut x1 = rug::Float::with_val(prec, 1.5);
+    let mut f0 = x0.clone().digamma();
+    for _ in 0..100 {
+        let f1 = x1.clone().digamma();
+        if f1.is_zero() || f1 == f0 {
+            break;
+        }
+        let x2 = x1.clone() - f1.clone() * (x1.clone() - &x0) / (f1.clone() - &f0);
+        x0 = x1;
+        f0 = f1;
+        x1 = x2;
+    }
+    x1
+}
@@ -8,8 +8,7 @@ pub(in super::super) fn gen_consts(args: &[&str]) -> Result<String, String> {
 
     // 180/π
     let tmp = 180u8 / rug::Float::with_val(aux_prec, rug::float::Constant::Pi);
-    let (hi, lo) = split_hi_lo(tmp.clone(), fkind.split_prec());
-    render_const(fkind, "RAD_TO_DEG", tmp, &mut out);
+    let (hi, lo) = split_hi_lo(tmp, fkind.split_prec());
     render_const(fkind, "RAD_TO_DEG_HI", hi, &mut out);
     render_const(fkind, "RAD_TO_DEG_LO", lo, &mut out);
 
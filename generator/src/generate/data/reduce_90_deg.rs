@@ -8,8 +8,7 @@ pub(in super::super) fn gen_consts(args: &[&str]) -> Result<String, String> {
 
     // π/180
     let tmp = rug::Float::with_val(aux_prec, rug::float::Constant::Pi) / 180u8;
-    let (hi, lo) = split_hi_lo(tmp.clone(), fkind.split_prec());
-    render_const(fkind, "DEG_TO_RAD", tmp, &mut out);
+    let (hi, lo) = split_hi_lo(tmp, fkind.split_prec());
     render_const(fkind, "DEG_TO_RAD_HI", hi, &mut out);
     render_const(fkind, "DEG_TO_RAD_LO", lo, &mut out);
 
@@ -7,8 +7,10 @@ pub(in super::super) fn gen_consts(args: &[&str]) -> Result<String, String> {
     let mut out = String::new();
 
     let split_prec = match fkind {
+        FloatKind::F16 => 7,
         FloatKind::F32 => 15,
         FloatKind::F64 => 33,
+        FloatKind::F128 => 83,
     };
 
     // π/2
@@ -7,7 +7,8 @@ pub(in super::super) fn gen_frac_2_pi_large(args: &[&str]) -> Result<String, Str
 
     let mut out = String::new();
 
-    let num_words = 66;
+    // enough for the exponent range of `f128`
+    let num_words = 768;
     let tmp = 2u8 / rug::Float::with_val(num_words * 24, rug::float::Constant::Pi);
 
     render_const_24bit_words("FRAC_2_PI_LARGE", tmp, num_words, &mut out);
@@ -20,7 +21,7 @@ pub(in super::super) fn gen_frac_pi_2_medium(args: &[&str]) -> Result<String, St
 
     let mut out = String::new();
 
-    let num_words = 8;
+    let num_words = 12;
     let mut tmp = rug::Float::with_val(num_words * 24, rug::float::Constant::Pi) / 2u8;
     tmp /= 2u8;
 
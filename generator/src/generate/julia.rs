@@ -57,6 +57,24 @@ pub(super) fn run_and_render_remez(
     coeff_prefix: &str,
     out: &mut String,
 ) {
+    let coeffs = run_remez(func, wfunc, range, poly_deg);
+
+    for (i, coeff_value) in (0..).zip(coeffs) {
+        super::render_const(
+            fkind,
+            &format!("{coeff_prefix}{}", i + poly_i_print_off),
+            coeff_value,
+            out,
+        );
+    }
+}
+
+pub(super) fn run_remez(
+    func: &str,
+    wfunc: &str,
+    range: (f64, f64),
+    poly_deg: i32,
+) -> Vec<rug::Float> {
     let prec = 1024;
     let code = gen_remez_code(prec, func, wfunc, range, poly_deg);
     let result = run_julia(&code).unwrap();
@@ -67,16 +85,12 @@ pub(super) fn run_and_render_remez(
     let err = parse_f64(err_line);
     eprintln!("error = {err:e} = 2^({})", err.log2());
 
-    for i in 0..=poly_deg {
-        let coeff_line = lines.next().unwrap();
-        let coeff_value = parse_rug_float(coeff_line, prec);
-        super::render_const(
-            fkind,
-            &format!("{coeff_prefix}{}", i + poly_i_print_off),
-            coeff_value,
-            out,
-        );
-    }
+    (0..=poly_deg)
+        .map(|_| {
+            let coeff_line = lines.next().unwrap();
+            parse_rug_float(coeff_line, prec)
+        })
+        .collect()
 }
 
 fn gen_remez_code(prec: u32, func: &str, wfunc: &str, range: (f64, f64), poly_deg: i32) -> String {
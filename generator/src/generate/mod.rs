@@ -18,13 +18,33 @@ pub(crate) fn generate(param: &str) -> Result<String, RunError> {
 
     let r = match cmd {
         "consts" => consts::gen_consts(&args),
+        "bessel::consts" => data::bessel::gen_consts(&args),
+        "bessel::j0_small_poly" => data::bessel::gen_j0_small_poly(&args),
+        "bessel::j1_small_poly" => data::bessel::gen_j1_small_poly(&args),
+        "bessel::y0_small_poly" => data::bessel::gen_y0_small_poly(&args),
+        "bessel::y1_small_poly" => data::bessel::gen_y1_small_poly(&args),
+        "bessel::asympt_amp_poly" => data::bessel::gen_asympt_amp_poly(&args),
+        "bessel::asympt_phase_poly" => data::bessel::gen_asympt_phase_poly(&args),
+        "bessel::asympt_amp_tail_poly" => data::bessel::gen_asympt_amp_tail_poly(&args),
+        "bessel::asympt_phase_tail_poly" => data::bessel::gen_asympt_phase_tail_poly(&args),
         "cbrt::consts" => data::cbrt::gen_consts(&args),
         "cbrt::inv_cbrt_poly" => data::cbrt::gen_inv_cbrt_poly(&args),
+        "cr::consts" => data::cr::gen_consts(&args),
+        "double_float::consts" => data::double_float::gen_consts(&args),
+        "erf::consts" => data::erf::gen_consts(&args),
+        "erf::erf_poly" => data::erf::gen_erf_poly(&args),
+        "erf::erfc_poly" => data::erf::gen_erfc_poly(&args),
+        "erf::erfc_asympt_poly" => data::erf::gen_erfc_asympt_poly(&args),
+        "erfinv::erfinv_poly" => data::erfinv::gen_erfinv_poly(&args),
+        "erfinv::erfcinv_poly" => data::erfinv::gen_erfcinv_poly(&args),
+        "erfinv::erfcinv_asympt_poly" => data::erfinv::gen_erfcinv_asympt_poly(&args),
         "exp::consts" => data::exp::gen_consts(&args),
         "exp::exp_special_poly" => data::exp::gen_exp_special_poly(&args),
         "exp::exp_m1_special_poly" => data::exp::gen_exp_m1_special_poly(&args),
         "exp2::consts" => data::exp2::gen_consts(&args),
         "exp10::consts" => data::exp10::gen_consts(&args),
+        "lambert_w::consts" => data::lambert_w::gen_consts(&args),
+        "lambert_w::branch_poly" => data::lambert_w::gen_branch_poly(&args),
         "ln::consts" => data::ln::gen_consts(&args),
         "ln::ln_special_poly" => data::ln::gen_ln_special_poly(&args),
         "ln::ln_special_poly_ex" => data::ln::gen_ln_special_poly_ex(&args),
@@ -33,6 +53,11 @@ pub(crate) fn generate(param: &str) -> Result<String, RunError> {
         "gamma::consts" => data::gamma::gen_consts(&args),
         "gamma::ln_gamma_poly" => data::gamma::gen_ln_gamma_poly(&args),
         "gamma::special_poly" => data::gamma::gen_special_poly(&args),
+        "gamma::inc_gamma_temme_coeffs" => data::gamma::gen_inc_gamma_temme_coeffs(&args),
+        "polygamma::consts" => data::polygamma::gen_consts(&args),
+        "polygamma::digamma_root_poly" => data::polygamma::gen_digamma_root_poly(&args),
+        "polygamma::digamma_asympt_poly" => data::polygamma::gen_digamma_asympt_poly(&args),
+        "polygamma::em_coeffs" => data::polygamma::gen_em_coeffs(&args),
         "reduce_pi_2::consts" => data::reduce_pi_2::gen_consts(&args),
         "reduce_pi_2_large::frac_2_pi_large" => data::reduce_pi_2_large::gen_frac_2_pi_large(&args),
         "reduce_pi_2_large::frac_pi_2_medium" => {
@@ -66,8 +91,10 @@ pub(crate) fn generate(param: &str) -> Result<String, RunError> {
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 enum FloatKind {
+    F16,
     F32,
     F64,
+    F128,
 }
 
 impl std::str::FromStr for FloatKind {
@@ -75,8 +102,10 @@ impl std::str::FromStr for FloatKind {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            "f16" => Ok(Self::F16),
             "f32" => Ok(Self::F32),
             "f64" => Ok(Self::F64),
+            "f128" => Ok(Self::F128),
             _ => Err("invalid float kind"),
         }
     }
@@ -85,22 +114,28 @@ impl std::str::FromStr for FloatKind {
 impl FloatKind {
     fn rug_aux_prec(self) -> u32 {
         match self {
+            Self::F16 => 64,
             Self::F32 => 128,
             Self::F64 => 256,
+            Self::F128 => 512,
         }
     }
 
     fn float_prec(self) -> u32 {
         match self {
+            Self::F16 => 11,
             Self::F32 => 24,
             Self::F64 => 53,
+            Self::F128 => 113,
         }
     }
 
     fn split_prec(self) -> u32 {
         match self {
+            Self::F16 => 5,
             Self::F32 => 12,
             Self::F64 => 26,
+            Self::F128 => 56,
         }
     }
 }
@@ -113,6 +148,15 @@ fn split_hi_lo(mut tmp: rug::Float, hi_prec: u32) -> (rug::Float, rug::Float) {
 
 fn render_const(fkind: FloatKind, name: &str, val: rug::Float, out: &mut String) {
     match fkind {
+        FloatKind::F16 => {
+            let bits = f16_bits(&val);
+            writeln!(
+                out,
+                "const {name}: f16 = f16::from_bits(0x{bits:04X}); // {}",
+                f16_to_string(bits),
+            )
+            .unwrap();
+        }
         FloatKind::F32 => {
             let val = val.to_f32();
             writeln!(
@@ -131,5 +175,124 @@ fn render_const(fkind: FloatKind, name: &str, val: rug::Float, out: &mut String)
             )
             .unwrap();
         }
+        FloatKind::F128 => {
+            let bits = f128_bits(&val);
+            writeln!(
+                out,
+                "const {name}: f128 = f128::from_bits(0x{bits:032X}); // {}",
+                f128_to_string(bits),
+            )
+            .unwrap();
+        }
     }
 }
+
+/// Rounds `val` to the nearest `f16` and returns its bits
+fn f16_bits(val: &rug::Float) -> u16 {
+    let sign = u16::from(val.is_sign_negative()) << 15;
+    if val.is_nan() {
+        return 0x7E00;
+    }
+    if val.is_zero() {
+        return sign;
+    }
+    if val.is_infinite() {
+        return sign | 0x7C00;
+    }
+
+    let abs = rug::Float::with_val(val.prec(), val.abs_ref());
+    // abs = 0.m * 2^exp, so the unbiased exponent is exp - 1
+    let e = (abs.get_exp().unwrap() - 1).max(-14);
+    let m = (abs << (10 - e)).round_even();
+    let m = m.to_u32_saturating().unwrap();
+    // `m` can be 2^11 after rounding, which carries into the exponent
+    let raw = ((e + 14) as u32) * (1 << 10) + m;
+    if raw >= 0x7C00 {
+        sign | 0x7C00
+    } else {
+        sign | raw as u16
+    }
+}
+
+/// Formats an `f16` with the shortest representation that round-trips
+fn f16_to_string(bits: u16) -> String {
+    let sign = if bits & 0x8000 != 0 { "-" } else { "" };
+    let abs_bits = bits & 0x7FFF;
+    let e = i32::from(abs_bits >> 10);
+    let m = f64::from(abs_bits & 0x3FF);
+    let abs = if e == 0 {
+        m * 2f64.powi(-24)
+    } else {
+        (1.0 + m / 1024.0) * 2f64.powi(e - 15)
+    };
+
+    for prec in 0..17 {
+        let s = format!("{abs:.prec$e}");
+        let parsed = rug::Float::with_val(64, rug::Float::parse(&s).unwrap());
+        if f16_bits(&parsed) == abs_bits {
+            return format!("{sign}{s}");
+        }
+    }
+    unreachable!();
+}
+
+/// Rounds `val` to the nearest `f128` and returns its bits
+fn f128_bits(val: &rug::Float) -> u128 {
+    let sign = u128::from(val.is_sign_negative()) << 127;
+    if val.is_nan() {
+        return 0x7FFF_8000 << 96;
+    }
+    if val.is_zero() {
+        return sign;
+    }
+    if val.is_infinite() {
+        return sign | (0x7FFF << 112);
+    }
+
+    let abs = rug::Float::with_val(val.prec(), val.abs_ref());
+    // abs = 0.m * 2^exp, so the unbiased exponent is exp - 1
+    let e = (abs.get_exp().unwrap() - 1).max(-16382);
+    let m = (abs << (112 - e)).round_even();
+    let m = m.to_integer().unwrap().to_u128().unwrap();
+    // `m` can be 2^113 after rounding, which carries into the exponent
+    let raw = ((e + 16382) as u128) * (1 << 112) + m;
+    if raw >= (0x7FFF << 112) {
+        sign | (0x7FFF << 112)
+    } else {
+        sign | raw
+    }
+}
+
+/// Formats an `f128` with the shortest representation that round-trips
+fn f128_to_string(bits: u128) -> String {
+    let sign = if bits & (1 << 127) != 0 { "-" } else { "" };
+    let abs_bits = bits & !(1 << 127);
+    let e = (abs_bits >> 112) as i32;
+    let m = abs_bits & ((1 << 112) - 1);
+    let abs = if e == 0 {
+        rug::Float::with_val(113, m) >> 16494
+    } else {
+        rug::Float::with_val(113, m | (1 << 112)) >> (16495 - e)
+    };
+
+    for digits in 1..=40 {
+        let s = abs.to_string_radix(10, Some(digits));
+        let parsed = rug::Float::with_val(256, rug::Float::parse(&s).unwrap());
+        if f128_bits(&parsed) == abs_bits {
+            return format!("{sign}{}", format_exp(&s));
+        }
+    }
+    unreachable!();
+}
+
+/// Converts the `d.ddde±x` format of MPFR to the `d.ddde±x` format of Rust
+fn format_exp(s: &str) -> String {
+    let (mant, exp) = s.split_once('e').unwrap_or((s, "0"));
+    let mant = if mant.contains('.') {
+        mant.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        mant
+    };
+    let exp: i32 = exp.parse().unwrap();
+    format!("{mant}e{exp}")
+}
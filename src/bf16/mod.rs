@@ -0,0 +1,1069 @@
+use half::bf16;
+
+use crate::DoubleFloat;
+use crate::complex::Complex;
+use crate::slice::LANES;
+
+impl crate::traits::Lanes for bf16 {
+    type Scalar = Self;
+
+    #[inline]
+    fn splat(value: Self) -> Self {
+        value
+    }
+
+    #[inline]
+    fn purify(self) -> Self {
+        // `bf16` is stored as an integer and its arithmetic operations round
+        // the `f32` result with integer operations, so it never carries
+        // excess precision.
+        self
+    }
+
+    #[inline]
+    fn split_hi(self) -> Self {
+        crate::traits::float_split_hi(self)
+    }
+}
+
+impl crate::traits::Float for bf16 {
+    type Raw = u16;
+
+    type RawExp = u8;
+
+    type Exp = i16;
+
+    const BITS: u8 = 16;
+    const MANT_BITS: u8 = 7;
+    const EXP_BITS: u8 = 8;
+
+    const SIGN_MASK: Self::Raw = 1 << (<Self as crate::traits::Float>::BITS - 1);
+    const EXP_MASK: Self::Raw = ((1 << Self::EXP_BITS) - 1) << Self::MANT_BITS;
+    const MANT_MASK: Self::Raw = (1 << Self::MANT_BITS) - 1;
+
+    const EXP_OFFSET: Self::RawExp = (1 << (Self::EXP_BITS - 1)) - 1;
+    const MAX_RAW_EXP: Self::RawExp = (Self::EXP_MASK >> Self::MANT_BITS) as Self::RawExp;
+
+    const MIN_NORMAL_EXP: Self::Exp = -<Self as crate::traits::Float>::MAX_EXP + 1;
+    const MAX_EXP: Self::Exp = (Self::MAX_RAW_EXP >> 1) as Self::Exp;
+
+    const INFINITY: Self = Self::INFINITY;
+
+    #[inline]
+    fn neg_infinity() -> Self {
+        Self::NEG_INFINITY
+    }
+
+    const NAN: Self = Self::NAN;
+
+    const ZERO: Self = Self::ZERO;
+
+    #[inline]
+    fn half() -> Self {
+        Self::from_bits(0x3F00)
+    }
+
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    #[inline]
+    fn two() -> Self {
+        Self::from_bits(0x4000)
+    }
+
+    #[cfg(test)]
+    #[inline]
+    fn largest() -> Self {
+        Self::MAX
+    }
+
+    #[inline]
+    fn to_raw(self) -> Self::Raw {
+        self.to_bits()
+    }
+
+    #[inline]
+    fn from_raw(raw: Self::Raw) -> Self {
+        Self::from_bits(raw)
+    }
+
+    #[inline]
+    fn raw_exp_to_exp(e: Self::RawExp) -> Self::Exp {
+        i16::from(e.wrapping_sub(Self::EXP_OFFSET) as i8)
+    }
+
+    #[inline]
+    fn exp_to_raw_exp(e: Self::Exp) -> Self::RawExp {
+        (e as Self::RawExp).wrapping_add(Self::EXP_OFFSET)
+    }
+
+    #[cfg(test)]
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.is_nan()
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        Self::from_bits(self.to_bits() & !Self::SIGN_MASK)
+    }
+
+    #[inline]
+    fn copysign(self, y: Self) -> Self {
+        self.copysign(y)
+    }
+
+    #[cfg(test)]
+    fn parse(s: &str) -> Self {
+        from_f64(s.parse().unwrap())
+    }
+}
+
+macro_rules! impl_cast_from_small_int {
+    ($($t:ty),+) => {
+        $(
+            impl crate::traits::CastFrom<$t> for bf16 {
+                #[inline]
+                fn cast_from(value: $t) -> Self {
+                    // exact in `f64`
+                    from_f64(f64::from(value))
+                }
+            }
+        )+
+    };
+}
+
+impl_cast_from_small_int!(u8, i16, u16, i32, u32);
+
+impl crate::traits::CastFrom<u64> for bf16 {
+    #[inline]
+    fn cast_from(value: u64) -> Self {
+        // Keep the 53 most significant bits and fold the discarded ones into
+        // a sticky bit, so the conversion to `f64` is exact and the result
+        // is rounded only once.
+        let shift = (64 - value.leading_zeros()).saturating_sub(53);
+        let sticky = u64::from((value & ((1 << shift) - 1)) != 0);
+        from_f64((((value >> shift) | sticky) << shift) as f64)
+    }
+}
+
+impl crate::traits::CastFrom<i64> for bf16 {
+    #[inline]
+    fn cast_from(value: i64) -> Self {
+        let abs = <Self as crate::traits::CastFrom<u64>>::cast_from(value.unsigned_abs());
+        if value < 0 { -abs } else { abs }
+    }
+}
+
+/// Rounds `x` to the nearest `bf16`, ties to even.
+///
+/// `bf16::from_f64` ignores the lower 32 bits of `x`, so it does not
+/// always round correctly. Instead, `x` is rounded to `f32` with
+/// round-to-odd, which keeps enough information for the final rounding to
+/// `bf16` to be correct.
+#[inline]
+fn from_f64(x: f64) -> bf16 {
+    let y = x as f32;
+    let mut y_raw = y.to_bits();
+    if (y_raw & 1) == 0 && f64::from(y) != x && !x.is_nan() {
+        // `y` is inexact and even, so take its odd neighbor towards `x`
+        if f64::from(y).abs() > x.abs() {
+            y_raw -= 1;
+        } else {
+            y_raw += 1;
+        }
+    }
+    bf16::from_f32(f32::from_bits(y_raw))
+}
+
+/// Like [`from_f64`], for functions that return an unrounded `(hi, lo)`
+/// result
+#[inline]
+fn hi_lo_from_f64((hi, lo): (f64, f64)) -> (bf16, bf16) {
+    let r_hi = from_f64(hi);
+    if !r_hi.is_finite() {
+        // infinity (including overflow) or NaN
+        return (r_hi, bf16::ZERO);
+    }
+    // `hi - r_hi` is exact, since `r_hi` is `hi` rounded to `bf16`
+    let r_lo = from_f64((hi - r_hi.to_f64()) + lo);
+    if r_lo == bf16::ZERO {
+        // also keeps the sign of zero `r_hi`
+        (r_hi, bf16::ZERO)
+    } else {
+        // renormalize, rounding `r_lo` can produce a tie in `r_hi + r_lo`
+        let s = r_hi + r_lo;
+        (s, r_lo - (s - r_hi))
+    }
+}
+
+/// Like [`from_f64`], for an `f64` result `y` whose exact value is
+/// `y * (1 + e)`, up to terms of higher order
+///
+/// When `|e|` is too small to affect `y`, `y` can be exactly halfway
+/// between two `bf16` values while the exact result is not (e.g.,
+/// `atan2(y, x) = y / x * (1 - (y / x)^2 / 3 + ...)`), so `y` is moved one
+/// `f64` ULP in the direction of `e`.
+#[inline]
+fn from_f64_corrected(y: f64, e: f64) -> bf16 {
+    if y != 0.0 && y.is_finite() && e != 0.0 && e.abs() < f64::EPSILON {
+        let bits = y.to_bits();
+        if e > 0.0 {
+            from_f64(f64::from_bits(bits + 1))
+        } else {
+            from_f64(f64::from_bits(bits - 1))
+        }
+    } else {
+        from_f64(y)
+    }
+}
+
+#[inline]
+fn dd_to_f64(x: DoubleFloat<bf16>) -> DoubleFloat<f64> {
+    // exact, the sum of two `bf16` always fits in a double-`f64`
+    crate::generic::dd_new(x.hi.to_f64(), x.lo.to_f64())
+}
+
+#[inline]
+fn dd_from_f64(x: DoubleFloat<f64>) -> DoubleFloat<bf16> {
+    let (hi, lo) = hi_lo_from_f64((x.hi, x.lo));
+    DoubleFloat { hi, lo }
+}
+
+/// Converts the exponent of `pown` to `f64`, keeping its parity
+///
+/// Exponents that are not exact in `f64` are clamped, which does not change
+/// the result, since the powers of any `bf16` other than ±1 overflow or
+/// underflow long before.
+fn pown_exp_to_f64(n: i64) -> f64 {
+    const MAX: i64 = 1 << 53;
+    if n > MAX {
+        (MAX - (n & 1)) as f64
+    } else if n < -MAX {
+        (-MAX + (n & 1)) as f64
+    } else {
+        n as f64
+    }
+}
+
+#[inline]
+fn c_to_f64(z: Complex<bf16>) -> Complex<f64> {
+    Complex::new(z.re.to_f64(), z.im.to_f64())
+}
+
+#[inline]
+fn c_from_f64(z: Complex<f64>) -> Complex<bf16> {
+    Complex::new(from_f64(z.re), from_f64(z.im))
+}
+
+#[inline]
+fn lanes_to_f64(x: &[bf16; LANES]) -> [f64; LANES] {
+    x.map(bf16::to_f64)
+}
+
+#[inline]
+fn lanes_from_f64(x: &[f64; LANES]) -> [bf16; LANES] {
+    x.map(from_f64)
+}
+
+impl crate::sealed::SealedMath for bf16 {
+    fn dd_from(x: Self) -> DoubleFloat<Self> {
+        crate::generic::dd_from(x)
+    }
+
+    fn dd_new(hi: Self, lo: Self) -> DoubleFloat<Self> {
+        crate::generic::dd_new(hi, lo)
+    }
+
+    fn dd_neg(x: DoubleFloat<Self>) -> DoubleFloat<Self> {
+        crate::generic::dd_neg(x)
+    }
+
+    fn dd_add(x: DoubleFloat<Self>, y: DoubleFloat<Self>) -> DoubleFloat<Self> {
+        crate::generic::dd_add(x, y)
+    }
+
+    fn dd_sub(x: DoubleFloat<Self>, y: DoubleFloat<Self>) -> DoubleFloat<Self> {
+        crate::generic::dd_sub(x, y)
+    }
+
+    fn dd_mul(x: DoubleFloat<Self>, y: DoubleFloat<Self>) -> DoubleFloat<Self> {
+        crate::generic::dd_mul(x, y)
+    }
+
+    fn dd_div(x: DoubleFloat<Self>, y: DoubleFloat<Self>) -> DoubleFloat<Self> {
+        crate::generic::dd_div(x, y)
+    }
+
+    fn dd_sqrt(x: DoubleFloat<Self>) -> DoubleFloat<Self> {
+        crate::generic::dd_sqrt(x)
+    }
+
+    fn dd_exp(x: DoubleFloat<Self>) -> DoubleFloat<Self> {
+        dd_from_f64(crate::generic::dd_exp(dd_to_f64(x)))
+    }
+
+    fn dd_ln(x: DoubleFloat<Self>) -> DoubleFloat<Self> {
+        dd_from_f64(crate::generic::dd_ln(dd_to_f64(x)))
+    }
+
+    fn dd_sin_cos(x: DoubleFloat<Self>) -> (DoubleFloat<Self>, DoubleFloat<Self>) {
+        let (sin, cos) = crate::generic::dd_sin_cos(dd_to_f64(x));
+        (dd_from_f64(sin), dd_from_f64(cos))
+    }
+
+    fn cr_exp(x: Self) -> Self {
+        from_f64(crate::generic::cr_exp(x.to_f64()))
+    }
+
+    fn cr_exp_m1(x: Self) -> Self {
+        from_f64(crate::generic::cr_exp_m1(x.to_f64()))
+    }
+
+    fn cr_exp2(x: Self) -> Self {
+        from_f64(crate::generic::cr_exp2(x.to_f64()))
+    }
+
+    fn cr_exp10(x: Self) -> Self {
+        from_f64(crate::generic::cr_exp10(x.to_f64()))
+    }
+
+    fn cr_ln(x: Self) -> Self {
+        from_f64(crate::generic::cr_ln(x.to_f64()))
+    }
+
+    fn cr_ln_1p(x: Self) -> Self {
+        from_f64(crate::generic::cr_ln_1p(x.to_f64()))
+    }
+
+    fn cr_log2(x: Self) -> Self {
+        from_f64(crate::generic::cr_log2(x.to_f64()))
+    }
+
+    fn cr_log10(x: Self) -> Self {
+        from_f64(crate::generic::cr_log10(x.to_f64()))
+    }
+
+    fn cr_pow(x: Self, y: Self) -> Self {
+        from_f64(crate::generic::cr_pow(x.to_f64(), y.to_f64()))
+    }
+
+    fn cr_cbrt(x: Self) -> Self {
+        from_f64(crate::generic::cr_cbrt(x.to_f64()))
+    }
+
+    fn cr_hypot(x: Self, y: Self) -> Self {
+        from_f64(crate::generic::cr_hypot(x.to_f64(), y.to_f64()))
+    }
+
+    fn cr_sin(x: Self) -> Self {
+        from_f64(crate::generic::cr_sin(x.to_f64()))
+    }
+
+    fn cr_cos(x: Self) -> Self {
+        from_f64(crate::generic::cr_cos(x.to_f64()))
+    }
+
+    fn cr_tan(x: Self) -> Self {
+        from_f64(crate::generic::cr_tan(x.to_f64()))
+    }
+
+    fn cr_asin(x: Self) -> Self {
+        from_f64(crate::generic::cr_asin(x.to_f64()))
+    }
+
+    fn cr_acos(x: Self) -> Self {
+        from_f64(crate::generic::cr_acos(x.to_f64()))
+    }
+
+    fn cr_atan(x: Self) -> Self {
+        from_f64(crate::generic::cr_atan(x.to_f64()))
+    }
+
+    fn cr_atan2(y: Self, x: Self) -> Self {
+        let r = crate::generic::cr_atan2(y.to_f64(), x.to_f64());
+        // `y / x` can be exactly halfway between two subnormal `bf16`,
+        // then `r = y / x` and the exact result is slightly closer to zero
+        let r32 = r as f32;
+        if r.abs() < f64::from(f32::MIN_POSITIVE)
+            && f64::from(r32) == r
+            && (r32.to_bits() & 0xFFFF) == 0x8000
+        {
+            from_f64_corrected(r, -r.abs())
+        } else {
+            from_f64(r)
+        }
+    }
+
+    fn cr_sinh(x: Self) -> Self {
+        from_f64(crate::generic::cr_sinh(x.to_f64()))
+    }
+
+    fn cr_cosh(x: Self) -> Self {
+        from_f64(crate::generic::cr_cosh(x.to_f64()))
+    }
+
+    fn cr_tanh(x: Self) -> Self {
+        from_f64(crate::generic::cr_tanh(x.to_f64()))
+    }
+
+    fn c_neg(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_neg(z)
+    }
+
+    fn c_conj(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_conj(z)
+    }
+
+    fn c_add(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_add(x, y)
+    }
+
+    fn c_sub(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_sub(x, y)
+    }
+
+    fn c_mul(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_mul(c_to_f64(x), c_to_f64(y)))
+    }
+
+    fn c_div(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_div(c_to_f64(x), c_to_f64(y)))
+    }
+
+    fn c_exp(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_exp(c_to_f64(z)))
+    }
+
+    fn c_ln(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_ln(c_to_f64(z)))
+    }
+
+    fn c_sqrt(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_sqrt(c_to_f64(z)))
+    }
+
+    fn c_pow(z: Complex<Self>, w: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_pow(c_to_f64(z), c_to_f64(w)))
+    }
+
+    fn c_sin(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_sin(c_to_f64(z)))
+    }
+
+    fn c_cos(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_cos(c_to_f64(z)))
+    }
+
+    fn c_tan(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_tan(c_to_f64(z)))
+    }
+
+    fn c_sinh(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_sinh(c_to_f64(z)))
+    }
+
+    fn c_cosh(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_cosh(c_to_f64(z)))
+    }
+
+    fn c_tanh(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_tanh(c_to_f64(z)))
+    }
+
+    fn c_asin(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_asin(c_to_f64(z)))
+    }
+
+    fn c_acos(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_acos(c_to_f64(z)))
+    }
+
+    fn c_atan(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_atan(c_to_f64(z)))
+    }
+
+    fn c_asinh(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_asinh(c_to_f64(z)))
+    }
+
+    fn c_acosh(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_acosh(c_to_f64(z)))
+    }
+
+    fn c_atanh(z: Complex<Self>) -> Complex<Self> {
+        c_from_f64(crate::generic::c_atanh(c_to_f64(z)))
+    }
+
+    fn slice_exp(x: &mut [Self; LANES]) {
+        let mut y = lanes_to_f64(x);
+        crate::generic::slice_exp(&mut y);
+        *x = lanes_from_f64(&y);
+    }
+
+    fn slice_ln(x: &mut [Self; LANES]) {
+        let mut y = lanes_to_f64(x);
+        crate::generic::slice_ln(&mut y);
+        *x = lanes_from_f64(&y);
+    }
+
+    fn slice_sin(x: &mut [Self; LANES]) {
+        let mut y = lanes_to_f64(x);
+        crate::generic::slice_sin(&mut y);
+        *x = lanes_from_f64(&y);
+    }
+
+    fn slice_cos(x: &mut [Self; LANES]) {
+        let mut y = lanes_to_f64(x);
+        crate::generic::slice_cos(&mut y);
+        *x = lanes_from_f64(&y);
+    }
+
+    fn slice_sin_cos(sin: &mut [Self; LANES], cos: &mut [Self; LANES]) {
+        let mut s = lanes_to_f64(sin);
+        let mut c = [0.0; LANES];
+        crate::generic::slice_sin_cos(&mut s, &mut c);
+        *sin = lanes_from_f64(&s);
+        *cos = lanes_from_f64(&c);
+    }
+}
+
+// The operations that are exact or correctly rounded in any precision
+// (rounding, scaling, square root) use the generic implementations. The
+// rest of functions are evaluated in `f64` and rounded to `bf16`. The error
+// of the `f64` result is far below half a `bf16` ULP, so the result is
+// correctly rounded unless the exact result is extremely close to a
+// halfway point between two `bf16` values. That only happens when the
+// exact result is a halfway point (e.g., `pow(17, 2)` or `hypot(207, 224)`),
+// so those functions are evaluated with correctly rounded `f64`
+// operations. The `f64` result can also be a halfway point when the exact
+// result is not, because a correction term is too small for `f64`, which
+// is handled with `from_f64_corrected`.
+impl crate::ElementaryMath for bf16 {
+    fn abs(x: Self) -> Self {
+        crate::traits::Float::abs(x)
+    }
+
+    fn copysign(x: Self, y: Self) -> Self {
+        x.copysign(y)
+    }
+
+    fn round(x: Self) -> Self {
+        crate::generic::round(x)
+    }
+
+    fn trunc(x: Self) -> Self {
+        crate::generic::trunc(x)
+    }
+
+    fn ceil(x: Self) -> Self {
+        crate::generic::ceil(x)
+    }
+
+    fn floor(x: Self) -> Self {
+        crate::generic::floor(x)
+    }
+
+    fn scalbn(x: Self, y: i32) -> Self {
+        crate::generic::scalbn(x, y)
+    }
+
+    fn frexp(x: Self) -> (Self, i32) {
+        crate::generic::frexp(x)
+    }
+
+    fn fma(x: Self, y: Self, z: Self) -> Self {
+        crate::generic::fma(x, y, z)
+    }
+
+    fn fmod(x: Self, y: Self) -> Self {
+        crate::generic::fmod(x, y)
+    }
+
+    fn remainder(x: Self, y: Self) -> Self {
+        crate::generic::remainder(x, y)
+    }
+
+    fn remquo(x: Self, y: Self) -> (Self, i32) {
+        crate::generic::remquo(x, y)
+    }
+
+    fn hypot(x: Self, y: Self) -> Self {
+        if x.is_infinite() || y.is_infinite() {
+            // hypot(±inf, y) = inf, even when y is NaN
+            bf16::INFINITY
+        } else {
+            // `x * x` and `y * y` are exact and cannot overflow or underflow
+            // in `f64`, so the sum has a relative error of at most 2^-53 and
+            // is exact when the result is a halfway point.
+            let (x, y) = (x.to_f64(), y.to_f64());
+            from_f64(crate::generic::sqrt(x * x + y * y))
+        }
+    }
+
+    fn sqrt(x: Self) -> Self {
+        crate::generic::sqrt(x)
+    }
+
+    fn rsqrt(x: Self) -> Self {
+        from_f64(crate::generic::rsqrt(x.to_f64()))
+    }
+
+    fn cbrt(x: Self) -> Self {
+        from_f64(crate::generic::cbrt(x.to_f64()))
+    }
+
+    fn exp(x: Self) -> Self {
+        from_f64(crate::generic::exp(x.to_f64()))
+    }
+
+    fn exp_m1(x: Self) -> Self {
+        from_f64(crate::generic::exp_m1(x.to_f64()))
+    }
+
+    fn exp_hi_lo(x: Self) -> (Self, Self) {
+        hi_lo_from_f64(crate::generic::exp_hi_lo(x.to_f64()))
+    }
+
+    fn exp2(x: Self) -> Self {
+        from_f64(crate::generic::exp2(x.to_f64()))
+    }
+
+    fn exp2_m1(x: Self) -> Self {
+        from_f64(crate::generic::exp2_m1(x.to_f64()))
+    }
+
+    fn exp10(x: Self) -> Self {
+        from_f64(crate::generic::exp10(x.to_f64()))
+    }
+
+    fn exp10_m1(x: Self) -> Self {
+        from_f64(crate::generic::exp10_m1(x.to_f64()))
+    }
+
+    fn ln(x: Self) -> Self {
+        from_f64(crate::generic::ln(x.to_f64()))
+    }
+
+    fn ln_1p(x: Self) -> Self {
+        from_f64(crate::generic::ln_1p(x.to_f64()))
+    }
+
+    fn ln_hi_lo(x: Self) -> (Self, Self) {
+        hi_lo_from_f64(crate::generic::ln_hi_lo(x.to_f64()))
+    }
+
+    fn ln_1p_hi_lo(x: Self) -> (Self, Self) {
+        hi_lo_from_f64(crate::generic::ln_1p_hi_lo(x.to_f64()))
+    }
+
+    fn log2(x: Self) -> Self {
+        from_f64(crate::generic::log2(x.to_f64()))
+    }
+
+    fn log2_1p(x: Self) -> Self {
+        from_f64(crate::generic::log2_1p(x.to_f64()))
+    }
+
+    fn log10(x: Self) -> Self {
+        from_f64(crate::generic::log10(x.to_f64()))
+    }
+
+    fn log10_1p(x: Self) -> Self {
+        from_f64(crate::generic::log10_1p(x.to_f64()))
+    }
+
+    fn pow(x: Self, y: Self) -> Self {
+        from_f64(crate::generic::cr_pow(x.to_f64(), y.to_f64()))
+    }
+
+    fn powi(x: Self, y: i32) -> Self {
+        from_f64(crate::generic::cr_pow(x.to_f64(), f64::from(y)))
+    }
+
+    fn powr(x: Self, y: Self) -> Self {
+        let (x, y) = (x.to_f64(), y.to_f64());
+        if crate::generic::powr(x, y).is_nan() {
+            Self::NAN
+        } else {
+            // `powr` is `pow` of `|x|` (`x` can be negative zero) out of the
+            // cases where it returns NaN
+            from_f64(crate::generic::cr_pow(x.abs(), y))
+        }
+    }
+
+    fn pown(x: Self, n: i64) -> Self {
+        from_f64(crate::generic::cr_pow(x.to_f64(), pown_exp_to_f64(n)))
+    }
+
+    fn rootn(x: Self, n: i64) -> Self {
+        // `x^(1/n)` cannot be a halfway point unless `n` is 1 or -1, which
+        // are calculated exactly
+        from_f64(crate::generic::rootn(x.to_f64(), n))
+    }
+
+    fn compound(x: Self, n: i64) -> Self {
+        let x = x.to_f64();
+        let xp1 = 1.0 + x;
+        let xp1_is_exact = xp1 - 1.0 == x && xp1 - x == 1.0;
+        if x < -1.0 {
+            Self::NAN
+        } else if (xp1_is_exact || x.is_nan()) && n.unsigned_abs() <= 1 << 53 {
+            // `1 + x` and `n` are exact (`pow` also handles NaN)
+            from_f64(crate::generic::cr_pow(xp1, n as f64))
+        } else if x > 1.0 {
+            // (1 + x)^n = x^n * (1 + n / x + ...)
+            let e = n as f64 / x;
+            from_f64_corrected(crate::generic::cr_pow(x, pown_exp_to_f64(n)), e)
+        } else {
+            // `x` is tiny or `n` is huge, so the result is not close to a
+            // halfway point
+            from_f64(crate::generic::compound(x, n))
+        }
+    }
+
+    fn sin(x: Self) -> Self {
+        from_f64(crate::generic::sin(x.to_f64()))
+    }
+
+    fn cos(x: Self) -> Self {
+        from_f64(crate::generic::cos(x.to_f64()))
+    }
+
+    fn sin_cos(x: Self) -> (Self, Self) {
+        let (sin, cos) = crate::generic::sin_cos(x.to_f64());
+        (from_f64(sin), from_f64(cos))
+    }
+
+    fn sin_cos_hi_lo(x: Self) -> ((Self, Self), (Self, Self)) {
+        let (sin, cos) = crate::generic::sin_cos_hi_lo(x.to_f64());
+        (hi_lo_from_f64(sin), hi_lo_from_f64(cos))
+    }
+
+    fn tan(x: Self) -> Self {
+        from_f64(crate::generic::tan(x.to_f64()))
+    }
+
+    fn rem_pio2(x: Self) -> (i32, Self, Self) {
+        let (n, y_hi, y_lo) = crate::generic::rem_pio2(x.to_f64());
+        let (y_hi, y_lo) = hi_lo_from_f64((y_hi, y_lo));
+        (n, y_hi, y_lo)
+    }
+
+    fn wrap_angle(x: Self) -> Self {
+        from_f64(crate::generic::wrap_angle(x.to_f64()))
+    }
+
+    fn sind(x: Self) -> Self {
+        from_f64(crate::generic::sind(x.to_f64()))
+    }
+
+    fn cosd(x: Self) -> Self {
+        from_f64(crate::generic::cosd(x.to_f64()))
+    }
+
+    fn sind_cosd(x: Self) -> (Self, Self) {
+        let (sin, cos) = crate::generic::sind_cosd(x.to_f64());
+        (from_f64(sin), from_f64(cos))
+    }
+
+    fn tand(x: Self) -> Self {
+        from_f64(crate::generic::tand(x.to_f64()))
+    }
+
+    fn sinpi(x: Self) -> Self {
+        from_f64(crate::generic::sinpi(x.to_f64()))
+    }
+
+    fn cospi(x: Self) -> Self {
+        from_f64(crate::generic::cospi(x.to_f64()))
+    }
+
+    fn sinpi_cospi(x: Self) -> (Self, Self) {
+        let (sin, cos) = crate::generic::sinpi_cospi(x.to_f64());
+        (from_f64(sin), from_f64(cos))
+    }
+
+    fn tanpi(x: Self) -> Self {
+        from_f64(crate::generic::tanpi(x.to_f64()))
+    }
+
+    fn asin(x: Self) -> Self {
+        from_f64(crate::generic::asin(x.to_f64()))
+    }
+
+    fn acos(x: Self) -> Self {
+        from_f64(crate::generic::acos(x.to_f64()))
+    }
+
+    fn atan(x: Self) -> Self {
+        from_f64(crate::generic::atan(x.to_f64()))
+    }
+
+    fn atan2(y: Self, x: Self) -> Self {
+        let (y, x) = (y.to_f64(), x.to_f64());
+        let e = if x > 0.0 {
+            let q = y / x;
+            -(q * q) / 3.0
+        } else {
+            0.0
+        };
+        from_f64_corrected(crate::generic::atan2(y, x), e)
+    }
+
+    fn asind(x: Self) -> Self {
+        from_f64(crate::generic::asind(x.to_f64()))
+    }
+
+    fn acosd(x: Self) -> Self {
+        from_f64(crate::generic::acosd(x.to_f64()))
+    }
+
+    fn atand(x: Self) -> Self {
+        from_f64(crate::generic::atand(x.to_f64()))
+    }
+
+    fn atan2d(y: Self, x: Self) -> Self {
+        from_f64(crate::generic::atan2d(y.to_f64(), x.to_f64()))
+    }
+
+    fn asinpi(x: Self) -> Self {
+        from_f64(crate::generic::asinpi(x.to_f64()))
+    }
+
+    fn acospi(x: Self) -> Self {
+        from_f64(crate::generic::acospi(x.to_f64()))
+    }
+
+    fn atanpi(x: Self) -> Self {
+        from_f64(crate::generic::atanpi(x.to_f64()))
+    }
+
+    fn atan2pi(y: Self, x: Self) -> Self {
+        from_f64(crate::generic::atan2pi(y.to_f64(), x.to_f64()))
+    }
+
+    fn sinh(x: Self) -> Self {
+        from_f64(crate::generic::sinh(x.to_f64()))
+    }
+
+    fn cosh(x: Self) -> Self {
+        from_f64(crate::generic::cosh(x.to_f64()))
+    }
+
+    fn sinh_cosh(x: Self) -> (Self, Self) {
+        let (sinh, cosh) = crate::generic::sinh_cosh(x.to_f64());
+        (from_f64(sinh), from_f64(cosh))
+    }
+
+    fn tanh(x: Self) -> Self {
+        from_f64(crate::generic::tanh(x.to_f64()))
+    }
+
+    fn asinh(x: Self) -> Self {
+        from_f64(crate::generic::asinh(x.to_f64()))
+    }
+
+    fn acosh(x: Self) -> Self {
+        from_f64(crate::generic::acosh(x.to_f64()))
+    }
+
+    fn atanh(x: Self) -> Self {
+        from_f64(crate::generic::atanh(x.to_f64()))
+    }
+}
+
+impl crate::FloatMath for bf16 {
+    fn gamma(x: Self) -> Self {
+        from_f64(crate::generic::gamma(x.to_f64()))
+    }
+
+    fn ln_gamma(x: Self) -> (Self, i8) {
+        let (r, s) = crate::generic::ln_gamma(x.to_f64());
+        (from_f64(r), s)
+    }
+
+    fn gamma_p(a: Self, x: Self) -> Self {
+        from_f64(crate::generic::gamma_p(a.to_f64(), x.to_f64()))
+    }
+
+    fn gamma_q(a: Self, x: Self) -> Self {
+        from_f64(crate::generic::gamma_q(a.to_f64(), x.to_f64()))
+    }
+
+    fn gamma_p_inv(a: Self, p: Self) -> Self {
+        from_f64(crate::generic::gamma_p_inv(a.to_f64(), p.to_f64()))
+    }
+
+    fn gamma_q_inv(a: Self, q: Self) -> Self {
+        from_f64(crate::generic::gamma_q_inv(a.to_f64(), q.to_f64()))
+    }
+
+    fn digamma(x: Self) -> Self {
+        from_f64(crate::generic::digamma(x.to_f64()))
+    }
+
+    fn polygamma(n: i32, x: Self) -> Self {
+        let x = x.to_f64();
+        let e = if x.abs() > 1.0 {
+            // ψ^(n)(x) = (-1)^(n + 1) * (n - 1)! / x^n * (1 + n / (2x) + ...)
+            f64::from(n) / (2.0 * x)
+        } else {
+            // ψ^(n)(x) = (-1)^(n + 1) * n! / x^(n + 1) * (1 + ζ(n + 1) * x^(n + 1) + ...)
+            crate::generic::powi(x, n.saturating_add(1))
+        };
+        from_f64_corrected(crate::generic::polygamma(n, x), e)
+    }
+
+    fn zeta(s: Self) -> Self {
+        from_f64(crate::generic::zeta(s.to_f64()))
+    }
+
+    fn hurwitz_zeta(s: Self, q: Self) -> Self {
+        from_f64(crate::generic::hurwitz_zeta(s.to_f64(), q.to_f64()))
+    }
+
+    fn beta(a: Self, b: Self) -> Self {
+        from_f64(crate::generic::beta(a.to_f64(), b.to_f64()))
+    }
+
+    fn ln_beta(a: Self, b: Self) -> (Self, i8) {
+        let (r, s) = crate::generic::ln_beta(a.to_f64(), b.to_f64());
+        (from_f64(r), s)
+    }
+
+    fn beta_inc(a: Self, b: Self, x: Self) -> Self {
+        let (a, b, x) = (a.to_f64(), b.to_f64(), x.to_f64());
+        // For tiny `x`,
+        // I_x(a, b) = x^a / (a * B(a, b)) * (1 + a * (1 - b) / (a + 1) * x + ...)
+        // where each term is less than half of the previous one
+        let t = (1.0 - b) * x;
+        let e = if x < f64::EPSILON && t.abs() < 0.5 {
+            a / (a + 1.0) * t
+        } else {
+            0.0
+        };
+        from_f64_corrected(crate::generic::beta_inc(a, b, x), e)
+    }
+
+    fn beta_inc_inv(a: Self, b: Self, p: Self) -> Self {
+        from_f64(crate::generic::beta_inc_inv(
+            a.to_f64(),
+            b.to_f64(),
+            p.to_f64(),
+        ))
+    }
+
+    fn erf(x: Self) -> Self {
+        from_f64(crate::generic::erf(x.to_f64()))
+    }
+
+    fn erfc(x: Self) -> Self {
+        from_f64(crate::generic::erfc(x.to_f64()))
+    }
+
+    fn erfinv(x: Self) -> Self {
+        from_f64(crate::generic::erfinv(x.to_f64()))
+    }
+
+    fn erfcinv(x: Self) -> Self {
+        from_f64(crate::generic::erfcinv(x.to_f64()))
+    }
+
+    fn j0(x: Self) -> Self {
+        from_f64(crate::generic::j0(x.to_f64()))
+    }
+
+    fn j1(x: Self) -> Self {
+        let x = x.to_f64();
+        // J_1(x) = x / 2 * (1 - x^2 / 8 + ...)
+        from_f64_corrected(crate::generic::j1(x), -(x * x) / 8.0)
+    }
+
+    fn jn(n: i32, x: Self) -> Self {
+        let x = x.to_f64();
+        // J_n(x) = (x / 2)^n / n! * (1 - x^2 / (4 * (n + 1)) + ...)
+        let e = -(x * x) / (4.0 * (f64::from(n.unsigned_abs()) + 1.0));
+        from_f64_corrected(crate::generic::jn(n, x), e)
+    }
+
+    fn y0(x: Self) -> Self {
+        from_f64(crate::generic::y0(x.to_f64()))
+    }
+
+    fn y1(x: Self) -> Self {
+        from_f64(crate::generic::y1(x.to_f64()))
+    }
+
+    fn yn(n: i32, x: Self) -> Self {
+        from_f64(crate::generic::yn(n, x.to_f64()))
+    }
+
+    fn lambert_w0(x: Self) -> Self {
+        let x = x.to_f64();
+        // W0(x) = x * (1 - x + ...)
+        from_f64_corrected(crate::generic::lambert_w0(x), -x)
+    }
+
+    fn lambert_wm1(x: Self) -> Self {
+        from_f64(crate::generic::lambert_wm1(x.to_f64()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use half::bf16;
+
+    use crate::traits::{CastFrom as _, Float as _};
+
+    #[test]
+    fn test_exp2i_fast() {
+        for e in -126..=127 {
+            let x = bf16::exp2i_fast(e);
+            assert_eq!(x.to_f32(), f32::exp2(f32::from(e)));
+            assert_eq!(x.exponent(), e);
+            assert_eq!(x.to_bits() & bf16::MANT_MASK, 0);
+        }
+    }
+
+    #[test]
+    fn test_from_f64() {
+        // 1 + 2^-8 is halfway between 1 and 1 + 2^-7
+        let one = 1.0f64;
+        let half_ulp = 2.0f64.powi(-8);
+        assert_eq!(super::from_f64(one + half_ulp).to_bits(), 0x3F80);
+        assert_eq!(super::from_f64(one + half_ulp * 3.0).to_bits(), 0x3F82);
+        // slightly above the halfway point, only in the lower 32 bits
+        let x = f64::from_bits((one + half_ulp).to_bits() + 1);
+        assert_eq!(super::from_f64(x).to_bits(), 0x3F81);
+        let x = f64::from_bits((one + half_ulp).to_bits() - 1);
+        assert_eq!(super::from_f64(x).to_bits(), 0x3F80);
+        // subnormals
+        let min_subnormal = 2.0f64.powi(-133);
+        assert_eq!(super::from_f64(min_subnormal).to_bits(), 0x0001);
+        assert_eq!(super::from_f64(min_subnormal * 0.5).to_bits(), 0x0000);
+        assert_eq!(super::from_f64(min_subnormal * 0.75).to_bits(), 0x0001);
+        assert_eq!(super::from_f64(-min_subnormal * 0.25).to_bits(), 0x8000);
+        // overflow
+        assert_eq!(super::from_f64(f64::from(f32::MAX)).to_bits(), 0x7F80);
+        assert_eq!(super::from_f64(-1.0e300).to_bits(), 0xFF80);
+        assert_eq!(
+            super::from_f64(f64::from(bf16::MAX.to_f32())).to_bits(),
+            bf16::MAX.to_bits(),
+        );
+        assert!(super::from_f64(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_cast_from_int() {
+        assert_eq!(bf16::cast_from(257u32).to_bits(), 0x4380);
+        assert_eq!(bf16::cast_from(259u32).to_bits(), 0x4382);
+        // halfway point in the upper 53 bits, but not exactly
+        let x = (1u64 << 63) | (1u64 << 55) | 1;
+        assert_eq!(bf16::cast_from(x).to_bits(), 0x5F01);
+        assert_eq!(bf16::cast_from(-(((x >> 1) | 1) as i64)).to_bits(), 0xDE81);
+        assert_eq!(bf16::cast_from(u64::MAX).to_bits(), 0x5F80);
+    }
+}
@@ -0,0 +1,517 @@
+use core::cmp::Ordering;
+
+use crate::generic::{scalbn, sqrt};
+use crate::traits::{CastInto as _, Float, Int as _};
+
+/// Maximum number of 32-bit words in the mantissa of a [`BigFloat`]
+pub(crate) const MAX_WORDS: usize = 32;
+
+/// Number of low bits of a [`BigFloat`] that are not trusted when rounding
+///
+/// Results of the multi-precision functions must have an error less than
+/// `2^-(32 * len - GUARD_BITS)` relative to their value.
+pub(crate) const GUARD_BITS: usize = 64;
+
+/// A multi-precision constant, `0.words * 2^exp`
+pub(crate) struct BigConst {
+    pub(crate) exp: i32,
+    pub(crate) words: &'static [u32],
+}
+
+/// A software floating point number with a mantissa of `len` 32-bit words
+///
+/// The value is `±0.m * 2^exp`, where the highest bit of `m` is set (unless
+/// the value is zero). The precision (`len`) is chosen at run time and
+/// operations return a result with the precision of the left operand.
+/// Results are truncated, so each operation has an error of a few units in
+/// the last word.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct BigFloat {
+    neg: bool,
+    exp: i32,
+    len: usize,
+    m: [u32; MAX_WORDS],
+}
+
+impl BigFloat {
+    #[inline]
+    pub(crate) fn zero(len: usize) -> Self {
+        debug_assert!((2..=MAX_WORDS).contains(&len));
+        Self {
+            neg: false,
+            exp: 0,
+            len,
+            m: [0; MAX_WORDS],
+        }
+    }
+
+    /// Creates a number from `±0.buf * 2^exp`, `buf` does not need to
+    /// be normalized and is truncated to `len` words
+    pub(crate) fn from_buf(neg: bool, exp: i32, buf: &[u32], len: usize) -> Self {
+        let Some(first) = buf.iter().position(|&w| w != 0) else {
+            return Self::zero(len);
+        };
+        let buf = &buf[first..];
+        let shift = buf[0].leading_zeros();
+        let mut m = [0; MAX_WORDS];
+        for (i, w) in m[..len].iter_mut().enumerate() {
+            let w0 = buf.get(i).copied().unwrap_or(0);
+            let w1 = buf.get(i + 1).copied().unwrap_or(0);
+            *w = if shift == 0 {
+                w0
+            } else {
+                (w0 << shift) | (w1 >> (32 - shift))
+            };
+        }
+        Self {
+            neg,
+            exp: exp - (first as i32) * 32 - shift as i32,
+            len,
+            m,
+        }
+    }
+
+    /// Creates a number from `±m * 2^e`
+    pub(crate) fn from_u64(neg: bool, m: u64, e: i32, len: usize) -> Self {
+        Self::from_buf(neg, e + 64, &[(m >> 32) as u32, m as u32], len)
+    }
+
+    /// Creates a number from a finite `x`, which is always exact
+    pub(crate) fn from_float<F: Float>(x: F, len: usize) -> Self {
+        let mant_bits = i32::from(F::MANT_BITS);
+        let (m, e) = if x.raw_exp() == F::RawExp::ZERO {
+            (x.raw_mant(), F::MIN_NORMAL_EXP.into() - mant_bits)
+        } else {
+            (x.mant(), x.exponent().into() - mant_bits)
+        };
+
+        // Split the mantissa in 32-bit words, from the highest one
+        let n = usize::from(F::MANT_BITS) / 32 + 1;
+        let mut buf = [0u32; 4];
+        for (i, w) in buf[..n].iter_mut().rev().enumerate() {
+            *w = (m >> (32 * i) as u8).cast_into();
+        }
+        Self::from_buf(x.sign(), e + 32 * n as i32, &buf[..n], len)
+    }
+
+    pub(crate) fn from_const(c: &BigConst, len: usize) -> Self {
+        Self::from_buf(false, c.exp, &c.words[..len], len)
+    }
+
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    #[inline]
+    pub(crate) fn is_zero(&self) -> bool {
+        self.m[0] == 0
+    }
+
+    #[inline]
+    pub(crate) fn is_neg(&self) -> bool {
+        self.neg
+    }
+
+    /// Returns `e` such as `2^(e - 1) <= |self| < 2^e`
+    ///
+    /// Returns `i32::MIN` for zero.
+    #[inline]
+    pub(crate) fn exponent(&self) -> i32 {
+        if self.is_zero() { i32::MIN } else { self.exp }
+    }
+
+    /// Returns `true` if `|self| < 2^-(32 * len)` relative to `|rel|`,
+    /// which means that adding `self` to `rel` does not change it
+    #[inline]
+    pub(crate) fn is_negligible(&self, rel: &Self) -> bool {
+        self.is_zero()
+            || (!rel.is_zero() && self.exp < rel.exp.saturating_sub((32 * self.len) as i32))
+    }
+
+    /// Returns the top 32 bits of the mantissa, in `[2^31, 2^32)` unless
+    /// the value is zero
+    #[inline]
+    pub(crate) fn top_word(&self) -> u32 {
+        self.m[0]
+    }
+
+    /// Calculates `self * 2^e`
+    #[inline]
+    pub(crate) fn scale(mut self, e: i32) -> Self {
+        if !self.is_zero() {
+            self.exp += e;
+        }
+        self
+    }
+
+    fn cmp_abs(&self, rhs: &Self) -> Ordering {
+        match (self.is_zero(), rhs.is_zero()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self
+                .exp
+                .cmp(&rhs.exp)
+                .then_with(|| self.m[..self.len].cmp(&rhs.m[..rhs.len])),
+        }
+    }
+
+    /// Returns `self` rounded to the nearest integer
+    ///
+    /// `|self|` must be less than `2^31`.
+    pub(crate) fn round_i32(&self) -> i32 {
+        if self.exp <= -1 || self.is_zero() {
+            return 0;
+        }
+        debug_assert!(self.exp <= 31);
+        let nbits = self.exp as usize;
+        let int = self.get_bits(0, nbits) as i32 + i32::from(self.bit(nbits));
+        if self.neg { -int } else { int }
+    }
+
+    /// Calculates `self * rhs`
+    pub(crate) fn mul(&self, rhs: &Self) -> Self {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero(self.len);
+        }
+        let len = self.len;
+        let rlen = rhs.len.min(len);
+        let mut prod = [0u32; MAX_WORDS * 2];
+        for i in (0..len).rev() {
+            let mut carry = 0u64;
+            for j in (0..rlen).rev() {
+                let k = i + j + 1;
+                let t = u64::from(self.m[i]) * u64::from(rhs.m[j]) + u64::from(prod[k]) + carry;
+                prod[k] = t as u32;
+                carry = t >> 32;
+            }
+            prod[i] = carry as u32;
+        }
+        Self::from_buf(
+            self.neg != rhs.neg,
+            self.exp + rhs.exp,
+            &prod[..(len + rlen)],
+            len,
+        )
+    }
+
+    /// Calculates `self * y`
+    pub(crate) fn mul_u32(&self, y: u32) -> Self {
+        let len = self.len;
+        let mut buf = [0u32; MAX_WORDS + 1];
+        let mut carry = 0u64;
+        for i in (0..len).rev() {
+            let t = u64::from(self.m[i]) * u64::from(y) + carry;
+            buf[i + 1] = t as u32;
+            carry = t >> 32;
+        }
+        buf[0] = carry as u32;
+        Self::from_buf(self.neg, self.exp + 32, &buf[..=len], len)
+    }
+
+    /// Calculates `self / y`
+    pub(crate) fn div_u32(&self, y: u32) -> Self {
+        let len = self.len;
+        let mut buf = [0u32; MAX_WORDS + 1];
+        let mut rem = 0u64;
+        for (i, q) in buf[..=len].iter_mut().enumerate() {
+            let cur = (rem << 32) | u64::from(self.m.get(i).copied().unwrap_or(0));
+            *q = (cur / u64::from(y)) as u32;
+            rem = cur % u64::from(y);
+        }
+        Self::from_buf(self.neg, self.exp, &buf[..=len], len)
+    }
+
+    /// Calculates `self + rhs`
+    pub(crate) fn add(&self, rhs: &Self) -> Self {
+        if rhs.is_zero() {
+            return *self;
+        }
+        if self.is_zero() {
+            let mut r = *rhs;
+            r.len = self.len;
+            r.m[self.len..].fill(0);
+            return r;
+        }
+
+        let len = self.len;
+        let (a, b) = if self.cmp_abs(rhs) == Ordering::Less {
+            (rhs, self)
+        } else {
+            (self, rhs)
+        };
+
+        // One extra word for the carry and one for the lower bits of `b`
+        let n = len + 2;
+        let mut x = [0u32; MAX_WORDS + 2];
+        let mut y = [0u32; MAX_WORDS + 2];
+        x[1..=len].copy_from_slice(&a.m[..len]);
+
+        let d = (a.exp - b.exp) as usize;
+        let (ws, bs) = (d / 32, d % 32);
+        for (i, &w) in b.m[..b.len.min(len)].iter().enumerate() {
+            let k = 1 + i + ws;
+            if k < n {
+                y[k] |= w >> bs;
+            }
+            if bs != 0 && k + 1 < n {
+                y[k + 1] |= w << (32 - bs);
+            }
+        }
+
+        if a.neg == b.neg {
+            let mut carry = 0u64;
+            for i in (0..n).rev() {
+                let t = u64::from(x[i]) + u64::from(y[i]) + carry;
+                x[i] = t as u32;
+                carry = t >> 32;
+            }
+        } else {
+            let mut borrow = 0u64;
+            for i in (0..n).rev() {
+                let t = u64::from(x[i])
+                    .wrapping_sub(u64::from(y[i]))
+                    .wrapping_sub(borrow);
+                x[i] = t as u32;
+                borrow = t >> 63;
+            }
+        }
+
+        Self::from_buf(a.neg, a.exp + 32, &x[..n], len)
+    }
+
+    /// Calculates `self - rhs`
+    #[inline]
+    pub(crate) fn sub(&self, rhs: &Self) -> Self {
+        self.add(&-*rhs)
+    }
+
+    /// Calculates `self + y`
+    #[inline]
+    pub(crate) fn add_i32(&self, y: i32) -> Self {
+        self.add(&Self::from_u64(
+            y < 0,
+            u64::from(y.unsigned_abs()),
+            0,
+            self.len,
+        ))
+    }
+
+    /// Calculates `1 / self` with Newton iterations
+    ///
+    /// `self` must not be zero.
+    pub(crate) fn recip(&self) -> Self {
+        let len = self.len;
+        // d = |self| scaled to [0.5, 1)
+        let d = Self {
+            neg: false,
+            exp: 0,
+            ..*self
+        };
+
+        // Initial approximation with about 60 bits
+        let top = (u64::from(self.m[0]) << 32) | u64::from(self.m[1]);
+        let r0 = (1u128 << 127) / u128::from(top);
+        let mut r = Self::from_buf(
+            false,
+            96 - 63,
+            &[(r0 >> 64) as u32, (r0 >> 32) as u32, r0 as u32],
+            len,
+        );
+
+        // r = r + r * (1 - d * r)
+        let mut bits = 60;
+        while bits < 32 * len + 8 {
+            let e = d.mul(&r).add_i32(-1);
+            r = r.sub(&r.mul(&e));
+            bits *= 2;
+        }
+
+        r.neg = self.neg;
+        r.scale(-self.exp)
+    }
+
+    /// Calculates `self / rhs`
+    #[inline]
+    pub(crate) fn div(&self, rhs: &Self) -> Self {
+        self.mul(&rhs.recip())
+    }
+
+    /// Calculates `sqrt(self)`, `self` must not be negative
+    pub(crate) fn sqrt(&self) -> Self {
+        if self.is_zero() {
+            return *self;
+        }
+        let len = self.len;
+        // self = d * 2^(2 * k), with d in [0.25, 1)
+        let k = (self.exp + 1).div_euclid(2);
+        let d = Self {
+            exp: self.exp - 2 * k,
+            ..*self
+        };
+
+        // Initial approximation of 1 / sqrt(d) with about 50 bits
+        let r0 = 1.0 / sqrt(d.round_ties_even::<f64>());
+        let mut r = Self::from_float(r0, len);
+
+        // r = r + r * (1 - d * r^2) / 2
+        let mut bits = 50;
+        while bits < 32 * len + 8 {
+            let e = d.mul(&r.mul(&r)).add_i32(-1);
+            r = r.sub(&r.mul(&e).scale(-1));
+            bits *= 2;
+        }
+
+        // sqrt(d) = d * r
+        d.mul(&r).scale(k)
+    }
+
+    /// Rounds to the nearest `F`
+    ///
+    /// Returns `None` if the bits that are not trusted (see [`GUARD_BITS`])
+    /// do not allow to decide the rounding.
+    #[inline]
+    pub(crate) fn round<F: Float>(&self) -> Option<F> {
+        self.round_inner(false)
+    }
+
+    /// Like [`round`](Self::round), but when the rounding cannot be decided,
+    /// the value is assumed to be exactly halfway between two values of `F`
+    /// and it is rounded to even.
+    ///
+    /// The result is only correctly rounded when the value is known to be
+    /// exact or the rounding can be decided.
+    #[inline]
+    pub(crate) fn round_ties_even<F: Float>(&self) -> F {
+        self.round_inner(true).unwrap()
+    }
+
+    fn round_inner<F: Float>(&self, force: bool) -> Option<F> {
+        let zero = F::ZERO.set_sign(self.neg);
+        if self.is_zero() {
+            return Some(zero);
+        }
+
+        // Number of trusted bits
+        let t = 32 * self.len - GUARD_BITS;
+
+        // The highest bit has a weight of 2^lead
+        let lead = self.exp - 1;
+        if lead > F::MAX_EXP.into() {
+            return Some(F::INFINITY.set_sign(self.neg));
+        }
+
+        // Number of bits that fit in the mantissa of `F`
+        let p = i32::from(F::MANT_BITS) + 1 - (F::MIN_NORMAL_EXP.into() - lead).max(0);
+        if p < 0 {
+            // Less than half the smallest subnormal, the rounding is only
+            // uncertain when very close to it
+            if p == -1 && !force && self.bits_all(0, t, true) {
+                return None;
+            }
+            return Some(zero);
+        }
+        let p = p as usize;
+
+        let k = (0..p).fold(F::Raw::ZERO, |acc, i| {
+            (acc << 1u8) | F::Raw::from(self.bit(i))
+        });
+        let r = self.bit(p);
+        let uncertain = self.bits_all(p + 1, t, !r);
+        let round_up = if uncertain {
+            if !force {
+                return None;
+            }
+            // assume halfway, round to even
+            (k & F::Raw::ONE) != F::Raw::ZERO
+        } else {
+            r
+        };
+        let k = k + F::Raw::from(round_up);
+
+        let y: F = scalbn(k.cast_into(), lead - (p as i32) + 1);
+        Some(y.set_sign(self.neg))
+    }
+
+    #[inline]
+    fn bit(&self, i: usize) -> bool {
+        i < 32 * self.len && ((self.m[i / 32] >> (31 - i % 32)) & 1) != 0
+    }
+
+    /// Returns the `n` (at most 64) bits starting from bit `start`,
+    /// where bit 0 is the highest one
+    fn get_bits(&self, start: usize, n: usize) -> u64 {
+        (start..(start + n)).fold(0, |acc, i| (acc << 1) | u64::from(self.bit(i)))
+    }
+
+    /// Returns whether all bits in `start..end` are `value`
+    fn bits_all(&self, start: usize, end: usize, value: bool) -> bool {
+        (start..end).all(|i| self.bit(i) == value)
+    }
+}
+
+impl core::ops::Neg for BigFloat {
+    type Output = Self;
+
+    #[inline]
+    fn neg(mut self) -> Self {
+        if !self.is_zero() {
+            self.neg = !self.neg;
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BigFloat, MAX_WORDS};
+
+    #[test]
+    fn test_arith() {
+        let len = MAX_WORDS;
+        let one = BigFloat::from_float(1.0f64, len);
+        let three = BigFloat::from_float(3.0f64, len);
+        let third = one.div(&three);
+
+        assert_eq!(third.round::<f64>(), Some(1.0 / 3.0));
+        assert_eq!(third.mul_u32(3).round::<f64>(), Some(1.0));
+        assert_eq!(third.add(&third).add(&third).round::<f64>(), Some(1.0));
+        assert_eq!(one.sub(&third).round::<f64>(), Some(2.0 / 3.0));
+        assert_eq!(three.div_u32(3).round::<f64>(), Some(1.0));
+        assert!(one.sub(&one).is_zero());
+        assert_eq!(BigFloat::from_float(-2.5f64, len).round_i32(), -3);
+        assert_eq!(BigFloat::from_float(1e9f64, len).round_i32(), 1_000_000_000);
+    }
+
+    #[test]
+    fn test_round() {
+        let len = MAX_WORDS;
+        let mk = |m: u64, e: i32| BigFloat::from_u64(false, m, e, len);
+
+        // exactly representable
+        assert_eq!(mk(1, -1074).round::<f64>(), Some(f64::from_bits(1)));
+        assert_eq!(
+            mk(3, 1022).round::<f64>(),
+            Some(f64::from_bits(0x7FE8_0000_0000_0000))
+        );
+        assert_eq!(mk(1, 1024).round::<f64>(), Some(f64::INFINITY));
+
+        // halfway, cannot be decided without assuming exactness
+        let half = mk((1 << 53) + 1, 0);
+        assert_eq!(half.round::<f64>(), None);
+        assert_eq!(half.round_ties_even::<f64>(), 9007199254740992.0);
+        let half = mk((1 << 53) + 3, 0);
+        assert_eq!(half.round_ties_even::<f64>(), 9007199254740996.0);
+        assert_eq!(mk(1, -1075).round::<f64>(), None);
+        assert_eq!(mk(1, -1075).round_ties_even::<f64>(), 0.0);
+        assert_eq!(mk(3, -1075).round_ties_even::<f64>(), f64::from_bits(2));
+
+        // not halfway
+        let x = mk((1 << 54) + 3, 0).scale(-1);
+        assert_eq!(x.round::<f64>(), Some(9007199254740994.0));
+        assert_eq!(mk(3, -1076).round::<f64>(), Some(f64::from_bits(1)));
+        assert_eq!(mk(1, -1076).round::<f64>(), Some(0.0));
+        assert_eq!((-mk(1, -1076)).round::<f64>(), Some(-0.0));
+    }
+}
@@ -0,0 +1,245 @@
+//! Complex numbers.
+//!
+//! [`Complex`] provides the complex elementary functions for any type that
+//! implements [`ElementaryMath`], including all types that implement
+//! [`FloatMath`](crate::FloatMath).
+//!
+//! Special values (zeros, infinities and NaN in any part) and branch cuts
+//! follow C99 Annex G. Branch cuts lie on the real or imaginary axis, and
+//! the sign of a zero real or imaginary part selects the side of the cut.
+//!
+//! # Example
+//!
+//! ```
+//! use fpmath::complex::Complex;
+//!
+//! let z = Complex::new(-4.0f64, 0.0);
+//! assert_eq!(z.sqrt(), Complex::new(0.0, 2.0));
+//! // negative zero selects the other side of the branch cut
+//! assert_eq!(Complex::new(-4.0f64, -0.0).sqrt(), Complex::new(0.0, -2.0));
+//! assert_eq!(z.abs(), 4.0);
+//! ```
+
+use crate::ElementaryMath;
+
+/// A complex number in Cartesian form, `re + i * im`.
+///
+/// The functions have an error of a few ULP in each part, relative to that
+/// part, except [`pow`](Self::pow).
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct Complex<F> {
+    /// The real part.
+    pub re: F,
+    /// The imaginary part.
+    pub im: F,
+}
+
+impl<F> Complex<F> {
+    /// Creates a complex number from its real and imaginary parts.
+    #[inline]
+    pub const fn new(re: F, im: F) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<F: ElementaryMath> Complex<F> {
+    /// Returns the complex conjugate.
+    #[inline]
+    pub fn conj(self) -> Self {
+        F::c_conj(self)
+    }
+
+    /// Calculates the absolute value (or modulus) with [`hypot`](crate::hypot).
+    #[inline]
+    pub fn abs(self) -> F {
+        F::hypot(self.re, self.im)
+    }
+
+    /// Calculates the argument (or phase) in `[-π, π]` with
+    /// [`atan2`](crate::atan2).
+    ///
+    /// The branch cut is the negative real axis. The sign of the imaginary
+    /// part selects `π` or `-π`.
+    #[inline]
+    pub fn arg(self) -> F {
+        F::atan2(self.im, self.re)
+    }
+
+    /// Calculates Euler's number raised to `self`.
+    #[inline]
+    pub fn exp(self) -> Self {
+        F::c_exp(self)
+    }
+
+    /// Calculates the principal value of the natural logarithm.
+    ///
+    /// The imaginary part is [`arg`](Self::arg), so the branch cut is the
+    /// negative real axis.
+    #[inline]
+    pub fn ln(self) -> Self {
+        F::c_ln(self)
+    }
+
+    /// Calculates the principal square root.
+    ///
+    /// The real part of the result is not negative. The branch cut is the
+    /// negative real axis.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        F::c_sqrt(self)
+    }
+
+    /// Calculates the principal value of `self` raised to `w`, that is,
+    /// `exp(w * ln(self))`.
+    ///
+    /// The error is a few ULP relative to the magnitude of the result, which
+    /// grows with the magnitude of `w`. The branch cut is the negative real
+    /// axis.
+    ///
+    /// Special cases:
+    /// * Returns one if `w` is zero, even if `self` is NaN
+    /// * Returns zero if `self` is zero and `w` is real and positive
+    #[inline]
+    pub fn pow(self, w: Self) -> Self {
+        F::c_pow(self, w)
+    }
+
+    /// Calculates the sine.
+    #[inline]
+    pub fn sin(self) -> Self {
+        F::c_sin(self)
+    }
+
+    /// Calculates the cosine.
+    #[inline]
+    pub fn cos(self) -> Self {
+        F::c_cos(self)
+    }
+
+    /// Calculates the tangent.
+    #[inline]
+    pub fn tan(self) -> Self {
+        F::c_tan(self)
+    }
+
+    /// Calculates the hyperbolic sine.
+    #[inline]
+    pub fn sinh(self) -> Self {
+        F::c_sinh(self)
+    }
+
+    /// Calculates the hyperbolic cosine.
+    #[inline]
+    pub fn cosh(self) -> Self {
+        F::c_cosh(self)
+    }
+
+    /// Calculates the hyperbolic tangent.
+    #[inline]
+    pub fn tanh(self) -> Self {
+        F::c_tanh(self)
+    }
+
+    /// Calculates the principal value of the arcsine.
+    ///
+    /// The real part of the result is in `[-π/2, π/2]`. The branch cuts are
+    /// the real axis outside `[-1, 1]`.
+    #[inline]
+    pub fn asin(self) -> Self {
+        F::c_asin(self)
+    }
+
+    /// Calculates the principal value of the arccosine.
+    ///
+    /// The real part of the result is in `[0, π]`. The branch cuts are the
+    /// real axis outside `[-1, 1]`.
+    #[inline]
+    pub fn acos(self) -> Self {
+        F::c_acos(self)
+    }
+
+    /// Calculates the principal value of the arctangent.
+    ///
+    /// The real part of the result is in `[-π/2, π/2]`. The branch cuts are
+    /// the imaginary axis outside `[-i, i]`.
+    #[inline]
+    pub fn atan(self) -> Self {
+        F::c_atan(self)
+    }
+
+    /// Calculates the principal value of the inverse hyperbolic sine.
+    ///
+    /// The imaginary part of the result is in `[-π/2, π/2]`. The branch cuts
+    /// are the imaginary axis outside `[-i, i]`.
+    #[inline]
+    pub fn asinh(self) -> Self {
+        F::c_asinh(self)
+    }
+
+    /// Calculates the principal value of the inverse hyperbolic cosine.
+    ///
+    /// The real part of the result is not negative and the imaginary part is
+    /// in `[-π, π]`. The branch cut is the real axis below 1.
+    #[inline]
+    pub fn acosh(self) -> Self {
+        F::c_acosh(self)
+    }
+
+    /// Calculates the principal value of the inverse hyperbolic tangent.
+    ///
+    /// The imaginary part of the result is in `[-π/2, π/2]`. The branch cuts
+    /// are the real axis outside `[-1, 1]`.
+    #[inline]
+    pub fn atanh(self) -> Self {
+        F::c_atanh(self)
+    }
+}
+
+impl<F: ElementaryMath> core::ops::Neg for Complex<F> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        F::c_neg(self)
+    }
+}
+
+impl<F: ElementaryMath> core::ops::Add for Complex<F> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        F::c_add(self, rhs)
+    }
+}
+
+impl<F: ElementaryMath> core::ops::Sub for Complex<F> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        F::c_sub(self, rhs)
+    }
+}
+
+impl<F: ElementaryMath> core::ops::Mul for Complex<F> {
+    type Output = Self;
+
+    /// Multiplies two complex numbers, recovering infinities as described in
+    /// C99 Annex G.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        F::c_mul(self, rhs)
+    }
+}
+
+impl<F: ElementaryMath> core::ops::Div for Complex<F> {
+    type Output = Self;
+
+    /// Divides two complex numbers, avoiding intermediate overflow and
+    /// recovering infinities and zeros as described in C99 Annex G.
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        F::c_div(self, rhs)
+    }
+}
@@ -0,0 +1,218 @@
+//! Correctly rounded functions.
+//!
+//! The functions in this module return the exact result rounded to the
+//! nearest representable value (ties to even), so they return the same
+//! results as any other correctly rounded implementation.
+//!
+//! This module provides [`exp`], [`exp_m1`], [`exp2`], [`exp10`], [`ln`],
+//! [`ln_1p`], [`log2`], [`log10`], [`pow`], [`cbrt`], [`hypot`], [`sin`],
+//! [`cos`], [`tan`], [`asin`], [`acos`], [`atan`], [`atan2`], [`sinh`],
+//! [`cosh`] and [`tanh`]. There are no correctly rounded versions of the
+//! other functions of the crate (such as the inverse hyperbolic functions,
+//! the variants that take or return multiples of π or degrees, or the
+//! special functions).
+//!
+//! The result is first calculated with double-float arithmetic. In the rare
+//! cases where its error does not allow to decide the rounding, it is
+//! calculated again with multi-precision arithmetic, which is much slower.
+//!
+//! # Precision of the multi-precision fallback
+//!
+//! The fallback starts with 256 bits and doubles the precision (up to 1024
+//! bits) until the rounding can be decided. The lowest 64 bits are not
+//! trusted, so each attempt decides the rounding unless the exact result is
+//! closer than `2^-192` (then `2^-448` and `2^-960`) to the midpoint between
+//! two representable values, relative to the result. If the rounding is
+//! still not decided with the maximum precision, the result is rounded as if
+//! it were exactly halfway (to even) instead of panicking, so it might not
+//! be correctly rounded. No input is known to reach this case.
+//!
+//! The results that are exactly halfway between two representable values
+//! (such as `pow(2^27 - 1, 2)`, `exp10(23)` or `hypot(134217731,
+//! 9007199657394180)` for `f64`) are detected and calculated exactly. When
+//! `atan2(y, x)` is tiny, `y / x` can be exactly halfway between two
+//! subnormals, and the exact result, slightly closer to zero, is rounded
+//! toward zero. The other results are not dyadic rationals (most
+//! of them are transcendental numbers), so they are never exactly halfway
+//! and the loop always ends. The needed precision is bounded as follows:
+//!
+//! * `f16` and `bf16`: all the inputs of the functions with one argument
+//!   are tested exhaustively. `bf16` is evaluated in `f64`, where its
+//!   midpoints are exactly representable.
+//! * `f32` functions with one argument: their worst cases are known from
+//!   exhaustive searches and need much less than the first attempt.
+//! * `f64` `exp`, `exp2`, `exp10`, `ln`, `log2` and `log10`: the worst cases
+//!   published by Lefèvre and Muller need less than 160 bits, which is
+//!   decided by the first attempt.
+//! * Other functions and types: the worst cases are not known.
+//!   Heuristically, the closest distance to a midpoint over all inputs is
+//!   about `2^-(2 * p + 20)` for functions with one argument and
+//!   `2^-(3 * p + 20)` for `pow`, `hypot` and `atan2` (where `p` is the
+//!   precision of the type),
+//!   which is decided by the second attempt even for `f128`, so the
+//!   maximum precision leaves a large margin.
+//!
+//! Special cases are the same as the functions with the same name in the
+//! crate root.
+//!
+//! # Example
+//!
+//! ```
+//! use fpmath::cr;
+//!
+//! assert_eq!(cr::exp(1.0f64), std::f64::consts::E);
+//! assert_eq!(cr::pow(10.0f64, -2.0), 0.01);
+//! ```
+
+use crate::ElementaryMath;
+
+/// Calculates Euler's number raised to `x`, correctly rounded
+///
+/// See [`exp`](crate::exp) for the special cases.
+pub fn exp<F: ElementaryMath>(x: F) -> F {
+    F::cr_exp(x)
+}
+
+/// Calculates `e^x - 1`, correctly rounded
+///
+/// See [`exp_m1`](crate::exp_m1) for the special cases.
+pub fn exp_m1<F: ElementaryMath>(x: F) -> F {
+    F::cr_exp_m1(x)
+}
+
+/// Calculates 2 raised to `x`, correctly rounded
+///
+/// See [`exp2`](crate::exp2) for the special cases.
+pub fn exp2<F: ElementaryMath>(x: F) -> F {
+    F::cr_exp2(x)
+}
+
+/// Calculates 10 raised to `x`, correctly rounded
+///
+/// See [`exp10`](crate::exp10) for the special cases.
+pub fn exp10<F: ElementaryMath>(x: F) -> F {
+    F::cr_exp10(x)
+}
+
+/// Calculates the natural logarithm of `x`, correctly rounded
+///
+/// See [`ln`](crate::ln) for the special cases.
+pub fn ln<F: ElementaryMath>(x: F) -> F {
+    F::cr_ln(x)
+}
+
+/// Calculates the natural logarithm of `x + 1`, correctly rounded
+///
+/// See [`ln_1p`](crate::ln_1p) for the special cases.
+pub fn ln_1p<F: ElementaryMath>(x: F) -> F {
+    F::cr_ln_1p(x)
+}
+
+/// Calculates the base-2 logarithm of `x`, correctly rounded
+///
+/// See [`log2`](crate::log2) for the special cases.
+pub fn log2<F: ElementaryMath>(x: F) -> F {
+    F::cr_log2(x)
+}
+
+/// Calculates the base-10 logarithm of `x`, correctly rounded
+///
+/// See [`log10`](crate::log10) for the special cases.
+pub fn log10<F: ElementaryMath>(x: F) -> F {
+    F::cr_log10(x)
+}
+
+/// Calculates `x` raised to `y`, correctly rounded
+///
+/// See [`pow`](crate::pow) for the special cases.
+pub fn pow<F: ElementaryMath>(x: F, y: F) -> F {
+    F::cr_pow(x, y)
+}
+
+/// Calculates the cube root of `x`, correctly rounded
+///
+/// See [`cbrt`](crate::cbrt) for the special cases.
+pub fn cbrt<F: ElementaryMath>(x: F) -> F {
+    F::cr_cbrt(x)
+}
+
+/// Calculates the Pythagorean addition of `x` and `y`, correctly rounded
+///
+/// See [`hypot`](crate::hypot) for the special cases.
+pub fn hypot<F: ElementaryMath>(x: F, y: F) -> F {
+    F::cr_hypot(x, y)
+}
+
+/// Calculates the sine of `x` radians, correctly rounded
+///
+/// See [`sin`](crate::sin) for the special cases.
+pub fn sin<F: ElementaryMath>(x: F) -> F {
+    F::cr_sin(x)
+}
+
+/// Calculates the cosine of `x` radians, correctly rounded
+///
+/// See [`cos`](crate::cos) for the special cases.
+pub fn cos<F: ElementaryMath>(x: F) -> F {
+    F::cr_cos(x)
+}
+
+/// Calculates the tangent of `x` radians, correctly rounded
+///
+/// See [`tan`](crate::tan) for the special cases.
+pub fn tan<F: ElementaryMath>(x: F) -> F {
+    F::cr_tan(x)
+}
+
+/// Calculates the arcsine of `x`, returning the result in radians,
+/// correctly rounded
+///
+/// See [`asin`](crate::asin) for the special cases.
+pub fn asin<F: ElementaryMath>(x: F) -> F {
+    F::cr_asin(x)
+}
+
+/// Calculates the arccosine of `x`, returning the result in radians,
+/// correctly rounded
+///
+/// See [`acos`](crate::acos) for the special cases.
+pub fn acos<F: ElementaryMath>(x: F) -> F {
+    F::cr_acos(x)
+}
+
+/// Calculates the arctangent of `x`, returning the result in radians,
+/// correctly rounded
+///
+/// See [`atan`](crate::atan) for the special cases.
+pub fn atan<F: ElementaryMath>(x: F) -> F {
+    F::cr_atan(x)
+}
+
+/// Calculates the 2-argument arctangent of `x` and `y`, returning the
+/// result in radians, correctly rounded
+///
+/// See [`atan2`](crate::atan2) for the special cases.
+pub fn atan2<F: ElementaryMath>(y: F, x: F) -> F {
+    F::cr_atan2(y, x)
+}
+
+/// Calculates the hyperbolic sine of `x`, correctly rounded
+///
+/// See [`sinh`](crate::sinh) for the special cases.
+pub fn sinh<F: ElementaryMath>(x: F) -> F {
+    F::cr_sinh(x)
+}
+
+/// Calculates the hyperbolic cosine of `x`, correctly rounded
+///
+/// See [`cosh`](crate::cosh) for the special cases.
+pub fn cosh<F: ElementaryMath>(x: F) -> F {
+    F::cr_cosh(x)
+}
+
+/// Calculates the hyperbolic tangent of `x`, correctly rounded
+///
+/// See [`tanh`](crate::tanh) for the special cases.
+pub fn tanh<F: ElementaryMath>(x: F) -> F {
+    F::cr_tanh(x)
+}
@@ -1,20 +1,15 @@
-use crate::traits::Float;
+use crate::traits::{Float, Lanes};
 
 /// A denormalized double-float.
 ///
 /// `hi` and `lo` might overlap partially.
 #[derive(Copy, Clone, Debug)]
-pub(crate) struct DenormDouble<F: Float> {
+pub(crate) struct DenormDouble<F: Lanes> {
     hi: F,
     lo: F,
 }
 
 impl<F: Float> DenormDouble<F> {
-    #[inline]
-    pub(crate) fn new(hi: F, lo: F) -> Self {
-        Self { hi, lo }
-    }
-
     #[inline]
     pub(crate) fn one() -> Self {
         Self {
@@ -22,6 +17,13 @@ impl<F: Float> DenormDouble<F> {
             lo: F::ZERO,
         }
     }
+}
+
+impl<F: Lanes> DenormDouble<F> {
+    #[inline]
+    pub(crate) fn new(hi: F, lo: F) -> Self {
+        Self { hi, lo }
+    }
 
     #[inline]
     pub(crate) fn hi(self) -> F {
@@ -173,7 +175,7 @@ impl<F: Float> DenormDouble<F> {
         let (lhs_hi, lhs_lo) = lhs.split_hi_lo();
         let (rhs_hi, rhs_lo) = rhs.split_hi_lo();
 
-        let rhs_inv = F::one() / rhs;
+        let rhs_inv = F::splat(F::Scalar::one()) / rhs;
         let (rhs_inv_hi, rhs_inv_lo) = rhs_inv.split_hi_lo();
 
         let res_hi = (lhs * rhs_inv).purify();
@@ -181,7 +183,11 @@ impl<F: Float> DenormDouble<F> {
             + lhs_hi * rhs_inv_hi
             + lhs_hi * rhs_inv_lo
             + lhs_lo * rhs_inv
-            + res_hi * (F::one() - rhs_hi * rhs_inv_hi - rhs_hi * rhs_inv_lo - rhs_lo * rhs_inv);
+            + res_hi
+                * (F::splat(F::Scalar::one())
+                    - rhs_hi * rhs_inv_hi
+                    - rhs_hi * rhs_inv_lo
+                    - rhs_lo * rhs_inv);
 
         Self {
             hi: res_hi,
@@ -193,14 +199,18 @@ impl<F: Float> DenormDouble<F> {
     pub(crate) fn new_recip(rhs: F) -> Self {
         let (rhs_hi, rhs_lo) = rhs.split_hi_lo();
 
-        let rhs_inv = F::one() / rhs;
+        let rhs_inv = F::splat(F::Scalar::one()) / rhs;
         let (rhs_inv_hi, rhs_inv_lo) = rhs_inv.split_hi_lo();
 
         let res_hi = rhs_inv.purify();
         let res_lo = -res_hi
             + rhs_inv_hi
             + rhs_inv_lo
-            + res_hi * (F::one() - rhs_hi * rhs_inv_hi - rhs_hi * rhs_inv_lo - rhs_lo * rhs_inv);
+            + res_hi
+                * (F::splat(F::Scalar::one())
+                    - rhs_hi * rhs_inv_hi
+                    - rhs_hi * rhs_inv_lo
+                    - rhs_lo * rhs_inv);
 
         Self {
             hi: res_hi,
@@ -209,7 +219,7 @@ impl<F: Float> DenormDouble<F> {
     }
 }
 
-impl<F: Float> core::ops::Neg for DenormDouble<F> {
+impl<F: Lanes> core::ops::Neg for DenormDouble<F> {
     type Output = Self;
 
     #[inline]
@@ -221,7 +231,7 @@ impl<F: Float> core::ops::Neg for DenormDouble<F> {
     }
 }
 
-impl<F: Float> core::ops::Add<F> for DenormDouble<F> {
+impl<F: Lanes> core::ops::Add<F> for DenormDouble<F> {
     type Output = Self;
 
     #[inline]
@@ -235,7 +245,7 @@ impl<F: Float> core::ops::Add<F> for DenormDouble<F> {
     }
 }
 
-impl<F: Float> core::ops::Add for DenormDouble<F> {
+impl<F: Lanes> core::ops::Add for DenormDouble<F> {
     type Output = Self;
 
     #[inline]
@@ -249,7 +259,7 @@ impl<F: Float> core::ops::Add for DenormDouble<F> {
     }
 }
 
-impl<F: Float> core::ops::Sub<F> for DenormDouble<F> {
+impl<F: Lanes> core::ops::Sub<F> for DenormDouble<F> {
     type Output = Self;
 
     #[inline]
@@ -263,7 +273,7 @@ impl<F: Float> core::ops::Sub<F> for DenormDouble<F> {
     }
 }
 
-impl<F: Float> core::ops::Sub for DenormDouble<F> {
+impl<F: Lanes> core::ops::Sub for DenormDouble<F> {
     type Output = Self;
 
     #[inline]
@@ -277,7 +287,7 @@ impl<F: Float> core::ops::Sub for DenormDouble<F> {
     }
 }
 
-impl<F: Float> core::ops::Mul for DenormDouble<F> {
+impl<F: Lanes> core::ops::Mul for DenormDouble<F> {
     type Output = Self;
 
     #[inline]
@@ -301,7 +311,7 @@ impl<F: Float> core::ops::Mul for DenormDouble<F> {
     }
 }
 
-impl<F: Float> core::ops::Div for DenormDouble<F> {
+impl<F: Lanes> core::ops::Div for DenormDouble<F> {
     type Output = Self;
 
     #[inline]
@@ -309,7 +319,7 @@ impl<F: Float> core::ops::Div for DenormDouble<F> {
         let lhs = self;
         let (lhs_hihi, lhs_hilo) = self.hi.split_hi_lo();
         let (rhs_hihi, rhs_hilo) = rhs.hi.split_hi_lo();
-        let rhs_inv = F::one() / rhs.hi;
+        let rhs_inv = F::splat(F::Scalar::one()) / rhs.hi;
         let (rhs_inv_hi, rhs_inv_lo) = rhs_inv.split_hi_lo();
 
         let res_hi = (lhs.hi * rhs_inv).purify();
@@ -319,7 +329,7 @@ impl<F: Float> core::ops::Div for DenormDouble<F> {
             + lhs_hilo * rhs_inv_hi
             + lhs_hilo * rhs_inv_lo
             + res_hi
-                * (F::one()
+                * (F::splat(F::Scalar::one())
                     - rhs_hihi * rhs_inv_hi
                     - rhs_hihi * rhs_inv_lo
                     - rhs_hilo * rhs_inv_hi
@@ -337,7 +347,7 @@ impl<F: Float> core::ops::Div for DenormDouble<F> {
 ///
 /// `hi` and `lo` should not overlap.
 #[derive(Copy, Clone, Debug)]
-pub(crate) struct NormDouble<F: Float> {
+pub(crate) struct NormDouble<F: Lanes> {
     hi: F,
     lo: F,
 }
@@ -347,12 +357,23 @@ impl<F: Float> NormDouble<F> {
         hi: F::ZERO,
         lo: F::ZERO,
     };
+}
 
+impl<F: Lanes> NormDouble<F> {
     #[inline]
     pub(crate) fn with_parts(hi: F, lo: F) -> Self {
         Self { hi, lo }
     }
 
+    /// Returns a value with all lanes set to `value`
+    #[inline]
+    pub(crate) fn splat(value: NormDouble<F::Scalar>) -> Self {
+        Self {
+            hi: F::splat(value.hi),
+            lo: F::splat(value.lo),
+        }
+    }
+
     #[inline]
     pub(crate) fn hi(self) -> F {
         self.hi
@@ -386,7 +407,7 @@ impl<F: Float> NormDouble<F> {
     }
 }
 
-impl<F: Float> core::ops::Neg for NormDouble<F> {
+impl<F: Lanes> core::ops::Neg for NormDouble<F> {
     type Output = Self;
 
     #[inline]
@@ -402,18 +423,12 @@ impl<F: Float> core::ops::Neg for NormDouble<F> {
 ///
 /// The lower half of bits of `hi` are zero.
 #[derive(Copy, Clone, Debug)]
-pub(crate) struct SemiDouble<F: Float> {
+pub(crate) struct SemiDouble<F: Lanes> {
     hi: F,
     lo: F,
 }
 
 impl<F: Float> SemiDouble<F> {
-    #[inline]
-    pub(crate) fn new(value: F) -> Self {
-        let (hi, lo) = value.split_hi_lo();
-        Self { hi, lo }
-    }
-
     #[inline]
     pub(crate) fn one() -> Self {
         Self {
@@ -421,6 +436,14 @@ impl<F: Float> SemiDouble<F> {
             lo: F::ZERO,
         }
     }
+}
+
+impl<F: Lanes> SemiDouble<F> {
+    #[inline]
+    pub(crate) fn new(value: F) -> Self {
+        let (hi, lo) = value.split_hi_lo();
+        Self { hi, lo }
+    }
 
     #[inline]
     pub(crate) fn with_parts(hi: F, lo: F) -> Self {
@@ -522,7 +545,7 @@ impl<F: Float> SemiDouble<F> {
     #[inline]
     pub(crate) fn square(self) -> DenormDouble<F> {
         let res_hi = self.hi * self.hi;
-        let res_lo = F::two() * self.hi * self.lo + self.lo * self.lo;
+        let res_lo = F::splat(F::Scalar::two()) * self.hi * self.lo + self.lo * self.lo;
 
         DenormDouble {
             hi: res_hi,
@@ -531,7 +554,7 @@ impl<F: Float> SemiDouble<F> {
     }
 }
 
-impl<F: Float> core::ops::Neg for SemiDouble<F> {
+impl<F: Lanes> core::ops::Neg for SemiDouble<F> {
     type Output = Self;
 
     #[inline]
@@ -543,7 +566,7 @@ impl<F: Float> core::ops::Neg for SemiDouble<F> {
     }
 }
 
-impl<F: Float> core::ops::Mul for SemiDouble<F> {
+impl<F: Lanes> core::ops::Mul for SemiDouble<F> {
     type Output = DenormDouble<F>;
 
     #[inline]
@@ -560,7 +583,7 @@ impl<F: Float> core::ops::Mul for SemiDouble<F> {
     }
 }
 
-impl<F: Float> core::ops::Mul<F> for SemiDouble<F> {
+impl<F: Lanes> core::ops::Mul<F> for SemiDouble<F> {
     type Output = DenormDouble<F>;
 
     #[inline]
@@ -578,13 +601,13 @@ impl<F: Float> core::ops::Mul<F> for SemiDouble<F> {
     }
 }
 
-impl<F: Float> core::ops::Div for SemiDouble<F> {
+impl<F: Lanes> core::ops::Div for SemiDouble<F> {
     type Output = DenormDouble<F>;
 
     #[inline]
     fn div(self, rhs: Self) -> DenormDouble<F> {
         let lhs = self;
-        let rhs_inv = F::one() / (rhs.hi + rhs.lo).purify();
+        let rhs_inv = F::splat(F::Scalar::one()) / (rhs.hi + rhs.lo).purify();
         let (rhs_inv_hi, rhs_inv_lo) = rhs_inv.split_hi_lo();
 
         let res_hi = ((lhs.hi + lhs.lo) * rhs_inv).purify();
@@ -592,7 +615,11 @@ impl<F: Float> core::ops::Div for SemiDouble<F> {
             + lhs.hi * rhs_inv_hi
             + lhs.hi * rhs_inv_lo
             + lhs.lo * rhs_inv
-            + res_hi * (F::one() - rhs.hi * rhs_inv_hi - rhs.hi * rhs_inv_lo - rhs.lo * rhs_inv);
+            + res_hi
+                * (F::splat(F::Scalar::one())
+                    - rhs.hi * rhs_inv_hi
+                    - rhs.hi * rhs_inv_lo
+                    - rhs.lo * rhs_inv);
 
         DenormDouble {
             hi: res_hi,
@@ -0,0 +1,200 @@
+use crate::ElementaryMath;
+
+/// A double-float number, represented as the unevaluated sum of two
+/// floating point numbers `hi + lo`.
+///
+/// The value is kept normalized, such as `hi` is `hi + lo` rounded to the
+/// nearest value of `F`. This provides about twice the precision of `F`
+/// (106 bits for [`prim@f64`] and 48 bits for [`prim@f32`]), but the same
+/// exponent range. When `hi` is infinite or NaN, `lo` is zero.
+///
+/// Arithmetic operations, [`sqrt`](Self::sqrt), [`exp`](Self::exp),
+/// [`ln`](Self::ln) and [`sin_cos`](Self::sin_cos) have an error of a few
+/// ULP of a 106-bit (for `f64`) or 48-bit (for `f32`) significand. Precision
+/// is lost when results are close to the subnormal range, because `lo`
+/// cannot be represented.
+///
+/// Values are compared by `hi` first and then by `lo`.
+///
+/// # Example
+///
+/// ```
+/// use fpmath::DoubleFloat;
+///
+/// let x = DoubleFloat::from(1.0f64) / DoubleFloat::from(3.0);
+/// assert_eq!(x.hi(), 1.0 / 3.0);
+/// assert_eq!(x.lo(), 1.850371707708594e-17);
+/// assert_eq!(f64::from(x * DoubleFloat::from(3.0)), 1.0);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DoubleFloat<F> {
+    pub(crate) hi: F,
+    pub(crate) lo: F,
+}
+
+impl<F: ElementaryMath> DoubleFloat<F> {
+    /// Creates a double-float from the sum `hi + lo`.
+    ///
+    /// `hi` and `lo` do not need to be normalized.
+    #[inline]
+    pub fn new(hi: F, lo: F) -> Self {
+        F::dd_new(hi, lo)
+    }
+
+    /// Returns the high part, which is the value rounded to `F`.
+    #[inline]
+    pub fn hi(self) -> F {
+        self.hi
+    }
+
+    /// Returns the low part.
+    #[inline]
+    pub fn lo(self) -> F {
+        self.lo
+    }
+
+    /// Calculates the square root.
+    ///
+    /// Special cases:
+    /// * Returns negative zero if `self` is negative zero
+    /// * Returns NaN if `self` is less than zero
+    /// * Returns infinity if `self` is infinity
+    /// * Returns NaN if `self` is NaN
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        F::dd_sqrt(self)
+    }
+
+    /// Calculates `e` raised to `self`.
+    ///
+    /// Special cases:
+    /// * Returns 0 if `self` is negative infinity
+    /// * Returns infinity if `self` is infinity
+    /// * Returns NaN if `self` is NaN
+    #[inline]
+    pub fn exp(self) -> Self {
+        F::dd_exp(self)
+    }
+
+    /// Calculates the natural logarithm.
+    ///
+    /// Special cases:
+    /// * Returns negative infinity if `self` is positive or negative zero
+    /// * Returns NaN if `self` is less than zero
+    /// * Returns infinity if `self` is infinity
+    /// * Returns NaN if `self` is NaN
+    #[inline]
+    pub fn ln(self) -> Self {
+        F::dd_ln(self)
+    }
+
+    /// Calculates the sine and the cosine, with `self` in radians.
+    ///
+    /// Special cases:
+    /// * Returns `(self, 1)` if `self` is positive or negative zero
+    /// * Returns `(NaN, NaN)` if `self` is infinity or NaN
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        F::dd_sin_cos(self)
+    }
+}
+
+impl<F: ElementaryMath> From<F> for DoubleFloat<F> {
+    #[inline]
+    fn from(x: F) -> Self {
+        F::dd_from(x)
+    }
+}
+
+impl From<DoubleFloat<f32>> for f32 {
+    #[inline]
+    fn from(x: DoubleFloat<f32>) -> Self {
+        x.hi
+    }
+}
+
+impl From<DoubleFloat<f64>> for f64 {
+    #[inline]
+    fn from(x: DoubleFloat<f64>) -> Self {
+        x.hi
+    }
+}
+
+#[cfg(feature = "f16")]
+impl From<DoubleFloat<f16>> for f16 {
+    #[inline]
+    fn from(x: DoubleFloat<f16>) -> Self {
+        x.hi
+    }
+}
+
+#[cfg(feature = "f128")]
+impl From<DoubleFloat<f128>> for f128 {
+    #[inline]
+    fn from(x: DoubleFloat<f128>) -> Self {
+        x.hi
+    }
+}
+
+#[cfg(feature = "bf16")]
+impl From<DoubleFloat<half::bf16>> for half::bf16 {
+    #[inline]
+    fn from(x: DoubleFloat<half::bf16>) -> Self {
+        x.hi
+    }
+}
+
+impl<F: ElementaryMath> PartialOrd for DoubleFloat<F> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        match self.hi.partial_cmp(&other.hi) {
+            Some(core::cmp::Ordering::Equal) => self.lo.partial_cmp(&other.lo),
+            ord => ord,
+        }
+    }
+}
+
+impl<F: ElementaryMath> core::ops::Neg for DoubleFloat<F> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        F::dd_neg(self)
+    }
+}
+
+impl<F: ElementaryMath> core::ops::Add for DoubleFloat<F> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        F::dd_add(self, rhs)
+    }
+}
+
+impl<F: ElementaryMath> core::ops::Sub for DoubleFloat<F> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        F::dd_sub(self, rhs)
+    }
+}
+
+impl<F: ElementaryMath> core::ops::Mul for DoubleFloat<F> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        F::dd_mul(self, rhs)
+    }
+}
+
+impl<F: ElementaryMath> core::ops::Div for DoubleFloat<F> {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        F::dd_div(self, rhs)
+    }
+}
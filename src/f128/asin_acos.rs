@@ -0,0 +1,55 @@
+use crate::double::NormDouble;
+
+// GENERATE: asin_acos::consts f128
+const FRAC_PI_2_HI: f128 = f128::from_bits(0x3FFF921FB54442D18469898CC51701B8); // 1.5707963267948966192313216916397514e0
+const FRAC_PI_2_LO: f128 = f128::from_bits(0x3F8CCD129024E088A67CC74020BBEA64); // 4.335905065061890512398522013021676e-35
+
+impl crate::generic::AsinAcos for f128 {
+    #[inline]
+    fn frac_pi_2_ex() -> NormDouble<Self> {
+        NormDouble::with_parts(FRAC_PI_2_HI, FRAC_PI_2_LO)
+    }
+
+    #[inline]
+    fn asin_poly(x2: Self) -> Self {
+        // GENERATE: asin_acos::asin_poly f128 29
+        const K0: f128 = f128::from_bits(0x3FFC5555555555555555555555555637); // 1.666666666666666666666666666666721e-1
+        const K2: f128 = f128::from_bits(0x3FFB33333333333333333333332BE0A6); // 7.499999999999999999999999999422359e-2
+        const K4: f128 = f128::from_bits(0x3FFA6DB6DB6DB6DB6DB6DB6DCE4DA3B2); // 4.464285714285714285714285951031189e-2
+        const K6: f128 = f128::from_bits(0x3FF9F1C71C71C71C71C71C493C061B8A); // 3.0381944444444444444443920434449815e-2
+        const K8: f128 = f128::from_bits(0x3FF96E8BA2E8BA2E8BA2FEBB4506645B); // 2.2372159090909090909163715044436266e-2
+        const K10: f128 = f128::from_bits(0x3FF91C4EC4EC4EC4EC4697B30D7DD7F1); // 1.7352764423076923069997180831370584e-2
+        const K12: f128 = f128::from_bits(0x3FF8C999999999999E00E186C2377D82); // 1.3964843750000000477422058026694842e-2
+        const K14: f128 = f128::from_bits(0x3FF87A8787878786A2A3665BA90077DE); // 1.1551800896139681065926775101177794e-2
+        const K16: f128 = f128::from_bits(0x3FF83FDE50D79459FB29706B31E6733F); // 9.76160952919508054517919254489618e-3
+        const K18: f128 = f128::from_bits(0x3FF812EF3CF3CAB8BE98B985C58D2163); // 8.390335809584724261419972994061207e-3
+        const K20: f128 = f128::from_bits(0x3FF7DF3BD37B590401281C87DE287293); // 7.312525874429154422705386489363491e-3
+        const K22: f128 = f128::from_bits(0x3FF7A6863D5D4F576C8F0533E221583A); // 6.447210294309050127821671671686091e-3
+        const K24: f128 = f128::from_bits(0x3FF7782DDB6563D7098D77BA996D89D4); // 5.7400379786395205510673386354083966e-3
+        const K26: f128 = f128::from_bits(0x3FF751BA1D42C68315697F02DF5BE97F); // 5.1533051908112953948397820560250705e-3
+        const K28: f128 = f128::from_bits(0x3FF7316927E487ED81882A0C4CC7214E); // 4.660198440006722925054599307133904e-3
+        const K30: f128 = f128::from_bits(0x3FF715E51E0D9B4AC0A25B513D78E1E8); // 4.2403410312605132114950163556334756e-3
+        const K32: f128 = f128::from_bits(0x3FF6FD54B20ED39FEC3C4F6A108F6791); // 3.885885940156485493480054178839179e-3
+        const K34: f128 = f128::from_bits(0x3FF6CF15DF8B5239D2F482865B3C82E4); // 3.533061540711603276977873076520106e-3
+        const K36: f128 = f128::from_bits(0x3FF6CD84FF7E7AF03BAE8080C3691A6F); // 3.5211145288850403479402514334944013e-3
+        const K38: f128 = f128::from_bits(0x3FF5EF14D482D133CCFCFB84E295210A); // 1.8885855394767524254556972558197231e-3
+        const K40: f128 = f128::from_bits(0x3FF804C815DE08AA95EBC40DAD2F98C1); // 7.958422353174415420883101145819308e-3
+        const K42: f128 = f128::from_bits(0xBFF9050944496D541BE0A9682F0B6137); // -1.5932385145523092978382184278266563e-2
+        const K44: f128 = f128::from_bits(0x3FFADC0C6D654EEE2E57EF23705927A5); // 5.8111394561334759448686205750733096e-2
+        const K46: f128 = f128::from_bits(0xBFFC0F5261DB1449D18AE879FD88A109); // -1.324813504202539154012450467715994e-1
+        const K48: f128 = f128::from_bits(0x3FFD0B44827F50CE704AB0C40A67B8BA); // 2.6100353147576388458589353935851654e-1
+        const K50: f128 = f128::from_bits(0xBFFD832BBC67DDA758E94D0FA4B9A498); // -3.780965269465136089887123307265135e-1
+        const K52: f128 = f128::from_bits(0x3FFD9FD6112BC8529F4C92AA6796A23C); // 4.060900385830615830881384218906591e-1
+        const K54: f128 = f128::from_bits(0xBFFD1D04BBDD68582CF7B87A4F20755A); // -2.7833837069382398934795175760478836e-1
+        const K56: f128 = f128::from_bits(0x3FFB99A921BFD68CFDA220B67B80E1EF); // 1.0001481231066411198396518861490648e-1
+
+        K0 + horner!(
+            x2,
+            x2,
+            [
+                K2, K4, K6, K8, K10, K12, K14, K16, K18, K20, K22, K24, K26, K28, K30, K32, K34,
+                K36, K38, K40, K42, K44, K46, K48, K50, K52, K54, K56
+            ]
+        )
+    }
+}
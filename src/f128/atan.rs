@@ -0,0 +1,94 @@
+use crate::double::DenormDouble;
+
+// GENERATE: atan::consts f128
+const FRAC_PI_2_HI: f128 = f128::from_bits(0x3FFF921FB54442D18469898CC51701B8); // 1.5707963267948966192313216916397514e0
+const FRAC_PI_2_LO: f128 = f128::from_bits(0x3F8CCD129024E088A67CC74020BBEA64); // 4.335905065061890512398522013021676e-35
+const FRAC_3PI_4: f128 = f128::from_bits(0x40002D97C7F3321D234F272993D1414A); // 2.356194490192344928846982537459627e0
+
+impl crate::generic::Atan for f128 {
+    #[inline]
+    fn frac_pi_2_hi() -> Self {
+        FRAC_PI_2_HI
+    }
+
+    #[inline]
+    fn frac_pi_2_lo() -> Self {
+        FRAC_PI_2_LO
+    }
+
+    #[inline]
+    fn frac_3pi_4() -> Self {
+        FRAC_3PI_4
+    }
+
+    #[inline]
+    fn atan_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V) -> (V, V) {
+        // GENERATE: atan::atan_poly f128 44
+        const K3: f128 = f128::from_bits(0xBFFD55555555555555555555555553F9); // -3.3333333333333333333333333333331656e-1
+        const K5: f128 = f128::from_bits(0x3FFC9999999999999999999999931735); // 1.9999999999999999999999999998973006e-1
+        const K7: f128 = f128::from_bits(0xBFFC249249249249249249248C49343B); // -1.4285714285714285714285714043385866e-1
+        const K9: f128 = f128::from_bits(0x3FFBC71C71C71C71C71C71C11D44FF14); // 1.1111111111111111111111080108500352e-1
+        const K11: f128 = f128::from_bits(0xBFFB745D1745D1745D1743ED4612A289); // -9.090909090909090909088405934719973e-2
+        const K13: f128 = f128::from_bits(0x3FFB3B13B13B13B13B1347F6B7D1F3DF); // 7.692307692307692307552987942294544e-2
+        const K15: f128 = f128::from_bits(0xBFFB1111111111111100580F8B3F3ADA); // -6.666666666666666661000802470577807e-2
+        const K17: f128 = f128::from_bits(0x3FFAE1E1E1E1E1E1DDD6015883BA8ACE); // 5.8823529411764704127508881828285004e-2
+        const K19: f128 = f128::from_bits(0xBFFAAF286BCA1AF224499A9FB0810339); // -5.2631578947368378357042117683357965e-2
+        const K21: f128 = f128::from_bits(0x3FFA861861861859FEC380A4DB3B5CBC); // 4.761904761904678320079483070765236e-2
+        const K23: f128 = f128::from_bits(0xBFFA642C8590B19D611A70CE29B90951); // -4.347826086955179684220309931353971e-2
+        const K25: f128 = f128::from_bits(0x3FFA47AE147ADAF6E81EC60B972CBDEC); // 3.9999999999820502107846574865208944e-2
+        const K27: f128 = f128::from_bits(0xBFFA2F684BD9CBB10BA0C39656B6419B); // -3.703703703501138260432303352851479e-2
+        const K29: f128 = f128::from_bits(0x3FFA1A7B960EF9C3342B158F8F5624AE); // 3.448275860119345323866613699221989e-2
+        const K31: f128 = f128::from_bits(0xBFFA0842106DEEFD003D975383472A27); // -3.225806435463562948260450619227156e-2
+        const K33: f128 = f128::from_bits(0x3FF9F07C1DC8D6AD170D5A711B060263); // 3.030302914280975035407644847988553e-2
+        const K35: f128 = f128::from_bits(0xBFF9D41D3A03A3D7483BE4DD0CB21BDD); // -2.8571421293745529310865165540669492e-2
+        const K37: f128 = f128::from_bits(0x3FF9BACF66410B2A25BD46101F59D6D4); // 2.7026986939903915730905992850817944e-2
+        const K39: f128 = f128::from_bits(0xBFF9A419706756C96ED0697CBF7A0F01); // -2.5640830773551028402424715060734318e-2
+        const K41: f128 = f128::from_bits(0x3FF98F9893702D3EC6EFE8A6793F3245); // 2.4389404280288505660790755662815347e-2
+        const K43: f128 = f128::from_bits(0xBFF97CF873EF01E1B11BC66BC6359299); // -2.3252595154216942442974242894036948e-2
+        const K45: f128 = f128::from_bits(0x3FF96BE88CDED8552B954C0D46C09594); // 2.2211206024967576358288979502399083e-2
+        const K47: f128 = f128::from_bits(0xBFF95C0AE9303D352699CBD340047332); // -2.1242835734443696370745894016550986e-2
+        const K49: f128 = f128::from_bits(0x3FF94CD869A7B19E1A38A5360087E6B8); // 2.031526868702283064187727608249173e-2
+        const K51: f128 = f128::from_bits(0xBFF93D7C312C04FC6460C1971D9A3A42); // -1.937775423000667141545286968851796e-2
+        const K53: f128 = f128::from_bits(0x3FF92CB4DD62F44F9DC19918ECC40470); // 1.835366840152106250512851220922514e-2
+        const K55: f128 = f128::from_bits(0xBFF918DB2C250591C52FA864EFCC5F2D); // -1.7142098531700887279385923512822163e-2
+        const K57: f128 = f128::from_bits(0x3FF90033629647806596BF937C57192A); // 1.5637251163859411208861115756190988e-2
+        const K59: f128 = f128::from_bits(0xBFF8C32924DD6F6D6DD70C52E9660B07); // -1.3768332481841322996813487227262808e-2
+        const K61: f128 = f128::from_bits(0x3FF87A65C0C2A886B0E3AC02EA4AA63B); // 1.1547774430543186501674163125716955e-2
+        const K63: f128 = f128::from_bits(0xBFF82A36FEA62A13563B888E0AFEEF28); // -9.100794163103923996191664767235952e-3
+        const K65: f128 = f128::from_bits(0x3FF7B3C3EB1A8377CFD0B60B565223C0); // 6.6492508872338902916947046827589914e-3
+        const K67: f128 = f128::from_bits(0xBFF723574A72CCFDB9BBC203F1D98EC0); // -4.445510555161260112261408991948805e-3
+        const K69: f128 = f128::from_bits(0x3FF65FFD258DC35AFF4C4670477A77B7); // 2.6854618398661419896412713420984417e-3
+        const K71: f128 = f128::from_bits(0xBFF57B6285CA9FB1B90C324DAE9B2F64); // -1.4472383651590929697165539410665713e-3
+        const K73: f128 = f128::from_bits(0x3FF467F4CBD285EF1D3FC4CCF65B6225); // 6.865620328566342145641051127483461e-4
+        const K75: f128 = f128::from_bits(0xBFF328349CA4401A07BB49700B4D069F); // -2.8248359218709977024867953133484044e-4
+        const K77: f128 = f128::from_bits(0x3FF19F7CD1063004CE3F54ADAD4F7795); // 9.905995475190214461432587512835975e-5
+        const K79: f128 = f128::from_bits(0xBFEFE604D8F26521EA00C748F69E0BDF); // -2.896898599543024750498143974989577e-5
+        const K81: f128 = f128::from_bits(0x3FEDCC97E9F00BDC7FACEFEF09B74A2C); // 6.8633766972382066861235522472065904e-6
+        const K83: f128 = f128::from_bits(0xBFEB535678B46E3F15CAC896895D304A); // -1.2641317371621441318080728677397431e-6
+        const K85: f128 = f128::from_bits(0x3FE86C578F9F9FAC3DFFD9C7A7512001); // 1.6965998116468600113082479654724863e-7
+        const K87: f128 = f128::from_bits(0xBFE4FABD1C2E4DA7FD3465E3915D65E4); // -1.4748037538258014084079823902237185e-8
+        const K89: f128 = f128::from_bits(0x3FE05666252D0268775984EC69734EBE); // 6.228201614393856291719692989472396e-10
+
+        // When `x2` is close to 1, the terms decrease slowly and the rounding
+        // errors of all steps accumulate, so the first ones are evaluated
+        // with double precision and the result is returned as `(hi, lo)`
+        let t = horner!(
+            x2,
+            x2,
+            [
+                K47, K49, K51, K53, K55, K57, K59, K61, K63, K65, K67, K69, K71, K73, K75, K77,
+                K79, K81, K83, K85, K87, K89
+            ]
+        );
+        let x2 = DenormDouble::new(x2, V::splat(0.0));
+        let mut t = DenormDouble::new_qadd11(V::splat(K45), t);
+        for k in [
+            K43, K41, K39, K37, K35, K33, K31, K29, K27, K25, K23, K21, K19, K17, K15, K13, K11,
+            K9, K7, K5,
+        ] {
+            t = x2 * t + V::splat(k);
+        }
+        let t = x2 * t + V::splat(K3);
+        (t.hi(), t.lo())
+    }
+}
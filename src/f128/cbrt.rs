@@ -0,0 +1,43 @@
+use crate::double::SemiDouble;
+
+// GENERATE: cbrt::consts f128
+const CBRT_2_HI: f128 = f128::from_bits(0x3FFF428A2F98D728AE00000000000000); // 1.259921049894873162910968744654383e0
+const CBRT_2_LO: f128 = f128::from_bits(0x3FC411EED5B8ADF128686144788148B2); // 1.8562418626238453058803577421673284e-18
+const CBRT_4_HI: f128 = f128::from_bits(0x3FFF965FEA53D6E3C800000000000000); // 1.5874010519681994724194851187348831e0
+const CBRT_4_LO: f128 = f128::from_bits(0x3FC4582CCCD5A1EE26F78CC03B10AC50); // 2.3322205205374251158936219289740717e-18
+
+impl crate::generic::Cbrt for f128 {
+    #[inline]
+    fn cbrt_2_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(CBRT_2_HI, CBRT_2_LO)
+    }
+
+    #[inline]
+    fn cbrt_4_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(CBRT_4_HI, CBRT_4_LO)
+    }
+
+    #[inline]
+    fn exp_mod_3(e: i16) -> i8 {
+        ((i32::from(e) + 16497) % 3) as i8
+    }
+
+    #[inline]
+    fn inv_cbrt_poly(x: Self) -> Self {
+        // GENERATE: cbrt::inv_cbrt_poly f128 12
+        const K0: f128 = f128::from_bits(0x400021FECCBF0FD14793AA16C3A4DC91); // 2.26558837250889609717980080468642e0
+        const K1: f128 = f128::from_bits(0xC00119E6038B8D0D4F175E306EB65C58); // -4.404663931160219319598301433552119e0
+        const K2: f128 = f128::from_bits(0x40021B21D3D713BD4A21F01E44A654DA); // 8.847879333565211072151220183868392e0
+        const K3: f128 = f128::from_bits(0xC0029FB8C2CBD4474138C99EAB22BF54); // -12.991303823570184896788068271820075e0
+        const K4: f128 = f128::from_bits(0x4002BCB9AE4B27492B3984BCC650AFD3); // 13.897666117462255466717031771408602e0
+        const K5: f128 = f128::from_bits(0xC0025DD6659C35DC929755798C21BF22); // -10.9324214983925166001674780091105e0
+        const K6: f128 = f128::from_bits(0x4001953FE24ACBFB51DB7866B9813AC9); // 6.3320241671025786410368323160729925e0
+        const K7: f128 = f128::from_bits(0xC00056092F4883FA9D7F810D84B65E19); // -2.6721552948074149130292792450311856e0
+        const K8: f128 = f128::from_bits(0x3FFE999E74FDEC51F5798ABD525ABC57); // 8.000370559658768579073626580006327e-1
+        const K9: f128 = f128::from_bits(0xBFFC4A11291BF14744B42BEFE513A7A1); // -1.6116554371379588611565875570765345e-1
+        const K10: f128 = f128::from_bits(0x3FF9412F358D80078D6C3F7D516E92D0); // 1.9603540704338648505588221197149697e-2
+        const K11: f128 = f128::from_bits(0xBFF51D57B62E8BF32DDDEC5831DF02DE); // -1.0884957261047655651104216123272914e-3
+
+        K0 + horner!(x, x, [K1, K2, K3, K4, K5, K6, K7, K8, K9, K10, K11])
+    }
+}
@@ -0,0 +1,12 @@
+use crate::double::SemiDouble;
+
+// GENERATE: div_pi::consts f128
+const FRAC_1_PI_HI: f128 = f128::from_bits(0x3FFD45F306DC9C882A00000000000000); // 3.1830988618379067039976249020583055e-1
+const FRAC_1_PI_LO: f128 = f128::from_bits(0x3FC34FE13ABE8FA9A6EE06DB14ACC9E2); // 1.1380050365391981789042745733107469e-18
+
+impl crate::generic::DivPi for f128 {
+    #[inline]
+    fn frac_1_pi_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(FRAC_1_PI_HI, FRAC_1_PI_LO)
+    }
+}
@@ -0,0 +1,33 @@
+// GENERATE: double_float::consts f128
+const DD_LN_2_HI: f128 = f128::from_bits(0x3FFE62E42FEFA39EF35793C767300000); // 6.931471805599453094172321214579819e-1
+const DD_LN_2_MI: f128 = f128::from_bits(0x3F98F97B57A079A193394C5B16C50000); // 1.9470450923807499515879595733329556e-31
+const DD_LN_2_LO: f128 = f128::from_bits(0x3F32A2EB71755F457CF70EC40DBD7593); // 3.182304313023816663926046977943482e-62
+const DD_LN_10_HI: f128 = f128::from_bits(0x400026BB1BBB5551582DD4ADAC5705A6); // 2.302585092994045684017991454684364e0
+const DD_LN_10_LO: f128 = f128::from_bits(0x3F8C451C51FD9F3B4BBF21D078C3D040); // 3.0573256347849463435911674091760823e-35
+
+impl crate::generic::DoubleFloatMath for f128 {
+    #[inline]
+    fn dd_ln_2_hi() -> Self {
+        DD_LN_2_HI
+    }
+
+    #[inline]
+    fn dd_ln_2_mi() -> Self {
+        DD_LN_2_MI
+    }
+
+    #[inline]
+    fn dd_ln_2_lo() -> Self {
+        DD_LN_2_LO
+    }
+
+    #[inline]
+    fn dd_ln_10_hi() -> Self {
+        DD_LN_10_HI
+    }
+
+    #[inline]
+    fn dd_ln_10_lo() -> Self {
+        DD_LN_10_LO
+    }
+}
@@ -0,0 +1,77 @@
+// GENERATE: exp::consts f128
+const LOG2_E: f128 = f128::from_bits(0x3FFF71547652B82FE1777D0FFDA0D23A); // 1.442695040888963407359924681001892e0
+const LN_2_HI: f128 = f128::from_bits(0x3FFE62E42FEFA39EF200000000000000); // 6.931471805599453001045517908096372e-1
+const LN_2_LO: f128 = f128::from_bits(0x3FC65793C7673007E5ED5E81E6864CE5); // 9.312680330648539399653158787619532e-18
+
+impl crate::generic::Exp for f128 {
+    #[inline]
+    fn log2_e() -> Self {
+        LOG2_E
+    }
+
+    #[inline]
+    fn ln_2_hi() -> Self {
+        LN_2_HI
+    }
+
+    #[inline]
+    fn ln_2_lo() -> Self {
+        LN_2_LO
+    }
+
+    #[inline]
+    fn exp_lo_th() -> Self {
+        -11434.0
+    }
+
+    #[inline]
+    fn exp_hi_th() -> Self {
+        11357.0
+    }
+
+    #[inline]
+    fn exp_m1_lo_th() -> Self {
+        -11356.0
+    }
+
+    #[inline]
+    fn exp_m1_hi_th() -> Self {
+        11357.0
+    }
+
+    #[inline]
+    fn exp_special_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V) -> V {
+        // GENERATE: exp::exp_special_poly f128 11
+        const K2: f128 = f128::from_bits(0xBFFC5555555555555555555555555542); // -1.666666666666666666666666666666662e-1
+        const K4: f128 = f128::from_bits(0x3FF66C16C16C16C16C16C16C16B82A68); // 2.7777777777777777777777777775495856e-3
+        const K6: f128 = f128::from_bits(0xBFF11566ABC011566ABC0114A49850DD); // -6.613756613756613756613752786284052e-5
+        const K8: f128 = f128::from_bits(0x3FEBBBD779334EF0AAC65847AA8F6119); // 1.6534391534391534391502375019414169e-6
+        const K10: f128 = f128::from_bits(0xBFE666A8F2BF70EBD9C90FE0F2B31097); // -4.175351397573619780281137755181707e-8
+        const K12: f128 = f128::from_bits(0x3FE122805D6442667F7B9075CBB945A2); // 1.0568380277374938810289133183133866e-9
+        const K14: f128 = f128::from_bits(0xBFDBD6DB2C4E01E3FFB908C26747C17F); // -2.676507306127412203587020714569003e-11
+        const K16: f128 = f128::from_bits(0x3FD67DA4E1EB9E60B24407597B5D9CCE); // 6.779360579960527588252029115304891e-13
+        const K18: f128 = f128::from_bits(0xBFD1355864AFAEF806E623FC98EBEAF4); // -1.7172112973276987095988337850521316e-14
+        const K20: f128 = f128::from_bits(0x3FCBF56B4B2F7BC4B1CE4401246CD938); // 4.3491175590518794753025211158960273e-16
+        const K22: f128 = f128::from_bits(0xBFC68F2DAA7D63596129621DA92B1B09); // -1.0819751743798652125217559010436323e-17
+
+        horner!(x2, x2, [K2, K4, K6, K8, K10, K12, K14, K16, K18, K20, K22])
+    }
+
+    #[inline]
+    fn exp_m1_special_poly(x2: Self) -> Self {
+        // GENERATE: exp::exp_m1_special_poly f128 11
+        const K2: f128 = f128::from_bits(0xBFF911111111111111111111111110F9); // -1.6666666666666666666666666666666594e-2
+        const K4: f128 = f128::from_bits(0x3FF3A01A01A01A01A01A01A019F65EEA); // 3.968253968253968253968253967907158e-4
+        const K6: f128 = f128::from_bits(0xBFEE4CE19AE67B348014CE18C26FE3F7); // -9.92063492063492063492062910357443e-6
+        const K8: f128 = f128::from_bits(0x3FE90CFEB60F94B0E39EFF2B9DD0A18F); // 2.505210838544171877500344911974351e-7
+        const K10: f128 = f128::from_bits(0xBFE3B3C08C16639BE6B9E70BB43EF53F); // -6.341028166424991798167517543017091e-9
+        const K12: f128 = f128::from_bits(0x3FDE6124613A86CED8636900A7386646); // 1.605904383682154231776566198728814e-10
+        const K14: f128 = f128::from_bits(0xBFD91E3BA979AEA072E1EBD13EDBEFA4); // -4.067616355572625360749838335396492e-12
+        const K16: f128 = f128::from_bits(0x3FD3D004AAB2EB65BF2179DF8F1DB6DA); // 1.0303274448255076085078221009000178e-13
+        const K18: f128 = f128::from_bits(0xBFCE781E20EC281E07C3D78789DD58D9); // -2.6098407453023963001748333898473014e-15
+        const K20: f128 = f128::from_bits(0x3FC930D37822843577FD536CD806C165); // 6.609861353179789460216791517949786e-17
+        const K22: f128 = f128::from_bits(0xBFC3E557B6619AB5A884C89CA96CBCCE); // -1.6444047819765226186397622047549974e-18
+
+        1.0 + horner!(x2, x2, [K2, K4, K6, K8, K10, K12, K14, K16, K18, K20, K22])
+    }
+}
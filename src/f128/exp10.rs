@@ -0,0 +1,49 @@
+// GENERATE: exp10::consts f128
+const LOG2_10: f128 = f128::from_bits(0x4000A934F0979A3715FC9257EDFE9B60); // 3.3219280948873623478703194294893903e0
+const LOG10_2_HI: f128 = f128::from_bits(0x3FFD34413509F79FEE00000000000000); // 3.01029995663981191078573118602435e-1
+const LOG10_2_LO: f128 = f128::from_bits(0x3FC5311F12B35816F922F04D5A618A88); // 4.1351657761220580386345805865707514e-18
+const LN_10: f128 = f128::from_bits(0x400026BB1BBB5551582DD4ADAC5705A6); // 2.302585092994045684017991454684364e0
+const LN_10_HI: f128 = f128::from_bits(0x400026BB1BBB55515800000000000000); // 2.302585092994045679049008867878001e0
+const LN_10_LO: f128 = f128::from_bits(0x3FC56EA56D62B82D30A28E28FECF9DA6); // 4.9689825868063630243296934075740856e-18
+
+impl crate::generic::Exp10 for f128 {
+    #[inline]
+    fn log2_10() -> Self {
+        LOG2_10
+    }
+
+    #[inline]
+    fn log10_2_hi() -> Self {
+        LOG10_2_HI
+    }
+
+    #[inline]
+    fn log10_2_lo() -> Self {
+        LOG10_2_LO
+    }
+
+    #[inline]
+    fn ln_10() -> Self {
+        LN_10
+    }
+
+    #[inline]
+    fn ln_10_hi() -> Self {
+        LN_10_HI
+    }
+
+    #[inline]
+    fn ln_10_lo() -> Self {
+        LN_10_LO
+    }
+
+    #[inline]
+    fn exp10_lo_th() -> Self {
+        -4966.0
+    }
+
+    #[inline]
+    fn exp10_hi_th() -> Self {
+        4933.0
+    }
+}
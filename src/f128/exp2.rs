@@ -0,0 +1,19 @@
+// GENERATE: exp2::consts f128
+const LN_2: f128 = f128::from_bits(0x3FFE62E42FEFA39EF35793C7673007E6); // 6.931471805599453094172321214581766e-1
+
+impl crate::generic::Exp2 for f128 {
+    #[inline]
+    fn ln_2() -> Self {
+        LN_2
+    }
+
+    #[inline]
+    fn exp2_lo_th() -> Self {
+        -16496.0
+    }
+
+    #[inline]
+    fn exp2_hi_th() -> Self {
+        16385.0
+    }
+}
@@ -0,0 +1,95 @@
+use crate::double::NormDouble;
+
+// GENERATE: ln::consts f128
+const SQRT_2: f128 = f128::from_bits(0x3FFF6A09E667F3BCC908B2FB1366EA95); // 1.414213562373095048801688724209698e0
+const LN_2_HI: f128 = f128::from_bits(0x3FFE62E42FEFA39EF200000000000000); // 6.931471805599453001045517908096372e-1
+const LN_2_LO: f128 = f128::from_bits(0x3FC65793C7673007E5ED5E81E6864CE5); // 9.312680330648539399653158787619532e-18
+const FRAC_2_3_HI: f128 = f128::from_bits(0x3FFE5555555555555555555555555555); // 6.666666666666666666666666666666666e-1
+const FRAC_2_3_LO: f128 = f128::from_bits(0x3F8C5555555555555555555555555555); // 3.209883240645393088426629904308212e-35
+const FRAC_4_10_HI: f128 = f128::from_bits(0x3FFD9999999999999999999999999999); // 3.9999999999999999999999999999999997e-1
+const FRAC_4_10_LO: f128 = f128::from_bits(0x3F8C3333333333333333333333333333); // 2.888894916580853779583966913877391e-35
+
+impl crate::generic::Ln for f128 {
+    #[inline]
+    fn sqrt_2() -> Self {
+        SQRT_2
+    }
+
+    #[inline]
+    fn ln_2_hi() -> Self {
+        LN_2_HI
+    }
+
+    #[inline]
+    fn ln_2_lo() -> Self {
+        LN_2_LO
+    }
+
+    #[inline]
+    fn frac_2_3_ex() -> NormDouble<Self> {
+        NormDouble::with_parts(FRAC_2_3_HI, FRAC_2_3_LO)
+    }
+
+    #[inline]
+    fn frac_4_10_ex() -> NormDouble<Self> {
+        NormDouble::with_parts(FRAC_4_10_HI, FRAC_4_10_LO)
+    }
+
+    #[inline]
+    fn ln_special_poly<V: crate::traits::Lanes<Scalar = Self>>(x: V) -> V {
+        // GENERATE: ln::ln_special_poly f128 16
+        const K2: f128 = f128::from_bits(0x3FFE555555555555555555555555554B); // 6.666666666666666666666666666666657e-1
+        const K4: f128 = f128::from_bits(0x3FFD99999999999999999999999AE830); // 4.000000000000000000000000000041241e-1
+        const K6: f128 = f128::from_bits(0x3FFD249249249249249249248B300AA3); // 2.85714285714285714285714279980525e-1
+        const K8: f128 = f128::from_bits(0x3FFCC71C71C71C71C71C71EE47948276); // 2.2222222222222222222222627215006728e-1
+        const K10: f128 = f128::from_bits(0x3FFC745D1745D1745D170542B24CCC44); // 1.818181818181818181801093550861186e-1
+        const K12: f128 = f128::from_bits(0x3FFC3B13B13B13B13B5945DF9B5EF72E); // 1.5384615384615384662534288011430702e-1
+        const K14: f128 = f128::from_bits(0x3FFC111111111110DD33E5A9CF6B9E30); // 1.333333333333332433637329307829859e-1
+        const K16: f128 = f128::from_bits(0x3FFBE1E1E1E1E2194FE1D60051F1D8A1); // 1.1764705882354171962761069271612752e-1
+        const K18: f128 = f128::from_bits(0x3FFBAF286BCA0539395AAE0C65719E83); // 1.0526315789350198493854795734809019e-1
+        const K20: f128 = f128::from_bits(0x3FFB8618618C6B65B882F6E70D7174A3); // 9.523809533012566513851185164728198e-2
+        const K22: f128 = f128::from_bits(0x3FFB642C843129DDBD61168928E6D8B0); // 8.695651662366493445392907096672864e-2
+        const K24: f128 = f128::from_bits(0x3FFB47AE4D2212EB6924A6D69D50982E); // 8.000021104924824005067102075493634e-2
+        const K26: f128 = f128::from_bits(0x3FFB2F619FD8C136C73269A9974FFF58); // 7.406771125904549404616870651965088e-2
+        const K28: f128 = f128::from_bits(0x3FFB1B0A0A64757975730C2FD41BB5A0); // 6.910137233293621923085606392786518e-2
+        const K30: f128 = f128::from_bits(0x3FFB0053B7E8872ED1A80F5DC0CFE7FF); // 6.257984008011492770743144049185974e-2
+        const K32: f128 = f128::from_bits(0x3FFB3B013C649491B1DC67B202C7B09D); // 7.690547553037101353043166053088743e-2
+
+        let x2 = x * x;
+        horner!(
+            x2,
+            x2,
+            [
+                K2, K4, K6, K8, K10, K12, K14, K16, K18, K20, K22, K24, K26, K28, K30, K32
+            ]
+        )
+    }
+
+    #[inline]
+    fn ln_special_poly_ex<V: crate::traits::Lanes<Scalar = Self>>(x2: V) -> V {
+        // GENERATE: ln::ln_special_poly_ex f128 15
+        const K6: f128 = f128::from_bits(0x3FFD24924924924924924924924B257F); // 2.85714285714285714285714285720608e-1
+        const K8: f128 = f128::from_bits(0x3FFCC71C71C71C71C71C71C7024A9A1D); // 2.2222222222222222222222221165909738e-1
+        const K10: f128 = f128::from_bits(0x3FFC745D1745D1745D17461977E4982C); // 1.81818181818181818181825627869172e-1
+        const K12: f128 = f128::from_bits(0x3FFC3B13B13B13B13B1340539FC9888A); // 1.5384615384615384615085760687689216e-1
+        const K14: f128 = f128::from_bits(0x3FFC11111111111111833C28578B79A8); // 1.3333333333333333410696798836229079e-1
+        const K16: f128 = f128::from_bits(0x3FFBE1E1E1E1E1E14292F5BEF5420344); // 1.1764705882352927358679002311569823e-1
+        const K18: f128 = f128::from_bits(0x3FFBAF286BCA1B425E09D4FCF74E90AE); // 1.0526315789475457037339105259596704e-1
+        const K20: f128 = f128::from_bits(0x3FFB86186185FAEDE8409EA171B718D6); // 9.523809523642110691188769578541974e-2
+        const K22: f128 = f128::from_bits(0x3FFB642C8598CC5FBCF309C775E90E2C); // 8.695652185704000071885669254699296e-2
+        const K24: f128 = f128::from_bits(0x3FFB47AE12CF88AB79BB86C132811188); // 7.999999378129529226503792379230484e-2
+        const K26: f128 = f128::from_bits(0x3FFB2F688D733997ACC1184296F7FB8D); // 7.407431844658233784292995608762468e-2
+        const K28: f128 = f128::from_bits(0x3FFB1A7433C379B54916B4B86AAFBCCE); // 6.895847530505012323914007705911413e-2
+        const K30: f128 = f128::from_bits(0x3FFB08D93B1DBCF521435D46B77829B1); // 6.466029255153429965078361355803643e-2
+        const K32: f128 = f128::from_bits(0x3FFAE04D61032D64FB8469237E8A0470); // 5.8630647160879177103207305700240103e-2
+        const K34: f128 = f128::from_bits(0x3FFB2BAB72ED2035C3CB1DF001BADAC4); // 7.316155331683433012247976708093382e-2
+
+        horner!(
+            x2,
+            x2,
+            [
+                K6, K8, K10, K12, K14, K16, K18, K20, K22, K24, K26, K28, K30, K32, K34
+            ]
+        )
+    }
+}
@@ -0,0 +1,24 @@
+use crate::double::SemiDouble;
+
+// GENERATE: log10::consts f128
+const LOG10_E_HI: f128 = f128::from_bits(0x3FFDBCB7B1526E50E200000000000000); // 4.3429448190325182360682632065618236e-1
+const LOG10_E_LO: f128 = f128::from_bits(0x3FC52A6AB7555F5A67B8647DC68C048C); // 4.044302598260422721459023604161821e-18
+const LOG10_2_HI: f128 = f128::from_bits(0x3FFD34413509F79FEE00000000000000); // 3.01029995663981191078573118602435e-1
+const LOG10_2_LO: f128 = f128::from_bits(0x3FC5311F12B35816F922F04D5A618A88); // 4.1351657761220580386345805865707514e-18
+
+impl crate::generic::Log10 for f128 {
+    #[inline]
+    fn log10_e_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(LOG10_E_HI, LOG10_E_LO)
+    }
+
+    #[inline]
+    fn log10_2_hi() -> Self {
+        LOG10_2_HI
+    }
+
+    #[inline]
+    fn log10_2_lo() -> Self {
+        LOG10_2_LO
+    }
+}
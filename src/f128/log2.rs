@@ -0,0 +1,12 @@
+use crate::double::SemiDouble;
+
+// GENERATE: log2::consts f128
+const LOG2_E_HI: f128 = f128::from_bits(0x3FFF71547652B82FE000000000000000); // 1.44269504088896338700465094007086e0
+const LOG2_E_LO: f128 = f128::from_bits(0x3FC7777D0FFDA0D23A7D11D6AEF551BB); // 2.0355273740931032049555094440481111e-17
+
+impl crate::generic::Log2 for f128 {
+    #[inline]
+    fn log2_e_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(LOG2_E_HI, LOG2_E_LO)
+    }
+}
@@ -0,0 +1,702 @@
+mod asin_acos;
+mod atan;
+mod cbrt;
+mod div_pi;
+mod double_float;
+mod exp;
+mod exp10;
+mod exp2;
+mod log;
+mod log10;
+mod log2;
+mod rad_to_deg;
+mod reduce_90_deg;
+mod reduce_half_mul_pi;
+mod reduce_pi_2;
+mod sin_cos;
+mod sinh_cosh;
+mod tan;
+
+use crate::complex::Complex;
+use crate::slice::LANES;
+
+impl crate::traits::Lanes for f128 {
+    type Scalar = Self;
+
+    #[inline]
+    fn splat(value: Self) -> Self {
+        value
+    }
+
+    #[inline]
+    fn purify(self) -> Self {
+        // `f128` arithmetic is implemented in software, so it does not have
+        // the X87 rounding issues
+        self
+    }
+
+    #[inline]
+    fn split_hi(self) -> Self {
+        crate::traits::float_split_hi(self)
+    }
+}
+
+impl crate::traits::Float for f128 {
+    type Raw = u128;
+
+    type RawExp = u16;
+
+    type Exp = i16;
+
+    const BITS: u8 = 128;
+    const MANT_BITS: u8 = 112;
+    const EXP_BITS: u8 = 15;
+
+    const SIGN_MASK: Self::Raw = 1 << (<Self as crate::traits::Float>::BITS - 1);
+    const EXP_MASK: Self::Raw = ((1 << Self::EXP_BITS) - 1) << Self::MANT_BITS;
+    const MANT_MASK: Self::Raw = (1 << Self::MANT_BITS) - 1;
+
+    const EXP_OFFSET: Self::RawExp = (1 << (Self::EXP_BITS - 1)) - 1;
+    const MAX_RAW_EXP: Self::RawExp = (Self::EXP_MASK >> Self::MANT_BITS) as Self::RawExp;
+
+    const MIN_NORMAL_EXP: Self::Exp = -<Self as crate::traits::Float>::MAX_EXP + 1;
+    const MAX_EXP: Self::Exp = (Self::MAX_RAW_EXP >> 1) as Self::Exp;
+
+    const INFINITY: Self = Self::INFINITY;
+
+    #[inline]
+    fn neg_infinity() -> Self {
+        Self::NEG_INFINITY
+    }
+
+    const NAN: Self = Self::NAN;
+
+    const ZERO: Self = 0.0;
+
+    #[inline]
+    fn half() -> Self {
+        0.5
+    }
+
+    #[inline]
+    fn one() -> Self {
+        1.0
+    }
+
+    #[inline]
+    fn two() -> Self {
+        2.0
+    }
+
+    #[cfg(test)]
+    #[inline]
+    fn largest() -> Self {
+        Self::MAX
+    }
+
+    #[inline]
+    fn to_raw(self) -> Self::Raw {
+        self.to_bits()
+    }
+
+    #[inline]
+    fn from_raw(raw: Self::Raw) -> Self {
+        Self::from_bits(raw)
+    }
+
+    #[inline]
+    fn raw_exp_to_exp(e: Self::RawExp) -> Self::Exp {
+        e.wrapping_sub(Self::EXP_OFFSET) as i16
+    }
+
+    #[inline]
+    fn exp_to_raw_exp(e: Self::Exp) -> Self::RawExp {
+        (e as Self::RawExp).wrapping_add(Self::EXP_OFFSET)
+    }
+
+    #[cfg(test)]
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.is_nan()
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        self.abs()
+    }
+
+    #[inline]
+    fn copysign(self, y: Self) -> Self {
+        self.copysign(y)
+    }
+
+    #[cfg(test)]
+    fn parse(s: &str) -> Self {
+        // `f128` does not implement `FromStr`, parsing as `f64` is enough
+        // for the values used in tests
+        s.parse::<f64>().unwrap() as f128
+    }
+}
+
+impl crate::traits::FloatConsts for f128 {
+    // GENERATE: consts f128 PI FRAC_PI_2 FRAC_PI_4 FRAC_2_PI
+    const PI: f128 = f128::from_bits(0x4000921FB54442D18469898CC51701B8); // 3.1415926535897932384626433832795028e0
+    const FRAC_PI_2: f128 = f128::from_bits(0x3FFF921FB54442D18469898CC51701B8); // 1.5707963267948966192313216916397514e0
+    const FRAC_PI_4: f128 = f128::from_bits(0x3FFE921FB54442D18469898CC51701B8); // 7.853981633974483096156608458198757e-1
+    const FRAC_2_PI: f128 = f128::from_bits(0x3FFE45F306DC9C882A53F84EAFA3EA6A); // 6.366197723675813430755350534900575e-1
+}
+
+impl crate::sealed::SealedMath for f128 {
+    fn dd_from(x: Self) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_from(x)
+    }
+
+    fn dd_new(hi: Self, lo: Self) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_new(hi, lo)
+    }
+
+    fn dd_neg(x: crate::DoubleFloat<Self>) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_neg(x)
+    }
+
+    fn dd_add(
+        x: crate::DoubleFloat<Self>,
+        y: crate::DoubleFloat<Self>,
+    ) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_add(x, y)
+    }
+
+    fn dd_sub(
+        x: crate::DoubleFloat<Self>,
+        y: crate::DoubleFloat<Self>,
+    ) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_sub(x, y)
+    }
+
+    fn dd_mul(
+        x: crate::DoubleFloat<Self>,
+        y: crate::DoubleFloat<Self>,
+    ) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_mul(x, y)
+    }
+
+    fn dd_div(
+        x: crate::DoubleFloat<Self>,
+        y: crate::DoubleFloat<Self>,
+    ) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_div(x, y)
+    }
+
+    fn dd_sqrt(x: crate::DoubleFloat<Self>) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_sqrt(x)
+    }
+
+    fn dd_exp(x: crate::DoubleFloat<Self>) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_exp(x)
+    }
+
+    fn dd_ln(x: crate::DoubleFloat<Self>) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_ln(x)
+    }
+
+    fn dd_sin_cos(
+        x: crate::DoubleFloat<Self>,
+    ) -> (crate::DoubleFloat<Self>, crate::DoubleFloat<Self>) {
+        crate::generic::dd_sin_cos(x)
+    }
+
+    fn cr_exp(x: Self) -> Self {
+        crate::generic::cr_exp(x)
+    }
+
+    fn cr_exp_m1(x: Self) -> Self {
+        crate::generic::cr_exp_m1(x)
+    }
+
+    fn cr_exp2(x: Self) -> Self {
+        crate::generic::cr_exp2(x)
+    }
+
+    fn cr_exp10(x: Self) -> Self {
+        crate::generic::cr_exp10(x)
+    }
+
+    fn cr_ln(x: Self) -> Self {
+        crate::generic::cr_ln(x)
+    }
+
+    fn cr_ln_1p(x: Self) -> Self {
+        crate::generic::cr_ln_1p(x)
+    }
+
+    fn cr_log2(x: Self) -> Self {
+        crate::generic::cr_log2(x)
+    }
+
+    fn cr_log10(x: Self) -> Self {
+        crate::generic::cr_log10(x)
+    }
+
+    fn cr_pow(x: Self, y: Self) -> Self {
+        crate::generic::cr_pow(x, y)
+    }
+
+    fn cr_cbrt(x: Self) -> Self {
+        crate::generic::cr_cbrt(x)
+    }
+
+    fn cr_hypot(x: Self, y: Self) -> Self {
+        crate::generic::cr_hypot(x, y)
+    }
+
+    fn cr_sin(x: Self) -> Self {
+        crate::generic::cr_sin(x)
+    }
+
+    fn cr_cos(x: Self) -> Self {
+        crate::generic::cr_cos(x)
+    }
+
+    fn cr_tan(x: Self) -> Self {
+        crate::generic::cr_tan(x)
+    }
+
+    fn cr_asin(x: Self) -> Self {
+        crate::generic::cr_asin(x)
+    }
+
+    fn cr_acos(x: Self) -> Self {
+        crate::generic::cr_acos(x)
+    }
+
+    fn cr_atan(x: Self) -> Self {
+        crate::generic::cr_atan(x)
+    }
+
+    fn cr_atan2(y: Self, x: Self) -> Self {
+        crate::generic::cr_atan2(y, x)
+    }
+
+    fn cr_sinh(x: Self) -> Self {
+        crate::generic::cr_sinh(x)
+    }
+
+    fn cr_cosh(x: Self) -> Self {
+        crate::generic::cr_cosh(x)
+    }
+
+    fn cr_tanh(x: Self) -> Self {
+        crate::generic::cr_tanh(x)
+    }
+
+    fn c_neg(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_neg(z)
+    }
+
+    fn c_conj(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_conj(z)
+    }
+
+    fn c_add(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_add(x, y)
+    }
+
+    fn c_sub(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_sub(x, y)
+    }
+
+    fn c_mul(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_mul(x, y)
+    }
+
+    fn c_div(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_div(x, y)
+    }
+
+    fn c_exp(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_exp(z)
+    }
+
+    fn c_ln(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_ln(z)
+    }
+
+    fn c_sqrt(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_sqrt(z)
+    }
+
+    fn c_pow(z: Complex<Self>, w: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_pow(z, w)
+    }
+
+    fn c_sin(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_sin(z)
+    }
+
+    fn c_cos(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_cos(z)
+    }
+
+    fn c_tan(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_tan(z)
+    }
+
+    fn c_sinh(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_sinh(z)
+    }
+
+    fn c_cosh(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_cosh(z)
+    }
+
+    fn c_tanh(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_tanh(z)
+    }
+
+    fn c_asin(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_asin(z)
+    }
+
+    fn c_acos(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_acos(z)
+    }
+
+    fn c_atan(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_atan(z)
+    }
+
+    fn c_asinh(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_asinh(z)
+    }
+
+    fn c_acosh(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_acosh(z)
+    }
+
+    fn c_atanh(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_atanh(z)
+    }
+
+    fn slice_exp(x: &mut [Self; LANES]) {
+        crate::generic::slice_exp(x);
+    }
+
+    fn slice_ln(x: &mut [Self; LANES]) {
+        crate::generic::slice_ln(x);
+    }
+
+    fn slice_sin(x: &mut [Self; LANES]) {
+        crate::generic::slice_sin(x);
+    }
+
+    fn slice_cos(x: &mut [Self; LANES]) {
+        crate::generic::slice_cos(x);
+    }
+
+    fn slice_sin_cos(sin: &mut [Self; LANES], cos: &mut [Self; LANES]) {
+        crate::generic::slice_sin_cos(sin, cos);
+    }
+}
+
+impl crate::ElementaryMath for f128 {
+    fn abs(x: Self) -> Self {
+        x.abs()
+    }
+
+    fn copysign(x: Self, y: Self) -> Self {
+        x.copysign(y)
+    }
+
+    fn round(x: Self) -> Self {
+        crate::generic::round(x)
+    }
+
+    fn trunc(x: Self) -> Self {
+        crate::generic::trunc(x)
+    }
+
+    fn ceil(x: Self) -> Self {
+        crate::generic::ceil(x)
+    }
+
+    fn floor(x: Self) -> Self {
+        crate::generic::floor(x)
+    }
+
+    fn scalbn(x: Self, y: i32) -> Self {
+        crate::generic::scalbn(x, y)
+    }
+
+    fn frexp(x: Self) -> (Self, i32) {
+        crate::generic::frexp(x)
+    }
+
+    fn fma(x: Self, y: Self, z: Self) -> Self {
+        crate::generic::fma(x, y, z)
+    }
+
+    fn fmod(x: Self, y: Self) -> Self {
+        crate::generic::fmod(x, y)
+    }
+
+    fn remainder(x: Self, y: Self) -> Self {
+        crate::generic::remainder(x, y)
+    }
+
+    fn remquo(x: Self, y: Self) -> (Self, i32) {
+        crate::generic::remquo(x, y)
+    }
+
+    fn hypot(x: Self, y: Self) -> Self {
+        crate::generic::hypot(x, y)
+    }
+
+    fn sqrt(x: Self) -> Self {
+        crate::generic::sqrt(x)
+    }
+
+    fn rsqrt(x: Self) -> Self {
+        crate::generic::rsqrt(x)
+    }
+
+    fn cbrt(x: Self) -> Self {
+        crate::generic::cbrt(x)
+    }
+
+    fn exp(x: Self) -> Self {
+        crate::generic::exp(x)
+    }
+
+    fn exp_m1(x: Self) -> Self {
+        crate::generic::exp_m1(x)
+    }
+
+    fn exp_hi_lo(x: Self) -> (Self, Self) {
+        crate::generic::exp_hi_lo(x)
+    }
+
+    fn exp2(x: Self) -> Self {
+        crate::generic::exp2(x)
+    }
+
+    fn exp2_m1(x: Self) -> Self {
+        crate::generic::exp2_m1(x)
+    }
+
+    fn exp10(x: Self) -> Self {
+        crate::generic::exp10(x)
+    }
+
+    fn exp10_m1(x: Self) -> Self {
+        crate::generic::exp10_m1(x)
+    }
+
+    fn ln(x: Self) -> Self {
+        crate::generic::ln(x)
+    }
+
+    fn ln_1p(x: Self) -> Self {
+        crate::generic::ln_1p(x)
+    }
+
+    fn ln_hi_lo(x: Self) -> (Self, Self) {
+        crate::generic::ln_hi_lo(x)
+    }
+
+    fn ln_1p_hi_lo(x: Self) -> (Self, Self) {
+        crate::generic::ln_1p_hi_lo(x)
+    }
+
+    fn log2(x: Self) -> Self {
+        crate::generic::log2(x)
+    }
+
+    fn log2_1p(x: Self) -> Self {
+        crate::generic::log2_1p(x)
+    }
+
+    fn log10(x: Self) -> Self {
+        crate::generic::log10(x)
+    }
+
+    fn log10_1p(x: Self) -> Self {
+        crate::generic::log10_1p(x)
+    }
+
+    fn pow(x: Self, y: Self) -> Self {
+        crate::generic::pow(x, y)
+    }
+
+    fn powi(x: Self, y: i32) -> Self {
+        crate::generic::powi(x, y)
+    }
+
+    fn powr(x: Self, y: Self) -> Self {
+        crate::generic::powr(x, y)
+    }
+
+    fn pown(x: Self, n: i64) -> Self {
+        crate::generic::pown(x, n)
+    }
+
+    fn rootn(x: Self, n: i64) -> Self {
+        crate::generic::rootn(x, n)
+    }
+
+    fn compound(x: Self, n: i64) -> Self {
+        crate::generic::compound(x, n)
+    }
+
+    fn sin(x: Self) -> Self {
+        crate::generic::sin(x)
+    }
+
+    fn cos(x: Self) -> Self {
+        crate::generic::cos(x)
+    }
+
+    fn sin_cos(x: Self) -> (Self, Self) {
+        crate::generic::sin_cos(x)
+    }
+
+    fn sin_cos_hi_lo(x: Self) -> ((Self, Self), (Self, Self)) {
+        crate::generic::sin_cos_hi_lo(x)
+    }
+
+    fn tan(x: Self) -> Self {
+        crate::generic::tan(x)
+    }
+
+    fn rem_pio2(x: Self) -> (i32, Self, Self) {
+        crate::generic::rem_pio2(x)
+    }
+
+    fn wrap_angle(x: Self) -> Self {
+        crate::generic::wrap_angle(x)
+    }
+
+    fn sind(x: Self) -> Self {
+        crate::generic::sind(x)
+    }
+
+    fn cosd(x: Self) -> Self {
+        crate::generic::cosd(x)
+    }
+
+    fn sind_cosd(x: Self) -> (Self, Self) {
+        crate::generic::sind_cosd(x)
+    }
+
+    fn tand(x: Self) -> Self {
+        crate::generic::tand(x)
+    }
+
+    fn sinpi(x: Self) -> Self {
+        crate::generic::sinpi(x)
+    }
+
+    fn cospi(x: Self) -> Self {
+        crate::generic::cospi(x)
+    }
+
+    fn sinpi_cospi(x: Self) -> (Self, Self) {
+        crate::generic::sinpi_cospi(x)
+    }
+
+    fn tanpi(x: Self) -> Self {
+        crate::generic::tanpi(x)
+    }
+
+    fn asin(x: Self) -> Self {
+        crate::generic::asin(x)
+    }
+
+    fn acos(x: Self) -> Self {
+        crate::generic::acos(x)
+    }
+
+    fn atan(x: Self) -> Self {
+        crate::generic::atan(x)
+    }
+
+    fn atan2(y: Self, x: Self) -> Self {
+        crate::generic::atan2(y, x)
+    }
+
+    fn asind(x: Self) -> Self {
+        crate::generic::asind(x)
+    }
+
+    fn acosd(x: Self) -> Self {
+        crate::generic::acosd(x)
+    }
+
+    fn atand(x: Self) -> Self {
+        crate::generic::atand(x)
+    }
+
+    fn atan2d(y: Self, x: Self) -> Self {
+        crate::generic::atan2d(y, x)
+    }
+
+    fn asinpi(x: Self) -> Self {
+        crate::generic::asinpi(x)
+    }
+
+    fn acospi(x: Self) -> Self {
+        crate::generic::acospi(x)
+    }
+
+    fn atanpi(x: Self) -> Self {
+        crate::generic::atanpi(x)
+    }
+
+    fn atan2pi(y: Self, x: Self) -> Self {
+        crate::generic::atan2pi(y, x)
+    }
+
+    fn sinh(x: Self) -> Self {
+        crate::generic::sinh(x)
+    }
+
+    fn cosh(x: Self) -> Self {
+        crate::generic::cosh(x)
+    }
+
+    fn sinh_cosh(x: Self) -> (Self, Self) {
+        crate::generic::sinh_cosh(x)
+    }
+
+    fn tanh(x: Self) -> Self {
+        crate::generic::tanh(x)
+    }
+
+    fn asinh(x: Self) -> Self {
+        crate::generic::asinh(x)
+    }
+
+    fn acosh(x: Self) -> Self {
+        crate::generic::acosh(x)
+    }
+
+    fn atanh(x: Self) -> Self {
+        crate::generic::atanh(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traits::Float as _;
+
+    #[test]
+    fn test_exp2i_fast() {
+        for e in -16382..=16383 {
+            let x = f128::exp2i_fast(e);
+            assert_eq!(x.exponent(), e);
+            assert_eq!(x.to_bits() & f128::MANT_MASK, 0);
+            if (-1022..=1023).contains(&e) {
+                assert_eq!(x, f64::exp2(f64::from(e)) as f128);
+            }
+        }
+    }
+}
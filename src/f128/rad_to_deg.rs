@@ -0,0 +1,12 @@
+use crate::double::SemiDouble;
+
+// GENERATE: rad_to_deg::consts f128
+const RAD_TO_DEG_HI: f128 = f128::from_bits(0x4004CA5DC1A63C1F7A00000000000000); // 57.29577951308232020011246277135797e0
+const RAD_TO_DEG_LO: f128 = f128::from_bits(0x3FCC86152EA6FE81A4AFAE690544BEF9); // 6.76685692042747201882812882134533e-16
+
+impl crate::generic::RadToDeg for f128 {
+    #[inline]
+    fn rad_to_deg_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(RAD_TO_DEG_HI, RAD_TO_DEG_LO)
+    }
+}
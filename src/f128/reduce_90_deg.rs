@@ -0,0 +1,14 @@
+use crate::double::SemiDouble;
+
+// GENERATE: reduce_90_deg::consts f128
+const DEG_TO_RAD_HI: f128 = f128::from_bits(0x3FF91DF46A2529D39000000000000000); // 1.7453292519943295474371680597869272e-2
+const DEG_TO_RAD_LO: f128 = f128::from_bits(0x3FC15C1D8BECDD290B89B2016F5DEA03); // 2.9486522708701685525627563317679907e-19
+
+impl crate::generic::Reduce90Deg for f128 {
+    #[inline]
+    fn deg_to_rad_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(DEG_TO_RAD_HI, DEG_TO_RAD_LO)
+    }
+
+    type SRaw = i128;
+}
@@ -0,0 +1,12 @@
+use crate::double::SemiDouble;
+
+// GENERATE: reduce_half_mul_pi::consts f128
+const PI_HI: f128 = f128::from_bits(0x4000921FB54442D18400000000000000); // 3.1415926535897932270202659310598392e0
+const PI_LO: f128 = f128::from_bits(0x3FC6A62633145C06E0E6894812704453); // 1.1442377452219663680243426418418074e-17
+
+impl crate::generic::ReduceHalfMulPi for f128 {
+    #[inline]
+    fn pi_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(PI_HI, PI_LO)
+    }
+}
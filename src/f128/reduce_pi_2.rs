@@ -0,0 +1,92 @@
+use crate::generic::scalbn_medium;
+use crate::traits::{Float as _, FloatConsts as _, Lanes as _};
+
+// GENERATE: reduce_pi_2::consts f128
+const FRAC_PI_2_HI: f128 = f128::from_bits(0x3FFF921FB54442D18469898CC0000000); // 1.5707963267948966192313216751934945e0
+const FRAC_PI_2_HIEX: f128 = f128::from_bits(0x3FA945C06E0E68948127044533E63A01); // 1.644625693632425780822015671603022e-26
+const FRAC_PI_2_MI: f128 = f128::from_bits(0x3FA945C06E0E68948127044500000000); // 1.644625693632425780822015454861338e-26
+const FRAC_PI_2_MIEX: f128 = f128::from_bits(0x3F569F31D0082EFA98EC4E6C89452822); // 2.1674168413243539905553514312988535e-51
+const FRAC_PI_2_LO: f128 = f128::from_bits(0x3F569F31D0082EFA98EC4E6C80000000); // 2.167416841324353990555351391269592e-51
+const FRAC_PI_2_LOEX: f128 = f128::from_bits(0x3F0128A5043CC71A026EF7CA8CD9E69D); // 4.002926142527453888525606058318009e-77
+
+impl crate::generic::ReducePi2 for f128 {
+    #[inline]
+    fn frac_pi_2_hi() -> Self {
+        FRAC_PI_2_HI
+    }
+
+    #[inline]
+    fn frac_pi_2_hiex() -> Self {
+        FRAC_PI_2_HIEX
+    }
+
+    #[inline]
+    fn frac_pi_2_mi() -> Self {
+        FRAC_PI_2_MI
+    }
+
+    #[inline]
+    fn frac_pi_2_miex() -> Self {
+        FRAC_PI_2_MIEX
+    }
+
+    #[inline]
+    fn frac_pi_2_lo() -> Self {
+        FRAC_PI_2_LO
+    }
+
+    #[inline]
+    fn frac_pi_2_loex() -> Self {
+        FRAC_PI_2_LOEX
+    }
+
+    #[inline]
+    fn max_reduce_pi_2_medium() -> Self {
+        ((1u64 << 30) - 1) as f128 * f128::FRAC_PI_2
+    }
+
+    const REDUCE_PI_2_MEDIUM_TH1: i16 = 66;
+    const REDUCE_PI_2_MEDIUM_TH2: i16 = 149;
+
+    type SrcChunks = [u32; 5];
+
+    /// Returns `(x_chunks, e0, jk)`
+    fn reduce_pi_2_prepare(x: Self) -> ([u32; 5], i16, usize) {
+        let mant = x.mant();
+        let x_chunks = [
+            ((mant >> 89) as u32),
+            (((mant >> 65) & 0x00FF_FFFF) as u32),
+            (((mant >> 41) & 0x00FF_FFFF) as u32),
+            (((mant >> 17) & 0x00FF_FFFF) as u32),
+            (((mant << 7) & 0x00FF_FFFF) as u32),
+        ];
+        let e0 = x.exponent() - 23;
+        let jk = 6;
+        (x_chunks, e0, jk)
+    }
+
+    fn reduce_pi_2_compress(qp: &[u64], qe: i16, ih: u32) -> (Self, Self) {
+        // iw = sum(qp), as a 256-bit number (iw_hi, iw_lo)
+        let mut iw_hi = 0u128;
+        let mut iw_lo = 0u128;
+        for &qp_i in qp.iter().rev() {
+            iw_lo = (iw_lo >> 24) | (iw_hi << 104);
+            iw_hi = (iw_hi >> 24) + (u128::from(qp_i) << 48);
+        }
+
+        // split iw into 80-bit chunks
+        let fw0 = (iw_lo & 0xFFFF_FFFF_FFFF_FFFF_FFFF) as f128;
+        let fw1 = (((iw_hi & 0xFFFF_FFFF) << 48) | (iw_lo >> 80)) as f128 * Self::exp2i_fast(80);
+        let fw2 = (iw_hi >> 32) as f128 * Self::exp2i_fast(160);
+
+        // add 80-bit chunks into y0, y1
+        let mut y0 = ((fw0 + fw1) + fw2).purify();
+        let mut y1 = ((fw2 - y0) + fw1) + fw0;
+
+        let scale = i32::from(qe) - 176;
+        y0 = scalbn_medium(y0, scale);
+        y1 = scalbn_medium(y1, scale);
+
+        if ih == 0 { (y0, y1) } else { (-y0, -y1) }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::double::SemiDouble;
+
+// GENERATE: sin_cos::consts f128
+const FRAC_1_6_HI: f128 = f128::from_bits(0x3FFC5555555555555400000000000000); // 1.6666666666666666435370203203092387e-1
+const FRAC_1_6_LO: f128 = f128::from_bits(0x3FC45555555555555555555555555555); // 2.3129646346357427925492326418558755e-18
+
+impl crate::generic::SinCos for f128 {
+    #[inline]
+    fn frac_1_6_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(FRAC_1_6_HI, FRAC_1_6_LO)
+    }
+
+    #[inline]
+    fn sin_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V, x5: V) -> (V, Self) {
+        // GENERATE: sin_cos::sin_poly f128 12
+        const K3: f128 = f128::from_bits(0xBFFC5555555555555555555555555555); // -1.6666666666666666666666666666666666e-1
+        const K5: f128 = f128::from_bits(0x3FF81111111111111111111111111086); // 8.333333333333333333333333333333124e-3
+        const K7: f128 = f128::from_bits(0xBFF2A01A01A01A01A01A01A019FC695D); // -1.9841269841269841269841269840466525e-4
+        const K9: f128 = f128::from_bits(0x3FEC71DE3A556C7338FAAC1C6FC5B133); // 2.7557319223985890652557317675704768e-6
+        const K11: f128 = f128::from_bits(0xBFE5AE64567F544E38FE73F06EC022A9); // -2.5052108385441718775050359796902843e-8
+        const K13: f128 = f128::from_bits(0x3FDE6124613A86D097C83A40ED9CDAEB); // 1.605904383682161459813526312980007e-10
+        const K15: f128 = f128::from_bits(0xBFD6AE7F3E733B81E7914AF7582BAEDF); // -7.647163731819815873351442035369658e-13
+        const K17: f128 = f128::from_bits(0x3FCE952C77030AB56F0AFFAB4456F37E); // 2.8114572543453237537218993091825004e-15
+        const K19: f128 = f128::from_bits(0xBFC62F49B467CE2DC3494130A80E69E8); // -8.220635246183167054456794621335984e-18
+        const K21: f128 = f128::from_bits(0x3FBD71B8EE9ACFD3DB88D4178353ADEC); // 1.9572940397598664242808264220522162e-20
+        const K23: f128 = f128::from_bits(0xBFB47619A6D5AE1772CA11D7E0716A7A); // -3.868105427516635892719677937768015e-23
+        const K25: f128 = f128::from_bits(0x3FAB3D6C4E74B668544275E83299D924); // 6.410307891919244962603118840206691e-26
+
+        let r = horner!(x5, x2, [K5, K7, K9, K11, K13, K15, K17, K19, K21, K23, K25]);
+        (r, K3)
+    }
+
+    #[inline]
+    fn sin_poly_ex(x2: Self, x5: Self) -> Self {
+        // GENERATE: sin_cos::sin_poly_ex f128 11
+        const K5: f128 = f128::from_bits(0x3FF811111111111111111111111110E3); // 8.333333333333333333333333333333264e-3
+        const K7: f128 = f128::from_bits(0xBFF2A01A01A01A01A01A01A019FEE94F); // -1.9841269841269841269841269840851678e-4
+        const K9: f128 = f128::from_bits(0x3FEC71DE3A556C7338FAAC1C78BAA254); // 2.7557319223985890652557318227710453e-6
+        const K11: f128 = f128::from_bits(0xBFE5AE64567F544E38FE7416C01066F3); // -2.5052108385441718775050832098169954e-8
+        const K13: f128 = f128::from_bits(0x3FDE6124613A86D097C8A34904E6A3E9); // 1.6059043836821614598394186027529082e-10
+        const K15: f128 = f128::from_bits(0xBFD6AE7F3E733B81E90FB2989B24C238); // -7.647163731819815967621504587032505e-13
+        const K17: f128 = f128::from_bits(0x3FCE952C77030AB9168AE5555B726B97); // 2.8114572543453468155675190336873494e-15
+        const K19: f128 = f128::from_bits(0xBFC62F49B467D41E4E3FDCB855DAAE53); // -8.220635246220651329134855039905663e-18
+        const K21: f128 = f128::from_bits(0x3FBD71B8EEA71DB699396765727E055A); // 1.9572940436424003150872939031187265e-20
+        const K23: f128 = f128::from_bits(0xBFB47619B5895332F3CF249B7D9C5F6A); // -3.868107747041919423302831979830899e-23
+        const K25: f128 = f128::from_bits(0x3FAB3D74049B7942646B8B8B27824DDA); // 6.410916224289905209700419380398779e-26
+
+        horner!(x5, x2, [K5, K7, K9, K11, K13, K15, K17, K19, K21, K23, K25])
+    }
+
+    #[inline]
+    fn cos_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V, x4: V) -> V {
+        // GENERATE: sin_cos::cos_poly f128 12
+        const K4: f128 = f128::from_bits(0x3FFA5555555555555555555555555555); // 4.1666666666666666666666666666666665e-2
+        const K6: f128 = f128::from_bits(0xBFF56C16C16C16C16C16C16C16C16B5A); // -1.3888888888888888888888888888888534e-3
+        const K8: f128 = f128::from_bits(0x3FEFA01A01A01A01A01A01A019FC7E5A); // 2.4801587301587301587301587300598946e-5
+        const K10: f128 = f128::from_bits(0xBFE927E4FB7789F5C72EF016C0945026); // -2.755731922398589065255731773437321e-7
+        const K12: f128 = f128::from_bits(0x3FE21EED8EFF8D897B544D4FBC7C1C6B); // 2.0876756987868098979208706829970558e-9
+        const K14: f128 = f128::from_bits(0xBFDA93974A8C07C9D20987E47015529B); // -1.1470745597729724713004211554592942e-11
+        const K16: f128 = f128::from_bits(0x3FD2AE7F3E733B81E81B9742F73AACA4); // 4.779477332387384942152836479195115e-14
+        const K18: f128 = f128::from_bits(0xBFCA6827863B97BF309319780891E5DE); // -1.5619206968585189979742579615901612e-16
+        const K20: f128 = f128::from_bits(0x3FC1E542BA3FB578BE63875A70BFEB3A); // 4.110317623101810393710902959134219e-19
+        const K22: f128 = f128::from_bits(0xBFB90CE39647C52E9F4484C584D24D5D); // -8.896791101108694548974575691352645e-22
+        const K24: f128 = f128::from_bits(0x3FAFF2CCECDA4E42DDE63F197062A935); // 1.6117113050942050107131474862009954e-24
+        const K26: f128 = f128::from_bits(0xBFA686B4921DD6EE69F4832D7051985D); // -2.4656974373944197941890007959465345e-27
+
+        horner!(
+            x4,
+            x2,
+            [K4, K6, K8, K10, K12, K14, K16, K18, K20, K22, K24, K26]
+        )
+    }
+}
@@ -0,0 +1,6 @@
+impl crate::generic::SinhCosh for f128 {
+    #[inline]
+    fn expo2_hi_th() -> Self {
+        11358.0
+    }
+}
@@ -0,0 +1,35 @@
+impl crate::generic::Tan for f128 {
+    #[inline]
+    fn tan_poly(x2: Self, x3: Self) -> Self {
+        // GENERATE: tan::tan_poly f128 20
+        const K3: f128 = f128::from_bits(0x3FFD5555555555555555555555555552); // 3.3333333333333333333333333333333317e-1
+        const K5: f128 = f128::from_bits(0x3FFC1111111111111111111111112CD1); // 1.3333333333333333333333333333350435e-1
+        const K7: f128 = f128::from_bits(0x3FFABA1BA1BA1BA1BA1BA1BA1AF1BEDF); // 5.396825396825396825396825389884155e-2
+        const K9: f128 = f128::from_bits(0x3FF9664F4882C10F9F32D6BCC1DF651A); // 2.1869488536155202821869502937905737e-2
+        const K11: f128 = f128::from_bits(0x3FF8226E355E6C23C8F5B4058632E8F3); // 8.863235529902196568861434471898939e-3
+        const K13: f128 = f128::from_bits(0x3FF6D6D3D0E157DDFB613482BBE10EA2); // 3.5921280365724810170745430432768255e-3
+        const K15: f128 = f128::from_bits(0x3FF57DA36452B75E2AC65A432708699B); // 1.4558343870513182595799471770392865e-3
+        const K17: f128 = f128::from_bits(0x3FF43558248036747B8EA9E187FED06E); // 5.900274409455863501669926591135408e-4
+        const K19: f128 = f128::from_bits(0x3FF2F57D7734D14B0AA2C03D51EECD58); // 2.3912911424354067813509176292979647e-4
+        const K21: f128 = f128::from_bits(0x3FF1967E18AFD033ACFDEB7DABDE0B67); // 9.691537956958439550250857679546042e-5
+        const K23: f128 = f128::from_bits(0x3FF0497D8EE95DA97D38C103D98D9C9C); // 3.9278323877780021072298819882354186e-5
+        const K25: f128 = f128::from_bits(0x3FEF0B132D50FC163F539B0EA74DBFC2); // 1.5918905152235803043644903494610112e-5
+        const K27: f128 = f128::from_bits(0x3FEDB0F728F363BAB6CA537171988492); // 6.4516882391081933611320089317032115e-6
+        const K29: f128 = f128::from_bits(0x3FEC5EF329C29CF6F8F8759C82D7FD5E); // 2.6147801873102698441854156296214537e-6
+        const K31: f128 = f128::from_bits(0x3FEB1C7363D03664A24C3C864560DA9B); // 1.0596615887453693211315018362156376e-6
+        const K33: f128 = f128::from_bits(0x3FE9CD8D4F5329F27269918A86B17C7B); // 4.2985378918357939872841954352264e-7
+        const K35: f128 = f128::from_bits(0x3FE8728C1BCD711489281CDAC64CB545); // 1.725495323690517835134875820852321e-7
+        const K37: f128 = f128::from_bits(0x3FE742B368DAAF98AF5B622DD2C2FB84); // 7.513463932526281333117459315185473e-8
+        const K39: f128 = f128::from_bits(0x3FE54A56524997245A35A3E27A0A81FF); // 1.9228155323480761324355368051789848e-8
+        const K41: f128 = f128::from_bits(0x3FE58623FAEDCDACF6562CD62236F1D5); // 2.2709168704544079197152265599102093e-8
+
+        horner!(
+            x3,
+            x2,
+            [
+                K3, K5, K7, K9, K11, K13, K15, K17, K19, K21, K23, K25, K27, K29, K31, K33, K35,
+                K37, K39, K41
+            ]
+        )
+    }
+}
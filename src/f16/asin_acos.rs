@@ -0,0 +1,22 @@
+use crate::double::NormDouble;
+
+// GENERATE: asin_acos::consts f16
+const FRAC_PI_2_HI: f16 = f16::from_bits(0x3E48); // 1.57e0
+const FRAC_PI_2_LO: f16 = f16::from_bits(0x0FED); // 4.838e-4
+
+impl crate::generic::AsinAcos for f16 {
+    #[inline]
+    fn frac_pi_2_ex() -> NormDouble<Self> {
+        NormDouble::with_parts(FRAC_PI_2_HI, FRAC_PI_2_LO)
+    }
+
+    #[inline]
+    fn asin_poly(x2: Self) -> Self {
+        // GENERATE: asin_acos::asin_poly f16 3
+        const K0: f16 = f16::from_bits(0x3157); // 1.669e-1
+        const K2: f16 = f16::from_bits(0x2C8F); // 7.12e-2
+        const K4: f16 = f16::from_bits(0x2C37); // 6.586e-2
+
+        K0 + horner!(x2, x2, [K2, K4])
+    }
+}
@@ -0,0 +1,34 @@
+// GENERATE: atan::consts f16
+const FRAC_PI_2_HI: f16 = f16::from_bits(0x3E48); // 1.57e0
+const FRAC_PI_2_LO: f16 = f16::from_bits(0x0FED); // 4.838e-4
+const FRAC_3PI_4: f16 = f16::from_bits(0x40B6); // 2.355e0
+
+impl crate::generic::Atan for f16 {
+    #[inline]
+    fn frac_pi_2_hi() -> Self {
+        FRAC_PI_2_HI
+    }
+
+    #[inline]
+    fn frac_pi_2_lo() -> Self {
+        FRAC_PI_2_LO
+    }
+
+    #[inline]
+    fn frac_3pi_4() -> Self {
+        FRAC_3PI_4
+    }
+
+    #[inline]
+    fn atan_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V) -> (V, V) {
+        // GENERATE: atan::atan_poly f16 5
+        const K3: f16 = f16::from_bits(0xB554); // -3.33e-1
+        const K5: f16 = f16::from_bits(0x323F); // 1.952e-1
+        const K7: f16 = f16::from_bits(0xAFAB); // -1.198e-1
+        const K9: f16 = f16::from_bits(0x2B25); // 5.582e-2
+        const K11: f16 = f16::from_bits(0xA28F); // -1.281e-2
+
+        let t = horner!(x2, x2, [K5, K7, K9, K11]);
+        (V::splat(K3), t)
+    }
+}
@@ -0,0 +1,213 @@
+use crate::double::NormDouble;
+
+impl crate::generic::Bessel for f16 {
+    #[inline]
+    fn frac_pi_4_ex() -> NormDouble<Self> {
+        // GENERATE: bessel::consts f16 FRAC_PI_4
+        const FRAC_PI_4_HI: f16 = f16::from_bits(0x3A48); // 7.85e-1
+        const FRAC_PI_4_LO: f16 = f16::from_bits(0x0BED); // 2.419e-4
+
+        NormDouble::with_parts(FRAC_PI_4_HI, FRAC_PI_4_LO)
+    }
+
+    #[inline]
+    fn frac_2_pi_ex() -> NormDouble<Self> {
+        // GENERATE: bessel::consts f16 FRAC_2_PI
+        const FRAC_2_PI_HI: f16 = f16::from_bits(0x3917); // 6.36e-1
+        const FRAC_2_PI_LO: f16 = f16::from_bits(0x0E61); // 3.893e-4
+
+        NormDouble::with_parts(FRAC_2_PI_HI, FRAC_2_PI_LO)
+    }
+
+    #[inline]
+    fn sqrt_frac_2_pi_ex() -> NormDouble<Self> {
+        // GENERATE: bessel::consts f16 SQRT_FRAC_2_PI
+        const SQRT_FRAC_2_PI_HI: f16 = f16::from_bits(0x3A62); // 7.98e-1
+        const SQRT_FRAC_2_PI_LO: f16 = f16::from_bits(0x022A); // 3.3e-5
+
+        NormDouble::with_parts(SQRT_FRAC_2_PI_HI, SQRT_FRAC_2_PI_LO)
+    }
+
+    #[inline]
+    fn j0_small_poly(u: Self) -> (Self, Self) {
+        // GENERATE: bessel::j0_small_poly f16 2
+        const K3_HI: f16 = f16::from_bits(0x8F1C); // -4.34e-4
+        const K3_LO: f16 = f16::from_bits(0x8002); // -1e-7
+        const K4: f16 = f16::from_bits(0x0072); // 6.8e-6
+        const K5: f16 = f16::from_bits(0x8001); // -6e-8
+
+        let r = K3_LO + horner!(u, u, [K4, K5]);
+        (r, K3_HI)
+    }
+
+    #[inline]
+    fn j1_small_poly(u: Self) -> (Self, Self) {
+        // GENERATE: bessel::j1_small_poly f16 2
+        const K2_HI: f16 = f16::from_bits(0x1955); // 2.604e-3
+        const K2_LO: f16 = f16::from_bits(0x000A); // 6e-7
+        const K3: f16 = f16::from_bits(0x838D); // -5.42e-5
+        const K4: f16 = f16::from_bits(0x000B); // 6.6e-7
+
+        let r = K2_LO + horner!(u, u, [K3, K4]);
+        (r, K2_HI)
+    }
+
+    #[inline]
+    fn y0_small_poly(u: Self) -> (Self, NormDouble<Self>, NormDouble<Self>, Self) {
+        // GENERATE: bessel::y0_small_poly f16 5
+        const K0_HI: f16 = f16::from_bits(0xACB9); // -7.38e-2
+        const K0_LO: f16 = f16::from_bits(0x80D7); // -1.28e-5
+        const K1_HI: f16 = f16::from_bits(0x31AE); // 1.775e-1
+        const K1_LO: f16 = f16::from_bits(0x0796); // 1.1575e-4
+        const K2_HI: f16 = f16::from_bits(0xA41D); // -1.607e-2
+        const K2_LO: f16 = f16::from_bits(0x806B); // -6.4e-6
+        const K3: f16 = f16::from_bits(0x1069); // 5.383e-4
+        const K4: f16 = f16::from_bits(0x809F); // -9.5e-6
+        const K5: f16 = f16::from_bits(0x0002); // 1e-7
+
+        let r = K2_LO + horner!(u, u, [K3, K4, K5]);
+        (
+            r,
+            NormDouble::with_parts(K0_HI, K0_LO),
+            NormDouble::with_parts(K1_HI, K1_LO),
+            K2_HI,
+        )
+    }
+
+    #[inline]
+    fn y1_small_poly(u: Self) -> (Self, NormDouble<Self>, NormDouble<Self>, Self) {
+        // GENERATE: bessel::y1_small_poly f16 4
+        const K0_HI: f16 = f16::from_bits(0xB246); // -1.96e-1
+        const K0_LO: f16 = f16::from_bits(0x80CC); // -1.216e-5
+        const K1_HI: f16 = f16::from_bits(0x2AF4); // 5.432e-2
+        const K1_LO: f16 = f16::from_bits(0x01C8); // 2.72e-5
+        const K2_HI: f16 = f16::from_bits(0x9A0D); // -2.954e-3
+        const K2_LO: f16 = f16::from_bits(0x8007); // -4e-7
+        const K3: f16 = f16::from_bits(0x04AD); // 7.135e-5
+        const K4: f16 = f16::from_bits(0x800F); // -9e-7
+
+        let r = K2_LO + horner!(u, u, [K3, K4]);
+        (
+            r,
+            NormDouble::with_parts(K0_HI, K0_LO),
+            NormDouble::with_parts(K1_HI, K1_LO),
+            K2_HI,
+        )
+    }
+
+    #[inline]
+    fn asympt0_poly_1(z: Self) -> (Self, Self, NormDouble<Self>, Self) {
+        // GENERATE: bessel::asympt_amp_poly f16 0 3 2 4
+        const A0: f16 = f16::from_bits(0x9FA3); // -7.458e-3
+        const A1: f16 = f16::from_bits(0xA874); // -3.48e-2
+        const A2: f16 = f16::from_bits(0xA6D9); // -2.675e-2
+        const A3: f16 = f16::from_bits(0x27A6); // 2.988e-2
+
+        // GENERATE: bessel::asympt_phase_poly f16 0 2 2 4
+        const K0_HI: f16 = f16::from_bits(0xA9AB); // -4.428e-2
+        const K0_LO: f16 = f16::from_bits(0x8194); // -2.41e-5
+        const K1_HI: f16 = f16::from_bits(0xAEDE); // -1.073e-1
+        const K1_LO: f16 = f16::from_bits(0x801B); // -1.6e-6
+        const K2: f16 = f16::from_bits(0x2837); // 3.293e-2
+
+        let a = A0 + horner!(z, z, [A1, A2, A3]);
+        let r = K1_LO + horner!(z, z, [K2]);
+        (a, r, NormDouble::with_parts(K0_HI, K0_LO), K1_HI)
+    }
+
+    #[inline]
+    fn asympt0_poly_2(z: Self) -> (Self, Self, NormDouble<Self>, Self) {
+        // GENERATE: bessel::asympt_amp_poly f16 0 3 4 8
+        const A0: f16 = f16::from_bits(0x9846); // -2.087e-3
+        const A1: f16 = f16::from_bits(0xA570); // -2.124e-2
+        const A2: f16 = f16::from_bits(0xAA02); // -4.694e-2
+        const A3: f16 = f16::from_bits(0x292A); // 4.034e-2
+
+        // GENERATE: bessel::asympt_phase_poly f16 0 3 4 8
+        const K0_HI: f16 = f16::from_bits(0xA5E6); // -2.304e-2
+        const K0_LO: f16 = f16::from_bits(0x8072); // -6.8e-6
+        const K1_HI: f16 = f16::from_bits(0xAF9F); // -1.191e-1
+        const K1_LO: f16 = f16::from_bits(0x81D0); // -2.766e-5
+        const K2: f16 = f16::from_bits(0x26F9); // 2.724e-2
+        const K3: f16 = f16::from_bits(0x26B1); // 2.614e-2
+
+        let a = A0 + horner!(z, z, [A1, A2, A3]);
+        let r = K1_LO + horner!(z, z, [K2, K3]);
+        (a, r, NormDouble::with_parts(K0_HI, K0_LO), K1_HI)
+    }
+
+    #[inline]
+    fn asympt0_poly_3(u: Self) -> (Self, Self) {
+        // GENERATE: bessel::asympt_amp_tail_poly f16 0 1 8
+        const A1: f16 = f16::from_bits(0xAC00); // -6.25e-2
+        const A2: f16 = f16::from_bits(0x2E28); // 9.62e-2
+
+        // GENERATE: bessel::asympt_phase_tail_poly f16 0 1 8
+        const P1: f16 = f16::from_bits(0x2C2A); // 6.506e-2
+        const P2: f16 = f16::from_bits(0xB207); // -1.884e-1
+
+        let a = horner!(u, u, [A1, A2]);
+        let p = horner!(u, u, [P1, P2]);
+        (a, p)
+    }
+
+    #[inline]
+    fn asympt1_poly_1(z: Self) -> (Self, Self, NormDouble<Self>, Self) {
+        // GENERATE: bessel::asympt_amp_poly f16 1 4 2 4
+        const A0: f16 = f16::from_bits(0x2610); // 2.368e-2
+        const A1: f16 = f16::from_bits(0x2F65); // 1.1554e-1
+        const A2: f16 = f16::from_bits(0x2EF1); // 1.0846e-1
+        const A3: f16 = f16::from_bits(0xACF2); // -7.73e-2
+        const A4: f16 = f16::from_bits(0x275B); // 2.873e-2
+
+        // GENERATE: bessel::asympt_phase_poly f16 1 4 2 4
+        const K0_HI: f16 = f16::from_bits(0x3046); // 1.335e-1
+        const K0_LO: f16 = f16::from_bits(0x06DB); // 1.046e-4
+        const K1_HI: f16 = f16::from_bits(0x3534); // 3.252e-1
+        const K1_LO: f16 = f16::from_bits(0x01AB); // 2.545e-5
+        const K2: f16 = f16::from_bits(0xAE7F); // -1.015e-1
+        const K3: f16 = f16::from_bits(0xA493); // -1.787e-2
+        const K4: f16 = f16::from_bits(0x2BC9); // 6.082e-2
+
+        let a = A0 + horner!(z, z, [A1, A2, A3, A4]);
+        let r = K1_LO + horner!(z, z, [K2, K3, K4]);
+        (a, r, NormDouble::with_parts(K0_HI, K0_LO), K1_HI)
+    }
+
+    #[inline]
+    fn asympt1_poly_2(z: Self) -> (Self, Self, NormDouble<Self>, Self) {
+        // GENERATE: bessel::asympt_amp_poly f16 1 3 4 8
+        const A0: f16 = f16::from_bits(0x1E88); // 6.38e-3
+        const A1: f16 = f16::from_bits(0x2C3A); // 6.604e-2
+        const A2: f16 = f16::from_bits(0x3101); // 1.564e-1
+        const A3: f16 = f16::from_bits(0xAD7E); // -8.58e-2
+
+        // GENERATE: bessel::asympt_phase_poly f16 1 4 4 8
+        const K0_HI: f16 = f16::from_bits(0x2C6F); // 6.93e-2
+        const K0_LO: f16 = f16::from_bits(0x01D8); // 2.813e-5
+        const K1_HI: f16 = f16::from_bits(0x35C0); // 3.594e-1
+        const K1_LO: f16 = f16::from_bits(0x0845); // 1.303e-4
+        const K2: f16 = f16::from_bits(0xACCA); // -7.48e-2
+        const K3: f16 = f16::from_bits(0xAD91); // -8.7e-2
+        const K4: f16 = f16::from_bits(0x3010); // 1.27e-1
+
+        let a = A0 + horner!(z, z, [A1, A2, A3]);
+        let r = K1_LO + horner!(z, z, [K2, K3, K4]);
+        (a, r, NormDouble::with_parts(K0_HI, K0_LO), K1_HI)
+    }
+
+    #[inline]
+    fn asympt1_poly_3(u: Self) -> (Self, Self) {
+        // GENERATE: bessel::asympt_amp_tail_poly f16 1 1 8
+        const A1: f16 = f16::from_bits(0x3200); // 1.875e-1
+        const A2: f16 = f16::from_bits(0xB1D5); // -1.823e-1
+
+        // GENERATE: bessel::asympt_phase_tail_poly f16 1 1 8
+        const P1: f16 = f16::from_bits(0xB140); // -1.641e-1
+        const P2: f16 = f16::from_bits(0x356F); // 3.396e-1
+
+        let a = horner!(u, u, [A1, A2]);
+        let p = horner!(u, u, [P1, P2]);
+        (a, p)
+    }
+}
@@ -0,0 +1,12 @@
+use crate::double::SemiDouble;
+
+// GENERATE: div_pi::consts f16
+const FRAC_1_PI_HI: f16 = f16::from_bits(0x3500); // 3.125e-1
+const FRAC_1_PI_LO: f16 = f16::from_bits(0x1DF3); // 5.81e-3
+
+impl crate::generic::DivPi for f16 {
+    #[inline]
+    fn frac_1_pi_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(FRAC_1_PI_HI, FRAC_1_PI_LO)
+    }
+}
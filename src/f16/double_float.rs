@@ -0,0 +1,33 @@
+// GENERATE: double_float::consts f16
+const DD_LN_2_HI: f16 = f16::from_bits(0x3980); // 6.875e-1
+const DD_LN_2_MI: f16 = f16::from_bits(0x1DC0); // 5.615e-3
+const DD_LN_2_LO: f16 = f16::from_bits(0x0218); // 3.195e-5
+const DD_LN_10_HI: f16 = f16::from_bits(0x409A); // 2.3e0
+const DD_LN_10_LO: f16 = f16::from_bits(0x1763); // 1.803e-3
+
+impl crate::generic::DoubleFloatMath for f16 {
+    #[inline]
+    fn dd_ln_2_hi() -> Self {
+        DD_LN_2_HI
+    }
+
+    #[inline]
+    fn dd_ln_2_mi() -> Self {
+        DD_LN_2_MI
+    }
+
+    #[inline]
+    fn dd_ln_2_lo() -> Self {
+        DD_LN_2_LO
+    }
+
+    #[inline]
+    fn dd_ln_10_hi() -> Self {
+        DD_LN_10_HI
+    }
+
+    #[inline]
+    fn dd_ln_10_lo() -> Self {
+        DD_LN_10_LO
+    }
+}
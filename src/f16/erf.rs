@@ -0,0 +1,92 @@
+use crate::double::NormDouble;
+
+impl crate::generic::Erf for f16 {
+    #[inline]
+    fn erf_one_th() -> Self {
+        2.6
+    }
+
+    #[inline]
+    fn erfc_hi_th() -> Self {
+        4.0
+    }
+
+    #[inline]
+    fn frac_1_sqrt_pi() -> NormDouble<Self> {
+        // GENERATE: erf::consts f16
+        const FRAC_1_SQRT_PI_HI: f16 = f16::from_bits(0x3883); // 5.64e-1
+        const FRAC_1_SQRT_PI_LO: f16 = f16::from_bits(0x0B5D); // 2.247e-4
+
+        NormDouble::with_parts(FRAC_1_SQRT_PI_HI, FRAC_1_SQRT_PI_LO)
+    }
+
+    #[inline]
+    fn erf_poly(x2: Self) -> (Self, Self, Self, Self) {
+        // GENERATE: erf::erf_poly f16 5 1
+        const K0: f16 = f16::from_bits(0x301C); // 1.284e-1
+        const K1: f16 = f16::from_bits(0xB605); // -3.762e-1
+        const K2: f16 = f16::from_bits(0x2F38); // 1.128e-1
+        const K3: f16 = f16::from_bits(0xA6D7); // -2.672e-2
+        const K4: f16 = f16::from_bits(0x1D0B); // 4.925e-3
+        const K5: f16 = f16::from_bits(0x90A3); // -5.66e-4
+
+        let r = horner!(x2, x2, [K3, K4, K5]);
+        (r, K0, K1, K2)
+    }
+
+    #[inline]
+    fn erfc_poly_1(x: Self) -> (Self, NormDouble<Self>, Self) {
+        // GENERATE: erf::erfc_poly f16 4 0.5 1
+        const K0_HI: f16 = f16::from_bits(0x380E); // 5.07e-1
+        const K0_LO: f16 = f16::from_bits(0x06AA); // 1.017e-4
+        const K1: f16 = f16::from_bits(0xB5E3); // -3.68e-1
+        const K2: f16 = f16::from_bits(0x3364); // 2.31e-1
+        const K3: f16 = f16::from_bits(0xB038); // -1.318e-1
+        const K4: f16 = f16::from_bits(0x2C5D); // 6.82e-2
+
+        let r = horner!(x, x, [K2, K3, K4]);
+        (r, NormDouble::with_parts(K0_HI, K0_LO), K1)
+    }
+
+    #[inline]
+    fn erfc_poly_2(x: Self) -> (Self, NormDouble<Self>, Self) {
+        // GENERATE: erf::erfc_poly f16 5 1 2
+        const K0_HI: f16 = f16::from_bits(0x3525); // 3.215e-1
+        const K0_LO: f16 = f16::from_bits(0x036C); // 5.22e-5
+        const K1: f16 = f16::from_bits(0xB13C); // -1.636e-1
+        const K2: f16 = f16::from_bits(0x2CDF); // 7.61e-2
+        const K3: f16 = f16::from_bits(0xA836); // -3.29e-2
+        const K4: f16 = f16::from_bits(0x2329); // 1.3985e-2
+        const K5: f16 = f16::from_bits(0x9D94); // -5.447e-3
+
+        let r = horner!(x, x, [K2, K3, K4, K5]);
+        (r, NormDouble::with_parts(K0_HI, K0_LO), K1)
+    }
+
+    #[inline]
+    fn erfc_poly_3(x: Self) -> (Self, NormDouble<Self>, Self) {
+        // GENERATE: erf::erfc_poly f16 6 2 4
+        const K0_HI: f16 = f16::from_bits(0x31BA); // 1.79e-1
+        const K0_LO: f16 = f16::from_bits(0x0305); // 4.61e-5
+        const K1: f16 = f16::from_bits(0xAAF6); // -5.438e-2
+        const K2: f16 = f16::from_bits(0x2411); // 1.588e-2
+        const K3: f16 = f16::from_bits(0x9C93); // -4.467e-3
+        const K4: f16 = f16::from_bits(0x14FB); // 1.216e-3
+        const K5: f16 = f16::from_bits(0x8DDD); // -3.579e-4
+        const K6: f16 = f16::from_bits(0x0635); // 9.47e-5
+
+        let r = horner!(x, x, [K2, K3, K4, K5, K6]);
+        (r, NormDouble::with_parts(K0_HI, K0_LO), K1)
+    }
+
+    #[inline]
+    fn erfc_asympt_poly(t: Self) -> (Self, Self) {
+        // GENERATE: erf::erfc_asympt_poly f16 2 4 10.06
+        const K0: f16 = f16::from_bits(0xB7FF); // -4.998e-1
+        const K1: f16 = f16::from_bits(0x39DA); // 7.314e-1
+        const K2: f16 = f16::from_bits(0xBD35); // -1.302e0
+
+        let r = horner!(t, t, [K1, K2]);
+        (r, K0)
+    }
+}
@@ -0,0 +1,44 @@
+impl crate::generic::ErfInv for f16 {
+    #[inline]
+    fn erfinv_poly(x2: Self) -> Self {
+        // GENERATE: erfinv::erfinv_poly f16 3 0.75
+        const K0: f16 = f16::from_bits(0x3B17); // 8.86e-1
+        const K1: f16 = f16::from_bits(0x33C2); // 2.424e-1
+        const K2: f16 = f16::from_bits(0x29FA); // 4.67e-2
+        const K3: f16 = f16::from_bits(0x343C); // 2.646e-1
+
+        K0 + horner!(x2, x2, [K1, K2, K3])
+    }
+
+    #[inline]
+    fn erfcinv_poly_1(t: Self) -> Self {
+        // GENERATE: erfinv::erfcinv_poly f16 4 1.17 3
+        const K0: f16 = f16::from_bits(0xB3AE); // -2.4e-1
+        const K1: f16 = f16::from_bits(0x39AB); // 7.085e-1
+        const K2: f16 = f16::from_bits(0x3331); // 2.247e-1
+        const K3: f16 = f16::from_bits(0xAC1F); // -6.44e-2
+        const K4: f16 = f16::from_bits(0x1EE7); // 6.74e-3
+
+        K0 + horner!(t, t, [K1, K2, K3, K4])
+    }
+
+    #[inline]
+    fn erfcinv_poly_2(t: Self) -> Self {
+        // GENERATE: erfinv::erfcinv_poly f16 2 3 6
+        const K0: f16 = f16::from_bits(0xB706); // -4.39e-1
+        const K1: f16 = f16::from_bits(0x3C40); // 1.0625e0
+        const K2: f16 = f16::from_bits(0x9BC4); // -3.792e-3
+
+        K0 + horner!(t, t, [K1, K2])
+    }
+
+    #[inline]
+    fn erfcinv_asympt_poly(u: Self) -> Self {
+        // GENERATE: erfinv::erfcinv_asympt_poly f16 2 6 10.2
+        const K0: f16 = f16::from_bits(0x3C04); // 1.004e0
+        const K1: f16 = f16::from_bits(0xB011); // -1.271e-1
+        const K2: f16 = f16::from_bits(0xB89B); // -5.757e-1
+
+        K0 + horner!(u, u, [K1, K2])
+    }
+}
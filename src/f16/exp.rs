@@ -0,0 +1,57 @@
+// GENERATE: exp::consts f16
+const LOG2_E: f16 = f16::from_bits(0x3DC5); // 1.442e0
+const LN_2_HI: f16 = f16::from_bits(0x3980); // 6.875e-1
+const LN_2_LO: f16 = f16::from_bits(0x1DC8); // 5.646e-3
+
+impl crate::generic::Exp for f16 {
+    #[inline]
+    fn log2_e() -> Self {
+        LOG2_E
+    }
+
+    #[inline]
+    fn ln_2_hi() -> Self {
+        LN_2_HI
+    }
+
+    #[inline]
+    fn ln_2_lo() -> Self {
+        LN_2_LO
+    }
+
+    #[inline]
+    fn exp_lo_th() -> Self {
+        -17.5
+    }
+
+    #[inline]
+    fn exp_hi_th() -> Self {
+        12.0
+    }
+
+    #[inline]
+    fn exp_m1_lo_th() -> Self {
+        -10.0
+    }
+
+    #[inline]
+    fn exp_m1_hi_th() -> Self {
+        12.0
+    }
+
+    #[inline]
+    fn exp_special_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V) -> V {
+        // GENERATE: exp::exp_special_poly f16 1
+        const K2: f16 = f16::from_bits(0xB153); // -1.664e-1
+
+        horner!(x2, x2, [K2])
+    }
+
+    #[inline]
+    fn exp_m1_special_poly(x2: Self) -> Self {
+        // GENERATE: exp::exp_m1_special_poly f16 1
+        const K2: f16 = f16::from_bits(0xA442); // -1.663e-2
+
+        1.0 + horner!(x2, x2, [K2])
+    }
+}
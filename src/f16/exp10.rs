@@ -0,0 +1,49 @@
+// GENERATE: exp10::consts f16
+const LOG2_10: f16 = f16::from_bits(0x42A5); // 3.322e0
+const LOG10_2_HI: f16 = f16::from_bits(0x34C0); // 2.969e-1
+const LOG10_2_LO: f16 = f16::from_bits(0x1C41); // 4.154e-3
+const LN_10: f16 = f16::from_bits(0x409B); // 2.303e0
+const LN_10_HI: f16 = f16::from_bits(0x4080); // 2.25e0
+const LN_10_LO: f16 = f16::from_bits(0x2ABB); // 5.258e-2
+
+impl crate::generic::Exp10 for f16 {
+    #[inline]
+    fn log2_10() -> Self {
+        LOG2_10
+    }
+
+    #[inline]
+    fn log10_2_hi() -> Self {
+        LOG10_2_HI
+    }
+
+    #[inline]
+    fn log10_2_lo() -> Self {
+        LOG10_2_LO
+    }
+
+    #[inline]
+    fn ln_10() -> Self {
+        LN_10
+    }
+
+    #[inline]
+    fn ln_10_hi() -> Self {
+        LN_10_HI
+    }
+
+    #[inline]
+    fn ln_10_lo() -> Self {
+        LN_10_LO
+    }
+
+    #[inline]
+    fn exp10_lo_th() -> Self {
+        -8.0
+    }
+
+    #[inline]
+    fn exp10_hi_th() -> Self {
+        5.0
+    }
+}
@@ -0,0 +1,19 @@
+// GENERATE: exp2::consts f16
+const LN_2: f16 = f16::from_bits(0x398C); // 6.934e-1
+
+impl crate::generic::Exp2 for f16 {
+    #[inline]
+    fn ln_2() -> Self {
+        LN_2
+    }
+
+    #[inline]
+    fn exp2_lo_th() -> Self {
+        -26.0
+    }
+
+    #[inline]
+    fn exp2_hi_th() -> Self {
+        17.0
+    }
+}
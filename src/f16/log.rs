@@ -0,0 +1,57 @@
+use crate::double::NormDouble;
+
+// GENERATE: ln::consts f16
+const SQRT_2: f16 = f16::from_bits(0x3DA8); // 1.414e0
+const LN_2_HI: f16 = f16::from_bits(0x3980); // 6.875e-1
+const LN_2_LO: f16 = f16::from_bits(0x1DC8); // 5.646e-3
+const FRAC_2_3_HI: f16 = f16::from_bits(0x3955); // 6.665e-1
+const FRAC_2_3_LO: f16 = f16::from_bits(0x0955); // 1.627e-4
+const FRAC_4_10_HI: f16 = f16::from_bits(0x3666); // 4e-1
+const FRAC_4_10_LO: f16 = f16::from_bits(0x0666); // 9.763e-5
+
+impl crate::generic::Ln for f16 {
+    #[inline]
+    fn sqrt_2() -> Self {
+        SQRT_2
+    }
+
+    #[inline]
+    fn ln_2_hi() -> Self {
+        LN_2_HI
+    }
+
+    #[inline]
+    fn ln_2_lo() -> Self {
+        LN_2_LO
+    }
+
+    #[inline]
+    fn frac_2_3_ex() -> NormDouble<Self> {
+        NormDouble::with_parts(FRAC_2_3_HI, FRAC_2_3_LO)
+    }
+
+    #[inline]
+    fn frac_4_10_ex() -> NormDouble<Self> {
+        NormDouble::with_parts(FRAC_4_10_HI, FRAC_4_10_LO)
+    }
+
+    #[inline]
+    fn ln_special_poly<V: crate::traits::Lanes<Scalar = Self>>(x: V) -> V {
+        // GENERATE: ln::ln_special_poly f16 3
+        const K2: f16 = f16::from_bits(0x3955); // 6.665e-1
+        const K4: f16 = f16::from_bits(0x3665); // 3.997e-1
+        const K6: f16 = f16::from_bits(0x34C8); // 2.988e-1
+
+        let x2 = x * x;
+        horner!(x2, x2, [K2, K4, K6])
+    }
+
+    #[inline]
+    fn ln_special_poly_ex<V: crate::traits::Lanes<Scalar = Self>>(x2: V) -> V {
+        // GENERATE: ln::ln_special_poly_ex f16 2
+        const K6: f16 = f16::from_bits(0x3492); // 2.856e-1
+        const K8: f16 = f16::from_bits(0x3367); // 2.313e-1
+
+        horner!(x2, x2, [K6, K8])
+    }
+}
@@ -0,0 +1,24 @@
+use crate::double::SemiDouble;
+
+// GENERATE: log10::consts f16
+const LOG10_E_HI: f16 = f16::from_bits(0x36C0); // 4.219e-1
+const LOG10_E_LO: f16 = f16::from_bits(0x225C); // 1.242e-2
+const LOG10_2_HI: f16 = f16::from_bits(0x34C0); // 2.969e-1
+const LOG10_2_LO: f16 = f16::from_bits(0x1C41); // 4.154e-3
+
+impl crate::generic::Log10 for f16 {
+    #[inline]
+    fn log10_e_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(LOG10_E_HI, LOG10_E_LO)
+    }
+
+    #[inline]
+    fn log10_2_hi() -> Self {
+        LOG10_2_HI
+    }
+
+    #[inline]
+    fn log10_2_lo() -> Self {
+        LOG10_2_LO
+    }
+}
@@ -0,0 +1,12 @@
+use crate::double::SemiDouble;
+
+// GENERATE: log2::consts f16
+const LOG2_E_HI: f16 = f16::from_bits(0x3DC0); // 1.4375e0
+const LOG2_E_LO: f16 = f16::from_bits(0x1D52); // 5.196e-3
+
+impl crate::generic::Log2 for f16 {
+    #[inline]
+    fn log2_e_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(LOG2_E_HI, LOG2_E_LO)
+    }
+}
@@ -0,0 +1,868 @@
+mod asin_acos;
+mod atan;
+mod bessel;
+mod div_pi;
+mod double_float;
+mod erf;
+mod erfinv;
+mod exp;
+mod exp10;
+mod exp2;
+mod log;
+mod log10;
+mod log2;
+mod rad_to_deg;
+mod reduce_90_deg;
+mod reduce_half_mul_pi;
+mod reduce_pi_2;
+mod sin_cos;
+mod sinh_cosh;
+mod tan;
+
+use crate::complex::Complex;
+use crate::slice::LANES;
+
+impl crate::traits::Lanes for f16 {
+    type Scalar = Self;
+
+    #[inline]
+    fn splat(value: Self) -> Self {
+        value
+    }
+
+    #[inline]
+    fn purify(self) -> Self {
+        if cfg!(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(target_feature = "sse2")
+        )) {
+            // Workaround X87 rounding issues
+            // `read_volatile` documentation says "Volatile operations are intended
+            // to act on I/O memory, and are guaranteed to not be elided or...". Not
+            // being elided means not being optimized away. Using `read_volatile::<f16>`
+            // guarantees that the returned value is the result of a 2-byte memory read,
+            // so it cannot have precision beyond a `f16`.
+            unsafe { core::ptr::read_volatile(&self) }
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn split_hi(self) -> Self {
+        crate::traits::float_split_hi(self)
+    }
+}
+
+impl crate::traits::Float for f16 {
+    type Raw = u16;
+
+    type RawExp = u8;
+
+    type Exp = i16;
+
+    const BITS: u8 = 16;
+    const MANT_BITS: u8 = 10;
+    const EXP_BITS: u8 = 5;
+
+    const SIGN_MASK: Self::Raw = 1 << (<Self as crate::traits::Float>::BITS - 1);
+    const EXP_MASK: Self::Raw = ((1 << Self::EXP_BITS) - 1) << Self::MANT_BITS;
+    const MANT_MASK: Self::Raw = (1 << Self::MANT_BITS) - 1;
+
+    const EXP_OFFSET: Self::RawExp = (1 << (Self::EXP_BITS - 1)) - 1;
+    const MAX_RAW_EXP: Self::RawExp = (Self::EXP_MASK >> Self::MANT_BITS) as Self::RawExp;
+
+    const MIN_NORMAL_EXP: Self::Exp = -<Self as crate::traits::Float>::MAX_EXP + 1;
+    const MAX_EXP: Self::Exp = (Self::MAX_RAW_EXP >> 1) as Self::Exp;
+
+    const INFINITY: Self = Self::INFINITY;
+
+    #[inline]
+    fn neg_infinity() -> Self {
+        Self::NEG_INFINITY
+    }
+
+    const NAN: Self = Self::NAN;
+
+    const ZERO: Self = 0.0;
+
+    #[inline]
+    fn half() -> Self {
+        0.5
+    }
+
+    #[inline]
+    fn one() -> Self {
+        1.0
+    }
+
+    #[inline]
+    fn two() -> Self {
+        2.0
+    }
+
+    #[cfg(test)]
+    #[inline]
+    fn largest() -> Self {
+        Self::MAX
+    }
+
+    #[inline]
+    fn to_raw(self) -> Self::Raw {
+        self.to_bits()
+    }
+
+    #[inline]
+    fn from_raw(raw: Self::Raw) -> Self {
+        Self::from_bits(raw)
+    }
+
+    #[inline]
+    fn raw_exp_to_exp(e: Self::RawExp) -> Self::Exp {
+        i16::from(e.wrapping_sub(Self::EXP_OFFSET) as i8)
+    }
+
+    #[inline]
+    fn exp_to_raw_exp(e: Self::Exp) -> Self::RawExp {
+        (e as Self::RawExp).wrapping_add(Self::EXP_OFFSET)
+    }
+
+    #[cfg(test)]
+    #[inline]
+    fn is_nan(self) -> bool {
+        self.is_nan()
+    }
+
+    #[inline]
+    fn abs(self) -> Self {
+        self.abs()
+    }
+
+    #[inline]
+    fn copysign(self, y: Self) -> Self {
+        self.copysign(y)
+    }
+
+    #[cfg(test)]
+    fn parse(s: &str) -> Self {
+        s.parse().unwrap()
+    }
+}
+
+impl crate::traits::FloatConsts for f16 {
+    // GENERATE: consts f16 PI FRAC_PI_2 FRAC_PI_4 FRAC_2_PI
+    const PI: f16 = f16::from_bits(0x4248); // 3.14e0
+    const FRAC_PI_2: f16 = f16::from_bits(0x3E48); // 1.57e0
+    const FRAC_PI_4: f16 = f16::from_bits(0x3A48); // 7.85e-1
+    const FRAC_2_PI: f16 = f16::from_bits(0x3918); // 6.367e-1
+}
+
+impl crate::sealed::SealedMath for f16 {
+    fn dd_from(x: Self) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_from(x)
+    }
+
+    fn dd_new(hi: Self, lo: Self) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_new(hi, lo)
+    }
+
+    fn dd_neg(x: crate::DoubleFloat<Self>) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_neg(x)
+    }
+
+    fn dd_add(
+        x: crate::DoubleFloat<Self>,
+        y: crate::DoubleFloat<Self>,
+    ) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_add(x, y)
+    }
+
+    fn dd_sub(
+        x: crate::DoubleFloat<Self>,
+        y: crate::DoubleFloat<Self>,
+    ) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_sub(x, y)
+    }
+
+    fn dd_mul(
+        x: crate::DoubleFloat<Self>,
+        y: crate::DoubleFloat<Self>,
+    ) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_mul(x, y)
+    }
+
+    fn dd_div(
+        x: crate::DoubleFloat<Self>,
+        y: crate::DoubleFloat<Self>,
+    ) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_div(x, y)
+    }
+
+    fn dd_sqrt(x: crate::DoubleFloat<Self>) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_sqrt(x)
+    }
+
+    fn dd_exp(x: crate::DoubleFloat<Self>) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_exp(x)
+    }
+
+    fn dd_ln(x: crate::DoubleFloat<Self>) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_ln(x)
+    }
+
+    fn dd_sin_cos(
+        x: crate::DoubleFloat<Self>,
+    ) -> (crate::DoubleFloat<Self>, crate::DoubleFloat<Self>) {
+        crate::generic::dd_sin_cos(x)
+    }
+
+    fn cr_exp(x: Self) -> Self {
+        crate::generic::cr_exp(x)
+    }
+
+    fn cr_exp_m1(x: Self) -> Self {
+        crate::generic::cr_exp_m1(x)
+    }
+
+    fn cr_exp2(x: Self) -> Self {
+        crate::generic::cr_exp2(x)
+    }
+
+    fn cr_exp10(x: Self) -> Self {
+        crate::generic::cr_exp10(x)
+    }
+
+    fn cr_ln(x: Self) -> Self {
+        crate::generic::cr_ln(x)
+    }
+
+    fn cr_ln_1p(x: Self) -> Self {
+        crate::generic::cr_ln_1p(x)
+    }
+
+    fn cr_log2(x: Self) -> Self {
+        crate::generic::cr_log2(x)
+    }
+
+    fn cr_log10(x: Self) -> Self {
+        crate::generic::cr_log10(x)
+    }
+
+    fn cr_pow(x: Self, y: Self) -> Self {
+        crate::generic::cr_pow(x, y)
+    }
+
+    fn cr_cbrt(x: Self) -> Self {
+        crate::generic::cr_cbrt(x)
+    }
+
+    fn cr_hypot(x: Self, y: Self) -> Self {
+        crate::generic::cr_hypot(x, y)
+    }
+
+    fn cr_sin(x: Self) -> Self {
+        crate::generic::cr_sin(x)
+    }
+
+    fn cr_cos(x: Self) -> Self {
+        crate::generic::cr_cos(x)
+    }
+
+    fn cr_tan(x: Self) -> Self {
+        crate::generic::cr_tan(x)
+    }
+
+    fn cr_asin(x: Self) -> Self {
+        crate::generic::cr_asin(x)
+    }
+
+    fn cr_acos(x: Self) -> Self {
+        crate::generic::cr_acos(x)
+    }
+
+    fn cr_atan(x: Self) -> Self {
+        crate::generic::cr_atan(x)
+    }
+
+    fn cr_atan2(y: Self, x: Self) -> Self {
+        crate::generic::cr_atan2(y, x)
+    }
+
+    fn cr_sinh(x: Self) -> Self {
+        crate::generic::cr_sinh(x)
+    }
+
+    fn cr_cosh(x: Self) -> Self {
+        crate::generic::cr_cosh(x)
+    }
+
+    fn cr_tanh(x: Self) -> Self {
+        crate::generic::cr_tanh(x)
+    }
+
+    fn c_neg(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_neg(z)
+    }
+
+    fn c_conj(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_conj(z)
+    }
+
+    fn c_add(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_add(x, y)
+    }
+
+    fn c_sub(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_sub(x, y)
+    }
+
+    fn c_mul(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_mul(c_to_f32(x), c_to_f32(y)))
+    }
+
+    fn c_div(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_div(c_to_f32(x), c_to_f32(y)))
+    }
+
+    fn c_exp(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_exp(c_to_f32(z)))
+    }
+
+    fn c_ln(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_ln(c_to_f32(z)))
+    }
+
+    fn c_sqrt(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_sqrt(c_to_f32(z)))
+    }
+
+    fn c_pow(z: Complex<Self>, w: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_pow(c_to_f32(z), c_to_f32(w)))
+    }
+
+    fn c_sin(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_sin(c_to_f32(z)))
+    }
+
+    fn c_cos(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_cos(c_to_f32(z)))
+    }
+
+    fn c_tan(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_tan(c_to_f32(z)))
+    }
+
+    fn c_sinh(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_sinh(c_to_f32(z)))
+    }
+
+    fn c_cosh(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_cosh(c_to_f32(z)))
+    }
+
+    fn c_tanh(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_tanh(c_to_f32(z)))
+    }
+
+    fn c_asin(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_asin(c_to_f32(z)))
+    }
+
+    fn c_acos(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_acos(c_to_f32(z)))
+    }
+
+    fn c_atan(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_atan(c_to_f32(z)))
+    }
+
+    fn c_asinh(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_asinh(c_to_f32(z)))
+    }
+
+    fn c_acosh(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_acosh(c_to_f32(z)))
+    }
+
+    fn c_atanh(z: Complex<Self>) -> Complex<Self> {
+        c_via_f32(crate::generic::c_atanh(c_to_f32(z)))
+    }
+
+    fn slice_exp(x: &mut [Self; LANES]) {
+        crate::generic::slice_exp(x);
+    }
+
+    fn slice_ln(x: &mut [Self; LANES]) {
+        crate::generic::slice_ln(x);
+    }
+
+    fn slice_sin(x: &mut [Self; LANES]) {
+        crate::generic::slice_sin(x);
+    }
+
+    fn slice_cos(x: &mut [Self; LANES]) {
+        crate::generic::slice_cos(x);
+    }
+
+    fn slice_sin_cos(sin: &mut [Self; LANES], cos: &mut [Self; LANES]) {
+        crate::generic::slice_sin_cos(sin, cos);
+    }
+}
+
+/// Rounds the result of a function evaluated in `f32` to `f16`.
+///
+/// Used for the functions whose `f16` evaluation cannot reach the accuracy
+/// of the `f32` and `f64` versions, because the low parts of double-`f16`
+/// values are often subnormal and intermediate results overflow easily.
+/// The error of the `f32` result is far below half an `f16` ULP, so the
+/// rounded result is almost always correctly rounded.
+#[inline]
+fn via_f32(r: f32) -> f16 {
+    r as f16
+}
+
+/// Like [`via_f32`], for functions that return an unrounded `(hi, lo)`
+/// result
+#[inline]
+fn hi_lo_via_f32((hi, lo): (f32, f32)) -> (f16, f16) {
+    let r_hi = hi as f16;
+    if !r_hi.is_finite() {
+        // infinity (including overflow) or NaN
+        return (r_hi, 0.0);
+    }
+    // `hi - r_hi` is exact, since `r_hi` is `hi` rounded to `f16`
+    let r_lo = ((hi - r_hi as f32) + lo) as f16;
+    if r_lo == 0.0 {
+        // also keeps the sign of zero `r_hi`
+        (r_hi, 0.0)
+    } else {
+        // renormalize, rounding `r_lo` can produce a tie in `r_hi + r_lo`
+        let s = r_hi + r_lo;
+        (s, r_lo - (s - r_hi))
+    }
+}
+
+#[inline]
+fn c_to_f32(z: Complex<f16>) -> Complex<f32> {
+    Complex::new(z.re as f32, z.im as f32)
+}
+
+/// Like [`via_f32`], for complex functions
+#[inline]
+fn c_via_f32(r: Complex<f32>) -> Complex<f16> {
+    Complex::new(r.re as f16, r.im as f16)
+}
+
+impl crate::ElementaryMath for f16 {
+    fn abs(x: Self) -> Self {
+        x.abs()
+    }
+
+    fn copysign(x: Self, y: Self) -> Self {
+        x.copysign(y)
+    }
+
+    fn round(x: Self) -> Self {
+        crate::generic::round(x)
+    }
+
+    fn trunc(x: Self) -> Self {
+        crate::generic::trunc(x)
+    }
+
+    fn ceil(x: Self) -> Self {
+        crate::generic::ceil(x)
+    }
+
+    fn floor(x: Self) -> Self {
+        crate::generic::floor(x)
+    }
+
+    fn scalbn(x: Self, y: i32) -> Self {
+        crate::generic::scalbn(x, y)
+    }
+
+    fn frexp(x: Self) -> (Self, i32) {
+        crate::generic::frexp(x)
+    }
+
+    fn fma(x: Self, y: Self, z: Self) -> Self {
+        crate::generic::fma(x, y, z)
+    }
+
+    fn fmod(x: Self, y: Self) -> Self {
+        crate::generic::fmod(x, y)
+    }
+
+    fn remainder(x: Self, y: Self) -> Self {
+        crate::generic::remainder(x, y)
+    }
+
+    fn remquo(x: Self, y: Self) -> (Self, i32) {
+        crate::generic::remquo(x, y)
+    }
+
+    fn hypot(x: Self, y: Self) -> Self {
+        crate::generic::hypot(x, y)
+    }
+
+    fn sqrt(x: Self) -> Self {
+        crate::generic::sqrt(x)
+    }
+
+    fn rsqrt(x: Self) -> Self {
+        // The result in `f64` is never close enough to a midpoint between two
+        // `f16` values to round incorrectly again
+        crate::generic::rsqrt(f64::from(x)) as f16
+    }
+
+    fn cbrt(x: Self) -> Self {
+        via_f32(crate::generic::cbrt(x as f32))
+    }
+
+    fn exp(x: Self) -> Self {
+        crate::generic::exp(x)
+    }
+
+    fn exp_m1(x: Self) -> Self {
+        via_f32(crate::generic::exp_m1(x as f32))
+    }
+
+    fn exp_hi_lo(x: Self) -> (Self, Self) {
+        hi_lo_via_f32(crate::generic::exp_hi_lo(x as f32))
+    }
+
+    fn exp2(x: Self) -> Self {
+        crate::generic::exp2(x)
+    }
+
+    fn exp2_m1(x: Self) -> Self {
+        via_f32(crate::generic::exp2_m1(x as f32))
+    }
+
+    fn exp10(x: Self) -> Self {
+        crate::generic::exp10(x)
+    }
+
+    fn exp10_m1(x: Self) -> Self {
+        via_f32(crate::generic::exp10_m1(x as f32))
+    }
+
+    fn ln(x: Self) -> Self {
+        crate::generic::ln(x)
+    }
+
+    fn ln_1p(x: Self) -> Self {
+        crate::generic::ln_1p(x)
+    }
+
+    fn ln_hi_lo(x: Self) -> (Self, Self) {
+        hi_lo_via_f32(crate::generic::ln_hi_lo(x as f32))
+    }
+
+    fn ln_1p_hi_lo(x: Self) -> (Self, Self) {
+        hi_lo_via_f32(crate::generic::ln_1p_hi_lo(x as f32))
+    }
+
+    fn log2(x: Self) -> Self {
+        crate::generic::log2(x)
+    }
+
+    fn log2_1p(x: Self) -> Self {
+        via_f32(crate::generic::log2_1p(x as f32))
+    }
+
+    fn log10(x: Self) -> Self {
+        crate::generic::log10(x)
+    }
+
+    fn log10_1p(x: Self) -> Self {
+        via_f32(crate::generic::log10_1p(x as f32))
+    }
+
+    fn pow(x: Self, y: Self) -> Self {
+        via_f32(crate::generic::pow(x as f32, y as f32))
+    }
+
+    fn powi(x: Self, y: i32) -> Self {
+        via_f32(crate::generic::powi(x as f32, y))
+    }
+
+    fn powr(x: Self, y: Self) -> Self {
+        via_f32(crate::generic::powr(x as f32, y as f32))
+    }
+
+    fn pown(x: Self, n: i64) -> Self {
+        via_f32(crate::generic::pown(x as f32, n))
+    }
+
+    fn rootn(x: Self, n: i64) -> Self {
+        via_f32(crate::generic::rootn(x as f32, n))
+    }
+
+    fn compound(x: Self, n: i64) -> Self {
+        via_f32(crate::generic::compound(x as f32, n))
+    }
+
+    fn sin(x: Self) -> Self {
+        crate::generic::sin(x)
+    }
+
+    fn cos(x: Self) -> Self {
+        crate::generic::cos(x)
+    }
+
+    fn sin_cos(x: Self) -> (Self, Self) {
+        crate::generic::sin_cos(x)
+    }
+
+    fn sin_cos_hi_lo(x: Self) -> ((Self, Self), (Self, Self)) {
+        let (sin, cos) = crate::generic::sin_cos_hi_lo(x as f32);
+        (hi_lo_via_f32(sin), hi_lo_via_f32(cos))
+    }
+
+    fn tan(x: Self) -> Self {
+        via_f32(crate::generic::tan(x as f32))
+    }
+
+    fn rem_pio2(x: Self) -> (i32, Self, Self) {
+        let (n, y_hi, y_lo) = crate::generic::rem_pio2(x as f32);
+        let (y_hi, y_lo) = hi_lo_via_f32((y_hi, y_lo));
+        (n, y_hi, y_lo)
+    }
+
+    fn wrap_angle(x: Self) -> Self {
+        via_f32(crate::generic::wrap_angle(x as f32))
+    }
+
+    fn sind(x: Self) -> Self {
+        crate::generic::sind(x)
+    }
+
+    fn cosd(x: Self) -> Self {
+        crate::generic::cosd(x)
+    }
+
+    fn sind_cosd(x: Self) -> (Self, Self) {
+        crate::generic::sind_cosd(x)
+    }
+
+    fn tand(x: Self) -> Self {
+        crate::generic::tand(x)
+    }
+
+    fn sinpi(x: Self) -> Self {
+        crate::generic::sinpi(x)
+    }
+
+    fn cospi(x: Self) -> Self {
+        crate::generic::cospi(x)
+    }
+
+    fn sinpi_cospi(x: Self) -> (Self, Self) {
+        crate::generic::sinpi_cospi(x)
+    }
+
+    fn tanpi(x: Self) -> Self {
+        crate::generic::tanpi(x)
+    }
+
+    fn asin(x: Self) -> Self {
+        crate::generic::asin(x)
+    }
+
+    fn acos(x: Self) -> Self {
+        crate::generic::acos(x)
+    }
+
+    fn atan(x: Self) -> Self {
+        crate::generic::atan(x)
+    }
+
+    fn atan2(y: Self, x: Self) -> Self {
+        crate::generic::atan2(y, x)
+    }
+
+    fn asind(x: Self) -> Self {
+        crate::generic::asind(x)
+    }
+
+    fn acosd(x: Self) -> Self {
+        crate::generic::acosd(x)
+    }
+
+    fn atand(x: Self) -> Self {
+        crate::generic::atand(x)
+    }
+
+    fn atan2d(y: Self, x: Self) -> Self {
+        crate::generic::atan2d(y, x)
+    }
+
+    fn asinpi(x: Self) -> Self {
+        crate::generic::asinpi(x)
+    }
+
+    fn acospi(x: Self) -> Self {
+        crate::generic::acospi(x)
+    }
+
+    fn atanpi(x: Self) -> Self {
+        crate::generic::atanpi(x)
+    }
+
+    fn atan2pi(y: Self, x: Self) -> Self {
+        via_f32(crate::generic::atan2pi(y as f32, x as f32))
+    }
+
+    fn sinh(x: Self) -> Self {
+        crate::generic::sinh(x)
+    }
+
+    fn cosh(x: Self) -> Self {
+        crate::generic::cosh(x)
+    }
+
+    fn sinh_cosh(x: Self) -> (Self, Self) {
+        crate::generic::sinh_cosh(x)
+    }
+
+    fn tanh(x: Self) -> Self {
+        crate::generic::tanh(x)
+    }
+
+    fn asinh(x: Self) -> Self {
+        crate::generic::asinh(x)
+    }
+
+    fn acosh(x: Self) -> Self {
+        crate::generic::acosh(x)
+    }
+
+    fn atanh(x: Self) -> Self {
+        via_f32(crate::generic::atanh(x as f32))
+    }
+}
+
+impl crate::FloatMath for f16 {
+    fn gamma(x: Self) -> Self {
+        via_f32(crate::generic::gamma(x as f32))
+    }
+
+    fn ln_gamma(x: Self) -> (Self, i8) {
+        let (r, s) = crate::generic::ln_gamma(x as f32);
+        (via_f32(r), s)
+    }
+
+    fn gamma_p(a: Self, x: Self) -> Self {
+        via_f32(crate::generic::gamma_p(a as f32, x as f32))
+    }
+
+    fn gamma_q(a: Self, x: Self) -> Self {
+        via_f32(crate::generic::gamma_q(a as f32, x as f32))
+    }
+
+    fn gamma_p_inv(a: Self, p: Self) -> Self {
+        via_f32(crate::generic::gamma_p_inv(a as f32, p as f32))
+    }
+
+    fn gamma_q_inv(a: Self, q: Self) -> Self {
+        via_f32(crate::generic::gamma_q_inv(a as f32, q as f32))
+    }
+
+    fn digamma(x: Self) -> Self {
+        via_f32(crate::generic::digamma(x as f32))
+    }
+
+    fn polygamma(n: i32, x: Self) -> Self {
+        via_f32(crate::generic::polygamma(n, x as f32))
+    }
+
+    fn zeta(s: Self) -> Self {
+        via_f32(crate::generic::zeta(s as f32))
+    }
+
+    fn hurwitz_zeta(s: Self, q: Self) -> Self {
+        via_f32(crate::generic::hurwitz_zeta(s as f32, q as f32))
+    }
+
+    fn beta(a: Self, b: Self) -> Self {
+        via_f32(crate::generic::beta(a as f32, b as f32))
+    }
+
+    fn ln_beta(a: Self, b: Self) -> (Self, i8) {
+        let (r, s) = crate::generic::ln_beta(a as f32, b as f32);
+        (via_f32(r), s)
+    }
+
+    fn beta_inc(a: Self, b: Self, x: Self) -> Self {
+        via_f32(crate::generic::beta_inc(a as f32, b as f32, x as f32))
+    }
+
+    fn beta_inc_inv(a: Self, b: Self, p: Self) -> Self {
+        via_f32(crate::generic::beta_inc_inv(a as f32, b as f32, p as f32))
+    }
+
+    fn erf(x: Self) -> Self {
+        via_f32(crate::generic::erf(x as f32))
+    }
+
+    fn erfc(x: Self) -> Self {
+        crate::generic::erfc(x)
+    }
+
+    fn erfinv(x: Self) -> Self {
+        crate::generic::erfinv(x)
+    }
+
+    fn erfcinv(x: Self) -> Self {
+        crate::generic::erfcinv(x)
+    }
+
+    fn j0(x: Self) -> Self {
+        crate::generic::j0(x)
+    }
+
+    fn j1(x: Self) -> Self {
+        crate::generic::j1(x)
+    }
+
+    fn jn(n: i32, x: Self) -> Self {
+        crate::generic::jn(n, x)
+    }
+
+    fn y0(x: Self) -> Self {
+        crate::generic::y0(x)
+    }
+
+    fn y1(x: Self) -> Self {
+        crate::generic::y1(x)
+    }
+
+    fn yn(n: i32, x: Self) -> Self {
+        via_f32(crate::generic::yn(n, x as f32))
+    }
+
+    fn lambert_w0(x: Self) -> Self {
+        via_f32(crate::generic::lambert_w0(x as f32))
+    }
+
+    fn lambert_wm1(x: Self) -> Self {
+        via_f32(crate::generic::lambert_wm1(x as f32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traits::Float as _;
+
+    #[test]
+    fn test_exp2i_fast() {
+        for e in -14..=15 {
+            let x = f16::exp2i_fast(e);
+            assert_eq!(x, f32::exp2(f32::from(e)) as f16);
+            assert_eq!(x.exponent(), e);
+            assert_eq!(x.to_bits() & f16::MANT_MASK, 0);
+        }
+    }
+}
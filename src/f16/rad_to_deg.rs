@@ -0,0 +1,12 @@
+use crate::double::SemiDouble;
+
+// GENERATE: rad_to_deg::consts f16
+const RAD_TO_DEG_HI: f16 = f16::from_bits(0x5300); // 5.6e1
+const RAD_TO_DEG_LO: f16 = f16::from_bits(0x3D2F); // 1.296e0
+
+impl crate::generic::RadToDeg for f16 {
+    #[inline]
+    fn rad_to_deg_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(RAD_TO_DEG_HI, RAD_TO_DEG_LO)
+    }
+}
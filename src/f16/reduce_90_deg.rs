@@ -0,0 +1,14 @@
+use crate::double::SemiDouble;
+
+// GENERATE: reduce_90_deg::consts f16
+const DEG_TO_RAD_HI: f16 = f16::from_bits(0x2440); // 1.66e-2
+const DEG_TO_RAD_LO: f16 = f16::from_bits(0x12FA); // 8.516e-4
+
+impl crate::generic::Reduce90Deg for f16 {
+    #[inline]
+    fn deg_to_rad_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(DEG_TO_RAD_HI, DEG_TO_RAD_LO)
+    }
+
+    type SRaw = i16;
+}
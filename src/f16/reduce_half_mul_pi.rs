@@ -0,0 +1,12 @@
+use crate::double::SemiDouble;
+
+// GENERATE: reduce_half_mul_pi::consts f16
+const PI_HI: f16 = f16::from_bits(0x4240); // 3.125e0
+const PI_LO: f16 = f16::from_bits(0x243F); // 1.659e-2
+
+impl crate::generic::ReduceHalfMulPi for f16 {
+    #[inline]
+    fn pi_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(PI_HI, PI_LO)
+    }
+}
@@ -0,0 +1,80 @@
+use crate::generic::scalbn;
+use crate::traits::{Float as _, FloatConsts as _};
+
+// GENERATE: reduce_pi_2::consts f16
+const FRAC_PI_2_HI: f16 = f16::from_bits(0x3E40); // 1.5625e0
+const FRAC_PI_2_HIEX: f16 = f16::from_bits(0x203F); // 8.29e-3
+const FRAC_PI_2_MI: f16 = f16::from_bits(0x2030); // 8.18e-3
+const FRAC_PI_2_MIEX: f16 = f16::from_bits(0x07B5); // 1.176e-4
+const FRAC_PI_2_LO: f16 = f16::from_bits(0x07B0); // 1.173e-4
+const FRAC_PI_2_LOEX: f16 = f16::from_bits(0x0005); // 3e-7
+
+impl crate::generic::ReducePi2 for f16 {
+    #[inline]
+    fn frac_pi_2_hi() -> Self {
+        FRAC_PI_2_HI
+    }
+
+    #[inline]
+    fn frac_pi_2_hiex() -> Self {
+        FRAC_PI_2_HIEX
+    }
+
+    #[inline]
+    fn frac_pi_2_mi() -> Self {
+        FRAC_PI_2_MI
+    }
+
+    #[inline]
+    fn frac_pi_2_miex() -> Self {
+        FRAC_PI_2_MIEX
+    }
+
+    #[inline]
+    fn frac_pi_2_lo() -> Self {
+        FRAC_PI_2_LO
+    }
+
+    #[inline]
+    fn frac_pi_2_loex() -> Self {
+        FRAC_PI_2_LOEX
+    }
+
+    #[inline]
+    fn max_reduce_pi_2_medium() -> Self {
+        // `FRAC_PI_2_LOEX` is subnormal, which limits the precision of
+        // the reduction for larger multiples of π/2
+        12.0 * f16::FRAC_PI_2
+    }
+
+    const REDUCE_PI_2_MEDIUM_TH1: i16 = 2;
+    const REDUCE_PI_2_MEDIUM_TH2: i16 = 8;
+
+    type SrcChunks = [u32; 1];
+
+    /// Returns `(x_chunks, e0, jk)`
+    fn reduce_pi_2_prepare(x: Self) -> ([u32; 1], i16, usize) {
+        // align the mantissa to a 24-bit chunk
+        let mant = x.mant();
+        let x_chunks = [u32::from(mant) << 13];
+        let e0 = x.exponent() - 23;
+        let jk = 3;
+        (x_chunks, e0, jk)
+    }
+
+    fn reduce_pi_2_compress(qp: &[u64], qe: i16, ih: u32) -> (Self, Self) {
+        // iw = sum(qp)
+        let mut iw = 0;
+        for &qp_i in qp.iter().rev() {
+            iw = (iw >> 24) + (qp_i << 6);
+        }
+
+        // the exponent range of `f16` is too narrow to scale the chunks
+        // of iw, so the sum is rounded to `f32` and split into y0, y1
+        let y = scalbn(iw as f32, i32::from(qe) - 6);
+        let y0 = y as f16;
+        let y1 = (y - y0 as f32) as f16;
+
+        if ih == 0 { (y0, y1) } else { (-y0, -y1) }
+    }
+}
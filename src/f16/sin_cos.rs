@@ -0,0 +1,39 @@
+use crate::double::SemiDouble;
+
+// GENERATE: sin_cos::consts f16
+const FRAC_1_6_HI: f16 = f16::from_bits(0x3140); // 1.641e-1
+const FRAC_1_6_LO: f16 = f16::from_bits(0x1955); // 2.604e-3
+
+impl crate::generic::SinCos for f16 {
+    #[inline]
+    fn frac_1_6_ex() -> SemiDouble<Self> {
+        SemiDouble::with_parts(FRAC_1_6_HI, FRAC_1_6_LO)
+    }
+
+    #[inline]
+    fn sin_poly<V: crate::traits::Lanes<Scalar = Self>>(_x2: V, x5: V) -> (V, Self) {
+        // GENERATE: sin_cos::sin_poly f16 2
+        const K3: f16 = f16::from_bits(0xB155); // -1.666e-1
+        const K5: f16 = f16::from_bits(0x202E); // 8.16e-3
+
+        let r = x5 * K5;
+        (r, K3)
+    }
+
+    #[inline]
+    fn sin_poly_ex(_x2: Self, x5: Self) -> Self {
+        // GENERATE: sin_cos::sin_poly_ex f16 1
+        const K5: f16 = f16::from_bits(0x2036); // 8.224e-3
+
+        x5 * K5
+    }
+
+    #[inline]
+    fn cos_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V, x4: V) -> V {
+        // GENERATE: sin_cos::cos_poly f16 2
+        const K4: f16 = f16::from_bits(0x2955); // 4.166e-2
+        const K6: f16 = f16::from_bits(0x9598); // -1.366e-3
+
+        horner!(x4, x2, [K4, K6])
+    }
+}
@@ -0,0 +1,6 @@
+impl crate::generic::SinhCosh for f16 {
+    #[inline]
+    fn expo2_hi_th() -> Self {
+        12.0
+    }
+}
@@ -0,0 +1,10 @@
+impl crate::generic::Tan for f16 {
+    #[inline]
+    fn tan_poly(x2: Self, x3: Self) -> Self {
+        // GENERATE: tan::tan_poly f16 2
+        const K3: f16 = f16::from_bits(0x3553); // 3.328e-1
+        const K5: f16 = f16::from_bits(0x30AB); // 1.459e-1
+
+        horner!(x3, x2, [K3, K5])
+    }
+}
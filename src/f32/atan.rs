@@ -20,7 +20,7 @@ impl crate::generic::Atan for f32 {
     }
 
     #[inline]
-    fn atan_poly(x2: Self) -> (Self, Self) {
+    fn atan_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V) -> (V, V) {
         // GENERATE: atan::atan_poly f32 9
         const K3: f32 = f32::from_bits(0xBEAAAA93); // -3.3333263e-1
         const K5: f32 = f32::from_bits(0x3E4CC690); // 1.999762e-1
@@ -33,6 +33,6 @@ impl crate::generic::Atan for f32 {
         const K19: f32 = f32::from_bits(0xBAD2081E); // -1.6024148e-3
 
         let t = horner!(x2, x2, [K5, K7, K9, K11, K13, K15, K17, K19]);
-        (K3, t)
+        (V::splat(K3), t)
     }
 }
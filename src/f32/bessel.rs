@@ -0,0 +1,246 @@
+use crate::double::NormDouble;
+
+impl crate::generic::Bessel for f32 {
+    #[inline]
+    fn frac_pi_4_ex() -> NormDouble<Self> {
+        // GENERATE: bessel::consts f32 FRAC_PI_4
+        const FRAC_PI_4_HI: f32 = f32::from_bits(0x3F490FDA); // 7.853981e-1
+        const FRAC_PI_4_LO: f32 = f32::from_bits(0x33222169); // 3.774895e-8
+
+        NormDouble::with_parts(FRAC_PI_4_HI, FRAC_PI_4_LO)
+    }
+
+    #[inline]
+    fn frac_2_pi_ex() -> NormDouble<Self> {
+        // GENERATE: bessel::consts f32 FRAC_2_PI
+        const FRAC_2_PI_HI: f32 = f32::from_bits(0x3F22F983); // 6.3661975e-1
+        const FRAC_2_PI_LO: f32 = f32::from_bits(0x32DC9C88); // 2.5682553e-8
+
+        NormDouble::with_parts(FRAC_2_PI_HI, FRAC_2_PI_LO)
+    }
+
+    #[inline]
+    fn sqrt_frac_2_pi_ex() -> NormDouble<Self> {
+        // GENERATE: bessel::consts f32 SQRT_FRAC_2_PI
+        const SQRT_FRAC_2_PI_HI: f32 = f32::from_bits(0x3F4C4229); // 7.978845e-1
+        const SQRT_FRAC_2_PI_LO: f32 = f32::from_bits(0x331EA1B3); // 3.6934306e-8
+
+        NormDouble::with_parts(SQRT_FRAC_2_PI_HI, SQRT_FRAC_2_PI_LO)
+    }
+
+    #[inline]
+    fn j0_small_poly(u: Self) -> (Self, Self) {
+        // GENERATE: bessel::j0_small_poly f32 3
+        const K3_HI: f32 = f32::from_bits(0xB9E38E38); // -4.3402775e-4
+        const K3_LO: f32 = f32::from_bits(0xADBAE727); // -2.1248404e-11
+        const K4: f32 = f32::from_bits(0x36E38DE7); // 6.781647e-6
+        const K5: f32 = f32::from_bits(0xB3918913); // -6.777022e-8
+        const K6: f32 = f32::from_bits(0x2FF892DE); // 4.5215293e-10
+
+        let r = K3_LO + horner!(u, u, [K4, K5, K6]);
+        (r, K3_HI)
+    }
+
+    #[inline]
+    fn j1_small_poly(u: Self) -> (Self, Self) {
+        // GENERATE: bessel::j1_small_poly f32 4
+        const K2_HI: f32 = f32::from_bits(0x3B2AAAAA); // 2.6041665e-3
+        const K2_LO: f32 = f32::from_bits(0x2F2A5994); // 1.5493234e-10
+        const K3: f32 = f32::from_bits(0xB8638E38); // -5.425347e-5
+        const K4: f32 = f32::from_bits(0x35360AE1); // 6.7816114e-7
+        const K5: f32 = f32::from_bits(0xB1C20176); // -5.646309e-9
+        const K6: f32 = f32::from_bits(0x2E0D7E63); // 3.2171942e-11
+
+        let r = K2_LO + horner!(u, u, [K3, K4, K5, K6]);
+        (r, K2_HI)
+    }
+
+    #[inline]
+    fn y0_small_poly(u: Self) -> (Self, NormDouble<Self>, NormDouble<Self>, Self) {
+        // GENERATE: bessel::y0_small_poly f32 6
+        const K0_HI: f32 = f32::from_bits(0xBD9726B4); // -7.380429e-2
+        const K0_LO: f32 = f32::from_bits(0xB1CE1A3B); // -5.998371e-9
+        const K1_HI: f32 = f32::from_bits(0x3E35DE5A); // 1.7760602e-1
+        const K1_LO: f32 = f32::from_bits(0x2F9A78A7); // 2.8098165e-10
+        const K2_HI: f32 = f32::from_bits(0xBC83AD8D); // -1.6073966e-2
+        const K2_LO: f32 = f32::from_bits(0xB06D26AC); // -8.6275054e-10
+        const K3: f32 = f32::from_bits(0x3A0D30F0); // 5.3860154e-4
+        const K4: f32 = f32::from_bits(0xB71F4932); // -9.494181e-6
+        const K5: f32 = f32::from_bits(0x33DDCB06); // 1.0328044e-7
+        const K6: f32 = f32::from_bits(0xB0441230); // -7.133023e-10
+
+        let r = K2_LO + horner!(u, u, [K3, K4, K5, K6]);
+        (
+            r,
+            NormDouble::with_parts(K0_HI, K0_LO),
+            NormDouble::with_parts(K1_HI, K1_LO),
+            K2_HI,
+        )
+    }
+
+    #[inline]
+    fn y1_small_poly(u: Self) -> (Self, NormDouble<Self>, NormDouble<Self>, Self) {
+        // GENERATE: bessel::y1_small_poly f32 5
+        const K0_HI: f32 = f32::from_bits(0xBE48C330); // -1.9605708e-1
+        const K0_LO: f32 = f32::from_bits(0xB22014C0); // -9.317944e-9
+        const K1_HI: f32 = f32::from_bits(0x3D5E9CBA); // 5.4348685e-2
+        const K1_LO: f32 = f32::from_bits(0x30C0DD21); // 1.4032687e-9
+        const K2_HI: f32 = f32::from_bits(0xBB41ADB3); // -2.9552996e-3
+        const K2_LO: f32 = f32::from_bits(0xAF42C801); // -1.7715253e-10
+        const K3: f32 = f32::from_bits(0x38963BA9); // 7.16367e-5
+        const K4: f32 = f32::from_bits(0xB584D829); // -9.897677e-7
+        const K5: f32 = f32::from_bits(0x320E36CD); // 8.277948e-9
+
+        let r = K2_LO + horner!(u, u, [K3, K4, K5]);
+        (
+            r,
+            NormDouble::with_parts(K0_HI, K0_LO),
+            NormDouble::with_parts(K1_HI, K1_LO),
+            K2_HI,
+        )
+    }
+
+    #[inline]
+    fn asympt0_poly_1(z: Self) -> (Self, Self, NormDouble<Self>, Self) {
+        // GENERATE: bessel::asympt_amp_poly f32 0 6 2 4
+        const A0: f32 = f32::from_bits(0xBBF474A4); // -7.4601937e-3
+        const A1: f32 = f32::from_bits(0xBD0E7EA1); // -3.478873e-2
+        const A2: f32 = f32::from_bits(0xBCD8A4DA); // -2.6445795e-2
+        const A3: f32 = f32::from_bits(0x3CF45B4F); // 2.9828696e-2
+        const A4: f32 = f32::from_bits(0xBC9ED1CD); // -1.938715e-2
+        const A5: f32 = f32::from_bits(0x3AFCF0A1); // 1.9297787e-3
+        const A6: f32 = f32::from_bits(0x3C96BFEC); // 1.8402062e-2
+
+        // GENERATE: bessel::asympt_phase_poly f32 0 6 2 4
+        const K0_HI: f32 = f32::from_bits(0xBD357943); // -4.4305097e-2
+        const K0_LO: f32 = f32::from_bits(0xB13D8AAD); // -2.7581948e-9
+        const K1_HI: f32 = f32::from_bits(0xBDDBBFF8); // -1.07299745e-1
+        const K1_LO: f32 = f32::from_bits(0xB00AD545); // -5.050718e-10
+        const K2: f32 = f32::from_bits(0x3D07AF0F); // 3.3125933e-2
+        const K3: f32 = f32::from_bits(0xBA0B878F); // -5.322629e-4
+        const K4: f32 = f32::from_bits(0xBC9092EE); // -1.7648187e-2
+        const K5: f32 = f32::from_bits(0x3CDD821A); // 2.7039576e-2
+        const K6: f32 = f32::from_bits(0xBCD8F4D6); // -2.6483934e-2
+
+        let a = A0 + horner!(z, z, [A1, A2, A3, A4, A5, A6]);
+        let r = K1_LO + horner!(z, z, [K2, K3, K4, K5, K6]);
+        (a, r, NormDouble::with_parts(K0_HI, K0_LO), K1_HI)
+    }
+
+    #[inline]
+    fn asympt0_poly_2(z: Self) -> (Self, Self, NormDouble<Self>, Self) {
+        // GENERATE: bessel::asympt_amp_poly f32 0 5 4 8
+        const A0: f32 = f32::from_bits(0xBB08C82C); // -2.0871265e-3
+        const A1: f32 = f32::from_bits(0xBCADF6A4); // -2.1235771e-2
+        const A2: f32 = f32::from_bits(0xBD40404B); // -4.6936315e-2
+        const A3: f32 = f32::from_bits(0x3D26DEA4); // 4.073967e-2
+        const A4: f32 = f32::from_bits(0x3B014880); // 1.9727051e-3
+        const A5: f32 = f32::from_bits(0xBD915007); // -7.095342e-2
+
+        // GENERATE: bessel::asympt_phase_poly f32 0 5 4 8
+        const K0_HI: f32 = f32::from_bits(0xBCBCCE44); // -2.3047574e-2
+        const K0_LO: f32 = f32::from_bits(0xB0E20D68); // -1.6447474e-9
+        const K1_HI: f32 = f32::from_bits(0xBDF3EE69); // -1.19107075e-1
+        const K1_LO: f32 = f32::from_bits(0xB1A8E817); // -4.915829e-9
+        const K2: f32 = f32::from_bits(0x3CE075D3); // 2.7399933e-2
+        const K3: f32 = f32::from_bits(0x3CD43F1A); // 2.5908995e-2
+        const K4: f32 = f32::from_bits(0xBD6907C6); // -5.689218e-2
+        const K5: f32 = f32::from_bits(0x3D40A7C6); // 4.7035e-2
+
+        let a = A0 + horner!(z, z, [A1, A2, A3, A4, A5]);
+        let r = K1_LO + horner!(z, z, [K2, K3, K4, K5]);
+        (a, r, NormDouble::with_parts(K0_HI, K0_LO), K1_HI)
+    }
+
+    #[inline]
+    fn asympt0_poly_3(u: Self) -> (Self, Self) {
+        // GENERATE: bessel::asympt_amp_tail_poly f32 0 3 8
+        const A1: f32 = f32::from_bits(0xBD7FFFFA); // -6.2499978e-2
+        const A2: f32 = f32::from_bits(0x3DD3E5EC); // 1.03465885e-1
+        const A3: f32 = f32::from_bits(0xBF067E9C); // -5.253694e-1
+        const A4: f32 = f32::from_bits(0x406ED8FF); // 3.7319944e0
+
+        // GENERATE: bessel::asympt_phase_tail_poly f32 0 3 8
+        const P1: f32 = f32::from_bits(0x3D855548); // 6.510407e-2
+        const P2: f32 = f32::from_bits(0xBE565F89); // -2.0934881e-1
+        const P3: f32 = f32::from_bits(0x3FC78C75); // 1.5589739e0
+        const P4: f32 = f32::from_bits(0xC159C443); // -1.3610415e1
+
+        let a = horner!(u, u, [A1, A2, A3, A4]);
+        let p = horner!(u, u, [P1, P2, P3, P4]);
+        (a, p)
+    }
+
+    #[inline]
+    fn asympt1_poly_1(z: Self) -> (Self, Self, NormDouble<Self>, Self) {
+        // GENERATE: bessel::asympt_amp_poly f32 1 7 2 4
+        const A0: f32 = f32::from_bits(0x3CC201B0); // 2.3682445e-2
+        const A1: f32 = f32::from_bits(0x3DEC92B1); // 1.1551417e-1
+        const A2: f32 = f32::from_bits(0x3DDE2AF5); // 1.0848037e-1
+        const A3: f32 = f32::from_bits(0xBD9EAA1C); // -7.7472895e-2
+        const A4: f32 = f32::from_bits(0x3CF5493C); // 2.9942147e-2
+        const A5: f32 = f32::from_bits(0x3C3182A6); // 1.0834372e-2
+        const A6: f32 = f32::from_bits(0xBD367011); // -4.454047e-2
+        const A7: f32 = f32::from_bits(0x3D877E68); // 6.615907e-2
+
+        // GENERATE: bessel::asympt_phase_poly f32 1 6 2 4
+        const K0_HI: f32 = f32::from_bits(0x3E08DB6A); // 1.336495e-1
+        const K0_LO: f32 = f32::from_bits(0x326E6566); // 1.3876479e-8
+        const K1_HI: f32 = f32::from_bits(0x3EA68319); // 3.2521895e-1
+        const K1_LO: f32 = f32::from_bits(0x319419E2); // 4.3103094e-9
+        const K2: f32 = f32::from_bits(0xBDCFE8D0); // -1.0151827e-1
+        const K3: f32 = f32::from_bits(0xBC8AC12C); // -1.6937815e-2
+        const K4: f32 = f32::from_bits(0x3D75A05A); // 5.9967376e-2
+        const K5: f32 = f32::from_bits(0xBD73E7AD); // -5.9547115e-2
+        const K6: f32 = f32::from_bits(0x3D130C12); // 3.5900183e-2
+
+        let a = A0 + horner!(z, z, [A1, A2, A3, A4, A5, A6, A7]);
+        let r = K1_LO + horner!(z, z, [K2, K3, K4, K5, K6]);
+        (a, r, NormDouble::with_parts(K0_HI, K0_LO), K1_HI)
+    }
+
+    #[inline]
+    fn asympt1_poly_2(z: Self) -> (Self, Self, NormDouble<Self>, Self) {
+        // GENERATE: bessel::asympt_amp_poly f32 1 5 4 8
+        const A0: f32 = f32::from_bits(0x3BD10F0B); // 6.379967e-3
+        const A1: f32 = f32::from_bits(0x3D87381A); // 6.6024974e-2
+        const A2: f32 = f32::from_bits(0x3E20362E); // 1.5645668e-1
+        const A3: f32 = f32::from_bits(0xBDB16815); // -8.66243e-2
+        const A4: f32 = f32::from_bits(0xBCD656D0); // -2.6164442e-2
+        const A5: f32 = f32::from_bits(0x3E0E697F); // 1.3907431e-1
+
+        // GENERATE: bessel::asympt_phase_poly f32 1 6 4 8
+        const K0_HI: f32 = f32::from_bits(0x3D8DEEC1); // 6.930304e-2
+        const K0_LO: f32 = f32::from_bits(0x30A30812); // 1.1862105e-9
+        const K1_HI: f32 = f32::from_bits(0x3EB8110F); // 3.5950515e-1
+        const K1_LO: f32 = f32::from_bits(0x32AF2451); // 2.0389196e-8
+        const K2: f32 = f32::from_bits(0xBD99356B); // -7.480892e-2
+        const K3: f32 = f32::from_bits(0xBDB1B618); // -8.67731e-2
+        const K4: f32 = f32::from_bits(0x3E02C281); // 1.276951e-1
+        const K5: f32 = f32::from_bits(0xBD6B1AD4); // -5.7398632e-2
+        const K6: f32 = f32::from_bits(0xBDFD8E07); // -1.2380605e-1
+
+        let a = A0 + horner!(z, z, [A1, A2, A3, A4, A5]);
+        let r = K1_LO + horner!(z, z, [K2, K3, K4, K5, K6]);
+        (a, r, NormDouble::with_parts(K0_HI, K0_LO), K1_HI)
+    }
+
+    #[inline]
+    fn asympt1_poly_3(u: Self) -> (Self, Self) {
+        // GENERATE: bessel::asympt_amp_tail_poly f32 1 3 8
+        const A1: f32 = f32::from_bits(0x3E3FFFFE); // 1.8749997e-1
+        const A2: f32 = f32::from_bits(0xBE45EF62); // -1.9329599e-1
+        const A3: f32 = f32::from_bits(0x3F487989); // 7.831045e-1
+        const A4: f32 = f32::from_bits(0xC0A2380D); // -5.069342e0
+
+        // GENERATE: bessel::asympt_phase_tail_poly f32 1 3 8
+        const P1: f32 = f32::from_bits(0xBE27FFF8); // -1.6406238e-1
+        const P2: f32 = f32::from_bits(0x3EBDC1AA); // 3.7061816e-1
+        const P3: f32 = f32::from_bits(0xC0114310); // -2.2697182e0
+        const P4: f32 = f32::from_bits(0x41923CEC); // 1.8279747e1
+
+        let a = horner!(u, u, [A1, A2, A3, A4]);
+        let p = horner!(u, u, [P1, P2, P3, P4]);
+        (a, p)
+    }
+}
@@ -0,0 +1,33 @@
+// GENERATE: double_float::consts f32
+const DD_LN_2_HI: f32 = f32::from_bits(0x3F317200); // 6.9314575e-1
+const DD_LN_2_MI: f32 = f32::from_bits(0x35BFBE00); // 1.4285906e-6
+const DD_LN_2_LO: f32 = f32::from_bits(0x2D8E7BCD); // 1.619851e-11
+const DD_LN_10_HI: f32 = f32::from_bits(0x40135D8D); // 2.302585e0
+const DD_LN_10_LO: f32 = f32::from_bits(0x345DAAA9); // 2.0644315e-7
+
+impl crate::generic::DoubleFloatMath for f32 {
+    #[inline]
+    fn dd_ln_2_hi() -> Self {
+        DD_LN_2_HI
+    }
+
+    #[inline]
+    fn dd_ln_2_mi() -> Self {
+        DD_LN_2_MI
+    }
+
+    #[inline]
+    fn dd_ln_2_lo() -> Self {
+        DD_LN_2_LO
+    }
+
+    #[inline]
+    fn dd_ln_10_hi() -> Self {
+        DD_LN_10_HI
+    }
+
+    #[inline]
+    fn dd_ln_10_lo() -> Self {
+        DD_LN_10_LO
+    }
+}
@@ -0,0 +1,107 @@
+use crate::double::NormDouble;
+
+impl crate::generic::Erf for f32 {
+    #[inline]
+    fn erf_one_th() -> Self {
+        4.0
+    }
+
+    #[inline]
+    fn erfc_hi_th() -> Self {
+        10.06
+    }
+
+    #[inline]
+    fn frac_1_sqrt_pi() -> NormDouble<Self> {
+        // GENERATE: erf::consts f32
+        const FRAC_1_SQRT_PI_HI: f32 = f32::from_bits(0x3F106EBA); // 5.6418955e-1
+        const FRAC_1_SQRT_PI_LO: f32 = f32::from_bits(0x330214DB); // 3.028695e-8
+
+        NormDouble::with_parts(FRAC_1_SQRT_PI_HI, FRAC_1_SQRT_PI_LO)
+    }
+
+    #[inline]
+    fn erf_poly(x2: Self) -> (Self, Self, Self, Self) {
+        // GENERATE: erf::erf_poly f32 7 1
+        const K0: f32 = f32::from_bits(0x3E0375D4); // 1.2837917e-1
+        const K1: f32 = f32::from_bits(0xBEC093A3); // -3.7612638e-1
+        const K2: f32 = f32::from_bits(0x3DE71785); // 1.1283783e-1
+        const K3: f32 = f32::from_bits(0xBCDC14EC); // -2.6865445e-2
+        const K4: f32 = f32::from_bits(0x3BAB158D); // 5.221075e-3
+        const K5: f32 = f32::from_bits(0xBA5E6BF0); // -8.484712e-4
+        const K6: f32 = f32::from_bits(0x38EC5C48); // 1.1270546e-4
+        const K7: f32 = f32::from_bits(0xB72267E2); // -9.68014e-6
+
+        let r = horner!(x2, x2, [K3, K4, K5, K6, K7]);
+        (r, K0, K1, K2)
+    }
+
+    #[inline]
+    fn erfc_poly_1(x: Self) -> (Self, NormDouble<Self>, Self) {
+        // GENERATE: erf::erfc_poly f32 7 0.5 1
+        const K0_HI: f32 = f32::from_bits(0x3F01C6AA); // 5.069376e-1
+        const K0_LO: f32 = f32::from_bits(0x32EA3D83); // 2.7269158e-8
+        const K1: f32 = f32::from_bits(0xBEBC66EB); // -3.679727e-1
+        const K2: f32 = f32::from_bits(0x3E6C804E); // 2.309582e-1
+        const K3: f32 = f32::from_bits(0xBE04F3C5); // -1.2983616e-1
+        const K4: f32 = f32::from_bits(0x3D88C50B); // 6.678208e-2
+        const K5: f32 = f32::from_bits(0xBD02A059); // -3.18912e-2
+        const K6: f32 = f32::from_bits(0x3C6E8865); // 1.4558886e-2
+        const K7: f32 = f32::from_bits(0xBBCAAD55); // -6.1852136e-3
+
+        let r = horner!(x, x, [K2, K3, K4, K5, K6, K7]);
+        (r, NormDouble::with_parts(K0_HI, K0_LO), K1)
+    }
+
+    #[inline]
+    fn erfc_poly_2(x: Self) -> (Self, NormDouble<Self>, Self) {
+        // GENERATE: erf::erfc_poly f32 8 1 2
+        const K0_HI: f32 = f32::from_bits(0x3EA4A6D7); // 3.215854e-1
+        const K0_LO: f32 = f32::from_bits(0x32FD156B); // 2.9462816e-8
+        const K1: f32 = f32::from_bits(0xBE278CC4); // -1.6362292e-1
+        const K2: f32 = f32::from_bits(0x3D9BF513); // 7.6151036e-2
+        const K3: f32 = f32::from_bits(0xBD06E2DF); // -3.2931205e-2
+        const K4: f32 = f32::from_bits(0x3C5B2DBA); // 1.3377601e-2
+        const K5: f32 = f32::from_bits(0xBBA86D99); // -5.140018e-3
+        const K6: f32 = f32::from_bits(0x3AF6CCF8); // 1.8829396e-3
+        const K7: f32 = f32::from_bits(0xBA375FD1); // -6.995174e-4
+        const K8: f32 = f32::from_bits(0x3979B9D7); // 2.3815721e-4
+
+        let r = horner!(x, x, [K2, K3, K4, K5, K6, K7, K8]);
+        (r, NormDouble::with_parts(K0_HI, K0_LO), K1)
+    }
+
+    #[inline]
+    fn erfc_poly_3(x: Self) -> (Self, NormDouble<Self>, Self) {
+        // GENERATE: erf::erfc_poly f32 10 2 4
+        const K0_HI: f32 = f32::from_bits(0x3E374C13); // 1.7900114e-1
+        const K0_LO: f32 = f32::from_bits(0x326914E9); // 1.3567139e-8
+        const K1: f32 = f32::from_bits(0xBD5EB572); // -5.437226e-2
+        const K2: f32 = f32::from_bits(0x3C821FF1); // 1.5884371e-2
+        const K3: f32 = f32::from_bits(0xBB92C821); // -4.4794236e-3
+        const K4: f32 = f32::from_bits(0x3AA04E30); // 1.2230333e-3
+        const K5: f32 = f32::from_bits(0xB9A9F74B); // -3.241844e-4
+        const K6: f32 = f32::from_bits(0x38AF48D3); // 8.358215e-5
+        const K7: f32 = f32::from_bits(0xB7AEB577); // -2.0826918e-5
+        const K8: f32 = f32::from_bits(0x36AACC92); // 5.09021e-6
+        const K9: f32 = f32::from_bits(0xB5BDCCCE); // -1.4141203e-6
+        const K10: f32 = f32::from_bits(0x34B5CF88); // 3.3864876e-7
+
+        let r = horner!(x, x, [K2, K3, K4, K5, K6, K7, K8, K9, K10]);
+        (r, NormDouble::with_parts(K0_HI, K0_LO), K1)
+    }
+
+    #[inline]
+    fn erfc_asympt_poly(t: Self) -> (Self, Self) {
+        // GENERATE: erf::erfc_asympt_poly f32 5 4 10.06
+        const K0: f32 = f32::from_bits(0xBEFFFFF5); // -4.9999967e-1
+        const K1: f32 = f32::from_bits(0x3F3FFB2D); // 7.499264e-1
+        const K2: f32 = f32::from_bits(0xBFEF28DF); // -1.8684348e0
+        const K3: f32 = f32::from_bits(0x40C87A4A); // 6.264928e0
+        const K4: f32 = f32::from_bits(0xC1AFC372); // -2.1970432e1
+        const K5: f32 = f32::from_bits(0x4246DDE2); // 4.9716682e1
+
+        let r = horner!(t, t, [K1, K2, K3, K4, K5]);
+        (r, K0)
+    }
+}
@@ -0,0 +1,50 @@
+impl crate::generic::ErfInv for f32 {
+    #[inline]
+    fn erfinv_poly(x2: Self) -> Self {
+        // GENERATE: erfinv::erfinv_poly f32 5 0.75
+        const K0: f32 = f32::from_bits(0x3F62DF67); // 8.8622135e-1
+        const K1: f32 = f32::from_bits(0x3E6E4242); // 2.3267463e-1
+        const K2: f32 = f32::from_bits(0x3DEB7CC6); // 1.1498408e-1
+        const K3: f32 = f32::from_bits(0x3E2FAC98); // 1.7155683e-1
+        const K4: f32 = f32::from_bits(0xBE381C0C); // -1.7979449e-1
+        const K5: f32 = f32::from_bits(0x3EA88922); // 3.2917124e-1
+
+        K0 + horner!(x2, x2, [K1, K2, K3, K4, K5])
+    }
+
+    #[inline]
+    fn erfcinv_poly_1(t: Self) -> Self {
+        // GENERATE: erfinv::erfcinv_poly f32 6 1.17 3
+        const K0: f32 = f32::from_bits(0xBDA4AC4F); // -8.040678e-2
+        const K1: f32 = f32::from_bits(0x3E67C5DF); // 2.2634076e-1
+        const K2: f32 = f32::from_bits(0x3F4F753B); // 8.1038254e-1
+        const K3: f32 = f32::from_bits(0xBEDC506B); // -4.3030104e-1
+        const K4: f32 = f32::from_bits(0x3E05AC22); // 1.3053945e-1
+        const K5: f32 = f32::from_bits(0xBCAFE166); // -2.1469783e-2
+        const K6: f32 = f32::from_bits(0x3AC2F441); // 1.4873819e-3
+
+        K0 + horner!(t, t, [K1, K2, K3, K4, K5, K6])
+    }
+
+    #[inline]
+    fn erfcinv_poly_2(t: Self) -> Self {
+        // GENERATE: erfinv::erfcinv_poly f32 3 3 6
+        const K0: f32 = f32::from_bits(0xBEF14040); // -4.711933e-1
+        const K1: f32 = f32::from_bits(0x3F8B0205); // 1.0859991e0
+        const K2: f32 = f32::from_bits(0xBC1775C8); // -9.24439e-3
+        const K3: f32 = f32::from_bits(0x39D8D28E); // 4.1355606e-4
+
+        K0 + horner!(t, t, [K1, K2, K3])
+    }
+
+    #[inline]
+    fn erfcinv_asympt_poly(u: Self) -> Self {
+        // GENERATE: erfinv::erfcinv_asympt_poly f32 3 6 10.2
+        const K0: f32 = f32::from_bits(0x3F802CF0); // 1.0013714e0
+        const K1: f32 = f32::from_bits(0xBD84067D); // -6.44655e-2
+        const K2: f32 = f32::from_bits(0xBF874D52); // -1.0570471e0
+        const K3: f32 = f32::from_bits(0x3F9B6802); // 1.2141116e0
+
+        K0 + horner!(u, u, [K1, K2, K3])
+    }
+}
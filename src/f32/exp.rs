@@ -40,7 +40,7 @@ impl crate::generic::Exp for f32 {
     }
 
     #[inline]
-    fn exp_special_poly(x2: Self) -> Self {
+    fn exp_special_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V) -> V {
         // GENERATE: exp::exp_special_poly f32 2
         const K2: f32 = f32::from_bits(0xBE2AAA8F); // -1.6666625e-1
         const K4: f32 = f32::from_bits(0x3B35526E); // 2.766754e-3
@@ -1,5 +1,11 @@
 use crate::double::NormDouble;
 
+// GENERATE: gamma::consts f32
+const HALF_LN_2_PI_HI: f32 = f32::from_bits(0x3F6B3F8E); // 9.189385e-1
+const HALF_LN_2_PI_LO: f32 = f32::from_bits(0x32864BEB); // 1.5634177e-8
+const EULER_GAMMA_HI: f32 = f32::from_bits(0x3F13C467); // 5.772156e-1
+const EULER_GAMMA_LO: f32 = f32::from_bits(0x33637DB1); // 5.2966872e-8
+
 impl crate::generic::Gamma for f32 {
     #[inline]
     fn lo_th() -> Self {
@@ -29,14 +35,30 @@ impl crate::generic::Gamma for f32 {
     const POLY_OFF: u8 = 3;
 
     #[inline]
-    fn half_ln_2_pi() -> NormDouble<Self> {
-        // GENERATE: gamma::consts f32
-        const HALF_LN_2_PI_HI: f32 = f32::from_bits(0x3F6B3F8E); // 9.189385e-1
-        const HALF_LN_2_PI_LO: f32 = f32::from_bits(0x32864BEB); // 1.5634177e-8
+    fn inc_gamma_small_x_th() -> Self {
+        1.1
+    }
+
+    #[inline]
+    fn inc_gamma_temme_th() -> Self {
+        20.0
+    }
+
+    #[inline]
+    fn inc_gamma_temme_sigma() -> Self {
+        0.4
+    }
 
+    #[inline]
+    fn half_ln_2_pi() -> NormDouble<Self> {
         NormDouble::with_parts(HALF_LN_2_PI_HI, HALF_LN_2_PI_LO)
     }
 
+    #[inline]
+    fn euler_gamma() -> NormDouble<Self> {
+        NormDouble::with_parts(EULER_GAMMA_HI, EULER_GAMMA_LO)
+    }
+
     #[inline]
     fn ln_gamma_poly_1(x: Self) -> (Self, Self, Self, Self) {
         // GENERATE: gamma::ln_gamma_poly f32 12 1 0.5 1.2001
@@ -92,4 +114,52 @@ impl crate::generic::Gamma for f32 {
 
         K0 + horner!(x, x, [K1, K2, K3, K4, K5, K6, K7, K8])
     }
+
+    #[inline]
+    fn inc_gamma_temme_coeffs() -> &'static [&'static [Self]] {
+        // GENERATE: gamma::inc_gamma_temme_coeffs f32 5 9
+        const C0_0: f32 = f32::from_bits(0xBEAAAAAB); // -3.3333334e-1
+        const C0_1: f32 = f32::from_bits(0x3DAAAAAB); // 8.3333336e-2
+        const C0_2: f32 = f32::from_bits(0xBC72B9D6); // -1.4814815e-2
+        const C0_3: f32 = f32::from_bits(0x3A97B426); // 1.1574074e-3
+        const C0_4: f32 = f32::from_bits(0x39B8EF1D); // 3.5273368e-4
+        const C0_5: f32 = f32::from_bits(0xB93B7038); // -1.7875514e-4
+        const C0_6: f32 = f32::from_bits(0x382462C5); // 3.9192633e-5
+        const C0_7: f32 = f32::from_bits(0xB612A9B8); // -2.1854485e-6
+        const C0_8: f32 = f32::from_bits(0xB5F8D918); // -1.8540622e-6
+        const C1_0: f32 = f32::from_bits(0xBAF2B9D6); // -1.8518518e-3
+        const C1_1: f32 = f32::from_bits(0xBB638E39); // -3.4722222e-3
+        const C1_2: f32 = f32::from_bits(0x3B2D602B); // 2.6455026e-3
+        const C1_3: f32 = f32::from_bits(0xBA81CA7B); // -9.902263e-4
+        const C1_4: f32 = f32::from_bits(0x3957C1A2); // 2.0576132e-4
+        const C1_5: f32 = f32::from_bits(0xB4D7C1A2); // -4.0187757e-7
+        const C1_6: f32 = f32::from_bits(0xB797D257); // -1.809855e-5
+        const C1_7: f32 = f32::from_bits(0x370054E5); // 7.649161e-6
+        const C2_0: f32 = f32::from_bits(0x3B877322); // 4.133598e-3
+        const C2_1: f32 = f32::from_bits(0xBB2FB934); // -2.681327e-3
+        const C2_2: f32 = f32::from_bits(0x3A4A4588); // 7.7160494e-4
+        const C2_3: f32 = f32::from_bits(0x3606D905); // 2.0093878e-6
+        const C2_4: f32 = f32::from_bits(0xB8E129F8); // -1.0736653e-4
+        const C2_5: f32 = f32::from_bits(0x385DFA1F); // 5.292345e-5
+        const C2_6: f32 = f32::from_bits(0xB7561683); // -1.2760635e-5
+        const C3_0: f32 = f32::from_bits(0x3A2A3ECA); // 6.4943417e-4
+        const C3_1: f32 = f32::from_bits(0x39709E72); // 2.2947209e-4
+        const C3_2: f32 = f32::from_bits(0xB9F5FD8C); // -4.691895e-4
+        const C3_3: f32 = f32::from_bits(0x398C5CDB); // 2.6772064e-4
+        const C3_4: f32 = f32::from_bits(0xB89E951D); // -7.5618016e-5
+        const C3_5: f32 = f32::from_bits(0xB480A951); // -2.3965052e-7
+        const C4_0: f32 = f32::from_bits(0xBA61F058); // -8.618883e-4
+        const C4_1: f32 = f32::from_bits(0x3A4D87FB); // 7.840392e-4
+        const C4_2: f32 = f32::from_bits(0xB99CCCD4); // -2.9907248e-4
+        const C4_3: f32 = f32::from_bits(0xB5C47957); // -1.4638453e-6
+        const C4_4: f32 = f32::from_bits(0x388B4846); // 6.6414985e-5
+
+        &[
+            &[C0_0, C0_1, C0_2, C0_3, C0_4, C0_5, C0_6, C0_7, C0_8],
+            &[C1_0, C1_1, C1_2, C1_3, C1_4, C1_5, C1_6, C1_7],
+            &[C2_0, C2_1, C2_2, C2_3, C2_4, C2_5, C2_6],
+            &[C3_0, C3_1, C3_2, C3_3, C3_4, C3_5],
+            &[C4_0, C4_1, C4_2, C4_3, C4_4],
+        ]
+    }
 }
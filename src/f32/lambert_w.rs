@@ -0,0 +1,25 @@
+use crate::double::NormDouble;
+
+impl crate::generic::LambertW for f32 {
+    #[inline]
+    fn frac_1_e() -> NormDouble<Self> {
+        // GENERATE: lambert_w::consts f32
+        const FRAC_1_E_HI: f32 = f32::from_bits(0x3EBC5AB1); // 3.6787942e-1
+        const FRAC_1_E_LO: f32 = f32::from_bits(0x32B1677A); // 2.0652568e-8
+
+        NormDouble::with_parts(FRAC_1_E_HI, FRAC_1_E_LO)
+    }
+
+    #[inline]
+    fn lambert_w_branch_poly(p: Self) -> Self {
+        // GENERATE: lambert_w::branch_poly f32 6
+        const K2: f32 = f32::from_bits(0xBEAAAAAB); // -3.3333334e-1
+        const K3: f32 = f32::from_bits(0x3E1C71C7); // 1.5277778e-1
+        const K4: f32 = f32::from_bits(0xBDA314DC); // -7.962963e-2
+        const K5: f32 = f32::from_bits(0x3D36480F); // 4.4502314e-2
+        const K6: f32 = f32::from_bits(0xBCD4DDE6); // -2.5984716e-2
+
+        let p2 = p * p;
+        horner!(p2, p, [K2, K3, K4, K5, K6])
+    }
+}
@@ -36,7 +36,7 @@ impl crate::generic::Ln for f32 {
     }
 
     #[inline]
-    fn ln_special_poly(x: Self) -> Self {
+    fn ln_special_poly<V: crate::traits::Lanes<Scalar = Self>>(x: V) -> V {
         // GENERATE: ln::ln_special_poly f32 4
         const K2: f32 = f32::from_bits(0x3F2AAAAA); // 6.666666e-1
         const K4: f32 = f32::from_bits(0x3ECCCD3D); // 4.0000334e-1
@@ -48,7 +48,7 @@ impl crate::generic::Ln for f32 {
     }
 
     #[inline]
-    fn ln_special_poly_ex(x2: Self) -> Self {
+    fn ln_special_poly_ex<V: crate::traits::Lanes<Scalar = Self>>(x2: V) -> V {
         // GENERATE: ln::ln_special_poly_ex f32 3
         const K6: f32 = f32::from_bits(0x3E92495E); // 2.85716e-1
         const K8: f32 = f32::from_bits(0x3E634F16); // 2.2198138e-1
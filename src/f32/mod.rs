@@ -1,14 +1,20 @@
 mod asin_acos;
 mod atan;
+mod bessel;
 mod cbrt;
 mod div_pi;
+mod double_float;
+mod erf;
+mod erfinv;
 mod exp;
 mod exp10;
 mod exp2;
 mod gamma;
+mod lambert_w;
 mod log;
 mod log10;
 mod log2;
+mod polygamma;
 mod rad_to_deg;
 mod reduce_90_deg;
 mod reduce_half_mul_pi;
@@ -17,6 +23,41 @@ mod sin_cos;
 mod sinh_cosh;
 mod tan;
 
+use crate::complex::Complex;
+use crate::slice::LANES;
+
+impl crate::traits::Lanes for f32 {
+    type Scalar = Self;
+
+    #[inline]
+    fn splat(value: Self) -> Self {
+        value
+    }
+
+    #[inline]
+    fn purify(self) -> Self {
+        if cfg!(all(
+            any(target_arch = "x86", target_arch = "x86_64"),
+            not(target_feature = "sse2")
+        )) {
+            // Workaround X87 rounding issues
+            // `read_volatile` documentation says "Volatile operations are intended
+            // to act on I/O memory, and are guaranteed to not be elided or...". Not
+            // being elided means not being optimized away. Using `read_volatile::<f32>`
+            // guarantees that the returned value is the result of a 4-byte memory read,
+            // so it cannot have precision beyond a `f32`.
+            unsafe { core::ptr::read_volatile(&self) }
+        } else {
+            self
+        }
+    }
+
+    #[inline]
+    fn split_hi(self) -> Self {
+        crate::traits::float_split_hi(self)
+    }
+}
+
 impl crate::traits::Float for f32 {
     type Raw = u32;
 
@@ -28,7 +69,7 @@ impl crate::traits::Float for f32 {
     const MANT_BITS: u8 = 23;
     const EXP_BITS: u8 = 8;
 
-    const SIGN_MASK: Self::Raw = 1 << (Self::BITS - 1);
+    const SIGN_MASK: Self::Raw = 1 << (<Self as crate::traits::Float>::BITS - 1);
     const EXP_MASK: Self::Raw = ((1 << Self::EXP_BITS) - 1) << Self::MANT_BITS;
     const MANT_MASK: Self::Raw = (1 << Self::MANT_BITS) - 1;
 
@@ -70,24 +111,6 @@ impl crate::traits::Float for f32 {
         Self::MAX
     }
 
-    #[inline]
-    fn purify(self) -> Self {
-        if cfg!(all(
-            any(target_arch = "x86", target_arch = "x86_64"),
-            not(target_feature = "sse2")
-        )) {
-            // Workaround X87 rounding issues
-            // `read_volatile` documentation says "Volatile operations are intended
-            // to act on I/O memory, and are guaranteed to not be elided or...". Not
-            // being elided means not being optimized away. Using `read_volatile::<f32>`
-            // guarantees that the returned value is the result of a 4-byte memory read,
-            // so it cannot have precision beyond a `f32`.
-            unsafe { core::ptr::read_volatile(&self) }
-        } else {
-            self
-        }
-    }
-
     #[inline]
     fn to_raw(self) -> Self::Raw {
         self.to_bits()
@@ -138,9 +161,259 @@ impl crate::traits::FloatConsts for f32 {
     const FRAC_2_PI: f32 = f32::from_bits(0x3F22F983); // 6.3661975e-1
 }
 
-impl crate::sealed::SealedMath for f32 {}
+impl crate::sealed::SealedMath for f32 {
+    fn dd_from(x: Self) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_from(x)
+    }
 
-impl crate::FloatMath for f32 {
+    fn dd_new(hi: Self, lo: Self) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_new(hi, lo)
+    }
+
+    fn dd_neg(x: crate::DoubleFloat<Self>) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_neg(x)
+    }
+
+    fn dd_add(
+        x: crate::DoubleFloat<Self>,
+        y: crate::DoubleFloat<Self>,
+    ) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_add(x, y)
+    }
+
+    fn dd_sub(
+        x: crate::DoubleFloat<Self>,
+        y: crate::DoubleFloat<Self>,
+    ) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_sub(x, y)
+    }
+
+    fn dd_mul(
+        x: crate::DoubleFloat<Self>,
+        y: crate::DoubleFloat<Self>,
+    ) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_mul(x, y)
+    }
+
+    fn dd_div(
+        x: crate::DoubleFloat<Self>,
+        y: crate::DoubleFloat<Self>,
+    ) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_div(x, y)
+    }
+
+    fn dd_sqrt(x: crate::DoubleFloat<Self>) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_sqrt(x)
+    }
+
+    fn dd_exp(x: crate::DoubleFloat<Self>) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_exp(x)
+    }
+
+    fn dd_ln(x: crate::DoubleFloat<Self>) -> crate::DoubleFloat<Self> {
+        crate::generic::dd_ln(x)
+    }
+
+    fn dd_sin_cos(
+        x: crate::DoubleFloat<Self>,
+    ) -> (crate::DoubleFloat<Self>, crate::DoubleFloat<Self>) {
+        crate::generic::dd_sin_cos(x)
+    }
+
+    fn cr_exp(x: Self) -> Self {
+        crate::generic::cr_exp(x)
+    }
+
+    fn cr_exp_m1(x: Self) -> Self {
+        crate::generic::cr_exp_m1(x)
+    }
+
+    fn cr_exp2(x: Self) -> Self {
+        crate::generic::cr_exp2(x)
+    }
+
+    fn cr_exp10(x: Self) -> Self {
+        crate::generic::cr_exp10(x)
+    }
+
+    fn cr_ln(x: Self) -> Self {
+        crate::generic::cr_ln(x)
+    }
+
+    fn cr_ln_1p(x: Self) -> Self {
+        crate::generic::cr_ln_1p(x)
+    }
+
+    fn cr_log2(x: Self) -> Self {
+        crate::generic::cr_log2(x)
+    }
+
+    fn cr_log10(x: Self) -> Self {
+        crate::generic::cr_log10(x)
+    }
+
+    fn cr_pow(x: Self, y: Self) -> Self {
+        crate::generic::cr_pow(x, y)
+    }
+
+    fn cr_cbrt(x: Self) -> Self {
+        crate::generic::cr_cbrt(x)
+    }
+
+    fn cr_hypot(x: Self, y: Self) -> Self {
+        crate::generic::cr_hypot(x, y)
+    }
+
+    fn cr_sin(x: Self) -> Self {
+        crate::generic::cr_sin(x)
+    }
+
+    fn cr_cos(x: Self) -> Self {
+        crate::generic::cr_cos(x)
+    }
+
+    fn cr_tan(x: Self) -> Self {
+        crate::generic::cr_tan(x)
+    }
+
+    fn cr_asin(x: Self) -> Self {
+        crate::generic::cr_asin(x)
+    }
+
+    fn cr_acos(x: Self) -> Self {
+        crate::generic::cr_acos(x)
+    }
+
+    fn cr_atan(x: Self) -> Self {
+        crate::generic::cr_atan(x)
+    }
+
+    fn cr_atan2(y: Self, x: Self) -> Self {
+        crate::generic::cr_atan2(y, x)
+    }
+
+    fn cr_sinh(x: Self) -> Self {
+        crate::generic::cr_sinh(x)
+    }
+
+    fn cr_cosh(x: Self) -> Self {
+        crate::generic::cr_cosh(x)
+    }
+
+    fn cr_tanh(x: Self) -> Self {
+        crate::generic::cr_tanh(x)
+    }
+
+    fn c_neg(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_neg(z)
+    }
+
+    fn c_conj(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_conj(z)
+    }
+
+    fn c_add(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_add(x, y)
+    }
+
+    fn c_sub(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_sub(x, y)
+    }
+
+    fn c_mul(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_mul(x, y)
+    }
+
+    fn c_div(x: Complex<Self>, y: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_div(x, y)
+    }
+
+    fn c_exp(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_exp(z)
+    }
+
+    fn c_ln(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_ln(z)
+    }
+
+    fn c_sqrt(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_sqrt(z)
+    }
+
+    fn c_pow(z: Complex<Self>, w: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_pow(z, w)
+    }
+
+    fn c_sin(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_sin(z)
+    }
+
+    fn c_cos(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_cos(z)
+    }
+
+    fn c_tan(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_tan(z)
+    }
+
+    fn c_sinh(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_sinh(z)
+    }
+
+    fn c_cosh(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_cosh(z)
+    }
+
+    fn c_tanh(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_tanh(z)
+    }
+
+    fn c_asin(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_asin(z)
+    }
+
+    fn c_acos(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_acos(z)
+    }
+
+    fn c_atan(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_atan(z)
+    }
+
+    fn c_asinh(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_asinh(z)
+    }
+
+    fn c_acosh(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_acosh(z)
+    }
+
+    fn c_atanh(z: Complex<Self>) -> Complex<Self> {
+        crate::generic::c_atanh(z)
+    }
+
+    fn slice_exp(x: &mut [Self; LANES]) {
+        crate::generic::slice_exp(x);
+    }
+
+    fn slice_ln(x: &mut [Self; LANES]) {
+        crate::generic::slice_ln(x);
+    }
+
+    fn slice_sin(x: &mut [Self; LANES]) {
+        crate::generic::slice_sin(x);
+    }
+
+    fn slice_cos(x: &mut [Self; LANES]) {
+        crate::generic::slice_cos(x);
+    }
+
+    fn slice_sin_cos(sin: &mut [Self; LANES], cos: &mut [Self; LANES]) {
+        crate::generic::slice_sin_cos(sin, cos);
+    }
+}
+
+impl crate::ElementaryMath for f32 {
     fn abs(x: Self) -> Self {
         x.abs()
     }
@@ -173,6 +446,22 @@ impl crate::FloatMath for f32 {
         crate::generic::frexp(x)
     }
 
+    fn fma(x: Self, y: Self, z: Self) -> Self {
+        crate::generic::fma(x, y, z)
+    }
+
+    fn fmod(x: Self, y: Self) -> Self {
+        crate::generic::fmod(x, y)
+    }
+
+    fn remainder(x: Self, y: Self) -> Self {
+        crate::generic::remainder(x, y)
+    }
+
+    fn remquo(x: Self, y: Self) -> (Self, i32) {
+        crate::generic::remquo(x, y)
+    }
+
     fn hypot(x: Self, y: Self) -> Self {
         crate::generic::hypot(x, y)
     }
@@ -181,6 +470,10 @@ impl crate::FloatMath for f32 {
         crate::generic::sqrt(x)
     }
 
+    fn rsqrt(x: Self) -> Self {
+        crate::generic::rsqrt(x)
+    }
+
     fn cbrt(x: Self) -> Self {
         crate::generic::cbrt(x)
     }
@@ -193,14 +486,26 @@ impl crate::FloatMath for f32 {
         crate::generic::exp_m1(x)
     }
 
+    fn exp_hi_lo(x: Self) -> (Self, Self) {
+        crate::generic::exp_hi_lo(x)
+    }
+
     fn exp2(x: Self) -> Self {
         crate::generic::exp2(x)
     }
 
+    fn exp2_m1(x: Self) -> Self {
+        crate::generic::exp2_m1(x)
+    }
+
     fn exp10(x: Self) -> Self {
         crate::generic::exp10(x)
     }
 
+    fn exp10_m1(x: Self) -> Self {
+        crate::generic::exp10_m1(x)
+    }
+
     fn ln(x: Self) -> Self {
         crate::generic::ln(x)
     }
@@ -209,14 +514,30 @@ impl crate::FloatMath for f32 {
         crate::generic::ln_1p(x)
     }
 
+    fn ln_hi_lo(x: Self) -> (Self, Self) {
+        crate::generic::ln_hi_lo(x)
+    }
+
+    fn ln_1p_hi_lo(x: Self) -> (Self, Self) {
+        crate::generic::ln_1p_hi_lo(x)
+    }
+
     fn log2(x: Self) -> Self {
         crate::generic::log2(x)
     }
 
+    fn log2_1p(x: Self) -> Self {
+        crate::generic::log2_1p(x)
+    }
+
     fn log10(x: Self) -> Self {
         crate::generic::log10(x)
     }
 
+    fn log10_1p(x: Self) -> Self {
+        crate::generic::log10_1p(x)
+    }
+
     fn pow(x: Self, y: Self) -> Self {
         crate::generic::pow(x, y)
     }
@@ -225,6 +546,22 @@ impl crate::FloatMath for f32 {
         crate::generic::powi(x, y)
     }
 
+    fn powr(x: Self, y: Self) -> Self {
+        crate::generic::powr(x, y)
+    }
+
+    fn pown(x: Self, n: i64) -> Self {
+        crate::generic::pown(x, n)
+    }
+
+    fn rootn(x: Self, n: i64) -> Self {
+        crate::generic::rootn(x, n)
+    }
+
+    fn compound(x: Self, n: i64) -> Self {
+        crate::generic::compound(x, n)
+    }
+
     fn sin(x: Self) -> Self {
         crate::generic::sin(x)
     }
@@ -237,10 +574,22 @@ impl crate::FloatMath for f32 {
         crate::generic::sin_cos(x)
     }
 
+    fn sin_cos_hi_lo(x: Self) -> ((Self, Self), (Self, Self)) {
+        crate::generic::sin_cos_hi_lo(x)
+    }
+
     fn tan(x: Self) -> Self {
         crate::generic::tan(x)
     }
 
+    fn rem_pio2(x: Self) -> (i32, Self, Self) {
+        crate::generic::rem_pio2(x)
+    }
+
+    fn wrap_angle(x: Self) -> Self {
+        crate::generic::wrap_angle(x)
+    }
+
     fn sind(x: Self) -> Self {
         crate::generic::sind(x)
     }
@@ -348,7 +697,9 @@ impl crate::FloatMath for f32 {
     fn atanh(x: Self) -> Self {
         crate::generic::atanh(x)
     }
+}
 
+impl crate::FloatMath for f32 {
     fn gamma(x: Self) -> Self {
         crate::generic::gamma(x)
     }
@@ -356,6 +707,102 @@ impl crate::FloatMath for f32 {
     fn ln_gamma(x: Self) -> (Self, i8) {
         crate::generic::ln_gamma(x)
     }
+
+    fn gamma_p(a: Self, x: Self) -> Self {
+        crate::generic::gamma_p(a, x)
+    }
+
+    fn gamma_q(a: Self, x: Self) -> Self {
+        crate::generic::gamma_q(a, x)
+    }
+
+    fn gamma_p_inv(a: Self, p: Self) -> Self {
+        crate::generic::gamma_p_inv(a, p)
+    }
+
+    fn gamma_q_inv(a: Self, q: Self) -> Self {
+        crate::generic::gamma_q_inv(a, q)
+    }
+
+    fn digamma(x: Self) -> Self {
+        crate::generic::digamma(x)
+    }
+
+    fn polygamma(n: i32, x: Self) -> Self {
+        crate::generic::polygamma(n, x)
+    }
+
+    fn zeta(s: Self) -> Self {
+        crate::generic::zeta(s)
+    }
+
+    fn hurwitz_zeta(s: Self, q: Self) -> Self {
+        crate::generic::hurwitz_zeta(s, q)
+    }
+
+    fn beta(a: Self, b: Self) -> Self {
+        crate::generic::beta(a, b)
+    }
+
+    fn ln_beta(a: Self, b: Self) -> (Self, i8) {
+        crate::generic::ln_beta(a, b)
+    }
+
+    fn beta_inc(a: Self, b: Self, x: Self) -> Self {
+        crate::generic::beta_inc(a, b, x)
+    }
+
+    fn beta_inc_inv(a: Self, b: Self, p: Self) -> Self {
+        crate::generic::beta_inc_inv(a, b, p)
+    }
+
+    fn erf(x: Self) -> Self {
+        crate::generic::erf(x)
+    }
+
+    fn erfc(x: Self) -> Self {
+        crate::generic::erfc(x)
+    }
+
+    fn erfinv(x: Self) -> Self {
+        crate::generic::erfinv(x)
+    }
+
+    fn erfcinv(x: Self) -> Self {
+        crate::generic::erfcinv(x)
+    }
+
+    fn j0(x: Self) -> Self {
+        crate::generic::j0(x)
+    }
+
+    fn j1(x: Self) -> Self {
+        crate::generic::j1(x)
+    }
+
+    fn jn(n: i32, x: Self) -> Self {
+        crate::generic::jn(n, x)
+    }
+
+    fn y0(x: Self) -> Self {
+        crate::generic::y0(x)
+    }
+
+    fn y1(x: Self) -> Self {
+        crate::generic::y1(x)
+    }
+
+    fn yn(n: i32, x: Self) -> Self {
+        crate::generic::yn(n, x)
+    }
+
+    fn lambert_w0(x: Self) -> Self {
+        crate::generic::lambert_w0(x)
+    }
+
+    fn lambert_wm1(x: Self) -> Self {
+        crate::generic::lambert_wm1(x)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,57 @@
+use crate::double::NormDouble;
+
+impl crate::generic::Polygamma for f32 {
+    #[inline]
+    fn digamma_asympt_th() -> Self {
+        6.0
+    }
+
+    #[inline]
+    fn digamma_root() -> NormDouble<Self> {
+        // GENERATE: polygamma::consts f32
+        const ROOT_HI: f32 = f32::from_bits(0x3FBB16C3); // 1.4616321e0
+        const ROOT_LO: f32 = f32::from_bits(0x3255AF90); // 1.243815e-8
+
+        NormDouble::with_parts(ROOT_HI, ROOT_LO)
+    }
+
+    #[inline]
+    fn digamma_root_poly(x: Self) -> (Self, NormDouble<Self>) {
+        // GENERATE: polygamma::digamma_root_poly f32 6 0.0625
+        const K1_HI: f32 = f32::from_bits(0x3F77B95E); // 9.676722e-1
+        const K1_LO: f32 = f32::from_bits(0x328EE37B); // 1.6634422e-8
+        const K2: f32 = f32::from_bits(0xBEE2B1DB); // -4.4276318e-1
+        const K3: f32 = f32::from_bits(0x3E845A15); // 2.5849977e-1
+        const K4: f32 = f32::from_bits(0xBE27E07B); // -1.6394226e-1
+        const K5: f32 = f32::from_bits(0x3DDCD2A7); // 1.0782366e-1
+        const K6: f32 = f32::from_bits(0xBD94547D); // -7.242677e-2
+        const K7: f32 = f32::from_bits(0x3D4898A0); // 4.897368e-2
+
+        let r = horner!(x, x, [K2, K3, K4, K5, K6, K7]);
+
+        (r, NormDouble::with_parts(K1_HI, K1_LO))
+    }
+
+    #[inline]
+    fn digamma_asympt_poly(x: Self) -> Self {
+        // GENERATE: polygamma::digamma_asympt_poly f32 3 6
+        const K0: f32 = f32::from_bits(0x3DAAAAAB); // 8.3333336e-2
+        const K1: f32 = f32::from_bits(0xBC088863); // -8.333298e-3
+        const K2: f32 = f32::from_bits(0x3B81D2D2); // 3.9618993e-3
+        const K3: f32 = f32::from_bits(0xBB782EFE); // -3.7869806e-3
+
+        K0 + horner!(x, x, [K1, K2, K3])
+    }
+
+    #[inline]
+    fn polygamma_em_coeffs() -> &'static [Self] {
+        // GENERATE: polygamma::em_coeffs f32 5
+        const B1: f32 = f32::from_bits(0x3DAAAAAB); // 8.3333336e-2
+        const B2: f32 = f32::from_bits(0xBAB60B61); // -1.3888889e-3
+        const B3: f32 = f32::from_bits(0x380AB356); // 3.3068784e-5
+        const B4: f32 = f32::from_bits(0xB55DEBBD); // -8.267196e-7
+        const B5: f32 = f32::from_bits(0x32B35479); // 2.0876756e-8
+
+        &[B1, B2, B3, B4, B5]
+    }
+}
@@ -1,5 +1,5 @@
 use crate::generic::scalbn_medium;
-use crate::traits::{Float as _, FloatConsts as _};
+use crate::traits::{Float as _, FloatConsts as _, Lanes as _};
 
 // GENERATE: reduce_pi_2::consts f32
 const FRAC_PI_2_HI: f32 = f32::from_bits(0x3FC90E00); // 1.5707397e0
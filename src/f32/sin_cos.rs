@@ -11,7 +11,7 @@ impl crate::generic::SinCos for f32 {
     }
 
     #[inline]
-    fn sin_poly(x2: Self, x5: Self) -> (Self, Self) {
+    fn sin_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V, x5: V) -> (V, Self) {
         // GENERATE: sin_cos::sin_poly f32 3
         const K3: f32 = f32::from_bits(0xBE2AAAA3); // -1.6666655e-1
         const K5: f32 = f32::from_bits(0x3C0883AC); // 8.332174e-3
@@ -31,7 +31,7 @@ impl crate::generic::SinCos for f32 {
     }
 
     #[inline]
-    fn cos_poly(x2: Self, x4: Self) -> Self {
+    fn cos_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V, x4: V) -> V {
         // GENERATE: sin_cos::cos_poly f32 3
         const K4: f32 = f32::from_bits(0x3D2AAAA5); // 4.1666646e-2
         const K6: f32 = f32::from_bits(0xBAB60642); // -1.3887363e-3
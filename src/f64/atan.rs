@@ -20,7 +20,7 @@ impl crate::generic::Atan for f64 {
     }
 
     #[inline]
-    fn atan_poly(x2: Self) -> (Self, Self) {
+    fn atan_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V) -> (V, V) {
         // GENERATE: atan::atan_poly f64 20
         const K3: f64 = f64::from_bits(0xBFD55555555554A8); // -3.333333333333237e-1
         const K5: f64 = f64::from_bits(0x3FC999999998DDDB); // 1.99999999998666e-1
@@ -51,6 +51,6 @@ impl crate::generic::Atan for f64 {
                 K39, K41
             ]
         );
-        (K3, t)
+        (V::splat(K3), t)
     }
 }
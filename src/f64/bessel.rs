@@ -0,0 +1,438 @@
+use crate::double::NormDouble;
+
+impl crate::generic::Bessel for f64 {
+    #[inline]
+    fn frac_pi_4_ex() -> NormDouble<Self> {
+        // GENERATE: bessel::consts f64 FRAC_PI_4
+        const FRAC_PI_4_HI: f64 = f64::from_bits(0x3FE921FB54442D18); // 7.853981633974483e-1
+        const FRAC_PI_4_LO: f64 = f64::from_bits(0x3C81A62633145C07); // 3.061616997868383e-17
+
+        NormDouble::with_parts(FRAC_PI_4_HI, FRAC_PI_4_LO)
+    }
+
+    #[inline]
+    fn frac_2_pi_ex() -> NormDouble<Self> {
+        // GENERATE: bessel::consts f64 FRAC_2_PI
+        const FRAC_2_PI_HI: f64 = f64::from_bits(0x3FE45F306DC9C882); // 6.366197723675813e-1
+        const FRAC_2_PI_LO: f64 = f64::from_bits(0x3C94A7F09D5F47D5); // 7.166494911215068e-17
+
+        NormDouble::with_parts(FRAC_2_PI_HI, FRAC_2_PI_LO)
+    }
+
+    #[inline]
+    fn sqrt_frac_2_pi_ex() -> NormDouble<Self> {
+        // GENERATE: bessel::consts f64 SQRT_FRAC_2_PI
+        const SQRT_FRAC_2_PI_HI: f64 = f64::from_bits(0x3FE9884533D43650); // 7.978845608028653e-1
+        const SQRT_FRAC_2_PI_LO: f64 = f64::from_bits(0x3C91A1F9678A0175); // 6.117575841696105e-17
+
+        NormDouble::with_parts(SQRT_FRAC_2_PI_HI, SQRT_FRAC_2_PI_LO)
+    }
+
+    #[inline]
+    fn j0_small_poly(u: Self) -> (Self, Self) {
+        // GENERATE: bessel::j0_small_poly f64 7
+        const K3_HI: f64 = f64::from_bits(0xBF3C71C71C71C71C); // -4.3402777777777775e-4
+        const K3_LO: f64 = f64::from_bits(0xBBDC1A206A49206C); // -2.380336826318627e-20
+        const K4: f64 = f64::from_bits(0x3EDC71C71C71C711); // 6.781684027777768e-6
+        const K5: f64 = f64::from_bits(0xBE723456789AAE77); // -6.781684027772897e-8
+        const K6: f64 = f64::from_bits(0x3E002E85C07B1F23); // 4.709502796090556e-10
+        const K7: f64 = f64::from_bits(0xBD8522A4313614A6); // -2.4028074534073174e-12
+        const K8: f64 = f64::from_bits(0x3D05229CAB2D7082); // 9.385915633183344e-15
+        const K9: f64 = f64::from_bits(0xBC80B0D43BABCD1B); // -2.8953815768485495e-17
+        const K10: f64 = f64::from_bits(0x3BF4AE3067733F6C); // 7.00680090391281e-20
+
+        let r = K3_LO + horner!(u, u, [K4, K5, K6, K7, K8, K9, K10]);
+        (r, K3_HI)
+    }
+
+    #[inline]
+    fn j1_small_poly(u: Self) -> (Self, Self) {
+        // GENERATE: bessel::j1_small_poly f64 7
+        const K2_HI: f64 = f64::from_bits(0x3F65555555555555); // 2.6041666666666665e-3
+        const K2_LO: f64 = f64::from_bits(0x3C0464FA2604E258); // 1.3819811427078364e-19
+        const K3: f64 = f64::from_bits(0xBF0C71C71C71C6FE); // -5.4253472222222016e-5
+        const K4: f64 = f64::from_bits(0x3EA6C16C16C14493); // 6.781684027767067e-7
+        const K5: f64 = f64::from_bits(0xBE3845C8A0A6C188); // -5.651403354336872e-9
+        const K6: f64 = f64::from_bits(0x3DC27E4FA404021E); // 3.3639303583969975e-11
+        const K7: f64 = f64::from_bits(0xBD452299D9B7152D); // -1.5017434457975118e-13
+        const K8: f64 = f64::from_bits(0x3CC2C660A232A222); // 5.211084900117303e-16
+        const K9: f64 = f64::from_bits(0xBC39C3DE1435965E); // -1.3967292711813675e-18
+
+        let r = K2_LO + horner!(u, u, [K3, K4, K5, K6, K7, K8, K9]);
+        (r, K2_HI)
+    }
+
+    #[inline]
+    fn y0_small_poly(u: Self) -> (Self, NormDouble<Self>, NormDouble<Self>, Self) {
+        // GENERATE: bessel::y0_small_poly f64 11
+        const K0_HI: f64 = f64::from_bits(0xBFB2E4D699CBD01E); // -7.380429510868722e-2
+        const K0_LO: f64 = f64::from_bits(0xBC611013BF96F55C); // -7.399810511143864e-18
+        const K1_HI: f64 = f64::from_bits(0x3FC6BBCB41034286); // 1.7760601686906713e-1
+        const K1_LO: f64 = f64::from_bits(0x3C6D93DB104B99DD); // 1.2827222528981868e-17
+        const K2_HI: f64 = f64::from_bits(0xBF9075B1BBF41363); // -1.6073968025938423e-2
+        const K2_LO: f64 = f64::from_bits(0xBC4C8CE24B7AB32B); // -3.095432762677608e-18
+        const K3: f64 = f64::from_bits(0x3F41A6206B7B973D); // 5.38602666861655e-4
+        const K4: f64 = f64::from_bits(0xBEE3E99794203BB7); // -9.495005205221536e-6
+        const K5: f64 = f64::from_bits(0x3E7BCE4A600D3A7C); // 1.0358476033626688e-7
+        const K6: f64 = f64::from_bits(0xBE0A6EE796B69150); // -7.693079900775772e-10
+        const K7: f64 = f64::from_bits(0x3D92393D82380251); // 4.143565728958778e-12
+        const K8: f64 = f64::from_bits(0xBD131085A1F18D11); // -1.693271218486601e-14
+        const K9: f64 = f64::from_bits(0x3C8F4EB710D1394F); // 5.4309822022900884e-17
+        const K10: f64 = f64::from_bits(0xBC04B2BE246F7685); // -1.4025655258548e-19
+        const K11: f64 = f64::from_bits(0x3B759FE054E7E049); // 2.861980965736515e-22
+
+        let r = K2_LO + horner!(u, u, [K3, K4, K5, K6, K7, K8, K9, K10, K11]);
+        (
+            r,
+            NormDouble::with_parts(K0_HI, K0_LO),
+            NormDouble::with_parts(K1_HI, K1_LO),
+            K2_HI,
+        )
+    }
+
+    #[inline]
+    fn y1_small_poly(u: Self) -> (Self, NormDouble<Self>, NormDouble<Self>, Self) {
+        // GENERATE: bessel::y1_small_poly f64 9
+        const K0_HI: f64 = f64::from_bits(0xBFC91866143CBC8A); // -1.9605709064623894e-1
+        const K0_LO: f64 = f64::from_bits(0xBC61D09867D206C2); // -7.725949014278927e-18
+        const K1_HI: f64 = f64::from_bits(0x3FABD3975C75B4A7); // 5.434868816051024e-2
+        const K1_LO: f64 = f64::from_bits(0x3C116F575A6A7F65); // 2.3628731694053774e-19
+        const K2_HI: f64 = f64::from_bits(0xBF6835B97894BE4E); // -2.955305336079828e-3
+        const K2_LO: f64 = f64::from_bits(0xBC1FE19A101C6F4E); // -4.320716021269013e-19
+        const K3: f64 = f64::from_bits(0x3F12C7DBFFCDE4C1); // 7.16426874997232e-5
+        const K4: f64 = f64::from_bits(0xBEB0A780AC7581F3); // -9.926740619157717e-7
+        const K5: f64 = f64::from_bits(0x3E432E5A4CF8AF29); // 8.931879596258483e-9
+        const K6: f64 = f64::from_bits(0xBDCF0CE2CA181D3F); // -5.648023130939856e-11
+        const K7: f64 = f64::from_bits(0x3D52A4CA9688E6B0); // 2.6494314295124067e-13
+        const K8: f64 = f64::from_bits(0xBCD1425DA4D73C48); // -9.580803590846215e-16
+        const K9: f64 = f64::from_bits(0x3C484DB19F40EAA0); // 2.634989833523485e-18
+
+        let r = K2_LO + horner!(u, u, [K3, K4, K5, K6, K7, K8, K9]);
+        (
+            r,
+            NormDouble::with_parts(K0_HI, K0_LO),
+            NormDouble::with_parts(K1_HI, K1_LO),
+            K2_HI,
+        )
+    }
+
+    #[inline]
+    fn asympt0_poly_1(z: Self) -> (Self, Self, NormDouble<Self>, Self) {
+        // GENERATE: bessel::asympt_amp_poly f64 0 15 2 4
+        const A0: f64 = f64::from_bits(0xBF7E8E94754F9BB8); // -7.460193552993148e-3
+        const A1: f64 = f64::from_bits(0xBFA1CFD3A75A156D); // -3.478871744031662e-2
+        const A2: f64 = f64::from_bits(0xBF9B14A09BA21AE7); // -2.6445874690149387e-2
+        const A3: f64 = f64::from_bits(0x3F9E894C0A1F91AB); // 2.982062160289593e-2
+        const A4: f64 = f64::from_bits(0xBF93D4B4BEBBF84B); // -1.9366096631522372e-2
+        const A5: f64 = f64::from_bits(0x3F68324CDD5E323D); // 2.953672529605349e-3
+        const A6: f64 = f64::from_bits(0x3F90F16B56BB52B3); // 1.6545941513714534e-2
+        const A7: f64 = f64::from_bits(0xBFA29B35C6E9FB6B); // -3.634040883206676e-2
+        const A8: f64 = f64::from_bits(0x3FAA9B36FCDA8AB6); // 5.196544491379511e-2
+        const A9: f64 = f64::from_bits(0xBFACAFB5E35FD2EE); // -5.602806473317466e-2
+        const A10: f64 = f64::from_bits(0x3FA2FA8E2947A648); // 3.7067835368202184e-2
+        const A11: f64 = f64::from_bits(0x3F957EE093A42CCD); // 2.09918108094314e-2
+        const A12: f64 = f64::from_bits(0xBFC16F49885B7B79); // -1.3620871694635264e-1
+        const A13: f64 = f64::from_bits(0x3FD5428172ADC49B); // 3.321841831121419e-1
+        const A14: f64 = f64::from_bits(0xBFE634CB9A8DA509); // -6.939447420710688e-1
+        const A15: f64 = f64::from_bits(0x3FF07734C331B891); // 1.0291030525945966e0
+
+        // GENERATE: bessel::asympt_phase_poly f64 0 16 2 4
+        const K0_HI: f64 = f64::from_bits(0xBFA6AF2877B155A0); // -4.430510006992994e-2
+        const K0_LO: f64 = f64::from_bits(0xBC58FD52558CA8FD); // -5.4187421845274485e-18
+        const K1_HI: f64 = f64::from_bits(0xBFBB77FF09B05A0D); // -1.0729974733872201e-1
+        const K1_LO: f64 = f64::from_bits(0xBC6D93E514801057); // -1.2827288812125838e-17
+        const K2: f64 = f64::from_bits(0x3FA0F5E195E8A4ED); // 3.3125924634370786e-2
+        const K3: f64 = f64::from_bits(0xBF415FB658F3D328); // -5.302086227901614e-4
+        const K4: f64 = f64::from_bits(0xBF921198C8A6FEE9); // -1.764525150951971e-2
+        const K5: f64 = f64::from_bits(0x3F9B521EE7E27FB4); // 2.668045320808661e-2
+        const K6: f64 = f64::from_bits(0xBF9B6C4751D881AC); // -2.6780237551180683e-2
+        const K7: f64 = f64::from_bits(0x3F90A114EE7906AD); // 1.6239478163930943e-2
+        const K8: f64 = f64::from_bits(0x3F7DE26D998CD309); // 7.296016811598313e-3
+        const K9: f64 = f64::from_bits(0xBFA751ED51B996F6); // -4.554692862147462e-2
+        const K10: f64 = f64::from_bits(0x3FB8F5E9318C1717); // 9.7502302738658e-2
+        const K11: f64 = f64::from_bits(0xBFC3EF1897324D44); // -1.5573413260117708e-1
+        const K12: f64 = f64::from_bits(0x3FC9AE330F5E6F62); // 2.0062864542421283e-1
+        const K13: f64 = f64::from_bits(0xBFC926DD8E50C8A0); // -1.964985794925882e-1
+        const K14: f64 = f64::from_bits(0x3FB0BEB91B501918); // 6.541020314043455e-2
+        const K15: f64 = f64::from_bits(0x3FE09BE4E631F35F); // 5.190300460813936e-1
+        const K16: f64 = f64::from_bits(0xBFF89DA8B3C7A569); // -1.5384909651423533e0
+
+        let a = A0
+            + horner!(
+                z,
+                z,
+                [
+                    A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15
+                ]
+            );
+        let r = K1_LO
+            + horner!(
+                z,
+                z,
+                [
+                    K2, K3, K4, K5, K6, K7, K8, K9, K10, K11, K12, K13, K14, K15, K16
+                ]
+            );
+        (a, r, NormDouble::with_parts(K0_HI, K0_LO), K1_HI)
+    }
+
+    #[inline]
+    fn asympt0_poly_2(z: Self) -> (Self, Self, NormDouble<Self>, Self) {
+        // GENERATE: bessel::asympt_amp_poly f64 0 13 4 8
+        const A0: f64 = f64::from_bits(0xBF611905A3C6510A); // -2.087126746298411e-3
+        const A1: f64 = f64::from_bits(0xBF95BED4644B7B2A); // -2.1235769864570354e-2
+        const A2: f64 = f64::from_bits(0xBFA807E1B9D735FE); // -4.693513291895711e-2
+        const A3: f64 = f64::from_bits(0x3FA4DB8D50E783EF); // 4.073754894830716e-2
+        const A4: f64 = f64::from_bits(0x3F53136346516E58); // 1.1642903785035377e-3
+        const A5: f64 = f64::from_bits(0xBFB1ED1897AA08EB); // -7.002404883136008e-2
+        const A6: f64 = f64::from_bits(0x3FC1C38F10473781); // 1.3878048224290399e-1
+        const A7: f64 = f64::from_bits(0xBFBFE80702EC4E3C); // -1.2463420697559341e-1
+        const A8: f64 = f64::from_bits(0xBFC41DB643B1CEF9); // -1.5715673737906125e-1
+        const A9: f64 = f64::from_bits(0x3FF0141997B8F102); // 1.0049072195679973e0
+        const A10: f64 = f64::from_bits(0xC0051913164243F7); // -2.637243436715376e0
+        const A11: f64 = f64::from_bits(0x40110CDBD62655AE); // 4.26255735978104e0
+        const A12: f64 = f64::from_bits(0x3FC67E5EF46B582C); // 1.7573153433358024e-1
+        const A13: f64 = f64::from_bits(0xC039C0FDC032B6D1); // -2.5753871929532412e1
+
+        // GENERATE: bessel::asympt_phase_poly f64 0 13 4 8
+        const K0_HI: f64 = f64::from_bits(0xBF9799C89C41AD0E); // -2.304757550920749e-2
+        const K0_LO: f64 = f64::from_bits(0xBC4152C0E20A1F4C); // -1.878191142164295e-18
+        const K1_HI: f64 = f64::from_bits(0xBFBE7DCD315B5D73); // -1.1910707907678901e-1
+        const K1_LO: f64 = f64::from_bits(0xBC63A55BC364DA5A); // -8.520064621785093e-18
+        const K2: f64 = f64::from_bits(0x3F9C0EC689C24412); // 2.740011421025563e-2
+        const K3: f64 = f64::from_bits(0x3F9A871EDDDB4F42); // 2.5906069079453482e-2
+        const K4: f64 = f64::from_bits(0xBFAD390BF5EC2E77); // -5.7075856942984275e-2
+        const K5: f64 = f64::from_bits(0x3FA8E8204FD98993); // 4.864598250130139e-2
+        const K6: f64 = f64::from_bits(0x3FA245B0E5F04C3A); // 3.568795019977995e-2
+        const K7: f64 = f64::from_bits(0xBFCDD553779F0DC0); // -2.3307269421466792e-1
+        const K8: f64 = f64::from_bits(0x3FE07A4618ED969F); // 5.149260031582407e-1
+        const K9: f64 = f64::from_bits(0xBFE34B9C1B7E4E13); // -6.029797112445884e-1
+        const K10: f64 = f64::from_bits(0xBFDAEB6350EBB547); // -4.2061694052138815e-1
+        const K11: f64 = f64::from_bits(0x4012DA470033E0C4); // 4.713161471525556e0
+        const K12: f64 = f64::from_bits(0xC03036D8C10DC308); // -1.621424490533812e1
+        const K13: f64 = f64::from_bits(0x4040E448E5E45FED); // 3.378347467089757e1
+
+        let a = A0
+            + horner!(
+                z,
+                z,
+                [A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13]
+            );
+        let r = K1_LO + horner!(z, z, [K2, K3, K4, K5, K6, K7, K8, K9, K10, K11, K12, K13]);
+        (a, r, NormDouble::with_parts(K0_HI, K0_LO), K1_HI)
+    }
+
+    #[inline]
+    fn asympt0_poly_3(u: Self) -> (Self, Self) {
+        // GENERATE: bessel::asympt_amp_tail_poly f64 0 14 8
+        const A1: f64 = f64::from_bits(0xBFAFFFFFFFFFFFFE); // -6.2499999999999986e-2
+        const A2: f64 = f64::from_bits(0x3FBA7FFFFFFFA1D9); // 1.035156249996655e-1
+        const A3: f64 = f64::from_bits(0xBFE15EFFFF110753); // -5.428466779487543e-1
+        const A4: f64 = f64::from_bits(0x401765108F2D6C7B); // 5.848695981166311e0
+        const A5: f64 = f64::from_bits(0xC05AB88100E6E035); // -1.068828737501761e2
+        const A6: f64 = f64::from_bits(0x40A72B0A0BEF736C); // 2.9655196223095318e3
+        const A7: f64 = f64::from_bits(0xC0FC2A2247064CBB); // -1.1536214233999226e5
+        const A8: f64 = f64::from_bits(0x41560461BC0E66EB); // 5.77165493837903e6
+        const A9: f64 = f64::from_bits(0xC1B3974E27EB4925); // -3.2868304791908485e8
+        const A10: f64 = f64::from_bits(0x421133114F557093); // 1.8467804117359936e10
+        const A11: f64 = f64::from_bits(0xC26A3D381126BB4D); // -9.015698742298531e11
+        const A12: f64 = f64::from_bits(0x42BF647230867357); // 3.451627297342734e13
+        const A13: f64 = f64::from_bits(0xC30AB86C761C719F); // -9.401406715039239e14
+        const A14: f64 = f64::from_bits(0x434C51667C110155); // 1.5941599915803306e16
+        const A15: f64 = f64::from_bits(0xC37BD81A7D6089C3); // -1.2539892248665195e17
+
+        // GENERATE: bessel::asympt_phase_tail_poly f64 0 15 8
+        const P1: f64 = f64::from_bits(0x3FB0AAAAAAAAAAA9); // 6.510416666666664e-2
+        const P2: f64 = f64::from_bits(0xBFCAD3333332AF31); // -2.0957031249906202e-1
+        const P3: f64 = f64::from_bits(0x3FFA358490CA5A3A); // 1.6380658775211843e0
+        const P4: f64 = f64::from_bits(0xC03779A11C3B40FE); // -2.3475114597765234e1
+        const P5: f64 = f64::from_bits(0x4080BCFE0ADE6872); // 5.35624044168055e2
+        const P6: f64 = f64::from_bits(0xC0D1682631D88670); // -1.7824596792346274e4
+        const P7: f64 = f64::from_bits(0x4128B972760703F9); // 8.101692305222742e5
+        const P8: f64 = f64::from_bits(0xC1864FEEEB6A53BD); // -4.679215742691753e7
+        const P9: f64 = f64::from_bits(0x41E70CD2E9CA6805); // 3.0937311503251977e9
+        const P10: f64 = f64::from_bits(0xC24801C2A8B9769D); // -2.0621749899492667e11
+        const P11: f64 = f64::from_bits(0x42A67344253D1161); // 1.234216017063269e13
+        const P12: f64 = f64::from_bits(0xC30138E289DBF655); // -6.059525288424426e14
+        const P13: f64 = f64::from_bits(0x43541B1486E12C4A); // 2.2637098041651496e16
+        const P14: f64 = f64::from_bits(0xC3A0766692495786); // -5.931241651702177e17
+        const P15: f64 = f64::from_bits(0x43E0AF509FD252EA); // 9.618146183119065e18
+        const P16: f64 = f64::from_bits(0xC40F54437E568C6A); // -7.224011273972621e19
+
+        let a = horner!(
+            u,
+            u,
+            [
+                A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15
+            ]
+        );
+        let p = horner!(
+            u,
+            u,
+            [
+                P1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11, P12, P13, P14, P15, P16
+            ]
+        );
+        (a, p)
+    }
+
+    #[inline]
+    fn asympt1_poly_1(z: Self) -> (Self, Self, NormDouble<Self>, Self) {
+        // GENERATE: bessel::asympt_amp_poly f64 1 15 2 4
+        const A0: f64 = f64::from_bits(0x3F984035F0077A5C); // 2.3682444358082186e-2
+        const A1: f64 = f64::from_bits(0x3FBD9256274A0EE7); // 1.1551416835032634e-1
+        const A2: f64 = f64::from_bits(0x3FBBC55FD70F18E1); // 1.0848044395888536e-1
+        const A3: f64 = f64::from_bits(0xBFB3D54601242F09); // -7.747304466118633e-2
+        const A4: f64 = f64::from_bits(0x3F9EA352B65031DE); // 2.9919903161543242e-2
+        const A5: f64 = f64::from_bits(0x3F864022C40C0244); // 1.0864516837956313e-2
+        const A6: f64 = f64::from_bits(0xBFA5A3A36EC01FD3); // -4.226408698019637e-2
+        const A7: f64 = f64::from_bits(0x3FB059167423A1F5); // 6.385937056950992e-2
+        const A8: f64 = f64::from_bits(0xBFB29552758BC38D); // -7.259097451948389e-2
+        const A9: f64 = f64::from_bits(0x3FAF48F22936F7C8); // 6.110340836629252e-2
+        const A10: f64 = f64::from_bits(0xBF919FCB2304BE0E); // -1.7211126339303155e-2
+        const A11: f64 = f64::from_bits(0xBFB355011E33CA51); // -7.551581370697959e-2
+        const A12: f64 = f64::from_bits(0x3FCDE2EF45175AAF); // 2.3348799584956256e-1
+        const A13: f64 = f64::from_bits(0xBFDE5E5A9398ED64); // -4.745088998203839e-1
+        const A14: f64 = f64::from_bits(0x3FEBB0C91C40FCB9); // 8.653302718957071e-1
+        const A15: f64 = f64::from_bits(0xBFF28928939AD8A6); // -1.15848596246864e0
+
+        // GENERATE: bessel::asympt_phase_poly f64 1 16 2 4
+        const K0_HI: f64 = f64::from_bits(0x3FC11B6D5DCCACCB); // 1.3364951210073736e-1
+        const K0_LO: f64 = f64::from_bits(0x3C794DACE33D5B25); // 2.1947217746525852e-17
+        const K1_HI: f64 = f64::from_bits(0x3FD4D06324B0D03C); // 3.252189501101872e-1
+        const K1_LO: f64 = f64::from_bits(0x3C861B6E6C6F8984); // 3.8349798475840645e-17
+        const K2: f64 = f64::from_bits(0xBFB9FD19B64604E3); // -1.0151825618779102e-1
+        const K3: f64 = f64::from_bits(0xBF91582BA623238D); // -1.69379062797677e-2
+        const K4: f64 = f64::from_bits(0x3FAEB2B3E5FF69B5); // 5.9957143617526944e-2
+        const K5: f64 = f64::from_bits(0xBFAE7963E39D989B); // -5.95198836661421e-2
+        const K6: f64 = f64::from_bits(0x3FA303F21603269B); // 3.713947790818147e-2
+        const K7: f64 = f64::from_bits(0xBF6716F9321A0715); // -2.8185717681602587e-3
+        const K8: f64 = f64::from_bits(0xBFA4E18E06C49E70); // -4.0783346487214156e-2
+        const K9: f64 = f64::from_bits(0x3FB7CE53363B76D7); // 9.29920203593285e-2
+        const K10: f64 = f64::from_bits(0xBFC330D88484DD8F); // -1.4992815466551376e-1
+        const K11: f64 = f64::from_bits(0x3FC9787CBF8A2489); // 1.989894805194903e-1
+        const K12: f64 = f64::from_bits(0xBFCB2BC088F05793); // -2.1227270781008353e-1
+        const K13: f64 = f64::from_bits(0x3FC26D8A768D2109); // 1.4396792210232714e-1
+        const K14: f64 = f64::from_bits(0x3FB994259677BD5B); // 9.991679118660583e-2
+        const K15: f64 = f64::from_bits(0xBFED5385CD3371A0); // -9.164456374960999e-1
+        const K16: f64 = f64::from_bits(0x40018CF1D02821D1); // 2.1938205969092697e0
+
+        let a = A0
+            + horner!(
+                z,
+                z,
+                [
+                    A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15
+                ]
+            );
+        let r = K1_LO
+            + horner!(
+                z,
+                z,
+                [
+                    K2, K3, K4, K5, K6, K7, K8, K9, K10, K11, K12, K13, K14, K15, K16
+                ]
+            );
+        (a, r, NormDouble::with_parts(K0_HI, K0_LO), K1_HI)
+    }
+
+    #[inline]
+    fn asympt1_poly_2(z: Self) -> (Self, Self, NormDouble<Self>, Self) {
+        // GENERATE: bessel::asympt_amp_poly f64 1 13 4 8
+        const A0: f64 = f64::from_bits(0x3F7A21E17975FB98); // 6.379967460246848e-3
+        const A1: f64 = f64::from_bits(0x3FB0E70341C3A13A); // 6.602497439928792e-2
+        const A2: f64 = f64::from_bits(0x3FC406B709427659); // 1.564549250805836e-1
+        const A3: f64 = f64::from_bits(0xBFB62CE22840B0EB); // -8.662236674053118e-2
+        const A4: f64 = f64::from_bits(0xBF999125D18C8348); // -2.4967757143829844e-2
+        const A5: f64 = f64::from_bits(0x3FC1B12F144D0925); // 1.382197236180868e-1
+        const A6: f64 = f64::from_bits(0xBFCA5C0EA28E9A7E); // -2.0593436181407648e-1
+        const A7: f64 = f64::from_bits(0x3FBE21C9362449A7); // 1.1770303317852059e-1
+        const A8: f64 = f64::from_bits(0x3FD60664C3610AA0); // 3.4414023475634714e-1
+        const A9: f64 = f64::from_bits(0xBFF7A6F29FD2DC51); // -1.4782587283241535e0
+        const A10: f64 = f64::from_bits(0x400AD054FAD7E596); // 3.35172458621373e0
+        const A11: f64 = f64::from_bits(0xC0124A1D3C45C8B9); // -4.572377149352342e0
+        const A12: f64 = f64::from_bits(0xC0073F333DF378B4); // -2.905859455101643e0
+        const A13: f64 = f64::from_bits(0x40427F6F3235D00B); // 3.699558093669483e1
+
+        // GENERATE: bessel::asympt_phase_poly f64 1 13 4 8
+        const K0_HI: f64 = f64::from_bits(0x3FB1BDD825184092); // 6.930304437287507e-2
+        const K0_LO: f64 = f64::from_bits(0x3C6FE93055DFE24A); // 1.3839144149400982e-17
+        const K1_HI: f64 = f64::from_bits(0x3FD70221F514D927); // 3.5950516637548663e-1
+        const K1_LO: f64 = f64::from_bits(0x3C8DFF07BDF6792B); // 5.203513294587514e-17
+        const K2: f64 = f64::from_bits(0xBFB326AD4718C534); // -7.480891214142532e-2
+        const K3: f64 = f64::from_bits(0xBFB63688896E3AE9); // -8.676961283911634e-2
+        const K4: f64 = f64::from_bits(0x3FC057E8CC726562); // 1.2768278105503855e-1
+        const K5: f64 = f64::from_bits(0xBFAE9A6ADB84B7F5); // -5.97718613592945e-2
+        const K6: f64 = f64::from_bits(0xBFBE546132A59E8A); // -1.1847503172395527e-1
+        const K7: f64 = f64::from_bits(0x3FD9C720D3B6CF3C); // 4.027788226624016e-1
+        const K8: f64 = f64::from_bits(0xBFE61112A327C7DA); // -6.895840822819495e-1
+        const K9: f64 = f64::from_bits(0x3FE189C8E6F33705); // 5.480694304221464e-1
+        const K10: f64 = f64::from_bits(0x3FF3406BC56E1C2C); // 1.2032277786363563e0
+        const K11: f64 = f64::from_bits(0xC01C0E21F35A9C29); // -7.013801386266132e0
+        const K12: f64 = f64::from_bits(0x40347C2F306FF19F); // 2.048509505017284e1
+        const K13: f64 = f64::from_bits(0xC042B47E39EC4573); // -3.7410102119795646e1
+
+        let a = A0
+            + horner!(
+                z,
+                z,
+                [A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13]
+            );
+        let r = K1_LO + horner!(z, z, [K2, K3, K4, K5, K6, K7, K8, K9, K10, K11, K12, K13]);
+        (a, r, NormDouble::with_parts(K0_HI, K0_LO), K1_HI)
+    }
+
+    #[inline]
+    fn asympt1_poly_3(u: Self) -> (Self, Self) {
+        // GENERATE: bessel::asympt_amp_tail_poly f64 1 14 8
+        const A1: f64 = f64::from_bits(0x3FC8000000000000); // 1.875e-1
+        const A2: f64 = f64::from_bits(0xBFC8BFFFFFFFCC02); // -1.9335937499963057e-1
+        const A3: f64 = f64::from_bits(0x3FE9C4FFFEF82BDB); // 8.052978496428936e-1
+        const A4: f64 = f64::from_bits(0xC01EF5B5763E8A16); // -7.739950034682371e0
+        const A5: f64 = f64::from_bits(0x4060983D716AA7BE); // 1.3275750037032225e2
+        const A6: f64 = f64::from_bits(0xC0ABA8D2F870286D); // -3.54041205168241e3
+        const A7: f64 = f64::from_bits(0x41005E951B53FE30); // 1.3409863834379753e5
+        const A8: f64 = f64::from_bits(0xC159222FB70686F5); // -6.588606859773387e6
+        const A9: f64 = f64::from_bits(0x41B61B536683F8F3); // 3.708895745155174e8
+        const A10: f64 = f64::from_bits(0xC2134636341D2300); // -2.069558400728418e10
+        const A11: f64 = f64::from_bits(0x426D4900C258E253); // 1.0062307048390726e12
+        const A12: f64 = f64::from_bits(0xC2C179B1D38040B8); // -3.842874428633744e13
+        const A13: f64 = f64::from_bits(0x430DB3EC468FAE60); // 1.045075212760524e15
+        const A14: f64 = f64::from_bits(0xC34F721C057FA44E); // -1.7702377909864604e16
+        const A15: f64 = f64::from_bits(0x437EE58FF9423F3C); // 1.3914649322035296e17
+
+        // GENERATE: bessel::asympt_phase_tail_poly f64 1 15 8
+        const P1: f64 = f64::from_bits(0xBFC4FFFFFFFFFFFF); // -1.6406249999999997e-1
+        const P2: f64 = f64::from_bits(0x3FD7BCCCCCCC8435); // 3.708984374989684e-1
+        const P3: f64 = f64::from_bits(0xC002F486DA9B4B55); // -2.369397838463025e0
+        const P4: f64 = f64::from_bits(0x403E9FBE4D826BED); // 3.0623997539845664e1
+        const P5: f64 = f64::from_bits(0xC0849956459CC19A); // -6.591671249624808e2
+        const P6: f64 = f64::from_bits(0x40D4A59905A4FA83); // 2.1142390969509735e4
+        const P7: f64 = f64::from_bits(0xC12C996A129B49D6); // -9.371410363410066e5
+        const P8: f64 = f64::from_bits(0x41895D8C96EB5CD1); // 5.319515486492313e7
+        const P9: f64 = f64::from_bits(0xC1E9E8869DFA46E4); // -3.4773537118211536e9
+        const P10: f64 = f64::from_bits(0x424ACBEABB1F5674); // 2.301806157426754e11
+        const P11: f64 = f64::from_bits(0xC2A8F483BAF25F27); // -1.3719230576943576e13
+        const P12: f64 = f64::from_bits(0x4303186B9AF6EAF0); // 6.71859374742878e14
+        const P13: f64 = f64::from_bits(0xC35641B7CFFE1C7F); // -2.5058828847968764e16
+        const P14: f64 = f64::from_bits(0x43A23438E9B129FB); // 6.558679839112554e17
+        const P15: f64 = f64::from_bits(0xC3E26F9102687786); // -1.062751883700969e19
+        const P16: f64 = f64::from_bits(0x44114C7FBA4057B2); // 7.977674462687478e19
+
+        let a = horner!(
+            u,
+            u,
+            [
+                A1, A2, A3, A4, A5, A6, A7, A8, A9, A10, A11, A12, A13, A14, A15
+            ]
+        );
+        let p = horner!(
+            u,
+            u,
+            [
+                P1, P2, P3, P4, P5, P6, P7, P8, P9, P10, P11, P12, P13, P14, P15, P16
+            ]
+        );
+        (a, p)
+    }
+}
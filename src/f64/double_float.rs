@@ -0,0 +1,33 @@
+// GENERATE: double_float::consts f64
+const DD_LN_2_HI: f64 = f64::from_bits(0x3FE62E42FEFA2000); // 6.931471805592082e-1
+const DD_LN_2_MI: f64 = f64::from_bits(0x3D69EF35793C6000); // 7.371002565161996e-13
+const DD_LN_2_LO: f64 = f64::from_bits(0x3AE673007E5ED5E8); // 5.8029889835956905e-25
+const DD_LN_10_HI: f64 = f64::from_bits(0x40026BB1BBB55515); // 2.3025850929940455e0
+const DD_LN_10_LO: f64 = f64::from_bits(0x3CB05BA95B58AE0B); // 2.2701358751183766e-16
+
+impl crate::generic::DoubleFloatMath for f64 {
+    #[inline]
+    fn dd_ln_2_hi() -> Self {
+        DD_LN_2_HI
+    }
+
+    #[inline]
+    fn dd_ln_2_mi() -> Self {
+        DD_LN_2_MI
+    }
+
+    #[inline]
+    fn dd_ln_2_lo() -> Self {
+        DD_LN_2_LO
+    }
+
+    #[inline]
+    fn dd_ln_10_hi() -> Self {
+        DD_LN_10_HI
+    }
+
+    #[inline]
+    fn dd_ln_10_lo() -> Self {
+        DD_LN_10_LO
+    }
+}
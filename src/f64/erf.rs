@@ -0,0 +1,164 @@
+use crate::double::NormDouble;
+
+impl crate::generic::Erf for f64 {
+    #[inline]
+    fn erf_one_th() -> Self {
+        6.0
+    }
+
+    #[inline]
+    fn erfc_hi_th() -> Self {
+        27.3
+    }
+
+    #[inline]
+    fn frac_1_sqrt_pi() -> NormDouble<Self> {
+        // GENERATE: erf::consts f64
+        const FRAC_1_SQRT_PI_HI: f64 = f64::from_bits(0x3FE20DD750429B6D); // 5.641895835477563e-1
+        const FRAC_1_SQRT_PI_LO: f64 = f64::from_bits(0x3C61AE3A914FED80); // 7.66772980658294e-18
+
+        NormDouble::with_parts(FRAC_1_SQRT_PI_HI, FRAC_1_SQRT_PI_LO)
+    }
+
+    #[inline]
+    fn erf_poly(x2: Self) -> (Self, Self, Self, Self) {
+        // GENERATE: erf::erf_poly f64 12 1
+        const K0: f64 = f64::from_bits(0x3FC06EBA8214DB69); // 1.283791670955126e-1
+        const K1: f64 = f64::from_bits(0xBFD812746B0379E6); // -3.761263890318375e-1
+        const K2: f64 = f64::from_bits(0x3FBCE2F21A042B34); // 1.1283791670954885e-1
+        const K3: f64 = f64::from_bits(0xBF9B82CE31284F37); // -2.686617064507787e-2
+        const K4: f64 = f64::from_bits(0x3F7565BCD0DBD8F3); // 5.223977624828391e-3
+        const K5: f64 = f64::from_bits(0xBF4C02DB3DB490C6); // -8.548326981423708e-4
+        const K6: f64 = f64::from_bits(0x3F1F9A321E4AED8E); // 1.2055331132903286e-4
+        const K7: f64 = f64::from_bits(0xBEEF4D1E43431C95); // -1.4925595771221506e-5
+        const K8: f64 = f64::from_bits(0x3EBB9DF3057AB8A0); // 1.6461008466684023e-6
+        const K9: f64 = f64::from_bits(0xBE85F1F40DED63F3); // -1.6350396144884376e-7
+        const K10: f64 = f64::from_bits(0x3E4F7B99CD6BF0E3); // 1.4660328435157754e-8
+        const K11: f64 = f64::from_bits(0xBE138AC2205D61E6); // -1.1374955434683388e-9
+        const K12: f64 = f64::from_bits(0x3DD06275BCDEA900); // 5.960686043485343e-11
+
+        let r = horner!(x2, x2, [K3, K4, K5, K6, K7, K8, K9, K10, K11, K12]);
+        (r, K0, K1, K2)
+    }
+
+    #[inline]
+    fn erfc_poly_1(x: Self) -> (Self, NormDouble<Self>, Self) {
+        // GENERATE: erf::erfc_poly f64 14 0.5 1
+        const K0_HI: f64 = f64::from_bits(0x3FE038D54EA3D833); // 5.069376502931447e-1
+        const K0_LO: f64 = f64::from_bits(0x3C909EF6593D74CD); // 5.766549210789333e-17
+        const K1: f64 = f64::from_bits(0xBFD78CDD551EE51A); // -3.679726916557954e-1
+        const K2: f64 = f64::from_bits(0x3FCD90093AE10928); // 2.309581315512983e-1
+        const K3: f64 = f64::from_bits(0xBFC09E77D40E0214); // -1.2983606199488007e-1
+        const K4: f64 = f64::from_bits(0x3FB1192F5BD68711); // 6.679054252756723e-2
+        const K5: f64 = f64::from_bits(0xBFA054D68296479A); // -3.18972620399471e-2
+        const K6: f64 = f64::from_bits(0x3F8D43A7C7A86B20); // 1.4289198666167346e-2
+        const K7: f64 = f64::from_bits(0xBF78C97DD32AA451); // -6.051532271762986e-3
+        const K8: f64 = f64::from_bits(0x3F63F818959AC327); // 2.4376373447342506e-3
+        const K9: f64 = f64::from_bits(0xBF4EC0D1CCEB0539); // -9.38513223296556e-4
+        const K10: f64 = f64::from_bits(0x3F36B985399C4A78); // 3.46751237530508e-4
+        const K11: f64 = f64::from_bits(0xBF202A2C4A9B4CCB); // -1.2332716624161572e-4
+        const K12: f64 = f64::from_bits(0x3F0635A5613EF949); // 4.2361363881126555e-5
+        const K13: f64 = f64::from_bits(0xBEEE34FB5996365C); // -1.4403801105423979e-5
+        const K14: f64 = f64::from_bits(0x3ED384F307DC39BD); // 4.65377172333001e-6
+
+        let r = horner!(
+            x,
+            x,
+            [K2, K3, K4, K5, K6, K7, K8, K9, K10, K11, K12, K13, K14]
+        );
+        (r, NormDouble::with_parts(K0_HI, K0_LO), K1)
+    }
+
+    #[inline]
+    fn erfc_poly_2(x: Self) -> (Self, NormDouble<Self>, Self) {
+        // GENERATE: erf::erfc_poly f64 16 1 2
+        const K0_HI: f64 = f64::from_bits(0x3FD494DAFFA2AD68); // 3.215854164543175e-1
+        const K0_LO: f64 = f64::from_bits(0x3C739BDF0F0D8E21); // 1.7007985607722196e-17
+        const K1: f64 = f64::from_bits(0xBFC4F1988444CAF7); // -1.6362291773256007e-1
+        const K2: f64 = f64::from_bits(0x3FB37EA271BC54BD); // 7.61510398554774e-2
+        const K3: f64 = f64::from_bits(0xBFA0DC51D2941EE4); // -3.293090529956347e-2
+        const K4: f64 = f64::from_bits(0x3F8B65944F34FA41); // 1.3377340953067864e-2
+        const K5: f64 = f64::from_bits(0xBF7513ED75FF9A1C); // -5.145957547915827e-3
+        const K6: f64 = f64::from_bits(0x3F5EE705E73230B1); // 1.886134876971684e-3
+        const K7: f64 = f64::from_bits(0xBF45B0ABFF9CB643); // -6.619300686218705e-4
+        const K8: f64 = f64::from_bits(0x3F2D4509D3C3367B); // 2.2330994568735607e-4
+        const K9: f64 = f64::from_bits(0xBF130C0E2EE539D4); // -7.265888755123652e-5
+        const K10: f64 = f64::from_bits(0x3EF7F99671ED7E18); // 2.2864295824985293e-5
+        const K11: f64 = f64::from_bits(0xBEDD41A80C347063); // -6.9752861151761164e-6
+        const K12: f64 = f64::from_bits(0x3EC1570853B45953); // 2.0670856010304418e-6
+        const K13: f64 = f64::from_bits(0xBEA3F46D1B5C06A3); // -5.946990799534224e-7
+        const K14: f64 = f64::from_bits(0x3E866D2C16E9CC3C); // 1.6709010304850508e-7
+        const K15: f64 = f64::from_bits(0xBE6A7BC4B9A0B4D9); // -4.9329307930419066e-8
+        const K16: f64 = f64::from_bits(0x3E4C8F243D140AC6); // 1.329888901997171e-8
+
+        let r = horner!(
+            x,
+            x,
+            [
+                K2, K3, K4, K5, K6, K7, K8, K9, K10, K11, K12, K13, K14, K15, K16
+            ]
+        );
+        (r, NormDouble::with_parts(K0_HI, K0_LO), K1)
+    }
+
+    #[inline]
+    fn erfc_poly_3(x: Self) -> (Self, NormDouble<Self>, Self) {
+        // GENERATE: erf::erfc_poly f64 19 2 4
+        const K0_HI: f64 = f64::from_bits(0x3FC6E9827D229D2C); // 1.7900115118138993e-1
+        const K0_LO: f64 = f64::from_bits(0x3C79BE2B0863B29B); // 2.2328358023608885e-17
+        const K1: f64 = f64::from_bits(0xBFABD6AE4D14B16F); // -5.437226000717287e-2
+        const K2: f64 = f64::from_bits(0x3F9043FE1A98C0CE); // 1.588437115987134e-2
+        const K3: f64 = f64::from_bits(0xBF7259061BA8569C); // -4.479431018372584e-3
+        const K4: f64 = f64::from_bits(0x3F5409CC2ED3F9DF); // 1.2230390523765217e-3
+        const K5: f64 = f64::from_bits(0xBF353DEC9D0881F3); // -3.2412554449659444e-4
+        const K6: f64 = f64::from_bits(0x3F15E739304DEBB4); // 8.35541396337198e-5
+        const K7: f64 = f64::from_bits(0xBEF6025103CFD61D); // -2.0989464463347125e-5
+        const K8: f64 = f64::from_bits(0x3ED595F1B3113ADA); // 5.146436522342473e-6
+        const K9: f64 = f64::from_bits(0xBEB4B146230B46A5); // -1.2333677085651356e-6
+        const K10: f64 = f64::from_bits(0x3E9369910C2CAD71); // 2.892668470751061e-7
+        const K11: f64 = f64::from_bits(0xBE71D7926E07A791); // -6.64669204186509e-8
+        const K12: f64 = f64::from_bits(0x3E5014EA7437AE13); // 1.497725256136006e-8
+        const K13: f64 = f64::from_bits(0xBE2C74D1EA13E118); // -3.3127525866764416e-9
+        const K14: f64 = f64::from_bits(0x3E08C1BD3353F308); // 7.205175125423026e-10
+        const K15: f64 = f64::from_bits(0xBDE528EE7EAC870B); // -1.5395845651911665e-10
+        const K16: f64 = f64::from_bits(0x3DC170C66ABF3A37); // 3.172413490183375e-11
+        const K17: f64 = f64::from_bits(0xBD9CC47F759A1468); // -6.54098809623721e-12
+        const K18: f64 = f64::from_bits(0x3D7CFFC429411C7E); // 1.6484072450342973e-12
+        const K19: f64 = f64::from_bits(0xBD57A09AEFDD84B9); // -3.3576503924737735e-13
+
+        let r = horner!(
+            x,
+            x,
+            [
+                K2, K3, K4, K5, K6, K7, K8, K9, K10, K11, K12, K13, K14, K15, K16, K17, K18, K19
+            ]
+        );
+        (r, NormDouble::with_parts(K0_HI, K0_LO), K1)
+    }
+
+    #[inline]
+    fn erfc_asympt_poly(t: Self) -> (Self, Self) {
+        // GENERATE: erf::erfc_asympt_poly f64 13 4 27.3
+        const K0: f64 = f64::from_bits(0xBFDFFFFFFFFFFF01); // -4.9999999999998584e-1
+        const K1: f64 = f64::from_bits(0x3FE7FFFFFFFD82FD); // 7.49999999981895e-1
+        const K2: f64 = f64::from_bits(0xBFFDFFFFFDA6DBF2); // -1.8749999912522495e0
+        const K3: f64 = f64::from_bits(0x401A3FFF6ADB2B78); // 6.562497777583182e0
+        const K4: f64 = f64::from_bits(0xC03D87E97E4244BC); // -2.953090657346614e1
+        const K5: f64 = f64::from_bits(0x40644C60AFA90F56); // 1.623868025114013e2
+        const K6: f64 = f64::from_bits(0xC09074FC73062425); // -1.0532465325316964e3
+        const K7: f64 = f64::from_bits(0x40BE6DCA1CFA4761); // 7.789789504663874e3
+        const K8: f64 = f64::from_bits(0xC0EE79A1C1B4C7EF); // -6.2413054895773406e4
+        const K9: f64 = f64::from_bits(0x411E64A3A7A72085); // 4.979609137234765e5
+        const K10: f64 = f64::from_bits(0xC14AF6B42EF83792); // -3.534184366949984e6
+        const K11: f64 = f64::from_bits(0x4172AF823CA2AE93); // 1.959325178971727e7
+        const K12: f64 = f64::from_bits(0xC1912A2F47935FCA); // -7.19943218939201e7
+        const K13: f64 = f64::from_bits(0x419E79B3C02F27B6); // 1.2782308804604992e8
+
+        let r = horner!(
+            t,
+            t,
+            [K1, K2, K3, K4, K5, K6, K7, K8, K9, K10, K11, K12, K13]
+        );
+        (r, K0)
+    }
+}
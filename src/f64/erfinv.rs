@@ -0,0 +1,77 @@
+impl crate::generic::ErfInv for f64 {
+    #[inline]
+    fn erfinv_poly(x2: Self) -> Self {
+        // GENERATE: erfinv::erfinv_poly f64 12 0.75
+        const K0: f64 = f64::from_bits(0x3FEC5BF891B9D410); // 8.862269254883604e-1
+        const K1: f64 = f64::from_bits(0x3FCDB29F86AD444E); // 2.3201364589707157e-1
+        const K2: f64 = f64::from_bits(0x3FC053D3568C8AF2); // 1.275581524469867e-1
+        const K3: f64 = f64::from_bits(0x3FB6236B9CDF7F94); // 8.647797183902944e-2
+        const K4: f64 = f64::from_bits(0x3FB0FF70E8C068F8); // 6.639772112285758e-2
+        const K5: f64 = f64::from_bits(0x3FA20B91C20BCFB3); // 3.524451726653357e-2
+        const K6: f64 = f64::from_bits(0x3FC4DEC3B9338CA8); // 1.6304823439631844e-1
+        const K7: f64 = f64::from_bits(0xBFE16318B3ED9062); // -5.433467401712841e-1
+        const K8: f64 = f64::from_bits(0x3FFE90A934DB945E); // 1.9103176178698713e0
+        const K9: f64 = f64::from_bits(0xC010213747AB7AC3); // -4.032437438813477e0
+        const K10: f64 = f64::from_bits(0x4016AFD63068B261); // 5.6717155040647365e0
+        const K11: f64 = f64::from_bits(0xC0125F2D49DF1CA7); // -4.5929461996485434e0
+        const K12: f64 = f64::from_bits(0x3FFBF1971872EF04); // 1.7464819865262493e0
+
+        K0 + horner!(x2, x2, [K1, K2, K3, K4, K5, K6, K7, K8, K9, K10, K11, K12])
+    }
+
+    #[inline]
+    fn erfcinv_poly_1(t: Self) -> Self {
+        // GENERATE: erfinv::erfcinv_poly f64 11 1.17 3
+        const K0: f64 = f64::from_bits(0x3FA704E446CF8E74); // 4.495919575108767e-2
+        const K1: f64 = f64::from_bits(0xBFD392FEC22B24C1); // -3.0584687196512667e-1
+        const K2: f64 = f64::from_bits(0x3FFCA239712B337E); // 1.7896055622724982e0
+        const K3: f64 = f64::from_bits(0xBFF7486A08F99956); // -1.4551792479120045e0
+        const K4: f64 = f64::from_bits(0x3FE9AE04A65A6BC7); // 8.02492451580627e-1
+        const K5: f64 = f64::from_bits(0xBFD39F45DB228A8E); // -3.065962448110121e-1
+        const K6: f64 = f64::from_bits(0x3FB418461EDD931A); // 7.849539044907453e-2
+        const K7: f64 = f64::from_bits(0xBF882DC9801B299E); // -1.1806081983554869e-2
+        const K8: f64 = f64::from_bits(0x3F3CAED06EC6FF13); // 4.3766583146619234e-4
+        const K9: f64 = f64::from_bits(0x3F288DDF2D313086); // 1.8733357737444142e-4
+        const K10: f64 = f64::from_bits(0xBF0222968895BB68); // -3.458997623339324e-5
+        const K11: f64 = f64::from_bits(0x3EC0AD62E1518024); // 1.9880878974615365e-6
+
+        K0 + horner!(t, t, [K1, K2, K3, K4, K5, K6, K7, K8, K9, K10, K11])
+    }
+
+    #[inline]
+    fn erfcinv_poly_2(t: Self) -> Self {
+        // GENERATE: erfinv::erfcinv_poly f64 10 3 6
+        const K0: f64 = f64::from_bits(0xBFD0E93F7C2F69E9); // -2.6423632743322273e-1
+        const K1: f64 = f64::from_bits(0x3FE5B280101AABA7); // 6.780395807776259e-1
+        const K2: f64 = f64::from_bits(0x3FD678DEC0A300F0); // 3.5112732708149874e-1
+        const K3: f64 = f64::from_bits(0xBFC82075253D7A81); // -1.8849052732817387e-1
+        const K4: f64 = f64::from_bits(0x3FB0BB714FA92CD2); // 6.53601474270318e-2
+        const K5: f64 = f64::from_bits(0xBF9002A8C4E8C9DE); // -1.5635144251243342e-2
+        const K6: f64 = f64::from_bits(0x3F6575FA32074981); // 2.6197325353020666e-3
+        const K7: f64 = f64::from_bits(0xBF33DF74D8798C33); // -3.0323603320975555e-4
+        const K8: f64 = f64::from_bits(0x3EF84A5BA6112771); // 2.3165188739938462e-5
+        const K9: f64 = f64::from_bits(0xBEB1AA465DA5A97E); // -1.0529241679333179e-6
+        const K10: f64 = f64::from_bits(0x3E572F975696A455); // 2.1593554865775578e-8
+
+        K0 + horner!(t, t, [K1, K2, K3, K4, K5, K6, K7, K8, K9, K10])
+    }
+
+    #[inline]
+    fn erfcinv_asympt_poly(u: Self) -> Self {
+        // GENERATE: erfinv::erfcinv_asympt_poly f64 11 6 27.3
+        const K0: f64 = f64::from_bits(0x3FF0001E11364E04); // 1.0000286743496654e0
+        const K1: f64 = f64::from_bits(0xBF807E237AFE68B7); // -8.053090277645543e-3
+        const K2: f64 = f64::from_bits(0xC001A045765C45C1); // -2.203257488909657e0
+        const K3: f64 = f64::from_bits(0x403219F66940E0B5); // 1.8101416185702607e1
+        const K4: f64 = f64::from_bits(0xC06AC51283B48096); // -2.1415851006750444e2
+        const K5: f64 = f64::from_bits(0x40A1EB3BB0AE78C0); // 2.2936165823480987e3
+        const K6: f64 = f64::from_bits(0xC0D2CB574CA10F75); // -1.924536405207166e4
+        const K7: f64 = f64::from_bits(0x40FD539534713F84); // 1.2012132530331431e5
+        const K8: f64 = f64::from_bits(0xC1204E3E09F29C61); // -5.343030194290989e5
+        const K9: f64 = f64::from_bits(0x41385430D7A4059E); // 1.5944168423465262e6
+        const K10: f64 = f64::from_bits(0xC145C6BDF14D9A04); // -2.8542678851807136e6
+        const K11: f64 = f64::from_bits(0x4141A64EBFF429A7); // 2.3133734996387544e6
+
+        K0 + horner!(u, u, [K1, K2, K3, K4, K5, K6, K7, K8, K9, K10, K11])
+    }
+}
@@ -40,7 +40,7 @@ impl crate::generic::Exp for f64 {
     }
 
     #[inline]
-    fn exp_special_poly(x2: Self) -> Self {
+    fn exp_special_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V) -> V {
         // GENERATE: exp::exp_special_poly f64 5
         const K2: f64 = f64::from_bits(0xBFC555555555553E); // -1.6666666666666602e-1
         const K4: f64 = f64::from_bits(0x3F66C16C16BEBD5F); // 2.777777777701537e-3
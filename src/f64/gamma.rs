@@ -1,5 +1,11 @@
 use crate::double::NormDouble;
 
+// GENERATE: gamma::consts f64
+const HALF_LN_2_PI_HI: f64 = f64::from_bits(0x3FED67F1C864BEB4); // 9.189385332046727e-1
+const HALF_LN_2_PI_LO: f64 = f64::from_bits(0x3C94D252F2400510); // 7.223936088184323e-17
+const EULER_GAMMA_HI: f64 = f64::from_bits(0x3FE2788CFC6FB618); // 5.772156649015328e-1
+const EULER_GAMMA_LO: f64 = f64::from_bits(0x3C9E9346F8FE0405); // 1.06079387310085e-16
+
 impl crate::generic::Gamma for f64 {
     #[inline]
     fn lo_th() -> Self {
@@ -29,14 +35,30 @@ impl crate::generic::Gamma for f64 {
     const POLY_OFF: u8 = 5;
 
     #[inline]
-    fn half_ln_2_pi() -> NormDouble<Self> {
-        // GENERATE: gamma::consts f64
-        const HALF_LN_2_PI_HI: f64 = f64::from_bits(0x3FED67F1C864BEB4); // 9.189385332046727e-1
-        const HALF_LN_2_PI_LO: f64 = f64::from_bits(0x3C94D252F2400510); // 7.223936088184323e-17
+    fn inc_gamma_small_x_th() -> Self {
+        1.1
+    }
+
+    #[inline]
+    fn inc_gamma_temme_th() -> Self {
+        20.0
+    }
+
+    #[inline]
+    fn inc_gamma_temme_sigma() -> Self {
+        0.4
+    }
 
+    #[inline]
+    fn half_ln_2_pi() -> NormDouble<Self> {
         NormDouble::with_parts(HALF_LN_2_PI_HI, HALF_LN_2_PI_LO)
     }
 
+    #[inline]
+    fn euler_gamma() -> NormDouble<Self> {
+        NormDouble::with_parts(EULER_GAMMA_HI, EULER_GAMMA_LO)
+    }
+
     #[inline]
     fn ln_gamma_poly_1(x: Self) -> (Self, Self, Self, Self) {
         // GENERATE: gamma::ln_gamma_poly f64 26 1 0.5 1.1001
@@ -155,4 +177,187 @@ impl crate::generic::Gamma for f64 {
             ]
         )
     }
+
+    #[inline]
+    fn inc_gamma_temme_coeffs() -> &'static [&'static [Self]] {
+        // GENERATE: gamma::inc_gamma_temme_coeffs f64 11 18
+        const C0_0: f64 = f64::from_bits(0xBFD5555555555555); // -3.333333333333333e-1
+        const C0_1: f64 = f64::from_bits(0x3FB5555555555555); // 8.333333333333333e-2
+        const C0_2: f64 = f64::from_bits(0xBF8E573AC901E574); // -1.4814814814814815e-2
+        const C0_3: f64 = f64::from_bits(0x3F52F684BDA12F68); // 1.1574074074074073e-3
+        const C0_4: f64 = f64::from_bits(0x3F371DE3A556C734); // 3.527336860670194e-4
+        const C0_5: f64 = f64::from_bits(0xBF276E06FEC7273B); // -1.787551440329218e-4
+        const C0_6: f64 = f64::from_bits(0x3F048C5892F7CD83); // 3.919263178522438e-5
+        const C0_7: f64 = f64::from_bits(0xBEC255370652AFC1); // -2.185448510679992e-6
+        const C0_8: f64 = f64::from_bits(0xBEBF1B22F594C6B5); // -1.85406221071516e-6
+        const C0_9: f64 = f64::from_bits(0x3EABD6D21E4B4109); // 8.296711340953087e-7
+        const C0_10: f64 = f64::from_bits(0xBE87B5F9A2D0465C); // -1.7665952736826078e-7
+        const C0_11: f64 = f64::from_bits(0x3E3CCF5CEB7F0D9F); // 6.707853543401498e-9
+        const C0_12: f64 = f64::from_bits(0x3E46097D55C37C1C); // 1.0261809784240309e-8
+        const C0_13: f64 = f64::from_bits(0xBE32D2197C7A2FAA); // -4.382036018453353e-9
+        const C0_14: f64 = f64::from_bits(0x3E0F6E66D24D5C8A); // 9.14769958223679e-10
+        const C0_15: f64 = f64::from_bits(0xBDBC0D9B6EDF2B0B); // -2.5514193994946248e-11
+        const C0_16: f64 = f64::from_bits(0xBDD0070A87340428); // -5.830772132550426e-11
+        const C0_17: f64 = f64::from_bits(0x3DBAC9475C463659); // 2.4361948020667415e-11
+        const C1_0: f64 = f64::from_bits(0xBF5E573AC901E574); // -1.851851851851852e-3
+        const C1_1: f64 = f64::from_bits(0xBF6C71C71C71C71C); // -3.472222222222222e-3
+        const C1_2: f64 = f64::from_bits(0x3F65AC056B015AC0); // 2.6455026455026454e-3
+        const C1_3: f64 = f64::from_bits(0xBF50394F6F09E723); // -9.902263374485596e-4
+        const C1_4: f64 = f64::from_bits(0x3F2AF83440E53DBC); // 2.0576131687242798e-4
+        const C1_5: f64 = f64::from_bits(0xBE9AF83440E53DBC); // -4.018775720164609e-7
+        const C1_6: f64 = f64::from_bits(0xBEF2FA4AE89E5AF0); // -1.8098550334489977e-5
+        const C1_7: f64 = f64::from_bits(0x3EE00A9CABD6B83E); // 7.64916091608111e-6
+        const C1_8: f64 = f64::from_bits(0xBEBB0BDFCC629CBA); // -1.6120900894563446e-6
+        const C1_9: f64 = f64::from_bits(0x3E33F59230A8357C); // 4.647127802807434e-9
+        const C1_10: f64 = f64::from_bits(0x3E8280F2CDE3F847); // 1.378633446915721e-7
+        const C1_11: f64 = f64::from_bits(0xBE6EE23D0CBA8AEE); // -5.752545603517705e-8
+        const C1_12: f64 = f64::from_bits(0x3E49AA7A30DE114C); // 1.1951628599778148e-8
+        const C1_13: f64 = f64::from_bits(0xBDB349FBCA3A377B); // -1.7543241719747647e-11
+        const C1_14: f64 = f64::from_bits(0xBE11564ECFF73D58); // -1.0091543710600413e-9
+        const C1_15: f64 = f64::from_bits(0x3DFC9B434BF3C34E); // 4.162792991842583e-10
+        const C1_16: f64 = f64::from_bits(0xBDD78A5056F8CE45); // -8.56390702649298e-11
+        const C2_0: f64 = f64::from_bits(0x3F70EE643B990EE6); // 4.133597883597883e-3
+        const C2_1: f64 = f64::from_bits(0xBF65F7268EDAB4C8); // -2.6813271604938273e-3
+        const C2_2: f64 = f64::from_bits(0x3F4948B0FCD6E9E0); // 7.716049382716049e-4
+        const C2_3: f64 = f64::from_bits(0x3EC0DB20A88F4696); // 2.0093878600823047e-6
+        const C2_4: f64 = f64::from_bits(0xBF1C253EFAA1A932); // -1.073665322636516e-4
+        const C2_5: f64 = f64::from_bits(0x3F0BBF43DAF4FE53); // 5.2923448829120125e-5
+        const C2_6: f64 = f64::from_bits(0xBEEAC2D05890F2C3); // -1.2760635188618728e-5
+        const C2_7: f64 = f64::from_bits(0x3E626154AE39151D); // 3.423578734096138e-8
+        const C2_8: f64 = f64::from_bits(0x3EB7058929663937); // 1.3721957309062934e-6
+        const C2_9: f64 = f64::from_bits(0xBEA522CB05171911); // -6.298992138380055e-7
+        const C2_10: f64 = f64::from_bits(0x3E832AC81C15D3D7); // 1.4280614206064242e-7
+        const C2_11: f64 = f64::from_bits(0xBDEC24BD0E740A6C); // -2.0477098421990866e-10
+        const C2_12: f64 = f64::from_bits(0xBE4E437343A46F5D); // -1.409252991086752e-8
+        const C2_13: f64 = f64::from_bits(0x3E3AC0D455E25360); // 6.228974084922022e-9
+        const C2_14: f64 = f64::from_bits(0xBE177C5829460139); // -1.3670488396617114e-9
+        const C2_15: f64 = f64::from_bits(0x3D70962774F638BB); // 9.428356159014678e-13
+        const C3_0: f64 = f64::from_bits(0x3F4547D93B34E2B6); // 6.494341563786008e-4
+        const C3_1: f64 = f64::from_bits(0x3F2E13CE465FA859); // 2.2947209362139917e-4
+        const C3_2: f64 = f64::from_bits(0xBF3EBFB188B7CA00); // -4.691894943952557e-4
+        const C3_3: f64 = f64::from_bits(0x3F318B9B5BF2D984); // 2.6772063206283885e-4
+        const C3_4: f64 = f64::from_bits(0xBF13D2A3A29B5D9D); // -7.561801671883977e-5
+        const C3_5: f64 = f64::from_bits(0xBE90152A1871F27A); // -2.396505113867297e-7
+        const C3_6: f64 = f64::from_bits(0x3EE73DF462204EF4); // 1.1082654115347302e-5
+        const C3_7: f64 = f64::from_bits(0xBED7CD6F27B3F020); // -5.6749528269915965e-6
+        const C3_8: f64 = f64::from_bits(0x3EB7E0201539310E); // 1.4230900732435883e-6
+        const C3_9: f64 = f64::from_bits(0xBDBEA23269C140A7); // -2.7861080291528143e-11
+        const C3_10: f64 = f64::from_bits(0xBE86C2DCFFBEFEEF); // -1.6958404091930278e-7
+        const C3_11: f64 = f64::from_bits(0x3E75BDE8EF4C4DC7); // 8.099464905388083e-8
+        const C3_12: f64 = f64::from_bits(0xBE54853CED169327); // -1.9111168485973655e-8
+        const C3_13: f64 = f64::from_bits(0x3D850C3F0DD501EB); // 2.3928620439808118e-12
+        const C3_14: f64 = f64::from_bits(0x3E21B66A39794BA9); // 2.0620131815488797e-9
+        const C4_0: f64 = f64::from_bits(0xBF4C3E0B02DA7BF9); // -8.618882909167117e-4
+        const C4_1: f64 = f64::from_bits(0x3F49B0FF6874F2C4); // 7.840392217200666e-4
+        const C4_2: f64 = f64::from_bits(0xBF33999A85A4237A); // -2.990724803031902e-4
+        const C4_3: f64 = f64::from_bits(0xBEB88F2AE1DEF9D0); // -1.4638452578843418e-6
+        const C4_4: f64 = f64::from_bits(0x3F116908B48CE058); // 6.641498215465122e-5
+        const C4_5: f64 = f64::from_bits(0xBF04CE3FD902BCAD); // -3.968365047179435e-5
+        const C4_6: f64 = f64::from_bits(0x3EE7DB4C02846E81); // 1.1375726970678419e-5
+        const C4_7: f64 = f64::from_bits(0x3DF13B3C5B7CB45E); // 2.507497226237533e-10
+        const C4_8: f64 = f64::from_bits(0xBEBC71C074985D3F); // -1.6954149536558305e-6
+        const C4_9: f64 = f64::from_bits(0x3EADE37D9F09164C); // 8.907507532205309e-7
+        const C4_10: f64 = f64::from_bits(0xBE8EC676CF33153C); // -2.292934834000805e-7
+        const C4_11: f64 = f64::from_bits(0x3DC041515BAB6ADA); // 2.956794137544049e-11
+        const C4_12: f64 = f64::from_bits(0x3E5EFE94304AC16B); // 2.8865829742708783e-8
+        const C4_13: f64 = f64::from_bits(0xBE4E78E449F4E3BE); // -1.4189739437803219e-8
+        const C5_0: f64 = f64::from_bits(0xBF36128AC5A4FA71); // -3.3679855336635813e-4
+        const C5_1: f64 = f64::from_bits(0xBF1247604839C038); // -6.972813758365857e-5
+        const C5_2: f64 = f64::from_bits(0x3F322BE87360EF1F); // 2.772753244959392e-4
+        const C5_3: f64 = f64::from_bits(0xBF2A2042C5148E27); // -1.9932570516188847e-4
+        const C5_4: f64 = f64::from_bits(0x3F11D1E9CB24760B); // 6.797780477937208e-5
+        const C5_5: f64 = f64::from_bits(0x3E830BDCF208080E); // 1.419062920643967e-7
+        const C5_6: f64 = f64::from_bits(0xBEEC823FC1B3CC36); // -1.3594048189768693e-5
+        const C5_7: f64 = f64::from_bits(0x3EE0D0E229150428); // 8.018470256334202e-6
+        const C5_8: f64 = f64::from_bits(0xBEC338EB19652FD9); // -2.291481176508095e-6
+        const C5_9: f64 = f64::from_bits(0xBDF659CFDE0BB2EB); // -3.252473551298454e-10
+        const C5_10: f64 = f64::from_bits(0x3E9741504E5C87C2); // 3.4652846491085265e-7
+        const C5_11: f64 = f64::from_bits(0xBE88C267BECD0C0F); // -1.8447187191171344e-7
+        const C5_12: f64 = f64::from_bits(0x3E69E630225A095B); // 4.8240967037894184e-8
+        const C6_0: f64 = f64::from_bits(0x3F4168EF1B0931C8); // 5.313079364639922e-4
+        const C6_1: f64 = f64::from_bits(0xBF436773BDB97B48); // -5.921664373536939e-4
+        const C6_2: f64 = f64::from_bits(0x3F31C0950D3ECB9D); // 2.708782096718045e-4
+        const C6_3: f64 = f64::from_bits(0x3EAA8411DA6CAB49); // 7.902353232660328e-7
+        const C6_4: f64 = f64::from_bits(0xBF15600945495B37); // -8.153969367561969e-5
+        const C6_5: f64 = f64::from_bits(0x3F0D6BDF83130DC1); // 5.61168275310625e-5
+        const C6_6: f64 = f64::from_bits(0xBEF3382F4CF48618); // -1.8329116582843375e-5
+        const C6_7: f64 = f64::from_bits(0xBE2A74243FA27729); // -3.0796134506033047e-9
+        const C6_8: f64 = f64::from_bits(0x3ECD115D4F5DCC68); // 3.465155368803609e-6
+        const C6_9: f64 = f64::from_bits(0xBEC10587854FCB37); // -2.0291327396058603e-6
+        const C6_10: f64 = f64::from_bits(0x3EA36C8903447D35); // 5.788792863149004e-7
+        const C6_11: f64 = f64::from_bits(0x3D5074E709BF4B8B); // 2.338630673826657e-13
+        const C7_0: f64 = f64::from_bits(0x3F3691879C01EFB4); // 3.4436760689237765e-4
+        const C7_1: f64 = f64::from_bits(0x3F0B1D75D3346711); // 5.171790908260592e-5
+        const C7_2: f64 = f64::from_bits(0xBF35F3385098CEBF); // -3.3493161081142234e-4
+        const C7_3: f64 = f64::from_bits(0x3F326EEB5ECE1D9F); // 2.812695154763237e-4
+        const C7_4: f64 = f64::from_bits(0xBF1CC642787368CE); // -1.0976582244684731e-4
+        const C7_5: f64 = f64::from_bits(0xBE8119C70312E0A2); // -1.2741009095484485e-7
+        const C7_6: f64 = f64::from_bits(0x3EFD179830B113AB); // 2.7744451511563645e-5
+        const C7_7: f64 = f64::from_bits(0xBEF3269164E3E304); // -1.8263488805711332e-5
+        const C7_8: f64 = f64::from_bits(0x3ED8467D794BD7F2); // 5.7876949497350525e-6
+        const C7_9: f64 = f64::from_bits(0x3E00F82DA50CDAEE); // 4.93875893393627e-10
+        const C7_10: f64 = f64::from_bits(0xBEB1C6ACEC59F442); // -1.0595367014026043e-6
+        const C8_0: f64 = f64::from_bits(0xBF45629B3187B744); // -6.526239185953094e-4
+        const C8_1: f64 = f64::from_bits(0x3F4B8239C670E690); // 8.394987206720873e-4
+        const C8_2: f64 = f64::from_bits(0xBF3CB967B4446107); // -4.38297098541721e-4
+        const C8_3: f64 = f64::from_bits(0xBEA762676B30CFD6); // -6.969091458420552e-7
+        const C8_4: f64 = f64::from_bits(0x3F25D1157082916D); // 1.6644846642067547e-4
+        const C8_5: f64 = f64::from_bits(0xBF20C16FCEA7DDB2); // -1.2783517679769218e-4
+        const C8_6: f64 = f64::from_bits(0x3F084637D3F583CD); // 4.629953263691304e-5
+        const C8_7: f64 = f64::from_bits(0x3E33937992EC9B02); // 4.557909867922708e-9
+        const C8_8: f64 = f64::from_bits(0xBEE6384AF9AC219D); // -1.0595271125805195e-5
+        const C8_9: f64 = f64::from_bits(0x3EDC738F198AB550); // 6.783342904865167e-6
+        const C9_0: f64 = f64::from_bits(0xBF438DFF1CC96982); // -5.967612901927463e-4
+        const C9_1: f64 = f64::from_bits(0xBF12E31F9B7913EA); // -7.204895416020011e-5
+        const C9_2: f64 = f64::from_bits(0x3F463969BB825829); // 6.782308837667328e-4
+        const C9_3: f64 = f64::from_bits(0xBF44F9F2582DD0A5); // -6.401475260262758e-4
+        const C9_4: f64 = f64::from_bits(0x3F322FB20C28E8A0); // 2.7750107634328704e-4
+        const C9_5: f64 = f64::from_bits(0x3E886C71C8CEBF16); // 1.819700838046515e-7
+        const C9_6: f64 = f64::from_bits(0xBF163A803AEBC9B7); // -8.479507117068503e-5
+        const C9_7: f64 = f64::from_bits(0x3F100120036172B0); // 6.105192082501531e-5
+        const C9_8: f64 = f64::from_bits(0xBEF618FCC48D37BC); // -2.1073920183404862e-5
+        const C10_0: f64 = f64::from_bits(0x3F55D4AE684527BF); // 1.3324454494800656e-3
+        const C10_1: f64 = f64::from_bits(0xBF5F5DBCAF756CDE); // -1.9144384985654776e-3
+        const C10_2: f64 = f64::from_bits(0x3F522B37F1B46951); // 1.1089369134596636e-3
+        const C10_3: f64 = f64::from_bits(0x3EB0A9EF61E90004); // 9.9324041226423e-7
+        const C10_4: f64 = f64::from_bits(0xBF40ABA998A532BF); // -5.087450129309319e-4
+        const C10_5: f64 = f64::from_bits(0x3F3C01C0B52C3345); // 4.2735056665392886e-4
+        const C10_6: f64 = f64::from_bits(0xBF2618E482F9D229); // -1.6858853767910798e-4
+        const C10_7: f64 = f64::from_bits(0xBE41759E6F571329); // -8.1301893922785e-9
+
+        &[
+            &[
+                C0_0, C0_1, C0_2, C0_3, C0_4, C0_5, C0_6, C0_7, C0_8, C0_9, C0_10, C0_11, C0_12,
+                C0_13, C0_14, C0_15, C0_16, C0_17,
+            ],
+            &[
+                C1_0, C1_1, C1_2, C1_3, C1_4, C1_5, C1_6, C1_7, C1_8, C1_9, C1_10, C1_11, C1_12,
+                C1_13, C1_14, C1_15, C1_16,
+            ],
+            &[
+                C2_0, C2_1, C2_2, C2_3, C2_4, C2_5, C2_6, C2_7, C2_8, C2_9, C2_10, C2_11, C2_12,
+                C2_13, C2_14, C2_15,
+            ],
+            &[
+                C3_0, C3_1, C3_2, C3_3, C3_4, C3_5, C3_6, C3_7, C3_8, C3_9, C3_10, C3_11, C3_12,
+                C3_13, C3_14,
+            ],
+            &[
+                C4_0, C4_1, C4_2, C4_3, C4_4, C4_5, C4_6, C4_7, C4_8, C4_9, C4_10, C4_11, C4_12,
+                C4_13,
+            ],
+            &[
+                C5_0, C5_1, C5_2, C5_3, C5_4, C5_5, C5_6, C5_7, C5_8, C5_9, C5_10, C5_11, C5_12,
+            ],
+            &[
+                C6_0, C6_1, C6_2, C6_3, C6_4, C6_5, C6_6, C6_7, C6_8, C6_9, C6_10, C6_11,
+            ],
+            &[
+                C7_0, C7_1, C7_2, C7_3, C7_4, C7_5, C7_6, C7_7, C7_8, C7_9, C7_10,
+            ],
+            &[C8_0, C8_1, C8_2, C8_3, C8_4, C8_5, C8_6, C8_7, C8_8, C8_9],
+            &[C9_0, C9_1, C9_2, C9_3, C9_4, C9_5, C9_6, C9_7, C9_8],
+            &[C10_0, C10_1, C10_2, C10_3, C10_4, C10_5, C10_6, C10_7],
+        ]
+    }
 }
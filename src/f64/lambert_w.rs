@@ -0,0 +1,29 @@
+use crate::double::NormDouble;
+
+impl crate::generic::LambertW for f64 {
+    #[inline]
+    fn frac_1_e() -> NormDouble<Self> {
+        // GENERATE: lambert_w::consts f64
+        const FRAC_1_E_HI: f64 = f64::from_bits(0x3FD78B56362CEF37); // 3.678794411714423e-1
+        const FRAC_1_E_LO: f64 = f64::from_bits(0x3C88D5D6F63C1483); // 4.3082397558469466e-17
+
+        NormDouble::with_parts(FRAC_1_E_HI, FRAC_1_E_LO)
+    }
+
+    #[inline]
+    fn lambert_w_branch_poly(p: Self) -> Self {
+        // GENERATE: lambert_w::branch_poly f64 10
+        const K2: f64 = f64::from_bits(0xBFD5555555555555); // -3.333333333333333e-1
+        const K3: f64 = f64::from_bits(0x3FC38E38E38E38E4); // 1.527777777777778e-1
+        const K4: f64 = f64::from_bits(0xBFB4629B7F0D462A); // -7.962962962962963e-2
+        const K5: f64 = f64::from_bits(0x3FA6C901E573AC90); // 4.4502314814814814e-2
+        const K6: f64 = f64::from_bits(0xBF9A9BBCB24FE29F); // -2.598471487360376e-2
+        const K7: f64 = f64::from_bits(0x3F9002C98983BC43); // 1.563563253233392e-2
+        const K8: f64 = f64::from_bits(0xBF83B20565DE449C); // -9.616892024299432e-3
+        const K9: f64 = f64::from_bits(0x3F78A2B4A92630E7); // 6.014543252956118e-3
+        const K10: f64 = f64::from_bits(0xBF6F38DF0D491306); // -3.8112980348919993e-3
+
+        let p2 = p * p;
+        horner!(p2, p, [K2, K3, K4, K5, K6, K7, K8, K9, K10])
+    }
+}
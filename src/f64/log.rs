@@ -36,7 +36,7 @@ impl crate::generic::Ln for f64 {
     }
 
     #[inline]
-    fn ln_special_poly(x: Self) -> Self {
+    fn ln_special_poly<V: crate::traits::Lanes<Scalar = Self>>(x: V) -> V {
         // GENERATE: ln::ln_special_poly f64 7
         const K2: f64 = f64::from_bits(0x3FE5555555555592); // 6.666666666666734e-1
         const K4: f64 = f64::from_bits(0x3FD999999997FCEC); // 3.999999999941355e-1
@@ -51,7 +51,7 @@ impl crate::generic::Ln for f64 {
     }
 
     #[inline]
-    fn ln_special_poly_ex(x2: Self) -> Self {
+    fn ln_special_poly_ex<V: crate::traits::Lanes<Scalar = Self>>(x2: V) -> V {
         // GENERATE: ln::ln_special_poly_ex f64 6
         const K6: f64 = f64::from_bits(0x3FD24924924812EE); // 2.8571428571039703e-1
         const K8: f64 = f64::from_bits(0x3FCC71C71F9F60BA); // 2.222222237021521e-1
@@ -0,0 +1,69 @@
+use crate::double::NormDouble;
+
+impl crate::generic::Polygamma for f64 {
+    #[inline]
+    fn digamma_asympt_th() -> Self {
+        10.0
+    }
+
+    #[inline]
+    fn digamma_root() -> NormDouble<Self> {
+        // GENERATE: polygamma::consts f64
+        const ROOT_HI: f64 = f64::from_bits(0x3FF762D86356BE3F); // 1.4616321449683622e0
+        const ROOT_LO: f64 = f64::from_bits(0x3C9B86A722197829); // 9.549995429965697e-17
+
+        NormDouble::with_parts(ROOT_HI, ROOT_LO)
+    }
+
+    #[inline]
+    fn digamma_root_poly(x: Self) -> (Self, NormDouble<Self>) {
+        // GENERATE: polygamma::digamma_root_poly f64 10 0.0625
+        const K1_HI: f64 = f64::from_bits(0x3FEEF72BC8EE38AB); // 9.676722454476211e-1
+        const K1_LO: f64 = f64::from_bits(0x3C963BC1E871A9CC); // 7.713769744840581e-17
+        const K2: f64 = f64::from_bits(0xBFDC563B54AA1A37); // -4.427631689835922e-1
+        const K3: f64 = f64::from_bits(0x3FD08B4294D50383); // 2.5849976095565114e-1
+        const K4: f64 = f64::from_bits(0xBFC4FC1317254AAD); // -1.6394270544204428e-1
+        const K5: f64 = f64::from_bits(0x3FBB9A5B637083EE); // 1.078240506908654e-1
+        const K6: f64 = f64::from_bits(0xBFB27BABA49C9CB6); // -7.219956177560757e-2
+        const K7: f64 = f64::from_bits(0x3FA8FCE02F1B34B7); // 4.880428862598179e-2
+        const K8: f64 = f64::from_bits(0xBFA0FA74935B618B); // -3.316082285681706e-2
+        const K9: f64 = f64::from_bits(0x3F9723C678CF10A6); // 2.2597409366213704e-2
+        const K10: f64 = f64::from_bits(0xBF8FBFBA1FC5427D); // -1.5502409071502793e-2
+        const K11: f64 = f64::from_bits(0x3F85B2E064D8FF28); // 1.059508617174705e-2
+
+        let r = horner!(x, x, [K2, K3, K4, K5, K6, K7, K8, K9, K10, K11]);
+
+        (r, NormDouble::with_parts(K1_HI, K1_LO))
+    }
+
+    #[inline]
+    fn digamma_asympt_poly(x: Self) -> Self {
+        // GENERATE: polygamma::digamma_asympt_poly f64 6 10
+        const K0: f64 = f64::from_bits(0x3FB5555555555555); // 8.333333333333333e-2
+        const K1: f64 = f64::from_bits(0xBF811111111107C2); // -8.3333333333292e-3
+        const K2: f64 = f64::from_bits(0x3F7041040FCB88A6); // 3.968253961575885e-3
+        const K3: f64 = f64::from_bits(0xBF71110FFA85E671); // -4.166662613323533e-3
+        const K4: f64 = f64::from_bits(0x3F7F06861F70C568); // 7.574581067318099e-3
+        const K5: f64 = f64::from_bits(0xBF956AF9A7E794DC); // -2.0915890572628423e-2
+        const K6: f64 = f64::from_bits(0x3FB1E06C2E4A17BE); // 6.983066682923231e-2
+
+        K0 + horner!(x, x, [K1, K2, K3, K4, K5, K6])
+    }
+
+    #[inline]
+    fn polygamma_em_coeffs() -> &'static [Self] {
+        // GENERATE: polygamma::em_coeffs f64 10
+        const B1: f64 = f64::from_bits(0x3FB5555555555555); // 8.333333333333333e-2
+        const B2: f64 = f64::from_bits(0xBF56C16C16C16C17); // -1.388888888888889e-3
+        const B3: f64 = f64::from_bits(0x3F01566ABC011567); // 3.306878306878307e-5
+        const B4: f64 = f64::from_bits(0xBEABBD779334EF0B); // -8.267195767195768e-7
+        const B5: f64 = f64::from_bits(0x3E566A8F2BF70EBE); // 2.08767569878681e-8
+        const B6: f64 = f64::from_bits(0xBE022805D644267F); // -5.284190138687493e-10
+        const B7: f64 = f64::from_bits(0x3DAD6DB2C4E09162); // 1.3382536530684679e-11
+        const B8: f64 = f64::from_bits(0xBD57DA4E1F79955C); // -3.3896802963225827e-13
+        const B9: f64 = f64::from_bits(0x3D0355871D652E9E); // 8.586062056277845e-15
+        const B10: f64 = f64::from_bits(0xBCAF57D968CAACF1); // -2.174868698558062e-16
+
+        &[B1, B2, B3, B4, B5, B6, B7, B8, B9, B10]
+    }
+}
@@ -1,5 +1,5 @@
 use crate::generic::scalbn_medium;
-use crate::traits::{Float as _, FloatConsts as _};
+use crate::traits::{Float as _, FloatConsts as _, Lanes as _};
 
 // GENERATE: reduce_pi_2::consts f64
 const FRAC_PI_2_HI: f64 = f64::from_bits(0x3FF921FB54400000); // 1.5707963267341256e0
@@ -11,7 +11,7 @@ impl crate::generic::SinCos for f64 {
     }
 
     #[inline]
-    fn sin_poly(x2: Self, x5: Self) -> (Self, Self) {
+    fn sin_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V, x5: V) -> (V, Self) {
         // GENERATE: sin_cos::sin_poly f64 6
         const K3: f64 = f64::from_bits(0xBFC5555555555549); // -1.6666666666666632e-1
         const K5: f64 = f64::from_bits(0x3F8111111110F850); // 8.33333333332234e-3
@@ -37,7 +37,7 @@ impl crate::generic::SinCos for f64 {
     }
 
     #[inline]
-    fn cos_poly(x2: Self, x4: Self) -> Self {
+    fn cos_poly<V: crate::traits::Lanes<Scalar = Self>>(x2: V, x4: V) -> V {
         // GENERATE: sin_cos::cos_poly f64 6
         const K4: f64 = f64::from_bits(0x3FA555555555554C); // 4.16666666666666e-2
         const K6: f64 = f64::from_bits(0xBF56C16C16C15150); // -1.3888888888874025e-3
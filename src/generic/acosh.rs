@@ -1,4 +1,5 @@
 use super::Ln;
+use super::asinh::large_raw_exp;
 use super::ln::{ln_hi_lo_inner, ln_inner};
 use super::sqrt::hi_lo_sqrt_hi_lo_inner;
 use crate::double::DenormDouble;
@@ -16,7 +17,7 @@ pub(crate) fn acosh<F: Ln>(x: F) -> F {
         // x is infinity or NaN
         // acosh(x) = x
         x
-    } else if e > (F::RawExp::from(F::MANT_BITS) + F::EXP_OFFSET) {
+    } else if e > large_raw_exp::<F>() {
         ln_inner(x, F::Exp::ONE)
     } else {
         acosh_inner(x)
@@ -52,10 +53,10 @@ fn acosh_inner<F: Ln>(x: F) -> F {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>() {
+    fn test<F: Float + ElementaryMath>() {
         use crate::acosh;
 
         assert_is_nan!(acosh(F::NAN));
@@ -67,6 +68,12 @@ mod tests {
         assert_total_eq!(acosh(F::one()), F::ZERO);
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>();
@@ -76,4 +83,16 @@ mod tests {
     fn test_f64() {
         test::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
 }
@@ -128,9 +128,9 @@ pub(crate) fn acos<F: AsinAcos>(x: F) -> F {
 #[cfg(test)]
 mod tests {
     use super::AsinAcos;
-    use crate::FloatMath;
+    use crate::ElementaryMath;
 
-    fn test_asin<F: AsinAcos + FloatMath>() {
+    fn test_asin<F: AsinAcos + ElementaryMath>() {
         use crate::asin;
 
         let f = F::parse;
@@ -146,7 +146,7 @@ mod tests {
         assert_total_eq!(asin(-F::one()), -F::FRAC_PI_2);
     }
 
-    fn test_acos<F: AsinAcos + FloatMath>() {
+    fn test_acos<F: AsinAcos + ElementaryMath>() {
         use crate::acos;
 
         let f = F::parse;
@@ -162,6 +162,13 @@ mod tests {
         assert_total_eq!(acos(-F::one()), F::PI);
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_asin::<f16>();
+        test_acos::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test_asin::<f32>();
@@ -173,4 +180,11 @@ mod tests {
         test_asin::<f64>();
         test_acos::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test_asin::<f128>();
+        test_acos::<f128>();
+    }
 }
@@ -1,5 +1,6 @@
 use super::asin_acos::{acos_inner, asin_inner};
-use super::{AsinAcos, RadToDeg};
+use super::{AsinAcos, RadToDeg, tiny_raw_exp, tiny_scale_exp};
+use crate::double::SemiDouble;
 use crate::traits::Int as _;
 
 pub(crate) fn asind<F: AsinAcos + RadToDeg>(x: F) -> F {
@@ -10,10 +11,20 @@ pub(crate) fn asind<F: AsinAcos + RadToDeg>(x: F) -> F {
     } else if e >= F::EXP_OFFSET {
         // NaN or |x| > 1 (including infinity)
         F::NAN
-    } else if e <= F::RawExp::from(F::MANT_BITS) {
-        // subnormal or zero, asind(x) ~= x * (180/π)
-        // also handles asind(-0) = -0
-        x * F::rad_to_deg()
+    } else if e == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO {
+        // asind(±0) = ±0
+        x
+    } else if e <= tiny_raw_exp::<F>() {
+        // very small, asind(x) ~= x * (180/π)
+
+        // scale temporarily to avoid temporary subnormal numbers
+        let logscale = tiny_scale_exp::<F>();
+        let scale = F::exp2i_fast(logscale);
+        let descale = F::exp2i_fast(-logscale);
+
+        let nx = SemiDouble::new(x * scale);
+
+        (nx * F::rad_to_deg_ex()).to_single() * descale
     } else {
         let y = asin_inner(x).to_semi();
 
@@ -47,10 +58,10 @@ pub(crate) fn acosd<F: AsinAcos + RadToDeg>(x: F) -> F {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test_asind<F: Float + FloatMath>() {
+    fn test_asind<F: Float + ElementaryMath>() {
         use crate::asind;
 
         let f = F::parse;
@@ -66,7 +77,7 @@ mod tests {
         assert_total_eq!(asind(-F::one()), f("-90"));
     }
 
-    fn test_acosd<F: Float + FloatMath>() {
+    fn test_acosd<F: Float + ElementaryMath>() {
         use crate::acosd;
 
         let f = F::parse;
@@ -82,6 +93,13 @@ mod tests {
         assert_total_eq!(acosd(-F::one()), f("180"));
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_asind::<f16>();
+        test_acosd::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test_asind::<f32>();
@@ -93,4 +111,18 @@ mod tests {
         test_asind::<f64>();
         test_acosd::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test_asind::<f128>();
+        test_acosd::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test_asind::<half::bf16>();
+        test_acosd::<half::bf16>();
+    }
 }
@@ -2,7 +2,7 @@ use super::Ln;
 use super::ln::{ln_hi_lo_inner, ln_inner};
 use super::sqrt::hi_lo_sqrt_hi_lo_inner;
 use crate::double::DenormDouble;
-use crate::traits::Int as _;
+use crate::traits::{Float, Int as _};
 
 pub(crate) fn asinh<F: Ln>(x: F) -> F {
     let e = x.raw_exp();
@@ -15,7 +15,7 @@ pub(crate) fn asinh<F: Ln>(x: F) -> F {
         // asinh(x) ~= x
         // also handles asinh(-0) = -0
         x
-    } else if e > (F::RawExp::from(F::MANT_BITS) + F::EXP_OFFSET) {
+    } else if e > large_raw_exp::<F>() {
         let y = ln_inner(x.abs(), F::Exp::ONE);
         y.copysign(x)
     } else {
@@ -23,6 +23,13 @@ pub(crate) fn asinh<F: Ln>(x: F) -> F {
     }
 }
 
+/// Returns the raw exponent above which `x^2` is negligible compared to
+/// `x^2 + 1` (or `x^2 - 1`), limited so `x^2` does not overflow when the
+/// exponent range is narrow
+pub(super) fn large_raw_exp<F: Float>() -> F::RawExp {
+    F::RawExp::from(F::MANT_BITS).min((F::EXP_OFFSET >> 1u8) - F::RawExp::ONE) + F::EXP_OFFSET
+}
+
 fn asinh_inner<F: Ln>(x: F) -> F {
     let absx = x.abs();
     let x2 = x * x;
@@ -45,10 +52,10 @@ fn asinh_inner<F: Ln>(x: F) -> F {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>() {
+    fn test<F: Float + ElementaryMath>() {
         use crate::asinh;
 
         assert_is_nan!(asinh(F::NAN));
@@ -58,6 +65,12 @@ mod tests {
         assert_total_eq!(asinh(-F::ZERO), -F::ZERO);
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>();
@@ -67,4 +80,16 @@ mod tests {
     fn test_f64() {
         test::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
 }
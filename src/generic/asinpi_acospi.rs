@@ -1,7 +1,7 @@
 use super::asin_acos::{acos_inner, asin_inner};
-use super::{AsinAcos, DivPi};
+use super::{AsinAcos, DivPi, tiny_raw_exp, tiny_scale_exp};
 use crate::double::SemiDouble;
-use crate::traits::{CastFrom as _, Int as _};
+use crate::traits::Int as _;
 
 pub(crate) fn asinpi<F: AsinAcos + DivPi>(x: F) -> F {
     let e = x.raw_exp();
@@ -14,11 +14,11 @@ pub(crate) fn asinpi<F: AsinAcos + DivPi>(x: F) -> F {
     } else if e == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO {
         // asinpi(±0) = ±0
         x
-    } else if e <= F::RawExp::from(F::MANT_BITS) {
+    } else if e <= tiny_raw_exp::<F>() {
         // very small, asinpi(x) ~= x / π
 
         // scale temporarily to avoid temporary subnormal numbers
-        let logscale = F::Exp::TWO * F::Exp::cast_from(F::MANT_BITS);
+        let logscale = tiny_scale_exp::<F>();
         let scale = F::exp2i_fast(logscale);
         let descale = F::exp2i_fast(-logscale);
 
@@ -58,10 +58,10 @@ pub(crate) fn acospi<F: AsinAcos + DivPi>(x: F) -> F {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test_asinpi<F: Float + FloatMath>() {
+    fn test_asinpi<F: Float + ElementaryMath>() {
         use crate::asinpi;
 
         let f = F::parse;
@@ -77,7 +77,7 @@ mod tests {
         assert_total_eq!(asinpi(-F::one()), -F::half());
     }
 
-    fn test_acospi<F: Float + FloatMath>() {
+    fn test_acospi<F: Float + ElementaryMath>() {
         use crate::acospi;
 
         let f = F::parse;
@@ -93,6 +93,13 @@ mod tests {
         assert_total_eq!(acospi(-F::one()), F::one());
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_asinpi::<f16>();
+        test_acospi::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test_asinpi::<f32>();
@@ -104,4 +111,18 @@ mod tests {
         test_asinpi::<f64>();
         test_acospi::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test_asinpi::<f128>();
+        test_acospi::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test_asinpi::<half::bf16>();
+        test_acospi::<half::bf16>();
+    }
 }
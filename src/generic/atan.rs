@@ -1,13 +1,14 @@
+use super::tiny_raw_exp;
 use crate::double::{DenormDouble, SemiDouble};
-use crate::traits::{CastInto as _, FloatConsts, Int as _};
+use crate::traits::{CastFrom as _, Float, FloatConsts, Int as _, Lanes};
 
 pub(crate) trait Atan: FloatConsts {
     fn frac_pi_2_hi() -> Self;
     fn frac_pi_2_lo() -> Self;
     fn frac_3pi_4() -> Self;
 
-    // Returns `(k3, (atan(x) - x) / x^3 - k3)`
-    fn atan_poly(x2: Self) -> (Self, Self);
+    // Returns `(a, b)`, such as `a + b = (atan(x) - x) / x^3` and `|a| >= |b|`
+    fn atan_poly<V: Lanes<Scalar = Self>>(x2: V) -> (V, V);
 }
 
 pub(crate) fn atan<F: Atan>(x: F) -> F {
@@ -20,8 +21,8 @@ pub(crate) fn atan<F: Atan>(x: F) -> F {
             // propagate NaN
             x
         }
-    } else if e <= F::MANT_BITS.into() {
-        // subnormal or zero
+    } else if e <= tiny_raw_exp::<F>() {
+        // very small, subnormal or zero
         // atan(x) ~= x
         // also handles atan(-0) = -0
         x
@@ -31,13 +32,7 @@ pub(crate) fn atan<F: Atan>(x: F) -> F {
 }
 
 pub(crate) fn atan2<F: Atan>(y: F, x: F) -> F {
-    let (ny, nx) = if y.raw_exp() <= F::MANT_BITS.into() || x.raw_exp() <= F::MANT_BITS.into() {
-        // convert possible subnormals to normals
-        let scale = F::exp2i_fast((F::MANT_BITS * 2 + 1).cast_into());
-        (y * scale, x * scale)
-    } else {
-        (y, x)
-    };
+    let (ny, nx) = atan2_normalize(y, x);
 
     let nxexp = nx.raw_exp();
     let nyexp = ny.raw_exp();
@@ -70,10 +65,7 @@ pub(crate) fn atan2<F: Atan>(y: F, x: F) -> F {
     } else if nxexp == F::RawExp::ZERO {
         // x = ±0
         F::FRAC_PI_2.copysign(ny)
-    } else if !nx.sign()
-        && nxexp > nyexp
-        && (nxexp - nyexp) >= ((F::MAX_RAW_EXP >> 1) - F::MANT_BITS.into())
-    {
+    } else if !nx.sign() && nxexp > nyexp && (nxexp - nyexp) >= atan2_tiny_exp_diff::<F>() {
         // y/x is very small
         // atan2(y, x) ~= y/x
         ny / nx
@@ -82,6 +74,53 @@ pub(crate) fn atan2<F: Atan>(y: F, x: F) -> F {
     }
 }
 
+/// Scales `y` and `x` by the same power of two to convert possible subnormals
+/// to normals
+///
+/// Subnormals that cannot be converted without overflowing the other argument
+/// are replaced with zero, which does not change the result of `atan2`.
+pub(super) fn atan2_normalize<F: Float>(y: F, x: F) -> (F, F) {
+    if y.raw_exp() <= F::MANT_BITS.into() || x.raw_exp() <= F::MANT_BITS.into() {
+        let max_exp = y.exponent().max(x.exponent());
+        let logscale = F::Exp::cast_from(F::MANT_BITS * 2 + 1)
+            .min(F::MAX_EXP)
+            .min(F::MAX_EXP - max_exp);
+        let scale = F::exp2i_fast(logscale);
+
+        let flush = |v: F| {
+            if v.raw_exp() == F::RawExp::ZERO {
+                F::ZERO.copysign(v)
+            } else {
+                v
+            }
+        };
+        (flush(y * scale), flush(x * scale))
+    } else {
+        (y, x)
+    }
+}
+
+/// Returns the minimum difference of raw exponents of `x` and `y` for
+/// `atan2(y, x) ~= y / x` (with `x > 0`)
+#[inline]
+pub(super) fn atan2_tiny_exp_diff<F: Float>() -> F::RawExp {
+    ((F::MAX_RAW_EXP >> 1) - F::MANT_BITS.into()).max((F::MANT_BITS / 2 + 3).into())
+}
+
+/// Returns the exponent of the factor used to scale temporarily `y` when
+/// `y / x` is very small to avoid subnormal numbers
+///
+/// No scaling is applied when `y` is too large, which can only happen when
+/// the exponent range is narrow.
+#[inline]
+pub(super) fn atan2_tiny_scale_exp<F: Float>(y: F) -> F::Exp {
+    if y.exponent() < F::MAX_EXP - F::Exp::cast_from(F::MANT_BITS + 6) {
+        F::Exp::cast_from(F::MANT_BITS)
+    } else {
+        F::Exp::ZERO
+    }
+}
+
 pub(super) fn atan_inner<F: Atan>(x: F) -> DenormDouble<F> {
     if x.abs() <= F::one() {
         atan_inner_common(SemiDouble::new(x))
@@ -111,6 +150,15 @@ pub(super) fn atan2_inner<F: Atan>(mut n: F, mut d: F) -> DenormDouble<F> {
         off = off + F::one().set_sign(ysgn ^ xsgn);
     }
 
+    atan2_inner_common(n, d, off)
+}
+
+/// Calculates `atan(n/d) + off * π/2`, where `|n| <= |d|`
+pub(super) fn atan2_inner_common<F: Atan, V: Lanes<Scalar = F>>(
+    n: V,
+    d: V,
+    off: V,
+) -> DenormDouble<V> {
     // z = n/d
     let z = DenormDouble::new_div11(n, d);
 
@@ -118,16 +166,18 @@ pub(super) fn atan2_inner<F: Atan>(mut n: F, mut d: F) -> DenormDouble<F> {
     let t1 = atan_inner_common(z.to_semi());
 
     // t2 = off * π/2
-    let t2 = DenormDouble::new(F::frac_pi_2_hi() * off, F::frac_pi_2_lo() * off);
+    let t2 = DenormDouble::new(off * F::frac_pi_2_hi(), off * F::frac_pi_2_lo());
 
     // atan2(y, x) = atan(n/d) + off * π/2 = t1 + t2
     t2.qadd2(t1)
 }
 
-pub(super) fn atan_inner_common<F: Atan>(x: SemiDouble<F>) -> DenormDouble<F> {
+pub(super) fn atan_inner_common<F: Atan, V: Lanes<Scalar = F>>(
+    x: SemiDouble<V>,
+) -> DenormDouble<V> {
     let x2 = x.square();
 
-    // t1 = (atan(x) - x) / x^3 - k3
+    // (atan(x) - x) / x^3 split in two parts
     let (k3, t1) = F::atan_poly(x2.to_single());
 
     // t2 = (atan(x) - x) / x^3 = t1 + k3
@@ -145,9 +195,9 @@ pub(super) fn atan_inner_common<F: Atan>(x: SemiDouble<F>) -> DenormDouble<F> {
 #[cfg(test)]
 mod tests {
     use super::Atan;
-    use crate::FloatMath;
+    use crate::ElementaryMath;
 
-    fn test_atan<F: Atan + FloatMath>() {
+    fn test_atan<F: Atan + ElementaryMath>() {
         use crate::atan;
 
         assert_is_nan!(atan(F::NAN));
@@ -157,7 +207,7 @@ mod tests {
         assert_total_eq!(atan(-F::ZERO), -F::ZERO);
     }
 
-    fn test_atan2<F: Atan + FloatMath>() {
+    fn test_atan2<F: Atan + ElementaryMath>() {
         use crate::atan2;
 
         assert_is_nan!(atan2(F::NAN, F::one()));
@@ -202,6 +252,13 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_atan::<f16>();
+        test_atan2::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test_atan::<f32>();
@@ -213,4 +270,11 @@ mod tests {
         test_atan::<f64>();
         test_atan2::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test_atan::<f128>();
+        test_atan2::<f128>();
+    }
 }
@@ -1,7 +1,9 @@
-use super::atan::{atan_inner, atan2_inner};
-use super::{Atan, RadToDeg};
+use super::atan::{
+    atan_inner, atan2_inner, atan2_normalize, atan2_tiny_exp_diff, atan2_tiny_scale_exp,
+};
+use super::{Atan, RadToDeg, tiny_raw_exp, tiny_scale_exp};
 use crate::double::SemiDouble;
-use crate::traits::{CastFrom as _, CastInto as _, Int as _};
+use crate::traits::Int as _;
 
 pub(crate) fn atand<F: Atan + RadToDeg>(x: F) -> F {
     let e = x.raw_exp();
@@ -13,11 +15,20 @@ pub(crate) fn atand<F: Atan + RadToDeg>(x: F) -> F {
             // propagate NaN
             x
         }
-    } else if e <= F::MANT_BITS.into() {
-        // subnormal or zero
-        // atand(x) ~= x * (180/π)
-        // also handles atand(-0) = -0
-        x * F::rad_to_deg()
+    } else if e == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO {
+        // atand(±0) = ±0
+        x
+    } else if e <= tiny_raw_exp::<F>() {
+        // very small, atand(x) ~= x * (180/π)
+
+        // scale temporarily to avoid temporary subnormal numbers
+        let logscale = tiny_scale_exp::<F>();
+        let scale = F::exp2i_fast(logscale);
+        let descale = F::exp2i_fast(-logscale);
+
+        let nx = SemiDouble::new(x * scale);
+
+        (nx * F::rad_to_deg_ex()).to_single() * descale
     } else {
         let y = atan_inner(x).to_semi();
 
@@ -26,13 +37,7 @@ pub(crate) fn atand<F: Atan + RadToDeg>(x: F) -> F {
 }
 
 pub(crate) fn atan2d<F: Atan + RadToDeg>(y: F, x: F) -> F {
-    let (ny, nx) = if y.raw_exp() <= F::MANT_BITS.into() || x.raw_exp() <= F::MANT_BITS.into() {
-        // convert possible subnormals to normals
-        let scale = F::exp2i_fast((F::MANT_BITS * 2 + 1).cast_into());
-        (y * scale, x * scale)
-    } else {
-        (y, x)
-    };
+    let (ny, nx) = atan2_normalize(y, x);
 
     let nxexp = nx.raw_exp();
     let nyexp = ny.raw_exp();
@@ -69,12 +74,10 @@ pub(crate) fn atan2d<F: Atan + RadToDeg>(y: F, x: F) -> F {
     } else if nxexp == F::RawExp::ZERO {
         // x = ±0
         F::cast_from(90u32).copysign(ny)
-    } else if !nx.sign()
-        && nxexp > nyexp
-        && (nxexp - nyexp) >= ((F::MAX_RAW_EXP >> 1) - F::MANT_BITS.into())
-    {
-        let scale = F::exp2i_fast(F::Exp::cast_from(F::MANT_BITS));
-        let descale = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS));
+    } else if !nx.sign() && nxexp > nyexp && (nxexp - nyexp) >= atan2_tiny_exp_diff::<F>() {
+        let logscale = atan2_tiny_scale_exp(ny);
+        let scale = F::exp2i_fast(logscale);
+        let descale = F::exp2i_fast(-logscale);
 
         // y/x is very small
         // atan2d(y, x) ~= (y/x) * (180/π)
@@ -93,10 +96,10 @@ pub(crate) fn atan2d<F: Atan + RadToDeg>(y: F, x: F) -> F {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test_atand<F: Float + FloatMath>() {
+    fn test_atand<F: Float + ElementaryMath>() {
         use crate::atand;
 
         let f = F::parse;
@@ -108,7 +111,7 @@ mod tests {
         assert_total_eq!(atand(-F::ZERO), -F::ZERO);
     }
 
-    fn test_atan2d<F: Float + FloatMath>() {
+    fn test_atan2d<F: Float + ElementaryMath>() {
         use crate::atan2d;
 
         let f = F::parse;
@@ -152,6 +155,13 @@ mod tests {
         assert_total_eq!(atan2d(F::neg_infinity(), F::neg_infinity()), f("-135"));
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_atand::<f16>();
+        test_atan2d::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test_atand::<f32>();
@@ -163,4 +173,18 @@ mod tests {
         test_atand::<f64>();
         test_atan2d::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test_atand::<f128>();
+        test_atan2d::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test_atand::<half::bf16>();
+        test_atan2d::<half::bf16>();
+    }
 }
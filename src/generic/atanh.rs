@@ -40,10 +40,10 @@ fn atanh_inner<F: Ln>(x: F) -> F {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>() {
+    fn test<F: Float + ElementaryMath>() {
         use crate::atanh;
 
         let f = F::parse;
@@ -59,6 +59,12 @@ mod tests {
         assert_total_eq!(atanh(-F::one()), F::neg_infinity());
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>();
@@ -68,4 +74,16 @@ mod tests {
     fn test_f64() {
         test::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
 }
@@ -1,7 +1,9 @@
-use super::atan::{atan_inner, atan2_inner};
-use super::{Atan, DivPi};
+use super::atan::{
+    atan_inner, atan2_inner, atan2_normalize, atan2_tiny_exp_diff, atan2_tiny_scale_exp,
+};
+use super::{Atan, DivPi, tiny_raw_exp, tiny_scale_exp};
 use crate::double::SemiDouble;
-use crate::traits::{CastFrom as _, CastInto as _, Int as _};
+use crate::traits::Int as _;
 
 pub(crate) fn atanpi<F: Atan + DivPi>(x: F) -> F {
     let e = x.raw_exp();
@@ -16,11 +18,11 @@ pub(crate) fn atanpi<F: Atan + DivPi>(x: F) -> F {
     } else if e == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO {
         // atanpi(±0) = ±0
         x
-    } else if e <= F::RawExp::from(F::MANT_BITS) {
+    } else if e <= tiny_raw_exp::<F>() {
         // very small, atanpi(x) ~= x / π
 
         // scale temporarily to avoid temporary subnormal numbers
-        let logscale = F::Exp::TWO * F::Exp::cast_from(F::MANT_BITS);
+        let logscale = tiny_scale_exp::<F>();
         let scale = F::exp2i_fast(logscale);
         let descale = F::exp2i_fast(-logscale);
 
@@ -35,13 +37,7 @@ pub(crate) fn atanpi<F: Atan + DivPi>(x: F) -> F {
 }
 
 pub(crate) fn atan2pi<F: Atan + DivPi>(y: F, x: F) -> F {
-    let (ny, nx) = if y.raw_exp() <= F::MANT_BITS.into() || x.raw_exp() <= F::MANT_BITS.into() {
-        // convert possible subnormals to normals
-        let scale = F::exp2i_fast((F::MANT_BITS * 2 + 1).cast_into());
-        (y * scale, x * scale)
-    } else {
-        (y, x)
-    };
+    let (ny, nx) = atan2_normalize(y, x);
 
     let nxexp = nx.raw_exp();
     let nyexp = ny.raw_exp();
@@ -76,12 +72,10 @@ pub(crate) fn atan2pi<F: Atan + DivPi>(y: F, x: F) -> F {
     } else if nxexp == F::RawExp::ZERO {
         // x = ±0
         F::half().copysign(ny)
-    } else if !nx.sign()
-        && nxexp > nyexp
-        && (nxexp - nyexp) >= ((F::MAX_RAW_EXP >> 1) - F::MANT_BITS.into())
-    {
-        let scale = F::exp2i_fast(F::Exp::cast_from(F::MANT_BITS));
-        let descale = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS));
+    } else if !nx.sign() && nxexp > nyexp && (nxexp - nyexp) >= atan2_tiny_exp_diff::<F>() {
+        let logscale = atan2_tiny_scale_exp(ny);
+        let scale = F::exp2i_fast(logscale);
+        let descale = F::exp2i_fast(-logscale);
 
         // y/x is very small
         // atan2pi(y, x) ~= (y/x) / π
@@ -100,10 +94,10 @@ pub(crate) fn atan2pi<F: Atan + DivPi>(y: F, x: F) -> F {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test_atanpi<F: Float + FloatMath>() {
+    fn test_atanpi<F: Float + ElementaryMath>() {
         use crate::atanpi;
 
         assert_is_nan!(atanpi(F::NAN));
@@ -113,7 +107,7 @@ mod tests {
         assert_total_eq!(atanpi(-F::ZERO), -F::ZERO);
     }
 
-    fn test_atan2pi<F: Float + FloatMath>() {
+    fn test_atan2pi<F: Float + ElementaryMath>() {
         use crate::atan2pi;
 
         let f = F::parse;
@@ -157,6 +151,13 @@ mod tests {
         assert_total_eq!(atan2pi(F::neg_infinity(), F::neg_infinity()), f("-0.75"));
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_atanpi::<f16>();
+        test_atan2pi::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test_atanpi::<f32>();
@@ -168,4 +169,18 @@ mod tests {
         test_atanpi::<f64>();
         test_atan2pi::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test_atanpi::<f128>();
+        test_atan2pi::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test_atanpi::<half::bf16>();
+        test_atan2pi::<half::bf16>();
+    }
 }
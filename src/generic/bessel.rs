@@ -0,0 +1,665 @@
+use super::ln::hi_lo_ln_inner;
+use super::sin_cos::{hi_lo_cos_inner, hi_lo_sin_inner};
+use super::sqrt::two_hi_lo_sqrt_inner;
+use super::{Ln, ReducePi2, SinCos, ln, reduce_pi_2};
+use crate::double::{DenormDouble, NormDouble, SemiDouble};
+use crate::traits::{CastFrom as _, Float, Int as _};
+
+pub(crate) trait Bessel: SinCos + ReducePi2 + Ln {
+    fn frac_pi_4_ex() -> NormDouble<Self>;
+    fn frac_2_pi_ex() -> NormDouble<Self>;
+    fn sqrt_frac_2_pi_ex() -> NormDouble<Self>;
+
+    /// Calculates `(r, K3)` such as
+    /// `j0(x) = 1 - u / 4 + u^2 / 64 + u^3 * (K3 + r)`
+    ///
+    /// Where:
+    /// * `u = x^2`
+    /// * `|x| < 2`
+    fn j0_small_poly(u: Self) -> (Self, Self);
+
+    /// Calculates `(r, K2)` such as
+    /// `j1(x) / x = 1 / 2 - u / 16 + u^2 * (K2 + r)`
+    ///
+    /// Where:
+    /// * `u = x^2`
+    /// * `|x| < 2`
+    fn j1_small_poly(u: Self) -> (Self, Self);
+
+    /// Calculates `(r, K0, K1, K2)` such as
+    /// `y0(x) - 2 / π * ln(x) * j0(x) = K0 + u * (K1 + u * (K2 + r))`
+    ///
+    /// Where:
+    /// * `u = x^2`
+    /// * `0 < x < 2`
+    fn y0_small_poly(u: Self) -> (Self, NormDouble<Self>, NormDouble<Self>, Self);
+
+    /// Calculates `(r, K0, K1, K2)` such as
+    /// `(y1(x) - 2 / π * (ln(x) * j1(x) - 1 / x)) / x = K0 + u * (K1 + u * (K2 + r))`
+    ///
+    /// Where:
+    /// * `u = x^2`
+    /// * `0 < x < 2`
+    fn y1_small_poly(u: Self) -> (Self, NormDouble<Self>, NormDouble<Self>, Self);
+
+    /// Calculates `(α, r, K0, K1)` such as
+    /// * `j0(x) = √(2 / (π * x)) * (1 + α) * cos(θ)`
+    /// * `y0(x) = √(2 / (π * x)) * (1 + α) * sin(θ)`
+    /// * `θ = x - π/4 + K0 + z * (K1 + r)`
+    ///
+    /// Where:
+    /// * `z = 1 / x - 3/8`
+    /// * `2 <= x < 4`
+    fn asympt0_poly_1(z: Self) -> (Self, Self, NormDouble<Self>, Self);
+
+    /// Like [`Bessel::asympt0_poly_1`] with `z = 1 / x - 3/16` and `4 <= x < 8`
+    fn asympt0_poly_2(z: Self) -> (Self, Self, NormDouble<Self>, Self);
+
+    /// Calculates `(α, β)` such as
+    /// * `j0(x) = √(2 / (π * x)) * (1 + α) * cos(θ)`
+    /// * `y0(x) = √(2 / (π * x)) * (1 + α) * sin(θ)`
+    /// * `θ = x - π/4 + (β - 1/8) / x`
+    ///
+    /// Where:
+    /// * `u = 1 / x^2`
+    /// * `8 <= x`
+    fn asympt0_poly_3(u: Self) -> (Self, Self);
+
+    /// Calculates `(α, r, K0, K1)` such as
+    /// * `j1(x) = √(2 / (π * x)) * (1 + α) * cos(θ)`
+    /// * `y1(x) = √(2 / (π * x)) * (1 + α) * sin(θ)`
+    /// * `θ = x - 3 * π/4 + K0 + z * (K1 + r)`
+    ///
+    /// Where:
+    /// * `z = 1 / x - 3/8`
+    /// * `2 <= x < 4`
+    fn asympt1_poly_1(z: Self) -> (Self, Self, NormDouble<Self>, Self);
+
+    /// Like [`Bessel::asympt1_poly_1`] with `z = 1 / x - 3/16` and `4 <= x < 8`
+    fn asympt1_poly_2(z: Self) -> (Self, Self, NormDouble<Self>, Self);
+
+    /// Calculates `(α, β)` such as
+    /// * `j1(x) = √(2 / (π * x)) * (1 + α) * cos(θ)`
+    /// * `y1(x) = √(2 / (π * x)) * (1 + α) * sin(θ)`
+    /// * `θ = x - 3 * π/4 + (β + 3/8) / x`
+    ///
+    /// Where:
+    /// * `u = 1 / x^2`
+    /// * `8 <= x`
+    fn asympt1_poly_3(u: Self) -> (Self, Self);
+}
+
+pub(crate) fn j0<F: Bessel>(x: F) -> F {
+    let e = x.raw_exp();
+    if e == F::MAX_RAW_EXP {
+        if x.raw_mant() == F::Raw::ZERO {
+            // j0(±inf) = 0
+            F::ZERO
+        } else {
+            // propagate NaN
+            x
+        }
+    } else {
+        let absx = x.abs();
+        if absx < F::two() {
+            // also handles zero and subnormals
+            j0_inner_small(absx).to_single()
+        } else {
+            asympt_0(absx, false)
+        }
+    }
+}
+
+pub(crate) fn j1<F: Bessel>(x: F) -> F {
+    let e = x.raw_exp();
+    if e == F::MAX_RAW_EXP {
+        if x.raw_mant() == F::Raw::ZERO {
+            // j1(±inf) = ±0
+            F::ZERO.copysign(x)
+        } else {
+            // propagate NaN
+            x
+        }
+    } else if e == F::RawExp::ZERO {
+        // j1(x) ~= x / 2 for zero and subnormals
+        // (exact unless the result is subnormal)
+        F::half() * x
+    } else {
+        // j1(-x) = -j1(x)
+        let absx = x.abs();
+        let y = if absx < F::two() {
+            j1_inner_small(absx).to_single()
+        } else {
+            asympt_1(absx, false)
+        };
+        if x.sign() { -y } else { y }
+    }
+}
+
+pub(crate) fn y0<F: Bessel>(x: F) -> F {
+    let e = x.raw_exp();
+    if e == F::MAX_RAW_EXP {
+        if x.raw_mant() == F::Raw::ZERO && !x.sign() {
+            // y0(inf) = 0
+            F::ZERO
+        } else {
+            // y0(NaN or -inf) = NaN
+            F::NAN
+        }
+    } else if e == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO {
+        // y0(±0) = -inf
+        F::neg_infinity()
+    } else if x.sign() {
+        // y0(x < 0) = NaN
+        F::NAN
+    } else if x < F::two() {
+        y0_inner_small(x).to_single()
+    } else {
+        asympt_0(x, true)
+    }
+}
+
+pub(crate) fn y1<F: Bessel>(x: F) -> F {
+    let e = x.raw_exp();
+    if e == F::MAX_RAW_EXP {
+        if x.raw_mant() == F::Raw::ZERO && !x.sign() {
+            // y1(inf) = 0
+            F::ZERO
+        } else {
+            // y1(NaN or -inf) = NaN
+            F::NAN
+        }
+    } else if e == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO {
+        // y1(±0) = -inf
+        F::neg_infinity()
+    } else if x.sign() {
+        // y1(x < 0) = NaN
+        F::NAN
+    } else if x < F::two() {
+        y1_inner_small(x)
+    } else {
+        asympt_1(x, true)
+    }
+}
+
+pub(crate) fn jn<F: Bessel>(n: i32, x: F) -> F {
+    // j(-n, x) = (-1)^n * j(n, x)
+    // j(n, -x) = (-1)^n * j(n, x)
+    let odd_n = (n & 1) != 0;
+    let neg = odd_n && ((n < 0) != x.sign());
+    let n = n.unsigned_abs();
+    let absx = x.abs();
+
+    let y = if x.raw_exp() == F::MAX_RAW_EXP && x.raw_mant() != F::Raw::ZERO {
+        // propagate NaN
+        return x;
+    } else if n == 0 {
+        return j0(x);
+    } else if n == 1 {
+        j1(absx)
+    } else if absx == F::ZERO || absx == F::INFINITY {
+        // jn(n, ±0) = ±0
+        // jn(n, ±inf) = ±0
+        F::ZERO
+    } else {
+        jn_inner(n, absx)
+    };
+
+    if neg { -y } else { y }
+}
+
+pub(crate) fn yn<F: Bessel>(n: i32, x: F) -> F {
+    let e = x.raw_exp();
+    if e == F::MAX_RAW_EXP {
+        if x.raw_mant() == F::Raw::ZERO && !x.sign() {
+            // yn(n, inf) = 0
+            F::ZERO
+        } else {
+            // yn(n, NaN or -inf) = NaN
+            F::NAN
+        }
+    } else if x.sign() && (e != F::RawExp::ZERO || x.raw_mant() != F::Raw::ZERO) {
+        // yn(n, x < 0) = NaN
+        F::NAN
+    } else {
+        // y(-n, x) = (-1)^n * y(n, x)
+        let neg = (n < 0) && (n & 1) != 0;
+        let y = match n.unsigned_abs() {
+            0 => y0(x),
+            1 => y1(x),
+            n => {
+                if e == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO {
+                    // yn(n, ±0) = -inf
+                    F::neg_infinity()
+                } else {
+                    yn_inner(n, x)
+                }
+            }
+        };
+        if neg { -y } else { y }
+    }
+}
+
+/// Calculates `j0(x)` for `0 <= x < 2`
+fn j0_inner_small<F: Bessel>(x: F) -> DenormDouble<F> {
+    // j0(x) = 1 - u / 4 + u^2 / 64 + u^3 * (K3 + r)
+    let u = SemiDouble::new(x).square();
+    let u_single = u.to_single();
+    let (r, k3) = F::j0_small_poly(u_single);
+
+    let quarter = F::half() * F::half();
+    let t1 = DenormDouble::new_qsub12(F::one(), u.pmul1(quarter));
+    let u2 = u.to_semi().square();
+    let t2 = u2.pmul1(quarter * quarter * quarter);
+    let u3 = (u2.to_semi() * u.to_semi()).to_semi();
+    let t3 = u3 * SemiDouble::new_qadd11(k3, r);
+
+    (t1 + t2) + t3
+}
+
+/// Calculates `j1(x)` for `0 <= x < 2`
+fn j1_inner_small<F: Bessel>(x: F) -> DenormDouble<F> {
+    // j1(x) = x * (1 / 2 - u / 16 + u^2 * (K2 + r))
+    let u = SemiDouble::new(x).square();
+    let u_single = u.to_single();
+    let (r, k2) = F::j1_small_poly(u_single);
+
+    let quarter = F::half() * F::half();
+    let t1 = DenormDouble::new_qsub12(F::half(), u.pmul1(quarter * quarter));
+    let t2 = u.to_semi().square().to_semi() * SemiDouble::new_qadd11(k2, r);
+
+    (t1 + t2).to_semi() * x
+}
+
+/// Calculates `y0(x)` for `0 < x < 2`
+fn y0_inner_small<F: Bessel>(x: F) -> DenormDouble<F> {
+    // y0(x) = 2 / π * ln(x) * j0(x) + K0 + u * (K1 + u * (K2 + r))
+    let (r, k0, k1, k2) = F::y0_small_poly(x * x);
+
+    let (xn, edelta) = x.normalize_arg();
+    let ln_x = hi_lo_ln_inner(xn, edelta);
+    let j0 = j0_inner_small(x);
+    let t1 = (F::frac_2_pi_ex().to_semi() * ln_x.to_semi()).to_semi() * j0.to_semi();
+
+    let t2 = finish_small_poly(x, r, k0, k1, k2);
+
+    t1 + t2
+}
+
+/// Calculates `y1(x)` for `0 < x < 2`
+fn y1_inner_small<F: Bessel>(x: F) -> F {
+    // The relative error of y1(x) ~= -2 / (π * x) is less than
+    // x^2 * |ln(x)|, which is negligible for tiny x
+    let tiny_th = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS / 2 + 4));
+    if x < tiny_th {
+        // Scale to avoid overflow in intermediate results
+        let escale = F::Exp::cast_from(F::MANT_BITS);
+        let xs = x * F::exp2i_fast(escale);
+        let y = F::frac_2_pi_ex().to_denorm() / DenormDouble::new(xs, F::ZERO);
+        // may overflow to -inf
+        return -(y.to_single() * F::exp2i_fast(escale));
+    }
+
+    // y1(x) = 2 / π * (ln(x) * j1(x) - 1 / x) + x * (K0 + u * (K1 + u * (K2 + r)))
+    let (r, k0, k1, k2) = F::y1_small_poly(x * x);
+
+    let (xn, edelta) = x.normalize_arg();
+    let ln_x = hi_lo_ln_inner(xn, edelta);
+    let j1 = j1_inner_small(x);
+    let t1 = (ln_x.to_semi() * j1.to_semi()) - DenormDouble::new_recip(x);
+    let t1 = F::frac_2_pi_ex().to_semi() * t1.to_semi();
+
+    let t2 = finish_small_poly(x, r, k0, k1, k2);
+    let t2 = SemiDouble::new(x) * t2.to_semi();
+
+    (t1 + t2).to_single()
+}
+
+/// Calculates `k0 + u * (k1 + u * (k2 + r))`, where `u = x^2`
+fn finish_small_poly<F: Float>(
+    x: F,
+    r: F,
+    k0: NormDouble<F>,
+    k1: NormDouble<F>,
+    k2: F,
+) -> DenormDouble<F> {
+    let u = SemiDouble::new(x).square().to_semi();
+    let t = SemiDouble::new_qadd11(k2, r);
+    let t = SemiDouble::new_qadd22(k1.to_denorm(), u * t);
+    let t = u * t;
+    DenormDouble::new_add11(k0.hi(), t.hi()).ladd(t.lo() + k0.lo())
+}
+
+/// Calculates `j0(x)` (or `y0(x)` when `y` is true) for `x >= 2`
+fn asympt_0<F: Bessel>(x: F, y: bool) -> F {
+    let t = DenormDouble::new_recip(x);
+    let eighth = F::half() * F::half() * F::half();
+    let three_eighths = (F::one() + F::half()) * (F::half() * F::half());
+
+    let (alpha, phi) = if x < F::two() * F::two() {
+        // phi = K0 + z * (K1 + r)
+        let z = t.hi() - three_eighths;
+        let (alpha, r, k0, k1) = F::asympt0_poly_1(z);
+        (alpha, finish_phase_poly(z, t.lo(), r, k0, k1))
+    } else if x < F::two() * F::two() * F::two() {
+        // phi = K0 + z * (K1 + r)
+        let z = t.hi() - F::half() * three_eighths;
+        let (alpha, r, k0, k1) = F::asympt0_poly_2(z);
+        (alpha, finish_phase_poly(z, t.lo(), r, k0, k1))
+    } else {
+        // phi = (β - 1/8) / x
+        let (alpha, beta) = F::asympt0_poly_3(t.hi() * t.hi());
+        (alpha, SemiDouble::new_qadd11(-eighth, beta) * t.to_semi())
+    };
+
+    asympt_common(x, 0, alpha, phi, y)
+}
+
+/// Calculates `j1(x)` (or `y1(x)` when `y` is true) for `x >= 2`
+fn asympt_1<F: Bessel>(x: F, y: bool) -> F {
+    let t = DenormDouble::new_recip(x);
+    let three_eighths = (F::one() + F::half()) * (F::half() * F::half());
+
+    let (alpha, phi) = if x < F::two() * F::two() {
+        // phi = K0 + z * (K1 + r)
+        let z = t.hi() - three_eighths;
+        let (alpha, r, k0, k1) = F::asympt1_poly_1(z);
+        (alpha, finish_phase_poly(z, t.lo(), r, k0, k1))
+    } else if x < F::two() * F::two() * F::two() {
+        // phi = K0 + z * (K1 + r)
+        let z = t.hi() - F::half() * three_eighths;
+        let (alpha, r, k0, k1) = F::asympt1_poly_2(z);
+        (alpha, finish_phase_poly(z, t.lo(), r, k0, k1))
+    } else {
+        // phi = (β + 3/8) / x
+        let (alpha, beta) = F::asympt1_poly_3(t.hi() * t.hi());
+        (
+            alpha,
+            SemiDouble::new_qadd11(three_eighths, beta) * t.to_semi(),
+        )
+    };
+
+    asympt_common(x, 1, alpha, phi, y)
+}
+
+/// Calculates `k0 + (z + z_lo) * (k1 + r)`
+fn finish_phase_poly<F: Float>(z: F, z_lo: F, r: F, k0: NormDouble<F>, k1: F) -> DenormDouble<F> {
+    let t = SemiDouble::new_qadd11(k1, r);
+    (SemiDouble::new(z) * t)
+        .qradd1(k0.hi())
+        .ladd(k0.lo() + z_lo * k1)
+}
+
+/// Calculates `√(2 / (π * x)) * (1 + alpha) * cos(θ)` (or `sin(θ)` when
+/// `sin` is true), where `θ = x - (2 * nu + 1) * π/4 + phi`
+///
+/// `x >= 2` and `|phi| < π/4`
+fn asympt_common<F: Bessel>(x: F, nu: u8, alpha: F, phi: DenormDouble<F>, sin: bool) -> F {
+    // x = 2 * π * M + n * π/2 + y
+    let (n, y_hi, y_lo) = reduce_pi_2(x);
+
+    // w = y + phi
+    let w = DenormDouble::new_add11(y_hi, phi.hi()).ladd(y_lo + phi.lo());
+
+    // θ = (n - nu) * π/2 + w - π/4
+    //   = q * π/2 + s
+    // where |s| <= π/4
+    let frac_pi_4 = F::frac_pi_4_ex().to_denorm();
+    let (q, s) = if w.hi() >= F::ZERO {
+        (n.wrapping_sub(nu), w - frac_pi_4)
+    } else {
+        (n.wrapping_sub(nu).wrapping_sub(1), w + frac_pi_4)
+    };
+
+    // sin(θ) = cos(θ - π/2)
+    let q = if sin { q.wrapping_sub(1) } else { q };
+    let s = s.to_norm();
+    let trig = match q & 3 {
+        0 => hi_lo_cos_inner(s),
+        1 => -hi_lo_sin_inner(s),
+        2 => -hi_lo_cos_inner(s),
+        3 => hi_lo_sin_inner(s),
+        _ => unreachable!(),
+    };
+
+    // √(2 / (π * x)) = √(2 / π) / √(xs) * 2^(-k)
+    // where x = xs * 2^(2 * k)
+    let k = x.exponent() >> 1u8;
+    let xs = x * F::exp2i_fast(-(k + k));
+    let m = F::sqrt_frac_2_pi_ex().to_denorm().pmul1(F::two()) / two_hi_lo_sqrt_inner(xs);
+
+    let amp = m.to_semi() * SemiDouble::new_qadd11(F::one(), alpha);
+    (amp.to_semi() * trig.to_semi()).to_single() * F::exp2i_fast(-k)
+}
+
+/// Calculates `jn(n, x)` for `n >= 2` and `0 < x < inf`
+fn jn_inner<F: Bessel>(n: u32, x: F) -> F {
+    let nf = F::cast_from(n);
+    if nf * nf < x * F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS)) {
+        // x is so large that the leading term of the
+        // asymptotic expansion is accurate enough
+        let nu = (n & 3) as u8;
+        return asympt_common(x, nu, F::ZERO, DenormDouble::new(F::ZERO, F::ZERO), false);
+    }
+
+    if nf - F::one() < x {
+        // Forward recurrence is stable
+        // j(k + 1, x) = 2 * k / x * j(k, x) - j(k - 1, x)
+        let mut a = j0(x);
+        let mut b = j1(x);
+        for i in 1..n {
+            let tmp = b;
+            b = F::cast_from(i + i) / x * b - a;
+            a = tmp;
+        }
+        return b;
+    }
+
+    // jn(n, x) < (x / 2)^n / n! < (e * x / (2 * n))^n, which is less
+    // than half the smallest subnormal when n * (ln(x / (2 * n)) + 1) is
+    // below (MIN_NORMAL_EXP - MANT_BITS - 2) * 3/4 (3/4 > ln(2)), avoid
+    // the O(n) recurrence in that case
+    let ln_th = F::cast_from((F::MIN_NORMAL_EXP.into() - i32::from(F::MANT_BITS) - 2) * 3 / 4);
+    if nf * (ln(x / (nf + nf)) + F::one()) < ln_th {
+        return F::ZERO;
+    }
+
+    let tiny_th = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS / 2 + 3));
+    if x < tiny_th {
+        // jn(n, x) ~= (x / 2)^n / n!
+        let half_x = F::half() * x;
+        let mut a = F::one();
+        let mut b = half_x;
+        for i in 2..=n {
+            a = a * F::cast_from(i);
+            b = b * half_x;
+            if b == F::ZERO {
+                break;
+            }
+        }
+        return b / a;
+    }
+
+    // Backward recurrence, using a continued fraction to find the
+    // ratio jn(n, x) / jn(n - 1, x) (algorithm from the msun math library)
+    let w = (nf + nf) / x;
+    let h = F::two() / x;
+    let mut z = w + h;
+    let mut q0 = w;
+    let mut q1 = w * z - F::one();
+    let mut k = 1u32;
+    let q_th = F::exp2i_fast(F::Exp::cast_from(F::MANT_BITS / 2 + 4));
+    while q1 < q_th {
+        k += 1;
+        z = z + h;
+        let tmp = z * q1 - q0;
+        q0 = q1;
+        q1 = tmp;
+    }
+
+    let mut t = F::ZERO;
+    for i in (0..=k).rev() {
+        t = F::one() / (F::two() * (F::cast_from(i) + nf) / x - t);
+    }
+
+    // Scale to avoid spurious overflow
+    let scale_th = F::exp2i_fast(F::MAX_EXP >> 1u8);
+    let mut a = t;
+    let mut b = F::one();
+    for i in (1..n).rev() {
+        let tmp = b;
+        b = F::cast_from(i + i) / x * b - a;
+        a = tmp;
+        if b > scale_th {
+            a = a / b;
+            t = t / b;
+            b = F::one();
+        }
+    }
+
+    let z = j0(x);
+    let w = j1(x);
+    if z.abs() >= w.abs() {
+        t * z / b
+    } else {
+        t * w / a
+    }
+}
+
+/// Calculates `yn(n, x)` for `n >= 2` and `0 < x < inf`
+fn yn_inner<F: Bessel>(n: u32, x: F) -> F {
+    let nf = F::cast_from(n);
+    if nf * nf < x * F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS)) {
+        // x is so large that the leading term of the
+        // asymptotic expansion is accurate enough
+        let nu = (n & 3) as u8;
+        return asympt_common(x, nu, F::ZERO, DenormDouble::new(F::ZERO, F::ZERO), true);
+    }
+
+    // Forward recurrence is stable
+    // y(k + 1, x) = 2 * k / x * y(k, x) - y(k - 1, x)
+    let mut a = y0(x);
+    let mut b = y1(x);
+    for i in 1..n {
+        if b == F::neg_infinity() {
+            break;
+        }
+        let tmp = b;
+        b = F::cast_from(i + i) / x * b - a;
+        a = tmp;
+    }
+    b
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FloatMath;
+    use crate::traits::Float;
+
+    fn test_j0<F: Float + FloatMath>() {
+        use crate::j0;
+
+        assert_is_nan!(j0(F::NAN));
+        assert_total_eq!(j0(F::INFINITY), F::ZERO);
+        assert_total_eq!(j0(F::neg_infinity()), F::ZERO);
+        assert_total_eq!(j0(F::ZERO), F::one());
+        assert_total_eq!(j0(-F::ZERO), F::one());
+    }
+
+    fn test_j1<F: Float + FloatMath>() {
+        use crate::j1;
+
+        assert_is_nan!(j1(F::NAN));
+        assert_total_eq!(j1(F::INFINITY), F::ZERO);
+        assert_total_eq!(j1(F::neg_infinity()), -F::ZERO);
+        assert_total_eq!(j1(F::ZERO), F::ZERO);
+        assert_total_eq!(j1(-F::ZERO), -F::ZERO);
+    }
+
+    fn test_y0_y1<F: Float + FloatMath>() {
+        use crate::{y0, y1};
+
+        for f in [y0::<F>, y1::<F>] {
+            assert_is_nan!(f(F::NAN));
+            assert_is_nan!(f(F::neg_infinity()));
+            assert_is_nan!(f(-F::one()));
+            assert_total_eq!(f(F::INFINITY), F::ZERO);
+            assert_total_eq!(f(F::ZERO), F::neg_infinity());
+            assert_total_eq!(f(-F::ZERO), F::neg_infinity());
+        }
+    }
+
+    fn test_jn<F: Float + FloatMath>() {
+        use crate::jn;
+
+        for n in [-3, -2, 2, 3, 100] {
+            assert_is_nan!(jn(n, F::NAN));
+        }
+        assert_total_eq!(jn(2, F::ZERO), F::ZERO);
+        assert_total_eq!(jn(2, -F::ZERO), F::ZERO);
+        assert_total_eq!(jn(3, F::ZERO), F::ZERO);
+        assert_total_eq!(jn(3, -F::ZERO), -F::ZERO);
+        assert_total_eq!(jn(-3, F::ZERO), -F::ZERO);
+        assert_total_eq!(jn(-3, -F::ZERO), F::ZERO);
+        assert_total_eq!(jn(2, F::INFINITY), F::ZERO);
+        assert_total_eq!(jn(3, F::neg_infinity()), -F::ZERO);
+        assert_total_eq!(jn(3, F::two()), -jn(-3, F::two()));
+        assert_total_eq!(jn(3, F::two()), -jn(3, -F::two()));
+        assert_total_eq!(jn(2, F::two()), jn(-2, F::two()));
+        assert_total_eq!(jn(2, F::two()), jn(2, -F::two()));
+    }
+
+    fn test_yn<F: Float + FloatMath>() {
+        use crate::yn;
+
+        for n in [-3, -2, 2, 3, 100] {
+            assert_is_nan!(yn(n, F::NAN));
+            assert_is_nan!(yn(n, F::neg_infinity()));
+            assert_is_nan!(yn(n, -F::one()));
+            assert_total_eq!(yn(n, F::INFINITY), F::ZERO);
+        }
+        assert_total_eq!(yn(2, F::ZERO), F::neg_infinity());
+        assert_total_eq!(yn(-2, F::ZERO), F::neg_infinity());
+        assert_total_eq!(yn(-3, F::ZERO), F::INFINITY);
+        assert_total_eq!(yn(3, F::two()), -yn(-3, F::two()));
+        assert_total_eq!(yn(2, F::two()), yn(-2, F::two()));
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_j0::<f16>();
+        test_j1::<f16>();
+        test_y0_y1::<f16>();
+        test_jn::<f16>();
+        test_yn::<f16>();
+    }
+
+    #[test]
+    fn test_f32() {
+        test_j0::<f32>();
+        test_j1::<f32>();
+        test_y0_y1::<f32>();
+        test_jn::<f32>();
+        test_yn::<f32>();
+    }
+
+    #[test]
+    fn test_f64() {
+        test_j0::<f64>();
+        test_j1::<f64>();
+        test_y0_y1::<f64>();
+        test_jn::<f64>();
+        test_yn::<f64>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test_j0::<half::bf16>();
+        test_j1::<half::bf16>();
+        test_y0_y1::<half::bf16>();
+        test_jn::<half::bf16>();
+        test_yn::<half::bf16>();
+    }
+}
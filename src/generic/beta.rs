@@ -0,0 +1,1147 @@
+use super::erf::{erf_inner_small, erfc_scaled_inner};
+use super::exp::{exp_split, hi_lo_exp_inner_common, hi_lo_exp_m1_inner_common};
+use super::gamma::{
+    gamma_inner_common, hi_lo_ln_inner_normalized, inc_gamma_complement, inc_gamma_mul_exp,
+    ln_gamma_1p_inner, mul_exp, stirling_corr,
+};
+use super::ln::{
+    hi_lo_ln_1p_hi_lo_inner, hi_lo_ln_hi_lo_inner, hi_lo_ln_inner, hi_lo_x_sub_ln_1p_hi_lo_inner,
+};
+use super::polygamma::{digamma_pos, polygamma_pos};
+use super::sqrt::hi_lo_sqrt_hi_lo_inner;
+use super::{Polygamma, digamma, erfcinv, exp, is_int, ln, ln_gamma, sqrt};
+use crate::double::{DenormDouble, NormDouble, SemiDouble};
+use crate::traits::{CastFrom as _, CastInto as _, Int as _};
+
+pub(crate) fn beta<F: Polygamma>(a: F, b: F) -> F {
+    match beta_args(a, b) {
+        Err((r, _)) => r,
+        Ok((p, q, c)) => {
+            let (y, s) = beta_inner_common(p, q, c);
+            if y.hi() > F::exp_hi_th() * F::cast_from(4u8) {
+                F::INFINITY.copysign(s.hi())
+            } else if y.hi() < F::exp_lo_th() * F::cast_from(4u8) {
+                F::ZERO.copysign(s.hi())
+            } else {
+                mul_exp(s, y)
+            }
+        }
+    }
+}
+
+pub(crate) fn ln_beta<F: Polygamma>(a: F, b: F) -> (F, i8) {
+    match beta_args(a, b) {
+        Err((r, sign)) => {
+            if r == F::ZERO {
+                (F::neg_infinity(), sign)
+            } else if r.abs() == F::INFINITY {
+                (F::INFINITY, sign)
+            } else {
+                // NaN
+                (r, sign)
+            }
+        }
+        Ok((p, q, c)) => {
+            let (y, s) = beta_inner_common(p, q, c);
+
+            let s = s.to_norm();
+            let (sign, abs_s) = if s.hi().sign() { (-1, -s) } else { (1, s) };
+
+            let ln_s = hi_lo_ln_hi_lo_inner(abs_s, F::Exp::ZERO);
+
+            (ln_s.qadd2(y).to_single(), sign)
+        }
+    }
+}
+
+pub(crate) fn beta_inc<F: Polygamma>(a: F, b: F, x: F) -> F {
+    if let Some(r) = beta_inc_special(a, b, x) {
+        r
+    } else {
+        let (r, is_compl) = beta_inc_inner(a, b, x);
+        if is_compl {
+            inc_gamma_complement(r)
+        } else {
+            r.to_single()
+        }
+    }
+}
+
+pub(crate) fn beta_inc_inv<F: Polygamma>(a: F, b: F, p: F) -> F {
+    let a_exp = a.raw_exp();
+    let b_exp = b.raw_exp();
+    let p_exp = p.raw_exp();
+    let a_is_zero = a_exp == F::RawExp::ZERO && a.raw_mant() == F::Raw::ZERO;
+    let b_is_zero = b_exp == F::RawExp::ZERO && b.raw_mant() == F::Raw::ZERO;
+    let p_is_zero = p_exp == F::RawExp::ZERO && p.raw_mant() == F::Raw::ZERO;
+
+    if a_exp == F::MAX_RAW_EXP || b_exp == F::MAX_RAW_EXP || p_exp == F::MAX_RAW_EXP {
+        // beta_inc_inv(NaN or inf, b, p) = beta_inc_inv(a, NaN or inf, p)
+        // = beta_inc_inv(a, b, NaN or inf) = NaN
+        F::NAN
+    } else if a.sign() || a_is_zero || b.sign() || b_is_zero {
+        // beta_inc_inv(a <= 0, b, p) = beta_inc_inv(a, b <= 0, p) = NaN
+        F::NAN
+    } else if (p.sign() && !p_is_zero) || p > F::one() {
+        // beta_inc_inv(a, b, p < 0 or p > 1) = NaN
+        F::NAN
+    } else if p_is_zero {
+        // beta_inc_inv(a, b, 0) = 0
+        F::ZERO
+    } else if p == F::one() {
+        // beta_inc_inv(a, b, 1) = 1
+        F::one()
+    } else if a == b && p == F::half() {
+        // I_0.5(a, a) = 0.5 by symmetry, which is handled explicitly
+        // because the inverse is very ill-conditioned when `a` is tiny
+        F::half()
+    } else {
+        // Solve for the smallest of I and 1 - I, `1 - p` is exact
+        let (t, is_compl) = if p > F::half() {
+            (F::one() - p, true)
+        } else {
+            (p, false)
+        };
+
+        // When the solution is greater than 0.5, it is calculated as
+        // `1 - y`, where `I_y(b, a) = 1 - p`, so it does not lose
+        // precision when it is close to 1
+        let (r, r_is_compl) = beta_inc_inner(a, b, F::half());
+        let v = if r_is_compl == is_compl {
+            r
+        } else {
+            DenormDouble::new_qsub12(F::one(), r)
+        };
+        let f = (v - t).to_single();
+        if f == F::ZERO {
+            F::half()
+        } else if (f > F::ZERO) == is_compl {
+            F::one() - beta_inc_inv_inner(b, a, t, !is_compl)
+        } else {
+            beta_inc_inv_inner(a, b, t, is_compl)
+        }
+    }
+}
+
+/// Handles the special cases of the beta function.
+///
+/// Returns `Ok((p, q, c))` with `p <= q` and `c = p + q` when the arguments
+/// are finite and the result has to be calculated, or `Err((r, sign))` with
+/// the value of the beta function and its sign otherwise.
+fn beta_args<F: Polygamma>(a: F, b: F) -> Result<(F, F, DenormDouble<F>), (F, i8)> {
+    let a_exp = a.raw_exp();
+    let b_exp = b.raw_exp();
+    let a_is_zero = a_exp == F::RawExp::ZERO && a.raw_mant() == F::Raw::ZERO;
+    let b_is_zero = b_exp == F::RawExp::ZERO && b.raw_mant() == F::Raw::ZERO;
+
+    if (a_exp == F::MAX_RAW_EXP && a.raw_mant() != F::Raw::ZERO)
+        || (b_exp == F::MAX_RAW_EXP && b.raw_mant() != F::Raw::ZERO)
+    {
+        // beta(NaN, b) = beta(a, NaN) = NaN
+        Err((F::NAN, 0))
+    } else if a_is_zero || b_is_zero {
+        let (z, other) = if a_is_zero { (a, b) } else { (b, a) };
+        if other.sign() && is_int(other) || other == F::INFINITY {
+            // beta(±0, -inf, neg integer or inf) = NaN
+            Err((F::NAN, 0))
+        } else if a_is_zero && b_is_zero && a.sign() != b.sign() {
+            // beta(+0, -0) = NaN
+            Err((F::NAN, 0))
+        } else {
+            // beta(±0, b) = ±inf
+            Err((F::INFINITY.copysign(z), if z.sign() { -1 } else { 1 }))
+        }
+    } else if (a.sign() && is_int(a)) || (b.sign() && is_int(b)) {
+        // beta(-inf or neg integer, b) = beta(a, -inf or neg integer) = NaN
+        Err((F::NAN, 0))
+    } else if a_exp == F::MAX_RAW_EXP || b_exp == F::MAX_RAW_EXP {
+        // only +inf can reach this point
+        let other = if a_exp == F::MAX_RAW_EXP { b } else { a };
+        if other.sign() {
+            // beta(inf, b) = ±inf when b is a negative non-integer,
+            // with the sign of Γ(b)
+            let (_, sign) = ln_gamma(other);
+            if sign < 0 {
+                Err((F::neg_infinity(), sign))
+            } else {
+                Err((F::INFINITY, sign))
+            }
+        } else {
+            // beta(inf, b) = 0 when b is positive
+            Err((F::ZERO, 1))
+        }
+    } else {
+        let (p, q) = if a < b { (a, b) } else { (b, a) };
+        let c = DenormDouble::new_add11(p, q);
+        if c.lo() == F::ZERO && (c.hi() == F::ZERO || (c.hi().sign() && is_int(c.hi()))) {
+            // a + b is zero or a negative integer, so Γ(a + b) is infinite
+            Err((F::ZERO, 0))
+        } else {
+            Ok((p, q, c))
+        }
+    }
+}
+
+/// Returns `(y, s)` such as `B(p, q) = s * exp(y)`.
+///
+/// `p <= q` and `c = p + q`.
+fn beta_inner_common<F: Polygamma>(
+    p: F,
+    q: F,
+    c: DenormDouble<F>,
+) -> (DenormDouble<F>, DenormDouble<F>) {
+    // The difference of the logarithms of the gamma functions has a large
+    // cancellation when one of the arguments is large. So, terms of the
+    // Stirling approximation
+    // Γ(t) = (P(1 / t) / t + 1) * t^(t - 0.5) * e^(-t) * √(2π)
+    // are combined before evaluating them.
+
+    if p >= F::th_3() {
+        // ln(B(p, q)) = 0.5 * ln(2π) - 0.5 * ln(c) + (p - 0.5) * ln(p / c)
+        //             + (q - 0.5) * ln(1 - p / c) + ln(P_p * P_q / P_c)
+        // where P_t = P(1 / t) / t + 1.
+        // c / 2 is used to avoid overflow.
+        let hp = p * F::half();
+        let hc = DenormDouble::new_add11(hp, q * F::half());
+        let ln_c = hi_lo_ln_hi_lo_inner(hc.to_norm(), F::Exp::ONE);
+
+        // r = p / c
+        let r = DenormDouble::new(hp, F::ZERO) / hc;
+        let ln_r = hi_lo_ln_hi_lo_inner(r.to_norm(), F::Exp::ZERO);
+        let ln_1m_r = hi_lo_ln_1p_hi_lo_inner((-r).to_norm());
+
+        let y = F::half_ln_2_pi().to_denorm() - ln_c.pmul1(F::half())
+            + DenormDouble::new_sub11(p, F::half()) * ln_r
+            + DenormDouble::new_sub11(q, F::half()) * ln_1m_r;
+
+        let pp = stirling_corr(F::one() / p);
+        let pq = stirling_corr(F::one() / q);
+        let pc = stirling_corr(F::half() / hc.hi());
+
+        (y, (pp * pq) / pc)
+    } else if q >= F::th_3() && c.hi() >= F::th_3() {
+        // ln(Γ(q) / Γ(c)) = (q - 0.5) * ln(1 - p / c) - p * ln(c) + p
+        //                 + ln(P_q / P_c)
+        let (y_p, s_p) = gamma_inner_common(p);
+        let ln_c = hi_lo_ln_hi_lo_inner(c.to_norm(), F::Exp::ZERO);
+
+        // r = -p / c
+        let r = DenormDouble::new(-p, F::ZERO) / c;
+        let ln_1m_r = hi_lo_ln_1p_hi_lo_inner(r.to_norm());
+
+        let y = y_p + DenormDouble::new_sub11(q, F::half()) * ln_1m_r
+            - DenormDouble::new(p, F::ZERO) * ln_c
+            + p;
+
+        let pq = stirling_corr(F::one() / q);
+        let pc = stirling_corr(F::one() / c.to_single());
+
+        (y, (s_p * pq) / pc)
+    } else {
+        // ln(B(p, q)) = ln(Γ(p)) + ln(Γ(q)) - ln(Γ(c))
+        // where ln(Γ(c_hi + c_lo)) ~= ln(Γ(c_hi)) + ψ(c_hi) * c_lo
+        let (y_p, s_p) = gamma_inner_common(p);
+        let (y_q, s_q) = gamma_inner_common(q);
+        let (y_c, s_c) = gamma_inner_common(c.hi());
+
+        let mut y = y_p + y_q - y_c;
+        if c.lo() != F::ZERO {
+            y = y - digamma(c.hi()) * c.lo();
+        }
+
+        // Γ(t) ~= 1 / t for small t, so order the operations to avoid
+        // intermediate overflows
+        let s = if q < F::half() {
+            (s_p / s_c.normalize()) * s_q
+        } else {
+            (s_p * s_q) / s_c.normalize()
+        };
+
+        (y, s)
+    }
+}
+
+/// Handles the special cases of the regularized incomplete beta function.
+///
+/// Returns `Some(r)` for the special cases, or `None` when `a` and `b` are
+/// positive and finite and `0 < x < 1`.
+fn beta_inc_special<F: Polygamma>(a: F, b: F, x: F) -> Option<F> {
+    let a_exp = a.raw_exp();
+    let b_exp = b.raw_exp();
+    let x_exp = x.raw_exp();
+    let a_is_zero = a_exp == F::RawExp::ZERO && a.raw_mant() == F::Raw::ZERO;
+    let b_is_zero = b_exp == F::RawExp::ZERO && b.raw_mant() == F::Raw::ZERO;
+    let x_is_zero = x_exp == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO;
+    let a_is_inf = a_exp == F::MAX_RAW_EXP && a.raw_mant() == F::Raw::ZERO;
+    let b_is_inf = b_exp == F::MAX_RAW_EXP && b.raw_mant() == F::Raw::ZERO;
+    let x_is_one = x == F::one();
+
+    if (a_exp == F::MAX_RAW_EXP && !a_is_inf)
+        || (b_exp == F::MAX_RAW_EXP && !b_is_inf)
+        || (x_exp == F::MAX_RAW_EXP && x.raw_mant() != F::Raw::ZERO)
+    {
+        // beta_inc(NaN, b, x) = beta_inc(a, NaN, x) = beta_inc(a, b, NaN) = NaN
+        Some(F::NAN)
+    } else if (a.sign() && !a_is_zero) || (b.sign() && !b_is_zero) {
+        // beta_inc(a < 0, b, x) = beta_inc(a, b < 0, x) = NaN
+        Some(F::NAN)
+    } else if (x.sign() && !x_is_zero) || x > F::one() {
+        // beta_inc(a, b, x < 0 or x > 1) = NaN
+        Some(F::NAN)
+    } else if (a_is_zero && b_is_zero) || (a_is_inf && b_is_inf) {
+        // beta_inc(0, 0, x) = beta_inc(inf, inf, x) = NaN
+        Some(F::NAN)
+    } else if (a_is_zero && x_is_zero)
+        || (b_is_zero && x_is_one)
+        || (a_is_inf && x_is_one)
+        || (b_is_inf && x_is_zero)
+    {
+        // beta_inc(0, b, 0) = beta_inc(a, 0, 1) = NaN
+        // beta_inc(inf, b, 1) = beta_inc(a, inf, 0) = NaN
+        Some(F::NAN)
+    } else if x_is_zero || a_is_inf || b_is_zero {
+        // beta_inc(a, b, 0) = beta_inc(inf, b, x) = beta_inc(a, 0, x) = 0
+        Some(F::ZERO)
+    } else if x_is_one || a_is_zero || b_is_inf {
+        // beta_inc(a, b, 1) = beta_inc(0, b, x) = beta_inc(a, inf, x) = 1
+        Some(F::one())
+    } else {
+        None
+    }
+}
+
+/// Returns `(r, is_compl)`, where `r` is `1 - I_x(a, b)` when `is_compl` is
+/// true or `I_x(a, b)` otherwise.
+///
+/// `a` and `b` are positive and finite and `0 < x < 1`. The function that is
+/// calculated directly is chosen to avoid cancellation, the other one can be
+/// obtained as `1 - r`.
+fn beta_inc_inner<F: Polygamma>(a: F, b: F, x: F) -> (DenormDouble<F>, bool) {
+    let ha = a * F::half();
+    let hb = b * F::half();
+
+    let hd = beta_inc_half_diff(x, ha, hb);
+
+    // Temme's expansion is used when `a` and `b` are large, since the
+    // continued fraction converges slowly when `x` is close to the mean,
+    // and the terms of the expansion that are ignored are negligible
+    let temme_th = F::exp2i_fast(F::Exp::cast_from(F::MANT_BITS * 4 / 5));
+    if a >= temme_th && b >= temme_th {
+        let hc = DenormDouble::new_add11(ha, hb);
+        let e = beta_inc_large_exp(a, b, hd);
+        return beta_inc_temme(a, b, hc, hd, e);
+    }
+
+    let ln_x = hi_lo_ln_inner_normalized(x);
+    let ln_1mx = hi_lo_ln_1p_hi_lo_inner(NormDouble::with_parts(-x, F::ZERO));
+
+    // x < (a + 1) / (a + b + 2), which is equivalent to
+    // (x * (a + b + 2) - a - 1) / 2 = hd + x - 0.5 < 0
+    //
+    // When `a` (or `b`) is small and `x^a` (or `(1 - x)^b`) is not, the
+    // function is close to 1, so its complement is calculated directly
+    // with a series to avoid the cancellation of `1 - r`.
+    let small_th = F::half() * F::half() * F::half();
+    if (hd + DenormDouble::new_sub11(x, F::half())).to_single() < F::ZERO {
+        let x = DenormDouble::new(x, F::ZERO);
+        if a < small_th && ln_x.hi() * a > -F::half() {
+            // 1 - I_x(a, b) = I_(1 - x)(b, a)
+            (beta_inc_small_b(b, a, x, ln_x), true)
+        } else {
+            (beta_inc_cont_frac(a, b, x, hd, ln_x, ln_1mx), false)
+        }
+    } else {
+        // I_x(a, b) = 1 - I_(1 - x)(b, a)
+        let y = DenormDouble::new_sub11(F::one(), x);
+        if b < small_th && ln_1mx.hi() * b > -F::half() {
+            (beta_inc_small_b(a, b, y, ln_1mx), false)
+        } else {
+            // `hd` changes its sign when swapping `a` and `b` and
+            // replacing `x` with `1 - x`
+            (beta_inc_cont_frac(b, a, y, -hd, ln_1mx, ln_x), true)
+        }
+    }
+}
+
+/// Returns `(y, s)` such that `x^a * (1 - x)^b / (a * B(a, b)) = s * exp(y)`
+///
+/// `a` and `b` are positive and finite, `0 < x < 1`,
+/// `hd = (x * (a + b) - a) / 2`, `ln_x = ln(x)` and `ln_1mx = ln(1 - x)`.
+fn beta_inc_prefactor<F: Polygamma>(
+    a: F,
+    b: F,
+    hd: DenormDouble<F>,
+    ln_x: DenormDouble<F>,
+    ln_1mx: DenormDouble<F>,
+) -> (DenormDouble<F>, DenormDouble<F>) {
+    if a >= F::th_3() && b >= F::th_3() {
+        // B(a, b) = √(2π) * a^(a - 0.5) * b^(b - 0.5) / c^(c - 0.5) * P_a * P_b / P_c
+        // where c = a + b and P_t = P(1 / t) / t + 1, so
+        // x^a * (1 - x)^b / (a * B(a, b))
+        //   = exp(-E) * √(b / (2π * a * c)) * P_c / (P_a * P_b)
+        // where E = a * ln(x0 / x) + b * ln((1 - x0) / (1 - x)) and
+        // x0 = a / c.
+        // c / 2 is used to avoid overflow.
+        let hc = DenormDouble::new_add11(a * F::half(), b * F::half());
+        let e = beta_inc_large_exp(a, b, hd);
+        if e.hi() == F::INFINITY {
+            // `E` overflows when `a` or `b` are close to the largest
+            // finite value
+            return (
+                DenormDouble::new(F::neg_infinity(), F::ZERO),
+                DenormDouble::one(),
+            );
+        }
+
+        let ln_a = hi_lo_ln_inner(a, F::Exp::ZERO);
+        let ln_b = hi_lo_ln_inner(b, F::Exp::ZERO);
+        let ln_c = hi_lo_ln_hi_lo_inner(hc.to_norm(), F::Exp::ONE);
+        let y = (ln_b - ln_a - ln_c).pmul1(F::half()) - F::half_ln_2_pi().to_denorm() - e;
+
+        let pa = stirling_corr(F::one() / a);
+        let pb = stirling_corr(F::one() / b);
+        let pc = stirling_corr(F::half() / hc.hi());
+
+        (y, pc / (pa * pb).normalize())
+    } else {
+        // x^a * (1 - x)^b / (a * B(a, b))
+        //   = exp(a * ln(x) + b * ln(1 - x) - ln(a * B(a, b)))
+        let y = ln_x.to_semi() * a + ln_1mx.to_semi() * b - hi_lo_ln_mul_beta(a, b);
+        (y, DenormDouble::one())
+    }
+}
+
+/// Calculates `hd = (x * (a + b) - a) / 2`, where `ha = a / 2` and
+/// `hb = b / 2`
+///
+/// It is calculated as `(x * hb - ha) + x * ha` or, when `x >= 0.5`, as
+/// `-((y * hb - hb) + y * ha)`, where `y = 1 - x` is exact. The products
+/// are exact and the large terms are subtracted first, so the result is
+/// accurate even when they cancel.
+fn beta_inc_half_diff<F: Polygamma>(x: F, ha: F, hb: F) -> DenormDouble<F> {
+    let (z, base) = if x < F::half() {
+        (x, ha)
+    } else {
+        (F::one() - x, hb)
+    };
+    let z = DenormDouble::new(z, F::ZERO);
+    let p1 = z * DenormDouble::new(hb, F::ZERO);
+    let p2 = z * DenormDouble::new(ha, F::ZERO);
+    let r = ((DenormDouble::new_sub11(p1.hi(), base) + p2) + p1.lo()).normalize();
+    if x < F::half() { r } else { -r }
+}
+
+/// Calculates `a * (μa - ln(1 + μa)) + b * (μb - ln(1 + μb))`, where
+/// `μa = 2 * hd / a` and `μb = -2 * hd / b`
+///
+/// With `hd = (x * (a + b) - a) / 2`, this is
+/// `a * ln(x0 / x) + b * ln((1 - x0) / (1 - x))`, where `x0 = a / (a + b)`,
+/// without the cancellation when `x` is close to `x0`.
+fn beta_inc_large_exp<F: Polygamma>(a: F, b: F, hd: DenormDouble<F>) -> DenormDouble<F> {
+    let mu_a = hd / DenormDouble::new(a * F::half(), F::ZERO);
+    let mu_b = -(hd / DenormDouble::new(b * F::half(), F::ZERO));
+    hi_lo_x_sub_ln_1p_hi_lo_inner(mu_a.to_norm()).to_semi() * a
+        + hi_lo_x_sub_ln_1p_hi_lo_inner(mu_b.to_norm()).to_semi() * b
+}
+
+/// Calculates `ln(a * B(a, b))`
+///
+/// `a` and `b` are positive and finite, and at least one of them is less
+/// than `th_3()`. The result keeps its relative accuracy when `a` or `b`
+/// are close to zero.
+fn hi_lo_ln_mul_beta<F: Polygamma>(a: F, b: F) -> DenormDouble<F> {
+    if a <= b {
+        hi_lo_ln_mul_beta_inner(a, b)
+    } else {
+        // a * B(a, b) = b * B(b, a) * a / b
+        hi_lo_ln_mul_beta_inner(b, a) + hi_lo_ln_inner_normalized(a) - hi_lo_ln_inner_normalized(b)
+    }
+}
+
+/// Calculates `ln(p * B(p, q))` for `p <= q` and `p < th_3()`
+fn hi_lo_ln_mul_beta_inner<F: Polygamma>(p: F, q: F) -> DenormDouble<F> {
+    if p < F::half() * F::half() * F::half() {
+        // ln(p * B(p, q)) = ln(Γ(1 + p)) - (ln(Γ(q + p)) - ln(Γ(q)))
+        // where
+        // ln(Γ(q + p)) - ln(Γ(q))
+        //   = ln(Γ(1 + q + p)) - ln(Γ(1 + q)) - ln(1 + p / q)
+        //   = sum(p^k / k! * ψ^(k - 1)(1 + q)) - ln(1 + p / q), k >= 1
+        // which converges quickly because `p` is small and 1 + q >= 1
+        let negl_th = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS * 2));
+
+        let q1 = DenormDouble::new_add11(q, F::one());
+        let mut sum = digamma_pos(q1).to_semi() * p;
+        // pk = p^k / k!
+        let mut pk = p;
+        let mut k = 1;
+        loop {
+            k += 1;
+            pk = pk * p / F::cast_from(k);
+            let term = pk * polygamma_pos(k - 1, q1.to_single());
+            sum = sum + term;
+            if term.abs() <= p * negl_th {
+                break;
+            }
+        }
+
+        // Both are scaled when `q` is small, since the division is not
+        // accurate with subnormal numbers
+        let sc = if q < F::one() {
+            F::exp2i_fast(F::Exp::cast_from(F::MANT_BITS * 4))
+        } else {
+            F::one()
+        };
+        let r = DenormDouble::new(p * sc, F::ZERO) / DenormDouble::new(q * sc, F::ZERO);
+        let ln_1p_r = hi_lo_ln_1p_hi_lo_inner(r.to_norm());
+
+        ln_gamma_1p_inner(p) - sum + ln_1p_r
+    } else if q < F::th_3() {
+        // ln(p * B(p, q)) = ln(Γ(1 + p)) + ln(Γ(1 + q)) - ln(Γ(1 + c)) + ln(c / q)
+        // where c = p + q and ln(c / q) = ln(1 + p / q)
+        let c = DenormDouble::new_add11(p, q);
+        let r = DenormDouble::new(p, F::ZERO) / DenormDouble::new(q, F::ZERO);
+        let ln_1p_r = hi_lo_ln_1p_hi_lo_inner(r.to_norm());
+
+        hi_lo_ln_gamma_1p(DenormDouble::new(p, F::ZERO))
+            + hi_lo_ln_gamma_1p(DenormDouble::new(q, F::ZERO))
+            - hi_lo_ln_gamma_1p(c)
+            + ln_1p_r
+    } else {
+        // ln(Γ(q) / Γ(c)) = (q - 0.5) * ln(1 - p / c) - p * ln(c) + p
+        //                 + ln(P_q / P_c)
+        // where c = p + q and P_t = P(1 / t) / t + 1
+        let c = DenormDouble::new_add11(p, q);
+        let ln_c = hi_lo_ln_hi_lo_inner(c.to_norm(), F::Exp::ZERO);
+
+        // r = -p / c
+        let r = DenormDouble::new(-p, F::ZERO) / c;
+        let ln_1m_r = hi_lo_ln_1p_hi_lo_inner(r.to_norm());
+
+        let pq = stirling_corr(F::one() / q);
+        let pc = stirling_corr(F::one() / c.to_single());
+        let ln_pq_pc = hi_lo_ln_hi_lo_inner((pq / pc.normalize()).to_norm(), F::Exp::ZERO);
+
+        hi_lo_ln_gamma_1p(DenormDouble::new(p, F::ZERO))
+            + DenormDouble::new_sub11(q, F::half()) * ln_1m_r
+            - DenormDouble::new(p, F::ZERO) * ln_c
+            + p
+            + ln_pq_pc
+    }
+}
+
+/// Calculates `ln(Γ(1 + x))` for `0 <= x < 2 * th_3()`
+///
+/// Unlike [`ln_gamma_1p_inner`], the absolute error is kept well below the
+/// precision of `F`, which is needed when the result is part of a
+/// cancelling sum.
+fn hi_lo_ln_gamma_1p<F: Polygamma>(x: DenormDouble<F>) -> DenormDouble<F> {
+    // ln(Γ(1 + x)) = ln(Γ(t)) - ln((1 + x) * (2 + x) * ... * (t - 1))
+    // where t = x + n is large enough for the Stirling approximation
+    // ln(Γ(t)) = (t - 0.5) * ln(t) - t + 0.5 * ln(2π) + ln(P(1 / t) / t + 1)
+    // to be accurate
+    let th = F::cast_from(F::MANT_BITS);
+    let mut t = x + F::one();
+    let mut prod = DenormDouble::one();
+    while t.hi() < th {
+        prod = (prod * t).normalize();
+        t = t + F::one();
+    }
+    let t = t.normalize();
+
+    let ln_t = hi_lo_ln_hi_lo_inner(t.to_norm(), F::Exp::ZERO);
+    let ln_prod = hi_lo_ln_hi_lo_inner(prod.to_norm(), F::Exp::ZERO);
+    let ln_p = hi_lo_ln_hi_lo_inner(stirling_corr(F::one() / t.hi()).to_norm(), F::Exp::ZERO);
+
+    (t - F::half()) * ln_t - t + F::half_ln_2_pi().to_denorm() + ln_p - ln_prod
+}
+
+/// Calculates `I_x(a, b)` with a continued fraction
+///
+/// The even part of the usual continued fraction of `I_x(a, b)` is used,
+/// as in BFRAC from TOMS 708 (Didonato and Morris), written in terms of
+/// `λ = a - (a + b) * x`:
+///
+/// ```text
+/// I_x(a, b) = x^a * (1 - x)^b / (a * B(a, b)) * a / (β_0 + α_1 / (β_1 + α_2 / (β_2 + ...)))
+/// ```
+///
+/// where
+/// * `β_0 = (1 + λ) * a / (a + 1)`
+/// * `α_n = (a + n - 1) * (a + b + n - 1) * n * (b - n) * x^2 / (a + 2n - 1)^2`
+/// * `β_n = n + n * (b - n) * x / (a + 2n - 1)
+///   + (a + n) / (a + 2n + 1) * (1 + λ + n * (2 - x))`
+///
+/// Unlike the usual form, it does not suffer from cancellation when `a`
+/// is large and `x` is close to `a / (a + b)`, as long as `λ` is accurate.
+///
+/// `a` and `b` are positive and finite, `0 < x < (a + 1) / (a + b + 2)`,
+/// `hd = (x * (a + b) - a) / 2`, `ln_x = ln(x)` and `ln_1mx = ln(1 - x)`.
+fn beta_inc_cont_frac<F: Polygamma>(
+    a: F,
+    b: F,
+    x: DenormDouble<F>,
+    hd: DenormDouble<F>,
+    ln_x: DenormDouble<F>,
+    ln_1mx: DenormDouble<F>,
+) -> DenormDouble<F> {
+    // The rounding errors of the double-double arithmetic prevent the
+    // result from converging arbitrarily, but this is already far below
+    // the precision of the result
+    let negl_th = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS + F::MANT_BITS / 2));
+
+    // 1 + λ = 1 - 2 * hd
+    let c = (hd.pmul1(-F::two()) + F::one()).normalize();
+    // 2 - x
+    let twomx = DenormDouble::new_qsub12(F::two(), x).normalize();
+    // (a + b) / 2, halved to avoid overflow
+    let hab = DenormDouble::new_add11(a * F::half(), b * F::half());
+
+    // α_n and β_n grow like `((a + b) * x)^2` and `(a + b) * x`, so they
+    // are divided by `sc^2` and `sc` respectively to avoid overflow,
+    // where `sc` is a power of two. This multiplies the result by `sc`.
+    let sc_inv = if hab.hi() * x.hi() >= F::one() {
+        F::exp2i_fast(-(hab.hi() * x.hi()).exponent())
+    } else {
+        F::one()
+    };
+    let x_s = x.pmul1(sc_inv);
+
+    // Evaluated with the three-term recurrence of the numerators and
+    // denominators of the convergents, which are rescaled on each
+    // iteration, so the last denominator is always 1
+    let beta0 = (c.pmul1(sc_inv)
+        * (DenormDouble::new(a, F::ZERO) / DenormDouble::new_add11(a, F::one())))
+    .normalize();
+    let mut r = (DenormDouble::one() / beta0).normalize();
+    let mut an = DenormDouble::new(F::ZERO, F::ZERO);
+    let mut bn = r;
+    let mut anp1 = r;
+    let mut bnp1 = DenormDouble::one();
+    let mut n = F::ZERO;
+    loop {
+        let nm1 = n;
+        n = n + F::one();
+
+        let dn = DenormDouble::new(n, F::ZERO);
+        let a2n1 = DenormDouble::new_add11(a, n + nm1).normalize();
+        // n * (b - n) * x / sc
+        let w = DenormDouble::new_sub11(b, n) * x_s * dn;
+        // (a + b + n - 1) * x / sc / (a + 2n - 1)
+        let abnx = ((hab + nm1 * F::half()) * x_s) / a2n1.pmul1(F::half());
+        let alpha = (DenormDouble::new_add11(a, nm1) / a2n1) * abnx * w;
+        let beta = (w / a2n1
+            + (DenormDouble::new_add11(a, n) / DenormDouble::new_add11(a, n + n + F::one()))
+                * (c.pmul1(sc_inv) + twomx * dn.pmul1(sc_inv)))
+            + n * sc_inv;
+
+        let t = (alpha * an + beta * anp1).normalize();
+        an = anp1;
+        anp1 = t;
+        let t = (alpha * bn + beta * bnp1).normalize();
+        bn = bnp1;
+        bnp1 = t;
+
+        let r0 = r;
+        r = (anp1 / bnp1).normalize();
+        if (r - r0).to_single().abs() <= r.hi().abs() * negl_th {
+            break;
+        }
+
+        an = (an / bnp1).normalize();
+        bn = (bn / bnp1).normalize();
+        anp1 = r;
+        bnp1 = DenormDouble::one();
+    }
+
+    let (y, s) = beta_inc_prefactor(a, b, hd, ln_x, ln_1mx);
+    inc_gamma_mul_exp(
+        s * r * DenormDouble::new(a * sc_inv, F::ZERO),
+        y.normalize(),
+    )
+}
+
+/// Calculates `I_x(a, b)` for `b < 0.125` and `(1 - x)^b` not small, where
+/// `y = 1 - x` and `ln_y = ln(1 - x)`
+fn beta_inc_small_b<F: Polygamma>(
+    a: F,
+    b: F,
+    y: DenormDouble<F>,
+    ln_y: DenormDouble<F>,
+) -> DenormDouble<F> {
+    // I_x(a, b) = 1 - I_y(b, a)
+    //           = 1 - y^b / (b * B(a, b)) * (1 + b * T)
+    //           = -(e^(-u) - 1) - b * e^(-u) * T
+    // where
+    // * u = ln(b * B(a, b)) - b * ln(y)
+    // * T = sum((1 - a)_n / n! * y^n / (b + n)), n >= 1
+    let u = hi_lo_ln_mul_beta(b, a) - ln_y.to_semi() * b;
+
+    // e^(-u) - 1, where |u| is small
+    let (k, r_hi, r_lo) = exp_split(-u.hi());
+    let r_lo = r_lo - u.lo();
+    let exp_m1_mu = if k == 0 {
+        hi_lo_exp_m1_inner_common(r_hi, r_lo)
+    } else {
+        hi_lo_exp_inner_common(r_hi, r_lo).pmul1(F::exp2i_fast(k.cast_into())) - F::one()
+    };
+    let exp_mu = exp_m1_mu.qradd1(F::one());
+
+    let sum = beta_inc_series_tail(b, a, y);
+
+    let bt = (exp_mu.to_semi() * sum.to_semi()).to_semi() * b;
+    -(exp_m1_mu + bt)
+}
+
+/// Calculates `sum((1 - b)_n / n! * x^n / (a + n))`, with `n >= 1`, where
+/// `(1 - b)_n = (1 - b) * (2 - b) * ... * (n - b)`
+///
+/// This is the series of `I_x(a, b) = x^a / (a * B(a, b)) * (1 + a * sum)`.
+/// Its terms alternate when `b > 1`, so it is only used when `b * x` is
+/// not large.
+fn beta_inc_series_tail<F: Polygamma>(a: F, b: F, x: DenormDouble<F>) -> DenormDouble<F> {
+    // Remaining terms are negligible when they are below this threshold
+    // relative to the sum
+    let negl_th = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS * 2));
+
+    // t = (1 - b)_n / n! * x^n
+    let mut t = DenormDouble::new_sub11(F::one(), b) * x;
+    let mut sum = t / DenormDouble::new_add11(a, F::one());
+    let mut n = F::one();
+    loop {
+        n = n + F::one();
+        t = (t * DenormDouble::new_sub11(n, b) * x) / DenormDouble::new(n, F::ZERO);
+        let term = t / DenormDouble::new_add11(a, n);
+        sum = sum + term;
+        if term.hi().abs() <= sum.hi().abs() * negl_th {
+            break;
+        }
+    }
+    sum
+}
+
+/// Calculates `I_x(a, b)` or `1 - I_x(a, b)` using Temme's uniform
+/// asymptotic expansion, where `hc = (a + b) / 2`,
+/// `hd = (x * (a + b) - a) / 2` and `e` is the result of
+/// [`beta_inc_large_exp`]
+///
+/// Returns `(r, is_compl)` like [`beta_inc_inner`].
+fn beta_inc_temme<F: Polygamma>(
+    a: F,
+    b: F,
+    hc: DenormDouble<F>,
+    hd: DenormDouble<F>,
+    e: DenormDouble<F>,
+) -> (DenormDouble<F>, bool) {
+    // 1 - I_x(a, b) = erfc(w) / 2 + R
+    // I_x(a, b) = erfc(-w) / 2 - R
+    // R = exp(-w^2) / √(2π) * P_c / (P_a * P_b) * (C_0(η) / √c + O(c^(-3/2)))
+    // where
+    // * c = a + b, x0 = a / c
+    // * w^2 = E, sign(w) = sign(x - x0)
+    // * η = w * √(2 / c)
+    // * C_0(η) = √(x0 * (1 - x0)) / (x - x0) - 1 / η
+    // * P_t = P(1 / t) / t + 1
+    // The remaining terms are negligible when `a` and `b` are large.
+    let is_compl = !hd.hi().sign();
+    let e = e.to_norm();
+
+    // v = a * b / c = c * x0 * (1 - x0)
+    let v = (DenormDouble::new(a * F::half(), F::ZERO) / hc) * DenormDouble::new(b, F::ZERO);
+
+    // z + z_lo = |w|
+    let z = sqrt(e.hi());
+    let z_lo = if z == F::ZERO {
+        // x = a / c
+        F::ZERO
+    } else {
+        (e.to_denorm() - SemiDouble::new(z).square()).to_single() / (F::two() * z)
+    };
+
+    // c0 = C_0(η) * √(2 / c)
+    let c0 = if z < F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS / 2)) {
+        // C_0(0) = (2 * x0 - 1) / (3 * √(x0 * (1 - x0)))
+        let three = F::one() + F::two();
+        let c0 = ((a - b) * F::half()) / (three * hc.hi() * sqrt(v.hi())) * F::sqrt_2();
+        DenormDouble::new(c0, F::ZERO)
+    } else {
+        // C_0(η) * √(2 / c) = √(2 * v) / (2 * hd) - 1 / w
+        let t1 = hi_lo_sqrt_hi_lo_inner(v.pmul1(F::two())) / hd.pmul1(F::two());
+        let t2 = DenormDouble::one() / hi_lo_sqrt_hi_lo_inner(e.to_denorm());
+        let t2 = if is_compl { t2 } else { -t2 };
+        t1 - t2
+    };
+
+    let pa = stirling_corr(F::one() / a);
+    let pb = stirling_corr(F::one() / b);
+    let pc = stirling_corr(F::half() / hc.hi());
+    let g = pc / (pa * pb).normalize();
+
+    // r = ±R * exp(w^2)
+    let r = (c0 * g * F::frac_1_sqrt_pi().to_denorm()).pmul1(F::half());
+    let r = if is_compl { r } else { -r };
+
+    let res = if z < F::half() {
+        // |w| < 0.5, so exp_split is not needed
+        let exp_mw = hi_lo_exp_inner_common(-e.hi(), -e.lo());
+
+        // erf(z + z_lo) ~= erf(z) + 2 / √π * exp(-z^2) * z_lo
+        let erf = erf_inner_small(z).ladd(F::two() * F::frac_1_sqrt_pi().hi() * exp_mw.hi() * z_lo);
+
+        // erfc(z) / 2 + exp(-E) * r
+        DenormDouble::new_qsub12(F::half(), erf.pmul1(F::half())).qadd2(exp_mw.pmul1(r.to_single()))
+    } else {
+        // erfc(z) / 2 + exp(-E) * r = exp(-E) * (erfc(z) * exp(z^2) / 2 + r)
+        // where
+        // erfc(z + z_lo) * exp((z + z_lo)^2)
+        //   ~= s + (2 * z * s - 2 / √π) * z_lo
+        // and s = erfc(z) * exp(z^2)
+        let s = erfc_scaled_inner(z, SemiDouble::new(z).square().to_norm());
+        let s = s.ladd((F::two() * z * s.hi() - F::two() * F::frac_1_sqrt_pi().hi()) * z_lo);
+        inc_gamma_mul_exp(s.pmul1(F::half()) + r, -e.to_denorm())
+    };
+
+    (res, is_compl)
+}
+
+/// Calculates `x` such that `I_x(a, b) = t` (or `1 - I_x(a, b) = t` when
+/// `is_compl` is true) with Halley's method
+///
+/// `a` and `b` are positive and finite, `0 < t <= 0.5` and the solution is
+/// not greater than 0.5.
+fn beta_inc_inv_inner<F: Polygamma>(a: F, b: F, t: F, is_compl: bool) -> F {
+    if a < F::th_3() {
+        if let Some(x) = beta_inc_inv_small_x(a, b, t, is_compl) {
+            return x;
+        }
+    }
+
+    let mut x = beta_inc_inv_guess(a, b, t, is_compl);
+    if x >= F::half() {
+        x = F::half() * F::half();
+    }
+
+    let tol = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS));
+    let ln_t = ln(t);
+    let ln_a = ln(a);
+
+    // The solution is always between `lo` and `hi`
+    let mut lo = F::ZERO;
+    let mut hi = F::half();
+    for _ in 0..100 {
+        if x == F::ZERO {
+            // The bisection reached the limits of the precision
+            return x;
+        }
+
+        let (r, r_is_compl) = beta_inc_inner(a, b, x);
+        let v = if r_is_compl == is_compl {
+            r
+        } else {
+            DenormDouble::new_qsub12(F::one(), r)
+        };
+        let f = (v - t).to_single();
+        if f == F::ZERO {
+            break;
+        }
+
+        // I_x(a, b) is increasing and 1 - I_x(a, b) is decreasing
+        if (f > F::ZERO) != is_compl {
+            hi = x;
+        } else {
+            lo = x;
+        }
+
+        // dp = I'(x) / t = x^(a - 1) * (1 - x)^(b - 1) / B(a, b) / t
+        //    = a / (x * (1 - x)) * x^a * (1 - x)^b / (a * B(a, b)) / t
+        let ln_x = hi_lo_ln_inner_normalized(x);
+        let ln_1mx = hi_lo_ln_1p_hi_lo_inner(NormDouble::with_parts(-x, F::ZERO));
+        let hd = beta_inc_half_diff(x, a * F::half(), b * F::half());
+        let (y, s) = beta_inc_prefactor(a, b, hd, ln_x, ln_1mx);
+        let dp = exp(y.to_single() - ln_t + ln_a - ln_x.hi() - ln_1mx.hi()) * s.hi();
+
+        // When `v` underflows, the step is meaningless, so bisection
+        // is used instead
+        let new_x = if dp < F::INFINITY && v.hi() != F::ZERO {
+            let ft = f / t;
+            let new_x = if ft > F::one() || ft < -F::half() {
+                // Far from the solution, `v` behaves like an exponential,
+                // so Newton's method is applied to ln(v) - ln(t) instead,
+                // which does not take tiny steps when `v` is much larger
+                // than `t`
+                let q = ft + F::one();
+                let step = q * ln(q) / dp;
+                let step = if is_compl { -step } else { step };
+                x - step
+            } else {
+                // Halley's method, where
+                // I''(x) / I'(x) = (a - 1) / x - (b - 1) / (1 - x)
+                let step = ft / dp;
+                let step = if is_compl { -step } else { step };
+                let d2 = (a - F::one()) / x - (b - F::one()) / (F::one() - x);
+                let den = F::one() - F::half() * step * d2;
+                if den > F::half() && den < F::INFINITY {
+                    x - step / den
+                } else {
+                    x - step
+                }
+            };
+            if (new_x - x).abs() <= x * tol {
+                return new_x;
+            }
+            new_x
+        } else {
+            F::NAN
+        };
+
+        // Fall back to bisection
+        x = if new_x > lo && new_x < hi {
+            new_x
+        } else if lo == F::ZERO {
+            hi * F::half() * F::half()
+        } else {
+            let mid = sqrt(lo) * sqrt(hi);
+            if mid <= lo || mid >= hi {
+                // `lo` and `hi` are consecutive numbers, which can happen
+                // when the solution is very close to 0.5 and `hi` has not
+                // been evaluated
+                return hi;
+            }
+            mid
+        };
+    }
+    x
+}
+
+/// Calculates `x` such that `I_x(a, b) = t` (or `1 - I_x(a, b) = t` when
+/// `is_compl` is true) when `x` is small
+///
+/// `0 < a < th_3()` and `0 < t <= 0.5`. Returns `None` when `x` is not
+/// small.
+fn beta_inc_inv_small_x<F: Polygamma>(a: F, b: F, t: F, is_compl: bool) -> Option<F> {
+    // I_x(a, b) = x^a / (a * B(a, b)) * g(x)
+    // where g(x) = 1 + a * sum((1 - b)_n / n! * x^n / (a + n)) ~= 1
+    // so
+    // x = exp((ln(I) + ln(a * B(a, b)) - ln(g(x))) / a)
+    // which is solved with fixed-point iterations. Unlike Halley's method,
+    // this does not amplify the errors of I_x(a, b) by 1 / a, which is
+    // important when `a` is small.
+    let ln_p = if is_compl {
+        hi_lo_ln_1p_hi_lo_inner(NormDouble::with_parts(-t, F::ZERO))
+    } else {
+        hi_lo_ln_inner_normalized(t)
+    };
+    let l = ln_p + hi_lo_ln_mul_beta(a, b);
+    let a = DenormDouble::new(a, F::ZERO);
+
+    let ln_x = l / a;
+    if ln_x.hi() < F::exp_lo_th() {
+        // Also handles the overflow of `l / a` when `a` is tiny
+        return Some(F::ZERO);
+    }
+    // `ln_x` is normalized, since `mul_exp` needs a small low part
+    let mut ln_x = ln_x.normalize();
+    // Each iteration reduces the error by a factor of about
+    // x * |1 - b| / (a + 1)
+    if ln_x.hi() + ln(F::one() + b) > -F::cast_from(4u8) {
+        return None;
+    }
+
+    let mut x = F::ZERO;
+    for _ in 0..F::MANT_BITS {
+        let new_x = if ln_x.hi() < F::exp_lo_th() {
+            F::ZERO
+        } else {
+            mul_exp(DenormDouble::one(), ln_x)
+        };
+        if new_x == x {
+            break;
+        }
+        x = new_x;
+
+        let g_m1 =
+            beta_inc_series_tail(a.hi(), b, DenormDouble::new(x, F::ZERO)).to_semi() * a.hi();
+        let ln_g = hi_lo_ln_1p_hi_lo_inner(g_m1.to_norm());
+        ln_x = ((l - ln_g) / a).normalize();
+    }
+    Some(x)
+}
+
+/// Calculates an initial approximation for [`beta_inc_inv_inner`]
+fn beta_inc_inv_guess<F: Polygamma>(a: F, b: F, t: F, is_compl: bool) -> F {
+    let two = F::two();
+    let three = F::one() + F::two();
+
+    let x = if a >= F::one() && b >= F::one() {
+        // Approximation from Abramowitz and Stegun 26.5.22, where `z` is
+        // the quantile of the standard normal distribution
+        let z = erfcinv(two * t) * F::sqrt_2();
+        let z = if is_compl { -z } else { z };
+        let l = (z * z - three) / (two * three);
+        let ra = F::one() / (two * a - F::one());
+        let rb = F::one() / (two * b - F::one());
+        let h = two / (ra + rb);
+        let w = z * sqrt(l + h) / h
+            - (rb - ra) * (l + F::cast_from(5u8) / (two * three) - two / (three * h));
+        a / (a + b * exp(two * w))
+    } else {
+        // I_x(a, b) ~= x^a / (a * B(a, b)) for small x and
+        // 1 - I_x(a, b) ~= (1 - x)^b / (b * B(a, b)) for x close to 1,
+        // where B(a, b) is replaced with an approximation that joins
+        // both ends
+        let ln_c = ln(a + b);
+        let ta = exp(a * (ln(a) - ln_c)) / a;
+        let tb = exp(b * (ln(b) - ln_c)) / b;
+        let w = ta + tb;
+        let (p, q) = if is_compl {
+            (F::one() - t, t)
+        } else {
+            (t, F::one() - t)
+        };
+        if p * w < ta {
+            exp(ln(a * w * p) / a)
+        } else {
+            F::one() - exp(ln(b * w * q) / b)
+        }
+    };
+
+    // Keep the approximation within (0, 1), also when it is NaN
+    if x > F::ZERO && x < F::one() {
+        x
+    } else if x >= F::one() {
+        F::one() - F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS + 1))
+    } else {
+        F::exp2i_fast(F::MIN_NORMAL_EXP)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FloatMath;
+    use crate::traits::Float;
+
+    fn test_beta<F: Float + FloatMath>() {
+        use crate::beta;
+
+        assert_is_nan!(beta(F::NAN, F::one()));
+        assert_is_nan!(beta(F::one(), F::NAN));
+        assert_is_nan!(beta(F::neg_infinity(), F::one()));
+        assert_is_nan!(beta(-F::one(), F::half()));
+        assert_is_nan!(beta(F::half(), -F::two()));
+        assert_is_nan!(beta(F::ZERO, -F::ZERO));
+        assert_is_nan!(beta(F::ZERO, F::INFINITY));
+        assert_total_eq!(beta(F::ZERO, F::one()), F::INFINITY);
+        assert_total_eq!(beta(-F::ZERO, F::one()), F::neg_infinity());
+        assert_total_eq!(beta(F::ZERO, F::ZERO), F::INFINITY);
+        assert_total_eq!(beta(F::INFINITY, F::one()), F::ZERO);
+        assert_total_eq!(beta(F::INFINITY, F::INFINITY), F::ZERO);
+        assert_total_eq!(beta(F::INFINITY, -F::half()), F::neg_infinity());
+        assert_total_eq!(beta(F::half(), -F::half()), F::ZERO);
+        assert_total_eq!(beta(F::one(), F::one()), F::one());
+        assert_total_eq!(beta(F::one(), F::two()), F::half());
+    }
+
+    fn test_ln_beta<F: Float + FloatMath>() {
+        use crate::ln_beta;
+
+        let test_nan = |a: F, b: F| {
+            let (r, sign) = ln_beta(a, b);
+            assert_is_nan!(r);
+            assert_eq!(sign, 0);
+        };
+        let test_value = |a: F, b: F, r: F, sign: i8| {
+            let (res, res_sign) = ln_beta(a, b);
+            assert_total_eq!(res, r);
+            assert_eq!(res_sign, sign);
+        };
+
+        test_nan(F::NAN, F::one());
+        test_nan(F::one(), F::NAN);
+        test_nan(F::neg_infinity(), F::one());
+        test_nan(-F::one(), F::half());
+        test_value(F::ZERO, F::one(), F::INFINITY, 1);
+        test_value(-F::ZERO, F::one(), F::INFINITY, -1);
+        test_value(F::INFINITY, F::one(), F::neg_infinity(), 1);
+        test_value(F::INFINITY, -F::half(), F::INFINITY, -1);
+        test_value(F::half(), -F::half(), F::neg_infinity(), 0);
+        test_value(F::one(), F::one(), F::ZERO, 1);
+    }
+
+    fn test_beta_inc<F: Float + FloatMath>() {
+        use crate::beta_inc;
+
+        assert_is_nan!(beta_inc(F::NAN, F::one(), F::half()));
+        assert_is_nan!(beta_inc(F::one(), F::NAN, F::half()));
+        assert_is_nan!(beta_inc(F::one(), F::one(), F::NAN));
+        assert_is_nan!(beta_inc(-F::one(), F::one(), F::half()));
+        assert_is_nan!(beta_inc(F::one(), -F::one(), F::half()));
+        assert_is_nan!(beta_inc(F::one(), F::one(), -F::half()));
+        assert_is_nan!(beta_inc(F::one(), F::one(), F::two()));
+        assert_is_nan!(beta_inc(F::ZERO, F::ZERO, F::half()));
+        assert_is_nan!(beta_inc(F::INFINITY, F::INFINITY, F::half()));
+        assert_is_nan!(beta_inc(F::ZERO, F::one(), F::ZERO));
+        assert_is_nan!(beta_inc(F::one(), F::ZERO, F::one()));
+        assert_is_nan!(beta_inc(F::INFINITY, F::one(), F::one()));
+        assert_is_nan!(beta_inc(F::one(), F::INFINITY, F::ZERO));
+        assert_total_eq!(beta_inc(F::one(), F::one(), F::ZERO), F::ZERO);
+        assert_total_eq!(beta_inc(F::one(), F::one(), -F::ZERO), F::ZERO);
+        assert_total_eq!(beta_inc(F::INFINITY, F::one(), F::half()), F::ZERO);
+        assert_total_eq!(beta_inc(F::one(), F::ZERO, F::half()), F::ZERO);
+        assert_total_eq!(beta_inc(F::one(), F::one(), F::one()), F::one());
+        assert_total_eq!(beta_inc(F::ZERO, F::one(), F::half()), F::one());
+        assert_total_eq!(beta_inc(F::one(), F::INFINITY, F::half()), F::one());
+        assert_total_eq!(beta_inc(F::one(), F::one(), F::half()), F::half());
+        assert_total_eq!(beta_inc(F::two(), F::two(), F::half()), F::half());
+    }
+
+    fn test_beta_inc_inv<F: Float + FloatMath>() {
+        use crate::beta_inc_inv;
+
+        assert_is_nan!(beta_inc_inv(F::NAN, F::one(), F::half()));
+        assert_is_nan!(beta_inc_inv(F::one(), F::NAN, F::half()));
+        assert_is_nan!(beta_inc_inv(F::one(), F::one(), F::NAN));
+        assert_is_nan!(beta_inc_inv(F::ZERO, F::one(), F::half()));
+        assert_is_nan!(beta_inc_inv(F::one(), F::ZERO, F::half()));
+        assert_is_nan!(beta_inc_inv(-F::one(), F::one(), F::half()));
+        assert_is_nan!(beta_inc_inv(F::one(), F::INFINITY, F::half()));
+        assert_is_nan!(beta_inc_inv(F::one(), F::one(), -F::half()));
+        assert_is_nan!(beta_inc_inv(F::one(), F::one(), F::two()));
+        assert_total_eq!(beta_inc_inv(F::one(), F::one(), F::ZERO), F::ZERO);
+        assert_total_eq!(beta_inc_inv(F::one(), F::one(), F::one()), F::one());
+        assert_total_eq!(beta_inc_inv(F::one(), F::one(), F::half()), F::half());
+        assert_total_eq!(beta_inc_inv(F::two(), F::two(), F::half()), F::half());
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_beta::<f16>();
+        test_ln_beta::<f16>();
+        test_beta_inc::<f16>();
+        test_beta_inc_inv::<f16>();
+    }
+
+    #[test]
+    fn test_f32() {
+        test_beta::<f32>();
+        test_ln_beta::<f32>();
+        test_beta_inc::<f32>();
+        test_beta_inc_inv::<f32>();
+    }
+
+    #[test]
+    fn test_f64() {
+        test_beta::<f64>();
+        test_ln_beta::<f64>();
+        test_beta_inc::<f64>();
+        test_beta_inc_inv::<f64>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test_beta::<half::bf16>();
+        test_ln_beta::<half::bf16>();
+        test_beta_inc::<half::bf16>();
+        test_beta_inc_inv::<half::bf16>();
+    }
+}
@@ -96,10 +96,10 @@ fn cbrt_split<F: Cbrt>(x: F, edelta: F::Exp) -> (bool, F::Exp, F, SemiDouble<F>)
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>(
+    fn test<F: Float + ElementaryMath>(
         full_e_mants: impl Clone + Iterator<Item = u64>,
         extra_e: impl Iterator<Item = i32>,
         extra_e_mants: impl Clone + Iterator<Item = u64>,
@@ -132,6 +132,12 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>(0x4..=0x7, core::iter::empty(), core::iter::empty());
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>(0x80..=0xFF, core::iter::empty(), core::iter::empty());
@@ -141,4 +147,20 @@ mod tests {
     fn test_f64() {
         test::<f64>(0x100..=0x1FF, [-340, 0, 340].into_iter(), 0x10000..=0x1FFFF);
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>(
+            0x8..=0xF,
+            [-5460, 0, 5460].into_iter(),
+            (1 << 36)..=((1 << 36) + 0x3FFF),
+        );
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>(0x2..=0x3, core::iter::empty(), core::iter::empty());
+    }
 }
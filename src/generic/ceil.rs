@@ -33,10 +33,10 @@ pub(crate) fn ceil<F: Float>(x: F) -> F {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>() {
+    fn test<F: Float + ElementaryMath>() {
         use crate::ceil;
 
         let one = F::one();
@@ -62,6 +62,12 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>();
@@ -71,4 +77,16 @@ mod tests {
     fn test_f64() {
         test::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
 }
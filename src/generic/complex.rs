@@ -0,0 +1,909 @@
+use super::atan::{atan2_inner, atan2_normalize, atan2_tiny_exp_diff};
+use super::ln::{hi_lo_ln_1p_hi_lo_inner, hi_lo_ln_hi_lo_inner};
+use super::{
+    AsinAcos, Atan, Exp, Ln, ReducePi2, SinCos, SinhCosh, Tan, acos, asin, atan, atan2, atanh,
+    cosh, exp, frexp, hypot, ln, ln_1p, scalbn, sin_cos, sinh, sinh_cosh, sqrt, tan, tanh,
+};
+use crate::complex::Complex;
+use crate::double::{DenormDouble, SemiDouble};
+use crate::traits::{CastFrom as _, Float, Int as _};
+
+#[inline]
+fn is_nan<F: Float>(x: F) -> bool {
+    x.raw_exp() == F::MAX_RAW_EXP && x.raw_mant() != F::Raw::ZERO
+}
+
+#[inline]
+fn is_inf<F: Float>(x: F) -> bool {
+    x.raw_exp() == F::MAX_RAW_EXP && x.raw_mant() == F::Raw::ZERO
+}
+
+#[inline]
+fn is_finite<F: Float>(x: F) -> bool {
+    x.raw_exp() != F::MAX_RAW_EXP
+}
+
+/// Returns `x` with its sign flipped when `s` is negative (including
+/// negative zero)
+#[inline]
+fn mul_sign<F: Float>(x: F, s: F) -> F {
+    if s.sign() { -x } else { x }
+}
+
+/// Returns `-i * z`
+#[inline]
+fn mul_neg_i<F: Float>(z: Complex<F>) -> Complex<F> {
+    Complex::new(z.im, -z.re)
+}
+
+/// Returns `i * z`
+#[inline]
+fn mul_i<F: Float>(z: Complex<F>) -> Complex<F> {
+    Complex::new(-z.im, z.re)
+}
+
+/// Swaps the real and imaginary parts, that is, `i * conj(z)`
+#[inline]
+fn reverse<F: Float>(z: Complex<F>) -> Complex<F> {
+    Complex::new(z.im, z.re)
+}
+
+pub(crate) fn c_neg<F: Float>(z: Complex<F>) -> Complex<F> {
+    Complex::new(-z.re, -z.im)
+}
+
+pub(crate) fn c_conj<F: Float>(z: Complex<F>) -> Complex<F> {
+    Complex::new(z.re, -z.im)
+}
+
+pub(crate) fn c_add<F: Float>(x: Complex<F>, y: Complex<F>) -> Complex<F> {
+    Complex::new(x.re + y.re, x.im + y.im)
+}
+
+pub(crate) fn c_sub<F: Float>(x: Complex<F>, y: Complex<F>) -> Complex<F> {
+    Complex::new(x.re - y.re, x.im - y.im)
+}
+
+/// Calculates `a * b` as `hi + lo` exactly (Dekker's algorithm), unless
+/// it overflows or underflows
+///
+/// Unlike [`SemiDouble`] products, the factors are split with Veltkamp's
+/// algorithm, so the product of the low parts is also exact.
+#[inline]
+fn exact_mul<F: Float>(a: F, b: F) -> DenormDouble<F> {
+    let split = |x: F| {
+        let c =
+            (x * (F::exp2i_fast(F::Exp::cast_from((F::MANT_BITS + 2) / 2)) + F::one())).purify();
+        let hi = (c - (c - x).purify()).purify();
+        (hi, x - hi)
+    };
+    let p = (a * b).purify();
+    let (ah, al) = split(a);
+    let (bh, bl) = split(b);
+    let lo = (((ah * bh - p) + ah * bl) + al * bh) + al * bl;
+    DenormDouble::new(p, lo)
+}
+
+/// Calculates `a * b + c * d` as `r * 2^e`, with exact products, for
+/// finite `a`, `b`, `c` and `d`
+///
+/// The products are calculated on the significands, so they cannot
+/// overflow or underflow. `r` is zero only when the exact result is zero.
+fn scaled_sum_of_products<F: Float>(a: F, b: F, c: F, d: F) -> (DenormDouble<F>, i32) {
+    let (ma, ea) = frexp(a);
+    let (mb, eb) = frexp(b);
+    let (mc, ec) = frexp(c);
+    let (md, ed) = frexp(d);
+    let ab = exact_mul(ma, mb);
+    let cd = exact_mul(mc, md);
+    let (e1, e2) = (ea + eb, ec + ed);
+    if ab.hi() == F::ZERO {
+        (cd, e2)
+    } else if cd.hi() == F::ZERO {
+        (ab, e1)
+    } else {
+        let e = if e1 > e2 { e1 } else { e2 };
+        let scale =
+            |p: DenormDouble<F>, s: i32| DenormDouble::new(scalbn(p.hi(), s), scalbn(p.lo(), s));
+        ((scale(ab, e1 - e) + scale(cd, e2 - e)).normalize(), e)
+    }
+}
+
+/// Returns `plain` when it is the result of a sum of products whose exact
+/// value is zero, which gives the sign of zero of the plain expression
+#[inline]
+fn exact_zero<F: Float>(plain: F) -> F {
+    // `plain` is NaN if the products overflow with opposite signs
+    if is_nan(plain) { F::ZERO } else { plain }
+}
+
+/// Calculates `a * b + c * d` with exact products
+///
+/// Falls back to the plain expression when any operand is not finite.
+#[inline]
+fn sum_of_products<F: Float>(a: F, b: F, c: F, d: F) -> F {
+    let plain = a * b + c * d;
+    if is_finite(a) && is_finite(b) && is_finite(c) && is_finite(d) {
+        let (r, e) = scaled_sum_of_products(a, b, c, d);
+        let r = r.to_single();
+        if r == F::ZERO {
+            exact_zero(plain)
+        } else {
+            scalbn(r, e)
+        }
+    } else {
+        plain
+    }
+}
+
+pub(crate) fn c_mul<F: Float>(x: Complex<F>, y: Complex<F>) -> Complex<F> {
+    let Complex { re: a, im: b } = x;
+    let Complex { re: c, im: d } = y;
+    let re = sum_of_products(a, c, -b, d);
+    let im = sum_of_products(a, d, b, c);
+    if is_nan(re) && is_nan(im) {
+        // Recover infinities as in C99 Annex G (G.5.1)
+        let [mut a, mut b, mut c, mut d] = [a, b, c, d];
+        let box_inf = |v: F| {
+            let one_or_zero = if is_inf(v) { F::one() } else { F::ZERO };
+            mul_sign(one_or_zero, v)
+        };
+        let nan_to_zero = |v: F| if is_nan(v) { mul_sign(F::ZERO, v) } else { v };
+        let mut recalc = false;
+        if is_inf(a) || is_inf(b) {
+            a = box_inf(a);
+            b = box_inf(b);
+            c = nan_to_zero(c);
+            d = nan_to_zero(d);
+            recalc = true;
+        }
+        if is_inf(c) || is_inf(d) {
+            c = box_inf(c);
+            d = box_inf(d);
+            a = nan_to_zero(a);
+            b = nan_to_zero(b);
+            recalc = true;
+        }
+        if !recalc && (is_inf(a * c) || is_inf(b * d) || is_inf(a * d) || is_inf(b * c)) {
+            a = nan_to_zero(a);
+            b = nan_to_zero(b);
+            c = nan_to_zero(c);
+            d = nan_to_zero(d);
+            recalc = true;
+        }
+        if recalc {
+            return Complex::new(F::INFINITY * (a * c - b * d), F::INFINITY * (a * d + b * c));
+        }
+    }
+    Complex::new(re, im)
+}
+
+pub(crate) fn c_div<F: Float>(x: Complex<F>, y: Complex<F>) -> Complex<F> {
+    let Complex { re: a, im: b } = x;
+    let Complex { re: c, im: d } = y;
+    let finite = is_finite(a) && is_finite(b) && is_finite(c) && is_finite(d);
+    let max_ab = if a.abs() > b.abs() { a.abs() } else { b.abs() };
+    let max_cd = if c.abs() > d.abs() { c.abs() } else { d.abs() };
+
+    if finite && max_ab != F::ZERO && max_cd != F::ZERO {
+        // (a + i * b) / (c + i * d) = ((a * c + b * d) + i * (b * c - a * d)) / (c^2 + d^2)
+        // with the sums of products scaled to avoid overflow and underflow
+        let (denom, edenom) = scaled_sum_of_products(c, c, d, d);
+        let div = |(n, e): (DenormDouble<F>, i32), plain: F| {
+            if n.hi() == F::ZERO {
+                exact_zero(plain)
+            } else {
+                scalbn((n / denom).to_single(), e - edenom)
+            }
+        };
+        let re = div(scaled_sum_of_products(a, c, b, d), a * c + b * d);
+        let im = div(scaled_sum_of_products(b, c, -a, d), b * c - a * d);
+        return Complex::new(re, im);
+    }
+
+    // Zero, infinity or NaN in any part, following C99 Annex G (G.5.1)
+    let (c, d, ey) = if is_finite(c) && is_finite(d) && max_cd != F::ZERO {
+        let (_, ey) = frexp(max_cd);
+        (scalbn(c, -ey), scalbn(d, -ey), ey)
+    } else {
+        (c, d, 0)
+    };
+    let denom = c * c + d * d;
+    let re = scalbn((a * c + b * d) / denom, -ey);
+    let im = scalbn((b * c - a * d) / denom, -ey);
+    if is_nan(re) && is_nan(im) {
+        if denom == F::ZERO && (!is_nan(a) || !is_nan(b)) {
+            let inf = mul_sign(F::INFINITY, c);
+            return Complex::new(inf * a, inf * b);
+        } else if (is_inf(a) || is_inf(b)) && is_finite(c) && is_finite(d) {
+            let a = mul_sign(if is_inf(a) { F::one() } else { F::ZERO }, a);
+            let b = mul_sign(if is_inf(b) { F::one() } else { F::ZERO }, b);
+            return Complex::new(F::INFINITY * (a * c + b * d), F::INFINITY * (b * c - a * d));
+        } else if (is_inf(c) || is_inf(d)) && is_finite(a) && is_finite(b) {
+            let c = mul_sign(if is_inf(c) { F::one() } else { F::ZERO }, c);
+            let d = mul_sign(if is_inf(d) { F::one() } else { F::ZERO }, d);
+            return Complex::new(F::ZERO * (a * c + b * d), F::ZERO * (b * c - a * d));
+        }
+    }
+    Complex::new(re, im)
+}
+
+/// Calculates `exp(x) * (cos(y) + i * sin(y))` for finite `x` and `y`,
+/// avoiding overflow when `exp(x)` overflows but the result does not
+fn exp_cis<F: Exp + SinCos + ReducePi2>(x: F, y: F) -> Complex<F> {
+    let (s, c) = sin_cos(y);
+    let e = exp(x);
+    if is_inf(e) {
+        let h = exp(x * F::half());
+        Complex::new((h * c) * h, (h * s) * h)
+    } else {
+        Complex::new(e * c, e * s)
+    }
+}
+
+pub(crate) fn c_exp<F: Exp + SinCos + ReducePi2>(z: Complex<F>) -> Complex<F> {
+    let Complex { re: x, im: y } = z;
+    if y == F::ZERO {
+        // exp(x ± i0) = exp(x) ± i0, also when x is infinite or NaN
+        Complex::new(exp(x), y)
+    } else if is_finite(x) {
+        if is_finite(y) {
+            exp_cis(x, y)
+        } else {
+            Complex::new(F::NAN, F::NAN)
+        }
+    } else if is_inf(x) {
+        if !is_finite(y) {
+            // exp(+inf + i * (inf or NaN)) = inf + i * NaN
+            // exp(-inf + i * (inf or NaN)) = 0 + i0
+            if x.sign() {
+                Complex::new(F::ZERO, F::ZERO)
+            } else {
+                Complex::new(x, F::NAN)
+            }
+        } else {
+            // exp(±inf + iy) = ±inf * (cos(y) + i * sin(y)), where the
+            // product is infinity or zero with the sign of the cosine
+            // or the sine
+            let (s, c) = sin_cos(y);
+            let m = if x.sign() { F::ZERO } else { F::INFINITY };
+            Complex::new(mul_sign(m, c), mul_sign(m, s))
+        }
+    } else {
+        Complex::new(F::NAN, F::NAN)
+    }
+}
+
+/// Calculates `x^2 + y^2 - 1` as a double-float, without cancellation
+/// when `x^2 + y^2` is close to one (Briggs-Kahan algorithm)
+///
+/// `x^2 + y^2` must not overflow.
+fn hypot_sq_sub1<F: Float>(x: F, y: F) -> DenormDouble<F> {
+    let (x, y) = if x.abs() > y.abs() { (x, y) } else { (y, x) };
+    let x2 = exact_mul(x, x);
+    let y2 = exact_mul(y, y);
+    let s = DenormDouble::new_qadd11(x2.hi(), y2.hi());
+    let u = DenormDouble::new_sub11(s.hi(), F::one());
+    let v = DenormDouble::new_add11(s.lo(), u.lo());
+    let w = DenormDouble::new_add11(x2.lo(), y2.lo());
+    let a = DenormDouble::new_add11(u.hi(), v.hi());
+    let b = DenormDouble::new_add11(a.hi(), w.hi());
+    let lo = ((w.lo() + v.lo()) + a.lo()) + b.lo();
+    DenormDouble::new_qadd11(b.hi(), lo)
+}
+
+/// Calculates `ln(hypot(x, y))` as a double-float
+///
+/// `x` and `y` must be finite and not both zero.
+fn hi_lo_ln_abs<F: Ln>(x: F, y: F) -> DenormDouble<F> {
+    let (ax, ay) = (x.abs(), y.abs());
+    let (max, min) = if ax > ay { (ax, ay) } else { (ay, ax) };
+
+    let (_, e) = frexp(max);
+    if e == 0 || e == 1 {
+        let t = hypot_sq_sub1(max, min);
+        if t.hi() > -F::half() && t.hi() < F::one() {
+            // |z| is close to one, ln(x^2 + y^2) = ln(1 + t) without
+            // cancellation
+            let r = hi_lo_ln_1p_hi_lo_inner(t.to_norm());
+            return DenormDouble::new(r.hi() * F::half(), r.lo() * F::half());
+        }
+    }
+
+    // x^2 + y^2 = (x'^2 + y'^2) * 2^(2 * e), with 0.25 <= x'^2 + y'^2 < 2,
+    // calculated as a double-float so ln(x^2 + y^2) is accurate
+    let sx = SemiDouble::new(scalbn(ax, -e));
+    let sy = SemiDouble::new(scalbn(ay, -e));
+    let s = (sx.square() + sy.square()).to_norm();
+
+    let r = hi_lo_ln_hi_lo_inner(s, F::Exp::cast_from(e * 2));
+    DenormDouble::new(r.hi() * F::half(), r.lo() * F::half())
+}
+
+/// Calculates `ln(hypot(x, y))`, with `x` and `y` not NaN
+fn ln_abs<F: Ln>(x: F, y: F) -> F {
+    if is_inf(x) || is_inf(y) {
+        F::INFINITY
+    } else if x == F::ZERO && y == F::ZERO {
+        F::neg_infinity()
+    } else {
+        hi_lo_ln_abs(x, y).to_single()
+    }
+}
+
+pub(crate) fn c_ln<F: Ln + Atan>(z: Complex<F>) -> Complex<F> {
+    let Complex { re: x, im: y } = z;
+    let re = if is_inf(x) || is_inf(y) {
+        // even if the other part is NaN
+        F::INFINITY
+    } else if is_nan(x) || is_nan(y) {
+        F::NAN
+    } else {
+        ln_abs(x, y)
+    };
+    Complex::new(re, atan2(y, x))
+}
+
+pub(crate) fn c_sqrt<F: Float>(z: Complex<F>) -> Complex<F> {
+    let Complex { re: x, im: y } = z;
+    if is_inf(y) {
+        // sqrt(x ± i * inf) = inf ± i * inf, even if x is NaN
+        Complex::new(F::INFINITY, y)
+    } else if is_nan(x) {
+        Complex::new(x, F::NAN)
+    } else if is_inf(x) {
+        if x.sign() {
+            // sqrt(-inf + iy) = 0 + i * inf * sign(y)
+            // sqrt(-inf + i * NaN) = NaN ± i * inf
+            let re = if is_nan(y) { y } else { F::ZERO };
+            Complex::new(re, mul_sign(F::INFINITY, y))
+        } else {
+            // sqrt(inf + iy) = inf + i0 * sign(y)
+            // sqrt(inf + i * NaN) = inf + i * NaN
+            let im = if is_nan(y) { y } else { mul_sign(F::ZERO, y) };
+            Complex::new(x, im)
+        }
+    } else if is_nan(y) {
+        Complex::new(F::NAN, F::NAN)
+    } else if x == F::ZERO && y == F::ZERO {
+        Complex::new(F::ZERO, y)
+    } else {
+        // t = sqrt((|x| + |z|) / 2), calculated with x and y scaled by an
+        // even power of two to avoid overflow and underflow
+        let (ax, ay) = (x.abs(), y.abs());
+        let max = if ax > ay { ax } else { ay };
+        let (_, e) = frexp(max);
+        let k = e / 2;
+        let (sx, sy) = (scalbn(ax, -2 * k), scalbn(ay, -2 * k));
+        let t = scalbn(sqrt((sx + hypot(sx, sy)) * F::half()), k);
+
+        // sqrt(z) = t + i * y / (2 * t) when x >= 0
+        // sqrt(z) = |y| / (2 * t) + i * t * sign(y) when x < 0
+        let u = ay / (F::two() * t);
+        if x.sign() {
+            Complex::new(u, mul_sign(t, y))
+        } else {
+            Complex::new(t, mul_sign(u, y))
+        }
+    }
+}
+
+/// Calculates `atan2(y, x)` as a double-float, for finite `x` and `y`, not
+/// both zero
+fn hi_lo_atan2<F: Atan>(y: F, x: F) -> DenormDouble<F> {
+    let (ny, nx) = atan2_normalize(y, x);
+    let (nxexp, nyexp) = (nx.raw_exp(), ny.raw_exp());
+    if !nx.sign() && nxexp > nyexp && (nxexp - nyexp) >= atan2_tiny_exp_diff::<F>() {
+        // y/x is very small
+        DenormDouble::new(atan2(y, x), F::ZERO)
+    } else {
+        atan2_inner(ny, nx)
+    }
+}
+
+pub(crate) fn c_pow<F: Exp + Ln + Atan + SinCos + ReducePi2>(
+    z: Complex<F>,
+    w: Complex<F>,
+) -> Complex<F> {
+    let Complex { re: x, im: y } = z;
+    let Complex { re: a, im: b } = w;
+    if a == F::ZERO && b == F::ZERO {
+        // z^0 = 1, even if z is NaN
+        return Complex::new(F::one(), F::ZERO);
+    }
+    if x == F::ZERO && y == F::ZERO && b == F::ZERO && a > F::ZERO {
+        // 0^a = 0, when a is real and positive
+        return Complex::new(F::ZERO, F::ZERO);
+    }
+    if !is_finite(x) || !is_finite(y) || !is_finite(a) || !is_finite(b) {
+        return c_exp(c_mul(w, c_ln(z)));
+    }
+    if x == F::ZERO && y == F::ZERO {
+        return c_exp(c_mul(w, c_ln(z)));
+    }
+
+    // z^w = exp(t), with t = w * ln(z) = (a * l - b * theta) + i * (a * theta + b * l)
+    // l = ln(|z|) and the products are calculated as double-floats, with
+    // full precision since the absolute error of ti matters when |ti| is large
+    let l = hi_lo_ln_abs(x, y);
+    let theta = hi_lo_atan2(y, x);
+    let mul = |p: DenormDouble<F>, q: F| exact_mul(p.hi(), q).ladd(p.lo() * q);
+    let tr = mul(l, a) - mul(theta, b);
+    let ti = mul(l, b) + mul(theta, a);
+    let (tr_hi, tr_lo) = (tr.to_single(), tr.to_norm().lo());
+    let (ti_hi, ti_lo) = (ti.to_single(), ti.to_norm().lo());
+    if !is_finite(tr_hi) || !is_finite(ti_hi) {
+        return c_exp(c_mul(w, c_ln(z)));
+    }
+
+    // exp(tr_hi + tr_lo) ~= exp(tr_hi) * (1 + tr_lo)
+    // ti_lo is not necessarily small when |ti| is large, so
+    // cos(ti_hi + ti_lo) = cos(ti_hi) * cos(ti_lo) - sin(ti_hi) * sin(ti_lo)
+    // sin(ti_hi + ti_lo) = sin(ti_hi) * cos(ti_lo) + cos(ti_hi) * sin(ti_lo)
+    let (s, c) = sin_cos(ti_hi);
+    let (sl, cl) = sin_cos(ti_lo);
+    let (s, c) = (s * cl + c * sl, c * cl - s * sl);
+    let (s, c) = (s + s * tr_lo, c + c * tr_lo);
+    let e = exp(tr_hi);
+    if is_inf(e) {
+        let h = exp(tr_hi * F::half());
+        Complex::new((h * c) * h, (h * s) * h)
+    } else {
+        Complex::new(e * c, e * s)
+    }
+}
+
+pub(crate) fn c_sinh<F: SinhCosh + SinCos + ReducePi2>(z: Complex<F>) -> Complex<F> {
+    let Complex { re: x, im: y } = z;
+    if is_finite(x) && is_finite(y) {
+        if y == F::ZERO {
+            Complex::new(sinh(x), y)
+        } else {
+            // sinh(x + iy) = sinh(x) * cos(y) + i * cosh(x) * sin(y)
+            let (s, c) = sin_cos(y);
+            let (sh, ch) = sinh_cosh(x);
+            if is_inf(ch) {
+                // |sinh(x)| = cosh(x) = exp(|x|) / 2 overflows, but the
+                // result might not
+                let h = exp(x.abs() * F::half());
+                let hh = h * F::half();
+                Complex::new(mul_sign((hh * c) * h, x), (hh * s) * h)
+            } else {
+                Complex::new(sh * c, ch * s)
+            }
+        }
+    } else if x == F::ZERO {
+        // sinh(±0 + i * (inf or NaN)) = ±0 + i * NaN
+        Complex::new(x, F::NAN)
+    } else if y == F::ZERO {
+        // sinh(±inf ± i0) = ±inf ± i0
+        // sinh(NaN ± i0) = NaN ± i0
+        Complex::new(x, y)
+    } else if is_finite(x) {
+        Complex::new(F::NAN, F::NAN)
+    } else if is_inf(x) {
+        if is_finite(y) {
+            // sinh(±inf + iy) = ±inf * cos(y) + i * inf * sin(y)
+            let (s, c) = sin_cos(y);
+            Complex::new(mul_sign(x, c), mul_sign(F::INFINITY, s))
+        } else {
+            // sinh(±inf + i * (inf or NaN)) = ±inf + i * NaN
+            Complex::new(x, F::NAN)
+        }
+    } else {
+        Complex::new(F::NAN, F::NAN)
+    }
+}
+
+pub(crate) fn c_cosh<F: SinhCosh + SinCos + ReducePi2>(z: Complex<F>) -> Complex<F> {
+    let Complex { re: x, im: y } = z;
+    if is_finite(x) && is_finite(y) {
+        if y == F::ZERO {
+            Complex::new(cosh(x), mul_sign(y, x))
+        } else {
+            // cosh(x + iy) = cosh(x) * cos(y) + i * sinh(x) * sin(y)
+            let (s, c) = sin_cos(y);
+            let (sh, ch) = sinh_cosh(x);
+            if is_inf(ch) {
+                // |sinh(x)| = cosh(x) = exp(|x|) / 2 overflows, but the
+                // result might not
+                let h = exp(x.abs() * F::half());
+                let hh = h * F::half();
+                Complex::new((hh * c) * h, mul_sign((hh * s) * h, x))
+            } else {
+                Complex::new(ch * c, sh * s)
+            }
+        }
+    } else if x == F::ZERO {
+        // cosh(±0 + i * (inf or NaN)) = NaN ± i0
+        Complex::new(F::NAN, mul_sign(mul_sign(F::ZERO, y), x))
+    } else if y == F::ZERO {
+        // cosh(±inf ± i0) = inf ± i0
+        // cosh(NaN ± i0) = NaN ± i0
+        Complex::new(x.abs(), mul_sign(y, x))
+    } else if is_finite(x) {
+        Complex::new(F::NAN, F::NAN)
+    } else if is_inf(x) {
+        if is_finite(y) {
+            // cosh(±inf + iy) = inf * cos(y) ± i * inf * sin(y)
+            let (s, c) = sin_cos(y);
+            Complex::new(mul_sign(F::INFINITY, c), mul_sign(x, s))
+        } else {
+            // cosh(±inf + i * (inf or NaN)) = inf + i * NaN
+            Complex::new(F::INFINITY, F::NAN)
+        }
+    } else {
+        Complex::new(F::NAN, F::NAN)
+    }
+}
+
+pub(crate) fn c_tanh<F: SinhCosh + SinCos + ReducePi2 + Tan + Ln>(z: Complex<F>) -> Complex<F> {
+    let Complex { re: x, im: y } = z;
+    if !is_finite(x) {
+        if is_nan(x) {
+            // tanh(NaN ± i0) = NaN ± i0
+            let im = if y == F::ZERO { y } else { F::NAN };
+            Complex::new(x, im)
+        } else {
+            // tanh(±inf + iy) = ±1 + i0 * sin(2 * y)
+            let im = if is_finite(y) {
+                let (s, c) = sin_cos(y);
+                mul_sign(F::ZERO, s * c)
+            } else {
+                mul_sign(F::ZERO, y)
+            };
+            Complex::new(mul_sign(F::one(), x), im)
+        }
+    } else if !is_finite(y) {
+        // tanh(±0 + i * (inf or NaN)) = ±0 + i * NaN
+        let re = if x == F::ZERO { x } else { F::NAN };
+        Complex::new(re, F::NAN)
+    } else if y == F::ZERO {
+        Complex::new(tanh(x), y)
+    } else if x.abs() > F::cast_from(F::MANT_BITS + 4) * <F as Ln>::ln_2_hi() * F::half() {
+        // tanh(x + iy) ~= ±1 + i * 4 * sin(y) * cos(y) * exp(-2 * |x|)
+        let (s, c) = sin_cos(y);
+        let e = exp(-x.abs());
+        Complex::new(mul_sign(F::one(), x), (F::two() * F::two() * s * c) * e * e)
+    } else {
+        // Kahan's algorithm:
+        // t = tan(y), beta = 1 + t^2, s = sinh(x), rho = sqrt(1 + s^2)
+        // tanh(x + iy) = (beta * rho * s + i * t) / (1 + beta * s^2)
+        let t = tan(y);
+        let beta = F::one() + t * t;
+        let s = sinh(x);
+        let rho = sqrt(F::one() + s * s);
+        let denom = F::one() + beta * s * s;
+        Complex::new((beta * rho * s) / denom, t / denom)
+    }
+}
+
+pub(crate) fn c_sin<F: SinhCosh + SinCos + ReducePi2>(z: Complex<F>) -> Complex<F> {
+    // sin(z) = -i * sinh(i * z)
+    mul_neg_i(c_sinh(mul_i(z)))
+}
+
+pub(crate) fn c_cos<F: SinhCosh + SinCos + ReducePi2>(z: Complex<F>) -> Complex<F> {
+    // cos(z) = cosh(i * z)
+    c_cosh(mul_i(z))
+}
+
+pub(crate) fn c_tan<F: SinhCosh + SinCos + ReducePi2 + Tan + Ln>(z: Complex<F>) -> Complex<F> {
+    // tan(z) = -i * tanh(i * z)
+    mul_neg_i(c_tanh(mul_i(z)))
+}
+
+// The inverse functions are based on the algorithms from the msun math
+// library (catrig.c), which are based on:
+//
+// T. E. Hull, Thomas F. Fairgrieve, Ping Tak Peter Tang,
+// "Implementing the complex arcsine and arccosine functions using exception
+// handling", ACM Transactions on Mathematical Software, Volume 23 Issue 3,
+// 1997, Pages 299-335.
+
+/// Returns `(hypot(a, b) - b) / 2`, given `h = hypot(a, b)`
+#[inline]
+fn half_hypot_sub<F: Float>(a: F, b: F, h: F) -> F {
+    if b < F::ZERO {
+        (h - b) * F::half()
+    } else if b == F::ZERO {
+        a * F::half()
+    } else {
+        a * a / (h + b) * F::half()
+    }
+}
+
+/// Imaginary part of `asinh` or real part of `acos`, as returned by
+/// [`asinh_hard_work`]
+enum AsinhIm<F> {
+    /// `B = y / A`, the result is `asin(B)` or `acos(B)`
+    B(F),
+    /// `(new_y, sqrt(A^2 - y^2))`, scaled together to avoid underflow, the
+    /// result is `atan2(new_y, sqrt(A^2 - y^2))`
+    Atan2(F, F),
+}
+
+/// Calculates the real part of `asinh(x + iy)` and the value needed for the
+/// imaginary part
+///
+/// `x` and `y` must be non-negative and not greater than `2^MANT_BITS`.
+///
+/// With `R = hypot(x, y + 1)`, `S = hypot(x, y - 1)` and `A = (R + S) / 2`:
+/// * `Re(asinh(x + iy)) = ln(A + sqrt(A^2 - 1))`
+/// * `Im(asinh(x + iy)) = asin(y / A)`
+fn asinh_hard_work<F: Ln>(x: F, y: F) -> (F, AsinhIm<F>) {
+    let eps = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS));
+    let a_crossover = F::cast_from(10u8);
+    // Hull et al suggest 0.6417
+    let b_crossover = F::cast_from(41u8) * F::exp2i_fast(-F::Exp::cast_from(6u8));
+    let four_sqrt_min = F::exp2i_fast(F::MIN_NORMAL_EXP / F::Exp::TWO + F::Exp::TWO);
+
+    // |z + i| and |z - i|
+    let r = hypot(x, y + F::one());
+    let s = hypot(x, y - F::one());
+
+    // A >= 1, but it could be less due to rounding errors
+    let a = (r + s) * F::half();
+    let a = if a < F::one() { F::one() } else { a };
+
+    let rx = if a < a_crossover {
+        // Re(asinh(z)) = ln_1p(am1 + sqrt(am1 * (A + 1))), with
+        // am1 = A - 1 = fp + fm, where fp = f(x, 1 + y) and fm = f(x, 1 - y)
+        if y == F::one() && x < eps * eps * F::exp2i_fast(-F::Exp::cast_from(7u8)) {
+            // fp is of order x^2 and fm = x / 2
+            sqrt(x)
+        } else if x >= eps * (y - F::one()).abs() {
+            let am1 = half_hypot_sub(x, F::one() + y, r) + half_hypot_sub(x, F::one() - y, s);
+            ln_1p(am1 + sqrt(am1 * (a + F::one())))
+        } else if y < F::one() {
+            // fp = x^2 / (1 + y) / 4, fm = x^2 / (1 - y) / 4
+            x / sqrt((F::one() - y) * (F::one() + y))
+        } else {
+            // A - 1 ~= y - 1
+            ln_1p((y - F::one()) + sqrt((y - F::one()) * (y + F::one())))
+        }
+    } else {
+        ln(a + sqrt(a * a - F::one()))
+    };
+
+    if y < four_sqrt_min {
+        // avoid underflow of y / A
+        let scale = F::two() / eps;
+        return (rx, AsinhIm::Atan2(y * scale, a * scale));
+    }
+
+    let b = y / a;
+    if b <= b_crossover {
+        return (rx, AsinhIm::B(b));
+    }
+
+    // asin(B) loses accuracy when B is close to 1, so it is calculated as
+    // atan2(y, sqrt(A^2 - y^2)), with A - y = fp + fm, where
+    // fp = f(x, y + 1) and fm = f(x, y - 1)
+    let im = if y == F::one() && x < eps * F::exp2i_fast(-F::Exp::cast_from(7u8)) {
+        AsinhIm::Atan2(y, sqrt(x) * sqrt((a + y) * F::half()))
+    } else if x >= eps * (y - F::one()).abs() {
+        let amy = half_hypot_sub(x, y + F::one(), r) + half_hypot_sub(x, y - F::one(), s);
+        AsinhIm::Atan2(y, sqrt(amy * (a + y)))
+    } else if y > F::one() {
+        // fp = x^2 / (y + 1) / 4, fm = x^2 / (y - 1) / 4, A ~= y
+        let scale = F::two() * F::two() / eps / eps;
+        AsinhIm::Atan2(
+            y * scale,
+            x * scale * y / sqrt((y + F::one()) * (y - F::one())),
+        )
+    } else {
+        // fm = 1 - y >= eps, fp is of order x^2, A ~= 1
+        AsinhIm::Atan2(y, sqrt((F::one() - y) * (F::one() + y)))
+    };
+    (rx, im)
+}
+
+/// Returns `2^MANT_BITS`, above which the inverse functions are calculated
+/// from the logarithm
+#[inline]
+fn recip_eps<F: Float>() -> F {
+    F::exp2i_fast(F::Exp::cast_from(F::MANT_BITS))
+}
+
+/// Returns the threshold below which the inverse functions are calculated
+/// with the first term of their Taylor series
+#[inline]
+fn tiny_th<F: Float>() -> F {
+    F::exp2i_fast(-F::Exp::cast_from((F::MANT_BITS + 3) / 2))
+}
+
+/// Calculates `ln(hypot(x, y)) + ln(2)`, for the inverse functions with
+/// large arguments
+#[inline]
+fn ln_abs_2<F: Ln>(x: F, y: F) -> F {
+    (ln_abs(x, y) + F::ln_2_lo()) + F::ln_2_hi()
+}
+
+pub(crate) fn c_asinh<F: Ln + Atan + AsinAcos>(z: Complex<F>) -> Complex<F> {
+    let Complex { re: x, im: y } = z;
+    let (ax, ay) = (x.abs(), y.abs());
+
+    if is_nan(x) || is_nan(y) {
+        return if is_inf(x) {
+            // asinh(±inf + i * NaN) = ±inf + i * NaN
+            Complex::new(x, F::NAN)
+        } else if is_inf(y) {
+            // asinh(NaN ± i * inf) = ±inf + i * NaN
+            Complex::new(y, F::NAN)
+        } else if y == F::ZERO {
+            // asinh(NaN ± i0) = NaN ± i0
+            Complex::new(x, y)
+        } else {
+            Complex::new(F::NAN, F::NAN)
+        };
+    }
+
+    if ax > recip_eps() || ay > recip_eps() {
+        // asinh(z) ~= sign(x) * ln(2 * sign(x) * z)
+        let rx = ln_abs_2(ax, ay);
+        let ry = atan2(ay, ax);
+        return Complex::new(mul_sign(rx, x), mul_sign(ry, y));
+    }
+
+    if ax < tiny_th() && ay < tiny_th() {
+        // asinh(z) ~= z
+        return z;
+    }
+
+    let (rx, im) = asinh_hard_work(ax, ay);
+    let ry = match im {
+        AsinhIm::B(b) => asin(b),
+        AsinhIm::Atan2(ny, d) => atan2(ny, d),
+    };
+    Complex::new(mul_sign(rx, x), mul_sign(ry, y))
+}
+
+pub(crate) fn c_asin<F: Ln + Atan + AsinAcos>(z: Complex<F>) -> Complex<F> {
+    // asin(z) = reverse(asinh(reverse(z)))
+    reverse(c_asinh(reverse(z)))
+}
+
+pub(crate) fn c_acos<F: Ln + Atan + AsinAcos>(z: Complex<F>) -> Complex<F> {
+    let Complex { re: x, im: y } = z;
+    let (ax, ay) = (x.abs(), y.abs());
+
+    if is_nan(x) || is_nan(y) {
+        return if is_inf(x) {
+            // acos(±inf + i * NaN) = NaN ± i * inf
+            Complex::new(F::NAN, F::neg_infinity())
+        } else if is_inf(y) {
+            // acos(NaN ± i * inf) = NaN ∓ i * inf
+            Complex::new(F::NAN, -y)
+        } else if x == F::ZERO {
+            // acos(±0 + i * NaN) = π/2 + i * NaN
+            Complex::new(F::FRAC_PI_2, F::NAN)
+        } else {
+            Complex::new(F::NAN, F::NAN)
+        };
+    }
+
+    if ax > recip_eps() || ay > recip_eps() {
+        // acos(z) ~= -sign(y) * i * ln(2 * z)
+        let rx = atan2(ay, x);
+        let ry = ln_abs_2(ax, ay);
+        return Complex::new(rx, mul_sign(-ry, y));
+    }
+
+    if x == F::one() && y == F::ZERO {
+        return Complex::new(F::ZERO, -y);
+    }
+
+    if ax < tiny_th() && ay < tiny_th() {
+        // acos(z) ~= π/2 - z
+        return Complex::new(F::FRAC_PI_2 - x, -y);
+    }
+
+    // acos(z) = π/2 - asin(z), with
+    // asin(x + iy) = reverse(asinh(y + ix))
+    let (ry, im) = asinh_hard_work(ay, ax);
+    let rx = match im {
+        AsinhIm::B(b) => acos(mul_sign(b, x)),
+        AsinhIm::Atan2(nx, d) => atan2(d, mul_sign(nx, x)),
+    };
+    Complex::new(rx, mul_sign(-ry, y))
+}
+
+pub(crate) fn c_acosh<F: Ln + Atan + AsinAcos>(z: Complex<F>) -> Complex<F> {
+    // acosh(z) = ±i * acos(z), with the sign chosen to make the real
+    // part non-negative
+    let w = c_acos(z);
+    if is_nan(w.re) && is_nan(w.im) {
+        Complex::new(F::NAN, F::NAN)
+    } else if is_nan(w.re) {
+        // acosh(NaN ± i * inf) = inf + i * NaN
+        // acosh(±inf + i * NaN) = inf + i * NaN
+        Complex::new(w.im.abs(), F::NAN)
+    } else if is_nan(w.im) {
+        // acosh(±0 + i * NaN) = NaN + i * NaN
+        Complex::new(F::NAN, F::NAN)
+    } else {
+        Complex::new(w.im.abs(), mul_sign(w.re, z.im))
+    }
+}
+
+/// Calculates the real part of `1 / (x + iy)`, that is `x / (x^2 + y^2)`,
+/// for `x` and `y` not NaN, avoiding overflow and underflow
+fn real_part_recip<F: Float>(x: F, y: F) -> F {
+    if is_inf(x) {
+        F::one() / x
+    } else if is_inf(y) {
+        x / y / y
+    } else {
+        let (ax, ay) = (x.abs(), y.abs());
+        let max = if ax > ay { ax } else { ay };
+        let (_, e) = frexp(max);
+        let (x, y) = (scalbn(x, -e), scalbn(y, -e));
+        scalbn(x / (x * x + y * y), -e)
+    }
+}
+
+pub(crate) fn c_atanh<F: Ln + Atan>(z: Complex<F>) -> Complex<F> {
+    let Complex { re: x, im: y } = z;
+    let (ax, ay) = (x.abs(), y.abs());
+
+    if y == F::ZERO && ax <= F::one() {
+        return Complex::new(atanh(x), y);
+    }
+
+    if x == F::ZERO {
+        return Complex::new(x, atan(y));
+    }
+
+    if is_nan(x) || is_nan(y) {
+        return if is_inf(x) {
+            // atanh(±inf + i * NaN) = ±0 + i * NaN
+            Complex::new(mul_sign(F::ZERO, x), F::NAN)
+        } else if is_inf(y) {
+            // atanh(NaN ± i * inf) = ±0 ± i * π/2
+            Complex::new(mul_sign(F::ZERO, x), mul_sign(F::FRAC_PI_2, y))
+        } else {
+            Complex::new(F::NAN, F::NAN)
+        };
+    }
+
+    if ax > recip_eps() || ay > recip_eps() {
+        // atanh(z) ~= 1 / z ± i * π/2
+        return Complex::new(real_part_recip(x, y), mul_sign(F::FRAC_PI_2, y));
+    }
+
+    if ax < tiny_th() && ay < tiny_th() {
+        // atanh(z) ~= z
+        return z;
+    }
+
+    // Re(atanh(z)) = ln_1p(4 * |x| / ((|x| - 1)^2 + y^2)) / 4
+    let eps = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS));
+    let rx = if ax == F::one() && ay < eps {
+        ((F::ln_2_hi() - ln(ay)) + F::ln_2_lo()) * F::half()
+    } else {
+        let axm1 = ax - F::one();
+        let sum_sq = if ay < F::exp2i_fast(F::MIN_NORMAL_EXP / F::Exp::TWO) {
+            axm1 * axm1
+        } else {
+            axm1 * axm1 + ay * ay
+        };
+        ln_1p(F::two() * F::two() * ax / sum_sq) * F::half() * F::half()
+    };
+
+    // Im(atanh(z)) = atan2(2 * |y|, 1 - x^2 - y^2) / 2, where the
+    // denominator is calculated as a double-float because it can cancel
+    let ry = if ax == F::one() {
+        atan2(F::two(), -ay) * F::half()
+    } else {
+        let d = -hypot_sq_sub1(ax, ay).to_single();
+        atan2(F::two() * ay, d) * F::half()
+    };
+
+    Complex::new(mul_sign(rx, x), mul_sign(ry, y))
+}
+
+pub(crate) fn c_atan<F: Ln + Atan>(z: Complex<F>) -> Complex<F> {
+    // atan(z) = reverse(atanh(reverse(z)))
+    reverse(c_atanh(reverse(z)))
+}
@@ -0,0 +1,108 @@
+use super::powi::exp_mul_int_inner;
+use super::{Exp, Ln, ln_1p_hi_lo};
+use crate::double::NormDouble;
+use crate::traits::Int as _;
+
+pub(crate) fn compound<F: Ln + Exp>(x: F, n: i64) -> F {
+    let xexp = x.raw_exp();
+
+    if x < -F::one() {
+        // compound(x, n) = NaN when x < -1
+        F::NAN
+    } else if n == 0 {
+        // compound(x, 0) = 1 when x >= -1 or NaN
+        F::one()
+    } else if xexp == F::MAX_RAW_EXP {
+        if x.raw_mant() != F::Raw::ZERO {
+            // compound(NaN, n) = NaN when n != 0
+            F::NAN
+        } else if n < 0 {
+            // compound(+inf, n) = 0 when n < 0
+            F::ZERO
+        } else {
+            // compound(+inf, n) = +inf when n > 0
+            F::INFINITY
+        }
+    } else if x == -F::one() {
+        if n < 0 {
+            // compound(-1, n) = +inf when n < 0
+            F::INFINITY
+        } else {
+            // compound(-1, n) = 0 when n > 0
+            F::ZERO
+        }
+    } else if x == F::ZERO {
+        // compound(±0, n) = 1
+        F::one()
+    } else {
+        // l = ln(1 + x), with full relative accuracy for tiny x
+        let (l_hi, l_lo) = ln_1p_hi_lo(x);
+        let l = NormDouble::with_parts(l_hi, l_lo).to_semi();
+
+        // (1 + x)^n = exp(n * ln(1 + x))
+        exp_mul_int_inner(l, n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ElementaryMath;
+    use crate::traits::Float;
+
+    fn test<F: Float + ElementaryMath>() {
+        use crate::compound;
+
+        let f = F::parse;
+
+        assert_is_nan!(compound(F::NAN, 1));
+        assert_is_nan!(compound(F::NAN, -1));
+        assert_is_nan!(compound(f("-1.5"), 0));
+        assert_is_nan!(compound(f("-1.5"), 2));
+        assert_is_nan!(compound(F::neg_infinity(), 1));
+        assert_total_eq!(compound(F::NAN, 0), F::one());
+        assert_total_eq!(compound(F::INFINITY, 0), F::one());
+        assert_total_eq!(compound(-F::one(), 0), F::one());
+        assert_total_eq!(compound(F::INFINITY, 3), F::INFINITY);
+        assert_total_eq!(compound(F::INFINITY, -3), F::ZERO);
+        assert_total_eq!(compound(-F::one(), 3), F::ZERO);
+        assert_total_eq!(compound(-F::one(), -3), F::INFINITY);
+        assert_total_eq!(compound(F::ZERO, 5), F::one());
+        assert_total_eq!(compound(-F::ZERO, -5), F::one());
+        assert_total_eq!(compound(F::one(), 3), f("8"));
+        assert_total_eq!(compound(F::one(), -2), f("0.25"));
+        assert_total_eq!(compound(f("0.5"), 2), f("2.25"));
+        assert_total_eq!(compound(f("-0.5"), 3), f("0.125"));
+        assert_total_eq!(compound(f("-0.5"), -3), f("8"));
+        assert_total_eq!(compound(f("9"), 4), f("10000"));
+        assert_total_eq!(compound(F::one(), i64::MAX), F::INFINITY);
+        assert_total_eq!(compound(F::one(), i64::MIN), F::ZERO);
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
+    #[test]
+    fn test_f32() {
+        test::<f32>();
+    }
+
+    #[test]
+    fn test_f64() {
+        test::<f64>();
+    }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
+}
@@ -0,0 +1,1392 @@
+use super::atan::atan2_normalize;
+use super::double_float::dd_exp_m1;
+use super::reduce_pi_2_large::mul_frac_2_pi_fixed;
+use super::{Atan, DoubleFloatMath, atan, cbrt, dd_add, dd_div, dd_exp, dd_from};
+use super::{dd_ln, dd_mul, dd_neg, dd_new, dd_sin_cos, dd_sqrt, dd_sub};
+use super::{is_int, is_odd_int, pow, scalbn, sqrt};
+use crate::DoubleFloat;
+use crate::big_float::{BigConst, BigFloat, MAX_WORDS};
+use crate::traits::{CastFrom as _, CastInto as _, Float, Int as _};
+
+// GENERATE: cr::consts
+const LN_2: BigConst = BigConst {
+    exp: 0,
+    words: &[
+        0xB17217F7, 0xD1CF79AB, 0xC9E3B398, 0x03F2F6AF, 0x40F34326, 0x7298B62D, 0x8A0D175B,
+        0x8BAAFA2B, 0xE7B87620, 0x6DEBAC98, 0x559552FB, 0x4AFA1B10, 0xED2EAE35, 0xC1382144,
+        0x27573B29, 0x1169B825, 0x3E96CA16, 0x224AE8C5, 0x1ACBDA11, 0x317C387E, 0xB9EA9BC3,
+        0xB136603B, 0x256FA0EC, 0x7657F74B, 0x72CE87B1, 0x9D6548CA, 0xF5DFA6BD, 0x38303248,
+        0x655FA187, 0x2F20E3A2, 0xDA2D97C5, 0x0F3FD5C6, 0x07F4CA11,
+    ],
+};
+const LN_10: BigConst = BigConst {
+    exp: 2,
+    words: &[
+        0x935D8DDD, 0xAAA8AC16, 0xEA56D62B, 0x82D30A28, 0xE28FECF9, 0xDA5DF90E, 0x83C61E82,
+        0x01F02D72, 0x962F02D7, 0xB1A8105C, 0xCC70CBC0, 0x2C5F0D68, 0x2C622418, 0x410BE2DA,
+        0xFB8F7884, 0x02E516D6, 0x782CF8A2, 0x8A8C911E, 0x765AA6C3, 0xB0D831FB, 0xEF66CEB0,
+        0x4AB3C6FA, 0x5161BB49, 0xD219C7BB, 0xCA67B35B, 0x23605085, 0x8E93368D, 0x44789C4F,
+        0x5B08B057, 0xD5EDE20F, 0x469EA58E, 0x9305E981, 0xE2478FCA,
+    ],
+};
+const LOG2_E: BigConst = BigConst {
+    exp: 1,
+    words: &[
+        0xB8AA3B29, 0x5C17F0BB, 0xBE87FED0, 0x691D3E88, 0xEB577AA8, 0xDD695A58, 0x8B25166C,
+        0xD1A13247, 0xDE1C43F7, 0x55176CD6, 0x24D92F75, 0xC16BE0B3, 0xEA90B9E6, 0x0C4A909F,
+        0xC4BFAF03, 0x53DF39B3, 0x2FE29493, 0x2617D9D5, 0xB21B43D5, 0x79D5A206, 0x0B5EBBBF,
+        0x3A828546, 0x8D1CF457, 0xAB63253C, 0x199A9483, 0x6F5B4967, 0x278CCF08, 0x4679C940,
+        0xCE7E2035, 0x8CD5DB8F, 0x612F08FB, 0xAE30A173, 0x2650B6D1,
+    ],
+};
+const LOG10_E: BigConst = BigConst {
+    exp: -1,
+    words: &[
+        0xDE5BD8A9, 0x37287195, 0x355BAAAF, 0xAD33DC32, 0x3EE34602, 0x45C9A202, 0x3A3F2D44,
+        0xF78EA53C, 0x75424EFA, 0x1402F3F2, 0x92235592, 0xC6464A15, 0x18CE3BD9, 0xFD38DCBC,
+        0x6FA2B8D2, 0xC8CDA7B3, 0x4356BD19, 0x48D06FF9, 0x40072005, 0x8C1DC4DA, 0x658B61EA,
+        0x42C84D6A, 0x50B36DED, 0x2F3739D5, 0x5430212A, 0xE68C04D4, 0x648ECC89, 0xCC51F934,
+        0xD794D64F, 0x27C968EB, 0xB3FE40C2, 0xECDB1F6C, 0xD121ADC6,
+    ],
+};
+const FRAC_PI_2: BigConst = BigConst {
+    exp: 1,
+    words: &[
+        0xC90FDAA2, 0x2168C234, 0xC4C6628B, 0x80DC1CD1, 0x29024E08, 0x8A67CC74, 0x020BBEA6,
+        0x3B139B22, 0x514A0879, 0x8E3404DD, 0xEF9519B3, 0xCD3A431B, 0x302B0A6D, 0xF25F1437,
+        0x4FE1356D, 0x6D51C245, 0xE485B576, 0x625E7EC6, 0xF44C42E9, 0xA637ED6B, 0x0BFF5CB6,
+        0xF406B7ED, 0xEE386BFB, 0x5A899FA5, 0xAE9F2411, 0x7C4B1FE6, 0x49286651, 0xECE45B3D,
+        0xC2007CB8, 0xA163BF05, 0x98DA4836, 0x1C55D39A, 0x69163FA8,
+    ],
+};
+
+/// Number of words of the first multi-precision attempt
+const ZIV_FIRST_LEN: usize = 8;
+
+pub(crate) fn cr_exp<F: DoubleFloatMath>(x: F) -> F {
+    if x >= F::exp_hi_th() {
+        // also handles x = inf
+        F::INFINITY
+    } else if x <= F::exp_lo_th() {
+        // also handles x = -inf
+        F::ZERO
+    } else if x.raw_exp() == F::MAX_RAW_EXP {
+        // x is NaN, propagate
+        x
+    } else if let Some(y) = dd_round(dd_exp(dd_from(x)), F::one()) {
+        y
+    } else {
+        ziv(|len| big_exp(&BigFloat::from_float(x, len)))
+    }
+}
+
+pub(crate) fn cr_exp_m1<F: DoubleFloatMath>(x: F) -> F {
+    if x >= F::exp_m1_hi_th() {
+        // also handles x = inf
+        F::INFINITY
+    } else if x <= F::exp_m1_lo_th() {
+        // also handles x = -inf
+        -F::one()
+    } else if x.raw_exp() == F::MAX_RAW_EXP {
+        // x is NaN, propagate
+        x
+    } else if x.exponent() < -F::Exp::cast_from(F::MANT_BITS + 2) {
+        // also handles x = ±0 and subnormals
+        // exp(x) - 1 = x + x^2 / 2 + ..., where x^2 / 2 is less than
+        // half an ULP of x
+        x
+    } else if let Some(y) = dd_round(dd_exp_m1(dd_from(x)), F::one()) {
+        y
+    } else {
+        ziv(|len| big_exp_m1(&BigFloat::from_float(x, len)))
+    }
+}
+
+pub(crate) fn cr_exp2<F: DoubleFloatMath>(x: F) -> F {
+    if x >= F::cast_from(F::MAX_EXP.into() + 1) {
+        // also handles x = inf
+        F::INFINITY
+    } else if x <= F::cast_from(F::MIN_NORMAL_EXP.into() - i32::from(F::MANT_BITS) - 1) {
+        // 2^x is less than or equal to half the smallest subnormal
+        // also handles x = -inf
+        F::ZERO
+    } else if x.raw_exp() == F::MAX_RAW_EXP {
+        // x is NaN, propagate
+        x
+    } else {
+        let ln_2 = dd_ln_2::<F>();
+        let y = dd_exp(dd_mul(dd_from(x), ln_2));
+        if let Some(y) = dd_round(y, F::one() + x.abs()) {
+            y
+        } else {
+            ziv(|len| big_exp(&BigFloat::from_float(x, len).mul_const(&LN_2)))
+        }
+    }
+}
+
+pub(crate) fn cr_exp10<F: DoubleFloatMath>(x: F) -> F {
+    // approximation of x * log2(10)
+    let t = x * (F::dd_ln_10_hi() / F::dd_ln_2_hi());
+    if t >= F::cast_from(F::MAX_EXP.into() + 2) {
+        // also handles x = inf
+        F::INFINITY
+    } else if t <= F::cast_from(F::MIN_NORMAL_EXP.into() - i32::from(F::MANT_BITS) - 2) {
+        // also handles x = -inf
+        F::ZERO
+    } else if x.raw_exp() == F::MAX_RAW_EXP {
+        // x is NaN, propagate
+        x
+    } else {
+        let z = dd_mul(dd_from(x), dd_ln_10::<F>());
+        if let Some(y) = dd_round(dd_exp(z), F::one() + z.hi.abs()) {
+            y
+        } else if x > F::ZERO && x <= F::cast_from(F::MANT_BITS / 2 + 1) && is_int(x) {
+            // 10^n = 5^n * 2^n can be exactly halfway between two values
+            // of `F` when 5^n has MANT_BITS + 2 bits, calculate it exactly
+            let n = BigFloat::from_float(x, ZIV_FIRST_LEN).round_i32();
+            let one = BigFloat::from_u64(false, 1, 0, ZIV_FIRST_LEN);
+            (0..n).fold(one, |acc, _| acc.mul_u32(10)).round_ties_even()
+        } else {
+            ziv(|len| big_exp(&BigFloat::from_float(x, len).mul_const(&LN_10)))
+        }
+    }
+}
+
+pub(crate) fn cr_sinh<F: DoubleFloatMath>(x: F) -> F {
+    let xabs = x.abs();
+    if x.raw_exp() == F::MAX_RAW_EXP {
+        // propagate infinity or NaN
+        x
+    } else if is_tiny(x) {
+        // also handles x = ±0 and subnormals
+        // sinh(x) = x + x^3 / 6 + ..., where x^3 / 6 is less than
+        // half an ULP of x
+        x
+    } else if xabs >= F::exp_hi_th() + F::one() {
+        // sinh(|x|) ~= exp(|x| - ln(2)), which overflows
+        F::INFINITY.copysign(x)
+    } else {
+        let y = if xabs > F::cast_from(F::MANT_BITS) {
+            // sinh(|x|) = exp(|x|) / 2 * (1 - exp(-2 * |x|)), where
+            // exp(-2 * |x|) is negligible
+            dd_exp(dd_sub(dd_from(xabs), dd_ln_2()))
+        } else {
+            // sinh(|x|) = (t + t / (t + 1)) / 2, with t = exp(|x|) - 1
+            let t = dd_exp_m1(dd_from(xabs));
+            let u = dd_add(t, dd_div(t, dd_add(t, dd_from(F::one()))));
+            dd_mul(u, dd_from(F::half()))
+        };
+        if let Some(y) = dd_round(y, F::two()) {
+            y.copysign(x)
+        } else {
+            let y: F = ziv(|len| {
+                let t = big_exp_m1(&BigFloat::from_float(xabs, len));
+                t.add(&t.div(&t.add_i32(1))).scale(-1)
+            });
+            y.copysign(x)
+        }
+    }
+}
+
+pub(crate) fn cr_cosh<F: DoubleFloatMath>(x: F) -> F {
+    let xabs = x.abs();
+    if x.raw_exp() == F::MAX_RAW_EXP {
+        // cosh(±inf) = inf, propagate NaN
+        xabs
+    } else if is_tiny(x) {
+        // also handles x = ±0 and subnormals
+        // cosh(x) = 1 + x^2 / 2 + ..., where x^2 / 2 is less than
+        // half an ULP of 1
+        F::one()
+    } else if xabs >= F::exp_hi_th() + F::one() {
+        // cosh(|x|) ~= exp(|x| - ln(2)), which overflows
+        F::INFINITY
+    } else {
+        let y = if xabs > F::cast_from(F::MANT_BITS) {
+            // cosh(|x|) = exp(|x|) / 2 * (1 + exp(-2 * |x|)), where
+            // exp(-2 * |x|) is negligible
+            dd_exp(dd_sub(dd_from(xabs), dd_ln_2()))
+        } else {
+            // cosh(|x|) = (e + 1 / e) / 2, with e = exp(|x|)
+            let e = dd_exp(dd_from(xabs));
+            let u = dd_add(e, dd_div(dd_from(F::one()), e));
+            dd_mul(u, dd_from(F::half()))
+        };
+        if let Some(y) = dd_round(y, F::two()) {
+            y
+        } else {
+            ziv(|len| {
+                let e = big_exp(&BigFloat::from_float(xabs, len));
+                e.add(&e.recip()).scale(-1)
+            })
+        }
+    }
+}
+
+pub(crate) fn cr_tanh<F: DoubleFloatMath>(x: F) -> F {
+    let xabs = x.abs();
+    // (MANT_BITS + 4) * ln(2) / 2
+    let one_th = F::cast_from(F::MANT_BITS + 4) * F::dd_ln_2_hi() * F::half();
+    if x.raw_exp() == F::MAX_RAW_EXP {
+        if x.raw_mant() == F::Raw::ZERO {
+            // tanh(±inf) = ±1
+            F::one().copysign(x)
+        } else {
+            // propagate NaN
+            x
+        }
+    } else if is_tiny(x) {
+        // also handles x = ±0 and subnormals
+        // tanh(x) = x - x^3 / 3 + ..., where x^3 / 3 is less than
+        // half an ULP of x
+        x
+    } else if xabs > one_th {
+        // 1 - tanh(|x|) < 2 * exp(-2 * |x|), which is less than
+        // half an ULP of 1
+        F::one().copysign(x)
+    } else {
+        // tanh(|x|) = t / (t + 2), with t = exp(2 * |x|) - 1
+        let t = dd_exp_m1(dd_from(xabs + xabs));
+        let y = dd_div(t, dd_add(t, dd_from(F::two())));
+        if let Some(y) = dd_round(y, F::two()) {
+            y.copysign(x)
+        } else {
+            let y: F = ziv(|len| {
+                let t = big_exp_m1(&BigFloat::from_float(xabs, len).scale(1));
+                t.div(&t.add_i32(2))
+            });
+            y.copysign(x)
+        }
+    }
+}
+
+pub(crate) fn cr_ln<F: DoubleFloatMath>(x: F) -> F {
+    cr_log_common(x, |y| y, |y| y)
+}
+
+pub(crate) fn cr_log2<F: DoubleFloatMath>(x: F) -> F {
+    cr_log_common(x, |y| dd_div(y, dd_ln_2()), |y| y.mul_const(&LOG2_E))
+}
+
+pub(crate) fn cr_log10<F: DoubleFloatMath>(x: F) -> F {
+    cr_log_common(x, |y| dd_div(y, dd_ln_10()), |y| y.mul_const(&LOG10_E))
+}
+
+/// Calculates a logarithm of `x`, where `dd_scale` and `big_scale` convert
+/// the natural logarithm to the desired base
+fn cr_log_common<F: DoubleFloatMath>(
+    x: F,
+    dd_scale: impl Fn(DoubleFloat<F>) -> DoubleFloat<F>,
+    big_scale: impl Fn(BigFloat) -> BigFloat,
+) -> F {
+    let e = x.raw_exp();
+    if e == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO {
+        // log(±0) = -inf
+        F::neg_infinity()
+    } else if x.sign() {
+        // x < 0, log(x) = NaN
+        F::NAN
+    } else if e == F::MAX_RAW_EXP {
+        // propagate infinity or NaN
+        x
+    } else if x == F::one() {
+        // log(1) = 0
+        F::ZERO
+    } else if let Some(y) = dd_round(dd_scale(dd_ln(dd_from(x))), F::one()) {
+        y
+    } else {
+        ziv(|len| big_scale(big_ln(&BigFloat::from_float(x, len))))
+    }
+}
+
+pub(crate) fn cr_ln_1p<F: DoubleFloatMath>(x: F) -> F {
+    if x == -F::one() {
+        // ln(1 + -1) = -inf
+        F::neg_infinity()
+    } else if x < -F::one() {
+        // x < -1, ln(1 + x) = NaN
+        // also handles x = -inf
+        F::NAN
+    } else if x.raw_exp() == F::MAX_RAW_EXP {
+        // propagate infinity or NaN
+        x
+    } else if x.exponent() < -F::Exp::cast_from(F::MANT_BITS + 2) {
+        // also handles x = ±0 and subnormals
+        // ln(1 + x) = x - x^2 / 2 + ..., where x^2 / 2 is less than
+        // half an ULP of x
+        x
+    } else if let Some(y) = dd_round(dd_ln(dd_new(F::one(), x)), F::one()) {
+        y
+    } else {
+        ziv(|len| big_ln_1p(&BigFloat::from_float(x, len)))
+    }
+}
+
+pub(crate) fn cr_sin<F: DoubleFloatMath>(x: F) -> F {
+    if x.raw_exp() == F::MAX_RAW_EXP {
+        // sin(inf or nan) = nan
+        F::NAN
+    } else if is_tiny(x) {
+        // also handles x = ±0 and subnormals
+        // sin(x) = x - x^3 / 6 + ..., where x^3 / 6 is less than
+        // half an ULP of x
+        x
+    } else if let Some(y) = dd_sin_cos_round(x, |(s, _)| s) {
+        y
+    } else {
+        ziv(|len| {
+            let (n, s, c) = big_sin_cos(x, len);
+            match n {
+                0 => s,
+                1 => c,
+                2 => -s,
+                3 => -c,
+                _ => unreachable!(),
+            }
+        })
+    }
+}
+
+pub(crate) fn cr_cos<F: DoubleFloatMath>(x: F) -> F {
+    if x.raw_exp() == F::MAX_RAW_EXP {
+        // cos(inf or nan) = nan
+        F::NAN
+    } else if is_tiny(x) {
+        // also handles x = ±0 and subnormals
+        // cos(x) = 1 - x^2 / 2 + ..., where x^2 / 2 is less than
+        // half an ULP of 1
+        F::one()
+    } else if let Some(y) = dd_sin_cos_round(x, |(_, c)| c) {
+        y
+    } else {
+        ziv(|len| {
+            let (n, s, c) = big_sin_cos(x, len);
+            match n {
+                0 => c,
+                1 => -s,
+                2 => -c,
+                3 => s,
+                _ => unreachable!(),
+            }
+        })
+    }
+}
+
+pub(crate) fn cr_tan<F: DoubleFloatMath>(x: F) -> F {
+    if x.raw_exp() == F::MAX_RAW_EXP {
+        // tan(inf or nan) = nan
+        F::NAN
+    } else if is_tiny(x) {
+        // also handles x = ±0 and subnormals
+        // tan(x) = x + x^3 / 3 + ..., where x^3 / 3 is less than
+        // half an ULP of x
+        x
+    } else if let Some(y) = dd_sin_cos_round(x, |(s, c)| dd_div(s, c)) {
+        y
+    } else {
+        ziv(|len| {
+            let (n, s, c) = big_sin_cos(x, len);
+            if (n & 1) == 0 { s.div(&c) } else { -c.div(&s) }
+        })
+    }
+}
+
+pub(crate) fn cr_atan<F: DoubleFloatMath + Atan>(x: F) -> F {
+    if x.raw_exp() == F::MAX_RAW_EXP {
+        if x.raw_mant() == F::Raw::ZERO {
+            // atan(±inf) = ±π/2
+            F::FRAC_PI_2.copysign(x)
+        } else {
+            // propagate NaN
+            x
+        }
+    } else if is_tiny(x) {
+        // also handles x = ±0 and subnormals
+        // atan(x) = x - x^3 / 3 + ..., where x^3 / 3 is less than
+        // half an ULP of x
+        x
+    } else if let Some(y) = dd_round(dd_atan_div(dd_from(x), dd_from(F::one())), F::two()) {
+        y
+    } else {
+        ziv(|len| big_atan(&BigFloat::from_float(x, len)))
+    }
+}
+
+pub(crate) fn cr_asin<F: DoubleFloatMath + Atan>(x: F) -> F {
+    let xabs = x.abs();
+    if x.raw_exp() == F::MAX_RAW_EXP || xabs > F::one() {
+        // x is NaN, ±inf or |x| > 1
+        F::NAN
+    } else if xabs == F::one() {
+        // asin(±1) = ±π/2
+        F::FRAC_PI_2.copysign(x)
+    } else if is_tiny(x) {
+        // also handles x = ±0 and subnormals
+        // asin(x) = x + x^3 / 6 + ..., where x^3 / 6 is less than
+        // half an ULP of x
+        x
+    } else {
+        // asin(x) = atan(x / sqrt((1 - x) * (1 + x))), where 1 - x and
+        // 1 + x are exact
+        let d = dd_mul(dd_new(F::one(), -x), dd_new(F::one(), x));
+        if let Some(y) = dd_round(dd_atan_div(dd_from(x), dd_sqrt(d)), F::two()) {
+            y
+        } else {
+            ziv(|len| {
+                let bx = BigFloat::from_float(x, len);
+                let d = bx.add_i32(1).mul(&(-bx).add_i32(1));
+                big_atan(&bx.div(&d.sqrt()))
+            })
+        }
+    }
+}
+
+pub(crate) fn cr_acos<F: DoubleFloatMath + Atan>(x: F) -> F {
+    if x.raw_exp() == F::MAX_RAW_EXP || x.abs() > F::one() {
+        // x is NaN, ±inf or |x| > 1
+        F::NAN
+    } else if x == F::one() {
+        // acos(1) = 0
+        F::ZERO
+    } else if x == -F::one() {
+        // acos(-1) = π
+        F::PI
+    } else {
+        // acos(x) = 2 * atan(sqrt(1 - x) / sqrt(1 + x)), where 1 - x and
+        // 1 + x are exact
+        let n = dd_sqrt(dd_new(F::one(), -x));
+        let d = dd_sqrt(dd_new(F::one(), x));
+        let y = dd_atan_div(n, d);
+        if let Some(y) = dd_round(dd_add(y, y), F::two()) {
+            y
+        } else {
+            ziv(|len| {
+                let bx = BigFloat::from_float(x, len);
+                let t = (-bx).add_i32(1).div(&bx.add_i32(1)).sqrt();
+                big_atan(&t).scale(1)
+            })
+        }
+    }
+}
+
+pub(crate) fn cr_atan2<F: DoubleFloatMath + Atan>(y: F, x: F) -> F {
+    let (ny, nx) = atan2_normalize(y, x);
+
+    let nxexp = nx.raw_exp();
+    let nyexp = ny.raw_exp();
+    if (nxexp == F::MAX_RAW_EXP && nx.raw_mant() != F::Raw::ZERO)
+        || (nyexp == F::MAX_RAW_EXP && ny.raw_mant() != F::Raw::ZERO)
+    {
+        // x and/or y is NaN
+        F::NAN
+    } else if nxexp == F::MAX_RAW_EXP && nyexp == F::MAX_RAW_EXP {
+        // x = ±inf, y = ±inf
+        match (nx.sign(), ny.sign()) {
+            (false, false) => F::FRAC_PI_4,
+            (false, true) => -F::FRAC_PI_4,
+            (true, false) => F::frac_3pi_4(),
+            (true, true) => -F::frac_3pi_4(),
+        }
+    } else if nxexp == F::MAX_RAW_EXP {
+        // x = ±inf
+        if nx.sign() {
+            F::PI.copysign(ny)
+        } else {
+            F::ZERO.copysign(ny)
+        }
+    } else if nyexp == F::MAX_RAW_EXP {
+        // y = ±inf
+        F::FRAC_PI_2.copysign(ny)
+    } else if nyexp == F::RawExp::ZERO {
+        // y = ±0
+        if nx.sign() { F::PI.copysign(ny) } else { ny }
+    } else if nxexp == F::RawExp::ZERO {
+        // x = ±0
+        F::FRAC_PI_2.copysign(ny)
+    } else if !nx.sign() && nxexp > nyexp && (nxexp - nyexp) >= F::RawExp::from(F::MANT_BITS + 5) {
+        // y/x is very small
+        // atan2(y, x) = y/x - (y/x)^3 / 3 + ..., where (y/x)^3 / 3 only
+        // affects the rounding when y/x is exactly halfway between two
+        // values of `F`, which must then be rounded toward zero
+        let q = ny / nx;
+        let qabs = q.abs();
+        if qabs != F::ZERO {
+            let len = ZIV_FIRST_LEN;
+            let [_, below] = midpoints(qabs, len);
+            let big_y = BigFloat::from_float(ny.abs(), len);
+            if below
+                .mul(&BigFloat::from_float(nx, len))
+                .sub(&big_y)
+                .is_zero()
+            {
+                return F::from_raw(qabs.to_raw() - F::Raw::ONE).copysign(q);
+            }
+        }
+        q
+    } else {
+        let mut r = dd_atan_div(dd_from(ny), dd_from(nx));
+        if nx.sign() {
+            // atan2(y, x) = atan(y / x) ± π
+            let pi = dd_mul(dd_frac_pi_2(), dd_from(F::two()));
+            r = if ny.sign() {
+                dd_sub(r, pi)
+            } else {
+                dd_add(r, pi)
+            };
+        }
+        if let Some(r) = dd_round(r, F::two()) {
+            r
+        } else {
+            ziv(|len| {
+                let big_x = BigFloat::from_float(nx, len);
+                let r = big_atan(&BigFloat::from_float(ny, len).div(&big_x));
+                if nx.sign() {
+                    let pi = BigFloat::from_const(&FRAC_PI_2, len).scale(1);
+                    if ny.sign() { r.sub(&pi) } else { r.add(&pi) }
+                } else {
+                    r
+                }
+            })
+        }
+    }
+}
+
+pub(crate) fn cr_pow<F: DoubleFloatMath>(x: F, y: F) -> F {
+    let is_special = |v: F| {
+        let e = v.raw_exp();
+        e == F::MAX_RAW_EXP || (e == F::RawExp::ZERO && v.raw_mant() == F::Raw::ZERO)
+    };
+    if is_special(x) || is_special(y) || x == F::one() || (x.sign() && !is_int(y)) {
+        // zero, infinity or NaN in either argument, x = 1 or
+        // negative x with a non-integer y, the result is exact
+        return pow(x, y);
+    }
+
+    // |x|^y = exp(y * ln(|x|))
+    let neg = x.sign() && is_odd_int(y);
+    let xabs = x.abs();
+    let z = dd_mul(dd_ln(dd_from(xabs)), dd_from(y));
+    let absr = if z.hi >= F::exp_hi_th() {
+        F::INFINITY
+    } else if z.hi <= F::exp_lo_th() {
+        F::ZERO
+    } else if let Some(r) = dd_round(dd_exp(z), F::one() + z.hi.abs()) {
+        r
+    } else if let Some(r) = pow_exact(xabs, y) {
+        r.round_ties_even()
+    } else {
+        ziv(|len| {
+            let lnx = big_ln(&BigFloat::from_float(xabs, len));
+            big_exp(&lnx.mul(&BigFloat::from_float(y, len)))
+        })
+    };
+
+    if neg { -absr } else { absr }
+}
+
+/// Calculates `x^y` exactly when it has at most `MANT_BITS + 2` significant
+/// bits, which includes the results that are exactly halfway between two
+/// values of `F`
+///
+/// `x` must be positive, finite and not one, and `y` finite and not zero.
+/// `x^y` must be within the range where [`big_exp`] can be used.
+fn pow_exact<F: Float>(x: F, y: F) -> Option<BigFloat> {
+    let len = ZIV_FIRST_LEN;
+    // x = xm * 2^xe and y = ym * 2^ye, with odd xm and ym
+    let (xm, xe) = split_odd(x);
+    let (_, ye) = split_odd(y);
+
+    if xm == F::Raw::ONE {
+        // x^y = 2^(xe * y), which is exact when xe * y is an integer
+        if ye < 0 && (ye <= -31 || xe % (1 << -ye) != 0) {
+            return None;
+        }
+        let e = BigFloat::from_u64(xe < 0, xe.unsigned_abs().into(), 0, len);
+        let k = BigFloat::from_float(y, len).mul(&e);
+        return Some(BigFloat::from_u64(false, 1, k.round_i32(), len));
+    }
+
+    // With xm >= 3, x^y is not a dyadic rational when y is negative
+    // or when y = ym / 2^k and xm is not the 2^k-th power of an integer.
+    // xm < 2^(MANT_BITS + 1) < 3^(2^7), so k <= 6.
+    let k = (-ye).max(0);
+    if y.sign() || k > 6 || xe % (1 << k) != 0 {
+        return None;
+    }
+    let mut b = scalbn(x, -xe);
+    for _ in 0..k {
+        let r = sqrt(b);
+        let big_r = BigFloat::from_float(r, len);
+        if !big_r
+            .mul(&big_r)
+            .sub(&BigFloat::from_float(b, len))
+            .is_zero()
+        {
+            return None;
+        }
+        b = r;
+    }
+
+    // x^y = b^ym * 2^(xe / 2^k * ym), where b^ym must fit in
+    // MANT_BITS + 2 bits (and b >= 3)
+    let max_bits = i32::from(F::MANT_BITS) + 2;
+    let ym = BigFloat::from_float(y, len).scale(k);
+    if ym.exponent() > 8 {
+        return None;
+    }
+    let ym = ym.round_i32();
+    let big_b = BigFloat::from_float(b, len);
+    let mut r = big_b;
+    for _ in 1..ym {
+        // exact, both factors have at most MANT_BITS + 2 bits
+        r = r.mul(&big_b);
+        if r.exponent() > max_bits {
+            return None;
+        }
+    }
+    Some(r.scale((xe >> k) * ym))
+}
+
+/// Returns `(m, e)` such as `|x| = m * 2^e` and `m` is odd
+///
+/// `x` must be finite and not zero.
+fn split_odd<F: Float>(x: F) -> (F::Raw, i32) {
+    let mant_bits = i32::from(F::MANT_BITS);
+    let (mut m, mut e) = if x.raw_exp() == F::RawExp::ZERO {
+        (x.raw_mant(), F::MIN_NORMAL_EXP.into() - mant_bits)
+    } else {
+        (x.mant(), x.exponent().into() - mant_bits)
+    };
+    while (m & F::Raw::ONE) == F::Raw::ZERO {
+        m >>= 1u8;
+        e += 1;
+    }
+    (m, e)
+}
+
+pub(crate) fn cr_cbrt<F: DoubleFloatMath>(x: F) -> F {
+    let e = x.raw_exp();
+    if e == F::MAX_RAW_EXP || (e == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO) {
+        // propagate infinity, NaN or zero
+        return x;
+    }
+
+    // x = m * 2^(3 * k), with 1 <= |m| < 8
+    let (x, edelta) = x.normalize_arg();
+    let e = x.exponent().into() + edelta.into();
+    let r = e.rem_euclid(3);
+    let k = (e - r) / 3;
+    let m = x.set_exp(F::Exp::cast_from(r as u8));
+
+    // Newton iteration from y0 ~= cbrt(m), which has an error of a
+    // few ULP
+    // y = y0 - (y0^3 - m) / (3 * y0^2)
+    let three = F::cast_from(3u8);
+    let y0 = pow(m.abs(), F::one() / three).copysign(m);
+    let y0_2 = dd_mul(dd_from(y0), dd_from(y0));
+    let num = dd_sub(dd_mul(y0_2, dd_from(y0)), dd_from(m));
+    let den = dd_mul(y0_2, dd_from(three));
+    let y = dd_sub(dd_from(y0), dd_div(num, den));
+
+    let y = if let Some(y) = dd_round(y, F::two()) {
+        y
+    } else {
+        ziv(|len| {
+            let r = big_cbrt(&BigFloat::from_float(m.abs(), len));
+            if m.sign() { -r } else { r }
+        })
+    };
+    // cbrt(x) is always normal, so scaling it is exact
+    scalbn(y, k)
+}
+
+pub(crate) fn cr_hypot<F: DoubleFloatMath>(x: F, y: F) -> F {
+    let xexp = x.raw_exp();
+    let yexp = y.raw_exp();
+    if xexp == F::MAX_RAW_EXP || yexp == F::MAX_RAW_EXP {
+        if xexp == F::MAX_RAW_EXP && x.raw_mant() == F::Raw::ZERO
+            || yexp == F::MAX_RAW_EXP && y.raw_mant() == F::Raw::ZERO
+        {
+            // x or y is inf
+            return F::INFINITY;
+        } else {
+            // x or y is NaN
+            return F::NAN;
+        }
+    }
+
+    // min = min(|x|, |y|)
+    // max = max(|x|, |y|)
+    let absx = x.abs();
+    let absy = y.abs();
+    let (min, max) = if absx < absy {
+        (absx, absy)
+    } else {
+        (absy, absx)
+    };
+    if min == F::ZERO {
+        // also handles x = y = 0
+        return max;
+    }
+
+    // max = smax * 2^e, with 1 <= smax < 2
+    let (nmax, max_edelta) = max.normalize_arg();
+    let (nmin, min_edelta) = min.normalize_arg();
+    let e = nmax.exponent().into() + max_edelta.into();
+    let ediff = e - (nmin.exponent().into() + min_edelta.into());
+    if ediff > i32::from(F::MANT_BITS / 2 + 2) {
+        // hypot(x, y) = max * sqrt(1 + (min / max)^2), where
+        // (min / max)^2 / 2 is less than half an ULP of 1
+        return max;
+    }
+    let smax = nmax.set_exp(F::Exp::ZERO);
+    let smin = scalbn(min, -e);
+
+    // hypot(x, y) = sqrt(smax^2 + smin^2) * 2^e
+    let s = dd_add(
+        dd_mul(dd_from(smax), dd_from(smax)),
+        dd_mul(dd_from(smin), dd_from(smin)),
+    );
+    let h = dd_sqrt(s);
+    if e >= F::MIN_NORMAL_EXP.into() {
+        // the result is normal, so scaling it is exact
+        if let Some(r) = dd_round(h, F::two()) {
+            return scalbn(r, e);
+        }
+    }
+
+    // hypot(x, y) can be exactly halfway between two values of `F`
+    let sum_sq = |len| {
+        let big_min = BigFloat::from_float(min, len);
+        let big_max = BigFloat::from_float(max, len);
+        big_min.mul(&big_min).add(&big_max.mul(&big_max))
+    };
+    let len = MAX_WORDS / 2;
+    let approx = scalbn(h.hi, e);
+    if approx.raw_exp() != F::MAX_RAW_EXP {
+        let exact_sum = sum_sq(len);
+        for m in midpoints(approx, len) {
+            if m.mul(&m).sub(&exact_sum).is_zero() {
+                return m.round_ties_even();
+            }
+        }
+    }
+
+    ziv(|len| sum_sq(len).sqrt())
+}
+
+/// Returns the values halfway between `x` and its neighbors, as
+/// `[above, below]`
+///
+/// `x` must be positive and finite. The result is exact with at least
+/// `(MANT_BITS + 3) / 32 + 1` words.
+fn midpoints<F: Float>(x: F, len: usize) -> [BigFloat; 2] {
+    let mant_bits = i32::from(F::MANT_BITS);
+    let (ulp_exp, below_ulp_exp) = if x.raw_exp() == F::RawExp::ZERO {
+        let e = F::MIN_NORMAL_EXP.into() - mant_bits;
+        (e, e)
+    } else {
+        let e = x.exponent().into() - mant_bits;
+        // The ULP below a power of two is halved, except for the
+        // smallest normal
+        if x.raw_mant() == F::Raw::ZERO && x.raw_exp() != F::RawExp::ONE {
+            (e, e - 1)
+        } else {
+            (e, e)
+        }
+    };
+    let big_x = BigFloat::from_float(x, len);
+    [
+        big_x.add(&BigFloat::from_u64(false, 1, ulp_exp - 1, len)),
+        big_x.sub(&BigFloat::from_u64(false, 1, below_ulp_exp - 1, len)),
+    ]
+}
+
+/// Returns whether `|x| < 2^-(MANT_BITS / 2 + 1)`, where odd functions
+/// with a Taylor series `x + a * x^3 + ...` and `|a| <= 1/2` (such as `sin`,
+/// `tan`, `atan` or `sinh`) round to `x`, and `cos(x)` and `cosh(x)` round
+/// to 1
+///
+/// `x` must be finite.
+#[inline]
+fn is_tiny<F: Float>(x: F) -> bool {
+    x.exponent() < -F::Exp::cast_from(F::MANT_BITS / 2 + 1)
+}
+
+/// Calculates `sin(x)` and `cos(x)` with double-float arithmetic, passing
+/// them to `f` and rounding its result
+fn dd_sin_cos_round<F: DoubleFloatMath>(
+    x: F,
+    f: impl FnOnce((DoubleFloat<F>, DoubleFloat<F>)) -> DoubleFloat<F>,
+) -> Option<F> {
+    let (s, c) = dd_sin_cos(dd_from(x));
+    // When the argument is reduced, results close to zero can have
+    // a larger relative error
+    let th = F::exp2i_fast((-F::Exp::cast_from(16)).max(F::MIN_NORMAL_EXP));
+    if x.abs() <= F::FRAC_PI_4 || (s.hi.abs() >= th && c.hi.abs() >= th) {
+        dd_round(f((s, c)), F::two())
+    } else {
+        None
+    }
+}
+
+fn dd_ln_2<F: DoubleFloatMath>() -> DoubleFloat<F> {
+    let hi = dd_new(F::dd_ln_2_hi(), F::dd_ln_2_mi());
+    dd_add(hi, dd_from(F::dd_ln_2_lo()))
+}
+
+fn dd_ln_10<F: DoubleFloatMath>() -> DoubleFloat<F> {
+    dd_new(F::dd_ln_10_hi(), F::dd_ln_10_lo())
+}
+
+fn dd_frac_pi_2<F: DoubleFloatMath>() -> DoubleFloat<F> {
+    let hi = dd_new(F::frac_pi_2_hi(), F::frac_pi_2_mi());
+    dd_add(hi, dd_from(F::frac_pi_2_miex()))
+}
+
+/// Calculates `atan(n / d)` with double-float arithmetic
+///
+/// `n` and `d` must be finite and at least one of them not zero.
+fn dd_atan_div<F: DoubleFloatMath + Atan>(n: DoubleFloat<F>, d: DoubleFloat<F>) -> DoubleFloat<F> {
+    if n.hi.abs() > d.hi.abs() {
+        // atan(n / d) = ±π/2 - atan(d / n)
+        let t = dd_atan_small(dd_div(d, n));
+        let frac_pi_2 = dd_frac_pi_2();
+        if n.hi.sign() == d.hi.sign() {
+            dd_sub(frac_pi_2, t)
+        } else {
+            dd_sub(dd_neg(frac_pi_2), t)
+        }
+    } else {
+        dd_atan_small(dd_div(n, d))
+    }
+}
+
+/// Calculates `atan(x)` with double-float arithmetic and `|x| <= 1`
+fn dd_atan_small<F: DoubleFloatMath + Atan>(x: DoubleFloat<F>) -> DoubleFloat<F> {
+    // Newton iteration of sin(y) - x * cos(y) = 0 from y0 ~= atan(x)
+    // y = y0 - cos(y0) * (sin(y0) - x * cos(y0))
+    let y0 = atan(x.hi);
+    let (s, c) = dd_sin_cos(dd_from(y0));
+    let f = dd_sub(s, dd_mul(x, c));
+    dd_sub(dd_from(y0), dd_mul(c, f))
+}
+
+/// Rounds `y` to `F` if the error of `y`, which is assumed to be less than
+/// `|y| * scale * 2^-(MANT_BITS * 2 - 6)`, cannot affect the rounding
+fn dd_round<F: Float>(y: DoubleFloat<F>, scale: F) -> Option<F> {
+    let hi = y.hi;
+    if hi.raw_exp() == F::MAX_RAW_EXP
+        || hi.exponent() < F::MIN_NORMAL_EXP + F::Exp::cast_from(F::MANT_BITS * 2 + 8)
+    {
+        // overflow or close to the subnormal range, where `lo` and
+        // the error bound lose precision
+        return None;
+    }
+
+    let err = hi.abs() * scale * F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS * 2 - 6));
+    let up = (hi + (y.lo + err)).purify();
+    let down = (hi + (y.lo - err)).purify();
+    if up == hi && down == hi {
+        Some(hi)
+    } else {
+        None
+    }
+}
+
+/// Calculates a function with multi-precision arithmetic, doubling the
+/// precision until the rounding can be decided
+///
+/// The result is never exactly halfway between two values of `F` (such
+/// cases must be handled before), so the rounding is always decided with
+/// enough precision. `MAX_WORDS` is expected to be above the precision
+/// needed by the worst cases of every function and type (see the
+/// [`cr`](crate::cr) module). If the rounding is still not decided with
+/// `MAX_WORDS`, the result is within `2^-960` of a midpoint, so it is
+/// rounded as if it were halfway instead of panicking.
+fn ziv<F: Float>(f: impl Fn(usize) -> BigFloat) -> F {
+    let mut len = ZIV_FIRST_LEN;
+    loop {
+        let y = f(len);
+        if len == MAX_WORDS {
+            return y.round_ties_even();
+        }
+        if let Some(y) = y.round() {
+            return y;
+        }
+        len = (len * 2).min(MAX_WORDS);
+    }
+}
+
+/// Calculates `exp(x)`
+///
+/// `|x|` must be small enough to keep the result exponent within `i32`.
+fn big_exp(x: &BigFloat) -> BigFloat {
+    let len = x.len();
+
+    // exp(x) = 2^k * exp(r)
+    //  - k is an integer
+    //  - r = x - k * ln(2)
+    let k = x.mul_const(&LOG2_E).round_i32();
+    let mut k_ln_2 = BigFloat::from_const(&LN_2, len).mul_u32(k.unsigned_abs());
+    if k < 0 {
+        k_ln_2 = -k_ln_2;
+    }
+    let r = x.sub(&k_ln_2);
+
+    big_exp_m1_small(&r).add_i32(1).scale(k)
+}
+
+/// Calculates `exp(x) - 1`
+fn big_exp_m1(x: &BigFloat) -> BigFloat {
+    if x.exponent() <= 0 {
+        // |x| < 1
+        big_exp_m1_small(x)
+    } else {
+        big_exp(x).add_i32(-1)
+    }
+}
+
+/// Calculates `exp(x) - 1` with `|x| < 1`
+fn big_exp_m1_small(x: &BigFloat) -> BigFloat {
+    // Reduce to r = x / 2^s with |r| < 2^-8
+    let s = x.exponent().saturating_add(8).clamp(0, 8);
+    let r = x.scale(-s);
+
+    // exp(r) - 1 = r + r^2 / 2! + r^3 / 3! + ...
+    let mut sum = r;
+    let mut term = r;
+    let mut n = 1;
+    loop {
+        n += 1;
+        term = term.mul(&r).div_u32(n);
+        if term.is_negligible(&sum) {
+            break;
+        }
+        sum = sum.add(&term);
+    }
+
+    // exp(2 * r) - 1 = (exp(r) - 1) * (exp(r) - 1 + 2)
+    for _ in 0..s {
+        sum = sum.mul(&sum.add_i32(2));
+    }
+    sum
+}
+
+/// Calculates `ln(x)` with `x > 0`
+fn big_ln(x: &BigFloat) -> BigFloat {
+    // x = f * 2^e, with sqrt(2)/2 <= f < sqrt(2)
+    let xe = x.exponent();
+    let (f, e) = if x.top_word() >= 0xB504_F334 {
+        (x.scale(-xe), xe)
+    } else {
+        (x.scale(1 - xe), xe - 1)
+    };
+
+    // ln(f) = 2 * atanh((f - 1) / (f + 1))
+    let s = f.add_i32(-1).div(&f.add_i32(1));
+    let ln_f = big_atanh_series(&s).scale(1);
+
+    let mut e_ln_2 = BigFloat::from_const(&LN_2, x.len()).mul_u32(e.unsigned_abs());
+    if e < 0 {
+        e_ln_2 = -e_ln_2;
+    }
+    ln_f.add(&e_ln_2)
+}
+
+/// Calculates `ln(1 + x)` with `x > -1`
+fn big_ln_1p(x: &BigFloat) -> BigFloat {
+    let xe = x.exponent();
+    if (!x.is_neg() && xe <= -1) || (x.is_neg() && xe <= -2) {
+        // -0.25 < x < 0.5
+        // ln(1 + x) = 2 * atanh(x / (2 + x))
+        let s = x.div(&x.add_i32(2));
+        big_atanh_series(&s).scale(1)
+    } else {
+        big_ln(&x.add_i32(1))
+    }
+}
+
+/// Calculates `atanh(x)` with `|x| <= 0.25`
+fn big_atanh_series(x: &BigFloat) -> BigFloat {
+    // atanh(x) = x + x^3 / 3 + x^5 / 5 + ...
+    let x2 = x.mul(x);
+    let mut sum = *x;
+    let mut pow = *x;
+    let mut n = 1;
+    loop {
+        n += 2;
+        pow = pow.mul(&x2);
+        let term = pow.div_u32(n);
+        if term.is_negligible(&sum) {
+            break;
+        }
+        sum = sum.add(&term);
+    }
+    sum
+}
+
+/// Calculates `atan(x)`
+fn big_atan(x: &BigFloat) -> BigFloat {
+    if x.exponent() > 1 {
+        // |x| >= 2, atan(x) = ±π/2 - atan(1 / x)
+        let frac_pi_2 = BigFloat::from_const(&FRAC_PI_2, x.len());
+        let t = big_atan(&x.recip());
+        return if x.is_neg() {
+            (-frac_pi_2).sub(&t)
+        } else {
+            frac_pi_2.sub(&t)
+        };
+    }
+
+    // Reduce to r with |r| < 2^-8 and atan(x) = atan(r) * 2^s, using
+    // atan(x) = 2 * atan(x / (1 + sqrt(1 + x^2)))
+    let mut r = *x;
+    let mut s = 0;
+    while r.exponent() > -8 && !r.is_zero() {
+        r = r.div(&r.mul(&r).add_i32(1).sqrt().add_i32(1));
+        s += 1;
+    }
+
+    // atan(r) = r - r^3 / 3 + r^5 / 5 - ...
+    let r2 = r.mul(&r);
+    let mut sum = r;
+    let mut pow = r;
+    let mut n = 1;
+    loop {
+        n += 2;
+        pow = -pow.mul(&r2);
+        let term = pow.div_u32(n);
+        if term.is_negligible(&sum) {
+            break;
+        }
+        sum = sum.add(&term);
+    }
+    sum.scale(s)
+}
+
+/// Calculates `cbrt(x)` with `x > 0`
+fn big_cbrt(x: &BigFloat) -> BigFloat {
+    let len = x.len();
+    // x = d * 2^(3 * k), with d in [0.125, 1)
+    let k = (x.exponent() + 2).div_euclid(3);
+    let d = x.scale(-3 * k);
+
+    // Initial approximation of 1 / cbrt(d) with about 50 bits
+    let r0 = 1.0 / cbrt(d.round_ties_even::<f64>());
+    let mut r = BigFloat::from_float(r0, len);
+
+    // r = r + r * (1 - d * r^3) / 3
+    let mut bits = 50;
+    while bits < 32 * len + 8 {
+        let e = d.mul(&r.mul(&r).mul(&r)).add_i32(-1);
+        r = r.sub(&r.mul(&e).div_u32(3));
+        bits *= 2;
+    }
+
+    // cbrt(d) = d * r^2
+    d.mul(&r.mul(&r)).scale(k)
+}
+
+/// Calculates `(n, sin(y), cos(y))`, such as `x = 2*π*M + π/2*n + y`
+/// (`M` is an integer and `0 <= n <= 3`)
+fn big_sin_cos<F: DoubleFloatMath>(x: F, len: usize) -> (u8, BigFloat, BigFloat) {
+    let (n, y) = big_reduce_pi_2(x, len);
+    let y2 = y.mul(&y);
+
+    // sin(y) = y - y^3 / 3! + y^5 / 5! - ...
+    let mut sin = y;
+    let mut term = y;
+    let mut k = 1;
+    loop {
+        term = -term.mul(&y2).div_u32((k + 1) * (k + 2));
+        k += 2;
+        if term.is_negligible(&sin) {
+            break;
+        }
+        sin = sin.add(&term);
+    }
+
+    // cos(y) = 1 - y^2 / 2! + y^4 / 4! - ...
+    let mut cos = BigFloat::from_u64(false, 1, 0, len);
+    let mut term = cos;
+    let mut k = 0;
+    loop {
+        term = -term.mul(&y2).div_u32((k + 1) * (k + 2));
+        k += 2;
+        if term.is_negligible(&cos) {
+            break;
+        }
+        cos = cos.add(&term);
+    }
+
+    (n, sin, cos)
+}
+
+/// Reduces `x` to `y` with `|y| <= π/4`
+///
+/// Returns `(n, y)`, such as:
+/// * `0 <= n <= 3`
+/// * `x = 2*π*M + π/2*n + y`
+/// * `M` is an integer
+fn big_reduce_pi_2<F: DoubleFloatMath>(x: F, len: usize) -> (u8, BigFloat) {
+    if x.abs() <= F::FRAC_PI_4 {
+        // reduction not needed
+        return (0, BigFloat::from_float(x, len));
+    }
+
+    // |x| = m * 2^e, with m split in 24-bit chunks
+    let m = x.mant();
+    let mut m_chunks = [0u32; 5];
+    let m_len = usize::from(F::MANT_BITS) / 24 + 1;
+    for (i, chunk) in m_chunks[..m_len].iter_mut().enumerate() {
+        let t: u32 = (m >> (24 * i) as u8).cast_into();
+        *chunk = t & 0xFF_FFFF;
+    }
+    let e = x.exponent().into() - i32::from(F::MANT_BITS);
+
+    // |x| * 2/π as a fixed point number, with enough fractional bits to
+    // keep the precision when the integer part cancels most of them
+    let mut q = [0u32; MAX_WORDS * 2 + 8];
+    let min_frac_bits = 32 * len + 2 * usize::from(F::MANT_BITS) + 56;
+    let (qlen, frac_bits) = mul_frac_2_pi_fixed(&m_chunks[..m_len], e, min_frac_bits, &mut q);
+    let bit = |q: &[u32], i: usize| (q[i / 24] >> (i % 24)) & 1;
+
+    let mut n =
+        ((bit(&q, frac_bits + 2) << 2) | (bit(&q, frac_bits + 1) << 1) | bit(&q, frac_bits)) as u8;
+    let frac_neg = bit(&q, frac_bits - 1) != 0;
+    if frac_neg {
+        // fraction >= 0.5, use fraction - 1
+        n += 1;
+        let mut borrow = 0;
+        for chunk in q[..qlen].iter_mut() {
+            let t = 0u32.wrapping_sub(*chunk).wrapping_sub(borrow);
+            *chunk = t & 0xFF_FFFF;
+            borrow = t >> 31;
+        }
+    }
+
+    // Pack the fractional bits in 32-bit words, from the highest one
+    let num_words = frac_bits.div_ceil(32);
+    let mut words = [0u32; MAX_WORDS + 16];
+    for (i, word) in words[..num_words].iter_mut().enumerate() {
+        let top = (frac_bits - 32 * i) as isize;
+        *word = (1..=32).fold(0, |acc, j| {
+            let pos = top - j;
+            (acc << 1) | if pos >= 0 { bit(&q, pos as usize) } else { 0 }
+        });
+    }
+
+    let frac = BigFloat::from_buf(frac_neg, 0, &words[..num_words], len);
+    let y = frac.mul_const(&FRAC_PI_2);
+
+    if x.sign() {
+        (n.wrapping_neg() & 3, -y)
+    } else {
+        (n & 3, y)
+    }
+}
+
+impl BigFloat {
+    #[inline]
+    fn mul_const(&self, c: &BigConst) -> Self {
+        self.mul(&Self::from_const(c, self.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traits::Float;
+    use crate::{ElementaryMath, cr};
+
+    fn test<F: Float + ElementaryMath>() {
+        let f = F::parse;
+        // π/2 rounded, scaling it by 2 is exact
+        let frac_pi_2 = cr::atan(F::INFINITY);
+        let pi = frac_pi_2 * F::two();
+
+        assert_is_nan!(cr::exp(F::NAN));
+        assert_total_eq!(cr::exp(F::INFINITY), F::INFINITY);
+        assert_total_eq!(cr::exp(F::neg_infinity()), F::ZERO);
+        assert_total_eq!(cr::exp(F::ZERO), F::one());
+        assert_total_eq!(cr::exp(-F::ZERO), F::one());
+        assert_total_eq!(cr::exp(F::largest()), F::INFINITY);
+        assert_total_eq!(cr::exp(-F::largest()), F::ZERO);
+
+        assert_is_nan!(cr::exp_m1(F::NAN));
+        assert_total_eq!(cr::exp_m1(F::INFINITY), F::INFINITY);
+        assert_total_eq!(cr::exp_m1(F::neg_infinity()), -F::one());
+        assert_total_eq!(cr::exp_m1(F::ZERO), F::ZERO);
+        assert_total_eq!(cr::exp_m1(-F::ZERO), -F::ZERO);
+
+        assert_is_nan!(cr::exp2(F::NAN));
+        assert_total_eq!(cr::exp2(F::INFINITY), F::INFINITY);
+        assert_total_eq!(cr::exp2(F::neg_infinity()), F::ZERO);
+        assert_total_eq!(cr::exp2(F::ZERO), F::one());
+        assert_total_eq!(cr::exp2(f("-1")), F::half());
+        assert_total_eq!(cr::exp2(f("10")), f("1024"));
+
+        assert_is_nan!(cr::exp10(F::NAN));
+        assert_total_eq!(cr::exp10(F::INFINITY), F::INFINITY);
+        assert_total_eq!(cr::exp10(F::neg_infinity()), F::ZERO);
+        assert_total_eq!(cr::exp10(F::ZERO), F::one());
+        assert_total_eq!(cr::exp10(f("3")), f("1000"));
+
+        assert_is_nan!(cr::ln(F::NAN));
+        assert_is_nan!(cr::ln(-F::one()));
+        assert_is_nan!(cr::ln(F::neg_infinity()));
+        assert_total_eq!(cr::ln(F::INFINITY), F::INFINITY);
+        assert_total_eq!(cr::ln(F::ZERO), F::neg_infinity());
+        assert_total_eq!(cr::ln(-F::ZERO), F::neg_infinity());
+        assert_total_eq!(cr::ln(F::one()), F::ZERO);
+
+        assert_is_nan!(cr::ln_1p(F::NAN));
+        assert_is_nan!(cr::ln_1p(f("-2")));
+        assert_total_eq!(cr::ln_1p(F::INFINITY), F::INFINITY);
+        assert_total_eq!(cr::ln_1p(-F::one()), F::neg_infinity());
+        assert_total_eq!(cr::ln_1p(F::ZERO), F::ZERO);
+        assert_total_eq!(cr::ln_1p(-F::ZERO), -F::ZERO);
+
+        assert_is_nan!(cr::log2(F::NAN));
+        assert_is_nan!(cr::log2(-F::one()));
+        assert_total_eq!(cr::log2(F::ZERO), F::neg_infinity());
+        assert_total_eq!(cr::log2(F::one()), F::ZERO);
+        assert_total_eq!(cr::log2(f("1024")), f("10"));
+        assert_total_eq!(cr::log2(f("0.125")), f("-3"));
+
+        assert_is_nan!(cr::log10(F::NAN));
+        assert_is_nan!(cr::log10(-F::one()));
+        assert_total_eq!(cr::log10(F::ZERO), F::neg_infinity());
+        assert_total_eq!(cr::log10(F::one()), F::ZERO);
+        assert_total_eq!(cr::log10(f("1000")), f("3"));
+
+        assert_is_nan!(cr::sin(F::NAN));
+        assert_is_nan!(cr::sin(F::INFINITY));
+        assert_is_nan!(cr::sin(F::neg_infinity()));
+        assert_total_eq!(cr::sin(F::ZERO), F::ZERO);
+        assert_total_eq!(cr::sin(-F::ZERO), -F::ZERO);
+
+        assert_is_nan!(cr::cos(F::NAN));
+        assert_is_nan!(cr::cos(F::INFINITY));
+        assert_is_nan!(cr::cos(F::neg_infinity()));
+        assert_total_eq!(cr::cos(F::ZERO), F::one());
+        assert_total_eq!(cr::cos(-F::ZERO), F::one());
+
+        assert_is_nan!(cr::tan(F::NAN));
+        assert_is_nan!(cr::tan(F::INFINITY));
+        assert_is_nan!(cr::tan(F::neg_infinity()));
+        assert_total_eq!(cr::tan(F::ZERO), F::ZERO);
+        assert_total_eq!(cr::tan(-F::ZERO), -F::ZERO);
+
+        assert_is_nan!(cr::asin(F::NAN));
+        assert_is_nan!(cr::asin(F::INFINITY));
+        assert_is_nan!(cr::asin(f("1.5")));
+        assert_is_nan!(cr::asin(f("-1.5")));
+        assert_total_eq!(cr::asin(F::ZERO), F::ZERO);
+        assert_total_eq!(cr::asin(-F::ZERO), -F::ZERO);
+        assert_total_eq!(cr::asin(F::one()), frac_pi_2);
+        assert_total_eq!(cr::asin(-F::one()), -frac_pi_2);
+
+        assert_is_nan!(cr::acos(F::NAN));
+        assert_is_nan!(cr::acos(F::neg_infinity()));
+        assert_is_nan!(cr::acos(f("1.5")));
+        assert_total_eq!(cr::acos(F::ZERO), frac_pi_2);
+        assert_total_eq!(cr::acos(F::one()), F::ZERO);
+        assert_total_eq!(cr::acos(-F::one()), pi);
+
+        assert_is_nan!(cr::atan(F::NAN));
+        assert_total_eq!(cr::atan(F::ZERO), F::ZERO);
+        assert_total_eq!(cr::atan(-F::ZERO), -F::ZERO);
+        assert_total_eq!(cr::atan(f("1")), frac_pi_2 * F::half());
+        assert_total_eq!(cr::atan(F::neg_infinity()), -frac_pi_2);
+
+        assert_is_nan!(cr::atan2(F::NAN, F::one()));
+        assert_is_nan!(cr::atan2(F::one(), F::NAN));
+        assert_total_eq!(cr::atan2(F::ZERO, F::one()), F::ZERO);
+        assert_total_eq!(cr::atan2(-F::ZERO, F::one()), -F::ZERO);
+        assert_total_eq!(cr::atan2(F::ZERO, -F::one()), pi);
+        assert_total_eq!(cr::atan2(-F::ZERO, -F::ZERO), -pi);
+        assert_total_eq!(cr::atan2(F::one(), F::ZERO), frac_pi_2);
+        assert_total_eq!(cr::atan2(-F::one(), F::INFINITY), -F::ZERO);
+        assert_total_eq!(cr::atan2(F::INFINITY, F::one()), frac_pi_2);
+
+        assert_is_nan!(cr::sinh(F::NAN));
+        assert_total_eq!(cr::sinh(F::INFINITY), F::INFINITY);
+        assert_total_eq!(cr::sinh(F::neg_infinity()), F::neg_infinity());
+        assert_total_eq!(cr::sinh(F::ZERO), F::ZERO);
+        assert_total_eq!(cr::sinh(-F::ZERO), -F::ZERO);
+        assert_total_eq!(cr::sinh(-F::largest()), F::neg_infinity());
+
+        assert_is_nan!(cr::cosh(F::NAN));
+        assert_total_eq!(cr::cosh(F::INFINITY), F::INFINITY);
+        assert_total_eq!(cr::cosh(F::neg_infinity()), F::INFINITY);
+        assert_total_eq!(cr::cosh(F::ZERO), F::one());
+        assert_total_eq!(cr::cosh(-F::ZERO), F::one());
+        assert_total_eq!(cr::cosh(-F::largest()), F::INFINITY);
+
+        assert_is_nan!(cr::tanh(F::NAN));
+        assert_total_eq!(cr::tanh(F::INFINITY), F::one());
+        assert_total_eq!(cr::tanh(F::neg_infinity()), -F::one());
+        assert_total_eq!(cr::tanh(F::ZERO), F::ZERO);
+        assert_total_eq!(cr::tanh(-F::ZERO), -F::ZERO);
+        assert_total_eq!(cr::tanh(f("100")), F::one());
+
+        assert_is_nan!(cr::cbrt(F::NAN));
+        assert_total_eq!(cr::cbrt(F::INFINITY), F::INFINITY);
+        assert_total_eq!(cr::cbrt(F::neg_infinity()), F::neg_infinity());
+        assert_total_eq!(cr::cbrt(F::ZERO), F::ZERO);
+        assert_total_eq!(cr::cbrt(-F::ZERO), -F::ZERO);
+        assert_total_eq!(cr::cbrt(f("8")), f("2"));
+        assert_total_eq!(cr::cbrt(f("-0.125")), f("-0.5"));
+
+        assert_is_nan!(cr::hypot(F::NAN, F::one()));
+        assert_total_eq!(cr::hypot(F::NAN, F::INFINITY), F::INFINITY);
+        assert_total_eq!(cr::hypot(F::neg_infinity(), F::NAN), F::INFINITY);
+        assert_total_eq!(cr::hypot(-F::ZERO, -F::ZERO), F::ZERO);
+        assert_total_eq!(cr::hypot(f("-3"), f("4")), f("5"));
+        assert_total_eq!(cr::hypot(F::largest(), F::largest()), F::INFINITY);
+
+        assert_is_nan!(cr::pow(F::NAN, F::one()));
+        assert_is_nan!(cr::pow(f("-2"), f("0.5")));
+        assert_total_eq!(cr::pow(F::NAN, F::ZERO), F::one());
+        assert_total_eq!(cr::pow(F::one(), F::NAN), F::one());
+        assert_total_eq!(cr::pow(F::ZERO, f("-1")), F::INFINITY);
+        assert_total_eq!(cr::pow(-F::ZERO, f("3")), -F::ZERO);
+        assert_total_eq!(cr::pow(f("2"), f("10")), f("1024"));
+        assert_total_eq!(cr::pow(f("-2"), f("3")), f("-8"));
+        assert_total_eq!(cr::pow(f("4"), f("0.5")), f("2"));
+        assert_total_eq!(cr::pow(f("10"), f("10000")), F::INFINITY);
+
+        // -big_odd is an odd integer whose power underflows for every type
+        let big_odd = F::exp2i_fast(F::Exp::try_from(F::MANT_BITS).ok().unwrap()) + F::one();
+        assert_total_eq!(cr::pow(f("-10"), -big_odd), -F::ZERO);
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
+    #[test]
+    fn test_f32() {
+        test::<f32>();
+    }
+
+    #[test]
+    fn test_f64() {
+        test::<f64>();
+    }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
+}
@@ -0,0 +1,613 @@
+use super::ln::hi_lo_ln_hi_lo_inner;
+use super::reduce_pi_2_large::reduce_pi_2_large;
+use super::{Exp, Ln, ReducePi2, round_as_i_f, scalbn, scalbn_medium, sqrt};
+use crate::DoubleFloat;
+use crate::double::{DenormDouble, NormDouble};
+use crate::traits::{CastFrom as _, Float, Int as _};
+
+pub(crate) trait DoubleFloatMath: Exp + Ln + ReducePi2 {
+    /// `ln(2) ~= DD_LN_2_HI + DD_LN_2_MI + DD_LN_2_LO`
+    ///
+    /// The lower bits of `DD_LN_2_HI` and `DD_LN_2_MI` are zero, so
+    /// multiplying them by any exponent is exact.
+    fn dd_ln_2_hi() -> Self;
+    fn dd_ln_2_mi() -> Self;
+    fn dd_ln_2_lo() -> Self;
+
+    /// `ln(10) ~= DD_LN_10_HI + DD_LN_10_LO`
+    fn dd_ln_10_hi() -> Self;
+    fn dd_ln_10_lo() -> Self;
+}
+
+pub(crate) fn dd_from<F: Float>(x: F) -> DoubleFloat<F> {
+    DoubleFloat { hi: x, lo: F::ZERO }
+}
+
+pub(crate) fn dd_new<F: Float>(hi: F, lo: F) -> DoubleFloat<F> {
+    from_denorm(DenormDouble::new_add11(hi, lo))
+}
+
+pub(crate) fn dd_neg<F: Float>(x: DoubleFloat<F>) -> DoubleFloat<F> {
+    DoubleFloat {
+        hi: -x.hi,
+        lo: -x.lo,
+    }
+}
+
+pub(crate) fn dd_add<F: Float>(x: DoubleFloat<F>, y: DoubleFloat<F>) -> DoubleFloat<F> {
+    from_denorm(add_inner(to_denorm(x), to_denorm(y)))
+}
+
+pub(crate) fn dd_sub<F: Float>(x: DoubleFloat<F>, y: DoubleFloat<F>) -> DoubleFloat<F> {
+    from_denorm(add_inner(to_denorm(x), -to_denorm(y)))
+}
+
+pub(crate) fn dd_mul<F: Float>(x: DoubleFloat<F>, y: DoubleFloat<F>) -> DoubleFloat<F> {
+    from_denorm(to_denorm(x) * to_denorm(y))
+}
+
+pub(crate) fn dd_div<F: Float>(x: DoubleFloat<F>, y: DoubleFloat<F>) -> DoubleFloat<F> {
+    let xe = x.hi.raw_exp();
+    let ye = y.hi.raw_exp();
+    if xe == F::MAX_RAW_EXP
+        || ye == F::MAX_RAW_EXP
+        || (xe == F::RawExp::ZERO && x.hi.raw_mant() == F::Raw::ZERO)
+        || (ye == F::RawExp::ZERO && y.hi.raw_mant() == F::Raw::ZERO)
+    {
+        // Zero, infinity or NaN in either operand, the low parts
+        // do not affect the result
+        dd_from(x.hi / y.hi)
+    } else {
+        from_denorm(div_inner(to_denorm(x), to_denorm(y)))
+    }
+}
+
+pub(crate) fn dd_sqrt<F: Float>(x: DoubleFloat<F>) -> DoubleFloat<F> {
+    let e = x.hi.raw_exp();
+    if e == F::RawExp::ZERO && x.hi.raw_mant() == F::Raw::ZERO {
+        // sqrt(±0) = ±0
+        dd_from(x.hi)
+    } else if x.hi.sign() {
+        // x < 0, sqrt(x) = NaN
+        dd_from(F::NAN)
+    } else if e == F::MAX_RAW_EXP {
+        // propagate infinity or NaN
+        dd_from(x.hi)
+    } else if x.hi.exponent() < F::MIN_NORMAL_EXP + sqrt_escale::<F>() * F::Exp::TWO {
+        // x is small or subnormal, scale by an even power of two to
+        // avoid losing precision in intermediate results that would
+        // be subnormal
+        let escale = sqrt_escale::<F>();
+        let y = to_denorm(x).pmul1(F::exp2i_fast(escale + escale));
+        from_denorm(sqrt_inner(y).pmul1(F::exp2i_fast(-escale)))
+    } else {
+        from_denorm(sqrt_inner(to_denorm(x)))
+    }
+}
+
+/// Returns the exponent of the factor (squared) used to scale the small
+/// arguments of [`dd_sqrt`], limited to keep them finite in `f16`
+#[inline]
+fn sqrt_escale<F: Float>() -> F::Exp {
+    F::Exp::cast_from(F::MANT_BITS * 2).min(F::MAX_EXP >> 1u8)
+}
+
+pub(crate) fn dd_exp<F: DoubleFloatMath>(x: DoubleFloat<F>) -> DoubleFloat<F> {
+    if x.hi >= F::exp_hi_th() {
+        // also handles x = inf
+        dd_from(F::INFINITY)
+    } else if x.hi <= F::exp_lo_th() {
+        // also handles x = -inf
+        dd_from(F::ZERO)
+    } else if x.hi.raw_exp() == F::MAX_RAW_EXP {
+        // x is NaN, propagate
+        dd_from(x.hi)
+    } else {
+        // exp(x) = 2^k * (1 + t)
+        let (k, t) = exp_split_m1(x);
+        let y = DenormDouble::new_add11(F::one(), t.hi())
+            .ladd(t.lo())
+            .normalize();
+        let y_hi = scalbn_medium(y.hi(), k);
+        let y_lo = scalbn_medium(y.lo(), k);
+        from_denorm(DenormDouble::new(y_hi, y_lo))
+    }
+}
+
+/// Calculates `exp(x) - 1`
+///
+/// `x` must be finite and greater than `exp_m1_lo_th`, and the result
+/// must not overflow.
+pub(crate) fn dd_exp_m1<F: DoubleFloatMath>(x: DoubleFloat<F>) -> DoubleFloat<F> {
+    let (k, t) = exp_split_m1(x);
+    if k == 0 {
+        from_denorm(t)
+    } else {
+        // exp(x) - 1 = 2^k * (1 + t) - 1, |x| is large enough to
+        // avoid a significant cancellation
+        let y = DenormDouble::new_add11(F::one(), t.hi())
+            .ladd(t.lo())
+            .normalize();
+        let y_hi = scalbn_medium(y.hi(), k);
+        let y_lo = scalbn_medium(y.lo(), k);
+        from_denorm(add_inner(
+            DenormDouble::new(y_hi, y_lo),
+            DenormDouble::new(-F::one(), F::ZERO),
+        ))
+    }
+}
+
+/// Calculates `m * exp(x)`, where `exp(x)` is calculated with full
+/// double-float accuracy
+///
+/// `x` must be finite and `|x|` small enough to avoid overflow of
+/// `x * log2(e)`. The result does not overflow or underflow when only
+/// `exp(x)` would.
+pub(super) fn mul_dd_exp<F: DoubleFloatMath>(m: DenormDouble<F>, x: DoubleFloat<F>) -> F {
+    // exp(x) = 2^k * (1 + t)
+    let (k, t) = exp_split_m1(x);
+    let y = DenormDouble::new_add11(F::one(), t.hi()).ladd(t.lo());
+    scalbn((m.to_semi() * y.to_semi()).to_single(), k)
+}
+
+pub(crate) fn dd_ln<F: DoubleFloatMath>(x: DoubleFloat<F>) -> DoubleFloat<F> {
+    let e = x.hi.raw_exp();
+    if e == F::RawExp::ZERO && x.hi.raw_mant() == F::Raw::ZERO {
+        // ln(±0) = -inf
+        dd_from(F::neg_infinity())
+    } else if x.hi.sign() {
+        // x < 0, ln(x) = NaN
+        dd_from(F::NAN)
+    } else if e == F::MAX_RAW_EXP {
+        // propagate infinity or NaN
+        dd_from(x.hi)
+    } else {
+        // x.lo is zero when x.hi is subnormal
+        let (x_hi, edelta) = x.hi.normalize_arg();
+        let x_lo = if e == F::RawExp::ZERO { F::ZERO } else { x.lo };
+
+        // Initial approximation with a few more bits than `F`
+        let y0 = hi_lo_ln_hi_lo_inner(NormDouble::with_parts(x_hi, x_lo), edelta).to_norm();
+        let y0 = DoubleFloat {
+            hi: y0.hi(),
+            lo: y0.lo(),
+        };
+
+        // Refine with a Newton iteration:
+        // y = y0 + x * exp(-y0) - 1
+        //   = y0 + (z - 1) + t + (z - 1) * t
+        // where exp(-y0) = 2^k * (1 + t) and z = x * 2^k,
+        // so z - 1 is exact and (z - 1) * t is small when x is
+        // close to 1.
+        let (k, t) = exp_split_m1(dd_neg(y0));
+        let k = k + Into::<i32>::into(edelta);
+        let z = DenormDouble::new(scalbn_medium(x_hi, k), scalbn_medium(x_lo, k));
+        let zm1 = DenormDouble::new_sub11(z.hi(), F::one()).ladd(z.lo());
+        let d = add_inner(add_inner(zm1, t), zm1 * t);
+        from_denorm(add_inner(to_denorm(y0), d))
+    }
+}
+
+pub(crate) fn dd_sin_cos<F: DoubleFloatMath>(
+    x: DoubleFloat<F>,
+) -> (DoubleFloat<F>, DoubleFloat<F>) {
+    let e = x.hi.raw_exp();
+    if e == F::MAX_RAW_EXP {
+        // sin(inf or nan) = cos(inf or nan) = nan
+        (dd_from(F::NAN), dd_from(F::NAN))
+    } else if e == F::RawExp::ZERO && x.hi.raw_mant() == F::Raw::ZERO {
+        // sin(±0) = ±0, cos(±0) = 1
+        (x, dd_from(F::one()))
+    } else {
+        let (n, y) = dd_reduce_pi_2(x);
+        let s = from_denorm(sin_inner(y));
+        let c = from_denorm(cos_inner(y));
+
+        match n {
+            0 => (s, c),
+            1 => (c, dd_neg(s)),
+            2 => (dd_neg(s), dd_neg(c)),
+            3 => (dd_neg(c), s),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[inline]
+fn to_denorm<F: Float>(x: DoubleFloat<F>) -> DenormDouble<F> {
+    DenormDouble::new(x.hi, x.lo)
+}
+
+fn from_denorm<F: Float>(x: DenormDouble<F>) -> DoubleFloat<F> {
+    let (hi, lo) = (x.hi(), x.lo());
+    if hi.raw_exp() == F::MAX_RAW_EXP || lo.raw_exp() == F::MAX_RAW_EXP {
+        // infinity, NaN or overflow in an intermediate result,
+        // the low part is meaningless
+        dd_from(hi)
+    } else if hi == F::ZERO && lo == F::ZERO {
+        // keep the sign of zero
+        dd_from(hi)
+    } else {
+        let x = x.to_norm();
+        if x.hi().raw_exp() == F::MAX_RAW_EXP {
+            // overflow when rounding
+            dd_from(x.hi())
+        } else {
+            DoubleFloat {
+                hi: x.hi(),
+                lo: x.lo(),
+            }
+        }
+    }
+}
+
+/// Calculates `x + y` keeping the relative accuracy when `x` and `y`
+/// (almost) cancel each other
+fn add_inner<F: Float>(x: DenormDouble<F>, y: DenormDouble<F>) -> DenormDouble<F> {
+    let s = DenormDouble::new_add11(x.hi(), y.hi());
+    let t = DenormDouble::new_add11(x.lo(), y.lo());
+    s.ladd(t.hi()).normalize().ladd(t.lo())
+}
+
+/// Calculates `x / y`
+///
+/// `x` and `y` must be finite and non-zero.
+fn div_inner<F: Float>(x: DenormDouble<F>, y: DenormDouble<F>) -> DenormDouble<F> {
+    // Long division, each step adds the bits of a quotient digit
+    let q1 = x.hi() / y.hi();
+    if q1.raw_exp() == F::MAX_RAW_EXP {
+        // overflow
+        return DenormDouble::new(q1, F::ZERO);
+    }
+    let r = add_inner(x, -(y * DenormDouble::new(q1, F::ZERO)));
+    let q2 = r.hi() / y.hi();
+    let r = add_inner(r, -(y * DenormDouble::new(q2, F::ZERO)));
+    let q3 = r.hi() / y.hi();
+
+    DenormDouble::new_qadd11(q1, q2).normalize().ladd(q3)
+}
+
+/// Calculates `sqrt(x)`
+///
+/// `x` must be positive, finite and not too small.
+fn sqrt_inner<F: Float>(x: DenormDouble<F>) -> DenormDouble<F> {
+    // Improve accuracy with a single Newton iteration
+    // y = sqrt(x_hi)
+    // sqrt(x) ~= y + (x - y^2) / (2 * y)
+    let y = sqrt(x.hi());
+    // y^2 is exact
+    let y2 = DenormDouble::new(y, F::ZERO) * DenormDouble::new(y, F::ZERO);
+    let r = add_inner(x, -y2);
+    DenormDouble::new_qadd11(y, r.hi() / (y + y))
+}
+
+/// Calculates `x / y`, where `y` is a small integer
+#[inline]
+fn div_int_inner<F: Float>(x: DenormDouble<F>, y: F) -> DenormDouble<F> {
+    let q1 = x.hi() / y;
+    let r = add_inner(
+        x,
+        -(DenormDouble::new(q1, F::ZERO) * DenormDouble::new(y, F::ZERO)),
+    );
+    let q2 = r.hi() / y;
+
+    DenormDouble::new_qadd11(q1, q2)
+}
+
+/// Returns `(k, t)` such as `exp(x) = 2^k * (1 + t)`
+///
+/// `x` must be finite and `|x|` small enough to avoid overflow
+/// of `x * log2(e)`.
+fn exp_split_m1<F: DoubleFloatMath>(x: DoubleFloat<F>) -> (i32, DenormDouble<F>) {
+    // r = x - k * ln(2)
+    //  - k is an integer
+    //  - |r| <= 0.5 * ln(2)
+    let (k, kf) = round_as_i_f(x.hi * F::log2_e());
+    // `kf * DD_LN_2_HI` and `kf * DD_LN_2_MI` are exact
+    let r = DenormDouble::new_sub11(x.hi, kf * F::dd_ln_2_hi()) - kf * F::dd_ln_2_mi();
+    let r = add_inner(
+        r,
+        -(DenormDouble::new(kf, F::ZERO) * DenormDouble::new(F::dd_ln_2_lo(), F::ZERO)),
+    );
+    let r = add_inner(r, DenormDouble::new(x.lo, F::ZERO));
+
+    // exp(r) - 1 = r + r^2 / 2! + r^3 / 3! + ...
+    //            = r * (1 + r / 2 * (1 + r / 3 * (1 + ...)))
+    // Find the number of needed terms and evaluate from the last one
+    let rabs = r.hi().abs();
+    let mut n = F::one();
+    let (mut term, eps) = taylor_eps::<F>();
+    while term > eps {
+        n = n + F::one();
+        term = term * rabs / n;
+    }
+    let mut p = DenormDouble::one();
+    while n > F::two() {
+        p = add_inner(DenormDouble::one(), div_int_inner(p * r, n));
+        n = n - F::one();
+    }
+    // Keep the multiplication error in the smaller term
+    let q = div_int_inner(p * r, F::two());
+    let t = add_inner(r, r * q);
+
+    (k, t.normalize())
+}
+
+/// Reduces `x` to `y` with `|y| <= π/4`
+///
+/// Returns `(n, y)`, such as:
+/// * `0 <= n <= 3`
+/// * `x = 2*π*M + π/2*n + y`
+/// * `M` is an integer
+fn dd_reduce_pi_2<F: DoubleFloatMath>(x: DoubleFloat<F>) -> (u8, DenormDouble<F>) {
+    if x.hi.abs() <= F::FRAC_PI_4 {
+        // reduction not needed
+        return (0, to_denorm(x));
+    }
+
+    // Reduce each part separately into exact terms
+    let mut terms = [F::ZERO; 48];
+    let (n_hi, hi_len) = reduce_pi_2_large_terms(x.hi, &mut terms[..22]);
+    let mut len = hi_len;
+    let mut n = n_hi;
+    if x.lo.abs() <= F::FRAC_PI_4 {
+        terms[len] = x.lo;
+        len += 1;
+    } else {
+        let (n_lo, lo_len) = reduce_pi_2_large_terms(x.lo, &mut terms[len..(len + 22)]);
+        len += lo_len;
+        n += n_lo;
+    }
+
+    // |y| <= π/2, reduce again if needed
+    let y_approx = terms[..len].iter().fold(F::ZERO, |acc, &t| acc + t);
+    if y_approx.abs() > F::FRAC_PI_4 {
+        // π/2 = FRAC_PI_2_HI + FRAC_PI_2_MI + FRAC_PI_2_LO + FRAC_PI_2_LOEX
+        let f_n = F::one().copysign(y_approx);
+        n += if y_approx.sign() { 3 } else { 1 };
+        terms[len] = -f_n * F::frac_pi_2_hi();
+        terms[len + 1] = -f_n * F::frac_pi_2_mi();
+        terms[len + 2] = -f_n * F::frac_pi_2_lo();
+        terms[len + 3] = -f_n * F::frac_pi_2_loex();
+        len += 4;
+    }
+
+    // Add the terms from the largest one, so any cancellation
+    // between them happens before rounding
+    let terms = &mut terms[..len];
+    terms.sort_unstable_by(|a, b| b.abs().partial_cmp(&a.abs()).unwrap());
+    let y = terms
+        .iter()
+        .fold(DenormDouble::new(F::ZERO, F::ZERO), |y, &t| {
+            add_inner(y, DenormDouble::new(t, F::ZERO))
+        });
+
+    (n & 3, y)
+}
+
+/// Like `reduce_pi_2` for `|x| > π/4`, but stores `y` as a sum of exact
+/// non-zero terms in `terms`
+///
+/// Returns `n` and the number of terms.
+fn reduce_pi_2_large_terms<F: DoubleFloatMath>(x: F, terms: &mut [F]) -> (u8, usize) {
+    let (x_chunks, e0, _) = F::reduce_pi_2_prepare(x);
+    let mut qp: [u64; 20] = [0; 20];
+    // Use more terms than `reduce_pi_2` to get enough bits, at least twice
+    // the precision of `F`
+    let jk = ((2 * usize::from(F::MANT_BITS) + 48) / 24).max(6);
+    let (ih, jz, n, qe) = reduce_pi_2_large(x_chunks.as_ref(), e0, jk, &mut qp);
+
+    // Propagate carries so each chunk fits in 24 bits and can be
+    // converted exactly. chunks[i] has a weight of 2^(qe + 48 - 24 * i).
+    let mut chunks: [u64; 22] = [0; 22];
+    let mut carry = 0;
+    for i in (0..=jz).rev() {
+        let v = qp[i] + carry;
+        chunks[i + 2] = v & 0xFF_FFFF;
+        carry = v >> 24;
+    }
+    chunks[1] = carry & 0xFF_FFFF;
+    chunks[0] = carry >> 24;
+
+    let f_s = if (ih != 0) != x.sign() {
+        -F::one()
+    } else {
+        F::one()
+    };
+    let mut len = 0;
+    let mut e = i32::from(qe) + 48;
+    for &chunk in chunks[..(jz + 3)].iter() {
+        if chunk != 0 {
+            terms[len] = f_s * scalbn_medium(F::cast_from(chunk), e);
+            len += 1;
+        }
+        e -= 24;
+    }
+
+    if x.sign() {
+        (n.wrapping_neg() & 3, len)
+    } else {
+        (n & 3, len)
+    }
+}
+
+/// Calculates `sin(x)` with `|x| <= π/4`
+fn sin_inner<F: Float>(x: DenormDouble<F>) -> DenormDouble<F> {
+    // sin(x) = x - x^3 / 3! + x^5 / 5! - ...
+    //        = x * (1 - x^2 / (2 * 3) * (1 - x^2 / (4 * 5) * (1 - ...)))
+    // Find the number of needed terms and evaluate from the last one
+    let x2 = x * x;
+    let x2abs = x2.hi();
+    let mut n = F::one();
+    let (mut term, eps) = taylor_eps::<F>();
+    while term > eps {
+        n = n + F::two();
+        term = term * x2abs / ((n - F::one()) * n);
+    }
+    let three = F::one() + F::two();
+    let mut p = DenormDouble::one();
+    while n > three {
+        p = add_inner(
+            DenormDouble::one(),
+            -div_int_inner(p * x2, (n - F::one()) * n),
+        );
+        n = n - F::two();
+    }
+    // Keep the multiplication error in the smaller term
+    let q = -div_int_inner(p * x2, F::two() * three);
+    add_inner(x, x * q)
+}
+
+/// Calculates `cos(x)` with `|x| <= π/4`
+fn cos_inner<F: Float>(x: DenormDouble<F>) -> DenormDouble<F> {
+    // cos(x) = 1 - x^2 / 2! + x^4 / 4! - ...
+    //        = 1 - x^2 / (1 * 2) * (1 - x^2 / (3 * 4) * (1 - ...))
+    // Find the number of needed terms and evaluate from the last one
+    let x2 = x * x;
+    let x2abs = x2.hi();
+    let mut n = F::ZERO;
+    let (mut term, eps) = taylor_eps::<F>();
+    while term > eps {
+        n = n + F::two();
+        term = term * x2abs / ((n - F::one()) * n);
+    }
+    let mut p = DenormDouble::one();
+    while n > F::ZERO {
+        p = add_inner(
+            DenormDouble::one(),
+            -div_int_inner(p * x2, (n - F::one()) * n),
+        );
+        n = n - F::two();
+    }
+    p
+}
+
+/// Returns `(one, eps)`, where `eps / one` is the relative size of the first
+/// neglected term of Taylor series
+///
+/// Both are scaled to stay within the normal range, which is too narrow
+/// for `eps` alone in `f16`.
+#[inline]
+fn taylor_eps<F: Float>() -> (F, F) {
+    let e = F::Exp::cast_from(F::MANT_BITS + 2);
+    (F::exp2i_fast(e), F::exp2i_fast(-e))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::traits::{CastFrom as _, Float};
+    use crate::{DoubleFloat, ElementaryMath};
+
+    fn test<F: Float + ElementaryMath + From<DoubleFloat<F>>>() {
+        let dd = DoubleFloat::<F>::new;
+        let eps = F::exp2i_fast((-F::Exp::cast_from(F::MANT_BITS + 10)).max(F::MIN_NORMAL_EXP));
+
+        // construction normalizes
+        let x = dd(F::one(), F::one());
+        assert_total_eq!(x.hi(), F::two());
+        assert_total_eq!(x.lo(), F::ZERO);
+        let x = dd(F::one(), eps);
+        assert_total_eq!(x.hi(), F::one());
+        assert_total_eq!(x.lo(), eps);
+        assert_total_eq!(F::from(x), F::one());
+
+        // arithmetic keeps the low part
+        let y = x + x;
+        assert_total_eq!(y.hi(), F::two());
+        assert_total_eq!(y.lo(), eps + eps);
+        let y = x - DoubleFloat::from(F::one());
+        assert_total_eq!(y.hi(), eps);
+        assert_total_eq!(y.lo(), F::ZERO);
+        let y = x * x;
+        assert_total_eq!(y.hi(), F::one());
+        assert_total_eq!(y.lo(), eps + eps);
+        let y = (x * x) / x;
+        assert_total_eq!(y.hi(), F::one());
+        assert_total_eq!(y.lo(), eps);
+        let y = -x;
+        assert_total_eq!(y.hi(), -F::one());
+        assert_total_eq!(y.lo(), -eps);
+
+        // comparison
+        assert!(x > DoubleFloat::from(F::one()));
+        assert!(-x < DoubleFloat::from(-F::one()));
+        assert!(x == dd(F::one(), eps));
+        assert!(DoubleFloat::from(F::NAN) != DoubleFloat::from(F::NAN));
+
+        // special cases
+        let zero = DoubleFloat::from(F::ZERO);
+        let one = DoubleFloat::from(F::one());
+        let inf = DoubleFloat::from(F::INFINITY);
+        let nan = DoubleFloat::from(F::NAN);
+
+        assert_total_eq!((one / zero).hi(), F::INFINITY);
+        assert_is_nan!((zero / -zero).hi());
+        assert_total_eq!((inf * one).hi(), F::INFINITY);
+        assert_total_eq!((inf * one).lo(), F::ZERO);
+        assert_is_nan!((inf + -inf).hi());
+        assert_total_eq!((-zero * one).hi(), -F::ZERO);
+
+        assert_total_eq!(zero.sqrt().hi(), F::ZERO);
+        assert_total_eq!((-zero).sqrt().hi(), -F::ZERO);
+        assert_total_eq!(one.sqrt().hi(), F::one());
+        assert_total_eq!(one.sqrt().lo(), F::ZERO);
+        assert_is_nan!((-one).sqrt().hi());
+        assert_total_eq!(inf.sqrt().hi(), F::INFINITY);
+        assert_is_nan!(nan.sqrt().hi());
+
+        assert_total_eq!(zero.exp().hi(), F::one());
+        assert_total_eq!(zero.exp().lo(), F::ZERO);
+        assert_total_eq!(inf.exp().hi(), F::INFINITY);
+        assert_total_eq!((-inf).exp().hi(), F::ZERO);
+        assert_is_nan!(nan.exp().hi());
+
+        assert_total_eq!(one.ln().hi(), F::ZERO);
+        assert_total_eq!(one.ln().lo(), F::ZERO);
+        assert_total_eq!(zero.ln().hi(), F::neg_infinity());
+        assert_total_eq!((-zero).ln().hi(), F::neg_infinity());
+        assert_is_nan!((-one).ln().hi());
+        assert_total_eq!(inf.ln().hi(), F::INFINITY);
+        assert_is_nan!(nan.ln().hi());
+
+        let (s, c) = zero.sin_cos();
+        assert_total_eq!(s.hi(), F::ZERO);
+        assert_total_eq!(c.hi(), F::one());
+        let (s, c) = (-zero).sin_cos();
+        assert_total_eq!(s.hi(), -F::ZERO);
+        assert_total_eq!(c.hi(), F::one());
+        let (s, c) = inf.sin_cos();
+        assert_is_nan!(s.hi());
+        assert_is_nan!(c.hi());
+        let (s, c) = nan.sin_cos();
+        assert_is_nan!(s.hi());
+        assert_is_nan!(c.hi());
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
+    #[test]
+    fn test_f32() {
+        test::<f32>();
+    }
+
+    #[test]
+    fn test_f64() {
+        test::<f64>();
+    }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
+}
@@ -0,0 +1,248 @@
+use super::exp::{exp_split, hi_lo_exp_inner_common};
+use super::{Exp, scalbn};
+use crate::double::{DenormDouble, NormDouble, SemiDouble};
+use crate::traits::{CastFrom as _, CastInto as _, Float, Int as _};
+
+pub(crate) trait Erf: Exp {
+    /// Threshold above which `erf(x)` rounds to one
+    fn erf_one_th() -> Self;
+    /// Threshold above which `erfc(x)` underflows to zero
+    fn erfc_hi_th() -> Self;
+
+    fn frac_1_sqrt_pi() -> NormDouble<Self>;
+
+    /// Calculates `(r, K0, K1, K2)` such as
+    /// `erf(x) / x - 1 = K0 + x2 * (K1 + x2 * (K2 + r))`
+    ///
+    /// Where:
+    /// * `x2 = x^2`
+    /// * `|x| < 1`
+    fn erf_poly(x2: Self) -> (Self, Self, Self, Self);
+
+    /// Calculates `(r, K0, K1)` such as
+    /// `erfc(0.75 + x) * exp((0.75 + x)^2) = K0 + x * (K1 + r)`
+    ///
+    /// `-0.25 <= x < 0.25`
+    fn erfc_poly_1(x: Self) -> (Self, NormDouble<Self>, Self);
+
+    /// Calculates `(r, K0, K1)` such as
+    /// `erfc(1.5 + x) * exp((1.5 + x)^2) = K0 + x * (K1 + r)`
+    ///
+    /// `-0.5 <= x < 0.5`
+    fn erfc_poly_2(x: Self) -> (Self, NormDouble<Self>, Self);
+
+    /// Calculates `(r, K0, K1)` such as
+    /// `erfc(3 + x) * exp((3 + x)^2) = K0 + x * (K1 + r)`
+    ///
+    /// `-1 <= x < 1`
+    fn erfc_poly_3(x: Self) -> (Self, NormDouble<Self>, Self);
+
+    /// Calculates `(r, K0)` such as
+    /// `(√π * x * erfc(x) * exp(x^2) - 1) * x^2 = K0 + r`
+    ///
+    /// Where:
+    /// * `t = 1 / x^2`
+    /// * `4 <= x < erfc_hi_th()`
+    fn erfc_asympt_poly(t: Self) -> (Self, Self);
+}
+
+pub(crate) fn erf<F: Erf>(x: F) -> F {
+    let e = x.raw_exp();
+    if e == F::MAX_RAW_EXP {
+        if x.raw_mant() == F::Raw::ZERO {
+            // erf(±inf) = ±1
+            F::one().copysign(x)
+        } else {
+            // propagate NaN
+            x
+        }
+    } else if e <= (F::MANT_BITS * 2 + 4).into() {
+        if e == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO {
+            // erf(±0) = ±0
+            x
+        } else {
+            // subnormal or tiny, scale to avoid losing precision in
+            // intermediate results that would be subnormal,
+            // erf(x) ~= x * 2/√π
+            let escale = F::Exp::cast_from(F::MANT_BITS * 2 + 4);
+            let y = x * F::exp2i_fast(escale);
+            erf_inner_small(y).to_single() * F::exp2i_fast(-escale)
+        }
+    } else {
+        let absx = x.abs();
+        if absx < F::one() {
+            erf_inner_small(x).to_single()
+        } else if absx >= F::erf_one_th() {
+            F::one().copysign(x)
+        } else {
+            // erf(x) = 1 - erfc(x)
+            let erfc = erfc_inner_large_scaled(absx);
+            DenormDouble::new_qsub12(F::one(), erfc)
+                .to_single()
+                .copysign(x)
+        }
+    }
+}
+
+pub(crate) fn erfc<F: Erf>(x: F) -> F {
+    let e = x.raw_exp();
+    if e == F::MAX_RAW_EXP {
+        if x.raw_mant() == F::Raw::ZERO {
+            // erfc(inf) = 0
+            // erfc(-inf) = 2
+            if x.sign() { F::two() } else { F::ZERO }
+        } else {
+            // propagate NaN
+            x
+        }
+    } else if x < F::half() && x > -F::one() {
+        // erfc(x) = 1 - erf(x)
+        // also handles zero and subnormals
+        DenormDouble::new_qsub12(F::one(), erf_inner_small(x)).to_single()
+    } else if x >= F::erfc_hi_th() {
+        F::ZERO
+    } else if x > F::ZERO {
+        let (k, erfc) = erfc_inner_large(x);
+        scalbn(erfc.to_single(), k)
+    } else if x <= -F::erf_one_th() {
+        F::two()
+    } else {
+        // erfc(x) = 2 - erfc(-x)
+        let erfc = erfc_inner_large_scaled(-x);
+        DenormDouble::new_qsub12(F::two(), erfc).to_single()
+    }
+}
+
+/// Calculates `erf(x)` for `|x| < 1`
+pub(super) fn erf_inner_small<F: Erf>(x: F) -> DenormDouble<F> {
+    // erf(x) = x + x * (erf(x) / x - 1)
+    //        = x + x * (K0 + x^2 * (K1 + x^2 * (K2 + r)))
+    let (r, k0, k1, k2) = F::erf_poly(x * x);
+
+    // The leading terms are evaluated with extra precision, since
+    // `1 - erf(x)` is used to calculate `erfc(x)`
+    let x2 = SemiDouble::new(x).square().to_semi();
+    let t = SemiDouble::new_qadd11(k2, r);
+    let t = SemiDouble::new_qadd12(k1, x2 * t);
+    let u = x2 * t;
+    let t = DenormDouble::new_add11(k0, u.hi()).ladd(u.lo());
+    DenormDouble::new(x, F::ZERO).qadd2(SemiDouble::new(x) * t.to_semi())
+}
+
+/// Calculates `erfc(x)` for `0.5 <= x < erf_one_th()`
+fn erfc_inner_large_scaled<F: Erf>(x: F) -> DenormDouble<F> {
+    let (k, erfc) = erfc_inner_large(x);
+    // `k` is small enough to not overflow nor underflow
+    erfc.pmul1(F::exp2i_fast(k.cast_into()))
+}
+
+/// Returns `(k, y)` such as `erfc(x) = y * 2^k`
+///
+/// `0.5 <= x < erfc_hi_th()`
+fn erfc_inner_large<F: Erf>(x: F) -> (i32, DenormDouble<F>) {
+    // erfc(x) = exp(-x^2) * s
+    let x2 = SemiDouble::new(x).square().to_norm();
+
+    let (k, r_hi, r_lo) = exp_split(-x2.hi());
+    let r_lo = r_lo - x2.lo();
+    let exp_mx2 = hi_lo_exp_inner_common(r_hi, r_lo);
+
+    let s = erfc_scaled_inner(x, x2);
+
+    (k, exp_mx2.to_semi() * s.to_semi())
+}
+
+/// Calculates `erfc(x) * exp(x^2)`, where `x2 = x^2`
+///
+/// `0.5 <= x < erfc_hi_th()`
+pub(super) fn erfc_scaled_inner<F: Erf>(x: F, x2: NormDouble<F>) -> DenormDouble<F> {
+    if x < F::one() {
+        // s = erfc(x) * exp(x^2) = K0 + y * (K1 + r)
+        let y = x - (F::half() + F::half() * F::half());
+        let (r, k0, k1) = F::erfc_poly_1(y);
+        finish_poly(y, r, k0, k1)
+    } else if x < F::two() {
+        // s = erfc(x) * exp(x^2) = K0 + y * (K1 + r)
+        let y = x - (F::one() + F::half());
+        let (r, k0, k1) = F::erfc_poly_2(y);
+        finish_poly(y, r, k0, k1)
+    } else if x < F::two() * F::two() {
+        // s = erfc(x) * exp(x^2) = K0 + y * (K1 + r)
+        let y = x - (F::one() + F::two());
+        let (r, k0, k1) = F::erfc_poly_3(y);
+        finish_poly(y, r, k0, k1)
+    } else {
+        // s = erfc(x) * exp(x^2) = (1 + t * (K0 + r)) / (√π * x)
+        let t = DenormDouble::one() / x2.to_denorm();
+        let (r, k0) = F::erfc_asympt_poly(t.to_single());
+        let u = t.to_semi() * SemiDouble::new_qadd11(k0, r);
+        let v = u.qradd1(F::one()) / DenormDouble::new(x, F::ZERO);
+        v.to_semi() * F::frac_1_sqrt_pi().to_semi()
+    }
+}
+
+/// Calculates `k0 + y * (k1 + r)`
+fn finish_poly<F: Float>(y: F, r: F, k0: NormDouble<F>, k1: F) -> DenormDouble<F> {
+    let t = SemiDouble::new_qadd11(k1, r);
+    (SemiDouble::new(y) * t).qradd1(k0.hi()).ladd(k0.lo())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FloatMath;
+    use crate::traits::Float;
+
+    fn test_erf<F: Float + FloatMath>() {
+        use crate::erf;
+
+        assert_is_nan!(erf(F::NAN));
+        assert_total_eq!(erf(F::INFINITY), F::one());
+        assert_total_eq!(erf(F::neg_infinity()), -F::one());
+        assert_total_eq!(erf(F::ZERO), F::ZERO);
+        assert_total_eq!(erf(-F::ZERO), -F::ZERO);
+        assert_total_eq!(erf(F::largest()), F::one());
+        assert_total_eq!(erf(-F::largest()), -F::one());
+    }
+
+    fn test_erfc<F: Float + FloatMath>(hi_th: &str) {
+        use crate::erfc;
+
+        let hi_th = F::parse(hi_th);
+
+        assert_is_nan!(erfc(F::NAN));
+        assert_total_eq!(erfc(F::INFINITY), F::ZERO);
+        assert_total_eq!(erfc(F::neg_infinity()), F::two());
+        assert_total_eq!(erfc(F::ZERO), F::one());
+        assert_total_eq!(erfc(-F::ZERO), F::one());
+        assert_total_eq!(erfc(F::largest()), F::ZERO);
+        assert_total_eq!(erfc(-F::largest()), F::two());
+        assert_total_eq!(erfc(hi_th), F::ZERO);
+        assert_total_eq!(erfc(hi_th + F::one()), F::ZERO);
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_erf::<f16>();
+        test_erfc::<f16>("4.0");
+    }
+
+    #[test]
+    fn test_f32() {
+        test_erf::<f32>();
+        test_erfc::<f32>("10.06");
+    }
+
+    #[test]
+    fn test_f64() {
+        test_erf::<f64>();
+        test_erfc::<f64>("27.3");
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test_erf::<half::bf16>();
+        test_erfc::<half::bf16>("10.06");
+    }
+}
@@ -0,0 +1,210 @@
+use super::erf::{erf_inner_small, erfc_scaled_inner};
+use super::ln::{hi_lo_ln_hi_lo_inner, hi_lo_ln_inner};
+use super::{Erf, Ln, exp, sqrt, tiny_raw_exp, tiny_scale_exp};
+use crate::double::{DenormDouble, SemiDouble};
+use crate::traits::Int as _;
+
+pub(crate) trait ErfInv: Erf + Ln {
+    /// Calculates an approximation of `erfinv(x) / x`
+    ///
+    /// Where:
+    /// * `x2 = x^2`
+    /// * `|x| < 0.75`
+    fn erfinv_poly(x2: Self) -> Self;
+
+    /// Calculates an approximation of `erfcinv(exp(-t^2))`
+    ///
+    /// `1.17 <= t < 3`
+    fn erfcinv_poly_1(t: Self) -> Self;
+
+    /// Calculates an approximation of `erfcinv(exp(-t^2))`
+    ///
+    /// `3 <= t < 6`
+    fn erfcinv_poly_2(t: Self) -> Self;
+
+    /// Calculates an approximation of `erfcinv(exp(-t^2)) / t`
+    ///
+    /// Where:
+    /// * `u = 1 / t`
+    /// * `6 <= t`
+    fn erfcinv_asympt_poly(u: Self) -> Self;
+}
+
+pub(crate) fn erfinv<F: ErfInv>(x: F) -> F {
+    let e = x.raw_exp();
+    if e == F::MAX_RAW_EXP {
+        if x.raw_mant() == F::Raw::ZERO {
+            // erfinv(±inf) = NaN
+            F::NAN
+        } else {
+            // propagate NaN
+            x
+        }
+    } else if e == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO {
+        // erfinv(±0) = ±0
+        x
+    } else if e <= tiny_raw_exp::<F>() {
+        // very small, erfinv(x) ~= x * √π/2
+
+        // scale temporarily to avoid temporary subnormal numbers
+        let logscale = tiny_scale_exp::<F>();
+        let scale = F::exp2i_fast(logscale);
+        let descale = F::exp2i_fast(-logscale);
+
+        let two_frac_1_sqrt_pi = F::frac_1_sqrt_pi().to_denorm().pmul1(F::two());
+        let r = DenormDouble::new(x * scale, F::ZERO) / two_frac_1_sqrt_pi;
+        r.to_single() * descale
+    } else {
+        let absx = x.abs();
+        if absx < F::half() + F::half() * F::half() {
+            erfinv_inner_central(DenormDouble::new(x, F::ZERO))
+        } else if absx < F::one() {
+            // 1 - |x| is exact
+            erfcinv_inner_tail(F::one() - absx).copysign(x)
+        } else if absx == F::one() {
+            // erfinv(±1) = ±inf
+            F::INFINITY.copysign(x)
+        } else {
+            // erfinv(x) = NaN for |x| > 1
+            F::NAN
+        }
+    }
+}
+
+pub(crate) fn erfcinv<F: ErfInv>(x: F) -> F {
+    let quarter = F::half() * F::half();
+    if x.raw_exp() == F::MAX_RAW_EXP {
+        if x.raw_mant() == F::Raw::ZERO {
+            // erfcinv(±inf) = NaN
+            F::NAN
+        } else {
+            // propagate NaN
+            x
+        }
+    } else if x <= quarter {
+        if x > F::ZERO {
+            erfcinv_inner_tail(x)
+        } else if x == F::ZERO {
+            // erfcinv(±0) = inf
+            F::INFINITY
+        } else {
+            // erfcinv(x) = NaN for x < 0
+            F::NAN
+        }
+    } else if x < F::two() - quarter {
+        // erfcinv(x) = erfinv(1 - x)
+        erfinv_inner_central(DenormDouble::new_sub11(F::one(), x))
+    } else if x < F::two() {
+        // erfcinv(x) = -erfcinv(2 - x), where 2 - x is exact
+        -erfcinv_inner_tail(F::two() - x)
+    } else if x == F::two() {
+        // erfcinv(2) = -inf
+        F::neg_infinity()
+    } else {
+        // erfcinv(x) = NaN for x > 2
+        F::NAN
+    }
+}
+
+/// Calculates `erfinv(x)` for `|x| < 0.75`
+fn erfinv_inner_central<F: ErfInv>(x: DenormDouble<F>) -> F {
+    // Initial approximation
+    let x_hi = x.hi();
+    let y0 = x_hi * F::erfinv_poly(x_hi * x_hi);
+
+    // Refine with one step of Newton's method, with the residual
+    // evaluated with extra precision:
+    // y1 = y0 - (erf(y0) - x) / (2 / √π * exp(-y0^2))
+    let d = (erf_inner_small(y0) - x).to_single();
+    let frac_sqrt_pi_2 = F::half() / F::frac_1_sqrt_pi().hi();
+    y0 - d * frac_sqrt_pi_2 * exp(y0 * y0)
+}
+
+/// Calculates `erfcinv(x)` for `0 < x <= 0.25`
+fn erfcinv_inner_tail<F: ErfInv>(x: F) -> F {
+    let (xn, edelta) = x.normalize_arg();
+    let ln_x = hi_lo_ln_inner(xn, edelta);
+
+    // Initial approximation, as a function of t = √(-ln(x))
+    let w = -ln_x.to_single();
+    let t = sqrt(w);
+    let y0 = if w < F::cast_from(9u8) {
+        F::erfcinv_poly_1(t)
+    } else if w < F::cast_from(36u8) {
+        F::erfcinv_poly_2(t)
+    } else {
+        t * F::erfcinv_asympt_poly(F::one() / t)
+    };
+
+    // Refine with one step of Newton's method applied to
+    // g(y) = ln(erfc(y)) = ln(s) - y^2, where s = erfc(y) * exp(y^2),
+    // with the residual evaluated with extra precision:
+    // y1 = y0 - (g(y0) - ln(x)) / g'(y0)
+    //    = y0 + (g(y0) - ln(x)) * √π / 2 * s
+    let y0_sq = SemiDouble::new(y0).square();
+    let s = erfc_scaled_inner(y0, y0_sq.to_norm());
+    let ln_s = hi_lo_ln_hi_lo_inner(s.to_norm(), F::Exp::ZERO);
+    let d = ((ln_s - ln_x) - y0_sq).to_single();
+    let frac_sqrt_pi_2 = F::half() / F::frac_1_sqrt_pi().hi();
+    y0 + d * frac_sqrt_pi_2 * s.to_single()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FloatMath;
+    use crate::traits::Float;
+
+    fn test_erfinv<F: Float + FloatMath>() {
+        use crate::erfinv;
+
+        assert_is_nan!(erfinv(F::NAN));
+        assert_is_nan!(erfinv(F::INFINITY));
+        assert_is_nan!(erfinv(F::neg_infinity()));
+        assert_is_nan!(erfinv(F::two()));
+        assert_is_nan!(erfinv(-F::two()));
+        assert_total_eq!(erfinv(F::ZERO), F::ZERO);
+        assert_total_eq!(erfinv(-F::ZERO), -F::ZERO);
+        assert_total_eq!(erfinv(F::one()), F::INFINITY);
+        assert_total_eq!(erfinv(-F::one()), F::neg_infinity());
+    }
+
+    fn test_erfcinv<F: Float + FloatMath>() {
+        use crate::erfcinv;
+
+        assert_is_nan!(erfcinv(F::NAN));
+        assert_is_nan!(erfcinv(F::INFINITY));
+        assert_is_nan!(erfcinv(F::neg_infinity()));
+        assert_is_nan!(erfcinv(-F::one()));
+        assert_is_nan!(erfcinv(F::two() + F::one()));
+        assert_total_eq!(erfcinv(F::ZERO), F::INFINITY);
+        assert_total_eq!(erfcinv(-F::ZERO), F::INFINITY);
+        assert_total_eq!(erfcinv(F::one()), F::ZERO);
+        assert_total_eq!(erfcinv(F::two()), F::neg_infinity());
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_erfinv::<f16>();
+        test_erfcinv::<f16>();
+    }
+
+    #[test]
+    fn test_f32() {
+        test_erfinv::<f32>();
+        test_erfcinv::<f32>();
+    }
+
+    #[test]
+    fn test_f64() {
+        test_erfinv::<f64>();
+        test_erfcinv::<f64>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test_erfinv::<half::bf16>();
+        test_erfcinv::<half::bf16>();
+    }
+}
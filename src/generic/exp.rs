@@ -1,6 +1,6 @@
 use super::{round_as_i_f, scalbn_medium};
 use crate::double::DenormDouble;
-use crate::traits::{CastInto as _, Float, Int as _};
+use crate::traits::{CastInto as _, Float, Int as _, Lanes};
 
 pub(crate) trait Exp: Float {
     fn log2_e() -> Self;
@@ -11,7 +11,7 @@ pub(crate) trait Exp: Float {
     fn exp_m1_lo_th() -> Self;
     fn exp_m1_hi_th() -> Self;
 
-    fn exp_special_poly(x2: Self) -> Self;
+    fn exp_special_poly<V: Lanes<Scalar = Self>>(x2: V) -> V;
 
     fn exp_m1_special_poly(x2: Self) -> Self;
 }
@@ -59,6 +59,40 @@ pub(crate) fn exp_m1<F: Exp>(x: F) -> F {
     }
 }
 
+/// Calculates `e^x` as `(hi, lo)`
+pub(crate) fn exp_hi_lo<F: Exp>(x: F) -> (F, F) {
+    if x >= F::exp_hi_th() {
+        // also handles x = inf
+        (F::INFINITY, F::ZERO)
+    } else if x <= F::exp_lo_th() {
+        // also handles x = -inf
+        (F::ZERO, F::ZERO)
+    } else {
+        let e = x.raw_exp();
+        if e == F::RawExp::ZERO {
+            // x is zero or subnormal
+            // exp(x) ~= 1 + x
+            (F::one(), x)
+        } else if e == F::MAX_RAW_EXP {
+            // x is NaN, propagate
+            (x, F::ZERO)
+        } else {
+            let (k, r_hi, r_lo) = exp_split(x);
+            let t = hi_lo_exp_inner_common(r_hi, r_lo).to_norm();
+
+            // exp(x) = exp(r_hi + r_lo) * 2^k
+            let hi = scalbn_medium(t.hi(), k);
+            if hi == F::INFINITY {
+                // overflow
+                return (hi, F::ZERO);
+            }
+            let lo = scalbn_medium(t.lo(), k);
+            let r = DenormDouble::new_qadd11(hi, lo);
+            (r.hi(), r.lo())
+        }
+    }
+}
+
 pub(super) fn exp_inner<F: Exp>(x: F) -> F {
     // Split x into k, r_hi, r_lo such as:
     //  - x = k*ln(2) + r_hi + r_lo
@@ -72,6 +106,12 @@ pub(super) fn exp_inner<F: Exp>(x: F) -> F {
 
 /// Calculates `exp(k*ln(2) + r_hi + r_lo)`
 pub(super) fn exp_inner_common<F: Exp>(k: i32, r_hi: F, r_lo: F) -> F {
+    // exp(x) = exp(r_hi + r_lo) * 2^k
+    scalbn_medium(exp_inner_reduced(r_hi, r_lo), k)
+}
+
+/// Calculates `exp(r_hi + r_lo)`, where `|r_hi + r_lo| <= 0.5*ln(2)`
+pub(super) fn exp_inner_reduced<F: Exp, V: Lanes<Scalar = F>>(r_hi: V, r_lo: V) -> V {
     // Based on the algorithm used by the msun math library
 
     let r = r_hi + r_lo;
@@ -80,16 +120,19 @@ pub(super) fn exp_inner_common<F: Exp>(k: i32, r_hi: F, r_lo: F) -> F {
     // t1 = 2 - 2 * r / (exp(r) - 1)
     let t1 = r + F::exp_special_poly(r2);
 
-    // t2 = exp(r) = 1 + r + (r * t1) / (2 - t1)
-    //             = 1 + r_hi + r_lo + (r * t1) / (2 - t1)
-    let t2 = F::one() + (r_hi + (r_lo + r * t1 / (F::two() - t1)));
-
-    // exp(x) = exp(r_hi + r_lo) * 2^k = t2 * 2^k
-    scalbn_medium(t2, k)
+    // exp(r) = 1 + r + (r * t1) / (2 - t1)
+    //        = 1 + r_hi + r_lo + (r * t1) / (2 - t1)
+    V::splat(F::one()) + (r_hi + (r_lo + r * t1 / (V::splat(F::two()) - t1)))
 }
 
 /// Calculates `exp(r_hi + r_lo)`
 pub(super) fn hi_lo_exp_inner_common<F: Exp>(r_hi: F, r_lo: F) -> DenormDouble<F> {
+    // exp(r) = 1 + (exp(r) - 1)
+    hi_lo_exp_m1_inner_common(r_hi, r_lo).qradd1(F::one())
+}
+
+/// Calculates `exp(r_hi + r_lo) - 1`
+pub(super) fn hi_lo_exp_m1_inner_common<F: Exp>(r_hi: F, r_lo: F) -> DenormDouble<F> {
     // Based on the algorithm used by the msun math library
 
     let r = r_hi + r_lo;
@@ -103,11 +146,24 @@ pub(super) fn hi_lo_exp_inner_common<F: Exp>(r_hi: F, r_lo: F) -> DenormDouble<F
     let twomt1 = t1.qrsub1(F::two());
     let t2 = rt1.to_semi() / twomt1.to_semi();
 
-    // t3 = exp(r) = 1 + r + t2
-    let t3 = DenormDouble::new(r_hi, r_lo).qadd2(t2).qradd1(F::one());
+    // exp(r) - 1 = r + t2
+    DenormDouble::new(r_hi, r_lo).qadd2(t2)
+}
 
-    // exp(x) = exp(r_hi + r_lo) * 2^k = t2 * 2^k
-    DenormDouble::new(t3.hi(), t3.lo())
+/// Calculates `exp(k*ln(2) + r_hi + r_lo) - 1`, where `|r_hi + r_lo| <= 0.5*ln(2)`
+pub(super) fn exp_m1_inner_common<F: Exp>(k: i32, r_hi: F, r_lo: F) -> F {
+    // t1 = exp(r_hi + r_lo) - 1
+    let t1 = hi_lo_exp_m1_inner_common(r_hi, r_lo);
+    if k == 0 {
+        t1.to_single()
+    } else if k < F::MAX_EXP.into() {
+        // exp(x) - 1 = (t1 + 1) * 2^k - 1 = 2^k + t1 * 2^k - 1
+        let s = F::exp2i_fast(k.cast_into());
+        let t2 = DenormDouble::new_qadd11(s, t1.hi() * s).qadd1(t1.lo() * s);
+        (t2 - F::one()).to_single()
+    } else {
+        scalbn_medium(t1.to_single() + F::one(), k)
+    }
 }
 
 fn exp_m1_inner<F: Exp>(x: F) -> F {
@@ -171,11 +227,11 @@ pub(super) fn exp_split<F: Exp>(x: F) -> (i32, F, F) {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test_exp<F: Float + FloatMath>(lo_th: &str, hi_th: &str) {
-        use crate::exp;
+    fn test_exp<F: Float + ElementaryMath>(lo_th: &str, hi_th: &str) {
+        use crate::{exp, exp_hi_lo};
 
         let lo_th = F::parse(lo_th);
         let hi_th = F::parse(hi_th);
@@ -191,9 +247,22 @@ mod tests {
         assert_total_eq!(exp(hi_th), F::INFINITY);
         assert_total_eq!(exp(hi_th + F::one()), F::INFINITY);
         assert_total_eq!(exp(hi_th + F::two()), F::INFINITY);
+
+        let (hi, lo) = exp_hi_lo(F::NAN);
+        assert_is_nan!(hi);
+        assert_total_eq!(lo, F::ZERO);
+        assert_total_eq!(exp_hi_lo(F::INFINITY).0, F::INFINITY);
+        assert_total_eq!(exp_hi_lo(F::INFINITY).1, F::ZERO);
+        assert_total_eq!(exp_hi_lo(F::neg_infinity()).0, F::ZERO);
+        assert_total_eq!(exp_hi_lo(F::ZERO).0, F::one());
+        assert_total_eq!(exp_hi_lo(F::ZERO).1, F::ZERO);
+        assert_total_eq!(exp_hi_lo(-F::ZERO).0, F::one());
+        assert_total_eq!(exp_hi_lo(lo_th).0, F::ZERO);
+        assert_total_eq!(exp_hi_lo(hi_th).0, F::INFINITY);
+        assert_total_eq!(exp_hi_lo(hi_th).1, F::ZERO);
     }
 
-    fn test_exp_m1<F: Float + FloatMath>(lo_th: &str, hi_th: &str) {
+    fn test_exp_m1<F: Float + ElementaryMath>(lo_th: &str, hi_th: &str) {
         use crate::exp_m1;
 
         let lo_th = F::parse(lo_th);
@@ -212,6 +281,13 @@ mod tests {
         assert_total_eq!(exp_m1(hi_th + F::two()), F::INFINITY);
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_exp::<f16>("-17.34", "11.09");
+        test_exp_m1::<f16>("-8.33", "11.09");
+    }
+
     #[test]
     fn test_f32() {
         test_exp::<f32>("-103.99", "88.9");
@@ -223,4 +299,18 @@ mod tests {
         test_exp::<f64>("-745.9", "709.9");
         test_exp_m1::<f64>("-708.9", "709.9");
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test_exp::<f128>("-11433.5", "11356.6");
+        test_exp_m1::<f128>("-11356.9", "11356.6");
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test_exp::<half::bf16>("-103.99", "88.9");
+        test_exp_m1::<half::bf16>("-87.9", "88.9");
+    }
 }
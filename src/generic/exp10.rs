@@ -1,5 +1,6 @@
-use super::exp::exp_inner_common;
-use super::{Exp, round_as_i_f};
+use super::exp::{exp_inner_common, exp_m1_inner_common};
+use super::{Exp, round_as_i_f, tiny_raw_exp, tiny_scale_exp};
+use crate::double::SemiDouble;
 use crate::traits::Int as _;
 
 pub(crate) trait Exp10: Exp {
@@ -36,6 +37,43 @@ pub(crate) fn exp10<F: Exp10>(x: F) -> F {
     }
 }
 
+/// Returns 10 raised to `x` minus 1.
+pub(crate) fn exp10_m1<F: Exp10>(x: F) -> F {
+    if x >= F::exp10_hi_th() {
+        // also handles x = inf
+        F::INFINITY
+    } else if x * F::log2_10() <= -F::cast_from(i32::from(F::MANT_BITS) + 3) {
+        // 10^x - 1 rounds to -1 (with a margin for the rounding
+        // of x*log2(10))
+        // also handles x = -inf
+        -F::one()
+    } else {
+        let e = x.raw_exp();
+        if x == F::ZERO {
+            // exp10_m1(±0) = ±0
+            x
+        } else if e <= tiny_raw_exp::<F>() {
+            // very small, 10^x - 1 ~= x*ln(10)
+
+            // scale temporarily to avoid temporary subnormal numbers
+            let logscale = tiny_scale_exp::<F>();
+            let scale = F::exp2i_fast(logscale);
+            let descale = F::exp2i_fast(-logscale);
+
+            let sx = SemiDouble::new(x * scale);
+            (sx * SemiDouble::with_parts(F::ln_10_hi(), F::ln_10_lo())).to_single() * descale
+        } else if e == F::MAX_RAW_EXP {
+            // x is NaN, propagate
+            x
+        } else {
+            let (k, r_hi, r_lo) = exp10_split(x);
+
+            // Calculate 10^x - 1 = exp(k*ln(2) + r_hi + r_lo) - 1
+            exp_m1_inner_common(k, r_hi, r_lo)
+        }
+    }
+}
+
 fn exp10_inner<F: Exp10>(x: F) -> F {
     // Split x into k, r_hi, r_lo such as:
     //  - x = k*log10(2) + (r_hi + r_lo)*log10(e)
@@ -71,11 +109,11 @@ fn exp10_split<F: Exp10>(x: F) -> (i32, F, F) {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>(lo_th: &str, hi_th: &str) {
-        use crate::exp10;
+    fn test<F: Float + ElementaryMath>(lo_th: &str, hi_th: &str) {
+        use crate::{exp10, exp10_m1};
 
         let f = F::parse;
 
@@ -95,6 +133,22 @@ mod tests {
         assert_total_eq!(exp10(hi_th), F::INFINITY);
         assert_total_eq!(exp10(hi_th + F::one()), F::INFINITY);
         assert_total_eq!(exp10(hi_th + F::two()), F::INFINITY);
+
+        assert_is_nan!(exp10_m1(F::NAN));
+        assert_total_eq!(exp10_m1(F::INFINITY), F::INFINITY);
+        assert_total_eq!(exp10_m1(F::neg_infinity()), -F::one());
+        assert_total_eq!(exp10_m1(F::ZERO), F::ZERO);
+        assert_total_eq!(exp10_m1(-F::ZERO), -F::ZERO);
+        assert_total_eq!(exp10_m1(F::one()), f("9"));
+        assert_total_eq!(exp10_m1(F::two()), f("99"));
+        assert_total_eq!(exp10_m1(lo_th), -F::one());
+        assert_total_eq!(exp10_m1(hi_th), F::INFINITY);
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>("-7.53", "4.82");
     }
 
     #[test]
@@ -106,4 +160,16 @@ mod tests {
     fn test_f64() {
         test::<f64>("-323.9", "308.9");
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>("-4965.9", "4932.9");
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>("-45.9", "38.9");
+    }
 }
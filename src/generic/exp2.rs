@@ -1,5 +1,6 @@
-use super::exp::exp_inner_common;
-use super::{Exp, round_as_i_f};
+use super::exp::{exp_inner_common, exp_m1_inner_common};
+use super::{Exp, round_as_i_f, tiny_raw_exp, tiny_scale_exp};
+use crate::double::SemiDouble;
 use crate::traits::Int as _;
 
 pub(crate) trait Exp2: Exp {
@@ -31,6 +32,42 @@ pub(crate) fn exp2<F: Exp2>(x: F) -> F {
     }
 }
 
+/// Returns 2 raised to `x` minus 1.
+pub(crate) fn exp2_m1<F: Exp2>(x: F) -> F {
+    if x >= F::exp2_hi_th() {
+        // also handles x = inf
+        F::INFINITY
+    } else if x <= -F::cast_from(i32::from(F::MANT_BITS) + 2) {
+        // 2^x - 1 rounds to -1
+        // also handles x = -inf
+        -F::one()
+    } else {
+        let e = x.raw_exp();
+        if x == F::ZERO {
+            // exp2_m1(±0) = ±0
+            x
+        } else if e <= tiny_raw_exp::<F>() {
+            // very small, 2^x - 1 ~= x*ln(2)
+
+            // scale temporarily to avoid temporary subnormal numbers
+            let logscale = tiny_scale_exp::<F>();
+            let scale = F::exp2i_fast(logscale);
+            let descale = F::exp2i_fast(-logscale);
+
+            let sx = SemiDouble::new(x * scale);
+            (sx * SemiDouble::with_parts(F::ln_2_hi(), F::ln_2_lo())).to_single() * descale
+        } else if e == F::MAX_RAW_EXP {
+            // x is NaN, propagate
+            x
+        } else {
+            let (k, r_hi, r_lo) = exp2_split(x);
+
+            // Calculate 2^x - 1 = exp(k*ln(2) + r_hi + r_lo) - 1
+            exp_m1_inner_common(k, r_hi, r_lo)
+        }
+    }
+}
+
 /// Calculates `2^x` where:
 ///
 ///  * `x` is not zero, subnormal, nan nor infinity
@@ -66,11 +103,11 @@ fn exp2_split<F: Exp2>(x: F) -> (i32, F, F) {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>(lo_th: &str, hi_th: &str) {
-        use crate::exp2;
+    fn test<F: Float + ElementaryMath>(lo_th: &str, hi_th: &str) {
+        use crate::{exp2, exp2_m1};
 
         let f = F::parse;
 
@@ -95,6 +132,23 @@ mod tests {
         assert_total_eq!(exp2(hi_th), F::INFINITY);
         assert_total_eq!(exp2(hi_th + F::one()), F::INFINITY);
         assert_total_eq!(exp2(hi_th + F::two()), F::INFINITY);
+
+        assert_is_nan!(exp2_m1(F::NAN));
+        assert_total_eq!(exp2_m1(F::INFINITY), F::INFINITY);
+        assert_total_eq!(exp2_m1(F::neg_infinity()), -F::one());
+        assert_total_eq!(exp2_m1(F::ZERO), F::ZERO);
+        assert_total_eq!(exp2_m1(-F::ZERO), -F::ZERO);
+        assert_total_eq!(exp2_m1(F::one()), F::one());
+        assert_total_eq!(exp2_m1(f("4")), f("15"));
+        assert_total_eq!(exp2_m1(-F::one()), -F::half());
+        assert_total_eq!(exp2_m1(lo_th), -F::one());
+        assert_total_eq!(exp2_m1(hi_th), F::INFINITY);
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>("-25", "16");
     }
 
     #[test]
@@ -106,4 +160,16 @@ mod tests {
     fn test_f64() {
         test::<f64>("-1075", "1024");
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>("-16495", "16384");
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>("-150", "128");
+    }
 }
@@ -0,0 +1,300 @@
+use crate::traits::{CastFrom as _, Float, Int as _};
+
+pub(crate) fn fma<F: Float>(x: F, y: F, z: F) -> F {
+    if x.raw_exp() == F::MAX_RAW_EXP || y.raw_exp() == F::MAX_RAW_EXP {
+        // x or y is infinity or NaN, x * y is exact
+        (x * y) + z
+    } else if z.raw_exp() == F::MAX_RAW_EXP {
+        // z is infinity or NaN and x * y is finite
+        z
+    } else if x == F::ZERO || y == F::ZERO {
+        // x * y is an exact zero
+        (x * y) + z
+    } else {
+        fma_inner(x, y, z)
+    }
+}
+
+/// Calculates `x * y + z` with finite non-zero `x` and `y` and finite `z`
+fn fma_inner<F: Float>(x: F, y: F, z: F) -> F {
+    let (xs, xe, xm) = split(x);
+    let (ys, ye, ym) = split(y);
+
+    // x * y = pm * 2^pe, with the highest bit of pm at 2 * BITS - 2,
+    // so the addition cannot overflow
+    let pm = Wide::<F>::mul(xm, ym);
+    let shift = pm.leading_zeros() - 1;
+    let pm = pm.shl(shift);
+    let pe = xe + ye - shift as i32;
+    let ps = xs ^ ys;
+
+    let (s, e, m) = if z == F::ZERO {
+        (ps, pe, pm)
+    } else {
+        // z = zm * 2^ze, with the highest bit of zm at 2 * BITS - 2
+        let (zs, ze, zm) = split(z);
+        let shift = Wide::<F>::BITS * 2 - 2 - u32::from(F::MANT_BITS);
+        let zm = Wide::<F>::from_raw(zm).shl(shift);
+        let ze = ze - shift as i32;
+
+        // a is the operand with the largest magnitude
+        let ((sa, ea, ma), (sb, eb, mb)) = if (pe, pm) >= (ze, zm) {
+            ((ps, pe, pm), (zs, ze, zm))
+        } else {
+            ((zs, ze, zm), (ps, pe, pm))
+        };
+
+        // The lowest bits of ma are zero, so the sticky bit of mb does not
+        // change the rounding of the result.
+        let mb = mb.shr_sticky((ea - eb) as u32);
+        let m = if sa == sb { ma.add(mb) } else { ma.sub(mb) };
+        (sa, ea, m)
+    };
+
+    if m == Wide::<F>::from_raw(F::Raw::ZERO) {
+        // exact cancellation, the result is positive zero when
+        // rounding to nearest
+        return F::ZERO;
+    }
+
+    round(s, e, m)
+}
+
+/// Splits a finite non-zero `x` into `(s, e, m)` such as
+/// `x = (-1)^s * m * 2^e` and `m` has `MANT_BITS + 1` bits
+pub(super) fn split<F: Float>(x: F) -> (bool, i32, F::Raw) {
+    let (y, edelta) = x.normalize_arg();
+    let e: i32 = (y.exponent() + edelta).into();
+    (x.sign(), e - i32::from(F::MANT_BITS), y.mant())
+}
+
+/// Rounds `(-1)^s * m * 2^e` to nearest, ties to even
+fn round<F: Float>(s: bool, e: i32, m: Wide<F>) -> F {
+    let shift = m.leading_zeros();
+    let m = m.shl(shift);
+    // exponent of the highest bit of m
+    let e = e - shift as i32 + (Wide::<F>::BITS * 2 - 1) as i32;
+
+    let max_exp: i32 = F::MAX_EXP.into();
+    let min_exp: i32 = F::MIN_NORMAL_EXP.into();
+    if e > max_exp {
+        return F::INFINITY.set_sign(s);
+    }
+
+    // Keep MANT_BITS + 1 bits (less when the result is subnormal),
+    // plus a rounding bit and a sticky bit
+    let shift = Wide::<F>::BITS * 2 - u32::from(F::MANT_BITS) - 1 + (min_exp - e).max(0) as u32;
+    let t = m.shr_sticky(shift - 2).lo;
+    let q = t >> 2;
+    let round_bit = (t >> 1) & F::Raw::ONE;
+    let sticky_bit = t & F::Raw::ONE;
+
+    // When the result is normal, q includes the implicit bit, which
+    // increments the exponent. A carry from rounding also propagates to
+    // the exponent, possibly overflowing to infinity.
+    let raw_exp = F::Raw::cast_from((e - min_exp).max(0));
+    let mut raw = (raw_exp << F::MANT_BITS) + q;
+    if round_bit != F::Raw::ZERO && (sticky_bit | (q & F::Raw::ONE)) != F::Raw::ZERO {
+        raw += F::Raw::ONE;
+    }
+    F::from_raw(raw).set_sign(s)
+}
+
+/// Unsigned integer with twice the bits of `F::Raw`
+#[derive(Copy, Clone, PartialEq, PartialOrd)]
+struct Wide<F: Float> {
+    hi: F::Raw,
+    lo: F::Raw,
+}
+
+impl<F: Float> Wide<F> {
+    const BITS: u32 = F::BITS as u32;
+
+    #[inline]
+    fn from_raw(x: F::Raw) -> Self {
+        Self {
+            hi: F::Raw::ZERO,
+            lo: x,
+        }
+    }
+
+    /// Calculates `a * b` without rounding
+    #[inline]
+    fn mul(a: F::Raw, b: F::Raw) -> Self {
+        let half = F::BITS / 2;
+        let mask = F::Raw::MAX >> half;
+        let (a1, a0) = (a >> half, a & mask);
+        let (b1, b0) = (b >> half, b & mask);
+
+        let p00 = a0 * b0;
+        let mid1 = a0 * b1 + (p00 >> half);
+        let mid2 = a1 * b0 + (mid1 & mask);
+        Self {
+            hi: a1 * b1 + (mid1 >> half) + (mid2 >> half),
+            lo: (mid2 << half) | (p00 & mask),
+        }
+    }
+
+    #[inline]
+    fn leading_zeros(self) -> u32 {
+        if self.hi != F::Raw::ZERO {
+            self.hi.leading_zeros()
+        } else {
+            Self::BITS + self.lo.leading_zeros()
+        }
+    }
+
+    /// Shifts left by `n`, with `n < 2 * BITS`
+    #[inline]
+    fn shl(self, n: u32) -> Self {
+        if n == 0 {
+            self
+        } else if n >= Self::BITS {
+            Self {
+                hi: self.lo << (n - Self::BITS) as u8,
+                lo: F::Raw::ZERO,
+            }
+        } else {
+            Self {
+                hi: (self.hi << n as u8) | (self.lo >> (Self::BITS - n) as u8),
+                lo: self.lo << n as u8,
+            }
+        }
+    }
+
+    /// Shifts right by `n`, setting the lowest bit of the result if any
+    /// non-zero bit is shifted out
+    #[inline]
+    fn shr_sticky(self, n: u32) -> Self {
+        if n == 0 {
+            self
+        } else if n >= Self::BITS * 2 {
+            Self::from_raw(F::Raw::from(self != Self::from_raw(F::Raw::ZERO)))
+        } else if n >= Self::BITS {
+            let n = n - Self::BITS;
+            let lost = self.lo != F::Raw::ZERO
+                || (n != 0 && (self.hi << (Self::BITS - n) as u8) != F::Raw::ZERO);
+            Self::from_raw((self.hi >> n as u8) | F::Raw::from(lost))
+        } else {
+            let lost = (self.lo << (Self::BITS - n) as u8) != F::Raw::ZERO;
+            Self {
+                hi: self.hi >> n as u8,
+                lo: (self.lo >> n as u8) | (self.hi << (Self::BITS - n) as u8) | F::Raw::from(lost),
+            }
+        }
+    }
+
+    /// Calculates `self + rhs`, which must not overflow
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let room = F::Raw::MAX - rhs.lo;
+        if self.lo > room {
+            Self {
+                hi: self.hi + rhs.hi + F::Raw::ONE,
+                lo: self.lo - room - F::Raw::ONE,
+            }
+        } else {
+            Self {
+                hi: self.hi + rhs.hi,
+                lo: self.lo + rhs.lo,
+            }
+        }
+    }
+
+    /// Calculates `self - rhs`, with `self >= rhs`
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        if self.lo < rhs.lo {
+            Self {
+                hi: self.hi - rhs.hi - F::Raw::ONE,
+                lo: self.lo + (F::Raw::MAX - rhs.lo) + F::Raw::ONE,
+            }
+        } else {
+            Self {
+                hi: self.hi - rhs.hi,
+                lo: self.lo - rhs.lo,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ElementaryMath;
+    use crate::traits::{CastFrom as _, Float};
+
+    fn test<F: Float + ElementaryMath>() {
+        use crate::fma;
+
+        let f = F::parse;
+
+        assert_is_nan!(fma(F::NAN, F::one(), F::one()));
+        assert_is_nan!(fma(F::one(), F::NAN, F::one()));
+        assert_is_nan!(fma(F::one(), F::one(), F::NAN));
+        assert_is_nan!(fma(F::INFINITY, F::ZERO, F::one()));
+        assert_is_nan!(fma(F::ZERO, F::neg_infinity(), F::one()));
+        assert_is_nan!(fma(F::INFINITY, F::one(), F::neg_infinity()));
+        assert_is_nan!(fma(F::INFINITY, F::ZERO, F::INFINITY));
+        assert_total_eq!(fma(F::INFINITY, F::one(), F::one()), F::INFINITY);
+        assert_total_eq!(fma(F::INFINITY, -F::one(), F::one()), F::neg_infinity());
+        assert_total_eq!(fma(F::one(), F::one(), F::INFINITY), F::INFINITY);
+        assert_total_eq!(
+            fma(F::largest(), F::two(), F::neg_infinity()),
+            F::neg_infinity()
+        );
+        assert_total_eq!(fma(F::largest(), F::two(), -F::largest()), F::largest());
+        assert_total_eq!(fma(F::largest(), F::two(), F::ZERO), F::INFINITY);
+        assert_total_eq!(fma(F::ZERO, F::ZERO, F::ZERO), F::ZERO);
+        assert_total_eq!(fma(F::ZERO, -F::ZERO, F::ZERO), F::ZERO);
+        assert_total_eq!(fma(F::ZERO, -F::ZERO, -F::ZERO), -F::ZERO);
+        assert_total_eq!(fma(F::one(), F::one(), -F::one()), F::ZERO);
+        assert_total_eq!(fma(-F::one(), F::one(), F::one()), F::ZERO);
+        assert_total_eq!(fma(f("2"), f("3"), F::ZERO), f("6"));
+        assert_total_eq!(fma(f("2"), f("3"), -F::ZERO), f("6"));
+        assert_total_eq!(fma(f("2"), f("3"), f("4")), f("10"));
+        assert_total_eq!(fma(f("2"), f("3"), f("-10")), f("-4"));
+        assert_total_eq!(fma(f("-2"), f("3"), F::one()), f("-5"));
+
+        // x * y is exact only with a fused operation
+        let e = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS));
+        let x = F::one() + e;
+        let y = F::one() - e;
+        assert_total_eq!(fma(x, y, -F::one()), -(e * e));
+        assert_total_eq!(fma(x, x, -F::one()), e + e + e * e);
+
+        // subnormal result
+        let min = F::exp2i_fast(F::MIN_NORMAL_EXP);
+        assert_total_eq!(fma(min, F::half(), F::ZERO), min * F::half());
+        assert_total_eq!(fma(min, e, -F::ZERO), min * e);
+        assert_total_eq!(fma(min, e * F::half(), F::ZERO), F::ZERO);
+        assert_total_eq!(fma(-min, e * F::half(), -F::ZERO), -F::ZERO);
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
+    #[test]
+    fn test_f32() {
+        test::<f32>();
+    }
+
+    #[test]
+    fn test_f64() {
+        test::<f64>();
+    }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
+}
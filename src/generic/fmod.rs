@@ -0,0 +1,192 @@
+use super::fma::split;
+use crate::traits::{CastFrom as _, Float, Int as _};
+
+pub(crate) fn fmod<F: Float>(x: F, y: F) -> F {
+    rem_common(x, y, false).0
+}
+
+pub(crate) fn remainder<F: Float>(x: F, y: F) -> F {
+    rem_common(x, y, true).0
+}
+
+pub(crate) fn remquo<F: Float>(x: F, y: F) -> (F, i32) {
+    let (r, q) = rem_common(x, y, true);
+    let q = (q & 0x7FFF_FFFF) as i32;
+    if x.sign() != y.sign() {
+        (r, -q)
+    } else {
+        (r, q)
+    }
+}
+
+/// Calculates `x - n * y` exactly and the lowest bits of `|n|`, where `n`
+/// is `x / y` rounded towards zero or, when `nearest` is true, to nearest
+/// with ties to even
+fn rem_common<F: Float>(x: F, y: F, nearest: bool) -> (F, u32) {
+    let xexp = x.raw_exp();
+    let yexp = y.raw_exp();
+    if xexp == F::MAX_RAW_EXP
+        || (yexp == F::MAX_RAW_EXP && y.raw_mant() != F::Raw::ZERO)
+        || y == F::ZERO
+    {
+        // x is infinity or NaN, or y is NaN or zero
+        (F::NAN, 0)
+    } else if yexp == F::MAX_RAW_EXP || x == F::ZERO {
+        // y is infinity or x is zero
+        (x, 0)
+    } else {
+        let (xs, ex, mx) = split(x);
+        let (_, ey, my) = split(y);
+
+        // Long division of |x| by |y|, leaving the remainder in m * 2^e
+        // and the lowest bits of the quotient in q
+        let (mut m, e, mut q) = if ex < ey {
+            (mx, ex, 0u32)
+        } else {
+            let mut m = mx;
+            let mut q = 0u32;
+            for _ in ey..ex {
+                if m >= my {
+                    m -= my;
+                    q |= 1;
+                }
+                m <<= 1;
+                q <<= 1;
+            }
+            if m >= my {
+                m -= my;
+                q |= 1;
+            }
+            (m, ey, q)
+        };
+
+        let mut s = xs;
+        // When ey - e > 1, |x| < |y| / 2 and n = 0
+        if nearest && ey - e <= 1 {
+            // |y| = my2 * 2^e
+            let my2 = my << (ey - e) as u8;
+            let m2 = m << 1;
+            if m2 > my2 || (m2 == my2 && (q & 1) != 0) {
+                // round the quotient up
+                m = my2 - m;
+                s = !s;
+                q = q.wrapping_add(1);
+            }
+        }
+
+        (from_parts(s, m, e), q)
+    }
+}
+
+/// Returns `(-1)^s * m * 2^e`, which must be exactly representable, with
+/// `m < 2^(MANT_BITS + 1)`
+fn from_parts<F: Float>(s: bool, m: F::Raw, e: i32) -> F {
+    if m == F::Raw::ZERO {
+        return F::ZERO.set_sign(s);
+    }
+
+    // Move the highest bit of m to the implicit bit
+    let shift = m.leading_zeros() - u32::from(F::BITS - 1 - F::MANT_BITS);
+    let m = m << shift as u8;
+    // exponent of the highest bit
+    let e = e - shift as i32 + i32::from(F::MANT_BITS);
+
+    let min_exp: i32 = F::MIN_NORMAL_EXP.into();
+    let raw = if e >= min_exp {
+        // the implicit bit of m increments the exponent
+        (F::Raw::cast_from(e - min_exp) << F::MANT_BITS) + m
+    } else {
+        // subnormal
+        m >> (min_exp - e) as u8
+    };
+    F::from_raw(raw).set_sign(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ElementaryMath;
+    use crate::traits::{CastFrom as _, Float};
+
+    fn test<F: Float + ElementaryMath>() {
+        use crate::{fmod, remainder, remquo};
+
+        let f = F::parse;
+
+        let check = |x: F, y: F, expected_fmod: F, expected_rem: F, expected_quo: i32| {
+            assert_total_eq!(fmod(x, y), expected_fmod);
+            assert_total_eq!(remainder(x, y), expected_rem);
+            let (rem, quo) = remquo(x, y);
+            assert_total_eq!(rem, expected_rem);
+            assert_eq!(quo, expected_quo);
+        };
+        let check_nan = |x: F, y: F| {
+            assert_is_nan!(fmod(x, y));
+            assert_is_nan!(remainder(x, y));
+            let (rem, quo) = remquo(x, y);
+            assert_is_nan!(rem);
+            assert_eq!(quo, 0);
+        };
+
+        check_nan(F::NAN, F::one());
+        check_nan(F::one(), F::NAN);
+        check_nan(F::INFINITY, F::one());
+        check_nan(F::neg_infinity(), F::one());
+        check_nan(F::INFINITY, F::INFINITY);
+        check_nan(F::one(), F::ZERO);
+        check_nan(F::one(), -F::ZERO);
+        check_nan(F::ZERO, F::ZERO);
+
+        check(F::ZERO, F::one(), F::ZERO, F::ZERO, 0);
+        check(-F::ZERO, F::one(), -F::ZERO, -F::ZERO, 0);
+        check(F::one(), F::INFINITY, F::one(), F::one(), 0);
+        check(-F::one(), F::neg_infinity(), -F::one(), -F::one(), 0);
+
+        check(f("7"), f("2"), F::one(), -F::one(), 4);
+        check(f("-7"), f("2"), -F::one(), F::one(), -4);
+        check(f("7"), f("-2"), F::one(), -F::one(), -4);
+        check(f("5"), f("2"), F::one(), F::one(), 2);
+        check(f("6"), f("2"), F::ZERO, F::ZERO, 3);
+        check(f("-6"), f("2"), -F::ZERO, -F::ZERO, -3);
+        check(f("6.5"), f("2"), f("0.5"), f("0.5"), 3);
+        check(f("7.5"), f("2"), f("1.5"), f("-0.5"), 4);
+        check(F::one(), f("3"), F::one(), F::one(), 0);
+        check(f("2"), f("3"), f("2"), -F::one(), 1);
+        check(f("1.5"), f("3"), f("1.5"), f("1.5"), 0);
+        check(f("4.5"), f("3"), f("1.5"), f("-1.5"), 2);
+        check(F::one(), f("16"), F::one(), F::one(), 0);
+
+        // large exponent difference
+        let min =
+            F::exp2i_fast(F::MIN_NORMAL_EXP) * F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS));
+        check(F::exp2i_fast(F::MAX_EXP), min, F::ZERO, F::ZERO, 0);
+        check(F::largest(), F::largest(), F::ZERO, F::ZERO, 1);
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
+    #[test]
+    fn test_f32() {
+        test::<f32>();
+    }
+
+    #[test]
+    fn test_f64() {
+        test::<f64>();
+    }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
+}
@@ -1,12 +1,17 @@
-use super::exp::{exp_split, hi_lo_exp_inner_common};
-use super::ln::hi_lo_ln_hi_lo_inner;
-use super::scalbn;
+use super::erf::{erf_inner_small, erfc_scaled_inner};
+use super::exp::{exp_split, hi_lo_exp_inner_common, hi_lo_exp_m1_inner_common};
+use super::ln::{
+    hi_lo_ln_1p_hi_lo_inner, hi_lo_ln_hi_lo_inner, hi_lo_ln_inner, hi_lo_x_sub_ln_1p_hi_lo_inner,
+};
 use super::sin_cos::{hi_lo_cos_inner, hi_lo_sin_inner};
-use super::{Exp, Ln, ReduceHalfMulPi, SinCos, is_int, reduce_half_mul_pi};
+use super::{
+    ErfInv, Exp, Ln, ReduceHalfMulPi, SinCos, erfcinv, exp, is_int, ln, reduce_half_mul_pi, scalbn,
+    sqrt,
+};
 use crate::double::{DenormDouble, NormDouble, SemiDouble};
-use crate::traits::{Float, FloatConsts, Int as _};
+use crate::traits::{CastFrom as _, CastInto as _, Float, FloatConsts, Int as _};
 
-pub(crate) trait Gamma: FloatConsts + SinCos + ReduceHalfMulPi + Exp + Ln {
+pub(crate) trait Gamma: FloatConsts + SinCos + ReduceHalfMulPi + Exp + Ln + ErfInv {
     fn lo_th() -> Self;
     fn hi_th() -> Self;
 
@@ -17,11 +22,25 @@ pub(crate) trait Gamma: FloatConsts + SinCos + ReduceHalfMulPi + Exp + Ln {
     const POLY_OFF: u8;
 
     fn half_ln_2_pi() -> NormDouble<Self>;
+    fn euler_gamma() -> NormDouble<Self>;
 
     fn ln_gamma_poly_1(x: Self) -> (Self, Self, Self, Self);
     fn ln_gamma_poly_2(x: Self) -> (Self, Self, Self, Self);
 
     fn special_poly(x: Self) -> Self;
+
+    /// `Q(a, x)` is calculated with a series for small `a` when `x` is
+    /// below this threshold
+    fn inc_gamma_small_x_th() -> Self;
+
+    /// Temme's uniform asymptotic expansion is used when `a` is above
+    /// this threshold and `|x - a| / a` is below `inc_gamma_temme_sigma()`
+    fn inc_gamma_temme_th() -> Self;
+    fn inc_gamma_temme_sigma() -> Self;
+
+    /// Coefficients of the power series of `C_k(η)` in Temme's uniform
+    /// asymptotic expansion, indexed by `k`
+    fn inc_gamma_temme_coeffs() -> &'static [&'static [Self]];
 }
 
 pub(crate) fn gamma<F: Gamma>(x: F) -> F {
@@ -29,6 +48,9 @@ pub(crate) fn gamma<F: Gamma>(x: F) -> F {
     if e == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO {
         // gamma(±0) = ±inf
         F::INFINITY.copysign(x)
+    } else if e == F::RawExp::ZERO {
+        // subnormal, gamma(x) ~= 1/x
+        F::one() / x
     } else if x >= F::hi_th() {
         // also handles x = inf
         F::INFINITY
@@ -52,6 +74,9 @@ pub(crate) fn ln_gamma<F: Gamma>(x: F) -> (F, i8) {
     if e == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO {
         // ln_gamma(0) = inf
         (F::INFINITY, if sign { -1 } else { 1 })
+    } else if e == F::RawExp::ZERO {
+        // subnormal, ln_gamma(x) ~= -ln(|x|)
+        (-ln(x.abs()), if sign { -1 } else { 1 })
     } else if e == F::MAX_RAW_EXP {
         if !sign && x.raw_mant() == F::Raw::ZERO {
             // ln_gamma(inf) = inf
@@ -72,9 +97,47 @@ pub(crate) fn ln_gamma<F: Gamma>(x: F) -> (F, i8) {
     }
 }
 
+pub(crate) fn gamma_p<F: Gamma>(a: F, x: F) -> F {
+    if let Some((p, _)) = inc_gamma_special(a, x) {
+        p
+    } else {
+        let (r, is_q) = inc_gamma_inner(a, x);
+        if is_q {
+            inc_gamma_complement(r)
+        } else {
+            r.to_single()
+        }
+    }
+}
+
+pub(crate) fn gamma_q<F: Gamma>(a: F, x: F) -> F {
+    if let Some((_, q)) = inc_gamma_special(a, x) {
+        q
+    } else {
+        let (r, is_q) = inc_gamma_inner(a, x);
+        if is_q {
+            r.to_single()
+        } else {
+            inc_gamma_complement(r)
+        }
+    }
+}
+
+pub(crate) fn gamma_p_inv<F: Gamma>(a: F, p: F) -> F {
+    inc_gamma_inv(a, p, false)
+}
+
+pub(crate) fn gamma_q_inv<F: Gamma>(a: F, q: F) -> F {
+    inc_gamma_inv(a, q, true)
+}
+
 fn gamma_inner<F: Gamma>(x: F) -> F {
     let (y, s) = gamma_inner_common(x);
+    mul_exp(s, y)
+}
 
+/// Calculates `s * exp(y)`
+pub(super) fn mul_exp<F: Exp>(s: DenormDouble<F>, y: DenormDouble<F>) -> F {
     let (k, r_hi, r_lo) = exp_split(y.hi());
     let r_lo = r_lo + y.lo();
     let exp_y = hi_lo_exp_inner_common(r_hi, r_lo);
@@ -98,21 +161,10 @@ fn ln_gamma_inner<F: Gamma>(x: F) -> (F, i8) {
 }
 
 /// Returns `(y, s)` such as `Γ(x) = s * exp(y)`.
-fn gamma_inner_common<F: Gamma>(x: F) -> (DenormDouble<F>, DenormDouble<F>) {
+pub(super) fn gamma_inner_common<F: Gamma>(x: F) -> (DenormDouble<F>, DenormDouble<F>) {
     // For x < 0.5, use gamma reflection formula:
     // Γ(x)*Γ(1-x) = π/sin(πx) => Γ(x) = π/(sin(πx)*Γ(1-x))
-    let reflect = (x < F::half()).then(|| {
-        let (n, z) = reduce_half_mul_pi(x);
-        let sinpix = match n {
-            0 => hi_lo_sin_inner(z),
-            1 => hi_lo_cos_inner(z),
-            2 => -hi_lo_sin_inner(z),
-            3 => -hi_lo_cos_inner(z),
-            _ => unreachable!(),
-        };
-        // π / sin(πx)
-        F::pi_ex() / sinpix.to_semi()
-    });
+    let reflect = (x < F::half()).then(|| pi_div_sin_pi(x));
     // nx is always greater or equal to 0.5
     let nx = if reflect.is_some() {
         DenormDouble::new_sub11(F::one(), x)
@@ -160,11 +212,7 @@ fn gamma_inner_common<F: Gamma>(x: F) -> (DenormDouble<F>, DenormDouble<F>) {
         } else {
             nx
         };
-        let tinv = F::one() / t.to_single();
-
-        // p = P(1 / t) * (1 / t) + 1
-        let p1 = F::special_poly(tinv);
-        let p = SemiDouble::new(p1) * SemiDouble::new(tinv) + F::one();
+        let p = stirling_corr(F::one() / t.to_single());
 
         // r = (t - 0.5) * ln(t) - t + 0.5 * ln(2π)
         //   = t * (ln(t) - 1) - 0.5 * ln(t) + 0.5 * ln(2π)
@@ -193,6 +241,27 @@ fn gamma_inner_common<F: Gamma>(x: F) -> (DenormDouble<F>, DenormDouble<F>) {
     }
 }
 
+/// Calculates `π / sin(πx)`
+fn pi_div_sin_pi<F: Gamma>(x: F) -> DenormDouble<F> {
+    let (n, z) = reduce_half_mul_pi(x);
+    let sinpix = match n {
+        0 => hi_lo_sin_inner(z),
+        1 => hi_lo_cos_inner(z),
+        2 => -hi_lo_sin_inner(z),
+        3 => -hi_lo_cos_inner(z),
+        _ => unreachable!(),
+    };
+    F::pi_ex() / sinpix.to_semi()
+}
+
+/// Calculates `P(1 / t) / t + 1`, where `tinv = 1 / t`, the factor that
+/// corrects the Stirling approximation of `Γ(t)` for large `t`.
+pub(super) fn stirling_corr<F: Gamma>(tinv: F) -> DenormDouble<F> {
+    // p = P(1 / t) * (1 / t) + 1
+    let p1 = F::special_poly(tinv);
+    SemiDouble::new(p1) * SemiDouble::new(tinv) + F::one()
+}
+
 fn finish_poly<F: Float>(y: F, r: F, k1: F, k2: F, k3: F) -> DenormDouble<F> {
     let y = SemiDouble::new(y);
 
@@ -209,10 +278,600 @@ fn finish_poly<F: Float>(y: F, r: F, k1: F, k2: F, k3: F) -> DenormDouble<F> {
     y * s
 }
 
+/// Handles the special cases of the regularized incomplete gamma functions.
+///
+/// Returns `Some((P(a, x), Q(a, x)))` for the special cases, or `None`
+/// when `a` and `x` are positive and finite.
+fn inc_gamma_special<F: Gamma>(a: F, x: F) -> Option<(F, F)> {
+    let a_exp = a.raw_exp();
+    let x_exp = x.raw_exp();
+    let a_is_zero = a_exp == F::RawExp::ZERO && a.raw_mant() == F::Raw::ZERO;
+    let x_is_zero = x_exp == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO;
+    let a_is_inf = a_exp == F::MAX_RAW_EXP && a.raw_mant() == F::Raw::ZERO;
+    let x_is_inf = x_exp == F::MAX_RAW_EXP && x.raw_mant() == F::Raw::ZERO;
+
+    if (a_exp == F::MAX_RAW_EXP && !a_is_inf) || (x_exp == F::MAX_RAW_EXP && !x_is_inf) {
+        // gamma_p(NaN, x) = gamma_p(a, NaN) = NaN
+        Some((F::NAN, F::NAN))
+    } else if (a.sign() && !a_is_zero) || (x.sign() && !x_is_zero) {
+        // gamma_p(a < 0, x) = gamma_p(a, x < 0) = NaN
+        Some((F::NAN, F::NAN))
+    } else if (a_is_zero && x_is_zero) || (a_is_inf && x_is_inf) {
+        // gamma_p(0, 0) = gamma_p(inf, inf) = NaN
+        Some((F::NAN, F::NAN))
+    } else if x_is_zero || a_is_inf {
+        // gamma_p(a, 0) = gamma_p(inf, x) = 0
+        Some((F::ZERO, F::one()))
+    } else if a_is_zero || x_is_inf {
+        // gamma_p(0, x) = gamma_p(a, inf) = 1
+        Some((F::one(), F::ZERO))
+    } else {
+        None
+    }
+}
+
+/// Calculates `1 - r` for `0 <= r <= 1`
+pub(super) fn inc_gamma_complement<F: Float>(r: DenormDouble<F>) -> F {
+    let c = DenormDouble::new_qsub12(F::one(), r).to_single();
+    // avoid returning tiny negative values caused by rounding errors
+    if c < F::ZERO { F::ZERO } else { c }
+}
+
+/// Returns `(r, is_q)`, where `r` is `Q(a, x)` when `is_q` is true or
+/// `P(a, x)` otherwise.
+///
+/// `a` and `x` are positive and finite. The function that is calculated
+/// directly is chosen to avoid cancellation, the other one can be
+/// obtained as `1 - r`.
+fn inc_gamma_inner<F: Gamma>(a: F, x: F) -> (DenormDouble<F>, bool) {
+    let three = F::one() + F::two();
+
+    // P(a, x) is close to 1 when `a` is small
+    let small_a = if x < F::half() {
+        // x^a > e^-0.5
+        a * -ln(x) < F::half()
+    } else {
+        a < x * (F::half() + F::half() * F::half())
+    };
+
+    let d = DenormDouble::new_sub11(x, a);
+    if a >= F::inc_gamma_temme_th() && d.hi().abs() < a * F::inc_gamma_temme_sigma() {
+        inc_gamma_temme(a, d)
+    } else if x < F::inc_gamma_small_x_th() && small_a {
+        (inc_gamma_q_small_a(a, x), true)
+    } else if x - F::one() / (three * x) < a {
+        (inc_gamma_p_series(a, x), false)
+    } else {
+        (inc_gamma_q_cont_frac(a, x), true)
+    }
+}
+
+/// Returns `(y, s)` such as `x^a * e^(-x) / Γ(a + 1) = s * exp(y)`
+///
+/// `a` and `x` are positive and finite.
+fn inc_gamma_prefactor<F: Gamma>(a: F, x: F) -> (DenormDouble<F>, DenormDouble<F>) {
+    let ln_x = hi_lo_ln_inner_normalized(x);
+    if a >= F::th_3() {
+        // Γ(a + 1) = a * Γ(a) = P_a * a^(a + 0.5) * e^(-a) * √(2π)
+        // so
+        // x^a * e^(-x) / Γ(a + 1)
+        //   = exp(a * ln(x / a) - (x - a) - 0.5 * ln(2π) - 0.5 * ln(a)) / P_a
+        // where P_a = P(1 / a) / a + 1
+        let d = DenormDouble::new_sub11(x, a);
+        let ln_a = hi_lo_ln_inner(a, F::Exp::ZERO);
+        let t = if x > a * F::half() && x < a * F::two() {
+            // a * ln(x / a) - (x - a) = -a * (μ - ln(1 + μ))
+            // where μ = (x - a) / a, which avoids the cancellation
+            // when x is close to a
+            let mu = d / DenormDouble::new(a, F::ZERO);
+            -(hi_lo_x_sub_ln_1p_hi_lo_inner(mu.to_norm()).to_semi() * a)
+        } else {
+            (ln_x - ln_a).to_semi() * a - d
+        };
+        let y = t - F::half_ln_2_pi().to_denorm() - ln_a.pmul1(F::half());
+        let s = DenormDouble::one() / stirling_corr(F::one() / a).normalize();
+        (y, s)
+    } else {
+        // x^a * e^(-x) / Γ(a + 1) = exp(a * ln(x) - x - ln(Γ(a + 1)))
+        let y = ln_x.to_semi() * a - x - ln_gamma_1p_inner(a);
+        (y, DenormDouble::one())
+    }
+}
+
+/// Calculates `s * exp(y)`, keeping the extra precision when the result
+/// is not too small
+pub(super) fn inc_gamma_mul_exp<F: Exp>(s: DenormDouble<F>, y: DenormDouble<F>) -> DenormDouble<F> {
+    if y.hi() < F::exp_lo_th() * F::two() {
+        // also handles y = -inf
+        return DenormDouble::new(F::ZERO, F::ZERO);
+    }
+
+    let (k, r_hi, r_lo) = exp_split(y.hi());
+    let r_lo = r_lo + y.lo();
+    let exp_y = hi_lo_exp_inner_common(r_hi, r_lo);
+    let r = s.to_semi() * exp_y.to_semi();
+
+    if k >= -i32::from(F::MANT_BITS) {
+        r.pmul1(F::exp2i_fast(k.cast_into()))
+    } else {
+        DenormDouble::new(scalbn(r.to_single(), k), F::ZERO)
+    }
+}
+
+/// Calculates `ln(x)` for any positive and finite `x`, including
+/// subnormals
+pub(super) fn hi_lo_ln_inner_normalized<F: Ln>(x: F) -> DenormDouble<F> {
+    let (x, edelta) = x.normalize_arg();
+    hi_lo_ln_inner(x, edelta)
+}
+
+/// Calculates `ln(Γ(1 + a))` for `0 < a < th_3()`
+///
+/// The result keeps its relative accuracy when `a` is close to zero.
+pub(super) fn ln_gamma_1p_inner<F: Gamma>(a: F) -> DenormDouble<F> {
+    // ln(Γ(1 + a)) is small in this range, so the arguments of the
+    // polynomials are kept exact to avoid large relative errors
+    if a < F::th_1() - F::one() {
+        // 1 + a < th_1
+        // K1 ~= -γ dominates when `a` is tiny, so its rounding error
+        // is corrected to keep the relative accuracy
+        let (r, k1, k2, k3) = F::ln_gamma_poly_1(a);
+        let euler_gamma = F::euler_gamma();
+        let k1_err = (-euler_gamma.hi() - k1) - euler_gamma.lo();
+        finish_poly(a, r, k1, k2, k3).qadd1(a * k1_err)
+    } else if a < F::th_2() - F::two() {
+        // ln(Γ(1 + a)) = ln(Γ(2 + a)) - ln(1 + a)
+        // where th_1 <= 2 + a < th_2
+        let (r, k1, k2, k3) = F::ln_gamma_poly_2(a);
+        finish_poly(a, r, k1, k2, k3) - hi_lo_ln_1p_hi_lo_inner(NormDouble::with_parts(a, F::ZERO))
+    } else if a < F::half() {
+        // ln(Γ(1 + a)) = ln(πa / sin(πa)) - ln(Γ(1 - a))
+        // where 0.5 < 1 - a < th_1
+        let t = (pi_div_sin_pi(a).to_semi() * a).to_norm();
+        let (r, k1, k2, k3) = F::ln_gamma_poly_1(-a);
+        hi_lo_ln_hi_lo_inner(t, F::Exp::ZERO) - finish_poly(-a, r, k1, k2, k3)
+    } else if a < F::th_2() - F::one() {
+        // th_1 <= 1 + a < th_2, `a - 1` is exact
+        let y = a - F::one();
+        let (r, k1, k2, k3) = F::ln_gamma_poly_2(y);
+        finish_poly(y, r, k1, k2, k3)
+    } else {
+        // ln(Γ(1 + a)) = ln(a * Γ(a))
+        let (y, s) = gamma_inner_common(a);
+        let s = (s.to_semi() * a).to_norm();
+        y + hi_lo_ln_hi_lo_inner(s, F::Exp::ZERO)
+    }
+}
+
+/// Calculates `Q(a, x)` for small `a` and `x < inc_gamma_small_x_th()`
+fn inc_gamma_q_small_a<F: Gamma>(a: F, x: F) -> DenormDouble<F> {
+    // Q(a, x) = 1 - x^a / Γ(a + 1) - x^a / Γ(a) * T
+    //         = -(e^(-u) - 1) - a * e^(-u) * T
+    // where
+    // * u = ln(Γ(a + 1)) - a * ln(x)
+    // * T = sum((-x)^n / (n! * (a + n))), n >= 1
+    let ln_x = hi_lo_ln_inner_normalized(x);
+    let u = ln_gamma_1p_inner(a) - ln_x.to_semi() * a;
+
+    // e^(-u) - 1, where |u| is small
+    let (k, r_hi, r_lo) = exp_split(-u.hi());
+    let r_lo = r_lo - u.lo();
+    let exp_m1_mu = if k == 0 {
+        hi_lo_exp_m1_inner_common(r_hi, r_lo)
+    } else {
+        hi_lo_exp_inner_common(r_hi, r_lo).pmul1(F::exp2i_fast(k.cast_into())) - F::one()
+    };
+    let exp_mu = exp_m1_mu.qradd1(F::one());
+
+    let sum = inc_gamma_small_a_series(a, x);
+
+    let at = (exp_mu.to_semi() * sum.to_semi()).to_semi() * a;
+    -(exp_m1_mu + at)
+}
+
+/// Calculates `sum((-x)^n / (n! * (a + n)))`, with `n >= 1`
+///
+/// The series is alternating, so it is only used when `x` is small.
+fn inc_gamma_small_a_series<F: Gamma>(a: F, x: F) -> DenormDouble<F> {
+    // Remaining terms are negligible when they are below this threshold
+    // relative to the sum
+    let negl_th = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS * 2));
+
+    // t = (-x)^n / n!
+    let mut t = DenormDouble::new(-x, F::ZERO);
+    let mut sum = t / DenormDouble::new_add11(a, F::one());
+    let mut n = F::one();
+    loop {
+        n = n + F::one();
+        t = (t.to_semi() * -x) / DenormDouble::new(n, F::ZERO);
+        let term = t / DenormDouble::new_add11(a, n);
+        sum = sum + term;
+        if term.hi().abs() <= sum.hi().abs() * negl_th {
+            break;
+        }
+    }
+    sum
+}
+
+/// Calculates `P(a, x)` with the series
+///
+/// ```text
+/// P(a, x) = x^a * e^(-x) / Γ(a + 1) * sum(x^n / ((a + 1) * ... * (a + n)))
+/// ```
+fn inc_gamma_p_series<F: Gamma>(a: F, x: F) -> DenormDouble<F> {
+    let sum = inc_gamma_p_series_tail(a, x) + F::one();
+    let (y, s) = inc_gamma_prefactor(a, x);
+    inc_gamma_mul_exp(s * sum, y)
+}
+
+/// Calculates the terms with `n >= 1` of the series of
+/// [`inc_gamma_p_series`]
+fn inc_gamma_p_series_tail<F: Gamma>(a: F, x: F) -> DenormDouble<F> {
+    // Remaining terms are negligible when they are below this threshold
+    // relative to the sum
+    let negl_th = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS * 2));
+
+    let mut t = DenormDouble::new(x, F::ZERO) / DenormDouble::new_add11(a, F::one());
+    let mut sum = t;
+    let mut n = F::one();
+    loop {
+        n = n + F::one();
+        t = (t.to_semi() * x) / DenormDouble::new_add11(a, n);
+        sum = sum.qadd2(t);
+        if t.hi() <= (sum.hi() + F::one()) * negl_th {
+            break;
+        }
+    }
+    sum
+}
+
+/// Calculates `Q(a, x)` with the continued fraction
+///
+/// ```text
+/// Q(a, x) = x^a * e^(-x) / Γ(a) / (x + 1 - a - 1 * (1 - a) / (x + 3 - a - 2 * (2 - a) / (x + 5 - a - ...)))
+/// ```
+fn inc_gamma_q_cont_frac<F: Gamma>(a: F, x: F) -> DenormDouble<F> {
+    // The rounding errors of the double-double arithmetic prevent `delta`
+    // from getting arbitrarily close to 1, but this is already far below
+    // the precision of the result
+    let negl_th = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS + F::MANT_BITS / 2));
+    let tiny = F::exp2i_fast(F::MIN_NORMAL_EXP);
+
+    // Evaluated with the modified Lentz's method. The intermediate values
+    // are normalized on each iteration, otherwise their low parts grow
+    // until the products lose accuracy.
+    let xma = DenormDouble::new_sub11(x, a);
+    let mut f = (xma + F::one()).normalize();
+    let mut c = f;
+    let mut d = DenormDouble::new(F::ZERO, F::ZERO);
+    let mut n = F::ZERO;
+    loop {
+        n = n + F::one();
+        // a_n = n * (a - n), b_n = x - a + 2 * n + 1
+        let an = DenormDouble::new_sub11(a, n).to_semi() * n;
+        let bn = xma + (n + n + F::one());
+
+        d = (bn + an * d).normalize();
+        if d.hi() == F::ZERO {
+            d = DenormDouble::new(tiny, F::ZERO);
+        }
+        c = (bn + an / c).normalize();
+        if c.hi() == F::ZERO {
+            c = DenormDouble::new(tiny, F::ZERO);
+        }
+        d = (DenormDouble::one() / d).normalize();
+        let delta = (c * d).normalize();
+        f = (f * delta).normalize();
+        if (delta - F::one()).to_single().abs() <= negl_th {
+            break;
+        }
+    }
+
+    // Q(a, x) = a * x^a * e^(-x) / Γ(a + 1) / f
+    let (y, s) = inc_gamma_prefactor(a, x);
+    let s = (s * DenormDouble::new(a, F::ZERO)) / f;
+    inc_gamma_mul_exp(s, y)
+}
+
+/// Calculates `P(a, x)` or `Q(a, x)` using Temme's uniform asymptotic
+/// expansion, where `d = x - a`
+///
+/// Returns `(r, is_q)` like [`inc_gamma_inner`].
+fn inc_gamma_temme<F: Gamma>(a: F, d: DenormDouble<F>) -> (DenormDouble<F>, bool) {
+    // Q(a, x) = erfc(η * √(a / 2)) / 2 + R
+    // P(a, x) = erfc(-η * √(a / 2)) / 2 - R
+    // R = exp(-a * η^2 / 2) / √(2πa) * sum(C_k(η) / a^k)
+    // where
+    // * μ = x / a - 1
+    // * η^2 / 2 = μ - ln(1 + μ)
+    // * sign(η) = sign(μ)
+    let mu = (d / DenormDouble::new(a, F::ZERO)).to_norm();
+    let is_q = !mu.hi().sign();
+
+    // h = η^2 / 2
+    let h = hi_lo_x_sub_ln_1p_hi_lo_inner(mu);
+    // w = a * η^2 / 2
+    let w = (h.to_semi() * a).to_norm();
+    let eta = sqrt(F::two() * h.to_single()).copysign(mu.hi());
+
+    // sum(C_k(η) / a^k)
+    let ainv = F::one() / a;
+    let mut sum = F::ZERO;
+    for c in F::inc_gamma_temme_coeffs().iter().rev() {
+        let mut p = F::ZERO;
+        for &c in c.iter().rev() {
+            p = p * eta + c;
+        }
+        sum = sum * ainv + p;
+    }
+
+    // r = ±sum(C_k(η) / a^k) / √(2πa)
+    let r = sum * F::frac_1_sqrt_pi().hi() * sqrt(F::half() * ainv);
+    let r = if is_q { r } else { -r };
+
+    // z = |η| * √(a / 2)
+    let z = sqrt(w.hi());
+    let res = if z < F::half() {
+        // |w| < 0.25, so exp_split is not needed
+        let exp_mw = hi_lo_exp_inner_common(-w.hi(), -w.lo());
+
+        // erf(z + z_lo) ~= erf(z) + 2 / √π * exp(-z^2) * z_lo
+        // where z + z_lo = √w
+        let z_lo = if z == F::ZERO {
+            // x = a
+            F::ZERO
+        } else {
+            (w.to_denorm() - SemiDouble::new(z).square()).to_single() / (F::two() * z)
+        };
+        let erf = erf_inner_small(z).ladd(F::two() * F::frac_1_sqrt_pi().hi() * exp_mw.hi() * z_lo);
+
+        // erfc(z) / 2 + exp(-w) * r
+        DenormDouble::new_qsub12(F::half(), erf.pmul1(F::half())).qadd2(exp_mw.pmul1(r))
+    } else {
+        // erfc(z) / 2 + exp(-w) * r = exp(-w) * (erfc(z) * exp(z^2) / 2 + r)
+        let erfc_scaled = erfc_scaled_inner(z, SemiDouble::new(z).square().to_norm());
+        inc_gamma_mul_exp(erfc_scaled.pmul1(F::half()) + r, -w.to_denorm())
+    };
+
+    (res, is_q)
+}
+
+/// Calculates `x` such as `P(a, x) = t` (or `Q(a, x) = t` when `is_q`
+/// is true)
+fn inc_gamma_inv<F: Gamma>(a: F, t: F, is_q: bool) -> F {
+    let a_exp = a.raw_exp();
+    let t_exp = t.raw_exp();
+    let a_is_zero = a_exp == F::RawExp::ZERO && a.raw_mant() == F::Raw::ZERO;
+    let t_is_zero = t_exp == F::RawExp::ZERO && t.raw_mant() == F::Raw::ZERO;
+
+    if a_exp == F::MAX_RAW_EXP || t_exp == F::MAX_RAW_EXP {
+        // gamma_p_inv(NaN or inf, t) = gamma_p_inv(a, NaN or inf) = NaN
+        F::NAN
+    } else if a.sign() || a_is_zero {
+        // gamma_p_inv(a <= 0, t) = NaN
+        F::NAN
+    } else if (t.sign() && !t_is_zero) || t > F::one() {
+        // gamma_p_inv(a, t < 0 or t > 1) = NaN
+        F::NAN
+    } else if t_is_zero {
+        // gamma_p_inv(a, 0) = 0
+        // gamma_q_inv(a, 0) = inf
+        if is_q { F::INFINITY } else { F::ZERO }
+    } else if t == F::one() {
+        // gamma_p_inv(a, 1) = inf
+        // gamma_q_inv(a, 1) = 0
+        if is_q { F::ZERO } else { F::INFINITY }
+    } else if t > F::half() {
+        // Solve for the smallest of P and Q, `1 - t` is exact
+        inc_gamma_inv_inner(a, F::one() - t, !is_q)
+    } else {
+        inc_gamma_inv_inner(a, t, is_q)
+    }
+}
+
+/// Calculates `x` such as `P(a, x) = t` (or `Q(a, x) = t` when `is_q`
+/// is true) with Halley's method
+///
+/// `a` is positive and finite and `0 < t <= 0.5`.
+fn inc_gamma_inv_inner<F: Gamma>(a: F, t: F, is_q: bool) -> F {
+    if a < F::th_3() {
+        if let Some(x) = inc_gamma_inv_small_x(a, t, is_q) {
+            return x;
+        }
+    }
+
+    let mut x = inc_gamma_inv_guess(a, t, is_q);
+    if x == F::ZERO {
+        // The result is too small to be represented
+        return F::ZERO;
+    }
+
+    let tol = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS));
+    let ln_t = ln(t);
+    let ln_a = ln(a);
+
+    // The solution is always between `lo` and `hi`
+    let mut lo = F::ZERO;
+    let mut hi = F::INFINITY;
+    for _ in 0..100 {
+        let (r, r_is_q) = inc_gamma_inner(a, x);
+        let v = if r_is_q == is_q {
+            r
+        } else {
+            DenormDouble::new_qsub12(F::one(), r)
+        };
+        let f = (v - t).to_single();
+        if f == F::ZERO {
+            break;
+        }
+
+        // P(a, x) is increasing and Q(a, x) is decreasing
+        if (f > F::ZERO) != is_q {
+            hi = x;
+        } else {
+            lo = x;
+        }
+
+        // dp = P'(x) / t = x^(a - 1) * e^(-x) / Γ(a) / t
+        //    = a / x * x^a * e^(-x) / Γ(a + 1) / t
+        let (y, s) = inc_gamma_prefactor(a, x);
+        let dp = exp(y.to_single() - ln_t + ln_a - ln(x)) * s.hi();
+
+        // When `v` underflows, the step is meaningless, so bisection
+        // is used instead
+        let new_x = if dp < F::INFINITY && v.hi() != F::ZERO {
+            // Halley's method, where P''(x) / P'(x) = (a - 1) / x - 1
+            let step = (f / t) / dp;
+            let step = if is_q { -step } else { step };
+            let den = F::one() - F::half() * step * ((a - F::one()) / x - F::one());
+            let new_x = if den > F::half() && den < F::INFINITY {
+                x - step / den
+            } else {
+                x - step
+            };
+            if (new_x - x).abs() <= x * tol {
+                return new_x;
+            }
+            new_x
+        } else {
+            F::NAN
+        };
+
+        x = if new_x > lo && new_x < hi {
+            new_x
+        } else if hi == F::INFINITY {
+            // Fall back to bisection
+            let x2 = x * F::two();
+            if x2 == F::INFINITY {
+                return x;
+            }
+            x2
+        } else if lo == F::ZERO {
+            hi * F::half() * F::half()
+        } else {
+            sqrt(lo) * sqrt(hi)
+        };
+    }
+    x
+}
+
+/// Calculates `x` such as `P(a, x) = t` (or `Q(a, x) = t` when `is_q`
+/// is true) when `x` is small
+///
+/// `0 < a < th_3()` and `0 < t <= 0.5`. Returns `None` when `x` is not
+/// small.
+fn inc_gamma_inv_small_x<F: Gamma>(a: F, t: F, is_q: bool) -> Option<F> {
+    // P(a, x) = x^a / Γ(a + 1) * g(x)
+    // where g(x) = e^(-x) * sum(x^n / ((a + 1) * ... * (a + n))) ~= 1
+    // so
+    // x = exp((ln(P) + ln(Γ(a + 1)) - ln(g(x))) / a)
+    // which is solved with fixed-point iterations. Unlike Halley's method,
+    // this does not amplify the errors of P(a, x) by 1 / a, which is
+    // important when `a` is small.
+    let ln_p = if is_q {
+        hi_lo_ln_1p_hi_lo_inner(NormDouble::with_parts(-t, F::ZERO))
+    } else {
+        hi_lo_ln_inner_normalized(t)
+    };
+    let l = ln_p + ln_gamma_1p_inner(a);
+    if l.hi() < a * F::exp_lo_th() {
+        // `x` underflows. This also avoids dividing by a subnormal `a`,
+        // which can overflow and make the iterations below NaN.
+        return Some(F::ZERO);
+    }
+    let a = DenormDouble::new(a, F::ZERO);
+
+    // g(x) < 1, so this is a lower bound of ln(x)
+    // `ln_x` is normalized, since `mul_exp` needs a small low part
+    let mut ln_x = (l / a).normalize();
+    // Each iteration reduces the error by a factor of about x / (a + 1)
+    if ln_x.hi() > -F::cast_from(4u8) {
+        return None;
+    }
+
+    let mut x = F::ZERO;
+    for _ in 0..F::MANT_BITS {
+        let new_x = if ln_x.hi() < F::exp_lo_th() {
+            F::ZERO
+        } else {
+            mul_exp(DenormDouble::one(), ln_x)
+        };
+        if new_x == x {
+            break;
+        }
+        x = new_x;
+
+        // g(x) = 1 + a * sum((-x)^n / (n! * (a + n))), n >= 1
+        // which avoids the cancellation of e^(-x) and the sum, since
+        // its error would be amplified by 1 / a
+        let g_m1 = inc_gamma_small_a_series(a.hi(), x).to_semi() * a.hi();
+        let ln_g = hi_lo_ln_1p_hi_lo_inner(g_m1.to_norm());
+        ln_x = ((l - ln_g) / a).normalize();
+    }
+    Some(x)
+}
+
+/// Calculates an initial approximation for [`inc_gamma_inv_inner`]
+fn inc_gamma_inv_guess<F: Gamma>(a: F, t: F, is_q: bool) -> F {
+    let three = F::one() + F::two();
+
+    // Wilson-Hilferty approximation:
+    // x ~= a * (1 - 1 / (9a) + z / (3 * √a))^3
+    // where `z` is the quantile of the standard normal distribution
+    let z = erfcinv(F::two() * t) * F::sqrt_2();
+    let z = if is_q { z } else { -z };
+    let u = F::one() - F::one() / (three * three * a) + z / (three * sqrt(a));
+    let x_wh = a * u * u * u;
+
+    // ln(Γ(a + 1)), only needed when `a` is not large
+    let ln_gamma_1p = || {
+        if a < F::th_3() {
+            ln_gamma_1p_inner(a).to_single()
+        } else {
+            ln_gamma(a).0 + ln(a)
+        }
+    };
+
+    if is_q {
+        if a < F::th_3() {
+            // Q(a, x) ~= x^(a - 1) * e^(-x) / Γ(a) for large x, so
+            // x ~= -ln(Q * Γ(a)) + (a - 1) * ln(x)
+            let c = -ln(t) - (ln_gamma_1p() - ln(a));
+            if c > a && c > F::one() {
+                let mut x = c;
+                for _ in 0..4 {
+                    x = c + (a - F::one()) * ln(x);
+                }
+                return x;
+            }
+        }
+        if u > F::ZERO {
+            x_wh
+        } else {
+            // P(a, x) ~= x^a / Γ(a + 1) for small x
+            exp((ln(F::one() - t) + ln_gamma_1p()) / a)
+        }
+    } else {
+        // P(a, x) < x^a / Γ(a + 1), so this is a lower bound. It is also
+        // needed when `u` is small but positive, since `x_wh` can be far
+        // too small.
+        let x_small = if a < F::th_3() || u < F::half() {
+            exp((ln(t) + ln_gamma_1p()) / a)
+        } else {
+            F::ZERO
+        };
+        if u > F::ZERO && x_wh > x_small {
+            x_wh
+        } else {
+            x_small
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::FloatMath;
-    use crate::traits::Float;
+    use crate::traits::{Float, Int as _};
 
     fn test_gamma<F: Float + FloatMath>() {
         use crate::gamma;
@@ -225,12 +884,14 @@ mod tests {
         assert_total_eq!(gamma(F::INFINITY), F::INFINITY);
         assert_total_eq!(gamma(F::ZERO), F::INFINITY);
         assert_total_eq!(gamma(-F::ZERO), F::neg_infinity());
+        assert_total_eq!(gamma(F::from_raw(F::Raw::ONE)), F::INFINITY);
+        assert_total_eq!(gamma(-F::from_raw(F::Raw::ONE)), F::neg_infinity());
         assert_total_eq!(gamma(F::one()), F::one());
         assert_total_eq!(gamma(F::two()), F::one());
     }
 
     fn test_ln_gamma<F: Float + FloatMath>() {
-        use crate::ln_gamma;
+        use crate::{ln, ln_gamma};
 
         let test_nan = |x: F| {
             let (r, sign) = ln_gamma(x);
@@ -248,6 +909,8 @@ mod tests {
         test_value(F::INFINITY, F::INFINITY, 1);
         test_value(F::ZERO, F::INFINITY, 1);
         test_value(-F::ZERO, F::INFINITY, -1);
+        test_value(F::from_raw(F::Raw::ONE), -ln(F::from_raw(F::Raw::ONE)), 1);
+        test_value(-F::from_raw(F::Raw::ONE), -ln(F::from_raw(F::Raw::ONE)), -1);
         test_value(-F::one(), F::INFINITY, 0);
         test_value(-F::two(), F::INFINITY, 0);
         test_value(-F::largest(), F::INFINITY, 0);
@@ -255,15 +918,109 @@ mod tests {
         test_value(F::two(), F::ZERO, 1);
     }
 
+    fn test_gamma_p<F: Float + FloatMath>() {
+        use crate::gamma_p;
+
+        assert_is_nan!(gamma_p(F::NAN, F::one()));
+        assert_is_nan!(gamma_p(F::one(), F::NAN));
+        assert_is_nan!(gamma_p(-F::one(), F::one()));
+        assert_is_nan!(gamma_p(F::one(), -F::one()));
+        assert_is_nan!(gamma_p(F::ZERO, F::ZERO));
+        assert_is_nan!(gamma_p(F::INFINITY, F::INFINITY));
+        assert_total_eq!(gamma_p(F::one(), F::ZERO), F::ZERO);
+        assert_total_eq!(gamma_p(F::INFINITY, F::one()), F::ZERO);
+        assert_total_eq!(gamma_p(F::ZERO, F::one()), F::one());
+        assert_total_eq!(gamma_p(F::one(), F::INFINITY), F::one());
+    }
+
+    fn test_gamma_q<F: Float + FloatMath>() {
+        use crate::gamma_q;
+
+        assert_is_nan!(gamma_q(F::NAN, F::one()));
+        assert_is_nan!(gamma_q(F::one(), F::NAN));
+        assert_is_nan!(gamma_q(-F::one(), F::one()));
+        assert_is_nan!(gamma_q(F::one(), -F::one()));
+        assert_is_nan!(gamma_q(F::ZERO, F::ZERO));
+        assert_is_nan!(gamma_q(F::INFINITY, F::INFINITY));
+        assert_total_eq!(gamma_q(F::one(), F::ZERO), F::one());
+        assert_total_eq!(gamma_q(F::INFINITY, F::one()), F::one());
+        assert_total_eq!(gamma_q(F::ZERO, F::one()), F::ZERO);
+        assert_total_eq!(gamma_q(F::one(), F::INFINITY), F::ZERO);
+    }
+
+    fn test_gamma_p_inv<F: Float + FloatMath>() {
+        use crate::gamma_p_inv;
+
+        assert_is_nan!(gamma_p_inv(F::NAN, F::half()));
+        assert_is_nan!(gamma_p_inv(F::one(), F::NAN));
+        assert_is_nan!(gamma_p_inv(F::ZERO, F::half()));
+        assert_is_nan!(gamma_p_inv(-F::one(), F::half()));
+        assert_is_nan!(gamma_p_inv(F::INFINITY, F::half()));
+        assert_is_nan!(gamma_p_inv(F::one(), -F::half()));
+        assert_is_nan!(gamma_p_inv(F::one(), F::two()));
+        assert_total_eq!(gamma_p_inv(F::one(), F::ZERO), F::ZERO);
+        assert_total_eq!(gamma_p_inv(F::one(), F::one()), F::INFINITY);
+        // the result underflows
+        assert_total_eq!(gamma_p_inv(F::from_raw(F::Raw::ONE), F::half()), F::ZERO);
+        assert_total_eq!(
+            gamma_p_inv(F::from_raw(F::Raw::ONE), F::from_raw(F::Raw::ONE)),
+            F::ZERO
+        );
+    }
+
+    fn test_gamma_q_inv<F: Float + FloatMath>() {
+        use crate::gamma_q_inv;
+
+        assert_is_nan!(gamma_q_inv(F::NAN, F::half()));
+        assert_is_nan!(gamma_q_inv(F::one(), F::NAN));
+        assert_is_nan!(gamma_q_inv(F::ZERO, F::half()));
+        assert_is_nan!(gamma_q_inv(-F::one(), F::half()));
+        assert_is_nan!(gamma_q_inv(F::INFINITY, F::half()));
+        assert_is_nan!(gamma_q_inv(F::one(), -F::half()));
+        assert_is_nan!(gamma_q_inv(F::one(), F::two()));
+        assert_total_eq!(gamma_q_inv(F::one(), F::one()), F::ZERO);
+        assert_total_eq!(gamma_q_inv(F::one(), F::ZERO), F::INFINITY);
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_gamma::<f16>();
+        test_ln_gamma::<f16>();
+        test_gamma_p::<f16>();
+        test_gamma_q::<f16>();
+        test_gamma_p_inv::<f16>();
+        test_gamma_q_inv::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test_gamma::<f32>();
         test_ln_gamma::<f32>();
+        test_gamma_p::<f32>();
+        test_gamma_q::<f32>();
+        test_gamma_p_inv::<f32>();
+        test_gamma_q_inv::<f32>();
     }
 
     #[test]
     fn test_f64() {
         test_gamma::<f64>();
         test_ln_gamma::<f64>();
+        test_gamma_p::<f64>();
+        test_gamma_q::<f64>();
+        test_gamma_p_inv::<f64>();
+        test_gamma_q_inv::<f64>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test_gamma::<half::bf16>();
+        test_ln_gamma::<half::bf16>();
+        test_gamma_p::<half::bf16>();
+        test_gamma_q::<half::bf16>();
+        test_gamma_p_inv::<half::bf16>();
+        test_gamma_q_inv::<half::bf16>();
     }
 }
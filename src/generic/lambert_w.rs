@@ -0,0 +1,227 @@
+use super::ln::{hi_lo_ln_1p_hi_lo_inner, hi_lo_ln_hi_lo_inner, hi_lo_ln_inner};
+use super::{Exp, Ln, exp, ln, ln_1p, sqrt};
+use crate::double::{DenormDouble, NormDouble};
+use crate::traits::{CastFrom as _, Int as _};
+
+pub(crate) trait LambertW: Ln + Exp {
+    /// Returns `1/e`
+    fn frac_1_e() -> NormDouble<Self>;
+
+    /// Calculates an approximation of `W(x) + 1 - p`
+    ///
+    /// Where:
+    /// * `p = ±sqrt(2 * (e * x + 1))` (positive for the principal branch
+    ///   and negative for the -1 branch)
+    /// * `|p| < 0.5`
+    fn lambert_w_branch_poly(p: Self) -> Self;
+}
+
+pub(crate) fn lambert_w0<F: LambertW>(x: F) -> F {
+    let e = x.raw_exp();
+    if e == F::MAX_RAW_EXP {
+        if x.raw_mant() != F::Raw::ZERO {
+            // propagate NaN
+            x
+        } else if x.sign() {
+            // lambert_w0(-inf) = NaN
+            F::NAN
+        } else {
+            // lambert_w0(inf) = inf
+            F::INFINITY
+        }
+    } else if x.abs() < F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS + 2)) {
+        // also handles x = ±0 and subnormals
+        // W0(x) = x - x^2 + ..., where x^2 is negligible
+        x
+    } else {
+        lambert_w_inner(x, false)
+    }
+}
+
+pub(crate) fn lambert_wm1<F: LambertW>(x: F) -> F {
+    let e = x.raw_exp();
+    if e == F::MAX_RAW_EXP {
+        if x.raw_mant() != F::Raw::ZERO {
+            // propagate NaN
+            x
+        } else {
+            // lambert_wm1(±inf) = NaN
+            F::NAN
+        }
+    } else if x == F::ZERO {
+        // lambert_wm1(±0) = -inf
+        F::neg_infinity()
+    } else if !x.sign() {
+        // lambert_wm1(x) = NaN when x > 0
+        F::NAN
+    } else {
+        lambert_w_inner(x, true)
+    }
+}
+
+/// Calculates the principal branch (`m1 == false`) or the -1 branch
+/// (`m1 == true`) of the Lambert W function
+fn lambert_w_inner<F: LambertW>(x: F, m1: bool) -> F {
+    // q = x + 1/e, without cancellation near the branch point
+    let frac_1_e = F::frac_1_e();
+    let q = (DenormDouble::new(frac_1_e.hi(), frac_1_e.lo()) + x).to_single();
+    if q < F::ZERO {
+        // lambert_w(x) = NaN when x < -1/e
+        return F::NAN;
+    }
+
+    // p = ±sqrt(2 * (e * x + 1)) = ±sqrt(2 * e * q)
+    let p = sqrt(F::two() * q / frac_1_e.hi());
+
+    let sp = if m1 { -p } else { p };
+    if p < F::exp2i_fast(-F::Exp::cast_from(5)) {
+        // Very close to the branch point, the series in p is accurate
+        // enough, while a Newton iteration would amplify the error of
+        // `ln(|x|)` because g'(w) = (1 + w) / w is close to zero.
+        return (DenormDouble::new(-F::one(), F::ZERO) + sp + F::lambert_w_branch_poly(sp))
+            .to_single();
+    }
+
+    // lnx = ln(|x|)
+    let (nx, edelta) = x.normalize_arg();
+    let lnx = hi_lo_ln_inner(nx.abs(), edelta);
+    let lnx_single = lnx.to_single();
+
+    let w = if p < F::half() {
+        // Near the branch point, use the series in p, which is accurate
+        // enough for the Newton iterations, but two of them are needed
+        // because the quadratic error term is larger when w is close to -1.
+        let w = (sp - F::one()) + F::lambert_w_branch_poly(sp);
+        let w = NormDouble::with_parts(w, F::ZERO);
+        newton_step(x, lnx, newton_step(x, lnx, w))
+    } else {
+        let w = if m1 {
+            // Approximation from "Analytical approximations for real values
+            // of the Lambert W-function" (Barry et al., 2000), with the
+            // coefficients rounded to simple fractions:
+            // σ = -1 - ln(-x)
+            // W-1(x) ~= -1 - σ - 6 * (1 - 1 / (1 + sqrt(σ / 2) / (3 * (1 - σ * exp(-sqrt(σ) / 50) / 256))))
+            let sigma = -F::one() - lnx_single;
+            let sqrt_sigma = sqrt(sigma);
+            let t = F::one() - sigma * exp(-sqrt_sigma / F::cast_from(50)) / F::cast_from(256);
+            let t = F::one() + sqrt(sigma * F::half()) / (F::cast_from(3) * t);
+            -F::one() - sigma - F::cast_from(6) * (F::one() - F::one() / t)
+        } else {
+            // Approximation from "Uniform approximations for transcendental
+            // functions" (Winitzki, 2003):
+            // l = ln(1 + x)
+            // W0(x) ~= l * (1 - ln(1 + l) / (2 + l))
+            let l = ln_1p(x);
+            l * (F::one() - ln_1p(l) / (F::two() + l))
+        };
+
+        // Two Fritsch iterations are enough to reach the precision of `F`
+        // from the initial approximation
+        let w = fritsch_step(lnx_single, w);
+        let w = fritsch_step(lnx_single, w);
+        newton_step(x, lnx, NormDouble::with_parts(w, F::ZERO))
+    };
+
+    w.hi()
+}
+
+/// Performs an iteration of Fritsch's method to solve `w * e^w = x`, with
+/// fourth order convergence
+///
+/// `lnx` is `ln(|x|)`.
+fn fritsch_step<F: LambertW>(lnx: F, w: F) -> F {
+    // z = ln(x / w) - w
+    let z = lnx - ln(w.abs()) - w;
+
+    // q = 2 * (1 + w) * (1 + w + 2 * z / 3)
+    let w1 = F::one() + w;
+    let q = F::two() * w1 * (w1 + F::two() * z / F::cast_from(3));
+
+    // ε = z / (1 + w) * (q - z) / (q - 2 * z)
+    let eps = z / w1 * ((q - z) / (q - F::two() * z));
+
+    // w * (1 + ε)
+    w + w * eps
+}
+
+/// Performs a Newton iteration to solve `w + ln(w / x) = 0`, with
+/// double-float arithmetic
+///
+/// `lnx` is `ln(|x|)`.
+fn newton_step<F: LambertW>(x: F, lnx: DenormDouble<F>, w: NormDouble<F>) -> NormDouble<F> {
+    // g(w) = w + ln(w / x)
+    let ln_w_x = if w.hi().abs() < F::half() {
+        // w / x = e^-w is close to 1, so ln(|w|) - ln(|x|) would lose
+        // relative accuracy
+        // ln(w / x) = ln(1 + (w - x) / x)
+        let t = (w.to_denorm() - x) / DenormDouble::new(x, F::ZERO);
+        hi_lo_ln_1p_hi_lo_inner(t.to_norm())
+    } else {
+        // ln(w / x) = ln(|w|) - ln(|x|)
+        let absw = if w.hi() < F::ZERO { -w } else { w };
+        hi_lo_ln_hi_lo_inner(absw, F::Exp::ZERO) - lnx
+    };
+    let g = (ln_w_x + w.to_denorm()).to_single();
+
+    // g'(w) = (1 + w) / w, where 1 + w is calculated without cancellation
+    // when w is close to -1
+    let w1 = (w.to_denorm() + F::one()).to_single();
+
+    // w - g(w) / g'(w)
+    (w.to_denorm() - g * w.hi() / w1).to_norm()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FloatMath;
+    use crate::traits::Float;
+
+    fn test<F: Float + FloatMath>() {
+        use crate::{lambert_w0, lambert_wm1};
+
+        let f = F::parse;
+        let min_normal = F::exp2i_fast(F::MIN_NORMAL_EXP);
+
+        assert_is_nan!(lambert_w0(F::NAN));
+        assert_is_nan!(lambert_w0(F::neg_infinity()));
+        assert_is_nan!(lambert_w0(-F::one()));
+        assert_is_nan!(lambert_w0(f("-0.37")));
+        assert_total_eq!(lambert_w0(F::INFINITY), F::INFINITY);
+        assert_total_eq!(lambert_w0(F::ZERO), F::ZERO);
+        assert_total_eq!(lambert_w0(-F::ZERO), -F::ZERO);
+        assert_total_eq!(lambert_w0(min_normal), min_normal);
+        assert_total_eq!(lambert_w0(-min_normal), -min_normal);
+
+        assert_is_nan!(lambert_wm1(F::NAN));
+        assert_is_nan!(lambert_wm1(F::neg_infinity()));
+        assert_is_nan!(lambert_wm1(F::INFINITY));
+        assert_is_nan!(lambert_wm1(-F::one()));
+        assert_is_nan!(lambert_wm1(f("-0.37")));
+        assert_is_nan!(lambert_wm1(F::one()));
+        assert_is_nan!(lambert_wm1(min_normal));
+        assert_total_eq!(lambert_wm1(F::ZERO), F::neg_infinity());
+        assert_total_eq!(lambert_wm1(-F::ZERO), F::neg_infinity());
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
+    #[test]
+    fn test_f32() {
+        test::<f32>();
+    }
+
+    #[test]
+    fn test_f64() {
+        test::<f64>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
+}
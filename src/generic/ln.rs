@@ -1,5 +1,5 @@
 use crate::double::{DenormDouble, NormDouble, SemiDouble};
-use crate::traits::{CastInto as _, Float, Int as _};
+use crate::traits::{CastFrom as _, CastInto as _, Float, Int as _, Lanes};
 
 pub(crate) trait Ln: Float {
     fn sqrt_2() -> Self;
@@ -11,12 +11,12 @@ pub(crate) trait Ln: Float {
     /// Calculates `(ln(1 + x) - ln(1 - x) - 2 * x) / x`
     ///
     /// `-0.1716 < x < 0.1716`
-    fn ln_special_poly(x: Self) -> Self;
+    fn ln_special_poly<V: Lanes<Scalar = Self>>(x: V) -> V;
 
     /// Calculates `(ln(1 + x) - ln(1 - x) - 2 * x - (2/3) * x^3 - 0.4 * x^5) / x`
     ///
     /// `-0.1716 < x < 0.1716`
-    fn ln_special_poly_ex(x2: Self) -> Self;
+    fn ln_special_poly_ex<V: Lanes<Scalar = Self>>(x2: V) -> V;
 }
 
 pub(crate) fn ln<F: Ln>(x: F) -> F {
@@ -56,6 +56,50 @@ pub(crate) fn ln_1p<F: Ln>(x: F) -> F {
     }
 }
 
+/// Calculates `ln(x)` as `(hi, lo)`
+pub(crate) fn ln_hi_lo<F: Ln>(x: F) -> (F, F) {
+    let (y, edelta) = x.normalize_arg();
+    let yexp = y.raw_exp();
+    if yexp == F::RawExp::ZERO {
+        // ln(±0) = -inf
+        (F::neg_infinity(), F::ZERO)
+    } else if y.sign() {
+        // x < 0, ln(x) = NaN
+        (F::NAN, F::ZERO)
+    } else if yexp == F::MAX_RAW_EXP {
+        // propagate infinity or NaN
+        (y, F::ZERO)
+    } else {
+        let r = hi_lo_ln_inner(y, edelta).to_norm();
+        (r.hi(), r.lo())
+    }
+}
+
+/// Calculates `ln(1 + x)` as `(hi, lo)`
+pub(crate) fn ln_1p_hi_lo<F: Ln>(x: F) -> (F, F) {
+    let e = x.raw_exp();
+    if e == F::RawExp::ZERO {
+        // subnormal or zero, log(1 + x) ~= x
+        // also handles log(1 + (-0)) = -0
+        (x, F::ZERO)
+    } else if x == -F::one() {
+        // x = -1, log(1 + x) = -inf
+        (F::neg_infinity(), F::ZERO)
+    } else if x < -F::one() {
+        // x < -1, log(1 + x) = NaN
+        (F::NAN, F::ZERO)
+    } else if e == F::MAX_RAW_EXP {
+        // propagate infinity or NaN
+        (x, F::ZERO)
+    } else if x.exponent() < -F::Exp::cast_from(F::MANT_BITS * 2 + 4) {
+        // very small, log(1 + x) ~= x - 0.5 * x^2
+        (x, -F::half() * x * x)
+    } else {
+        let r = hi_lo_ln_1p_hi_lo_inner(NormDouble::with_parts(x, F::ZERO)).to_norm();
+        (r.hi(), r.lo())
+    }
+}
+
 /// Calculates `ln(x * 2^edelta)`
 ///
 /// `x` must be normal and positive.
@@ -70,15 +114,19 @@ pub(super) fn ln_inner<F: Ln>(x: F, edelta: F::Exp) -> F {
     //  - sqrt(2) / 2 <= 1 + r < sqrt(2)
     let (k, r) = ln_split(x, edelta);
 
+    ln_inner_common(k.cast_into(), r)
+}
+
+/// Calculates `ln(2^k * (1 + r))`, where `sqrt(2) / 2 <= 1 + r <= sqrt(2)`
+pub(super) fn ln_inner_common<F: Ln, V: Lanes<Scalar = F>>(kf: V, r: V) -> V {
     // s = r / (2 + r)
     // So, ln(1 + r) = ln(1 + s) - ln(1 - s)
-    let s = r / (F::two() + r);
+    let s = r / (V::splat(F::two()) + r);
 
     // p = (ln(1 + s) - ln(1 - s) - 2 * s) / s
     let p = F::ln_special_poly(s);
 
     // t1 = k * ln(2)
-    let kf: F = k.cast_into();
     let t1_hi = kf * F::ln_2_hi();
     let t1_lo = kf * F::ln_2_lo();
 
@@ -86,7 +134,7 @@ pub(super) fn ln_inner<F: Ln>(x: F, edelta: F::Exp) -> F {
     // where ln(1 + r) = p * s + 2 * s
     //                 = r - s * (r - p)
     //                 = r - (0.5 * r^2 - s * (0.5 * r^2 + p))
-    let hr2 = F::half() * r * r;
+    let hr2 = V::splat(F::half()) * r * r;
     (((s * (hr2 + p) + t1_lo) - hr2) + r) + t1_hi
 }
 
@@ -179,8 +227,13 @@ pub(super) fn hi_lo_ln_inner<F: Ln>(x: F, edelta: F::Exp) -> DenormDouble<F> {
     //  - sqrt(2) / 2 <= 1 + r < sqrt(2)
     let (k, r) = ln_split(x, edelta);
 
+    hi_lo_ln_inner_common(k.cast_into(), r)
+}
+
+/// Calculates `ln(2^k * (1 + r))`, where `sqrt(2) / 2 <= 1 + r <= sqrt(2)`
+pub(super) fn hi_lo_ln_inner_common<F: Ln, V: Lanes<Scalar = F>>(kf: V, r: V) -> DenormDouble<V> {
     // rp2 = 2 + r
-    let rp2 = SemiDouble::new_qadd11(F::two(), r);
+    let rp2 = SemiDouble::new_qadd11(V::splat(F::two()), r);
 
     // s = r / (2 + r)
     let s = (SemiDouble::new(r) / rp2).to_semi();
@@ -190,12 +243,11 @@ pub(super) fn hi_lo_ln_inner<F: Ln>(x: F, edelta: F::Exp) -> DenormDouble<F> {
     let p = hi_lo_ln_special_poly(s2).to_semi();
 
     // t1 = k * ln(2)
-    let kf: F = k.cast_into();
-    let t1 = DenormDouble::new(F::ln_2_hi(), F::ln_2_lo()).pmul1(kf);
+    let t1 = DenormDouble::new(V::splat(F::ln_2_hi()), V::splat(F::ln_2_lo())).pmul1(kf);
 
     // t2 = ln(1 + r) = p * s + 2 * s
     let ps = p * s;
-    let twos = s.pmul1(F::two());
+    let twos = s.pmul1(V::splat(F::two()));
     let t2 = twos.to_denorm().qadd2(ps);
 
     // ln(2^k * (1 + r)) = t1 + t2
@@ -246,21 +298,99 @@ pub(super) fn hi_lo_ln_hi_lo_inner<F: Ln>(x: NormDouble<F>, edelta: F::Exp) -> D
     t1.qadd2(t2)
 }
 
+/// Calculates ln(1 + x_hi + x_lo)
+///
+/// `1 + x` must be positive. The result keeps its relative accuracy
+/// when `x` is close to zero.
+pub(super) fn hi_lo_ln_1p_hi_lo_inner<F: Ln>(x: NormDouble<F>) -> DenormDouble<F> {
+    let xp1 = F::one() + x.hi();
+    if xp1 > F::sqrt_2() * F::half() && xp1 < F::sqrt_2() {
+        // sqrt(2) / 2 < 1 + x < sqrt(2), so ln(1 + x) can be calculated
+        // without splitting a power of two, which would cancel with
+        // ln(1 + x) when x is small.
+
+        // xp2 = 2 + x
+        let xp2 = SemiDouble::new_qadd12(F::two(), x.to_denorm());
+
+        // s = x / (2 + x)
+        let s = (x.to_semi() / xp2).to_semi();
+        let s2 = s.square().to_semi();
+
+        // p = (ln(1 + s) - ln(1 - s) - 2 * s) / s
+        let p = hi_lo_ln_special_poly(s2).to_semi();
+
+        // ln(1 + x) = p * s + 2 * s
+        let ps = p * s;
+        let twos = s.pmul1(F::two());
+        twos.to_denorm().qadd2(ps)
+    } else {
+        let xp1 = DenormDouble::new_add11(F::one(), x.hi()).ladd(x.lo());
+        hi_lo_ln_hi_lo_inner(xp1.to_norm(), F::Exp::ZERO)
+    }
+}
+
+/// Calculates `x - ln(1 + x)`
+///
+/// `1 + x` must be positive. The result keeps its relative accuracy
+/// when `x` is close to zero.
+pub(super) fn hi_lo_x_sub_ln_1p_hi_lo_inner<F: Ln>(x: NormDouble<F>) -> DenormDouble<F> {
+    let xp1 = F::one() + x.hi();
+    if xp1 > F::sqrt_2() * F::half() && xp1 < F::sqrt_2() {
+        // xp2 = 2 + x
+        let xp2 = SemiDouble::new_qadd12(F::two(), x.to_denorm());
+
+        // s = x / (2 + x)
+        let s = (x.to_semi() / xp2).to_semi();
+        let s2 = s.square().to_semi();
+
+        // p = (ln(1 + s) - ln(1 - s) - 2 * s) / s
+        //   = sum(2 * s^(2 * k) / (2 * k + 1)), for k >= 1
+        // `x - p` cancels, so the series is used instead of the polynomial
+        // approximation to get enough accuracy. s^2 < 0.03, so each term is
+        // at least 32 times smaller than the previous one, and only the first
+        // ones need extra precision.
+        let n = F::MANT_BITS * 2 / 5 + 2;
+        let mut r = F::ZERO;
+        for k in (5..=n).rev() {
+            r = r * s2.to_single() + F::two() / F::cast_from(2 * k + 1);
+        }
+        let mut p = DenormDouble::new(r, F::ZERO);
+        for k in (1..=4u8).rev() {
+            let c = DenormDouble::new_div11(F::two(), F::cast_from(2 * k + 1));
+            p = c.qadd2(p.to_semi() * s2);
+        }
+        let p = p.to_semi() * s2;
+
+        // ln(1 + x) = p * s + 2 * s, where x - 2 * s = s * x, so
+        // x - ln(1 + x) = s * (x - p)
+        let xmp = x.to_denorm().qsub2(p);
+        s * xmp.to_semi()
+    } else {
+        x.to_denorm() - hi_lo_ln_1p_hi_lo_inner(x)
+    }
+}
+
 /// Calculates `(ln(1 + x) - ln(1 - x) - 2 * x) / x`
 ///
 /// `-0.1716 < x < 0.1716`
-fn hi_lo_ln_special_poly<F: Ln>(x2: SemiDouble<F>) -> DenormDouble<F> {
+fn hi_lo_ln_special_poly<F: Ln, V: Lanes<Scalar = F>>(x2: SemiDouble<V>) -> DenormDouble<V> {
     // p0 = (p - 2/3 * x^2 - 0.4 * x^4) / x^4
     let p0 = F::ln_special_poly_ex(x2.to_single());
 
     // p1 = (p - 2/3 * x^2) / x^4 = p0 + 0.4
-    let p1 = F::frac_4_10_ex().to_denorm().qadd1(p0).to_semi();
+    let p1 = NormDouble::splat(F::frac_4_10_ex())
+        .to_denorm()
+        .qadd1(p0)
+        .to_semi();
 
     // p2 = (p - 2/3 * x^2) / x^2 = p1 * x2
     let p2 = p1 * x2;
 
     // p3 = p / x^2 = p2 + 2/3
-    let p3 = F::frac_2_3_ex().to_denorm().qadd2(p2).to_semi();
+    let p3 = NormDouble::splat(F::frac_2_3_ex())
+        .to_denorm()
+        .qadd2(p2)
+        .to_semi();
 
     // (log(1 + x) - log(1 - x) - 2 * x) / x = p3 * x2
     p3 * x2
@@ -268,11 +398,11 @@ fn hi_lo_ln_special_poly<F: Ln>(x2: SemiDouble<F>) -> DenormDouble<F> {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test_ln<F: Float + FloatMath>() {
-        use crate::ln;
+    fn test_ln<F: Float + ElementaryMath>() {
+        use crate::{ln, ln_hi_lo};
 
         assert_is_nan!(ln(F::NAN));
         assert_is_nan!(ln(-F::one()));
@@ -280,10 +410,20 @@ mod tests {
         assert_total_eq!(ln(F::ZERO), F::neg_infinity());
         assert_total_eq!(ln(-F::ZERO), F::neg_infinity());
         assert_total_eq!(ln(F::INFINITY), F::INFINITY);
+
+        assert_is_nan!(ln_hi_lo(F::NAN).0);
+        assert_is_nan!(ln_hi_lo(-F::one()).0);
+        assert_total_eq!(ln_hi_lo(-F::one()).1, F::ZERO);
+        assert_total_eq!(ln_hi_lo(F::ZERO).0, F::neg_infinity());
+        assert_total_eq!(ln_hi_lo(-F::ZERO).0, F::neg_infinity());
+        assert_total_eq!(ln_hi_lo(F::INFINITY).0, F::INFINITY);
+        assert_total_eq!(ln_hi_lo(F::INFINITY).1, F::ZERO);
+        assert_total_eq!(ln_hi_lo(F::one()).0, F::ZERO);
+        assert_total_eq!(ln_hi_lo(F::one()).1, F::ZERO);
     }
 
-    fn test_ln_1p<F: Float + FloatMath>() {
-        use crate::ln_1p;
+    fn test_ln_1p<F: Float + ElementaryMath>() {
+        use crate::{ln_1p, ln_1p_hi_lo};
 
         assert_is_nan!(ln_1p(F::NAN));
         assert_is_nan!(ln_1p(-(F::one() + F::half())));
@@ -292,6 +432,22 @@ mod tests {
         assert_total_eq!(ln_1p(-F::ZERO), -F::ZERO);
         assert_total_eq!(ln_1p(F::ZERO), F::ZERO);
         assert_total_eq!(ln_1p(F::INFINITY), F::INFINITY);
+
+        assert_is_nan!(ln_1p_hi_lo(F::NAN).0);
+        assert_is_nan!(ln_1p_hi_lo(F::neg_infinity()).0);
+        assert_total_eq!(ln_1p_hi_lo(-F::one()).0, F::neg_infinity());
+        assert_total_eq!(ln_1p_hi_lo(-F::one()).1, F::ZERO);
+        assert_total_eq!(ln_1p_hi_lo(-F::ZERO).0, -F::ZERO);
+        assert_total_eq!(ln_1p_hi_lo(F::ZERO).0, F::ZERO);
+        assert_total_eq!(ln_1p_hi_lo(F::ZERO).1, F::ZERO);
+        assert_total_eq!(ln_1p_hi_lo(F::INFINITY).0, F::INFINITY);
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_ln::<f16>();
+        test_ln_1p::<f16>();
     }
 
     #[test]
@@ -305,4 +461,18 @@ mod tests {
         test_ln::<f64>();
         test_ln_1p::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test_ln::<f128>();
+        test_ln_1p::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test_ln::<half::bf16>();
+        test_ln_1p::<half::bf16>();
+    }
 }
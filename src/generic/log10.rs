@@ -1,5 +1,5 @@
-use super::{Ln, ln::ln_split};
-use crate::double::{DenormDouble, SemiDouble};
+use super::{Ln, ln::ln_split, ln_1p_hi_lo, tiny_raw_exp, tiny_scale_exp};
+use crate::double::{DenormDouble, NormDouble, SemiDouble};
 use crate::traits::{CastInto as _, Int as _};
 
 pub(crate) trait Log10: Ln {
@@ -30,6 +30,32 @@ pub(crate) fn log10<F: Log10>(x: F) -> F {
     }
 }
 
+pub(crate) fn log10_1p<F: Log10>(x: F) -> F {
+    if x != F::ZERO && x.raw_exp() <= tiny_raw_exp::<F>() {
+        // very small, log10(1 + x) ~= x * log10(e)
+
+        // scale temporarily to avoid temporary subnormal numbers
+        let logscale = tiny_scale_exp::<F>();
+        let scale = F::exp2i_fast(logscale);
+        let descale = F::exp2i_fast(-logscale);
+
+        let sx = SemiDouble::new(x * scale);
+        return (sx * F::log10_e_ex()).to_single() * descale;
+    }
+
+    let (hi, lo) = ln_1p_hi_lo(x);
+    if hi == F::ZERO || hi.raw_exp() == F::MAX_RAW_EXP {
+        // log10(1 + (±0)) = ±0
+        // or
+        // propagate infinity or NaN
+        hi
+    } else {
+        // log10(1 + x) = ln(1 + x) * log10(e)
+        let t = NormDouble::with_parts(hi, lo).to_semi() * F::log10_e_ex();
+        t.to_single()
+    }
+}
+
 /// Calculates `log10(x)`
 ///
 /// `x` must be finite normal and positive.
@@ -74,11 +100,13 @@ fn log10_inner<F: Log10>(x: F, edelta: F::Exp) -> F {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>() {
-        use crate::log10;
+    fn test<F: Float + ElementaryMath>() {
+        use crate::{log10, log10_1p};
+
+        let f = F::parse;
 
         assert_is_nan!(log10(F::NAN));
         assert_is_nan!(log10(-F::one()));
@@ -86,6 +114,22 @@ mod tests {
         assert_total_eq!(log10(F::ZERO), F::neg_infinity());
         assert_total_eq!(log10(-F::ZERO), F::neg_infinity());
         assert_total_eq!(log10(F::INFINITY), F::INFINITY);
+
+        assert_is_nan!(log10_1p(F::NAN));
+        assert_is_nan!(log10_1p(-F::two()));
+        assert_is_nan!(log10_1p(F::neg_infinity()));
+        assert_total_eq!(log10_1p(-F::one()), F::neg_infinity());
+        assert_total_eq!(log10_1p(F::ZERO), F::ZERO);
+        assert_total_eq!(log10_1p(-F::ZERO), -F::ZERO);
+        assert_total_eq!(log10_1p(F::INFINITY), F::INFINITY);
+        assert_total_eq!(log10_1p(f("9")), F::one());
+        assert_total_eq!(log10_1p(f("99")), F::two());
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
     }
 
     #[test]
@@ -97,4 +141,16 @@ mod tests {
     fn test_f64() {
         test::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
 }
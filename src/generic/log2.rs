@@ -1,5 +1,5 @@
-use super::{Ln, ln::ln_split};
-use crate::double::{DenormDouble, SemiDouble};
+use super::{Ln, ln::ln_split, ln_1p_hi_lo, tiny_raw_exp, tiny_scale_exp};
+use crate::double::{DenormDouble, NormDouble, SemiDouble};
 use crate::traits::{CastInto as _, Int as _};
 
 pub(crate) trait Log2: Ln {
@@ -28,6 +28,32 @@ pub(crate) fn log2<F: Log2>(x: F) -> F {
     }
 }
 
+pub(crate) fn log2_1p<F: Log2>(x: F) -> F {
+    if x != F::ZERO && x.raw_exp() <= tiny_raw_exp::<F>() {
+        // very small, log2(1 + x) ~= x * log2(e)
+
+        // scale temporarily to avoid temporary subnormal numbers
+        let logscale = tiny_scale_exp::<F>();
+        let scale = F::exp2i_fast(logscale);
+        let descale = F::exp2i_fast(-logscale);
+
+        let sx = SemiDouble::new(x * scale);
+        return (sx * F::log2_e_ex()).to_single() * descale;
+    }
+
+    let (hi, lo) = ln_1p_hi_lo(x);
+    if hi == F::ZERO || hi.raw_exp() == F::MAX_RAW_EXP {
+        // log2(1 + (±0)) = ±0
+        // or
+        // propagate infinity or NaN
+        hi
+    } else {
+        // log2(1 + x) = ln(1 + x) * log2(e)
+        let t = NormDouble::with_parts(hi, lo).to_semi() * F::log2_e_ex();
+        t.to_single()
+    }
+}
+
 /// Calculates `log2(x)`
 ///
 /// `x` must be normal and positive.
@@ -68,11 +94,13 @@ fn log2_inner<F: Log2>(x: F, edelta: F::Exp) -> F {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>() {
-        use crate::log2;
+    fn test<F: Float + ElementaryMath>() {
+        use crate::{log2, log2_1p};
+
+        let f = F::parse;
 
         assert_is_nan!(log2(F::NAN));
         assert_is_nan!(log2(-F::one()));
@@ -80,6 +108,23 @@ mod tests {
         assert_total_eq!(log2(F::ZERO), F::neg_infinity());
         assert_total_eq!(log2(-F::ZERO), F::neg_infinity());
         assert_total_eq!(log2(F::INFINITY), F::INFINITY);
+
+        assert_is_nan!(log2_1p(F::NAN));
+        assert_is_nan!(log2_1p(-F::two()));
+        assert_is_nan!(log2_1p(F::neg_infinity()));
+        assert_total_eq!(log2_1p(-F::one()), F::neg_infinity());
+        assert_total_eq!(log2_1p(F::ZERO), F::ZERO);
+        assert_total_eq!(log2_1p(-F::ZERO), -F::ZERO);
+        assert_total_eq!(log2_1p(F::INFINITY), F::INFINITY);
+        assert_total_eq!(log2_1p(F::one()), F::one());
+        assert_total_eq!(log2_1p(f("3")), F::two());
+        assert_total_eq!(log2_1p(-F::half()), -F::one());
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
     }
 
     #[test]
@@ -91,4 +136,16 @@ mod tests {
     fn test_f64() {
         test::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
 }
@@ -1,4 +1,4 @@
-use crate::traits::{Float, Int as _};
+use crate::traits::{CastFrom as _, Float, Int as _};
 
 mod acosh;
 mod asin_acos;
@@ -9,19 +9,31 @@ mod atan;
 mod atand;
 mod atanh;
 mod atanpi;
+mod bessel;
+mod beta;
 mod cbrt;
 mod ceil;
+mod complex;
+mod compound;
+mod cr;
 mod div_pi;
+mod double_float;
+mod erf;
+mod erfinv;
 mod exp;
 mod exp10;
 mod exp2;
 mod floor;
+mod fma;
+mod fmod;
 mod frexp;
 mod gamma;
 mod hypot;
+mod lambert_w;
 mod ln;
 mod log10;
 mod log2;
+mod polygamma;
 mod pow;
 mod powi;
 mod rad_to_deg;
@@ -29,18 +41,22 @@ mod reduce_90_deg;
 mod reduce_half_mul_pi;
 mod reduce_pi_2;
 mod reduce_pi_2_large;
+mod rootn;
 mod round;
 mod scalbn;
+mod simd;
 mod sin_cos;
 mod sind_cosd;
 mod sinh_cosh;
 mod sinpi_cospi;
+mod slice;
 mod sqrt;
 mod tan;
 mod tand;
 mod tanh;
 mod tanpi;
 mod trunc;
+mod zeta;
 
 pub(crate) use acosh::acosh;
 pub(crate) use asin_acos::{AsinAcos, acos, asin};
@@ -51,37 +67,80 @@ pub(crate) use atan::{Atan, atan, atan2};
 pub(crate) use atand::{atan2d, atand};
 pub(crate) use atanh::atanh;
 pub(crate) use atanpi::{atan2pi, atanpi};
+pub(crate) use bessel::{Bessel, j0, j1, jn, y0, y1, yn};
+pub(crate) use beta::{beta, beta_inc, beta_inc_inv, ln_beta};
 pub(crate) use cbrt::{Cbrt, cbrt};
 pub(crate) use ceil::ceil;
+pub(crate) use complex::{
+    c_acos, c_acosh, c_add, c_asin, c_asinh, c_atan, c_atanh, c_conj, c_cos, c_cosh, c_div, c_exp,
+    c_ln, c_mul, c_neg, c_pow, c_sin, c_sinh, c_sqrt, c_sub, c_tan, c_tanh,
+};
+pub(crate) use compound::compound;
+pub(crate) use cr::{
+    cr_acos, cr_asin, cr_atan, cr_atan2, cr_cbrt, cr_cos, cr_cosh, cr_exp, cr_exp_m1, cr_exp2,
+    cr_exp10, cr_hypot, cr_ln, cr_ln_1p, cr_log2, cr_log10, cr_pow, cr_sin, cr_sinh, cr_tan,
+    cr_tanh,
+};
 pub(crate) use div_pi::DivPi;
-pub(crate) use exp::{Exp, exp, exp_m1};
-pub(crate) use exp2::{Exp2, exp2};
-pub(crate) use exp10::{Exp10, exp10};
+pub(crate) use double_float::{
+    DoubleFloatMath, dd_add, dd_div, dd_exp, dd_from, dd_ln, dd_mul, dd_neg, dd_new, dd_sin_cos,
+    dd_sqrt, dd_sub,
+};
+pub(crate) use erf::{Erf, erf, erfc};
+pub(crate) use erfinv::{ErfInv, erfcinv, erfinv};
+pub(crate) use exp::{Exp, exp, exp_hi_lo, exp_m1};
+pub(crate) use exp2::{Exp2, exp2, exp2_m1};
+pub(crate) use exp10::{Exp10, exp10, exp10_m1};
 pub(crate) use floor::floor;
+pub(crate) use fma::fma;
+pub(crate) use fmod::{fmod, remainder, remquo};
 pub(crate) use frexp::frexp;
-pub(crate) use gamma::{Gamma, gamma, ln_gamma};
+pub(crate) use gamma::{Gamma, gamma, gamma_p, gamma_p_inv, gamma_q, gamma_q_inv, ln_gamma};
 pub(crate) use hypot::hypot;
-pub(crate) use ln::{Ln, ln, ln_1p};
-pub(crate) use log2::{Log2, log2};
-pub(crate) use log10::{Log10, log10};
-pub(crate) use pow::pow;
-pub(crate) use powi::powi;
+pub(crate) use lambert_w::{LambertW, lambert_w0, lambert_wm1};
+pub(crate) use ln::{Ln, ln, ln_1p, ln_1p_hi_lo, ln_hi_lo};
+pub(crate) use log2::{Log2, log2, log2_1p};
+pub(crate) use log10::{Log10, log10, log10_1p};
+pub(crate) use polygamma::{Polygamma, digamma, polygamma};
+pub(crate) use pow::{pow, powr};
+pub(crate) use powi::{powi, pown};
 pub(crate) use rad_to_deg::RadToDeg;
 pub(crate) use reduce_90_deg::{Reduce90Deg, reduce_90_deg};
 pub(crate) use reduce_half_mul_pi::{ReduceHalfMulPi, reduce_half_mul_pi};
-pub(crate) use reduce_pi_2::{ReducePi2, reduce_pi_2};
+pub(crate) use reduce_pi_2::{ReducePi2, reduce_pi_2, rem_pio2, wrap_angle};
+pub(crate) use rootn::rootn;
 pub(crate) use round::{round, round_as_i_f};
 pub(crate) use scalbn::{scalbn, scalbn_medium};
-pub(crate) use sin_cos::{SinCos, cos, sin, sin_cos};
+pub(crate) use simd::{simd_atan2, simd_exp, simd_ln, simd_pow, simd_sin_cos};
+pub(crate) use sin_cos::{SinCos, cos, sin, sin_cos, sin_cos_hi_lo};
 pub(crate) use sind_cosd::{cosd, sind, sind_cosd};
 pub(crate) use sinh_cosh::{SinhCosh, cosh, sinh, sinh_cosh};
 pub(crate) use sinpi_cospi::{cospi, sinpi, sinpi_cospi};
-pub(crate) use sqrt::sqrt;
+pub(crate) use slice::{slice_cos, slice_exp, slice_ln, slice_sin, slice_sin_cos};
+pub(crate) use sqrt::{rsqrt, sqrt};
 pub(crate) use tan::{Tan, tan};
 pub(crate) use tand::tand;
 pub(crate) use tanh::tanh;
 pub(crate) use tanpi::tanpi;
 pub(crate) use trunc::trunc;
+pub(crate) use zeta::{hurwitz_zeta, zeta};
+
+/// Returns the largest raw exponent of the arguments that are small enough
+/// to approximate a function with the first term of its Taylor series
+///
+/// The threshold is also limited by the size of the exponent range, which is
+/// relevant for types such as `f16`.
+#[inline]
+fn tiny_raw_exp<F: Float>() -> F::RawExp {
+    F::RawExp::from(F::MANT_BITS).min(F::EXP_OFFSET - F::RawExp::from(F::MANT_BITS / 2 + 3))
+}
+
+/// Returns the exponent of the factor used to scale temporarily the
+/// arguments below [`tiny_raw_exp`] to avoid subnormal numbers
+#[inline]
+fn tiny_scale_exp<F: Float>() -> F::Exp {
+    (F::Exp::TWO * F::Exp::cast_from(F::MANT_BITS)).min(-F::MIN_NORMAL_EXP)
+}
 
 fn is_int<F: Float>(x: F) -> bool {
     let e = x.raw_exp();
@@ -0,0 +1,420 @@
+use super::gamma::{gamma_inner_common, mul_exp};
+use super::ln::{hi_lo_ln_hi_lo_inner, hi_lo_ln_inner};
+use super::sin_cos::{hi_lo_cos_inner, hi_lo_sin_inner};
+use super::{Gamma, is_int, reduce_half_mul_pi, round};
+use crate::double::{DenormDouble, NormDouble, SemiDouble};
+use crate::traits::{CastFrom as _, Float, Int as _};
+
+pub(crate) trait Polygamma: Gamma {
+    fn digamma_asympt_th() -> Self;
+
+    /// Returns the positive root of the digamma function, `x0`
+    fn digamma_root() -> NormDouble<Self>;
+
+    /// Calculates `(r, k1)` such as `ψ(x0 + x) ~= x * (k1 + r)`
+    ///
+    /// Where:
+    /// * `x0` is the positive root of the digamma function
+    /// * `|x| <= 1/16`
+    fn digamma_root_poly(x: Self) -> (Self, NormDouble<Self>);
+
+    /// Calculates an approximation of `(ln(t) - 1/(2*t) - ψ(t)) * t^2`
+    ///
+    /// Where:
+    /// * `x = 1/t^2`
+    /// * `t >= digamma_asympt_th()`
+    fn digamma_asympt_poly(x: Self) -> Self;
+
+    /// Returns the coefficients `B_2j / (2j)!` used in the
+    /// Euler-Maclaurin summation formula, starting with `j = 1`
+    fn polygamma_em_coeffs() -> &'static [Self];
+}
+
+pub(crate) fn digamma<F: Polygamma>(x: F) -> F {
+    let e = x.raw_exp();
+    if e == F::MAX_RAW_EXP {
+        if x.raw_mant() != F::Raw::ZERO {
+            // propagate NaN
+            x
+        } else if x.sign() {
+            // digamma(-inf) = NaN
+            F::NAN
+        } else {
+            // digamma(inf) = inf
+            F::INFINITY
+        }
+    } else if e == F::RawExp::ZERO {
+        if x.raw_mant() == F::Raw::ZERO {
+            // digamma(±0) = ∓inf
+            F::INFINITY.copysign(-x)
+        } else {
+            // subnormal, digamma(x) ~= -1/x
+            -(F::one() / x)
+        }
+    } else if x.sign() {
+        if is_int(x) {
+            // digamma(negative integer) = NaN
+            F::NAN
+        } else {
+            digamma_neg(x)
+        }
+    } else {
+        digamma_pos(DenormDouble::new(x, F::ZERO)).to_single()
+    }
+}
+
+pub(crate) fn polygamma<F: Polygamma>(n: i32, x: F) -> F {
+    let e = x.raw_exp();
+    // ψ^(n)(x) = (-1)^(n + 1) * n! * ζ(n + 1, x), where ζ is the
+    // Hurwitz zeta function
+    let n_odd = (n & 1) != 0;
+    if n == 0 {
+        digamma(x)
+    } else if n < 0 {
+        // polygamma(n, x) = NaN for n < 0
+        F::NAN
+    } else if e == F::MAX_RAW_EXP {
+        if x.raw_mant() != F::Raw::ZERO {
+            // propagate NaN
+            x
+        } else if x.sign() {
+            // polygamma(n, -inf) = NaN
+            F::NAN
+        } else {
+            // polygamma(n, inf) = 0 for odd n
+            // polygamma(n, inf) = -0 for even n
+            if n_odd { F::ZERO } else { -F::ZERO }
+        }
+    } else if e == F::RawExp::ZERO {
+        // polygamma(n, x) ~= (-1)^(n + 1) * n! / x^(n + 1) for x near zero,
+        // which overflows for subnormal x
+        if n_odd {
+            F::INFINITY
+        } else {
+            F::INFINITY.copysign(-x)
+        }
+    } else if x.sign() {
+        if is_int(x) {
+            if n_odd {
+                // polygamma(n, negative integer) = inf for odd n
+                F::INFINITY
+            } else {
+                // polygamma(n, negative integer) = NaN for even n
+                F::NAN
+            }
+        } else if n == 1 {
+            trigamma_neg(x)
+        } else {
+            polygamma_neg(n as u32, x)
+        }
+    } else {
+        polygamma_pos(n as u32, x)
+    }
+}
+
+/// Calculates `ψ(x)` for `x > 0`
+pub(super) fn digamma_pos<F: Polygamma>(x: DenormDouble<F>) -> DenormDouble<F> {
+    let root = F::digamma_root();
+    let root_w = F::half() * F::half() * F::half() * F::half();
+
+    // `x.hi() - root.hi()` is exact when both are close
+    let d_hi = x.hi() - root.hi();
+    if d_hi.abs() <= root_w {
+        // Close to the positive root, calculate with a polynomial
+        // to keep relative precision:
+        // ψ(x) = d * (k1 + r), where d = x - x0
+        let d = DenormDouble::new_add11(d_hi, x.lo() - root.lo());
+        let (r, k1) = F::digamma_root_poly(d.hi());
+        d.to_semi() * SemiDouble::new_qadd21(k1.to_denorm(), r)
+    } else {
+        // Use the recurrence relation ψ(x) = ψ(x + 1) - 1/x until
+        // reaching the range of the asymptotic expansion
+        let th = F::digamma_asympt_th();
+        let mut t = x;
+        let mut sum = DenormDouble::new(F::ZERO, F::ZERO);
+        while t.hi() < th {
+            sum = sum + DenormDouble::one() / t;
+            t = t + F::one();
+        }
+        digamma_asympt(t) - sum
+    }
+}
+
+/// Calculates `ψ(x)` for `x >= digamma_asympt_th()`
+fn digamma_asympt<F: Polygamma>(x: DenormDouble<F>) -> DenormDouble<F> {
+    let ln_x = hi_lo_ln_hi_lo_inner(x.to_norm(), F::Exp::ZERO);
+    if x.hi() >= F::exp2i_fast(F::Exp::cast_from(F::MANT_BITS)) {
+        // ψ(x) ~= ln(x), the remaining terms are negligible
+        ln_x
+    } else {
+        // ψ(x) = ln(x) - 1/(2*x) - P(1/x^2) / x^2
+        let u = DenormDouble::one() / x;
+        let v = u.hi() * u.hi();
+        let p = F::digamma_asympt_poly(v);
+        ln_x - u.pmul1(F::half()).qadd1(v * p)
+    }
+}
+
+/// Calculates `ψ(x)` for negative non-integer `x`
+fn digamma_neg<F: Polygamma>(x: F) -> F {
+    // Use the reflection formula:
+    // ψ(x) = ψ(1 - x) - π / tan(πx)
+    let (n, z) = reduce_half_mul_pi(x);
+    let sin = hi_lo_sin_inner(z);
+    let cos = hi_lo_cos_inner(z);
+    let (num, den) = if (n & 1) == 0 {
+        // 1 / tan(πx) = cos(z) / sin(z)
+        (cos, sin)
+    } else {
+        // 1 / tan(πx) = -sin(z) / cos(z)
+        (-sin, cos)
+    };
+    // `den` is normalized because the division does not account for
+    // large low parts in the divisor
+    let pi_cot = (F::pi_ex() * num.to_semi()) / den.normalize();
+
+    let psi_1mx = digamma_pos(DenormDouble::new_sub11(F::one(), x));
+
+    (psi_1mx - pi_cot).to_single()
+}
+
+/// Calculates `ψ^(n)(x)` for `x > 0` and `n >= 1`
+pub(super) fn polygamma_pos<F: Polygamma>(n: u32, x: F) -> F {
+    let z = hurwitz_zeta_scaled(n + 1, DenormDouble::new(x, F::ZERO), x);
+    polygamma_finish(n, x, z)
+}
+
+/// Calculates `ψ^(1)(x)` for negative non-integer `x`
+fn trigamma_neg<F: Polygamma>(x: F) -> F {
+    // Use the reflection formula:
+    // ψ^(1)(x) = π^2 / sin^2(πx) - ψ^(1)(1 - x)
+    let (n, z) = reduce_half_mul_pi(x);
+    let sin = if (n & 1) == 0 {
+        hi_lo_sin_inner(z)
+    } else {
+        hi_lo_cos_inner(z)
+    };
+    let pi_csc = F::pi_ex() / sin.to_semi();
+
+    let psi_1mx = polygamma_pos(1, F::one() - x);
+
+    (pi_csc.to_semi().square() - psi_1mx).to_single()
+}
+
+/// Calculates `ψ^(n)(x)` for negative non-integer `x` and `n >= 2`
+fn polygamma_neg<F: Polygamma>(n: u32, x: F) -> F {
+    // With x = m + f, where m is an integer and 0 < f < 1:
+    // ζ(s, x) = ζ(s, f) + (-1)^s * (ζ(s, 1 - f) - ζ(s, 1 - x))
+    let s = n + 1;
+
+    // delta is the distance from x to the nearest integer
+    let f_signed = x - round(x);
+    let delta = f_signed.abs();
+    let (f, g) = if f_signed.sign() {
+        (
+            DenormDouble::new_add11(F::one(), f_signed),
+            DenormDouble::new(delta, F::ZERO),
+        )
+    } else {
+        (
+            DenormDouble::new(delta, F::ZERO),
+            DenormDouble::new_sub11(F::one(), delta),
+        )
+    };
+    let one_m_x = DenormDouble::new_sub11(F::one(), x);
+
+    // ζ(s, f) and ζ(s, 1 - f) are combined first, since they cancel
+    // exactly when `f = 1/2` and odd `s`, and `ζ(s, 1 - x)` can be much
+    // smaller than both
+    let z_f = hurwitz_zeta_scaled(s, f, delta);
+    let z_g = hurwitz_zeta_scaled(s, g, delta);
+    let z_1mx = hurwitz_zeta_scaled(s, one_m_x, delta);
+    let z = if (s & 1) == 0 {
+        (z_f + z_g) - z_1mx
+    } else {
+        (z_f - z_g) + z_1mx
+    };
+
+    polygamma_finish(n, delta, z)
+}
+
+/// Calculates `(-1)^(n + 1) * n! * z / delta^(n + 1)`
+fn polygamma_finish<F: Polygamma>(n: u32, delta: F, z: DenormDouble<F>) -> F {
+    let z = z.to_norm();
+    if z.hi() == F::ZERO {
+        return F::ZERO;
+    }
+    let (z_neg, abs_z) = if z.hi().sign() {
+        (true, -z)
+    } else {
+        (false, z)
+    };
+
+    // n! = Γ(n + 1) = g_s * exp(g_y)
+    let (g_y, g_s) = gamma_inner_common(F::cast_from(n + 1));
+
+    // y = ln(n!) + ln(|z|) - (n + 1) * ln(delta)
+    let ln_z = hi_lo_ln_hi_lo_inner(abs_z, F::Exp::ZERO);
+    let ln_delta = hi_lo_ln_inner(delta, F::Exp::ZERO).to_semi();
+    let y = (g_y + ln_z) - ln_delta * F::cast_from(n + 1);
+
+    let r = if y.hi() >= F::exp_hi_th() * F::two() {
+        F::INFINITY
+    } else if y.hi() <= F::exp_lo_th() * F::two() {
+        F::ZERO
+    } else {
+        mul_exp(g_s, y)
+    };
+
+    // The sign is negative for even n
+    if z_neg == ((n & 1) != 0) { -r } else { r }
+}
+
+/// Calculates `x0^s * ζ(s, x)`, where `ζ` is the Hurwitz zeta function
+///
+/// `s >= 2` and `x >= x0 > 0`
+fn hurwitz_zeta_scaled<F: Polygamma>(s: u32, x: DenormDouble<F>, x0: F) -> DenormDouble<F> {
+    let coeffs = F::polygamma_em_coeffs();
+    let sf = F::cast_from(s);
+
+    // Above this threshold, the Euler-Maclaurin formula is accurate enough
+    // with the available coefficients
+    let em_th = sf + F::cast_from(coeffs.len() as u32 * 2 + 2);
+
+    // Remaining terms are negligible when they are below this threshold
+    // relative to the sum
+    let negl_th = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS * 2));
+
+    let x0 = DenormDouble::new(x0, F::ZERO);
+    let mut sum = DenormDouble::new(F::ZERO, F::ZERO);
+    let mut t = x;
+    loop {
+        // q = (x0 / t)^s
+        let q = powu_hi_lo(x0 / t, s);
+        if t.hi() >= em_th {
+            // sum + (x0 / t)^s * t^s * ζ(s, t)
+            return sum + q * hurwitz_zeta_em(s, t, coeffs);
+        }
+
+        sum = sum + q;
+
+        // The sum of the remaining terms is less than q * t
+        if q.hi() * t.hi() <= sum.hi() * negl_th {
+            return sum;
+        }
+
+        t = t + F::one();
+    }
+}
+
+/// Calculates `x^s * ζ(s, x)` using the Euler-Maclaurin summation formula:
+///
+/// ```text
+/// x^s * ζ(s, x) ~= x / (s - 1) + 1/2 + sum(B_2j / (2j)! * s * ... * (s + 2j - 2) / x^(2j - 1))
+/// ```
+fn hurwitz_zeta_em<F: Float>(s: u32, x: DenormDouble<F>, coeffs: &[F]) -> DenormDouble<F> {
+    let sf = F::cast_from(s);
+    let u = F::one() / x.hi();
+
+    let mut a = sf;
+    let mut f = sf * u;
+    let mut corr = F::ZERO;
+    for &c in coeffs {
+        corr = corr + c * f;
+        f = f * ((a + F::one()) * u) * ((a + F::two()) * u);
+        a = a + F::two();
+    }
+
+    let t = x / DenormDouble::new(sf - F::one(), F::ZERO);
+    t.qadd1(F::half()).qadd1(corr)
+}
+
+/// Calculates `x^n` for `n >= 1`
+fn powu_hi_lo<F: Float>(x: DenormDouble<F>, n: u32) -> DenormDouble<F> {
+    let mut r = DenormDouble::one();
+    let mut b = x;
+    let mut n = n;
+    loop {
+        if (n & 1) != 0 {
+            r = r.to_semi() * b.to_semi();
+        }
+        n >>= 1;
+        if n == 0 {
+            return r;
+        }
+        b = b.to_semi().square();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FloatMath;
+    use crate::traits::Float;
+
+    fn test_digamma<F: Float + FloatMath>() {
+        use crate::digamma;
+
+        assert_is_nan!(digamma(F::NAN));
+        assert_is_nan!(digamma(F::neg_infinity()));
+        assert_is_nan!(digamma(-F::one()));
+        assert_is_nan!(digamma(-F::two()));
+        assert_is_nan!(digamma(-F::largest()));
+        assert_total_eq!(digamma(F::INFINITY), F::INFINITY);
+        assert_total_eq!(digamma(F::ZERO), F::neg_infinity());
+        assert_total_eq!(digamma(-F::ZERO), F::INFINITY);
+    }
+
+    fn test_polygamma<F: Float + FloatMath>() {
+        use crate::polygamma;
+
+        assert_is_nan!(polygamma(-1, F::one()));
+        for n in [1, 2, 3, 10] {
+            assert_is_nan!(polygamma(n, F::NAN));
+            assert_is_nan!(polygamma(n, F::neg_infinity()));
+        }
+
+        // odd n
+        for n in [1, 3] {
+            assert_total_eq!(polygamma(n, F::INFINITY), F::ZERO);
+            assert_total_eq!(polygamma(n, F::ZERO), F::INFINITY);
+            assert_total_eq!(polygamma(n, -F::ZERO), F::INFINITY);
+            assert_total_eq!(polygamma(n, -F::one()), F::INFINITY);
+            assert_total_eq!(polygamma(n, -F::two()), F::INFINITY);
+        }
+
+        // even n
+        for n in [2, 4] {
+            assert_total_eq!(polygamma(n, F::INFINITY), -F::ZERO);
+            assert_total_eq!(polygamma(n, F::ZERO), F::neg_infinity());
+            assert_total_eq!(polygamma(n, -F::ZERO), F::INFINITY);
+            assert_is_nan!(polygamma(n, -F::one()));
+            assert_is_nan!(polygamma(n, -F::two()));
+        }
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_digamma::<f16>();
+        test_polygamma::<f16>();
+    }
+
+    #[test]
+    fn test_f32() {
+        test_digamma::<f32>();
+        test_polygamma::<f32>();
+    }
+
+    #[test]
+    fn test_f64() {
+        test_digamma::<f64>();
+        test_polygamma::<f64>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test_digamma::<half::bf16>();
+        test_polygamma::<half::bf16>();
+    }
+}
@@ -1,6 +1,7 @@
 use super::exp::{exp_inner_common, exp_split};
 use super::ln::hi_lo_ln_inner;
 use super::{Exp, Ln, int_is_odd, is_int, is_odd_int};
+use crate::double::NormDouble;
 use crate::traits::Int as _;
 
 pub(crate) fn pow<F: Ln + Exp>(x: F, y: F) -> F {
@@ -104,20 +105,8 @@ pub(crate) fn pow<F: Ln + Exp>(x: F, y: F) -> F {
         // logx = ln(|x|)
         let logx = hi_lo_ln_inner(nx.abs(), xedelta).to_semi();
 
-        // ylx = y * ln(|x|)
-        let ylx = (logx * y).to_norm();
-
         // |z| = |x|^y = exp(y * ln(|x|))
-        let absz = if ylx.hi() >= F::exp_hi_th() {
-            F::INFINITY
-        } else if ylx.hi() <= F::exp_lo_th() {
-            F::ZERO
-        } else {
-            let (k, r_hi, r_lo) = exp_split(ylx.hi());
-            let r_lo = r_lo + ylx.lo();
-
-            exp_inner_common(k, r_hi, r_lo)
-        };
+        let absz = exp_norm_inner((logx * y).to_norm());
 
         if nx.sign() && int_is_odd(ny) {
             -absz
@@ -127,12 +116,96 @@ pub(crate) fn pow<F: Ln + Exp>(x: F, y: F) -> F {
     }
 }
 
+pub(crate) fn powr<F: Ln + Exp>(x: F, y: F) -> F {
+    let (nx, xedelta) = x.normalize_arg();
+    let (ny, _) = y.normalize_arg();
+    let xexp = nx.raw_exp();
+    let yexp = ny.raw_exp();
+
+    if (yexp == F::MAX_RAW_EXP && ny.raw_mant() != F::Raw::ZERO)
+        || (xexp == F::MAX_RAW_EXP && nx.raw_mant() != F::Raw::ZERO)
+    {
+        // powr(x, NaN) = NaN
+        // powr(NaN, y) = NaN
+        F::NAN
+    } else if xexp == F::RawExp::ZERO {
+        // x = ±0
+        if yexp == F::RawExp::ZERO {
+            // powr(±0, ±0) = NaN
+            F::NAN
+        } else if ny.sign() {
+            // powr(±0, y) = +inf when y < 0
+            F::INFINITY
+        } else {
+            // powr(±0, y) = 0 when y > 0
+            F::ZERO
+        }
+    } else if nx.sign() {
+        // powr(x, y) = NaN when x < 0
+        F::NAN
+    } else if xexp == F::MAX_RAW_EXP {
+        // x = +inf
+        if yexp == F::RawExp::ZERO {
+            // powr(+inf, ±0) = NaN
+            F::NAN
+        } else if ny.sign() {
+            // powr(+inf, y) = 0 when y < 0
+            F::ZERO
+        } else {
+            // powr(+inf, y) = +inf when y > 0
+            F::INFINITY
+        }
+    } else if nx == F::one() {
+        if yexp == F::MAX_RAW_EXP {
+            // powr(1, ±inf) = NaN
+            F::NAN
+        } else {
+            // powr(1, y) = 1
+            F::one()
+        }
+    } else if yexp == F::RawExp::ZERO {
+        // powr(x, ±0) = 1
+        F::one()
+    } else if yexp == F::MAX_RAW_EXP {
+        // y = ±inf
+        if ny.sign() == (xexp < F::EXP_OFFSET) {
+            // powr(x, -inf) = inf when x < 1
+            // powr(x, +inf) = inf when x > 1
+            F::INFINITY
+        } else {
+            // powr(x, -inf) = 0 when x > 1
+            // powr(x, +inf) = 0 when x < 1
+            F::ZERO
+        }
+    } else {
+        // logx = ln(x)
+        let logx = hi_lo_ln_inner(nx, xedelta).to_semi();
+
+        // z = x^y = exp(y * ln(x))
+        exp_norm_inner((logx * y).to_norm())
+    }
+}
+
+/// Calculates `exp(x_hi + x_lo)`, handling overflow and underflow
+pub(super) fn exp_norm_inner<F: Exp>(x: NormDouble<F>) -> F {
+    if x.hi() >= F::exp_hi_th() {
+        F::INFINITY
+    } else if x.hi() <= F::exp_lo_th() {
+        F::ZERO
+    } else {
+        let (k, r_hi, r_lo) = exp_split(x.hi());
+        let r_lo = r_lo + x.lo();
+
+        exp_inner_common(k, r_hi, r_lo)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>() {
+    fn test<F: Float + ElementaryMath>() {
         use crate::pow;
 
         let f = F::parse;
@@ -208,13 +281,75 @@ mod tests {
         assert_total_eq!(pow(f("10"), f("4")), f("10000"));
     }
 
+    fn test_powr<F: Float + ElementaryMath>() {
+        use crate::powr;
+
+        let f = F::parse;
+
+        assert_is_nan!(powr(F::NAN, F::NAN));
+        assert_is_nan!(powr(F::NAN, F::ZERO));
+        assert_is_nan!(powr(F::one(), F::NAN));
+        assert_is_nan!(powr(F::two(), F::NAN));
+        assert_is_nan!(powr(F::ZERO, F::ZERO));
+        assert_is_nan!(powr(-F::ZERO, -F::ZERO));
+        assert_is_nan!(powr(F::INFINITY, F::ZERO));
+        assert_is_nan!(powr(F::INFINITY, -F::ZERO));
+        assert_is_nan!(powr(F::one(), F::INFINITY));
+        assert_is_nan!(powr(F::one(), F::neg_infinity()));
+        assert_is_nan!(powr(-F::one(), F::two()));
+        assert_is_nan!(powr(-F::two(), f("3")));
+        assert_is_nan!(powr(F::neg_infinity(), F::two()));
+        assert_total_eq!(powr(F::ZERO, f("-3")), F::INFINITY);
+        assert_total_eq!(powr(-F::ZERO, f("-3")), F::INFINITY);
+        assert_total_eq!(powr(F::ZERO, F::neg_infinity()), F::INFINITY);
+        assert_total_eq!(powr(F::ZERO, f("3")), F::ZERO);
+        assert_total_eq!(powr(-F::ZERO, f("3")), F::ZERO);
+        assert_total_eq!(powr(F::ZERO, F::INFINITY), F::ZERO);
+        assert_total_eq!(powr(F::INFINITY, f("-3")), F::ZERO);
+        assert_total_eq!(powr(F::INFINITY, f("3")), F::INFINITY);
+        assert_total_eq!(powr(F::one(), f("3.5")), F::one());
+        assert_total_eq!(powr(F::two(), F::ZERO), F::one());
+        assert_total_eq!(powr(F::two(), -F::ZERO), F::one());
+        assert_total_eq!(powr(f("0.5"), F::INFINITY), F::ZERO);
+        assert_total_eq!(powr(f("0.5"), F::neg_infinity()), F::INFINITY);
+        assert_total_eq!(powr(f("1.5"), F::INFINITY), F::INFINITY);
+        assert_total_eq!(powr(f("1.5"), F::neg_infinity()), F::ZERO);
+        assert_total_eq!(powr(F::two(), F::two()), f("4"));
+        assert_total_eq!(powr(F::two(), -F::two()), f("0.25"));
+        assert_total_eq!(powr(f("4"), f("0.5")), F::two());
+        assert_total_eq!(powr(f("10"), f("4")), f("10000"));
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+        test_powr::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>();
+        test_powr::<f32>();
     }
 
     #[test]
     fn test_f64() {
         test::<f64>();
+        test_powr::<f64>();
+    }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+        test_powr::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+        test_powr::<half::bf16>();
     }
 }
@@ -5,6 +5,10 @@ use crate::double::{DenormDouble, SemiDouble};
 use crate::traits::{CastInto as _, Int as _};
 
 pub(crate) fn powi<F: Ln + Exp>(x: F, y: i32) -> F {
+    pown(x, y.into())
+}
+
+pub(crate) fn pown<F: Ln + Exp>(x: F, y: i64) -> F {
     let (nx, xedelta) = x.normalize_arg();
     let xexp = nx.raw_exp();
 
@@ -73,54 +77,9 @@ pub(crate) fn powi<F: Ln + Exp>(x: F, y: i32) -> F {
         // logx = ln(|x|)
         let logx = hi_lo_ln_inner(nx.abs(), xedelta).to_semi();
 
-        // Split y = sum(y_i)
-        // |x|^y = prod(|x|^y_i)
-        //       = prod(e^(ln(|x|)*y_i))
-        //       = prod(e^(r_i)) * 2^(sum(k_i))
-        // ln(|x|) * y_i = r_i + k_i*ln(2)
-
-        // k_total = sum(k_i)
-        // z = prod(e^(r_i))
-        let mut k_total = 0;
-        let mut z = SemiDouble::one();
-
-        let absy = y.unsigned_abs();
-        let mut yshift = 0;
-        while yshift < 32 {
-            // yf is a chunk of y (one of the y_i above)
-            let mask: u32 = ((F::MANT_MASK << 1) | F::Raw::ONE).cast_into();
-            let yf: F = ((absy >> yshift) & mask).cast_into();
-            let yf = (yf * F::exp2i_fast(yshift.cast_into())).set_sign(y < 0);
-            yshift += F::MANT_BITS + 1;
-
-            // ylx = yf * ln(|x|)
-            let ylx = (logx * yf).to_norm();
-
-            if ylx.hi() >= F::exp_hi_th() {
-                if (absy & 1) == 0 || !nx.sign() {
-                    return F::INFINITY;
-                } else {
-                    return F::neg_infinity();
-                }
-            } else if ylx.hi() <= F::exp_lo_th() {
-                if (absy & 1) == 0 || !nx.sign() {
-                    return F::ZERO;
-                } else {
-                    return -F::ZERO;
-                }
-            } else {
-                let (k, r_hi, r_lo) = exp_split(ylx.hi());
-
-                // t = |x|^yf / 2^k = exp(yf * ln(|x|)) / 2^k
-                let r = DenormDouble::new(r_hi, r_lo + ylx.lo());
-                let t = hi_lo_exp_inner(r).to_semi();
+        // |z| = |x|^y = exp(y * ln(|x|))
+        let absz = exp_mul_int_inner(logx, y);
 
-                k_total += k;
-                z = (z * t).to_semi();
-            }
-        }
-
-        let absz = scalbn(z.to_single(), k_total);
         if nx.sign() && (y & 1) != 0 {
             -absz
         } else {
@@ -129,6 +88,51 @@ pub(crate) fn powi<F: Ln + Exp>(x: F, y: i32) -> F {
     }
 }
 
+/// Calculates `exp(y * (l_hi + l_lo))`, handling overflow and underflow
+pub(super) fn exp_mul_int_inner<F: Exp>(l: SemiDouble<F>, y: i64) -> F {
+    // Split y = sum(y_i)
+    // e^(l*y) = prod(e^(l*y_i))
+    //         = prod(e^(r_i)) * 2^(sum(k_i))
+    // l * y_i = r_i + k_i*ln(2)
+
+    // k_total = sum(k_i)
+    // z = prod(e^(r_i))
+    let mut k_total = 0;
+    let mut z = SemiDouble::one();
+
+    let absy = y.unsigned_abs();
+    let mut yshift = 0;
+    while yshift < 64 && (absy >> yshift) != 0 {
+        // yf is a chunk of y (one of the y_i above)
+        let mask: u64 = ((F::MANT_MASK << 1) | F::Raw::ONE).cast_into();
+        let yf: F = ((absy >> yshift) & mask).cast_into();
+        let yf = (yf * F::exp2i_fast(yshift.cast_into())).set_sign(y < 0);
+        yshift += F::MANT_BITS + 1;
+
+        // ly = yf * l
+        let ly = (l * yf).to_norm();
+
+        // All the chunks have the same sign, so the result overflows or
+        // underflows when any of them does
+        if ly.hi() >= F::exp_hi_th() {
+            return F::INFINITY;
+        } else if ly.hi() <= F::exp_lo_th() {
+            return F::ZERO;
+        }
+
+        let (k, r_hi, r_lo) = exp_split(ly.hi());
+
+        // t = e^(l*yf) / 2^k
+        let r = DenormDouble::new(r_hi, r_lo + ly.lo());
+        let t = hi_lo_exp_inner(r).to_semi();
+
+        k_total += k;
+        z = (z * t).to_semi();
+    }
+
+    scalbn(z.to_single(), k_total)
+}
+
 fn hi_lo_exp_inner<F: Exp>(r: DenormDouble<F>) -> DenormDouble<F> {
     // Calculates exp(r_hi + r_lo)
     // Similar to `exp_inner_common` in exp.rs, but returns hi/lo parts
@@ -152,10 +156,10 @@ fn hi_lo_exp_inner<F: Exp>(r: DenormDouble<F>) -> DenormDouble<F> {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>() {
+    fn test<F: Float + ElementaryMath>() {
         use crate::powi;
 
         let f = F::parse;
@@ -193,13 +197,60 @@ mod tests {
         assert_total_eq!(powi(f("10"), 4), f("10000"));
     }
 
+    fn test_pown<F: Float + ElementaryMath>() {
+        use crate::pown;
+
+        let f = F::parse;
+
+        assert_is_nan!(pown(F::NAN, 1));
+        assert_total_eq!(pown(F::NAN, 0), F::one());
+        assert_total_eq!(pown(F::ZERO, -33), F::INFINITY);
+        assert_total_eq!(pown(-F::ZERO, -33), F::neg_infinity());
+        assert_total_eq!(pown(-F::ZERO, 34), F::ZERO);
+        assert_total_eq!(pown(F::neg_infinity(), 33), F::neg_infinity());
+        assert_total_eq!(pown(F::neg_infinity(), -33), -F::ZERO);
+        assert_total_eq!(pown(F::one(), i64::MAX), F::one());
+        assert_total_eq!(pown(-F::one(), i64::MAX), -F::one());
+        assert_total_eq!(pown(-F::one(), i64::MIN), F::one());
+        assert_total_eq!(pown(F::two(), i64::MAX), F::INFINITY);
+        assert_total_eq!(pown(-F::two(), i64::MAX), F::neg_infinity());
+        assert_total_eq!(pown(-F::two(), i64::MIN), F::ZERO);
+        assert_total_eq!(pown(f("-0.5"), i64::MAX), -F::ZERO);
+        assert_total_eq!(pown(-F::two(), 3), f("-8"));
+        assert_total_eq!(pown(-F::two(), -3), f("-0.125"));
+        assert_total_eq!(pown(f("10"), 4), f("10000"));
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+        test_pown::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>();
+        test_pown::<f32>();
     }
 
     #[test]
     fn test_f64() {
         test::<f64>();
+        test_pown::<f64>();
+    }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+        test_pown::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+        test_pown::<half::bf16>();
     }
 }
@@ -1,14 +1,26 @@
 use super::reduce_pi_2::round_fi;
+use super::tiny_scale_exp;
 use crate::double::{NormDouble, SemiDouble};
 use crate::traits::{CastFrom, CastInto, Float, Int as _, SInt};
 
 pub(crate) trait Reduce90Deg: Float {
-    fn deg_to_rad() -> Self;
     fn deg_to_rad_ex() -> SemiDouble<Self>;
 
     type SRaw: SInt + CastInto<Self> + CastFrom<Self::Raw>;
 }
 
+/// Converts the very small angle `x` from degrees to radians
+pub(crate) fn tiny_deg_to_rad<F: Reduce90Deg>(x: F) -> F {
+    // scale temporarily to avoid temporary subnormal numbers
+    let logscale = tiny_scale_exp::<F>();
+    let scale = F::exp2i_fast(logscale);
+    let descale = F::exp2i_fast(-logscale);
+
+    // the sign is restored for `x = -0`
+    let sx = SemiDouble::new(x * scale);
+    ((sx * F::deg_to_rad_ex()).to_single() * descale).copysign(x)
+}
+
 /// Reduces the angle argument `x` (in degrees) and converts it to
 /// radians, returning `(n, y_hi, y_lo)` such as:
 /// * `|y_hi| <= π/4`
@@ -57,6 +57,49 @@ pub(crate) fn reduce_pi_2<F: ReducePi2>(x: F) -> (u8, F, F) {
     }
 }
 
+pub(crate) fn rem_pio2<F: ReducePi2>(x: F) -> (i32, F, F) {
+    if x.raw_exp() == F::MAX_RAW_EXP {
+        // infinity or NaN
+        (0, F::NAN, F::ZERO)
+    } else {
+        let (n, y_hi, y_lo) = reduce_pi_2(x);
+        if y_lo == F::ZERO {
+            // keep the sign of a zero `y_hi`
+            (n.into(), y_hi, y_lo)
+        } else {
+            // `reduce_pi_2` does not always return a normalized pair
+            let (y_hi, y_lo) = F::norm_hi_lo_full(y_hi, y_lo);
+            (n.into(), y_hi, y_lo)
+        }
+    }
+}
+
+pub(crate) fn wrap_angle<F: ReducePi2>(x: F) -> F {
+    if x.raw_exp() == F::MAX_RAW_EXP {
+        // infinity or NaN
+        F::NAN
+    } else if x.abs() <= F::PI {
+        // already in range
+        x
+    } else {
+        let (n, y_hi, y_lo) = reduce_pi_2(x);
+        // x = 2*π*M + π/2*k + y_hi + y_lo, with -2 <= k <= 2
+        let k: i32 = match n {
+            0 => return y_hi + y_lo,
+            1 => 1,
+            2 if y_hi > F::ZERO => -2,
+            2 => 2,
+            _ => -1,
+        };
+        let k = F::cast_from(k);
+        // π/2*k = c_hi + c_lo, c_hi is exact and |c_hi| > |y_hi|
+        let c_hi = k * F::frac_pi_2_hi();
+        let c_lo = k * F::frac_pi_2_hiex();
+        let (s_hi, s_lo) = F::norm_hi_lo_full(c_hi, y_hi);
+        s_hi + (s_lo + (c_lo + y_lo))
+    }
+}
+
 // π/4 < x < MAX_REDUCE_PI_2_MEDIUM
 fn reduce_pi_2_medium<F: ReducePi2>(x: F) -> (u8, F, F) {
     // Based on __rem_pio2 (the part after 'medium:') from musl libc
@@ -114,3 +157,73 @@ pub(super) fn round_fi<F: Float>(x: F) -> (F, i32) {
         (ipart_f, ipart_i)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::ElementaryMath;
+    use crate::traits::Float;
+
+    fn test<F: Float + ElementaryMath>() {
+        use crate::{rem_pio2, wrap_angle};
+
+        let f = F::parse;
+
+        let test_nan = |arg: F| {
+            let (n, y_hi, y_lo) = rem_pio2(arg);
+            assert_eq!(n, 0);
+            assert_is_nan!(y_hi);
+            assert_total_eq!(y_lo, F::ZERO);
+            assert_is_nan!(wrap_angle(arg));
+        };
+
+        test_nan(F::NAN);
+        test_nan(F::INFINITY);
+        test_nan(F::neg_infinity());
+
+        for arg in [F::ZERO, -F::ZERO, F::half(), -F::half(), f("0.75")] {
+            let (n, y_hi, y_lo) = rem_pio2(arg);
+            assert_eq!(n, 0);
+            assert_total_eq!(y_hi, arg);
+            assert_total_eq!(y_lo, F::ZERO);
+        }
+
+        for arg in [F::ZERO, -F::ZERO, F::one(), -F::two(), f("3"), f("-3")] {
+            assert_total_eq!(wrap_angle(arg), arg);
+        }
+
+        let (n, _, _) = rem_pio2(F::one());
+        assert_eq!(n, 1);
+        let (n, _, _) = rem_pio2(-F::one());
+        assert_eq!(n, 3);
+        let (n, _, _) = rem_pio2(f("3"));
+        assert_eq!(n, 2);
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
+    #[test]
+    fn test_f32() {
+        test::<f32>();
+    }
+
+    #[test]
+    fn test_f64() {
+        test::<f64>();
+    }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
+}
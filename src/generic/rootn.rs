@@ -0,0 +1,145 @@
+use super::ln::hi_lo_ln_inner;
+use super::pow::exp_norm_inner;
+use super::{Cbrt, Exp, Ln, cbrt, rsqrt, sqrt};
+use crate::double::SemiDouble;
+use crate::traits::Int as _;
+
+pub(crate) fn rootn<F: Ln + Exp + Cbrt>(x: F, n: i64) -> F {
+    let (nx, xedelta) = x.normalize_arg();
+    let xexp = nx.raw_exp();
+    let n_is_odd = (n & 1) != 0;
+
+    if n == 0 || (xexp == F::MAX_RAW_EXP && nx.raw_mant() != F::Raw::ZERO) {
+        // rootn(x, 0) = NaN
+        // rootn(NaN, n) = NaN
+        F::NAN
+    } else if xexp == F::RawExp::ZERO {
+        // x = ±0
+        if n_is_odd {
+            if n < 0 {
+                // rootn(±0, n) = ±inf when n < 0 and odd
+                F::INFINITY.copysign(nx)
+            } else {
+                // rootn(±0, n) = ±0 when n > 0 and odd
+                nx
+            }
+        } else if n < 0 {
+            // rootn(±0, n) = +inf when n < 0 and even
+            F::INFINITY
+        } else {
+            // rootn(±0, n) = +0 when n > 0 and even
+            F::ZERO
+        }
+    } else if nx.sign() && !n_is_odd {
+        // rootn(x, n) = NaN when x < 0 and n is even
+        F::NAN
+    } else if xexp == F::MAX_RAW_EXP {
+        // x = ±inf
+        if n < 0 {
+            // rootn(±inf, n) = ±0 when n < 0
+            F::ZERO.copysign(nx)
+        } else {
+            // rootn(±inf, n) = ±inf when n > 0
+            nx
+        }
+    } else if n == 1 {
+        x
+    } else if n == -1 {
+        F::one() / x
+    } else if n == 2 {
+        sqrt(x)
+    } else if n == -2 {
+        rsqrt(x)
+    } else if n == 3 {
+        cbrt(x)
+    } else {
+        // logx = ln(|x|)
+        let logx = hi_lo_ln_inner(nx.abs(), xedelta).to_semi();
+
+        // The conversion of `n` is inexact only when `|n|` is so large
+        // that `ln(|x|) / n` is far below one ULP of the result.
+        let nf = SemiDouble::new(F::cast_from(n));
+
+        // |z| = |x|^(1/n) = exp(ln(|x|) / n)
+        let absz = exp_norm_inner((logx / nf).to_norm());
+
+        if nx.sign() { -absz } else { absz }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ElementaryMath;
+    use crate::traits::Float;
+
+    fn test<F: Float + ElementaryMath>() {
+        use crate::rootn;
+
+        let f = F::parse;
+
+        assert_is_nan!(rootn(F::NAN, 3));
+        assert_is_nan!(rootn(F::NAN, -2));
+        assert_is_nan!(rootn(F::two(), 0));
+        assert_is_nan!(rootn(F::ZERO, 0));
+        assert_is_nan!(rootn(F::INFINITY, 0));
+        assert_is_nan!(rootn(-F::two(), 2));
+        assert_is_nan!(rootn(-F::two(), -4));
+        assert_is_nan!(rootn(F::neg_infinity(), 4));
+        assert_total_eq!(rootn(F::ZERO, 3), F::ZERO);
+        assert_total_eq!(rootn(-F::ZERO, 3), -F::ZERO);
+        assert_total_eq!(rootn(F::ZERO, 4), F::ZERO);
+        assert_total_eq!(rootn(-F::ZERO, 4), F::ZERO);
+        assert_total_eq!(rootn(F::ZERO, -3), F::INFINITY);
+        assert_total_eq!(rootn(-F::ZERO, -3), F::neg_infinity());
+        assert_total_eq!(rootn(F::ZERO, -4), F::INFINITY);
+        assert_total_eq!(rootn(-F::ZERO, -4), F::INFINITY);
+        assert_total_eq!(rootn(F::INFINITY, 3), F::INFINITY);
+        assert_total_eq!(rootn(F::neg_infinity(), 3), F::neg_infinity());
+        assert_total_eq!(rootn(F::INFINITY, 4), F::INFINITY);
+        assert_total_eq!(rootn(F::INFINITY, -3), F::ZERO);
+        assert_total_eq!(rootn(F::neg_infinity(), -3), -F::ZERO);
+        assert_total_eq!(rootn(F::INFINITY, -4), F::ZERO);
+        assert_total_eq!(rootn(F::one(), 7), F::one());
+        assert_total_eq!(rootn(F::one(), -6), F::one());
+        assert_total_eq!(rootn(-F::one(), 7), -F::one());
+        assert_total_eq!(rootn(-F::one(), i64::MAX), -F::one());
+        assert_total_eq!(rootn(f("3.5"), 1), f("3.5"));
+        assert_total_eq!(rootn(f("-0.25"), -1), f("-4"));
+        assert_total_eq!(rootn(f("16"), 2), f("4"));
+        assert_total_eq!(rootn(f("0.25"), -2), F::two());
+        assert_total_eq!(rootn(f("8"), 3), F::two());
+        assert_total_eq!(rootn(f("-8"), 3), -F::two());
+        assert_total_eq!(rootn(f("16"), 4), F::two());
+        assert_total_eq!(rootn(f("0.0625"), -4), F::two());
+        assert_total_eq!(rootn(f("-0.125"), -3), -F::two());
+        assert_total_eq!(rootn(f("1024"), 10), F::two());
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
+    #[test]
+    fn test_f32() {
+        test::<f32>();
+    }
+
+    #[test]
+    fn test_f64() {
+        test::<f64>();
+    }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
+}
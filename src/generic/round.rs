@@ -62,10 +62,10 @@ pub(crate) fn round_as_i_f<F: Float>(x: F) -> (i32, F) {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test_round<F: Float + FloatMath>() {
+    fn test_round<F: Float + ElementaryMath>() {
         use crate::round;
 
         let one = F::one();
@@ -111,6 +111,13 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test_round::<f16>();
+        test_round_as_i_f::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test_round::<f32>();
@@ -122,4 +129,17 @@ mod tests {
         test_round::<f64>();
         test_round_as_i_f::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test_round::<f128>();
+        test_round_as_i_f::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test_round::<half::bf16>();
+    }
 }
@@ -50,10 +50,10 @@ fn scalbn_split3<F: Float>(e: i32) -> (F::Exp, F::Exp, F::Exp) {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::{Float, Int as _};
 
-    fn test<F: Float + FloatMath>() {
+    fn test<F: Float + ElementaryMath>() {
         use crate::scalbn;
 
         let f = F::parse;
@@ -94,7 +94,11 @@ mod tests {
         let min_normal = F::exp2i_fast(F::MIN_NORMAL_EXP);
         let max_normal = F::exp2i_fast(F::MAX_EXP);
 
-        let max_norm_to_min_sub: i32 = (min_subnormal_exp - F::MAX_EXP).into();
+        let min_subnormal_exp_i32: i32 = min_subnormal_exp.into();
+        let max_exp_i32: i32 = F::MAX_EXP.into();
+
+        // computed in `i32`, because it might not fit in `F::Exp`
+        let max_norm_to_min_sub = min_subnormal_exp_i32 - max_exp_i32;
         let min_norm_to_max_norm: i32 = (F::MAX_EXP - F::MIN_NORMAL_EXP).into();
 
         assert_total_eq!(scalbn(min_normal, 1), min_normal * F::two());
@@ -113,7 +117,7 @@ mod tests {
             F::neg_infinity()
         );
 
-        let min_sub_to_max_exp: i32 = (F::MAX_EXP - min_subnormal_exp).into();
+        let min_sub_to_max_exp = max_exp_i32 - min_subnormal_exp_i32;
 
         let mant_bits_m1 = F::MANT_BITS - 1;
         assert_total_eq!(scalbn(min_subnormal, 1), min_subnormal * F::two());
@@ -131,6 +135,12 @@ mod tests {
         assert_total_eq!(scalbn(-min_subnormal, min_sub_to_max_exp), -max_normal);
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>();
@@ -140,4 +150,16 @@ mod tests {
     fn test_f64() {
         test::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
 }
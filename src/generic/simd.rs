@@ -0,0 +1,328 @@
+use super::atan::{Atan, atan2, atan2_inner_common, atan2_tiny_exp_diff};
+use super::exp::{Exp, exp, exp_inner_reduced};
+use super::ln::{Ln, hi_lo_ln_inner_common, ln, ln_inner_common};
+use super::pow::pow;
+use super::reduce_pi_2::ReducePi2;
+use super::sin_cos::{SinCos, cos_inner, sin_cos, sin_inner};
+use crate::traits::{CastInto as _, Float, Int as _, Simd, SimdMask as _};
+
+// Each function first checks which lanes need special handling (the same
+// checks that the scalar function does). The other lanes are calculated
+// without branches with the same operations as the scalar function, so the
+// results are bit-identical. Finally, the lanes that need special handling
+// are calculated with the scalar function.
+//
+// Integers (such as the `k` in `2^k`) are kept as floats, which can be
+// converted from and to raw bits with float additions and bitwise operations.
+
+#[inline]
+pub(crate) fn simd_exp<F: Exp, V: Simd<Scalar = F>>(x: V) -> V {
+    let common = x.cmp_gt(V::splat(F::exp_lo_th()))
+        & x.cmp_lt(V::splat(F::exp_hi_th()))
+        & abs(x).cmp_ge(min_positive());
+
+    let (kf, r_hi, r_lo) = exp_split(x);
+    let y = scalbn_medium(exp_inner_reduced(r_hi, r_lo), kf);
+
+    fix_lanes(common, y, [x], |[x]| exp(x))
+}
+
+#[inline]
+pub(crate) fn simd_ln<F: Ln, V: Simd<Scalar = F>>(x: V) -> V {
+    let common = x.cmp_ge(min_positive()) & x.cmp_le(largest());
+
+    let (kf, r) = ln_split(x);
+    let y = ln_inner_common(kf, r);
+
+    fix_lanes(common, y, [x], |[x]| ln(x))
+}
+
+#[inline]
+pub(crate) fn simd_sin_cos<F: SinCos + ReducePi2, V: Simd<Scalar = F>>(x: V) -> (V, V) {
+    let xabs = abs(x);
+    let common = xabs.cmp_ge(min_positive()) & xabs.cmp_lt(V::splat(F::max_reduce_pi_2_medium()));
+
+    let (nf, y_hi, y_lo) = reduce_pi_2(x);
+    let sin = sin_inner(y_hi, y_lo);
+    let cos = cos_inner(y_hi, y_lo);
+
+    // same as the `match n` in the scalar function
+    let n_odd = int_bit(nf, F::Raw::ONE);
+    let (sin, cos) = (V::select(n_odd, cos, sin), V::select(n_odd, -sin, cos));
+    let n_neg = int_bit(nf, F::Raw::TWO);
+    let (sin, cos) = (V::select(n_neg, -sin, sin), V::select(n_neg, -cos, cos));
+
+    if common.all() {
+        return (sin, cos);
+    }
+
+    let x = x.to_array();
+    let mut sin = sin.to_array();
+    let mut cos = cos.to_array();
+    let common = common.bitmask();
+    for (i, ((&x, sin), cos)) in x
+        .as_ref()
+        .iter()
+        .zip(sin.as_mut().iter_mut())
+        .zip(cos.as_mut().iter_mut())
+        .enumerate()
+    {
+        if (common & (1 << i)) == 0 {
+            (*sin, *cos) = sin_cos(x);
+        }
+    }
+    (V::from_array(sin), V::from_array(cos))
+}
+
+#[inline]
+pub(crate) fn simd_atan2<F: Atan, V: Simd<Scalar = F>>(y: V, x: V) -> V {
+    // Arguments that would be scaled by `atan2_normalize` are not common
+    let min = V::splat_raw(F::Raw::from(F::MANT_BITS + 1) << F::MANT_BITS);
+    let max = largest();
+    let yabs = abs(y);
+    let xabs = abs(x);
+    let common = yabs.cmp_ge(min) & yabs.cmp_le(max) & xabs.cmp_ge(min) & xabs.cmp_le(max);
+
+    let zero = V::splat(F::ZERO);
+    let one = V::splat(F::one());
+    let two = V::splat(F::two());
+
+    // same as `atan2_inner`
+    let ysgn = y.cmp_lt(zero);
+    let xsgn = x.cmp_lt(zero);
+    let off = V::select(xsgn, V::select(ysgn, -two, two), zero);
+    let swap = yabs.cmp_gt(xabs);
+    let n = V::select(swap, -x, y);
+    let d = V::select(swap, y, x);
+    let off = V::select(swap, off + V::select(ysgn ^ xsgn, -one, one), off);
+    let r = atan2_inner_common(n, d, off).to_single();
+
+    // y/x is very small
+    let yexp = raw_exp(y);
+    let xexp = raw_exp(x);
+    let tiny_diff: u32 = atan2_tiny_exp_diff::<F>().cast_into();
+    let tiny = !xsgn & xexp.cmp_gt(yexp) & (xexp - yexp).cmp_ge(V::splat(F::cast_from(tiny_diff)));
+    let r = V::select(tiny, y / x, r);
+
+    fix_lanes(common, r, [y, x], |[y, x]| atan2(y, x))
+}
+
+#[inline]
+pub(crate) fn simd_pow<F: Ln + Exp, V: Simd<Scalar = F>>(x: V, y: V) -> V {
+    let yabs = abs(y);
+    let common = x.cmp_ge(min_positive())
+        & x.cmp_le(largest())
+        & yabs.cmp_gt(V::splat(F::ZERO))
+        & yabs.cmp_le(largest());
+
+    // logx = ln(x)
+    let (kf, r) = ln_split(x);
+    let logx = hi_lo_ln_inner_common(kf, r).to_semi();
+
+    // ylx = y * ln(x)
+    let ylx = (logx * y).to_norm();
+
+    // z = x^y = exp(y * ln(x))
+    let (kf, r_hi, r_lo) = exp_split(ylx.hi());
+    let r_lo = r_lo + ylx.lo();
+    let z = scalbn_medium(exp_inner_reduced(r_hi, r_lo), kf);
+    let z = V::select(
+        ylx.hi().cmp_le(V::splat(F::exp_lo_th())),
+        V::splat(F::ZERO),
+        z,
+    );
+    let z = V::select(
+        ylx.hi().cmp_ge(V::splat(F::exp_hi_th())),
+        V::splat(F::INFINITY),
+        z,
+    );
+
+    fix_lanes(common, z, [x, y], |[x, y]| pow(x, y))
+}
+
+/// Replaces the lanes of `r` that are not set in `common` with the result of
+/// the scalar function `f`
+#[inline]
+fn fix_lanes<V: Simd, const N: usize>(
+    common: V::Mask,
+    r: V,
+    args: [V; N],
+    f: impl Fn([V::Scalar; N]) -> V::Scalar,
+) -> V {
+    if common.all() {
+        return r;
+    }
+
+    let args = args.map(V::to_array);
+    let mut r = r.to_array();
+    let common = common.bitmask();
+    for (i, r) in r.as_mut().iter_mut().enumerate() {
+        if (common & (1 << i)) == 0 {
+            *r = f(args.map(|arg| arg.as_ref()[i]));
+        }
+    }
+    V::from_array(r)
+}
+
+#[inline]
+fn abs<V: Simd>(x: V) -> V {
+    x.and(V::splat_raw(!<V::Scalar as Float>::SIGN_MASK))
+}
+
+/// Returns the smallest positive normal number
+#[inline]
+fn min_positive<V: Simd>() -> V {
+    V::splat_raw(<V::Scalar as Float>::MANT_MASK + <V::Scalar as Float>::Raw::ONE)
+}
+
+/// Returns the largest finite number
+#[inline]
+fn largest<V: Simd>() -> V {
+    V::splat_raw(<V::Scalar as Float>::EXP_MASK - <V::Scalar as Float>::Raw::ONE)
+}
+
+/// Returns `2^MANT_BITS + i`, whose lower mantissa bits are `i`
+///
+/// `0 <= i < 2^MANT_BITS`
+#[inline]
+fn raw_int_base<F: Float, V: Simd<Scalar = F>>(i: F::Raw) -> V {
+    let e = F::EXP_OFFSET + F::RawExp::from(F::MANT_BITS);
+    V::splat_raw((F::Raw::from(e) << F::MANT_BITS) | i)
+}
+
+/// Returns the raw exponent of `x` as a float
+#[inline]
+fn raw_exp<F: Float, V: Simd<Scalar = F>>(x: V) -> V {
+    let base = raw_int_base(F::Raw::ZERO);
+    x.and(V::splat_raw(F::EXP_MASK)).shr_mant().or(base) - base
+}
+
+/// Returns `2^k`
+///
+/// `k` must be an integer and `MIN_NORMAL_EXP <= k <= MAX_EXP`.
+#[inline]
+fn exp2i<F: Float, V: Simd<Scalar = F>>(k: V) -> V {
+    // The lower mantissa bits of `t` are `k + EXP_OFFSET`
+    let t = k + raw_int_base::<F, V>(F::Raw::from(F::EXP_OFFSET));
+    t.and(V::splat_raw(F::MANT_MASK)).shl_mant()
+}
+
+/// Returns a mask of the lanes of `i` that have `bit` set
+///
+/// `i` must be an integer and `abs(i) < 2^(MANT_BITS - 1)`. Negative values
+/// are handled as two's complement.
+#[inline]
+fn int_bit<F: Float, V: Simd<Scalar = F>>(i: V, bit: F::Raw) -> V::Mask {
+    // The lower mantissa bits of `t` are `i + 2^(MANT_BITS - 1)`
+    let t = i + raw_int_base::<F, V>(F::Raw::ONE << (F::MANT_BITS - 1));
+    let one = V::splat(F::one());
+    t.and(V::splat_raw(bit)).or(one).cmp_gt(one)
+}
+
+/// Same as `round_as_i_f`, returning only the float
+///
+/// `abs(x) < 2^(MANT_BITS - 1)`
+#[inline]
+fn round<F: Float, V: Simd<Scalar = F>>(x: V) -> V {
+    // Round to nearest, ties to even
+    let magic = raw_int_base::<F, V>(F::Raw::ONE << (F::MANT_BITS - 1));
+    let r = (x + magic).purify() - magic;
+
+    // Round ties away from zero
+    let half = V::splat(F::half());
+    let tie = abs(r - x).cmp_eq(half);
+    let away = x + x.and(V::splat_raw(F::SIGN_MASK)).or(half);
+    V::select(tie, away, r)
+}
+
+/// Same as `scalbn_medium`, with `k` as a float
+#[inline]
+fn scalbn_medium<F: Float, V: Simd<Scalar = F>>(x: V, k: V) -> V {
+    let max_exp = V::splat(F::MAX_EXP.cast_into());
+    let min_exp = V::splat(F::MIN_NORMAL_EXP.cast_into());
+    let min = |a: V, b: V| V::select(a.cmp_lt(b), a, b);
+    let max = |a: V, b: V| V::select(a.cmp_gt(b), a, b);
+
+    // same as `scalbn_split2`
+    let e1_pos = min(k, max_exp);
+    let e2_pos = min(k - e1_pos, max_exp);
+    let e2_neg = max(k, min_exp);
+    let e1_neg = max(k - e2_neg, min_exp);
+    let pos = k.cmp_ge(V::splat(F::ZERO));
+    let e1 = V::select(pos, e1_pos, e1_neg);
+    let e2 = V::select(pos, e2_pos, e2_neg);
+
+    (x * exp2i(e1)) * exp2i(e2)
+}
+
+/// Same as `exp_split`, with `k` as a float
+#[inline]
+fn exp_split<F: Exp, V: Simd<Scalar = F>>(x: V) -> (V, V, V) {
+    let y = x * F::log2_e();
+    let kf = round(y);
+    let r_hi = x - kf * F::ln_2_hi();
+    let r_lo = -kf * F::ln_2_lo();
+
+    (kf, r_hi, r_lo)
+}
+
+/// Same as `ln_split` with `edelta = 0`, with `k` as a float
+///
+/// `x` must be normal and positive.
+#[inline]
+fn ln_split<F: Ln, V: Simd<Scalar = F>>(x: V) -> (V, V) {
+    let one = V::splat(F::one());
+    let offset: u32 = F::EXP_OFFSET.cast_into();
+    let kf = raw_exp(x) - V::splat(F::cast_from(offset));
+    let mant = x.and(V::splat_raw(F::MANT_MASK));
+    let m = mant.or(one);
+
+    // reduce 1 <= m < 2 into sqrt(2) / 2 <= 1 + r <= sqrt(2)
+    let big = m.cmp_gt(V::splat(F::sqrt_2()));
+    let kf = V::select(big, kf + one, kf);
+    let m = V::select(big, mant.or(V::splat(F::half())), m);
+    (kf, m - one)
+}
+
+/// Same as `reduce_pi_2`, with `n` as a float
+///
+/// `abs(x) < max_reduce_pi_2_medium`
+#[inline]
+fn reduce_pi_2<F: ReducePi2, V: Simd<Scalar = F>>(x: V) -> (V, V, V) {
+    // same as `reduce_pi_2_medium`
+    let f_n = round(x * F::FRAC_2_PI);
+    let xexp = raw_exp(x);
+    let r = x - f_n * F::frac_pi_2_hi();
+    let w = f_n * F::frac_pi_2_hiex();
+    let y0 = (r - w).purify();
+
+    let th1 = V::splat(F::REDUCE_PI_2_MEDIUM_TH1.cast_into());
+    let refine1 = (xexp - raw_exp(y0)).cmp_gt(th1);
+    let t = r;
+    let w1 = f_n * F::frac_pi_2_mi();
+    let r1 = (t - w1).purify();
+    let w1 = f_n * F::frac_pi_2_miex() - ((t - r1) - w1);
+    let y01 = (r1 - w1).purify();
+
+    let th2 = V::splat(F::REDUCE_PI_2_MEDIUM_TH2.cast_into());
+    let refine2 = refine1 & (xexp - raw_exp(y01)).cmp_gt(th2);
+    let t = r1;
+    let w2 = f_n * F::frac_pi_2_lo();
+    let r2 = (t - w2).purify();
+    let w2 = f_n * F::frac_pi_2_loex() - ((t - r2) - w2);
+    let y02 = (r2 - w2).purify();
+
+    let r = V::select(refine2, r2, V::select(refine1, r1, r));
+    let w = V::select(refine2, w2, V::select(refine1, w1, w));
+    let y0 = V::select(refine2, y02, V::select(refine1, y01, y0));
+    let y1 = (r - y0).purify() - w;
+
+    // reduction not needed
+    let small = abs(x).cmp_le(V::splat(F::FRAC_PI_4));
+    let zero = V::splat(F::ZERO);
+    (
+        V::select(small, zero, f_n),
+        V::select(small, x, y0),
+        V::select(small, zero, y1),
+    )
+}
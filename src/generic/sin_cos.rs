@@ -1,6 +1,6 @@
 use crate::double::{DenormDouble, NormDouble, SemiDouble};
 use crate::generic::{ReducePi2, reduce_pi_2};
-use crate::traits::{Float, Int as _};
+use crate::traits::{Float, Int as _, Lanes};
 
 pub(crate) trait SinCos: Float {
     fn frac_1_6_ex() -> SemiDouble<Self>;
@@ -11,7 +11,7 @@ pub(crate) trait SinCos: Float {
     /// * `x2 = x^2`
     /// * `x5 = x^5`
     /// * `K3 ~= -1/6`
-    fn sin_poly(x2: Self, x5: Self) -> (Self, Self);
+    fn sin_poly<V: Lanes<Scalar = Self>>(x2: V, x5: V) -> (V, Self);
 
     /// Calculates `sin(x) - x + x^3 * 1/6`
     ///
@@ -25,7 +25,7 @@ pub(crate) trait SinCos: Float {
     /// Where:
     /// * `x2 = x^2`
     /// * `x4 = x^4`
-    fn cos_poly(x2: Self, x4: Self) -> Self;
+    fn cos_poly<V: Lanes<Scalar = Self>>(x2: V, x4: V) -> V;
 }
 
 pub(crate) fn sin<F: SinCos + ReducePi2>(x: F) -> F {
@@ -98,9 +98,39 @@ pub(crate) fn sin_cos<F: SinCos + ReducePi2>(x: F) -> (F, F) {
     }
 }
 
+/// Calculates `(sin(x), cos(x))` as `((sin_hi, sin_lo), (cos_hi, cos_lo))`
+pub(crate) fn sin_cos_hi_lo<F: SinCos + ReducePi2>(x: F) -> ((F, F), (F, F)) {
+    let e = x.raw_exp();
+    if e == F::MAX_RAW_EXP {
+        // sin(inf or nan) = nan
+        // cos(inf or nan) = nan
+        ((F::NAN, F::ZERO), (F::NAN, F::ZERO))
+    } else if e == F::RawExp::ZERO {
+        // subnormal or zero
+        // sin(x) ~= x
+        // cos(x) ~= 1
+        // also handles sin(-0) = -0
+        ((x, F::ZERO), (F::one(), F::ZERO))
+    } else {
+        let (n, y_hi, y_lo) = reduce_pi_2(x);
+        let y = NormDouble::with_parts(y_hi, y_lo);
+
+        let sin = hi_lo_sin_inner(y).to_norm();
+        let cos = hi_lo_cos_inner(y).to_norm();
+        let (sin, cos) = match n {
+            0 => (sin, cos),
+            1 => (cos, -sin),
+            2 => (-sin, -cos),
+            3 => (-cos, sin),
+            _ => unreachable!(),
+        };
+        ((sin.hi(), sin.lo()), (cos.hi(), cos.lo()))
+    }
+}
+
 /// Calculates `sin(x_hi + x_lo)`, where
 /// `x_lo` is very small and `|x_hi| <= π/4`
-pub(super) fn sin_inner<F: SinCos>(x_hi: F, x_lo: F) -> F {
+pub(super) fn sin_inner<F: SinCos, V: Lanes<Scalar = F>>(x_hi: V, x_lo: V) -> V {
     // sin(x_hi + x_lo) = sin(x_hi) * cos(x_lo) + cos(x_hi) * sin(x_lo)
     // x_lo is small, so sin(x_lo) ~= x_lo and cos(x_lo) ~= 1,
     // then sin(x_hi + x_lo) ~= sin(x_hi) + cos(x_hi) * x_lo
@@ -117,7 +147,7 @@ pub(super) fn sin_inner<F: SinCos>(x_hi: F, x_lo: F) -> F {
 
     // sin(x_hi + x_lo) ~= sin(x) + (1 - 0.5 * x^2) * x_lo
     //                  = x + t1 + x^3 * k3 + (1 - 0.5 * x^2) * x_lo
-    x_hi + (x3 * k3 + (t1 + (x_lo - F::half() * x2 * x_lo)))
+    x_hi + (x3 * k3 + (t1 + (x_lo - V::splat(F::half()) * x2 * x_lo)))
 }
 
 pub(super) fn hi_lo_sin_inner<F: SinCos>(x: NormDouble<F>) -> DenormDouble<F> {
@@ -139,7 +169,7 @@ pub(super) fn hi_lo_sin_inner<F: SinCos>(x: NormDouble<F>) -> DenormDouble<F> {
 
 /// Calculates `cos(x_hi + x_lo)`, where
 /// `x_lo` is very small and `|x_hi| <= π/4`
-pub(super) fn cos_inner<F: SinCos>(x_hi: F, x_lo: F) -> F {
+pub(super) fn cos_inner<F: SinCos, V: Lanes<Scalar = F>>(x_hi: V, x_lo: V) -> V {
     // cos(x_hi + x_lo) = cos(x_hi) * cos(x_lo) - sin(x_hi) * sin(x_lo)
     // x_lo is small, so sin(x_lo) ~= x_lo and cos(x_lo) ~= 1
     // then
@@ -154,7 +184,7 @@ pub(super) fn cos_inner<F: SinCos>(x_hi: F, x_lo: F) -> F {
     let t1 = F::cos_poly(x2, x4);
 
     // cos(x_hi + x_lo) = t1 + 1 - 0.5 * x^2 - x_hi * x_lo
-    let t2 = DenormDouble::new_qsub11(F::one(), F::half() * x2);
+    let t2 = DenormDouble::new_qsub11(V::splat(F::one()), V::splat(F::half()) * x2);
     t2.lsub(x_hi * x_lo).ladd(t1).to_single()
 }
 
@@ -176,11 +206,11 @@ pub(super) fn hi_lo_cos_inner<F: SinCos>(x: NormDouble<F>) -> DenormDouble<F> {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>() {
-        use crate::{cos, sin, sin_cos};
+    fn test<F: Float + ElementaryMath>() {
+        use crate::{cos, sin, sin_cos, sin_cos_hi_lo};
 
         let test_nan = |arg: F| {
             let sin1 = sin(arg);
@@ -190,6 +220,11 @@ mod tests {
             assert_is_nan!(cos1);
             assert_is_nan!(sin2);
             assert_is_nan!(cos2);
+            let ((sin_hi, sin_lo), (cos_hi, cos_lo)) = sin_cos_hi_lo(arg);
+            assert_is_nan!(sin_hi);
+            assert_total_eq!(sin_lo, F::ZERO);
+            assert_is_nan!(cos_hi);
+            assert_total_eq!(cos_lo, F::ZERO);
         };
 
         let test_value = |arg: F, expected_sin: F, expected_cos: F| {
@@ -200,6 +235,11 @@ mod tests {
             assert_total_eq!(cos1, expected_cos);
             assert_total_eq!(sin2, expected_sin);
             assert_total_eq!(cos2, expected_cos);
+            let ((sin_hi, sin_lo), (cos_hi, cos_lo)) = sin_cos_hi_lo(arg);
+            assert_total_eq!(sin_hi, expected_sin);
+            assert_total_eq!(sin_lo, F::ZERO);
+            assert_total_eq!(cos_hi, expected_cos);
+            assert_total_eq!(cos_lo, F::ZERO);
         };
 
         test_nan(F::NAN);
@@ -209,6 +249,12 @@ mod tests {
         test_value(-F::ZERO, -F::ZERO, F::one());
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>();
@@ -218,4 +264,16 @@ mod tests {
     fn test_f64() {
         test::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
 }
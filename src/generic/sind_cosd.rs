@@ -1,3 +1,4 @@
+use super::reduce_90_deg::tiny_deg_to_rad;
 use super::sin_cos::{cos_inner, sin_inner};
 use super::{Reduce90Deg, SinCos, reduce_90_deg};
 
@@ -9,7 +10,7 @@ pub(crate) fn sind<F: SinCos + Reduce90Deg>(x: F) -> F {
     } else if e <= F::RawExp::from(F::MANT_BITS) {
         // subnormal or zero, sind(x) ~= x * (π/180)
         // also handles sind(-0) = -0
-        x * F::deg_to_rad()
+        tiny_deg_to_rad(x)
     } else {
         let (n, y) = reduce_90_deg(x);
 
@@ -55,7 +56,7 @@ pub(crate) fn sind_cosd<F: SinCos + Reduce90Deg>(x: F) -> (F, F) {
         // sind(x) ~= x * (π/180)
         // cosd(x) ~= 1
         // also handles sind(-0) = -0
-        (x * F::deg_to_rad(), F::one())
+        (tiny_deg_to_rad(x), F::one())
     } else {
         let (n, y) = reduce_90_deg(x);
 
@@ -73,10 +74,10 @@ pub(crate) fn sind_cosd<F: SinCos + Reduce90Deg>(x: F) -> (F, F) {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>() {
+    fn test<F: Float + ElementaryMath>() {
         use crate::{cosd, sind, sind_cosd};
 
         let test_nan = |arg: F| {
@@ -106,6 +107,12 @@ mod tests {
         test_value(-F::ZERO, -F::ZERO, F::one());
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>();
@@ -115,4 +122,16 @@ mod tests {
     fn test_f64() {
         test::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
 }
@@ -171,10 +171,10 @@ pub(super) fn sinh_cosh_inner_common_2<F: Float>(
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>(hi_th: &str) {
+    fn test<F: Float + ElementaryMath>(hi_th: &str) {
         use crate::{cosh, sinh, sinh_cosh};
 
         let test_nan = |arg: F| {
@@ -212,6 +212,12 @@ mod tests {
         test_value(-F::ZERO, -F::ZERO, F::one());
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>("11.8");
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>("89.5");
@@ -221,4 +227,16 @@ mod tests {
     fn test_f64() {
         test::<f64>("710.5");
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>("11357.5");
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>("89.5");
+    }
 }
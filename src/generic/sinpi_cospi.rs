@@ -1,7 +1,7 @@
 use super::sin_cos::{cos_inner, sin_inner};
-use super::{ReduceHalfMulPi, SinCos, reduce_half_mul_pi};
+use super::{ReduceHalfMulPi, SinCos, reduce_half_mul_pi, tiny_raw_exp, tiny_scale_exp};
 use crate::double::SemiDouble;
-use crate::traits::{CastFrom as _, Int as _};
+use crate::traits::Int as _;
 
 pub(crate) fn sinpi<F: SinCos + ReduceHalfMulPi>(x: F) -> F {
     let e = x.raw_exp();
@@ -16,7 +16,7 @@ pub(crate) fn sinpi<F: SinCos + ReduceHalfMulPi>(x: F) -> F {
             // subnormal: sinpi(x) ~= x * π
 
             // scale temporarily to avoid temporary subnormal numbers
-            let logscale = F::Exp::TWO * F::Exp::cast_from(F::MANT_BITS);
+            let logscale = tiny_scale_exp::<F>();
             let scale = F::exp2i_fast(logscale);
             let descale = F::exp2i_fast(-logscale);
 
@@ -42,8 +42,8 @@ pub(crate) fn cospi<F: SinCos + ReduceHalfMulPi>(x: F) -> F {
     if e == F::MAX_RAW_EXP {
         // cospi(inf or nan) = nan
         F::NAN
-    } else if e <= F::RawExp::from(F::MANT_BITS) {
-        // subnormal or zero, cospi(x) ~= 1
+    } else if e <= tiny_raw_exp::<F>() {
+        // very small, cospi(x) ~= 1
         F::one()
     } else {
         let (n, y) = reduce_half_mul_pi(x);
@@ -75,7 +75,7 @@ pub(crate) fn sinpi_cospi<F: SinCos + ReduceHalfMulPi>(x: F) -> (F, F) {
             // cospi(x) ~= 1
 
             // scale temporarily to avoid temporary subnormal numbers
-            let logscale = F::Exp::TWO * F::Exp::cast_from(F::MANT_BITS);
+            let logscale = tiny_scale_exp::<F>();
             let scale = F::exp2i_fast(logscale);
             let descale = F::exp2i_fast(-logscale);
 
@@ -100,10 +100,10 @@ pub(crate) fn sinpi_cospi<F: SinCos + ReduceHalfMulPi>(x: F) -> (F, F) {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>() {
+    fn test<F: Float + ElementaryMath>() {
         use crate::{cospi, sinpi, sinpi_cospi};
 
         let test_nan = |arg: F| {
@@ -133,6 +133,12 @@ mod tests {
         test_value(-F::ZERO, -F::ZERO, F::one());
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>();
@@ -142,4 +148,16 @@ mod tests {
     fn test_f64() {
         test::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
 }
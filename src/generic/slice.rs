@@ -0,0 +1,135 @@
+use super::exp::{Exp, exp, exp_inner_reduced, exp_split};
+use super::ln::{Ln, ln, ln_inner};
+use super::reduce_pi_2::{ReducePi2, reduce_pi_2};
+use super::scalbn_medium;
+use super::sin_cos::{SinCos, cos, cos_inner, sin, sin_cos, sin_inner};
+use crate::slice::LANES;
+use crate::traits::{Float, Int as _};
+
+// Each function first checks whether any lane needs special handling (the
+// same checks that the scalar function does). In that case, all lanes are
+// calculated with the scalar function. Otherwise, the argument reduction is
+// done for each lane and then the polynomial approximations are evaluated
+// for all lanes without branches, so the compiler can vectorize them. Both
+// paths use the same operations, so the results are bit-identical.
+//
+// `slice_sin` and `slice_cos` only use the second path when no lane needs
+// argument reduction. Otherwise, the quadrant of each lane selects which
+// polynomial is needed and evaluating both of them for every lane is slower
+// than the scalar function.
+
+pub(crate) fn slice_exp<F: Exp>(x: &mut [F; LANES]) {
+    let common = x.iter().fold(true, |acc, &x| {
+        acc & (x > F::exp_lo_th()) & (x < F::exp_hi_th()) & (x.raw_exp() != F::RawExp::ZERO)
+    });
+    if !common {
+        x.iter_mut().for_each(|x| *x = exp(*x));
+        return;
+    }
+
+    let mut k = [0; LANES];
+    let mut r_lo = [F::ZERO; LANES];
+    for ((x, k), r_lo) in x.iter_mut().zip(k.iter_mut()).zip(r_lo.iter_mut()) {
+        let r_hi;
+        (*k, r_hi, *r_lo) = exp_split(*x);
+        *x = r_hi;
+    }
+    for (x, &r_lo) in x.iter_mut().zip(r_lo.iter()) {
+        *x = exp_inner_reduced(*x, r_lo);
+    }
+    for (x, &k) in x.iter_mut().zip(k.iter()) {
+        *x = scalbn_medium(*x, k);
+    }
+}
+
+pub(crate) fn slice_ln<F: Ln>(x: &mut [F; LANES]) {
+    let common = x.iter().fold(true, |acc, &x| {
+        let e = x.raw_exp();
+        acc & (e != F::RawExp::ZERO) & (e != F::MAX_RAW_EXP) & !x.sign()
+    });
+    if !common {
+        x.iter_mut().for_each(|x| *x = ln(*x));
+        return;
+    }
+
+    for x in x.iter_mut() {
+        *x = ln_inner(*x, F::Exp::ZERO);
+    }
+}
+
+pub(crate) fn slice_sin<F: SinCos + ReducePi2>(x: &mut [F; LANES]) {
+    if !sin_cos_small(x) {
+        x.iter_mut().for_each(|x| *x = sin(*x));
+        return;
+    }
+
+    // reduction not needed
+    x.iter_mut().for_each(|x| *x = sin_inner(*x, F::ZERO));
+}
+
+pub(crate) fn slice_cos<F: SinCos + ReducePi2>(x: &mut [F; LANES]) {
+    if !sin_cos_small(x) {
+        x.iter_mut().for_each(|x| *x = cos(*x));
+        return;
+    }
+
+    // reduction not needed
+    x.iter_mut().for_each(|x| *x = cos_inner(*x, F::ZERO));
+}
+
+/// Calculates the sine and cosine of `sin`, storing them in `sin` and `cos`
+pub(crate) fn slice_sin_cos<F: SinCos + ReducePi2>(sin: &mut [F; LANES], cos: &mut [F; LANES]) {
+    if !sin_cos_common(sin) {
+        for (s, c) in sin.iter_mut().zip(cos.iter_mut()) {
+            (*s, *c) = sin_cos(*s);
+        }
+        return;
+    }
+
+    sin_cos_lanes(sin, cos);
+}
+
+/// Returns whether all lanes are finite, non-zero and normal
+#[inline]
+fn sin_cos_common<F: Float>(x: &[F; LANES]) -> bool {
+    x.iter().fold(true, |acc, &x| {
+        let e = x.raw_exp();
+        acc & (e != F::RawExp::ZERO) & (e != F::MAX_RAW_EXP)
+    })
+}
+
+/// Returns whether all lanes are non-zero and normal with an absolute value
+/// of at most pi/4, so the argument reduction is not needed
+#[inline]
+fn sin_cos_small<F: ReducePi2>(x: &[F; LANES]) -> bool {
+    x.iter().fold(true, |acc, &x| {
+        acc & (x.raw_exp() != F::RawExp::ZERO) & (x.abs() <= F::FRAC_PI_4)
+    })
+}
+
+/// Calculates the sine and cosine of `sin`, storing them in `sin` and `cos`,
+/// when all lanes are finite, non-zero and normal
+#[inline]
+fn sin_cos_lanes<F: SinCos + ReducePi2>(sin: &mut [F; LANES], cos: &mut [F; LANES]) {
+    let mut n = [0; LANES];
+    for ((s, c), n) in sin.iter_mut().zip(cos.iter_mut()).zip(n.iter_mut()) {
+        (*n, *s, *c) = reduce_pi_2(*s);
+    }
+    for ((s, c), &n) in sin.iter_mut().zip(cos.iter_mut()).zip(n.iter()) {
+        let (y_hi, y_lo) = (*s, *c);
+        let sin = sin_inner(y_hi, y_lo);
+        let cos = cos_inner(y_hi, y_lo);
+
+        // same as the `match n` in the scalar functions
+        let (sin, cos) = if (n & 1) == 0 {
+            (sin, cos)
+        } else {
+            (cos, -sin)
+        };
+        (*s, *c) = if (n & 2) == 0 {
+            (sin, cos)
+        } else {
+            (-sin, -cos)
+        };
+    }
+}
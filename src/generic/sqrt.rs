@@ -1,5 +1,6 @@
+use crate::big_float::{BigFloat, MAX_WORDS};
 use crate::double::{DenormDouble, SemiDouble};
-use crate::traits::{Float, Int as _};
+use crate::traits::{CastFrom as _, Float, Int as _};
 
 pub(crate) fn sqrt<F: Float>(x: F) -> F {
     let (y, edelta) = x.normalize_arg();
@@ -51,6 +52,82 @@ fn sqrt_inner<F: Float>(x: F, edelta: F::Exp) -> F {
     F::from_raw(yraw)
 }
 
+pub(crate) fn rsqrt<F: Float>(x: F) -> F {
+    let (y, edelta) = x.normalize_arg();
+    let yexp = y.raw_exp();
+    if yexp == F::RawExp::ZERO {
+        // rsqrt(±0) = ±inf
+        F::INFINITY.copysign(y)
+    } else if y.sign() {
+        // x < 0, rsqrt(x) = NaN
+        F::NAN
+    } else if yexp == F::MAX_RAW_EXP {
+        if y.raw_mant() == F::Raw::ZERO {
+            // rsqrt(inf) = 0
+            F::ZERO
+        } else {
+            // propagate NaN
+            y
+        }
+    } else {
+        rsqrt_inner(y, edelta)
+    }
+}
+
+fn rsqrt_inner<F: Float>(x: F, edelta: F::Exp) -> F {
+    // Split x * 2^edelta = 2^k * r such as
+    // * k is an even integer
+    // * 1 <= r < 4
+    let k = x.exponent() + edelta;
+    let r = F::from_raw(x.raw_mant() | (F::Raw::from(F::EXP_OFFSET) << F::MANT_BITS));
+    let (k, r) = if (k & F::Exp::ONE) == F::Exp::ZERO {
+        (k, r)
+    } else {
+        (k - F::Exp::ONE, r * F::two())
+    };
+
+    // y0 = 1 / sqrt(r), with 0.5 < y0 <= 1 and an error of about one ULP
+    let y0 = DenormDouble::new(F::one() / sqrt_inner(r, F::Exp::ZERO), F::ZERO);
+
+    // Improve accuracy with a single Newton iteration
+    // y = y0 + y0 * (1 - r * y0^2) / 2
+    let e = DenormDouble::one().qsub2(DenormDouble::new(r, F::ZERO) * y0 * y0);
+    let y = y0.qadd2(y0 * e.pmul1(F::half())).to_norm();
+    let (y_hi, y_lo) = (y.hi(), y.lo());
+
+    // The error of y is far below this bound, so `y_hi` is the correctly
+    // rounded result unless `1 / sqrt(r)` is very close to the midpoint
+    // between `y_hi` and one of its neighbors.
+    let err = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS * 2 - 10));
+    let up = (y_hi + (y_lo + err)).purify();
+    let down = (y_hi + (y_lo - err)).purify();
+
+    let res = if up == y_hi && down == y_hi {
+        y_hi
+    } else {
+        // Decide exactly on which side of the midpoint `mid` the result is,
+        // with the sign of `r * mid^2 - 1`, which is never zero because
+        // `mid` has one more bit than `F`.
+        let nb = if y_lo > F::ZERO {
+            F::from_raw(y_hi.to_raw() + F::Raw::ONE)
+        } else {
+            F::from_raw(y_hi.to_raw() - F::Raw::ONE)
+        };
+        let mid = BigFloat::from_float(y_hi, MAX_WORDS)
+            .add(&BigFloat::from_float(nb, MAX_WORDS))
+            .scale(-1);
+        let t = mid
+            .mul(&mid)
+            .mul(&BigFloat::from_float(r, MAX_WORDS))
+            .add_i32(-1);
+        // `t < 0` means that `1 / sqrt(r)` is above `mid`
+        if t.is_neg() == (nb > y_hi) { nb } else { y_hi }
+    };
+
+    // rsqrt(x) = 2^(-k/2) * y
+    res * F::exp2i_fast(-(k >> 1))
+}
+
 fn sqrt_split<F: Float>(x: F, edelta: F::Exp) -> (F::Exp, F::Raw) {
     // Split x * 2^edelta = 2^(k - MANT_BITS) * m
 
@@ -96,15 +173,15 @@ pub(super) fn hi_lo_sqrt_hi_lo_inner<F: Float>(x: DenormDouble<F>) -> DenormDoub
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>(
+    fn test<F: Float + ElementaryMath>(
         full_e_mants: impl Clone + Iterator<Item = u64>,
         extra_e: impl Iterator<Item = i32>,
         extra_e_mants: impl Clone + Iterator<Item = u64>,
     ) {
-        use crate::{scalbn, sqrt};
+        use crate::{rsqrt, scalbn, sqrt};
 
         assert_is_nan!(sqrt(F::NAN));
         assert_is_nan!(sqrt(F::neg_infinity()));
@@ -113,6 +190,16 @@ mod tests {
         assert_total_eq!(sqrt(F::ZERO), F::ZERO);
         assert_total_eq!(sqrt(-F::ZERO), -F::ZERO);
 
+        assert_is_nan!(rsqrt(F::NAN));
+        assert_is_nan!(rsqrt(F::neg_infinity()));
+        assert_is_nan!(rsqrt(-F::one()));
+        assert_total_eq!(rsqrt(F::INFINITY), F::ZERO);
+        assert_total_eq!(rsqrt(F::ZERO), F::INFINITY);
+        assert_total_eq!(rsqrt(-F::ZERO), F::neg_infinity());
+        assert_total_eq!(rsqrt(F::one()), F::one());
+        assert_total_eq!(rsqrt(F::cast_from(4)), F::half());
+        assert_total_eq!(rsqrt(F::cast_from(64)), F::half() * F::half() * F::half());
+
         let min_normal_exp: i32 = F::MIN_NORMAL_EXP.into();
         let e_limit = (-min_normal_exp) / 2;
         for e in (-e_limit)..e_limit {
@@ -131,6 +218,12 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>(0x800..=0xFFF, core::iter::empty(), core::iter::empty());
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>(0x800..=0xFFF, core::iter::empty(), core::iter::empty());
@@ -140,4 +233,20 @@ mod tests {
     fn test_f64() {
         test::<f64>(0x800..=0xFFF, [-511, 0, 511].into_iter(), 0x8000..=0xFFFF);
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>(
+            0x8..=0xF,
+            [-8191, 0, 8191].into_iter(),
+            (1 << 55)..=((1 << 55) + 0x3FFF),
+        );
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>(0x80..=0xFF, core::iter::empty(), core::iter::empty());
+    }
 }
@@ -1,3 +1,4 @@
+use super::reduce_90_deg::tiny_deg_to_rad;
 use super::{Reduce90Deg, Tan, reduce_90_deg, tan::tan_inner};
 
 pub(crate) fn tand<F: Reduce90Deg + Tan>(x: F) -> F {
@@ -9,7 +10,7 @@ pub(crate) fn tand<F: Reduce90Deg + Tan>(x: F) -> F {
         // very small, includes subnormal and zero
         // tand(x) ~= x * (π/180)
         // also handles tand(-0) = -0
-        x * F::deg_to_rad()
+        tiny_deg_to_rad(x)
     } else {
         let (n, y) = reduce_90_deg(x);
         let inv = (n & 1) != 0;
@@ -23,10 +24,10 @@ pub(crate) fn tand<F: Reduce90Deg + Tan>(x: F) -> F {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>() {
+    fn test<F: Float + ElementaryMath>() {
         use crate::tand;
 
         assert_is_nan!(tand(F::NAN));
@@ -36,6 +37,12 @@ mod tests {
         assert_total_eq!(tand(-F::ZERO), -F::ZERO);
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>();
@@ -45,4 +52,16 @@ mod tests {
     fn test_f64() {
         test::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
 }
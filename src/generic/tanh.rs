@@ -55,10 +55,10 @@ fn tanh_inner<F: Exp>(x: F) -> F {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>(hi_th: &str) {
+    fn test<F: Float + ElementaryMath>(hi_th: &str) {
         use crate::tanh;
 
         let test_nan = |arg: F| {
@@ -86,6 +86,12 @@ mod tests {
         test_value(-F::ZERO, -F::ZERO);
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>("11.8");
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>("89.5");
@@ -95,4 +101,16 @@ mod tests {
     fn test_f64() {
         test::<f64>("710.5");
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>("11357.5");
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>("89.5");
+    }
 }
@@ -1,6 +1,8 @@
-use super::{ReduceHalfMulPi, Tan, reduce_half_mul_pi, tan::tan_inner};
+use super::{
+    ReduceHalfMulPi, Tan, reduce_half_mul_pi, tan::tan_inner, tiny_raw_exp, tiny_scale_exp,
+};
 use crate::double::SemiDouble;
-use crate::traits::{CastFrom as _, Int as _};
+use crate::traits::Int as _;
 
 pub(crate) fn tanpi<F: ReduceHalfMulPi + Tan>(x: F) -> F {
     let e = x.raw_exp();
@@ -10,11 +12,11 @@ pub(crate) fn tanpi<F: ReduceHalfMulPi + Tan>(x: F) -> F {
     } else if e == F::RawExp::ZERO && x.raw_mant() == F::Raw::ZERO {
         // tanpi(±0) = ±0
         x
-    } else if e <= F::RawExp::from(F::MANT_BITS) {
+    } else if e <= tiny_raw_exp::<F>() {
         // very small: tanpi(x) ~= x * π
 
         // scale temporarily to avoid temporary subnormal numbers
-        let logscale = F::Exp::TWO * F::Exp::cast_from(F::MANT_BITS);
+        let logscale = tiny_scale_exp::<F>();
         let scale = F::exp2i_fast(logscale);
         let descale = F::exp2i_fast(-logscale);
 
@@ -34,10 +36,10 @@ pub(crate) fn tanpi<F: ReduceHalfMulPi + Tan>(x: F) -> F {
 
 #[cfg(test)]
 mod tests {
-    use crate::FloatMath;
+    use crate::ElementaryMath;
     use crate::traits::Float;
 
-    fn test<F: Float + FloatMath>() {
+    fn test<F: Float + ElementaryMath>() {
         use crate::tanpi;
 
         assert_is_nan!(tanpi(F::NAN));
@@ -47,6 +49,12 @@ mod tests {
         assert_total_eq!(tanpi(-F::ZERO), -F::ZERO);
     }
 
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
     #[test]
     fn test_f32() {
         test::<f32>();
@@ -56,4 +64,16 @@ mod tests {
     fn test_f64() {
         test::<f64>();
     }
+
+    #[cfg(feature = "f128")]
+    #[test]
+    fn test_f128() {
+        test::<f128>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
 }
@@ -0,0 +1,307 @@
+use super::double_float::mul_dd_exp;
+use super::gamma::{gamma_inner_common, mul_exp};
+use super::ln::{hi_lo_ln_1p_hi_lo_inner, hi_lo_ln_hi_lo_inner};
+use super::sin_cos::{hi_lo_cos_inner, hi_lo_sin_inner};
+use super::{
+    DoubleFloatMath, Polygamma, dd_exp, dd_from, dd_ln, dd_mul, dd_new, is_int, reduce_half_mul_pi,
+};
+use crate::double::DenormDouble;
+use crate::traits::{CastFrom as _, Float, Int as _};
+
+pub(crate) fn zeta<F: Polygamma + DoubleFloatMath>(s: F) -> F {
+    let e = s.raw_exp();
+    if e == F::MAX_RAW_EXP {
+        if s.raw_mant() != F::Raw::ZERO {
+            // propagate NaN
+            s
+        } else if s.sign() {
+            // zeta(-inf) = NaN
+            F::NAN
+        } else {
+            // zeta(inf) = 1
+            F::one()
+        }
+    } else if s.abs() < F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS + 3)) {
+        // also handles s = ±0 and subnormals
+        // ζ(s) = -1/2 - ln(2π) / 2 * s + ..., where the second term is
+        // negligible (even for negative s, when the result is rounded
+        // towards the smaller ULP below 1/2)
+        -F::half()
+    } else if s >= F::cast_from(u32::from(F::MANT_BITS) + 2) {
+        // ζ(s) = 1 + 2^-s + 3^-s + ..., where the remaining terms are
+        // negligible
+        F::one()
+    } else if s == F::one() {
+        // zeta(1) = inf
+        F::INFINITY
+    } else if s > -F::one() {
+        zeta_direct(DenormDouble::new(s, F::ZERO)).to_single()
+    } else if is_int(s * F::half()) {
+        // zeta(negative even integer) = 0
+        F::ZERO
+    } else {
+        zeta_neg(s)
+    }
+}
+
+pub(crate) fn hurwitz_zeta<F: Polygamma + DoubleFloatMath>(s: F, q: F) -> F {
+    let s_exp = s.raw_exp();
+    let q_exp = q.raw_exp();
+    if (s_exp == F::MAX_RAW_EXP && s.raw_mant() != F::Raw::ZERO)
+        || (q_exp == F::MAX_RAW_EXP && q.raw_mant() != F::Raw::ZERO)
+    {
+        // hurwitz_zeta(NaN, q) = hurwitz_zeta(s, NaN) = NaN
+        F::NAN
+    } else if s < F::one() || q < F::ZERO {
+        // hurwitz_zeta(s, q) = NaN when s < 1 or q < 0
+        F::NAN
+    } else if s == F::one() || q == F::ZERO {
+        // the series diverges
+        F::INFINITY
+    } else if q_exp == F::MAX_RAW_EXP {
+        // hurwitz_zeta(s, inf) = 0
+        F::ZERO
+    } else if s_exp == F::MAX_RAW_EXP {
+        // hurwitz_zeta(inf, q) = lim q^-s
+        if q < F::one() {
+            F::INFINITY
+        } else if q == F::one() {
+            F::one()
+        } else {
+            F::ZERO
+        }
+    } else {
+        hurwitz_zeta_inner(s, q)
+    }
+}
+
+/// Calculates `ζ(s)` for `s > -1` and `s != 1`
+fn zeta_direct<F: Polygamma + DoubleFloatMath>(s: DenormDouble<F>) -> DenormDouble<F> {
+    // ζ(s) = 1^s * ζ(s, 1)
+    hurwitz_zeta_scaled(s, F::one(), DenormDouble::new(F::ZERO, F::ZERO))
+}
+
+/// Calculates `ζ(s)` for `s <= -1` that is not an even integer
+fn zeta_neg<F: Polygamma + DoubleFloatMath>(s: F) -> F {
+    // Use the reflection formula:
+    // ζ(s) = 2^s * π^(s - 1) * sin(πs / 2) * Γ(1 - s) * ζ(1 - s)
+    //      = (2π)^s * sin(πs / 2) * (-s) * Γ(-s) * ζ(1 - s) / π
+    // where Γ(1 - s) = -s * Γ(-s) avoids rounding 1 - s
+
+    // Γ(-s) = g_s * exp(g_y)
+    let (g_y, g_s) = gamma_inner_common(-s);
+
+    // ζ(1 - s), where 1 - s is exact as a double-float
+    let z = zeta_direct(DenormDouble::new_sub11(F::one(), s));
+
+    // sin(πs / 2)
+    let (n, r) = reduce_half_mul_pi(s * F::half());
+    let sin = match n {
+        0 => hi_lo_sin_inner(r),
+        1 => hi_lo_cos_inner(r),
+        2 => -hi_lo_sin_inner(r),
+        3 => -hi_lo_cos_inner(r),
+        _ => unreachable!(),
+    };
+
+    // y = s * ln(2π) + ln(Γ(-s) / g_s)
+    let y = F::half_ln_2_pi().to_semi() * (s * F::two()) + g_y;
+
+    // m = sin(πs / 2) * (-s) * g_s * ζ(1 - s) / π
+    let m = (sin.to_semi() * g_s.to_semi()).to_semi() * (z.to_semi() * -s).to_semi();
+    let m = m.to_semi() / F::pi_ex();
+
+    if y.hi() >= F::exp_hi_th() * F::two() {
+        F::INFINITY.copysign(m.hi())
+    } else if y.hi() <= F::exp_lo_th() * F::two() {
+        F::ZERO.copysign(m.hi())
+    } else {
+        mul_exp(m, y)
+    }
+}
+
+/// Calculates `ζ(s, q)` for `s > 1` and finite `q > 0`
+fn hurwitz_zeta_inner<F: Polygamma + DoubleFloatMath>(s: F, q: F) -> F {
+    // |ln(q) * s| can be large, so both the exponent and `exp` are
+    // calculated with full double-float accuracy to keep the error of
+    // the result below 1 ULP
+    let ln_q = dd_ln(dd_from(q));
+
+    // ζ(s, q) = m * exp(y)
+    let (m, y) = if q >= hurwitz_zeta_em_th(s) {
+        // Scale by q^(s - 1), which keeps `m` bounded when q is large:
+        // ζ(s, q) = (q^(s - 1) * ζ(s, q)) * q^(1 - s)
+        let m = hurwitz_zeta_em(
+            DenormDouble::new(s, F::ZERO),
+            DenormDouble::new(q, F::ZERO),
+            F::polygamma_em_coeffs(),
+        );
+        (m, dd_mul(ln_q, dd_new(F::one(), -s)))
+    } else {
+        // ζ(s, q) = (q^s * ζ(s, q)) * q^-s
+        let m = hurwitz_zeta_scaled(
+            DenormDouble::new(s, F::ZERO),
+            q,
+            DenormDouble::new(ln_q.hi, ln_q.lo),
+        );
+        (m, dd_mul(ln_q, dd_from(-s)))
+    };
+
+    if y.hi >= F::exp_hi_th() * F::two() {
+        F::INFINITY
+    } else if y.hi <= F::exp_lo_th() * F::two() {
+        F::ZERO
+    } else {
+        mul_dd_exp(m, y)
+    }
+}
+
+/// Returns the threshold above which the Euler-Maclaurin formula is
+/// accurate enough with the available coefficients
+fn hurwitz_zeta_em_th<F: Polygamma>(s: F) -> F {
+    s + F::cast_from(F::polygamma_em_coeffs().len() as u32 * 2 + 2)
+}
+
+/// Calculates `x^s * ζ(s, x)` for `0 < x < hurwitz_zeta_em_th(s)` and
+/// either `s > 1`, or `-1 < s < 1` with `x = 1`
+///
+/// `ln_x` is `ln(x)`, only used when `x < 1`.
+fn hurwitz_zeta_scaled<F: Polygamma + DoubleFloatMath>(
+    s: DenormDouble<F>,
+    x: F,
+    ln_x: DenormDouble<F>,
+) -> DenormDouble<F> {
+    let coeffs = F::polygamma_em_coeffs();
+    let em_th = hurwitz_zeta_em_th(s.hi());
+
+    // Remaining terms are negligible when they are below this threshold
+    // relative to the sum
+    let negl_th = F::exp2i_fast(-F::Exp::cast_from(F::MANT_BITS * 2));
+    let s_m1 = (s - F::one()).to_single();
+
+    // The first term is (x / x)^s = 1
+    let mut sum = DenormDouble::one();
+    let mut k = F::one();
+    loop {
+        let t = DenormDouble::new_add11(x, k);
+
+        // q = (x / t)^s = exp(s * ln(x / t))
+        let ln_x_t = if x >= F::one() {
+            // ln(x / t) = -ln(1 + k / x), which keeps its relative
+            // accuracy when x is large
+            -hi_lo_ln_1p_hi_lo_inner(DenormDouble::new_div11(k, x).to_norm())
+        } else {
+            // t >= 1 > x, so there is no cancellation
+            ln_x - hi_lo_ln_hi_lo_inner(t.to_norm(), F::Exp::ZERO)
+        };
+        // `exp` is calculated with full double-float accuracy, because the
+        // sum cancels with the Euler-Maclaurin tail when s < 1, and the
+        // last term is multiplied by 1 / (s - 1) when s is close to 1
+        let y = s * ln_x_t;
+        let q = dd_exp(dd_new(y.hi(), y.lo()));
+        let q = DenormDouble::new(q.hi, q.lo);
+
+        if t.hi() >= em_th {
+            // sum + (x / t)^s * t * (t^(s - 1) * ζ(s, t))
+            return sum + q * t * hurwitz_zeta_em(s, t, coeffs);
+        }
+
+        sum = sum + q;
+
+        // When s > 1, the sum of the remaining terms is less than
+        // q * t / (s - 1)
+        if q.hi() * t.hi() <= sum.hi() * negl_th * s_m1 {
+            return sum;
+        }
+
+        k = k + F::one();
+    }
+}
+
+/// Calculates `x^(s - 1) * ζ(s, x)` using the Euler-Maclaurin summation
+/// formula:
+///
+/// ```text
+/// x^(s - 1) * ζ(s, x) ~= 1 / (s - 1) + 1 / (2 * x) + sum(B_2j / (2j)! * s * ... * (s + 2j - 2) / x^(2j))
+/// ```
+fn hurwitz_zeta_em<F: Float>(
+    s: DenormDouble<F>,
+    x: DenormDouble<F>,
+    coeffs: &[F],
+) -> DenormDouble<F> {
+    let u = F::one() / x.hi();
+
+    // The first term of the sum, with B_2 / 2! = 1/12, is not small
+    // relative to the result when s is close to -1, so it is calculated
+    // with double-float arithmetic and skipped here
+    let mut a = s.hi();
+    let mut f = a * u * u;
+    let mut corr = F::ZERO;
+    for &c in &coeffs[1..] {
+        f = f * ((a + F::one()) * u) * ((a + F::two()) * u);
+        a = a + F::two();
+        corr = corr + c * f;
+    }
+
+    let x_inv = DenormDouble::one() / x;
+    let t1 = DenormDouble::one() / (s - F::one()).normalize();
+    let t2 = x_inv.pmul1(F::half());
+    let t3 = s * x_inv * x_inv / DenormDouble::new(F::cast_from(12), F::ZERO);
+    (t1 + t2 + t3).qadd1(corr)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::FloatMath;
+    use crate::traits::Float;
+
+    fn test<F: Float + FloatMath>() {
+        use crate::{hurwitz_zeta, zeta};
+
+        assert_is_nan!(zeta(F::NAN));
+        assert_is_nan!(zeta(F::neg_infinity()));
+        assert_total_eq!(zeta(F::INFINITY), F::one());
+        assert_total_eq!(zeta(F::one()), F::INFINITY);
+        assert_total_eq!(zeta(F::ZERO), -F::half());
+        assert_total_eq!(zeta(-F::ZERO), -F::half());
+        assert_total_eq!(zeta(-F::two()), F::ZERO);
+        assert_total_eq!(zeta(-F::largest()), F::ZERO);
+        assert_total_eq!(zeta(F::largest()), F::one());
+
+        assert_is_nan!(hurwitz_zeta(F::NAN, F::one()));
+        assert_is_nan!(hurwitz_zeta(F::two(), F::NAN));
+        assert_is_nan!(hurwitz_zeta(F::half(), F::one()));
+        assert_is_nan!(hurwitz_zeta(F::neg_infinity(), F::one()));
+        assert_is_nan!(hurwitz_zeta(F::two(), -F::one()));
+        assert_is_nan!(hurwitz_zeta(F::two(), F::neg_infinity()));
+        assert_total_eq!(hurwitz_zeta(F::one(), F::one()), F::INFINITY);
+        assert_total_eq!(hurwitz_zeta(F::two(), F::ZERO), F::INFINITY);
+        assert_total_eq!(hurwitz_zeta(F::two(), -F::ZERO), F::INFINITY);
+        assert_total_eq!(hurwitz_zeta(F::two(), F::INFINITY), F::ZERO);
+        assert_total_eq!(hurwitz_zeta(F::INFINITY, F::half()), F::INFINITY);
+        assert_total_eq!(hurwitz_zeta(F::INFINITY, F::one()), F::one());
+        assert_total_eq!(hurwitz_zeta(F::INFINITY, F::two()), F::ZERO);
+    }
+
+    #[cfg(feature = "f16")]
+    #[test]
+    fn test_f16() {
+        test::<f16>();
+    }
+
+    #[test]
+    fn test_f32() {
+        test::<f32>();
+    }
+
+    #[test]
+    fn test_f64() {
+        test::<f64>();
+    }
+
+    #[cfg(feature = "bf16")]
+    #[test]
+    fn test_bf16() {
+        test::<half::bf16>();
+    }
+}
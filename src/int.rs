@@ -22,8 +22,16 @@ macro_rules! impl_int {
         impl_cast_from!($t as i32);
         impl_cast_from!($t as u64);
         impl_cast_from!($t as i64);
+        #[cfg(feature = "f128")]
+        impl_cast_from!($t as u128);
+        #[cfg(feature = "f128")]
+        impl_cast_from!($t as i128);
+        #[cfg(feature = "f16")]
+        impl_cast_from!($t as f16);
         impl_cast_from!($t as f32);
         impl_cast_from!($t as f64);
+        #[cfg(feature = "f128")]
+        impl_cast_from!($t as f128);
 
         impl traits::Int for $t {
             const ZERO: Self = 0;
@@ -31,6 +39,11 @@ macro_rules! impl_int {
             const TWO: Self = 2;
 
             const MAX: Self = <$t>::MAX;
+
+            #[inline]
+            fn leading_zeros(self) -> u32 {
+                <$t>::leading_zeros(self)
+            }
         }
     };
 }
@@ -53,8 +66,12 @@ impl_uint!(u8);
 impl_uint!(u16);
 impl_uint!(u32);
 impl_uint!(u64);
+#[cfg(feature = "f128")]
+impl_uint!(u128);
 
 impl_sint!(i8);
 impl_sint!(i16);
 impl_sint!(i32);
 impl_sint!(i64);
+#[cfg(feature = "f128")]
+impl_sint!(i128);
@@ -6,22 +6,30 @@
     unused_qualifications
 )]
 #![no_std]
+#![cfg_attr(feature = "f16", feature(f16))]
+#![cfg_attr(feature = "f128", feature(f128))]
 
 //! A pure-Rust floating point math library.
 //!
-//! This crate is `no_std`.
+//! This crate is `no_std`. The `std` feature only enables run time CPU
+//! feature detection for the [`x86_64`] module.
 //!
 //! The following math functions are implemented:
 //!
 //! * Sign operations ([`abs`], [`copysign`]).
 //! * Rounding ([`round`], [`trunc`], [`ceil`], [`floor`]).
-//! * Exponential ([`exp`], [`exp_m1`], [`exp2`], [`exp10`]).
-//! * Logarithmic ([`ln`], [`ln_1p`], [`log2`], [`log10`]).
-//! * Power ([`pow`], [`powi`]).
+//! * Exponential ([`exp`], [`exp_m1`], [`exp2`], [`exp2_m1`], [`exp10`],
+//!   [`exp10_m1`]).
+//! * Logarithmic ([`ln`], [`ln_1p`], [`log2`], [`log2_1p`], [`log10`],
+//!   [`log10_1p`]).
+//! * Unrounded `(hi, lo)` results ([`exp_hi_lo`], [`ln_hi_lo`],
+//!   [`ln_1p_hi_lo`], [`sin_cos_hi_lo`]).
+//! * Power ([`pow`], [`powi`], [`powr`], [`pown`], [`rootn`], [`compound`]).
 //! * Trigonometric
 //!   - Radians ([`sin`], [`cos`], [`sin_cos`], [`tan`]).
 //!   - Degrees ([`sind`], [`cosd`], [`sind_cosd`], [`tand`]).
 //!   - Half-revolutions ([`sinpi`], [`cospi`], [`sinpi_cospi`], [`tanpi`]).
+//! * Angle reduction ([`rem_pio2`], [`wrap_angle`]).
 //! * Inverse trigonometric
 //!   - Radians ([`asin`], [`acos`], [`atan`], [`atan2`]).
 //!   - Degrees ([`asind`], [`acosd`], [`atand`], [`atan2d`]).
@@ -29,16 +37,64 @@
 //! * Hyperbolic ([`sinh`], [`cosh`], [`sinh_cosh`], [`tanh`]).
 //! * Inverse hyperbolic ([`asinh`], [`acosh`], [`atanh`]).
 //! * Gamma ([`gamma`], [`ln_gamma`]).
+//! * Regularized incomplete gamma ([`gamma_p`], [`gamma_q`]) and its inverse
+//!   ([`gamma_p_inv`], [`gamma_q_inv`]).
+//! * Digamma and polygamma ([`digamma`], [`polygamma`]).
+//! * Riemann zeta ([`zeta`]) and Hurwitz zeta ([`hurwitz_zeta`]).
+//! * Beta ([`beta`], [`ln_beta`]).
+//! * Regularized incomplete beta ([`beta_inc`]) and its inverse
+//!   ([`beta_inc_inv`]).
+//! * Error function ([`erf`], [`erfc`]).
+//! * Inverse error function ([`erfinv`], [`erfcinv`]).
+//! * Bessel functions
+//!   - First kind ([`j0`], [`j1`], [`jn`]).
+//!   - Second kind ([`y0`], [`y1`], [`yn`]).
+//! * Lambert W ([`lambert_w0`], [`lambert_wm1`]).
 //!
 //! All functions are implemted for the native floating point types [`prim@f32`]
 //! and [`prim@f64`].
 //!
-//! The [`FloatMath`] trait is used to identify types that support the math
-//! functions.
+//! With the `f16` feature (requires a nightly compiler), they are also
+//! implemented for `f16`. Some of them are evaluated in `f32`, because the
+//! precision and exponent range of `f16` are too small for their algorithms.
+//!
+//! With the `f128` feature (requires a nightly compiler), the elementary
+//! functions (all except gamma, beta, error and Bessel functions and their
+//! relatives) are also implemented for `f128`.
+//!
+//! With the `bf16` feature, they are also implemented for the `bf16` type of
+//! the [`half`](https://docs.rs/half) crate. They are evaluated in `f64` and
+//! are intended to be correctly rounded. This is verified exhaustively for the
+//! functions with one argument, with sampled inputs for the functions with two
+//! arguments, and only for `|x| < 1024` and a few values of `n` for
+//! [`jn`] and [`yn`].
+//!
+//! The [`ElementaryMath`] trait is used to identify types that support the
+//! elementary functions and the [`FloatMath`] trait is used to identify types
+//! that support all the math functions.
+//!
+//! The [`DoubleFloat`] type provides double-float arithmetic (about twice
+//! the precision of the underlying type) with some elementary functions.
+//!
+//! The [`cr`] module provides correctly rounded versions of the exponential,
+//! logarithm, power, cube root, `hypot`, trigonometric, inverse
+//! trigonometric and hyperbolic functions.
+//!
+//! The [`complex`] module provides a [`Complex`](complex::Complex) type with
+//! arithmetic operations and elementary functions.
+//!
+//! The [`slice`](mod@slice) module provides versions of some functions that operate on
+//! slices, which can be faster than calling the scalar functions in a loop.
+//!
+//! The [`simd`] module provides portable SIMD vector types (such as
+//! [`F64x4`](simd::F64x4)) with lane-wise versions of all the math
+//! functions, some of which are evaluated for all lanes at once.
+//!
+//! On x86_64, the [`x86_64`] module provides versions of some functions that
+//! operate on the lanes of SIMD vectors (`__m256d` and `__m256`).
 
-// TODO:
-// * Error function and complementary (erf, erfc)
-// * Bessel functions (j0, y0, j1, y1, jn, yn)
+#[cfg(feature = "std")]
+extern crate std;
 
 // Uncomment to use `dbg!`
 //extern crate std;
@@ -48,7 +104,62 @@ macro_rules! horner {
         $outer_x * $coef
     };
     ($outer_x:ident, $inner_x:ident, [$coef0:expr, $($coefs:expr),+]) => {
-        $outer_x * ($coef0 + horner!($inner_x, $inner_x, [$($coefs),+]))
+        $outer_x * (horner!($inner_x, $inner_x, [$($coefs),+]) + $coef0)
+    };
+}
+
+/// Implements `Neg`, the operations with a scalar and `Lanes` for a vector
+/// type that implements `Simd` and the arithmetic operations between vectors
+///
+/// `$purify` is the implementation of `Lanes::purify`.
+macro_rules! impl_lanes {
+    ($vec:ty, $scalar:ty, purify = $purify:expr) => {
+        impl core::ops::Neg for $vec {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                let sign = <Self as crate::traits::Lanes>::splat(-0.0);
+                <Self as crate::traits::Simd>::xor(self, sign)
+            }
+        }
+
+        impl_lanes!(@scalar_op $vec, $scalar, Add, add);
+        impl_lanes!(@scalar_op $vec, $scalar, Sub, sub);
+        impl_lanes!(@scalar_op $vec, $scalar, Mul, mul);
+        impl_lanes!(@scalar_op $vec, $scalar, Div, div);
+
+        impl crate::traits::Lanes for $vec {
+            type Scalar = $scalar;
+
+            #[inline]
+            fn splat(value: $scalar) -> Self {
+                <Self as crate::traits::Simd>::splat_raw(value.to_bits())
+            }
+
+            #[inline]
+            fn purify(self) -> Self {
+                let purify: fn(Self) -> Self = $purify;
+                purify(self)
+            }
+
+            #[inline]
+            fn split_hi(self) -> Self {
+                let mask = crate::traits::float_split_hi(<$scalar>::from_bits(!0));
+                let mask = <Self as crate::traits::Simd>::splat_raw(mask.to_bits());
+                <Self as crate::traits::Simd>::and(self, mask)
+            }
+        }
+    };
+    (@scalar_op $vec:ty, $scalar:ty, $op:ident, $method:ident) => {
+        impl core::ops::$op<$scalar> for $vec {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: $scalar) -> Self {
+                core::ops::$op::$method(self, <Self as crate::traits::Lanes>::splat(rhs))
+            }
+        }
     };
 }
 
@@ -66,8 +177,8 @@ macro_rules! assert_is_nan {
 macro_rules! assert_total_eq {
     ($lhs:expr, $rhs:expr) => {
         let [lhs, rhs] = [$lhs, $rhs];
-        let lhs = $crate::traits::Float::purify(lhs);
-        let rhs = $crate::traits::Float::purify(rhs);
+        let lhs = $crate::traits::Lanes::purify(lhs);
+        let rhs = $crate::traits::Lanes::purify(rhs);
         let lhs_raw = $crate::traits::Float::to_raw(lhs);
         let rhs_raw = $crate::traits::Float::to_raw(rhs);
         if lhs_raw != rhs_raw {
@@ -76,19 +187,102 @@ macro_rules! assert_total_eq {
     };
 }
 
+#[cfg(feature = "bf16")]
+mod bf16;
+mod big_float;
+pub mod complex;
+pub mod cr;
 mod double;
+mod double_float;
+#[cfg(feature = "f128")]
+mod f128;
+#[cfg(feature = "f16")]
+mod f16;
 mod f32;
 mod f64;
 mod generic;
 mod int;
+pub mod simd;
+pub mod slice;
 mod traits;
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+pub mod x86_64;
+
+pub use double_float::DoubleFloat;
 
 mod sealed {
-    pub trait SealedMath {}
+    use crate::DoubleFloat;
+    use crate::complex::Complex;
+    use crate::slice::LANES;
+
+    pub trait SealedMath: Copy + PartialOrd {
+        fn dd_from(x: Self) -> DoubleFloat<Self>;
+        fn dd_new(hi: Self, lo: Self) -> DoubleFloat<Self>;
+        fn dd_neg(x: DoubleFloat<Self>) -> DoubleFloat<Self>;
+        fn dd_add(x: DoubleFloat<Self>, y: DoubleFloat<Self>) -> DoubleFloat<Self>;
+        fn dd_sub(x: DoubleFloat<Self>, y: DoubleFloat<Self>) -> DoubleFloat<Self>;
+        fn dd_mul(x: DoubleFloat<Self>, y: DoubleFloat<Self>) -> DoubleFloat<Self>;
+        fn dd_div(x: DoubleFloat<Self>, y: DoubleFloat<Self>) -> DoubleFloat<Self>;
+        fn dd_sqrt(x: DoubleFloat<Self>) -> DoubleFloat<Self>;
+        fn dd_exp(x: DoubleFloat<Self>) -> DoubleFloat<Self>;
+        fn dd_ln(x: DoubleFloat<Self>) -> DoubleFloat<Self>;
+        fn dd_sin_cos(x: DoubleFloat<Self>) -> (DoubleFloat<Self>, DoubleFloat<Self>);
+
+        fn cr_exp(x: Self) -> Self;
+        fn cr_exp_m1(x: Self) -> Self;
+        fn cr_exp2(x: Self) -> Self;
+        fn cr_exp10(x: Self) -> Self;
+        fn cr_ln(x: Self) -> Self;
+        fn cr_ln_1p(x: Self) -> Self;
+        fn cr_log2(x: Self) -> Self;
+        fn cr_log10(x: Self) -> Self;
+        fn cr_pow(x: Self, y: Self) -> Self;
+        fn cr_cbrt(x: Self) -> Self;
+        fn cr_hypot(x: Self, y: Self) -> Self;
+        fn cr_sin(x: Self) -> Self;
+        fn cr_cos(x: Self) -> Self;
+        fn cr_tan(x: Self) -> Self;
+        fn cr_asin(x: Self) -> Self;
+        fn cr_acos(x: Self) -> Self;
+        fn cr_atan(x: Self) -> Self;
+        fn cr_atan2(y: Self, x: Self) -> Self;
+        fn cr_sinh(x: Self) -> Self;
+        fn cr_cosh(x: Self) -> Self;
+        fn cr_tanh(x: Self) -> Self;
+
+        fn c_neg(z: Complex<Self>) -> Complex<Self>;
+        fn c_conj(z: Complex<Self>) -> Complex<Self>;
+        fn c_add(x: Complex<Self>, y: Complex<Self>) -> Complex<Self>;
+        fn c_sub(x: Complex<Self>, y: Complex<Self>) -> Complex<Self>;
+        fn c_mul(x: Complex<Self>, y: Complex<Self>) -> Complex<Self>;
+        fn c_div(x: Complex<Self>, y: Complex<Self>) -> Complex<Self>;
+        fn c_exp(z: Complex<Self>) -> Complex<Self>;
+        fn c_ln(z: Complex<Self>) -> Complex<Self>;
+        fn c_sqrt(z: Complex<Self>) -> Complex<Self>;
+        fn c_pow(z: Complex<Self>, w: Complex<Self>) -> Complex<Self>;
+        fn c_sin(z: Complex<Self>) -> Complex<Self>;
+        fn c_cos(z: Complex<Self>) -> Complex<Self>;
+        fn c_tan(z: Complex<Self>) -> Complex<Self>;
+        fn c_sinh(z: Complex<Self>) -> Complex<Self>;
+        fn c_cosh(z: Complex<Self>) -> Complex<Self>;
+        fn c_tanh(z: Complex<Self>) -> Complex<Self>;
+        fn c_asin(z: Complex<Self>) -> Complex<Self>;
+        fn c_acos(z: Complex<Self>) -> Complex<Self>;
+        fn c_atan(z: Complex<Self>) -> Complex<Self>;
+        fn c_asinh(z: Complex<Self>) -> Complex<Self>;
+        fn c_acosh(z: Complex<Self>) -> Complex<Self>;
+        fn c_atanh(z: Complex<Self>) -> Complex<Self>;
+
+        fn slice_exp(x: &mut [Self; LANES]);
+        fn slice_ln(x: &mut [Self; LANES]);
+        fn slice_sin(x: &mut [Self; LANES]);
+        fn slice_cos(x: &mut [Self; LANES]);
+        fn slice_sin_cos(sin: &mut [Self; LANES], cos: &mut [Self; LANES]);
+    }
 }
 
-/// Floating point types with math functions.
-pub trait FloatMath: sealed::SealedMath + Sized {
+/// Floating point types with elementary math functions.
+pub trait ElementaryMath: sealed::SealedMath + Sized {
     /// See the [`abs`] function.
     fn abs(x: Self) -> Self;
 
@@ -113,12 +307,27 @@ pub trait FloatMath: sealed::SealedMath + Sized {
     /// See the [`frexp`] function.
     fn frexp(x: Self) -> (Self, i32);
 
+    /// See the [`fma`] function.
+    fn fma(x: Self, y: Self, z: Self) -> Self;
+
+    /// See the [`fmod`] function.
+    fn fmod(x: Self, y: Self) -> Self;
+
+    /// See the [`remainder`] function.
+    fn remainder(x: Self, y: Self) -> Self;
+
+    /// See the [`remquo`] function.
+    fn remquo(x: Self, y: Self) -> (Self, i32);
+
     /// See the [`hypot`] function.
     fn hypot(x: Self, y: Self) -> Self;
 
     /// See the [`sqrt`] function.
     fn sqrt(x: Self) -> Self;
 
+    /// See the [`rsqrt`] function.
+    fn rsqrt(x: Self) -> Self;
+
     /// See the [`cbrt`] function.
     fn cbrt(x: Self) -> Self;
 
@@ -128,30 +337,63 @@ pub trait FloatMath: sealed::SealedMath + Sized {
     /// See the [`exp_m1`] function.
     fn exp_m1(x: Self) -> Self;
 
+    /// See the [`exp_hi_lo`] function.
+    fn exp_hi_lo(x: Self) -> (Self, Self);
+
     /// See the [`exp2`] function.
     fn exp2(x: Self) -> Self;
 
+    /// See the [`exp2_m1`] function.
+    fn exp2_m1(x: Self) -> Self;
+
     /// See the [`exp10`] function.
     fn exp10(x: Self) -> Self;
 
+    /// See the [`exp10_m1`] function.
+    fn exp10_m1(x: Self) -> Self;
+
     /// See the [`ln`] function.
     fn ln(x: Self) -> Self;
 
     /// See the [`ln_1p`] function.
     fn ln_1p(x: Self) -> Self;
 
+    /// See the [`ln_hi_lo`] function.
+    fn ln_hi_lo(x: Self) -> (Self, Self);
+
+    /// See the [`ln_1p_hi_lo`] function.
+    fn ln_1p_hi_lo(x: Self) -> (Self, Self);
+
     /// See the [`log2`] function.
     fn log2(x: Self) -> Self;
 
+    /// See the [`log2_1p`] function.
+    fn log2_1p(x: Self) -> Self;
+
     /// See the [`log10`] function.
     fn log10(x: Self) -> Self;
 
+    /// See the [`log10_1p`] function.
+    fn log10_1p(x: Self) -> Self;
+
     /// See the [`pow`] function.
     fn pow(x: Self, y: Self) -> Self;
 
     /// See the [`powi`] function.
     fn powi(x: Self, y: i32) -> Self;
 
+    /// See the [`powr`] function.
+    fn powr(x: Self, y: Self) -> Self;
+
+    /// See the [`pown`] function.
+    fn pown(x: Self, n: i64) -> Self;
+
+    /// See the [`rootn`] function.
+    fn rootn(x: Self, n: i64) -> Self;
+
+    /// See the [`compound`] function.
+    fn compound(x: Self, n: i64) -> Self;
+
     /// See the [`sin`] function.
     fn sin(x: Self) -> Self;
 
@@ -161,9 +403,18 @@ pub trait FloatMath: sealed::SealedMath + Sized {
     /// See the [`sin_cos`] function.
     fn sin_cos(x: Self) -> (Self, Self);
 
+    /// See the [`sin_cos_hi_lo`] function.
+    fn sin_cos_hi_lo(x: Self) -> ((Self, Self), (Self, Self));
+
     /// See the [`tan`] function.
     fn tan(x: Self) -> Self;
 
+    /// See the [`rem_pio2`] function.
+    fn rem_pio2(x: Self) -> (i32, Self, Self);
+
+    /// See the [`wrap_angle`] function.
+    fn wrap_angle(x: Self) -> Self;
+
     /// See the [`sind`] function.
     fn sind(x: Self) -> Self;
 
@@ -244,48 +495,124 @@ pub trait FloatMath: sealed::SealedMath + Sized {
 
     /// See the [`atanh`] function.
     fn atanh(x: Self) -> Self;
+}
 
+/// Floating point types with all math functions, including the special
+/// functions.
+pub trait FloatMath: ElementaryMath {
     /// See the [`gamma`] function.
     fn gamma(x: Self) -> Self;
 
     /// See the [`ln_gamma`] function.
     fn ln_gamma(x: Self) -> (Self, i8);
+
+    /// See the [`gamma_p`] function.
+    fn gamma_p(a: Self, x: Self) -> Self;
+
+    /// See the [`gamma_q`] function.
+    fn gamma_q(a: Self, x: Self) -> Self;
+
+    /// See the [`gamma_p_inv`] function.
+    fn gamma_p_inv(a: Self, p: Self) -> Self;
+
+    /// See the [`gamma_q_inv`] function.
+    fn gamma_q_inv(a: Self, q: Self) -> Self;
+
+    /// See the [`digamma`] function.
+    fn digamma(x: Self) -> Self;
+
+    /// See the [`polygamma`] function.
+    fn polygamma(n: i32, x: Self) -> Self;
+
+    /// See the [`zeta`] function.
+    fn zeta(s: Self) -> Self;
+
+    /// See the [`hurwitz_zeta`] function.
+    fn hurwitz_zeta(s: Self, q: Self) -> Self;
+
+    /// See the [`beta`] function.
+    fn beta(a: Self, b: Self) -> Self;
+
+    /// See the [`ln_beta`] function.
+    fn ln_beta(a: Self, b: Self) -> (Self, i8);
+
+    /// See the [`beta_inc`] function.
+    fn beta_inc(a: Self, b: Self, x: Self) -> Self;
+
+    /// See the [`beta_inc_inv`] function.
+    fn beta_inc_inv(a: Self, b: Self, p: Self) -> Self;
+
+    /// See the [`erf`] function.
+    fn erf(x: Self) -> Self;
+
+    /// See the [`erfc`] function.
+    fn erfc(x: Self) -> Self;
+
+    /// See the [`erfinv`] function.
+    fn erfinv(x: Self) -> Self;
+
+    /// See the [`erfcinv`] function.
+    fn erfcinv(x: Self) -> Self;
+
+    /// See the [`j0`] function.
+    fn j0(x: Self) -> Self;
+
+    /// See the [`j1`] function.
+    fn j1(x: Self) -> Self;
+
+    /// See the [`jn`] function.
+    fn jn(n: i32, x: Self) -> Self;
+
+    /// See the [`y0`] function.
+    fn y0(x: Self) -> Self;
+
+    /// See the [`y1`] function.
+    fn y1(x: Self) -> Self;
+
+    /// See the [`yn`] function.
+    fn yn(n: i32, x: Self) -> Self;
+
+    /// See the [`lambert_w0`] function.
+    fn lambert_w0(x: Self) -> Self;
+
+    /// See the [`lambert_wm1`] function.
+    fn lambert_wm1(x: Self) -> Self;
 }
 
 /// Calculates the absolute value of `x`
 #[inline]
-pub fn abs<F: FloatMath>(x: F) -> F {
+pub fn abs<F: ElementaryMath>(x: F) -> F {
     F::abs(x)
 }
 
 /// Returns a value with the magnitude of `x` and the sign of `y`
 #[inline]
-pub fn copysign<F: FloatMath>(x: F, y: F) -> F {
+pub fn copysign<F: ElementaryMath>(x: F, y: F) -> F {
     F::copysign(x, y)
 }
 
 /// Rounds `x` to the nearest integer, ties round away from zero
-pub fn round<F: FloatMath>(x: F) -> F {
+pub fn round<F: ElementaryMath>(x: F) -> F {
     F::round(x)
 }
 
 /// Rounds `x` to the nearest integer that is not greater in magnitude than `x`
-pub fn trunc<F: FloatMath>(x: F) -> F {
+pub fn trunc<F: ElementaryMath>(x: F) -> F {
     F::trunc(x)
 }
 
 /// Rounds `x` to the nearest integer that is not less than `x`
-pub fn ceil<F: FloatMath>(x: F) -> F {
+pub fn ceil<F: ElementaryMath>(x: F) -> F {
     F::ceil(x)
 }
 
 /// Rounds `x` to the nearest integer that is not greater than `x`
-pub fn floor<F: FloatMath>(x: F) -> F {
+pub fn floor<F: ElementaryMath>(x: F) -> F {
     F::floor(x)
 }
 
 /// Calculates `x` times two raised to `y`.
-pub fn scalbn<F: FloatMath>(x: F, y: i32) -> F {
+pub fn scalbn<F: ElementaryMath>(x: F, y: i32) -> F {
     F::scalbn(x, y)
 }
 
@@ -297,10 +624,62 @@ pub fn scalbn<F: FloatMath>(x: F, y: i32) -> F {
 ///
 /// When `x` is zero, infinity or NaN, returns `x` as mantissa and zero as
 /// exponent.
-pub fn frexp<F: FloatMath>(x: F) -> (F, i32) {
+pub fn frexp<F: ElementaryMath>(x: F) -> (F, i32) {
     F::frexp(x)
 }
 
+/// Calculates `x * y + z` with a single rounding (fused multiply-add)
+///
+/// The result is correctly rounded. It is calculated with integer
+/// arithmetic, so it does not depend on hardware FMA support.
+///
+/// Special cases:
+/// * Returns NaN if `x`, `y` or `z` is NaN
+/// * Returns NaN if `x` or `y` is infinity and the other is zero
+/// * Returns NaN if `x * y` is infinity and `z` is infinity with the opposite
+///   sign
+pub fn fma<F: ElementaryMath>(x: F, y: F, z: F) -> F {
+    F::fma(x, y, z)
+}
+
+/// Calculates the remainder of `x / y`, with the quotient rounded towards
+/// zero
+///
+/// The result is exact and equal to `x - n * y`, where `n` is `x / y`
+/// rounded towards zero. It has the same sign as `x`.
+///
+/// Special cases:
+/// * Returns NaN if `x` is infinity or NaN, or `y` is zero or NaN
+/// * Returns `x` if `y` is infinity and `x` is finite
+pub fn fmod<F: ElementaryMath>(x: F, y: F) -> F {
+    F::fmod(x, y)
+}
+
+/// Calculates the IEEE remainder of `x / y`
+///
+/// The result is exact and equal to `x - n * y`, where `n` is `x / y`
+/// rounded to the nearest integer, ties to even. When the result is zero,
+/// it has the sign of `x`.
+///
+/// Special cases:
+/// * Returns NaN if `x` is infinity or NaN, or `y` is zero or NaN
+/// * Returns `x` if `y` is infinity and `x` is finite
+pub fn remainder<F: ElementaryMath>(x: F, y: F) -> F {
+    F::remainder(x, y)
+}
+
+/// Calculates the IEEE remainder of `x / y` and the lowest bits of the
+/// quotient
+///
+/// Returns `(r, q)`, where `r` is [`remainder(x, y)`](remainder) and `q`
+/// has the sign of `x / y` and the 31 lowest bits of the magnitude of the
+/// rounded quotient `n` (see [`remainder`]).
+///
+/// `q` is zero in the special cases of [`remainder`].
+pub fn remquo<F: ElementaryMath>(x: F, y: F) -> (F, i32) {
+    F::remquo(x, y)
+}
+
 /// Calculates the Pythagorean addition of `x` and `y` with and error of less
 /// than 1 ULP
 ///
@@ -310,7 +689,7 @@ pub fn frexp<F: FloatMath>(x: F) -> (F, i32) {
 /// Special cases:
 /// * Returns positive infinity if `x` or `y` is infinity
 /// * Returns NaN if `x` or `y` is NaN and neither is infinity
-pub fn hypot<F: FloatMath>(x: F, y: F) -> F {
+pub fn hypot<F: ElementaryMath>(x: F, y: F) -> F {
     F::hypot(x, y)
 }
 
@@ -320,10 +699,25 @@ pub fn hypot<F: FloatMath>(x: F, y: F) -> F {
 /// * Returns negative zero if `x` is negative zero
 /// * Returns positive infinity if `x` is positive infinity
 /// * Returns NaN if `x` is NaN or negative non-zero (including infinity)
-pub fn sqrt<F: FloatMath>(x: F) -> F {
+pub fn sqrt<F: ElementaryMath>(x: F) -> F {
     F::sqrt(x)
 }
 
+/// Calculates the reciprocal square root of `x` with an error of less than
+/// 0.5 ULP.
+///
+/// The result is correctly rounded, unlike `1.0 / sqrt(x)`, which is
+/// rounded twice.
+///
+/// Special cases:
+/// * Returns positive infinity if `x` is positive zero
+/// * Returns negative infinity if `x` is negative zero
+/// * Returns positive zero if `x` is positive infinity
+/// * Returns NaN if `x` is NaN or negative non-zero (including infinity)
+pub fn rsqrt<F: ElementaryMath>(x: F) -> F {
+    F::rsqrt(x)
+}
+
 /// Calculates the cube root of `x` with and error of less than 1 ULP.
 ///
 /// Special cases:
@@ -331,7 +725,7 @@ pub fn sqrt<F: FloatMath>(x: F) -> F {
 /// * Returns positive infinity if `x` is positive infinity
 /// * Returns negative infinity if `x` is negative infinity
 /// * Returns NaN if `x` is NaN
-pub fn cbrt<F: FloatMath>(x: F) -> F {
+pub fn cbrt<F: ElementaryMath>(x: F) -> F {
     F::cbrt(x)
 }
 
@@ -341,7 +735,7 @@ pub fn cbrt<F: FloatMath>(x: F) -> F {
 /// * Returns positive infinity if `x` is positive infinity
 /// * Returns zero if `x` is negative infinity
 /// * Returns NaN if `x` is NaN
-pub fn exp<F: FloatMath>(x: F) -> F {
+pub fn exp<F: ElementaryMath>(x: F) -> F {
     F::exp(x)
 }
 
@@ -352,37 +746,73 @@ pub fn exp<F: FloatMath>(x: F) -> F {
 /// * Returns positive infinity if `x` is positive infinity
 /// * Returns minus one if `x` is negative infinity
 /// * Returns NaN if `x` is NaN
-pub fn exp_m1<F: FloatMath>(x: F) -> F {
+pub fn exp_m1<F: ElementaryMath>(x: F) -> F {
     F::exp_m1(x)
 }
 
+/// Calculates Euler's number raised to `x` as an unevaluated sum `hi + lo`
+///
+/// Returns `(hi, lo)`, where `hi` is `hi + lo` rounded to the nearest and
+/// `hi + lo` has an error of less than 0.07 ULP. Precision is lost when the
+/// result is close to the subnormal range.
+///
+/// Special cases:
+/// * Returns `(inf, 0)` if `x` is positive infinity
+/// * Returns `(0, 0)` if `x` is negative infinity
+/// * Returns `(NaN, 0)` if `x` is NaN
+pub fn exp_hi_lo<F: ElementaryMath>(x: F) -> (F, F) {
+    F::exp_hi_lo(x)
+}
+
 /// Calculates 2 raised to `x` with an error of less than 1 ULP
 ///
 /// Special cases:
 /// * Returns positive infinity if `x` is positive infinity
 /// * Returns zero if `x` is negative infinity
 /// * Returns NaN if `x` is NaN
-pub fn exp2<F: FloatMath>(x: F) -> F {
+pub fn exp2<F: ElementaryMath>(x: F) -> F {
     F::exp2(x)
 }
 
+/// Calculates `2^x - 1.0` with an error of less than 1 ULP
+///
+/// Special cases:
+/// * Returns negative zero if `x` is negative zero
+/// * Returns positive infinity if `x` is positive infinity
+/// * Returns minus one if `x` is negative infinity
+/// * Returns NaN if `x` is NaN
+pub fn exp2_m1<F: ElementaryMath>(x: F) -> F {
+    F::exp2_m1(x)
+}
+
 /// Calculates 10 raised to `x` with an error of less than 1 ULP
 ///
 /// Special cases:
 /// * Returns positive infinity if `x` is positive infinity
 /// * Returns zero if `x` is negative infinity
 /// * Returns NaN if `x` is NaN
-pub fn exp10<F: FloatMath>(x: F) -> F {
+pub fn exp10<F: ElementaryMath>(x: F) -> F {
     F::exp10(x)
 }
 
+/// Calculates `10^x - 1.0` with an error of less than 1 ULP
+///
+/// Special cases:
+/// * Returns negative zero if `x` is negative zero
+/// * Returns positive infinity if `x` is positive infinity
+/// * Returns minus one if `x` is negative infinity
+/// * Returns NaN if `x` is NaN
+pub fn exp10_m1<F: ElementaryMath>(x: F) -> F {
+    F::exp10_m1(x)
+}
+
 /// Calculates the natural logarithm of `x` with an error of less than 1 ULP
 ///
 /// Special cases:
 /// * Returns negative infinity if `x` is positive or negative zero
 /// * Returns positive infinity if `x` is positive infinity
 /// * Returns NaN if `x` is NaN or negative non-zero (including infinity)
-pub fn ln<F: FloatMath>(x: F) -> F {
+pub fn ln<F: ElementaryMath>(x: F) -> F {
     F::ln(x)
 }
 
@@ -394,30 +824,85 @@ pub fn ln<F: FloatMath>(x: F) -> F {
 /// * Returns positive infinity if `x` is positive infinity
 /// * Returns NaN if `x` is NaN or less than minus one (including negative
 ///   infinity)
-pub fn ln_1p<F: FloatMath>(x: F) -> F {
+pub fn ln_1p<F: ElementaryMath>(x: F) -> F {
     F::ln_1p(x)
 }
 
+/// Calculates the natural logarithm of `x` as an unevaluated sum `hi + lo`
+///
+/// Returns `(hi, lo)`, where `hi` is `hi + lo` rounded to the nearest and
+/// `hi + lo` has an error of less than 0.001 ULP.
+///
+/// Special cases:
+/// * Returns `(-inf, 0)` if `x` is positive or negative zero
+/// * Returns `(inf, 0)` if `x` is positive infinity
+/// * Returns `(NaN, 0)` if `x` is NaN or negative non-zero (including
+///   infinity)
+pub fn ln_hi_lo<F: ElementaryMath>(x: F) -> (F, F) {
+    F::ln_hi_lo(x)
+}
+
+/// Calculates the natural logarithm of `x + 1` as an unevaluated sum
+/// `hi + lo`
+///
+/// Returns `(hi, lo)`, where `hi` is `hi + lo` rounded to the nearest and
+/// `hi + lo` has an error of less than 0.001 ULP.
+///
+/// Special cases:
+/// * Returns `(-0, 0)` if `x` is negative zero
+/// * Returns `(-inf, 0)` if `x` is minus one
+/// * Returns `(inf, 0)` if `x` is positive infinity
+/// * Returns `(NaN, 0)` if `x` is NaN or less than minus one (including
+///   negative infinity)
+pub fn ln_1p_hi_lo<F: ElementaryMath>(x: F) -> (F, F) {
+    F::ln_1p_hi_lo(x)
+}
+
 /// Calculates the base-2 logarithm of `x` with an error of less than 1 ULP
 ///
 /// Special cases:
 /// * Returns negative infinity if `x` is positive or negative zero
 /// * Returns positive infinity if `x` is positive infinity
 /// * Returns NaN if `x` is NaN or negative non-zero (including infinity)
-pub fn log2<F: FloatMath>(x: F) -> F {
+pub fn log2<F: ElementaryMath>(x: F) -> F {
     F::log2(x)
 }
 
+/// Calculates the base-2 logarithm of `x + 1` with an error of less than 1 ULP
+///
+/// Special cases:
+/// * Returns negative zero if `x` is negative zero
+/// * Returns negative infinity if `x` is minus one
+/// * Returns positive infinity if `x` is positive infinity
+/// * Returns NaN if `x` is NaN or less than minus one (including negative
+///   infinity)
+pub fn log2_1p<F: ElementaryMath>(x: F) -> F {
+    F::log2_1p(x)
+}
+
 /// Calculates the base-10 logarithm of `x` with an error of less than 1 ULP
 ///
 /// Special cases:
 /// * Returns negative infinity if `x` is positive or negative zero
 /// * Returns positive infinity if `x` is positive infinity
 /// * Returns NaN if `x` is NaN or negative non-zero (including infinity)
-pub fn log10<F: FloatMath>(x: F) -> F {
+pub fn log10<F: ElementaryMath>(x: F) -> F {
     F::log10(x)
 }
 
+/// Calculates the base-10 logarithm of `x + 1` with an error of less than 1
+/// ULP
+///
+/// Special cases:
+/// * Returns negative zero if `x` is negative zero
+/// * Returns negative infinity if `x` is minus one
+/// * Returns positive infinity if `x` is positive infinity
+/// * Returns NaN if `x` is NaN or less than minus one (including negative
+///   infinity)
+pub fn log10_1p<F: ElementaryMath>(x: F) -> F {
+    F::log10_1p(x)
+}
+
 /// Calculates `x` raised to `y` with an error of less than 1 ULP
 ///
 /// Special cases:
@@ -455,7 +940,7 @@ pub fn log10<F: FloatMath>(x: F) -> F {
 /// * Returns positive infinity when the absolute value of `x` is greater than
 ///   one and `y` is positive infinity
 /// * Returns NaN when `x` is negative and `y` is finite and not integer
-pub fn pow<F: FloatMath>(x: F, y: F) -> F {
+pub fn pow<F: ElementaryMath>(x: F, y: F) -> F {
     F::pow(x, y)
 }
 
@@ -486,16 +971,123 @@ pub fn pow<F: FloatMath>(x: F, y: F) -> F {
 ///   positive and odd
 /// * Returns negative infinity when `x` is negative zero and `y` is negative
 ///   and odd
-pub fn powi<F: FloatMath>(x: F, y: i32) -> F {
+pub fn powi<F: ElementaryMath>(x: F, y: i32) -> F {
     F::powi(x, y)
 }
 
+/// Calculates `x` raised to `y` with an error of less than 1 ULP, defined as
+/// `exp(y * ln(x))`
+///
+/// This is the `powr` operation of IEEE 754-2019. Unlike [`pow`], it is not
+/// defined for negative `x`, and the cases where `pow` returns 1 by
+/// convention return NaN.
+///
+/// Special cases:
+/// * Returns NaN when `x` or `y` is NaN
+/// * Returns NaN when `x` is negative non-zero (including infinity)
+/// * Returns NaN when `x` is zero and `y` is zero
+/// * Returns NaN when `x` is positive infinity and `y` is zero
+/// * Returns NaN when `x` is 1 and `y` is infinity
+/// * Returns 1 when `y` is zero and `x` is positive and finite
+/// * Returns 1 when `x` is 1 and `y` is finite
+/// * Returns positive zero when `x` is zero and `y` is positive
+/// * Returns positive infinity when `x` is zero and `y` is negative
+/// * Returns positive zero when `x` is positive infinity and `y` is negative
+/// * Returns positive infinity when `x` is positive infinity and `y` is
+///   positive
+/// * Returns positive zero when `x` is less than one and `y` is positive
+///   infinity
+/// * Returns positive zero when `x` is greater than one and `y` is negative
+///   infinity
+/// * Returns positive infinity when `x` is less than one and `y` is negative
+///   infinity
+/// * Returns positive infinity when `x` is greater than one and `y` is
+///   positive infinity
+pub fn powr<F: ElementaryMath>(x: F, y: F) -> F {
+    F::powr(x, y)
+}
+
+/// Calculates `x` raised to `n` with an error of less than 1 ULP
+///
+/// This is the `pown` operation of IEEE 754-2019. It is equivalent to
+/// [`powi`], but with a 64-bit exponent.
+///
+/// Special cases:
+/// * Returns 1 when `n` is zero (even if `x` is NaN)
+/// * Returns NaN when `x` is NaN and `n` is not zero
+/// * Returns positive zero when `x` is positive zero and `n` is positive
+/// * Returns positive zero when `x` is positive infinity and `n` is negative
+/// * Returns positive infinity when `x` is positive infinity and `n` is
+///   positive
+/// * Returns positive infinity when `x` is positive zero and `n` is negative
+/// * Returns positive zero when `x` is negative zero and `n` is positive and
+///   even
+/// * Returns positive zero when `x` is negative infinity and `n` is negative
+///   and even
+/// * Returns positive infinity when `x` is negative infinity and `n` is
+///   positive and even
+/// * Returns positive infinity when `x` is negative zero and `n` is negative
+///   and even
+/// * Returns negative zero when `x` is negative zero and `n` is positive and
+///   odd
+/// * Returns negative zero when `x` is negative infinity and `n` is negative
+///   and odd
+/// * Returns negative infinity when `x` is negative infinity and `n` is
+///   positive and odd
+/// * Returns negative infinity when `x` is negative zero and `n` is negative
+///   and odd
+pub fn pown<F: ElementaryMath>(x: F, n: i64) -> F {
+    F::pown(x, n)
+}
+
+/// Calculates the `n`-th root of `x` with an error of less than 1 ULP
+///
+/// This is the `rootn` operation of IEEE 754-2019. The result is real when
+/// `x` is negative and `n` is odd.
+///
+/// Special cases:
+/// * Returns NaN when `x` is NaN or `n` is zero
+/// * Returns NaN when `x` is negative non-zero (including infinity) and `n`
+///   is even
+/// * Returns `x` when `x` is zero and `n` is positive and odd
+/// * Returns positive zero when `x` is zero and `n` is positive and even
+/// * Returns infinity with the sign of `x` when `x` is zero and `n` is
+///   negative and odd
+/// * Returns positive infinity when `x` is zero and `n` is negative and even
+/// * Returns `x` when `x` is infinity and `n` is positive
+/// * Returns zero with the sign of `x` when `x` is infinity and `n` is
+///   negative
+pub fn rootn<F: ElementaryMath>(x: F, n: i64) -> F {
+    F::rootn(x, n)
+}
+
+/// Calculates `(1 + x)^n` with an error of less than 1 ULP
+///
+/// This is the `compound` operation of IEEE 754-2019, which calculates the
+/// compound interest of rate `x` over `n` periods. The error does not grow
+/// when `x` is tiny and `n` is large.
+///
+/// Special cases:
+/// * Returns NaN when `x` is less than minus one (including negative
+///   infinity)
+/// * Returns 1 when `n` is zero and `x` is NaN or not less than minus one
+/// * Returns NaN when `x` is NaN and `n` is not zero
+/// * Returns 1 when `x` is zero
+/// * Returns positive zero when `x` is minus one and `n` is positive
+/// * Returns positive infinity when `x` is minus one and `n` is negative
+/// * Returns positive infinity when `x` is positive infinity and `n` is
+///   positive
+/// * Returns positive zero when `x` is positive infinity and `n` is negative
+pub fn compound<F: ElementaryMath>(x: F, n: i64) -> F {
+    F::compound(x, n)
+}
+
 /// Calculates the sine of `x` radians with an error of less than 1 ULP
 ///
 /// Special cases:
 /// * Returns negative zero if `x` is negative zero
 /// * Returns NaN if `x` is infinity or NaN
-pub fn sin<F: FloatMath>(x: F) -> F {
+pub fn sin<F: ElementaryMath>(x: F) -> F {
     F::sin(x)
 }
 
@@ -503,7 +1095,7 @@ pub fn sin<F: FloatMath>(x: F) -> F {
 ///
 /// Special cases:
 /// * Returns NaN if `x` is infinity or NaN
-pub fn cos<F: FloatMath>(x: F) -> F {
+pub fn cos<F: ElementaryMath>(x: F) -> F {
     F::cos(x)
 }
 
@@ -512,25 +1104,72 @@ pub fn cos<F: FloatMath>(x: F) -> F {
 /// The same accuracy and special cases of [`sin`] and [`cos`] also
 /// apply to this function. Using this function can be faster than
 /// using [`sin`] and [`cos`] separately.
-pub fn sin_cos<F: FloatMath>(x: F) -> (F, F) {
+pub fn sin_cos<F: ElementaryMath>(x: F) -> (F, F) {
     F::sin_cos(x)
 }
 
+/// Calculates the sine and the cosine of `x` radians as unevaluated sums
+/// `hi + lo`
+///
+/// Returns `((sin_hi, sin_lo), (cos_hi, cos_lo))`, where each `hi` is
+/// `hi + lo` rounded to the nearest and each `hi + lo` has an error of less
+/// than 0.15 ULP.
+///
+/// Special cases:
+/// * Returns `((NaN, 0), (NaN, 0))` if `x` is infinity or NaN
+pub fn sin_cos_hi_lo<F: ElementaryMath>(x: F) -> ((F, F), (F, F)) {
+    F::sin_cos_hi_lo(x)
+}
+
 /// Calculates the tangent of `x` radians with an error of less than 1 ULP
 ///
 /// Special cases:
 /// * Returns negative zero if `x` is negative zero
 /// * Returns NaN if `x` is infinity or NaN
-pub fn tan<F: FloatMath>(x: F) -> F {
+pub fn tan<F: ElementaryMath>(x: F) -> F {
     F::tan(x)
 }
 
+/// Reduces the angle `x` (in radians) by multiples of π/2
+///
+/// Returns `(n, y_hi, y_lo)` such as:
+/// * `0 <= n <= 3`
+/// * `|y_hi| <= π/4` (approximately)
+/// * `y_hi + y_lo` approximates `x - 2*π*M - π/2*n`, for some integer `M`
+/// * `y_hi` is `y_hi + y_lo` rounded to nearest
+///
+/// The reduction is accurate for any finite `x`, using as many bits of π as
+/// needed when `x` is large. The error of `y_hi + y_lo` is less than 0.002
+/// ULP of `y_hi` for `f16` and `bf16`, 0.02 ULP for [`prim@f32`],
+/// 2<sup>-17</sup> ULP for [`prim@f64`] and 0.03 ULP for `f128` (except
+/// close to the subnormal range, where `y_lo` cannot be represented), so the
+/// pair does not have full double-float precision.
+///
+/// Special cases:
+/// * Returns `(0, NaN, 0)` if `x` is infinity or NaN
+pub fn rem_pio2<F: ElementaryMath>(x: F) -> (i32, F, F) {
+    F::rem_pio2(x)
+}
+
+/// Wraps the angle `x` (in radians) into the range `[-π, π]`
+///
+/// Calculates `x - 2*π*M`, where `M` is an integer, with an error of less
+/// than 1 ULP. The reduction is accurate for any finite `x`, using as many
+/// bits of π as needed when `x` is large. `x` is returned unchanged when
+/// its magnitude does not exceed π rounded to the float type.
+///
+/// Special cases:
+/// * Returns NaN if `x` is infinity or NaN
+pub fn wrap_angle<F: ElementaryMath>(x: F) -> F {
+    F::wrap_angle(x)
+}
+
 /// Calculates the sine of `x` degrees with an error of less than 1 ULP
 ///
 /// Special cases:
 /// * Returns negative zero if `x` is negative zero
 /// * Returns NaN if `x` is infinity or NaN
-pub fn sind<F: FloatMath>(x: F) -> F {
+pub fn sind<F: ElementaryMath>(x: F) -> F {
     F::sind(x)
 }
 
@@ -538,7 +1177,7 @@ pub fn sind<F: FloatMath>(x: F) -> F {
 ///
 /// Special cases:
 /// * Returns NaN if `x` is infinity or NaN
-pub fn cosd<F: FloatMath>(x: F) -> F {
+pub fn cosd<F: ElementaryMath>(x: F) -> F {
     F::cosd(x)
 }
 
@@ -547,7 +1186,7 @@ pub fn cosd<F: FloatMath>(x: F) -> F {
 /// The same accuracy and special cases of [`sind`] and [`cosd`] also apply to
 /// this function. Using this function can be faster than using [`sind`] and
 /// [`cosd`] separately.
-pub fn sind_cosd<F: FloatMath>(x: F) -> (F, F) {
+pub fn sind_cosd<F: ElementaryMath>(x: F) -> (F, F) {
     F::sind_cosd(x)
 }
 
@@ -556,7 +1195,7 @@ pub fn sind_cosd<F: FloatMath>(x: F) -> (F, F) {
 /// Special cases:
 /// * Returns negative zero if `x` is negative zero
 /// * Returns NaN if `x` is infinity or NaN
-pub fn tand<F: FloatMath>(x: F) -> F {
+pub fn tand<F: ElementaryMath>(x: F) -> F {
     F::tand(x)
 }
 
@@ -566,7 +1205,7 @@ pub fn tand<F: FloatMath>(x: F) -> F {
 /// Special cases:
 /// * Returns negative zero if `x` is negative zero
 /// * Returns NaN if `x` is infinity or NaN
-pub fn sinpi<F: FloatMath>(x: F) -> F {
+pub fn sinpi<F: ElementaryMath>(x: F) -> F {
     F::sinpi(x)
 }
 
@@ -575,7 +1214,7 @@ pub fn sinpi<F: FloatMath>(x: F) -> F {
 ///
 /// Special cases:
 /// * Returns NaN if `x` is infinity or NaN
-pub fn cospi<F: FloatMath>(x: F) -> F {
+pub fn cospi<F: ElementaryMath>(x: F) -> F {
     F::cospi(x)
 }
 
@@ -584,7 +1223,7 @@ pub fn cospi<F: FloatMath>(x: F) -> F {
 /// The same accuracy and special cases of [`sinpi`] and [`cospi`] also apply to
 /// this function. Using this function can be faster than using [`sinpi`] and
 /// [`cospi`] separately.
-pub fn sinpi_cospi<F: FloatMath>(x: F) -> (F, F) {
+pub fn sinpi_cospi<F: ElementaryMath>(x: F) -> (F, F) {
     F::sinpi_cospi(x)
 }
 
@@ -594,7 +1233,7 @@ pub fn sinpi_cospi<F: FloatMath>(x: F) -> (F, F) {
 /// Special cases:
 /// * Returns negative zero if `x` is negative zero
 /// * Returns NaN if `x` is infinity or NaN
-pub fn tanpi<F: FloatMath>(x: F) -> F {
+pub fn tanpi<F: ElementaryMath>(x: F) -> F {
     F::tanpi(x)
 }
 
@@ -605,7 +1244,7 @@ pub fn tanpi<F: FloatMath>(x: F) -> F {
 /// * Returns negative zero if `x` is negative zero
 /// * Returns NaN if `x` is NaN or greater than one in magnitude (including
 ///   infinity)
-pub fn asin<F: FloatMath>(x: F) -> F {
+pub fn asin<F: ElementaryMath>(x: F) -> F {
     F::asin(x)
 }
 
@@ -615,7 +1254,7 @@ pub fn asin<F: FloatMath>(x: F) -> F {
 /// Special cases:
 /// * Returns NaN if `x` is NaN or greater than one in magnitude (including
 ///   infinity)
-pub fn acos<F: FloatMath>(x: F) -> F {
+pub fn acos<F: ElementaryMath>(x: F) -> F {
     F::acos(x)
 }
 
@@ -627,7 +1266,7 @@ pub fn acos<F: FloatMath>(x: F) -> F {
 /// * Returns NaN if `x` is NaN
 /// * Returns π/2 if `x` is positive infinity
 /// * Returns -π/2 if `x` is negative infinity
-pub fn atan<F: FloatMath>(x: F) -> F {
+pub fn atan<F: ElementaryMath>(x: F) -> F {
     F::atan(x)
 }
 
@@ -657,7 +1296,7 @@ pub fn atan<F: FloatMath>(x: F) -> F {
 /// * Returns -π/4 if `x` is positive infinity and `y` is negative infinity
 /// * Returns 3π/4 if `x` is negative infinity and `y` is positive infinity
 /// * Returns -3π/4 if `x` is negative infinity and `y` is negative infinity
-pub fn atan2<F: FloatMath>(y: F, x: F) -> F {
+pub fn atan2<F: ElementaryMath>(y: F, x: F) -> F {
     F::atan2(y, x)
 }
 
@@ -668,7 +1307,7 @@ pub fn atan2<F: FloatMath>(y: F, x: F) -> F {
 /// * Returns negative zero if `x` is negative zero
 /// * Returns NaN if `x` is NaN or greater than one in magnitude (including
 ///   infinity)
-pub fn asind<F: FloatMath>(x: F) -> F {
+pub fn asind<F: ElementaryMath>(x: F) -> F {
     F::asind(x)
 }
 
@@ -678,7 +1317,7 @@ pub fn asind<F: FloatMath>(x: F) -> F {
 /// Special cases:
 /// * Returns NaN if `x` is NaN or greater than one in magnitude (including
 ///   infinity)
-pub fn acosd<F: FloatMath>(x: F) -> F {
+pub fn acosd<F: ElementaryMath>(x: F) -> F {
     F::acosd(x)
 }
 
@@ -690,7 +1329,7 @@ pub fn acosd<F: FloatMath>(x: F) -> F {
 /// * Returns NaN if `x` is NaN
 /// * Returns 90 if `x` is positive infinity
 /// * Returns -90 if `x` is negative infinity
-pub fn atand<F: FloatMath>(x: F) -> F {
+pub fn atand<F: ElementaryMath>(x: F) -> F {
     F::atand(x)
 }
 
@@ -721,7 +1360,7 @@ pub fn atand<F: FloatMath>(x: F) -> F {
 /// * Returns -45 if `x` is positive infinity and `y` is negative infinity
 /// * Returns 135 if `x` is negative infinity and `y` is positive infinity
 /// * Returns -135 if `x` is negative infinity and `y` is negative infinity
-pub fn atan2d<F: FloatMath>(y: F, x: F) -> F {
+pub fn atan2d<F: ElementaryMath>(y: F, x: F) -> F {
     F::atan2d(y, x)
 }
 
@@ -732,7 +1371,7 @@ pub fn atan2d<F: FloatMath>(y: F, x: F) -> F {
 /// * Returns negative zero if `x` is negative zero
 /// * Returns NaN if `x` is NaN or greater than one in magnitude (including
 ///   infinity)
-pub fn asinpi<F: FloatMath>(x: F) -> F {
+pub fn asinpi<F: ElementaryMath>(x: F) -> F {
     F::asinpi(x)
 }
 
@@ -742,7 +1381,7 @@ pub fn asinpi<F: FloatMath>(x: F) -> F {
 /// Special cases:
 /// * Returns NaN if `x` is NaN or greater than one in magnitude (including
 ///   infinity)
-pub fn acospi<F: FloatMath>(x: F) -> F {
+pub fn acospi<F: ElementaryMath>(x: F) -> F {
     F::acospi(x)
 }
 
@@ -754,7 +1393,7 @@ pub fn acospi<F: FloatMath>(x: F) -> F {
 /// * Returns NaN if `x` is NaN
 /// * Returns 0.5 if `x` is positive infinity
 /// * Returns -0.5 if `x` is negative infinity
-pub fn atanpi<F: FloatMath>(x: F) -> F {
+pub fn atanpi<F: ElementaryMath>(x: F) -> F {
     F::atanpi(x)
 }
 
@@ -784,7 +1423,7 @@ pub fn atanpi<F: FloatMath>(x: F) -> F {
 /// * Returns -0.25 if `x` is positive infinity and `y` is negative infinity
 /// * Returns 0.75 if `x` is negative infinity and `y` is positive infinity
 /// * Returns -0.75 if `x` is negative infinity and `y` is negative infinity
-pub fn atan2pi<F: FloatMath>(y: F, x: F) -> F {
+pub fn atan2pi<F: ElementaryMath>(y: F, x: F) -> F {
     F::atan2pi(y, x)
 }
 
@@ -795,7 +1434,7 @@ pub fn atan2pi<F: FloatMath>(y: F, x: F) -> F {
 /// * Returns positive infinity if `x` is positive infinity
 /// * Returns negative infinity if `x` is negative infinity
 /// * Returns NaN if `x` is NaN
-pub fn sinh<F: FloatMath>(x: F) -> F {
+pub fn sinh<F: ElementaryMath>(x: F) -> F {
     F::sinh(x)
 }
 
@@ -804,7 +1443,7 @@ pub fn sinh<F: FloatMath>(x: F) -> F {
 /// Special cases:
 /// * Returns positive infinity if `x` is positive or negative infinity
 /// * Returns NaN if `x` is NaN
-pub fn cosh<F: FloatMath>(x: F) -> F {
+pub fn cosh<F: ElementaryMath>(x: F) -> F {
     F::cosh(x)
 }
 
@@ -813,7 +1452,7 @@ pub fn cosh<F: FloatMath>(x: F) -> F {
 /// The same accuracy and special cases of [`sinh`] and [`cosh`] also apply to
 /// this function. Using this function can be faster than using [`sinh`] and
 /// [`cosh`] separately.
-pub fn sinh_cosh<F: FloatMath>(x: F) -> (F, F) {
+pub fn sinh_cosh<F: ElementaryMath>(x: F) -> (F, F) {
     F::sinh_cosh(x)
 }
 
@@ -824,7 +1463,7 @@ pub fn sinh_cosh<F: FloatMath>(x: F) -> (F, F) {
 /// * Returns one if `x` is positive infinity
 /// * Returns minus one if `x` is negative infinity
 /// * Returns NaN if `x` is NaN
-pub fn tanh<F: FloatMath>(x: F) -> F {
+pub fn tanh<F: ElementaryMath>(x: F) -> F {
     F::tanh(x)
 }
 
@@ -835,7 +1474,7 @@ pub fn tanh<F: FloatMath>(x: F) -> F {
 /// * Returns negative zero if `x` is negative zero
 /// * Returns positive infinity if `x` is positive infinity
 /// * Returns negative infinity if `x` is negative infinity
-pub fn asinh<F: FloatMath>(x: F) -> F {
+pub fn asinh<F: ElementaryMath>(x: F) -> F {
     F::asinh(x)
 }
 
@@ -844,7 +1483,7 @@ pub fn asinh<F: FloatMath>(x: F) -> F {
 /// Special cases:
 /// * Returns NaN if `x` is NaN or less than one (including negative infinity)
 /// * Returns positive infinity if `x` is positive infinity
-pub fn acosh<F: FloatMath>(x: F) -> F {
+pub fn acosh<F: ElementaryMath>(x: F) -> F {
     F::acosh(x)
 }
 
@@ -855,7 +1494,7 @@ pub fn acosh<F: FloatMath>(x: F) -> F {
 /// * Returns negative zero if `x` is negative zero
 /// * Returns positive infinity if `x` is 1
 /// * Returns negative infinity if `x` is -1
-pub fn atanh<F: FloatMath>(x: F) -> F {
+pub fn atanh<F: ElementaryMath>(x: F) -> F {
     F::atanh(x)
 }
 
@@ -892,3 +1531,375 @@ pub fn gamma<F: FloatMath>(x: F) -> F {
 pub fn ln_gamma<F: FloatMath>(x: F) -> (F, i8) {
     F::ln_gamma(x)
 }
+
+/// Calculates the regularized lower incomplete gamma function of `a` and `x`,
+/// `P(a, x) = γ(a, x) / Γ(a)`
+///
+/// The error is less than 2 ULP.
+///
+/// Special cases:
+/// * Returns NaN if `a` or `x` is NaN or negative
+/// * Returns NaN if `a` and `x` are both zero or both positive infinity
+/// * Returns zero if `x` is zero or `a` is positive infinity
+/// * Returns one if `a` is zero or `x` is positive infinity
+pub fn gamma_p<F: FloatMath>(a: F, x: F) -> F {
+    F::gamma_p(a, x)
+}
+
+/// Calculates the regularized upper incomplete gamma function of `a` and `x`,
+/// `Q(a, x) = Γ(a, x) / Γ(a) = 1 - P(a, x)`
+///
+/// The error is less than 2 ULP.
+///
+/// Special cases:
+/// * Returns NaN if `a` or `x` is NaN or negative
+/// * Returns NaN if `a` and `x` are both zero or both positive infinity
+/// * Returns one if `x` is zero or `a` is positive infinity
+/// * Returns zero if `a` is zero or `x` is positive infinity
+pub fn gamma_q<F: FloatMath>(a: F, x: F) -> F {
+    F::gamma_q(a, x)
+}
+
+/// Calculates the inverse of [`gamma_p`] with respect to `x`, the value `x`
+/// such that `gamma_p(a, x) = p`
+///
+/// The error is less than 1 ULP when `p` is not subnormal.
+///
+/// Special cases:
+/// * Returns NaN if `a` or `p` is NaN
+/// * Returns NaN if `a` is zero, negative or infinity
+/// * Returns NaN if `p` is less than 0 or greater than 1
+/// * Returns zero if `p` is zero
+/// * Returns positive infinity if `p` is one
+pub fn gamma_p_inv<F: FloatMath>(a: F, p: F) -> F {
+    F::gamma_p_inv(a, p)
+}
+
+/// Calculates the inverse of [`gamma_q`] with respect to `x`, the value `x`
+/// such that `gamma_q(a, x) = q`
+///
+/// The error is less than 1 ULP when `q` is not subnormal.
+///
+/// Special cases:
+/// * Returns NaN if `a` or `q` is NaN
+/// * Returns NaN if `a` is zero, negative or infinity
+/// * Returns NaN if `q` is less than 0 or greater than 1
+/// * Returns zero if `q` is one
+/// * Returns positive infinity if `q` is zero
+pub fn gamma_q_inv<F: FloatMath>(a: F, q: F) -> F {
+    F::gamma_q_inv(a, q)
+}
+
+/// Calculates the digamma function of `x`, the logarithmic derivative of the
+/// gamma function
+///
+/// When `x` is positive, the error is less than 1 ULP. When `x` is negative,
+/// the result is calculated with the reflection formula, so the error is less
+/// than 2 ULP except near the zeros of the function, where the absolute error
+/// is kept small but the relative error can be larger.
+///
+/// Special cases:
+/// * Returns NaN if `x` is NaN, negative infinity or a negative integer
+/// * Returns positive infinity if `x` is positive infinity
+/// * Returns negative infinity if `x` is positive zero
+/// * Returns positive infinity if `x` is negative zero
+pub fn digamma<F: FloatMath>(x: F) -> F {
+    F::digamma(x)
+}
+
+/// Calculates the polygamma function of order `n` of `x`, the `n`-th
+/// derivative of the digamma function
+///
+/// When `n` is 0, it is equivalent to [`digamma`]. Otherwise, the error is
+/// less than 1 ULP, except near the zeros of the function (which only exist
+/// for negative `x` when `n` is even), where the absolute error is kept small
+/// but the relative error can be larger.
+///
+/// Special cases:
+/// * Returns NaN if `n` is negative
+/// * Returns NaN if `x` is NaN or negative infinity
+/// * Returns zero if `x` is positive infinity, with negative sign when `n` is
+///   even
+/// * Returns positive infinity if `x` is zero or a negative integer and `n` is
+///   odd
+/// * Returns NaN if `x` is a negative integer and `n` is even and greater
+///   than 0
+/// * Returns negative infinity if `x` is positive zero and `n` is even
+/// * Returns positive infinity if `x` is negative zero and `n` is even
+pub fn polygamma<F: FloatMath>(n: i32, x: F) -> F {
+    F::polygamma(n, x)
+}
+
+/// Calculates the Riemann zeta function of `s`
+///
+/// The error is less than 1 ULP.
+///
+/// Special cases:
+/// * Returns NaN if `s` is NaN or negative infinity
+/// * Returns positive infinity if `s` is one
+/// * Returns one if `s` is positive infinity
+/// * Returns -0.5 if `s` is zero
+/// * Returns zero if `s` is a negative even integer
+pub fn zeta<F: FloatMath>(s: F) -> F {
+    F::zeta(s)
+}
+
+/// Calculates the Hurwitz zeta function of `s` and `q`, the sum of
+/// `1 / (q + k)^s` for every non-negative integer `k`
+///
+/// The error is less than 1 ULP.
+///
+/// Special cases:
+/// * Returns NaN if `s` or `q` is NaN
+/// * Returns NaN if `s` is less than one or `q` is less than zero
+/// * Returns positive infinity if `s` is one or `q` is zero
+/// * Returns zero if `q` is positive infinity
+/// * Returns positive infinity, one or zero if `s` is positive infinity and
+///   `q` is less than, equal to or greater than one, respectively
+pub fn hurwitz_zeta<F: FloatMath>(s: F, q: F) -> F {
+    F::hurwitz_zeta(s, q)
+}
+
+/// Calculates the beta function of `a` and `b`, `Γ(a) * Γ(b) / Γ(a + b)`
+///
+/// The result is not calculated from the gamma functions of the arguments,
+/// so it remains accurate when one of them is much larger than the other.
+/// When `a` and `b` are positive, the error is less than 2 ULP. For negative
+/// values, the error can be higher.
+///
+/// Special cases:
+/// * Returns NaN if `a` or `b` is NaN, negative infinity or a negative
+///   integer
+/// * Returns NaN if `a` is positive zero and `b` is negative zero, or
+///   vice versa
+/// * Returns NaN if `a` or `b` is zero and the other one is positive
+///   infinity
+/// * Returns positive infinity if `a` or `b` is positive zero
+/// * Returns negative infinity if `a` or `b` is negative zero
+/// * Returns zero if `a` or `b` is positive infinity and the other one is
+///   positive
+/// * Returns infinity with the sign of `Γ(b)` if `a` is positive infinity and
+///   `b` is negative, or vice versa
+/// * Returns zero if `a + b` is zero or a negative integer
+pub fn beta<F: FloatMath>(a: F, b: F) -> F {
+    F::beta(a, b)
+}
+
+/// Calculates the logarithm of the absolute value of the beta function of `a`
+/// and `b`
+///
+/// The integer field of the returned tuple is `1` when the beta function is
+/// positive, `-1` when the beta function is negative, and `0` when the sign
+/// of the beta function is not defined.
+///
+/// The result is not calculated from the logarithms of the gamma functions of
+/// the arguments, so it remains accurate when one of them is much larger than
+/// the other. When `a` and `b` are positive, the error is less than 2 ULP,
+/// except when the result is close to zero, where the absolute error is kept
+/// small but the relative error can be larger. For negative values, the error
+/// can be higher.
+///
+/// Special cases are the same as [`beta`], returning positive infinity when
+/// [`beta`] returns infinity and negative infinity when [`beta`] returns zero.
+/// The sign is considered undefined when the result is NaN or `a + b` is zero
+/// or a negative integer.
+pub fn ln_beta<F: FloatMath>(a: F, b: F) -> (F, i8) {
+    F::ln_beta(a, b)
+}
+
+/// Calculates the regularized incomplete beta function of `a`, `b` and `x`,
+/// `I_x(a, b) = B(x; a, b) / B(a, b)`
+///
+/// The error is less than 1 ULP.
+///
+/// Special cases:
+/// * Returns NaN if `a`, `b` or `x` is NaN
+/// * Returns NaN if `a` or `b` is negative
+/// * Returns NaN if `x` is less than 0 or greater than 1
+/// * Returns NaN if `a` and `b` are both zero or both positive infinity
+/// * Returns NaN if `a` is zero and `x` is zero, or `b` is zero and `x` is
+///   one
+/// * Returns NaN if `a` is positive infinity and `x` is one, or `b` is
+///   positive infinity and `x` is zero
+/// * Returns zero if `x` is zero, `a` is positive infinity or `b` is zero
+/// * Returns one if `x` is one, `a` is zero or `b` is positive infinity
+pub fn beta_inc<F: FloatMath>(a: F, b: F, x: F) -> F {
+    F::beta_inc(a, b, x)
+}
+
+/// Calculates the inverse of [`beta_inc`] with respect to `x`, the value `x`
+/// such that `beta_inc(a, b, x) = p`
+///
+/// The error is less than 2 ULP when `p` is not subnormal and `a + b` is not
+/// small. When `a` and `b` are both small, [`beta_inc`] is very flat away
+/// from 0 and 1, so the result is very sensitive to rounding errors and the
+/// error can be much larger.
+///
+/// Special cases:
+/// * Returns NaN if `a`, `b` or `p` is NaN
+/// * Returns NaN if `a` or `b` is zero, negative or infinity
+/// * Returns NaN if `p` is less than 0 or greater than 1
+/// * Returns zero if `p` is zero
+/// * Returns one if `p` is one
+pub fn beta_inc_inv<F: FloatMath>(a: F, b: F, p: F) -> F {
+    F::beta_inc_inv(a, b, p)
+}
+
+/// Calculates the error function of `x` with an error of less than 1 ULP
+///
+/// Special cases:
+/// * Returns negative zero if `x` is negative zero
+/// * Returns one if `x` is positive infinity
+/// * Returns minus one if `x` is negative infinity
+/// * Returns NaN if `x` is NaN
+pub fn erf<F: FloatMath>(x: F) -> F {
+    F::erf(x)
+}
+
+/// Calculates the complementary error function of `x` (`1 - erf(x)`) with an
+/// error of less than 1 ULP
+///
+/// The result is calculated without cancellation, so it remains accurate for
+/// large values of `x`, until it underflows.
+///
+/// Special cases:
+/// * Returns zero if `x` is positive infinity
+/// * Returns two if `x` is negative infinity
+/// * Returns NaN if `x` is NaN
+pub fn erfc<F: FloatMath>(x: F) -> F {
+    F::erfc(x)
+}
+
+/// Calculates the inverse error function of `x` with an error of less than
+/// 1 ULP
+///
+/// Special cases:
+/// * Returns negative zero if `x` is negative zero
+/// * Returns positive infinity if `x` is one
+/// * Returns negative infinity if `x` is minus one
+/// * Returns NaN if `|x|` is greater than one or `x` is NaN
+pub fn erfinv<F: FloatMath>(x: F) -> F {
+    F::erfinv(x)
+}
+
+/// Calculates the inverse complementary error function of `x` with an error
+/// of less than 1 ULP
+///
+/// The result is calculated directly from `x`, so it remains accurate when
+/// `x` is close to zero, where `erfinv(1 - x)` would lose precision.
+///
+/// Special cases:
+/// * Returns positive infinity if `x` is zero
+/// * Returns negative infinity if `x` is two
+/// * Returns NaN if `x` is less than zero, greater than two or NaN
+pub fn erfcinv<F: FloatMath>(x: F) -> F {
+    F::erfcinv(x)
+}
+
+/// Calculates the Bessel function of the first kind of order 0 of `x`
+///
+/// The error is less than 1 ULP, except near the zeros of the function,
+/// where the absolute error is kept small but the relative error can be
+/// larger.
+///
+/// Special cases:
+/// * Returns zero if `x` is infinity
+/// * Returns NaN if `x` is NaN
+pub fn j0<F: FloatMath>(x: F) -> F {
+    F::j0(x)
+}
+
+/// Calculates the Bessel function of the first kind of order 1 of `x`
+///
+/// The error is less than 1 ULP, except near the zeros of the function,
+/// where the absolute error is kept small but the relative error can be
+/// larger.
+///
+/// Special cases:
+/// * Returns negative zero if `x` is negative zero
+/// * Returns zero if `x` is infinity
+/// * Returns NaN if `x` is NaN
+pub fn j1<F: FloatMath>(x: F) -> F {
+    F::j1(x)
+}
+
+/// Calculates the Bessel function of the first kind of order `n` of `x`
+///
+/// For `n` other than 0 or 1, the result is calculated with recurrence
+/// relations, so the error is larger than for [`j0`] and [`j1`] and grows with
+/// `n`, especially near the zeros of the function.
+///
+/// Special cases:
+/// * Returns zero if `x` is infinity
+/// * Returns NaN if `x` is NaN
+pub fn jn<F: FloatMath>(n: i32, x: F) -> F {
+    F::jn(n, x)
+}
+
+/// Calculates the Bessel function of the second kind of order 0 of `x`
+///
+/// The error is less than 1 ULP, except near the zeros of the function,
+/// where the absolute error is kept small but the relative error can be
+/// larger.
+///
+/// Special cases:
+/// * Returns negative infinity if `x` is zero
+/// * Returns zero if `x` is positive infinity
+/// * Returns NaN if `x` is less than zero, negative infinity or NaN
+pub fn y0<F: FloatMath>(x: F) -> F {
+    F::y0(x)
+}
+
+/// Calculates the Bessel function of the second kind of order 1 of `x`
+///
+/// The error is less than 1 ULP, except near the zeros of the function,
+/// where the absolute error is kept small but the relative error can be
+/// larger.
+///
+/// Special cases:
+/// * Returns negative infinity if `x` is zero
+/// * Returns zero if `x` is positive infinity
+/// * Returns NaN if `x` is less than zero, negative infinity or NaN
+pub fn y1<F: FloatMath>(x: F) -> F {
+    F::y1(x)
+}
+
+/// Calculates the Bessel function of the second kind of order `n` of `x`
+///
+/// For `n` other than 0 or 1, the result is calculated with recurrence
+/// relations, so the error is larger than for [`y0`] and [`y1`] and grows with
+/// `n`, especially near the zeros of the function.
+///
+/// Special cases:
+/// * Returns negative infinity if `x` is zero and `n` is not negative or is
+///   even, and positive infinity if `x` is zero and `n` is negative and odd
+/// * Returns zero if `x` is positive infinity
+/// * Returns NaN if `x` is less than zero, negative infinity or NaN
+pub fn yn<F: FloatMath>(n: i32, x: F) -> F {
+    F::yn(n, x)
+}
+
+/// Calculates the principal branch of the Lambert W function of `x`, the
+/// value `w >= -1` such that `w * e^w = x`
+///
+/// The error is less than 1 ULP, including near the branch point `-1/e`.
+///
+/// Special cases:
+/// * Returns NaN if `x` is NaN or less than `-1/e`
+/// * Returns positive infinity if `x` is positive infinity
+/// * Returns `x` if `x` is zero
+pub fn lambert_w0<F: FloatMath>(x: F) -> F {
+    F::lambert_w0(x)
+}
+
+/// Calculates the -1 branch of the Lambert W function of `x`, the value
+/// `w <= -1` such that `w * e^w = x`
+///
+/// The error is less than 1 ULP, including near the branch point `-1/e`.
+///
+/// Special cases:
+/// * Returns NaN if `x` is NaN, positive or less than `-1/e`
+/// * Returns negative infinity if `x` is zero
+pub fn lambert_wm1<F: FloatMath>(x: F) -> F {
+    F::lambert_wm1(x)
+}
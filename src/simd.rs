@@ -0,0 +1,762 @@
+//! Portable SIMD vector types.
+//!
+//! The types in this module are arrays of floats with lane-wise arithmetic
+//! operations and methods for all the functions of
+//! [`ElementaryMath`](crate::ElementaryMath) and
+//! [`FloatMath`](crate::FloatMath). The result of each lane is
+//! bit-identical to the function with the same name in the crate root, so
+//! they have the same special cases.
+//!
+//! [`exp`](F32x4::exp), [`ln`](F32x4::ln), [`pow`](F32x4::pow),
+//! [`sin`](F32x4::sin), [`cos`](F32x4::cos), [`sin_cos`](F32x4::sin_cos) and
+//! [`atan2`](F32x4::atan2) evaluate the same algorithms as the scalar
+//! functions for all lanes at once, using lane-wise masks instead of
+//! branches, so the compiler can vectorize them without target-specific
+//! intrinsics. The lanes that need special handling are calculated with the
+//! scalar function. The other methods call the scalar function for each
+//! lane.
+//!
+//! The vector types do not implement the traits themselves, because some of
+//! their functions take or return integers (such as the exponents of
+//! [`frexp`](F32x4::frexp)), which are arrays for the vector types.
+//!
+//! # Example
+//!
+//! ```
+//! use fpmath::simd::F32x4;
+//!
+//! let angles = F32x4::from_array([0.0, 0.5, 1.0, 1.5]);
+//! let (sin, cos) = angles.sin_cos();
+//! assert_eq!(sin.to_array()[1], fpmath::sin(0.5f32));
+//! assert_eq!(cos.to_array()[2], fpmath::cos(1.0f32));
+//! assert_eq!(angles.tan().to_array()[3], fpmath::tan(1.5f32));
+//! ```
+
+use crate::generic;
+use crate::traits::{Float, Simd};
+
+type Raw<F> = <F as Float>::Raw;
+
+/// The result of a lane-wise comparison
+#[derive(Copy, Clone)]
+pub(crate) struct Mask<const N: usize>([bool; N]);
+
+impl<const N: usize> Mask<N> {
+    #[inline]
+    fn zip(self, rhs: Self, f: impl Fn(bool, bool) -> bool) -> Self {
+        Self(core::array::from_fn(|i| f(self.0[i], rhs.0[i])))
+    }
+}
+
+impl<const N: usize> core::ops::BitAnd for Mask<N> {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        self.zip(rhs, |a, b| a & b)
+    }
+}
+
+impl<const N: usize> core::ops::BitOr for Mask<N> {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        self.zip(rhs, |a, b| a | b)
+    }
+}
+
+impl<const N: usize> core::ops::BitXor for Mask<N> {
+    type Output = Self;
+
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self {
+        self.zip(rhs, |a, b| a ^ b)
+    }
+}
+
+impl<const N: usize> core::ops::Not for Mask<N> {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        Self(self.0.map(|a| !a))
+    }
+}
+
+impl<const N: usize> crate::traits::SimdMask for Mask<N> {
+    #[inline]
+    fn bitmask(self) -> u32 {
+        self.0
+            .iter()
+            .enumerate()
+            .fold(0, |acc, (i, &a)| acc | (u32::from(a) << i))
+    }
+
+    #[inline]
+    fn all(self) -> bool {
+        self.0.iter().fold(true, |acc, &a| acc & a)
+    }
+}
+
+/// Defines methods that apply the function with the same name in the crate
+/// root to each lane
+macro_rules! lane_wise {
+    ($($(#[$attr:meta])* $kind:ident $name:ident;)*) => {
+        $(lane_wise!(@$kind $name, $(#[$attr])*);)*
+    };
+    (@unary $name:ident, $(#[$attr:meta])*) => {
+        $(#[$attr])*
+        ///
+        #[doc = concat!(
+            "See [`", stringify!($name), "`](crate::", stringify!($name), ") for the special cases."
+        )]
+        #[inline]
+        pub fn $name(self) -> Self {
+            self.map(crate::$name)
+        }
+    };
+    (@binary $name:ident, $(#[$attr:meta])*) => {
+        $(#[$attr])*
+        ///
+        #[doc = concat!(
+            "See [`", stringify!($name), "`](crate::", stringify!($name), ") for the special cases."
+        )]
+        #[inline]
+        pub fn $name(self, other: Self) -> Self {
+            self.zip(other, crate::$name)
+        }
+    };
+    (@pair $name:ident, $(#[$attr:meta])*) => {
+        $(#[$attr])*
+        ///
+        #[doc = concat!(
+            "See [`", stringify!($name), "`](crate::", stringify!($name), ") for the special cases."
+        )]
+        #[inline]
+        pub fn $name(self) -> (Self, Self) {
+            self.map_pair(crate::$name)
+        }
+    };
+}
+
+macro_rules! impl_vector {
+    ($(#[$attr:meta])* $vec:ident, $scalar:ident, $lanes:literal) => {
+        $(#[$attr])*
+        #[derive(Copy, Clone, Debug, Default, PartialEq)]
+        pub struct $vec([$scalar; $lanes]);
+
+        impl $vec {
+            /// Number of lanes
+            pub const LANES: usize = $lanes;
+
+            /// Creates a vector from an array of lanes.
+            #[inline]
+            pub const fn from_array(array: [$scalar; $lanes]) -> Self {
+                Self(array)
+            }
+
+            /// Returns the lanes as an array.
+            #[inline]
+            pub const fn to_array(self) -> [$scalar; $lanes] {
+                self.0
+            }
+
+            /// Creates a vector with all lanes set to `value`.
+            #[inline]
+            pub const fn splat(value: $scalar) -> Self {
+                Self([value; $lanes])
+            }
+
+            /// Calculates Euler's number raised to each lane
+            ///
+            /// See [`exp`](crate::exp) for the special cases.
+            #[inline]
+            pub fn exp(self) -> Self {
+                generic::simd_exp(self)
+            }
+
+            /// Calculates the natural logarithm of each lane
+            ///
+            /// See [`ln`](crate::ln) for the special cases.
+            #[inline]
+            pub fn ln(self) -> Self {
+                generic::simd_ln(self)
+            }
+
+            /// Calculates `self` raised to `other`, lane-wise
+            ///
+            /// See [`pow`](crate::pow) for the special cases.
+            #[inline]
+            pub fn pow(self, other: Self) -> Self {
+                generic::simd_pow(self, other)
+            }
+
+            /// Calculates the sine of each lane
+            ///
+            /// See [`sin`](crate::sin) for the special cases.
+            #[inline]
+            pub fn sin(self) -> Self {
+                generic::simd_sin_cos(self).0
+            }
+
+            /// Calculates the cosine of each lane
+            ///
+            /// See [`cos`](crate::cos) for the special cases.
+            #[inline]
+            pub fn cos(self) -> Self {
+                generic::simd_sin_cos(self).1
+            }
+
+            /// Calculates the sine and cosine of each lane, returning
+            /// `(sin, cos)`
+            ///
+            /// See [`sin_cos`](crate::sin_cos) for the special cases.
+            #[inline]
+            pub fn sin_cos(self) -> (Self, Self) {
+                generic::simd_sin_cos(self)
+            }
+
+            /// Calculates the four quadrant arctangent of `self` (`y`) and
+            /// `other` (`x`), lane-wise
+            ///
+            /// See [`atan2`](crate::atan2) for the special cases.
+            #[inline]
+            pub fn atan2(self, other: Self) -> Self {
+                generic::simd_atan2(self, other)
+            }
+
+            #[inline]
+            fn map(self, f: impl Fn($scalar) -> $scalar) -> Self {
+                Self(self.0.map(f))
+            }
+
+            #[inline]
+            fn map_pair(self, f: impl Fn($scalar) -> ($scalar, $scalar)) -> (Self, Self) {
+                let r = self.0.map(f);
+                (Self(r.map(|r| r.0)), Self(r.map(|r| r.1)))
+            }
+
+            #[inline]
+            fn map_int<I: Copy>(self, f: impl Fn($scalar) -> ($scalar, I)) -> (Self, [I; $lanes]) {
+                let r = self.0.map(f);
+                (Self(r.map(|r| r.0)), r.map(|r| r.1))
+            }
+
+            #[inline]
+            fn zip(self, rhs: Self, f: impl Fn($scalar, $scalar) -> $scalar) -> Self {
+                Self(core::array::from_fn(|i| f(self.0[i], rhs.0[i])))
+            }
+
+            #[inline]
+            fn zip_int<I: Copy>(
+                self,
+                rhs: Self,
+                f: impl Fn($scalar, $scalar) -> ($scalar, I),
+            ) -> (Self, [I; $lanes]) {
+                let r: [($scalar, I); $lanes] = core::array::from_fn(|i| f(self.0[i], rhs.0[i]));
+                (Self(r.map(|r| r.0)), r.map(|r| r.1))
+            }
+
+            #[inline]
+            fn zip_bits(self, rhs: Self, f: impl Fn(Raw<$scalar>, Raw<$scalar>) -> Raw<$scalar>) -> Self {
+                self.zip(rhs, |a, b| <$scalar>::from_bits(f(a.to_bits(), b.to_bits())))
+            }
+
+            #[inline]
+            fn cmp(self, rhs: Self, f: impl Fn($scalar, $scalar) -> bool) -> Mask<$lanes> {
+                Mask(core::array::from_fn(|i| f(self.0[i], rhs.0[i])))
+            }
+        }
+
+        /// Functions that are applied to each lane with the scalar function
+        impl $vec {
+            lane_wise! {
+                /// Calculates the absolute value of each lane
+                unary abs;
+                /// Returns the magnitude of each lane of `self` with the sign of the lane of
+                /// `other`
+                binary copysign;
+                /// Rounds each lane to the nearest integer, ties round away from zero
+                unary round;
+                /// Rounds each lane to the nearest integer that is not greater in magnitude
+                unary trunc;
+                /// Rounds each lane to the nearest integer that is not less
+                unary ceil;
+                /// Rounds each lane to the nearest integer that is not greater
+                unary floor;
+            }
+
+            /// Calculates each lane times two raised to `n`
+            ///
+            /// See [`scalbn`](crate::scalbn) for the special cases.
+            #[inline]
+            pub fn scalbn(self, n: i32) -> Self {
+                self.map(|x| crate::scalbn(x, n))
+            }
+
+            /// Splits each lane into mantissa and exponent, returning the
+            /// mantissas and the exponents
+            ///
+            /// See [`frexp`](crate::frexp) for the special cases.
+            #[inline]
+            pub fn frexp(self) -> (Self, [i32; $lanes]) {
+                self.map_int(crate::frexp)
+            }
+
+            /// Calculates `self * a + b` with a single rounding, lane-wise
+            ///
+            /// See [`fma`](crate::fma) for the special cases.
+            #[inline]
+            pub fn fma(self, a: Self, b: Self) -> Self {
+                Self(core::array::from_fn(|i| crate::fma(self.0[i], a.0[i], b.0[i])))
+            }
+
+            lane_wise! {
+                /// Calculates the remainder of `self / other`, with the quotient rounded towards
+                /// zero, lane-wise
+                binary fmod;
+                /// Calculates the IEEE remainder of `self / other`, lane-wise
+                binary remainder;
+            }
+
+            /// Calculates the IEEE remainder of `self / other` and the lowest
+            /// bits of the quotient, lane-wise
+            ///
+            /// See [`remquo`](crate::remquo) for the special cases.
+            #[inline]
+            pub fn remquo(self, other: Self) -> (Self, [i32; $lanes]) {
+                self.zip_int(other, crate::remquo)
+            }
+
+            lane_wise! {
+                /// Calculates the Pythagorean addition of `self` and `other`, lane-wise
+                binary hypot;
+                /// Calculates the square root of each lane
+                unary sqrt;
+                /// Calculates the reciprocal square root of each lane
+                unary rsqrt;
+                /// Calculates the cube root of each lane
+                unary cbrt;
+                /// Calculates `exp(x) - 1` for each lane
+                unary exp_m1;
+                /// Calculates Euler's number raised to each lane as unevaluated sums `hi + lo`,
+                /// returning `(hi, lo)`
+                pair exp_hi_lo;
+                /// Calculates 2 raised to each lane
+                unary exp2;
+                /// Calculates `2^x - 1` for each lane
+                unary exp2_m1;
+                /// Calculates 10 raised to each lane
+                unary exp10;
+                /// Calculates `10^x - 1` for each lane
+                unary exp10_m1;
+                /// Calculates the natural logarithm of each lane plus 1
+                unary ln_1p;
+                /// Calculates the natural logarithm of each lane as unevaluated sums `hi + lo`,
+                /// returning `(hi, lo)`
+                pair ln_hi_lo;
+                /// Calculates the natural logarithm of each lane plus 1 as unevaluated sums `hi +
+                /// lo`, returning `(hi, lo)`
+                pair ln_1p_hi_lo;
+                /// Calculates the base-2 logarithm of each lane
+                unary log2;
+                /// Calculates the base-2 logarithm of each lane plus 1
+                unary log2_1p;
+                /// Calculates the base-10 logarithm of each lane
+                unary log10;
+                /// Calculates the base-10 logarithm of each lane plus 1
+                unary log10_1p;
+            }
+
+            /// Calculates each lane raised to `n`
+            ///
+            /// See [`powi`](crate::powi) for the special cases.
+            #[inline]
+            pub fn powi(self, n: i32) -> Self {
+                self.map(|x| crate::powi(x, n))
+            }
+
+            lane_wise! {
+                /// Calculates `self` raised to `other`, defined as `exp(other * ln(self))`,
+                /// lane-wise
+                binary powr;
+            }
+
+            /// Calculates each lane raised to `n`
+            ///
+            /// See [`pown`](crate::pown) for the special cases.
+            #[inline]
+            pub fn pown(self, n: i64) -> Self {
+                self.map(|x| crate::pown(x, n))
+            }
+
+            /// Calculates the `n`-th root of each lane
+            ///
+            /// See [`rootn`](crate::rootn) for the special cases.
+            #[inline]
+            pub fn rootn(self, n: i64) -> Self {
+                self.map(|x| crate::rootn(x, n))
+            }
+
+            /// Calculates `(1 + x)^n` for each lane
+            ///
+            /// See [`compound`](crate::compound) for the special cases.
+            #[inline]
+            pub fn compound(self, n: i64) -> Self {
+                self.map(|x| crate::compound(x, n))
+            }
+
+            /// Calculates the sine and cosine of each lane as unevaluated
+            /// sums, returning `((sin_hi, sin_lo), (cos_hi, cos_lo))`
+            ///
+            /// See [`sin_cos_hi_lo`](crate::sin_cos_hi_lo) for the special
+            /// cases.
+            #[inline]
+            pub fn sin_cos_hi_lo(self) -> ((Self, Self), (Self, Self)) {
+                let r = self.0.map(crate::sin_cos_hi_lo);
+                (
+                    (Self(r.map(|r| r.0.0)), Self(r.map(|r| r.0.1))),
+                    (Self(r.map(|r| r.1.0)), Self(r.map(|r| r.1.1))),
+                )
+            }
+
+            lane_wise! {
+                /// Calculates the tangent of each lane
+                unary tan;
+            }
+
+            /// Reduces each lane by multiples of π/2, returning the
+            /// quadrants and the remainders as unevaluated sums `hi + lo`
+            ///
+            /// See [`rem_pio2`](crate::rem_pio2) for the special cases.
+            #[inline]
+            pub fn rem_pio2(self) -> ([i32; $lanes], Self, Self) {
+                let r = self.0.map(crate::rem_pio2);
+                (r.map(|r| r.0), Self(r.map(|r| r.1)), Self(r.map(|r| r.2)))
+            }
+
+            lane_wise! {
+                /// Wraps each lane (in radians) into the range `[-π, π]`
+                unary wrap_angle;
+                /// Calculates the sine of each lane in degrees
+                unary sind;
+                /// Calculates the cosine of each lane in degrees
+                unary cosd;
+                /// Calculates the sine and cosine of each lane in degrees, returning `(sin, cos)`
+                pair sind_cosd;
+                /// Calculates the tangent of each lane in degrees
+                unary tand;
+                /// Calculates the sine of each lane in half-revolutions
+                unary sinpi;
+                /// Calculates the cosine of each lane in half-revolutions
+                unary cospi;
+                /// Calculates the sine and cosine of each lane in half-revolutions, returning
+                /// `(sin, cos)`
+                pair sinpi_cospi;
+                /// Calculates the tangent of each lane in half-revolutions
+                unary tanpi;
+                /// Calculates the arcsine of each lane, in radians
+                unary asin;
+                /// Calculates the arccosine of each lane, in radians
+                unary acos;
+                /// Calculates the arctangent of each lane, in radians
+                unary atan;
+                /// Calculates the arcsine of each lane, in degrees
+                unary asind;
+                /// Calculates the arccosine of each lane, in degrees
+                unary acosd;
+                /// Calculates the arctangent of each lane, in degrees
+                unary atand;
+                /// Calculates the four quadrant arctangent of `self` (`y`) and `other` (`x`) in
+                /// degrees, lane-wise
+                binary atan2d;
+                /// Calculates the arcsine of each lane, in half-revolutions
+                unary asinpi;
+                /// Calculates the arccosine of each lane, in half-revolutions
+                unary acospi;
+                /// Calculates the arctangent of each lane, in half-revolutions
+                unary atanpi;
+                /// Calculates the four quadrant arctangent of `self` (`y`) and `other` (`x`) in
+                /// half-revolutions, lane-wise
+                binary atan2pi;
+                /// Calculates the hyperbolic sine of each lane
+                unary sinh;
+                /// Calculates the hyperbolic cosine of each lane
+                unary cosh;
+                /// Calculates the hyperbolic sine and cosine of each lane, returning `(sinh, cosh)`
+                pair sinh_cosh;
+                /// Calculates the hyperbolic tangent of each lane
+                unary tanh;
+                /// Calculates the hyperbolic arcsine of each lane
+                unary asinh;
+                /// Calculates the hyperbolic arccosine of each lane
+                unary acosh;
+                /// Calculates the hyperbolic arctangent of each lane
+                unary atanh;
+                /// Calculates the gamma function of each lane
+                unary gamma;
+            }
+
+            /// Calculates the logarithm of the absolute value of the gamma
+            /// function of each lane, returning the logarithms and the signs
+            ///
+            /// See [`ln_gamma`](crate::ln_gamma) for the special cases.
+            #[inline]
+            pub fn ln_gamma(self) -> (Self, [i8; $lanes]) {
+                self.map_int(crate::ln_gamma)
+            }
+
+            lane_wise! {
+                /// Calculates the regularized lower incomplete gamma function of `self` (`a`) and
+                /// `other` (`x`), lane-wise
+                binary gamma_p;
+                /// Calculates the regularized upper incomplete gamma function of `self` (`a`) and
+                /// `other` (`x`), lane-wise
+                binary gamma_q;
+                /// Calculates the inverse of [`gamma_p`](Self::gamma_p) of `self` (`a`) and `other`
+                /// (`p`), lane-wise
+                binary gamma_p_inv;
+                /// Calculates the inverse of [`gamma_q`](Self::gamma_q) of `self` (`a`) and `other`
+                /// (`q`), lane-wise
+                binary gamma_q_inv;
+                /// Calculates the digamma function of each lane
+                unary digamma;
+            }
+
+            /// Calculates the polygamma function of order `n` of each lane
+            ///
+            /// See [`polygamma`](crate::polygamma) for the special cases.
+            #[inline]
+            pub fn polygamma(self, n: i32) -> Self {
+                self.map(|x| crate::polygamma(n, x))
+            }
+
+            lane_wise! {
+                /// Calculates the Riemann zeta function of each lane
+                unary zeta;
+                /// Calculates the Hurwitz zeta function of `self` (`s`) and `other` (`q`),
+                /// lane-wise
+                binary hurwitz_zeta;
+                /// Calculates the beta function of `self` and `other`, lane-wise
+                binary beta;
+            }
+
+            /// Calculates the logarithm of the absolute value of the beta
+            /// function of `self` and `other`, lane-wise, returning the
+            /// logarithms and the signs
+            ///
+            /// See [`ln_beta`](crate::ln_beta) for the special cases.
+            #[inline]
+            pub fn ln_beta(self, other: Self) -> (Self, [i8; $lanes]) {
+                self.zip_int(other, crate::ln_beta)
+            }
+
+            /// Calculates the regularized incomplete beta function of `self`
+            /// (`a`), `b` and `x`, lane-wise
+            ///
+            /// See [`beta_inc`](crate::beta_inc) for the special cases.
+            #[inline]
+            pub fn beta_inc(self, b: Self, x: Self) -> Self {
+                Self(core::array::from_fn(|i| crate::beta_inc(self.0[i], b.0[i], x.0[i])))
+            }
+
+            /// Calculates the inverse of [`beta_inc`](Self::beta_inc) of
+            /// `self` (`a`), `b` and `p`, lane-wise
+            ///
+            /// See [`beta_inc_inv`](crate::beta_inc_inv) for the special
+            /// cases.
+            #[inline]
+            pub fn beta_inc_inv(self, b: Self, p: Self) -> Self {
+                Self(core::array::from_fn(|i| crate::beta_inc_inv(self.0[i], b.0[i], p.0[i])))
+            }
+
+            lane_wise! {
+                /// Calculates the error function of each lane
+                unary erf;
+                /// Calculates the complementary error function of each lane
+                unary erfc;
+                /// Calculates the inverse error function of each lane
+                unary erfinv;
+                /// Calculates the inverse complementary error function of each lane
+                unary erfcinv;
+                /// Calculates the Bessel function of the first kind of order 0 of each lane
+                unary j0;
+                /// Calculates the Bessel function of the first kind of order 1 of each lane
+                unary j1;
+            }
+
+            /// Calculates the Bessel function of the first kind of order `n`
+            /// of each lane
+            ///
+            /// See [`jn`](crate::jn) for the special cases.
+            #[inline]
+            pub fn jn(self, n: i32) -> Self {
+                self.map(|x| crate::jn(n, x))
+            }
+
+            lane_wise! {
+                /// Calculates the Bessel function of the second kind of order 0 of each lane
+                unary y0;
+                /// Calculates the Bessel function of the second kind of order 1 of each lane
+                unary y1;
+            }
+
+            /// Calculates the Bessel function of the second kind of order `n`
+            /// of each lane
+            ///
+            /// See [`yn`](crate::yn) for the special cases.
+            #[inline]
+            pub fn yn(self, n: i32) -> Self {
+                self.map(|x| crate::yn(n, x))
+            }
+
+            lane_wise! {
+                /// Calculates the principal branch of the Lambert W function of each lane
+                unary lambert_w0;
+                /// Calculates the -1 branch of the Lambert W function of each lane
+                unary lambert_wm1;
+            }
+        }
+
+        impl From<[$scalar; $lanes]> for $vec {
+            #[inline]
+            fn from(array: [$scalar; $lanes]) -> Self {
+                Self(array)
+            }
+        }
+
+        impl From<$vec> for [$scalar; $lanes] {
+            #[inline]
+            fn from(vec: $vec) -> Self {
+                vec.0
+            }
+        }
+
+        impl core::ops::Add for $vec {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                self.zip(rhs, |a, b| a + b)
+            }
+        }
+
+        impl core::ops::Sub for $vec {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                self.zip(rhs, |a, b| a - b)
+            }
+        }
+
+        impl core::ops::Mul for $vec {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                self.zip(rhs, |a, b| a * b)
+            }
+        }
+
+        impl core::ops::Div for $vec {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: Self) -> Self {
+                self.zip(rhs, |a, b| a / b)
+            }
+        }
+
+        impl_lanes!($vec, $scalar, purify = |x| x.map(crate::traits::Lanes::purify));
+
+        impl Simd for $vec {
+            type Mask = Mask<$lanes>;
+
+            type Array = [$scalar; $lanes];
+
+            #[inline]
+            fn from_array(array: Self::Array) -> Self {
+                Self(array)
+            }
+
+            #[inline]
+            fn to_array(self) -> Self::Array {
+                self.0
+            }
+
+            #[inline]
+            fn splat_raw(raw: Raw<$scalar>) -> Self {
+                Self::splat(<$scalar>::from_bits(raw))
+            }
+
+            #[inline]
+            fn and(self, rhs: Self) -> Self {
+                self.zip_bits(rhs, |a, b| a & b)
+            }
+
+            #[inline]
+            fn or(self, rhs: Self) -> Self {
+                self.zip_bits(rhs, |a, b| a | b)
+            }
+
+            #[inline]
+            fn xor(self, rhs: Self) -> Self {
+                self.zip_bits(rhs, |a, b| a ^ b)
+            }
+
+            #[inline]
+            fn shl_mant(self) -> Self {
+                self.map(|x| <$scalar>::from_bits(x.to_bits() << (<$scalar>::MANTISSA_DIGITS - 1)))
+            }
+
+            #[inline]
+            fn shr_mant(self) -> Self {
+                self.map(|x| <$scalar>::from_bits(x.to_bits() >> (<$scalar>::MANTISSA_DIGITS - 1)))
+            }
+
+            #[inline]
+            fn cmp_eq(self, rhs: Self) -> Self::Mask {
+                self.cmp(rhs, |a, b| a == b)
+            }
+
+            #[inline]
+            fn cmp_lt(self, rhs: Self) -> Self::Mask {
+                self.cmp(rhs, |a, b| a < b)
+            }
+
+            #[inline]
+            fn cmp_le(self, rhs: Self) -> Self::Mask {
+                self.cmp(rhs, |a, b| a <= b)
+            }
+
+            #[inline]
+            fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self {
+                Self(core::array::from_fn(|i| {
+                    if mask.0[i] { if_true.0[i] } else { if_false.0[i] }
+                }))
+            }
+        }
+    };
+}
+
+impl_vector! {
+    /// A vector of four `f32`
+    F32x4, f32, 4
+}
+
+impl_vector! {
+    /// A vector of eight `f32`
+    F32x8, f32, 8
+}
+
+impl_vector! {
+    /// A vector of two `f64`
+    F64x2, f64, 2
+}
+
+impl_vector! {
+    /// A vector of four `f64`
+    F64x4, f64, 4
+}
@@ -0,0 +1,159 @@
+//! Math functions on slices.
+//!
+//! The functions in this module apply a math function to every element of
+//! a slice. The results are bit-identical to the functions with the same name
+//! in the crate root, so they have the same special cases.
+//!
+//! The elements are processed in groups. The special cases are checked once
+//! for each group and, when no element of the group needs special handling,
+//! the polynomial approximations are evaluated without branches, which allows
+//! the compiler to vectorize them. For [`sin`] and [`cos`], this is only done
+//! when no element of the group needs argument reduction (that is, all of
+//! them are in [-pi/4, pi/4]); other groups are calculated element by element
+//! with the same speed as the scalar functions.
+//!
+//! # Example
+//!
+//! ```
+//! let mut x = [0.5f64, 1.0, 1.5, 2.0, 2.5];
+//! fpmath::slice::sin(&mut x);
+//! assert_eq!(x[1], fpmath::sin(1.0));
+//!
+//! let mut y = [0.0; 5];
+//! fpmath::slice::exp_into(&x, &mut y);
+//! assert_eq!(y[1], fpmath::exp(x[1]));
+//! ```
+
+use crate::ElementaryMath;
+
+/// Number of elements in each group
+pub(crate) const LANES: usize = 8;
+
+/// Calls `f` for each group of elements of `x`
+#[inline]
+fn for_each_group<F: Copy>(x: &mut [F], f: impl Fn(&mut [F; LANES])) {
+    let mut chunks = x.chunks_exact_mut(LANES);
+    for chunk in &mut chunks {
+        f(chunk.try_into().unwrap());
+    }
+
+    let rem = chunks.into_remainder();
+    if let Some(&first) = rem.first() {
+        // Fill the last group with an element of the slice, so the extra
+        // elements do not need special handling
+        let mut group = [first; LANES];
+        group[..rem.len()].copy_from_slice(rem);
+        f(&mut group);
+        rem.copy_from_slice(&group[..rem.len()]);
+    }
+}
+
+/// Replaces each element of `x` with Euler's number raised to it
+///
+/// See [`exp`](crate::exp) for the special cases.
+pub fn exp<F: ElementaryMath>(x: &mut [F]) {
+    for_each_group(x, F::slice_exp);
+}
+
+/// Stores Euler's number raised to each element of `x` in `y`
+///
+/// See [`exp`](crate::exp) for the special cases.
+///
+/// # Panics
+///
+/// Panics if `x` and `y` have different lengths.
+pub fn exp_into<F: ElementaryMath>(x: &[F], y: &mut [F]) {
+    assert_eq!(x.len(), y.len(), "slices have different lengths");
+    y.copy_from_slice(x);
+    exp(y);
+}
+
+/// Replaces each element of `x` with its natural logarithm
+///
+/// See [`ln`](crate::ln) for the special cases.
+pub fn ln<F: ElementaryMath>(x: &mut [F]) {
+    for_each_group(x, F::slice_ln);
+}
+
+/// Stores the natural logarithm of each element of `x` in `y`
+///
+/// See [`ln`](crate::ln) for the special cases.
+///
+/// # Panics
+///
+/// Panics if `x` and `y` have different lengths.
+pub fn ln_into<F: ElementaryMath>(x: &[F], y: &mut [F]) {
+    assert_eq!(x.len(), y.len(), "slices have different lengths");
+    y.copy_from_slice(x);
+    ln(y);
+}
+
+/// Replaces each element of `x` with its sine
+///
+/// See [`sin`](crate::sin) for the special cases.
+pub fn sin<F: ElementaryMath>(x: &mut [F]) {
+    for_each_group(x, F::slice_sin);
+}
+
+/// Stores the sine of each element of `x` in `y`
+///
+/// See [`sin`](crate::sin) for the special cases.
+///
+/// # Panics
+///
+/// Panics if `x` and `y` have different lengths.
+pub fn sin_into<F: ElementaryMath>(x: &[F], y: &mut [F]) {
+    assert_eq!(x.len(), y.len(), "slices have different lengths");
+    y.copy_from_slice(x);
+    sin(y);
+}
+
+/// Replaces each element of `x` with its cosine
+///
+/// See [`cos`](crate::cos) for the special cases.
+pub fn cos<F: ElementaryMath>(x: &mut [F]) {
+    for_each_group(x, F::slice_cos);
+}
+
+/// Stores the cosine of each element of `x` in `y`
+///
+/// See [`cos`](crate::cos) for the special cases.
+///
+/// # Panics
+///
+/// Panics if `x` and `y` have different lengths.
+pub fn cos_into<F: ElementaryMath>(x: &[F], y: &mut [F]) {
+    assert_eq!(x.len(), y.len(), "slices have different lengths");
+    y.copy_from_slice(x);
+    cos(y);
+}
+
+/// Stores the sine and cosine of each element of `x` in `sin` and `cos`
+///
+/// See [`sin_cos`](crate::sin_cos) for the special cases.
+///
+/// # Panics
+///
+/// Panics if `x`, `sin` and `cos` do not have the same length.
+pub fn sin_cos_into<F: ElementaryMath>(x: &[F], sin: &mut [F], cos: &mut [F]) {
+    assert_eq!(x.len(), sin.len(), "slices have different lengths");
+    assert_eq!(x.len(), cos.len(), "slices have different lengths");
+    sin.copy_from_slice(x);
+
+    let mut sin_chunks = sin.chunks_exact_mut(LANES);
+    let mut cos_chunks = cos.chunks_exact_mut(LANES);
+    for (sin, cos) in (&mut sin_chunks).zip(&mut cos_chunks) {
+        F::slice_sin_cos(sin.try_into().unwrap(), cos.try_into().unwrap());
+    }
+
+    let sin_rem = sin_chunks.into_remainder();
+    let cos_rem = cos_chunks.into_remainder();
+    if let Some(&first) = sin_rem.first() {
+        let mut sin_group = [first; LANES];
+        let mut cos_group = [first; LANES];
+        sin_group[..sin_rem.len()].copy_from_slice(sin_rem);
+        F::slice_sin_cos(&mut sin_group, &mut cos_group);
+        sin_rem.copy_from_slice(&sin_group[..sin_rem.len()]);
+        cos_rem.copy_from_slice(&cos_group[..cos_rem.len()]);
+    }
+}
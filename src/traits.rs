@@ -60,6 +60,8 @@ pub(crate) trait Int:
     const TWO: Self;
 
     const MAX: Self;
+
+    fn leading_zeros(self) -> u32;
 }
 
 #[allow(dead_code)] // https://github.com/rust-lang/rust/issues/128839
@@ -68,9 +70,110 @@ pub(crate) trait UInt: Int + From<u8> {}
 #[allow(dead_code)] // https://github.com/rust-lang/rust/issues/128839
 pub(crate) trait SInt: Int + From<i8> + core::ops::Neg<Output = Self> {}
 
+/// A float or a SIMD vector of floats
+///
+/// Arithmetic operations are done independently for each lane, so code that
+/// is generic over this trait gives the same bits for a float and for each
+/// lane of a vector.
+pub(crate) trait Lanes:
+    Copy
+    + core::ops::Neg<Output = Self>
+    + core::ops::Add<Self, Output = Self>
+    + core::ops::Sub<Self, Output = Self>
+    + core::ops::Mul<Self, Output = Self>
+    + core::ops::Div<Self, Output = Self>
+    + core::ops::Add<Self::Scalar, Output = Self>
+    + core::ops::Sub<Self::Scalar, Output = Self>
+    + core::ops::Mul<Self::Scalar, Output = Self>
+    + core::ops::Div<Self::Scalar, Output = Self>
+{
+    type Scalar: Float;
+
+    /// Returns a value with all lanes set to `value`
+    fn splat(value: Self::Scalar) -> Self;
+
+    /// Workarounds rustc/LLVM bugs
+    fn purify(self) -> Self;
+
+    /// Clears the lower half of the mantissa bits
+    fn split_hi(self) -> Self;
+
+    #[inline]
+    fn split_hi_lo(self) -> (Self, Self) {
+        let x = self.purify();
+        let hi = x.split_hi();
+        let lo = x - hi;
+        (hi, lo)
+    }
+}
+
+/// A SIMD vector of floats
+pub(crate) trait Simd: Lanes {
+    type Mask: SimdMask;
+
+    type Array: Copy + AsRef<[Self::Scalar]> + AsMut<[Self::Scalar]>;
+
+    fn from_array(array: Self::Array) -> Self;
+
+    fn to_array(self) -> Self::Array;
+
+    /// Returns a value with the raw bits of all lanes set to `raw`
+    fn splat_raw(raw: <Self::Scalar as Float>::Raw) -> Self;
+
+    /// Calculates the bitwise AND of the raw bits of each lane
+    fn and(self, rhs: Self) -> Self;
+
+    /// Calculates the bitwise OR of the raw bits of each lane
+    fn or(self, rhs: Self) -> Self;
+
+    /// Calculates the bitwise XOR of the raw bits of each lane
+    fn xor(self, rhs: Self) -> Self;
+
+    /// Shifts the raw bits of each lane to the left by `MANT_BITS`
+    fn shl_mant(self) -> Self;
+
+    /// Shifts the raw bits of each lane to the right by `MANT_BITS`,
+    /// filling with zeros
+    fn shr_mant(self) -> Self;
+
+    fn cmp_eq(self, rhs: Self) -> Self::Mask;
+
+    fn cmp_lt(self, rhs: Self) -> Self::Mask;
+
+    fn cmp_le(self, rhs: Self) -> Self::Mask;
+
+    #[inline]
+    fn cmp_gt(self, rhs: Self) -> Self::Mask {
+        rhs.cmp_lt(self)
+    }
+
+    #[inline]
+    fn cmp_ge(self, rhs: Self) -> Self::Mask {
+        rhs.cmp_le(self)
+    }
+
+    /// Takes each lane from `if_true` or `if_false` depending on `mask`
+    fn select(mask: Self::Mask, if_true: Self, if_false: Self) -> Self;
+}
+
+/// The result of a lane-wise comparison of SIMD vectors
+pub(crate) trait SimdMask:
+    Copy
+    + core::ops::BitAnd<Self, Output = Self>
+    + core::ops::BitOr<Self, Output = Self>
+    + core::ops::BitXor<Self, Output = Self>
+    + core::ops::Not<Output = Self>
+{
+    /// Returns an integer where the bit `i` is set when lane `i` is true
+    fn bitmask(self) -> u32;
+
+    /// Returns whether all lanes are true
+    fn all(self) -> bool;
+}
+
 pub(crate) trait Float:
     'static
-    + Copy
+    + Lanes<Scalar = Self>
     + PartialOrd
     + CastFrom<u8>
     + CastFrom<i16>
@@ -79,12 +182,6 @@ pub(crate) trait Float:
     + CastFrom<i64>
     + CastFrom<u64>
     + core::fmt::Debug
-    + core::fmt::Display
-    + core::ops::Neg<Output = Self>
-    + core::ops::Add<Self, Output = Self>
-    + core::ops::Sub<Self, Output = Self>
-    + core::ops::Mul<Self, Output = Self>
-    + core::ops::Div<Self, Output = Self>
 {
     type Raw: UInt
         + From<Self::RawExp>
@@ -125,9 +222,6 @@ pub(crate) trait Float:
     #[cfg(test)]
     fn largest() -> Self;
 
-    /// Workarounds rustc/LLVM bugs
-    fn purify(self) -> Self;
-
     fn to_raw(self) -> Self::Raw;
 
     fn from_raw(raw: Self::Raw) -> Self;
@@ -207,19 +301,6 @@ pub(crate) trait Float:
         Self::one().set_exp(x)
     }
 
-    #[inline]
-    fn split_hi(self) -> Self {
-        Self::from_raw(self.to_raw() & (Self::Raw::MAX << ((Self::MANT_BITS + 2) / 2)))
-    }
-
-    #[inline]
-    fn split_hi_lo(self) -> (Self, Self) {
-        let x = self.purify();
-        let hi = x.split_hi();
-        let lo = x - hi;
-        (hi, lo)
-    }
-
     #[inline]
     fn norm_hi_lo_full(hi: Self, lo: Self) -> (Self, Self) {
         let lo = lo.purify();
@@ -240,6 +321,12 @@ pub(crate) trait Float:
     fn parse(s: &str) -> Self;
 }
 
+/// Implementation of [`Lanes::split_hi`] for floats
+#[inline]
+pub(crate) fn float_split_hi<F: Float>(x: F) -> F {
+    F::from_raw(x.to_raw() & (F::Raw::MAX << ((F::MANT_BITS + 2) / 2)))
+}
+
 pub(crate) trait FloatConsts: Float {
     const PI: Self;
     const FRAC_PI_2: Self;
@@ -0,0 +1,207 @@
+//! Vectors of 256 bits using AVX2
+//!
+//! When AVX2 is not enabled at compile time, these types must only be used
+//! after checking that AVX2 is available.
+
+#![allow(unused_unsafe)] // intrinsics are safe in newer versions of Rust
+
+use core::arch::x86_64::{
+    __m256, __m256d, _CMP_EQ_OQ, _CMP_LE_OQ, _CMP_LT_OQ, _mm256_add_pd, _mm256_add_ps,
+    _mm256_and_pd, _mm256_and_ps, _mm256_blendv_pd, _mm256_blendv_ps, _mm256_castpd_si256,
+    _mm256_castps_si256, _mm256_castsi256_pd, _mm256_castsi256_ps, _mm256_cmp_pd, _mm256_cmp_ps,
+    _mm256_div_pd, _mm256_div_ps, _mm256_movemask_pd, _mm256_movemask_ps, _mm256_mul_pd,
+    _mm256_mul_ps, _mm256_or_pd, _mm256_or_ps, _mm256_set1_pd, _mm256_set1_ps, _mm256_slli_epi32,
+    _mm256_slli_epi64, _mm256_srli_epi32, _mm256_srli_epi64, _mm256_sub_pd, _mm256_sub_ps,
+    _mm256_xor_pd, _mm256_xor_ps,
+};
+
+macro_rules! impl_vector {
+    (
+        $vec:ident, $mask:ident, $wide:ty, $scalar:ty, $lanes:literal,
+        set1 = $set1:ident,
+        add = $add:ident,
+        sub = $sub:ident,
+        mul = $mul:ident,
+        div = $div:ident,
+        and = $and:ident,
+        or = $or:ident,
+        xor = $xor:ident,
+        cmp = $cmp:ident,
+        blendv = $blendv:ident,
+        movemask = $movemask:ident,
+        to_int = $to_int:ident,
+        from_int = $from_int:ident,
+        slli = $slli:ident,
+        srli = $srli:ident,
+    ) => {
+        #[derive(Copy, Clone)]
+        pub(crate) struct $vec($wide);
+
+        #[derive(Copy, Clone)]
+        pub(crate) struct $mask($wide);
+
+        impl From<$wide> for $vec {
+            #[inline]
+            fn from(value: $wide) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$vec> for $wide {
+            #[inline]
+            fn from(value: $vec) -> Self {
+                value.0
+            }
+        }
+
+        impl_vector!(@op $vec, core::ops::Add, add, $add);
+        impl_vector!(@op $vec, core::ops::Sub, sub, $sub);
+        impl_vector!(@op $vec, core::ops::Mul, mul, $mul);
+        impl_vector!(@op $vec, core::ops::Div, div, $div);
+
+        impl_lanes!($vec, $scalar, purify = |x| x);
+
+        impl crate::traits::Simd for $vec {
+            type Mask = $mask;
+
+            type Array = [$scalar; $lanes];
+
+            #[inline]
+            fn from_array(array: Self::Array) -> Self {
+                // SAFETY: both types are vectors of 256 bits
+                Self(unsafe { core::mem::transmute::<Self::Array, $wide>(array) })
+            }
+
+            #[inline]
+            fn to_array(self) -> Self::Array {
+                // SAFETY: both types are vectors of 256 bits
+                unsafe { core::mem::transmute::<$wide, Self::Array>(self.0) }
+            }
+
+            #[inline]
+            fn splat_raw(raw: <$scalar as crate::traits::Float>::Raw) -> Self {
+                Self(unsafe { $set1(<$scalar>::from_bits(raw)) })
+            }
+
+            #[inline]
+            fn and(self, rhs: Self) -> Self {
+                Self(unsafe { $and(self.0, rhs.0) })
+            }
+
+            #[inline]
+            fn or(self, rhs: Self) -> Self {
+                Self(unsafe { $or(self.0, rhs.0) })
+            }
+
+            #[inline]
+            fn xor(self, rhs: Self) -> Self {
+                Self(unsafe { $xor(self.0, rhs.0) })
+            }
+
+            #[inline]
+            fn shl_mant(self) -> Self {
+                const SHIFT: i32 = <$scalar>::MANTISSA_DIGITS as i32 - 1;
+                Self(unsafe { $from_int($slli::<SHIFT>($to_int(self.0))) })
+            }
+
+            #[inline]
+            fn shr_mant(self) -> Self {
+                const SHIFT: i32 = <$scalar>::MANTISSA_DIGITS as i32 - 1;
+                Self(unsafe { $from_int($srli::<SHIFT>($to_int(self.0))) })
+            }
+
+            #[inline]
+            fn cmp_eq(self, rhs: Self) -> $mask {
+                $mask(unsafe { $cmp::<_CMP_EQ_OQ>(self.0, rhs.0) })
+            }
+
+            #[inline]
+            fn cmp_lt(self, rhs: Self) -> $mask {
+                $mask(unsafe { $cmp::<_CMP_LT_OQ>(self.0, rhs.0) })
+            }
+
+            #[inline]
+            fn cmp_le(self, rhs: Self) -> $mask {
+                $mask(unsafe { $cmp::<_CMP_LE_OQ>(self.0, rhs.0) })
+            }
+
+            #[inline]
+            fn select(mask: $mask, if_true: Self, if_false: Self) -> Self {
+                Self(unsafe { $blendv(if_false.0, if_true.0, mask.0) })
+            }
+        }
+
+        impl_vector!(@op $mask, core::ops::BitAnd, bitand, $and);
+        impl_vector!(@op $mask, core::ops::BitOr, bitor, $or);
+        impl_vector!(@op $mask, core::ops::BitXor, bitxor, $xor);
+
+        impl core::ops::Not for $mask {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self {
+                self ^ $mask(<$vec as crate::traits::Simd>::splat_raw(!0).0)
+            }
+        }
+
+        impl crate::traits::SimdMask for $mask {
+            #[inline]
+            fn bitmask(self) -> u32 {
+                unsafe { $movemask(self.0) as u32 }
+            }
+
+            #[inline]
+            fn all(self) -> bool {
+                self.bitmask() == (1 << $lanes) - 1
+            }
+        }
+    };
+    (@op $vec:ident, $trait:path, $method:ident, $intrinsic:ident) => {
+        impl $trait for $vec {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                Self(unsafe { $intrinsic(self.0, rhs.0) })
+            }
+        }
+    };
+}
+
+impl_vector! {
+    F64x4, M64x4, __m256d, f64, 4,
+    set1 = _mm256_set1_pd,
+    add = _mm256_add_pd,
+    sub = _mm256_sub_pd,
+    mul = _mm256_mul_pd,
+    div = _mm256_div_pd,
+    and = _mm256_and_pd,
+    or = _mm256_or_pd,
+    xor = _mm256_xor_pd,
+    cmp = _mm256_cmp_pd,
+    blendv = _mm256_blendv_pd,
+    movemask = _mm256_movemask_pd,
+    to_int = _mm256_castpd_si256,
+    from_int = _mm256_castsi256_pd,
+    slli = _mm256_slli_epi64,
+    srli = _mm256_srli_epi64,
+}
+
+impl_vector! {
+    F32x8, M32x8, __m256, f32, 8,
+    set1 = _mm256_set1_ps,
+    add = _mm256_add_ps,
+    sub = _mm256_sub_ps,
+    mul = _mm256_mul_ps,
+    div = _mm256_div_ps,
+    and = _mm256_and_ps,
+    or = _mm256_or_ps,
+    xor = _mm256_xor_ps,
+    cmp = _mm256_cmp_ps,
+    blendv = _mm256_blendv_ps,
+    movemask = _mm256_movemask_ps,
+    to_int = _mm256_castps_si256,
+    from_int = _mm256_castsi256_ps,
+    slli = _mm256_slli_epi32,
+    srli = _mm256_srli_epi32,
+}
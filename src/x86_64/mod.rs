@@ -0,0 +1,211 @@
+//! Explicit SIMD versions of some math functions for x86_64.
+//!
+//! The functions in this module operate on the lanes of `__m256d` (four
+//! `f64`) and `__m256` (eight `f32`) vectors. The result of each lane is
+//! bit-identical to the function with the same name in the crate root, so
+//! they have the same special cases.
+//!
+//! The implementation is selected at compile time from the target features
+//! enabled (for example, with `-C target-feature=+avx2`):
+//!
+//! * AVX-512 (`avx512f` and `avx512vl`), which uses mask registers for the
+//!   comparisons. It requires Rust 1.89 or newer.
+//! * AVX2 (`avx2`).
+//! * SSE2, which is always available on x86_64 and processes each vector in
+//!   two halves.
+//!
+//! When AVX2 is not enabled at compile time and the `std` feature is enabled,
+//! the availability of AVX2 is detected at run time.
+//!
+//! [`from_array_pd`], [`to_array_pd`], [`from_array_ps`] and [`to_array_ps`]
+//! convert between vectors and arrays without `unsafe` code.
+//!
+//! # Example
+//!
+//! ```
+//! use fpmath::x86_64::{exp_pd, from_array_pd, to_array_pd};
+//!
+//! let x = from_array_pd([1.0, 2.0, 3.0, 4.0]);
+//! let y = to_array_pd(exp_pd(x));
+//! assert_eq!(y, [1.0, 2.0, 3.0, 4.0].map(fpmath::exp));
+//! ```
+
+use core::arch::x86_64::{__m256, __m256d};
+
+use crate::generic;
+
+#[cfg(any(
+    all(
+        target_feature = "avx2",
+        not(all(target_feature = "avx512f", target_feature = "avx512vl"))
+    ),
+    all(feature = "std", not(target_feature = "avx2"))
+))]
+mod avx2;
+#[cfg(all(target_feature = "avx512f", target_feature = "avx512vl"))]
+mod avx512;
+#[cfg(not(target_feature = "avx2"))]
+mod sse2;
+
+/// Evaluates `$body` with `$V` as the vector type `$vec` of the best
+/// available implementation
+macro_rules! dispatch {
+    (($($arg:ident: $arg_ty:ty),*) -> $ret:ty, $V:ident = $vec:ident => $body:expr) => {{
+        #[cfg(all(target_feature = "avx512f", target_feature = "avx512vl"))]
+        {
+            use crate::x86_64::avx512::$vec as $V;
+            return $body;
+        }
+        #[cfg(all(
+            target_feature = "avx2",
+            not(all(target_feature = "avx512f", target_feature = "avx512vl"))
+        ))]
+        {
+            use crate::x86_64::avx2::$vec as $V;
+            return $body;
+        }
+        #[cfg(not(target_feature = "avx2"))]
+        {
+            #[cfg(feature = "std")]
+            {
+                #[target_feature(enable = "avx2")]
+                unsafe fn with_avx2($($arg: $arg_ty),*) -> $ret {
+                    use crate::x86_64::avx2::$vec as $V;
+                    $body
+                }
+
+                if std::is_x86_feature_detected!("avx2") {
+                    // SAFETY: AVX2 is available
+                    return unsafe { with_avx2($($arg),*) };
+                }
+            }
+
+            use crate::x86_64::sse2::$vec as $V;
+            return $body;
+        }
+    }};
+}
+
+/// Calculates Euler's number raised to each lane of `x`
+///
+/// See [`exp`](crate::exp) for the special cases.
+#[inline]
+pub fn exp_pd(x: __m256d) -> __m256d {
+    dispatch!((x: __m256d) -> __m256d, V = F64x4 => generic::simd_exp(V::from(x)).into())
+}
+
+/// Calculates Euler's number raised to each lane of `x`
+///
+/// See [`exp`](crate::exp) for the special cases.
+#[inline]
+pub fn exp_ps(x: __m256) -> __m256 {
+    dispatch!((x: __m256) -> __m256, V = F32x8 => generic::simd_exp(V::from(x)).into())
+}
+
+/// Calculates the natural logarithm of each lane of `x`
+///
+/// See [`ln`](crate::ln) for the special cases.
+#[inline]
+pub fn ln_pd(x: __m256d) -> __m256d {
+    dispatch!((x: __m256d) -> __m256d, V = F64x4 => generic::simd_ln(V::from(x)).into())
+}
+
+/// Calculates the natural logarithm of each lane of `x`
+///
+/// See [`ln`](crate::ln) for the special cases.
+#[inline]
+pub fn ln_ps(x: __m256) -> __m256 {
+    dispatch!((x: __m256) -> __m256, V = F32x8 => generic::simd_ln(V::from(x)).into())
+}
+
+/// Calculates the sine and cosine of each lane of `x`, returning
+/// `(sin, cos)`
+///
+/// See [`sin_cos`](crate::sin_cos) for the special cases.
+#[inline]
+pub fn sin_cos_pd(x: __m256d) -> (__m256d, __m256d) {
+    dispatch!((x: __m256d) -> (__m256d, __m256d), V = F64x4 => {
+        let (sin, cos) = generic::simd_sin_cos(V::from(x));
+        (sin.into(), cos.into())
+    })
+}
+
+/// Calculates the sine and cosine of each lane of `x`, returning
+/// `(sin, cos)`
+///
+/// See [`sin_cos`](crate::sin_cos) for the special cases.
+#[inline]
+pub fn sin_cos_ps(x: __m256) -> (__m256, __m256) {
+    dispatch!((x: __m256) -> (__m256, __m256), V = F32x8 => {
+        let (sin, cos) = generic::simd_sin_cos(V::from(x));
+        (sin.into(), cos.into())
+    })
+}
+
+/// Calculates the four quadrant arctangent of the lanes of `y` and `x`
+///
+/// See [`atan2`](crate::atan2) for the special cases.
+#[inline]
+pub fn atan2_pd(y: __m256d, x: __m256d) -> __m256d {
+    dispatch!((y: __m256d, x: __m256d) -> __m256d, V = F64x4 => {
+        generic::simd_atan2(V::from(y), V::from(x)).into()
+    })
+}
+
+/// Calculates the four quadrant arctangent of the lanes of `y` and `x`
+///
+/// See [`atan2`](crate::atan2) for the special cases.
+#[inline]
+pub fn atan2_ps(y: __m256, x: __m256) -> __m256 {
+    dispatch!((y: __m256, x: __m256) -> __m256, V = F32x8 => {
+        generic::simd_atan2(V::from(y), V::from(x)).into()
+    })
+}
+
+/// Calculates each lane of `x` raised to the corresponding lane of `y`
+///
+/// See [`pow`](crate::pow) for the special cases.
+#[inline]
+pub fn pow_pd(x: __m256d, y: __m256d) -> __m256d {
+    dispatch!((x: __m256d, y: __m256d) -> __m256d, V = F64x4 => {
+        generic::simd_pow(V::from(x), V::from(y)).into()
+    })
+}
+
+/// Calculates each lane of `x` raised to the corresponding lane of `y`
+///
+/// See [`pow`](crate::pow) for the special cases.
+#[inline]
+pub fn pow_ps(x: __m256, y: __m256) -> __m256 {
+    dispatch!((x: __m256, y: __m256) -> __m256, V = F32x8 => {
+        generic::simd_pow(V::from(x), V::from(y)).into()
+    })
+}
+
+/// Creates a vector from four `f64`, with `x[0]` in the lowest lane
+#[inline]
+pub fn from_array_pd(x: [f64; 4]) -> __m256d {
+    // SAFETY: both types have the same size and any bit pattern is valid
+    unsafe { core::mem::transmute::<[f64; 4], __m256d>(x) }
+}
+
+/// Returns the lanes of a vector of four `f64`, from the lowest one
+#[inline]
+pub fn to_array_pd(x: __m256d) -> [f64; 4] {
+    // SAFETY: both types have the same size and any bit pattern is valid
+    unsafe { core::mem::transmute::<__m256d, [f64; 4]>(x) }
+}
+
+/// Creates a vector from eight `f32`, with `x[0]` in the lowest lane
+#[inline]
+pub fn from_array_ps(x: [f32; 8]) -> __m256 {
+    // SAFETY: both types have the same size and any bit pattern is valid
+    unsafe { core::mem::transmute::<[f32; 8], __m256>(x) }
+}
+
+/// Returns the lanes of a vector of eight `f32`, from the lowest one
+#[inline]
+pub fn to_array_ps(x: __m256) -> [f32; 8] {
+    // SAFETY: both types have the same size and any bit pattern is valid
+    unsafe { core::mem::transmute::<__m256, [f32; 8]>(x) }
+}
@@ -0,0 +1,237 @@
+//! Vectors of 256 bits made of two SSE2 vectors of 128 bits
+
+#![allow(unused_unsafe)] // intrinsics are safe in newer versions of Rust
+
+use core::arch::x86_64::{
+    __m128, __m128d, __m256, __m256d, _mm_add_pd, _mm_add_ps, _mm_and_pd, _mm_and_ps,
+    _mm_andnot_pd, _mm_andnot_ps, _mm_castpd_si128, _mm_castps_si128, _mm_castsi128_pd,
+    _mm_castsi128_ps, _mm_cmpeq_pd, _mm_cmpeq_ps, _mm_cmple_pd, _mm_cmple_ps, _mm_cmplt_pd,
+    _mm_cmplt_ps, _mm_div_pd, _mm_div_ps, _mm_movemask_pd, _mm_movemask_ps, _mm_mul_pd, _mm_mul_ps,
+    _mm_or_pd, _mm_or_ps, _mm_set1_pd, _mm_set1_ps, _mm_slli_epi32, _mm_slli_epi64, _mm_srli_epi32,
+    _mm_srli_epi64, _mm_sub_pd, _mm_sub_ps, _mm_xor_pd, _mm_xor_ps,
+};
+
+macro_rules! impl_vector {
+    (
+        $vec:ident($half:ty), $mask:ident, $wide:ty, $scalar:ty, $lanes:literal,
+        set1 = $set1:ident,
+        add = $add:ident,
+        sub = $sub:ident,
+        mul = $mul:ident,
+        div = $div:ident,
+        and = $and:ident,
+        andnot = $andnot:ident,
+        or = $or:ident,
+        xor = $xor:ident,
+        cmpeq = $cmpeq:ident,
+        cmplt = $cmplt:ident,
+        cmple = $cmple:ident,
+        movemask = $movemask:ident,
+        to_int = $to_int:ident,
+        from_int = $from_int:ident,
+        slli = $slli:ident,
+        srli = $srli:ident,
+    ) => {
+        #[derive(Copy, Clone)]
+        pub(crate) struct $vec([$half; 2]);
+
+        #[derive(Copy, Clone)]
+        pub(crate) struct $mask([$half; 2]);
+
+        impl $vec {
+            #[inline]
+            fn map(self, f: impl Fn($half) -> $half) -> Self {
+                Self([f(self.0[0]), f(self.0[1])])
+            }
+
+            #[inline]
+            fn zip(self, rhs: Self, f: impl Fn($half, $half) -> $half) -> Self {
+                Self([f(self.0[0], rhs.0[0]), f(self.0[1], rhs.0[1])])
+            }
+
+            #[inline]
+            fn cmp(self, rhs: Self, f: impl Fn($half, $half) -> $half) -> $mask {
+                $mask(self.zip(rhs, f).0)
+            }
+        }
+
+        impl From<$wide> for $vec {
+            #[inline]
+            fn from(value: $wide) -> Self {
+                // SAFETY: both types are vectors of 256 bits
+                Self(unsafe { core::mem::transmute::<$wide, [$half; 2]>(value) })
+            }
+        }
+
+        impl From<$vec> for $wide {
+            #[inline]
+            fn from(value: $vec) -> Self {
+                // SAFETY: both types are vectors of 256 bits
+                unsafe { core::mem::transmute::<[$half; 2], $wide>(value.0) }
+            }
+        }
+
+        impl_vector!(@op $vec, core::ops::Add, add, $add);
+        impl_vector!(@op $vec, core::ops::Sub, sub, $sub);
+        impl_vector!(@op $vec, core::ops::Mul, mul, $mul);
+        impl_vector!(@op $vec, core::ops::Div, div, $div);
+
+        impl_lanes!($vec, $scalar, purify = |x| x);
+
+        impl crate::traits::Simd for $vec {
+            type Mask = $mask;
+
+            type Array = [$scalar; $lanes];
+
+            #[inline]
+            fn from_array(array: Self::Array) -> Self {
+                // SAFETY: both types are vectors of 256 bits
+                Self(unsafe { core::mem::transmute::<Self::Array, [$half; 2]>(array) })
+            }
+
+            #[inline]
+            fn to_array(self) -> Self::Array {
+                // SAFETY: both types are vectors of 256 bits
+                unsafe { core::mem::transmute::<[$half; 2], Self::Array>(self.0) }
+            }
+
+            #[inline]
+            fn splat_raw(raw: <$scalar as crate::traits::Float>::Raw) -> Self {
+                let half = unsafe { $set1(<$scalar>::from_bits(raw)) };
+                Self([half; 2])
+            }
+
+            #[inline]
+            fn and(self, rhs: Self) -> Self {
+                self.zip(rhs, |a, b| unsafe { $and(a, b) })
+            }
+
+            #[inline]
+            fn or(self, rhs: Self) -> Self {
+                self.zip(rhs, |a, b| unsafe { $or(a, b) })
+            }
+
+            #[inline]
+            fn xor(self, rhs: Self) -> Self {
+                self.zip(rhs, |a, b| unsafe { $xor(a, b) })
+            }
+
+            #[inline]
+            fn shl_mant(self) -> Self {
+                const SHIFT: i32 = <$scalar>::MANTISSA_DIGITS as i32 - 1;
+                self.map(|x| unsafe { $from_int($slli::<SHIFT>($to_int(x))) })
+            }
+
+            #[inline]
+            fn shr_mant(self) -> Self {
+                const SHIFT: i32 = <$scalar>::MANTISSA_DIGITS as i32 - 1;
+                self.map(|x| unsafe { $from_int($srli::<SHIFT>($to_int(x))) })
+            }
+
+            #[inline]
+            fn cmp_eq(self, rhs: Self) -> $mask {
+                self.cmp(rhs, |a, b| unsafe { $cmpeq(a, b) })
+            }
+
+            #[inline]
+            fn cmp_lt(self, rhs: Self) -> $mask {
+                self.cmp(rhs, |a, b| unsafe { $cmplt(a, b) })
+            }
+
+            #[inline]
+            fn cmp_le(self, rhs: Self) -> $mask {
+                self.cmp(rhs, |a, b| unsafe { $cmple(a, b) })
+            }
+
+            #[inline]
+            fn select(mask: $mask, if_true: Self, if_false: Self) -> Self {
+                let t = if_true.and($vec(mask.0));
+                let f = $vec(mask.0).zip(if_false, |m, f| unsafe { $andnot(m, f) });
+                t.or(f)
+            }
+        }
+
+        impl_vector!(@op $mask, core::ops::BitAnd, bitand, $and);
+        impl_vector!(@op $mask, core::ops::BitOr, bitor, $or);
+        impl_vector!(@op $mask, core::ops::BitXor, bitxor, $xor);
+
+        impl core::ops::Not for $mask {
+            type Output = Self;
+
+            #[inline]
+            fn not(self) -> Self {
+                self ^ $mask(<$vec as crate::traits::Simd>::splat_raw(!0).0)
+            }
+        }
+
+        impl crate::traits::SimdMask for $mask {
+            #[inline]
+            fn bitmask(self) -> u32 {
+                const HALF_LANES: u32 = $lanes / 2;
+                let lo = unsafe { $movemask(self.0[0]) } as u32;
+                let hi = unsafe { $movemask(self.0[1]) } as u32;
+                lo | (hi << HALF_LANES)
+            }
+
+            #[inline]
+            fn all(self) -> bool {
+                self.bitmask() == (1 << $lanes) - 1
+            }
+        }
+    };
+    (@op $vec:ident, $trait:path, $method:ident, $intrinsic:ident) => {
+        impl $trait for $vec {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, rhs: Self) -> Self {
+                Self([
+                    unsafe { $intrinsic(self.0[0], rhs.0[0]) },
+                    unsafe { $intrinsic(self.0[1], rhs.0[1]) },
+                ])
+            }
+        }
+    };
+}
+
+impl_vector! {
+    F64x4(__m128d), M64x4, __m256d, f64, 4,
+    set1 = _mm_set1_pd,
+    add = _mm_add_pd,
+    sub = _mm_sub_pd,
+    mul = _mm_mul_pd,
+    div = _mm_div_pd,
+    and = _mm_and_pd,
+    andnot = _mm_andnot_pd,
+    or = _mm_or_pd,
+    xor = _mm_xor_pd,
+    cmpeq = _mm_cmpeq_pd,
+    cmplt = _mm_cmplt_pd,
+    cmple = _mm_cmple_pd,
+    movemask = _mm_movemask_pd,
+    to_int = _mm_castpd_si128,
+    from_int = _mm_castsi128_pd,
+    slli = _mm_slli_epi64,
+    srli = _mm_srli_epi64,
+}
+
+impl_vector! {
+    F32x8(__m128), M32x8, __m256, f32, 8,
+    set1 = _mm_set1_ps,
+    add = _mm_add_ps,
+    sub = _mm_sub_ps,
+    mul = _mm_mul_ps,
+    div = _mm_div_ps,
+    and = _mm_and_ps,
+    andnot = _mm_andnot_ps,
+    or = _mm_or_ps,
+    xor = _mm_xor_ps,
+    cmpeq = _mm_cmpeq_ps,
+    cmplt = _mm_cmplt_ps,
+    cmple = _mm_cmple_ps,
+    movemask = _mm_movemask_ps,
+    to_int = _mm_castps_si128,
+    from_int = _mm_castsi128_ps,
+    slli = _mm_slli_epi32,
+    srli = _mm_srli_epi32,
+}
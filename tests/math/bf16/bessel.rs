@@ -0,0 +1,80 @@
+use half::bf16;
+use rug::float::Round;
+
+use super::{check, from_rug, same_result, test_all, to_rug};
+
+#[test]
+fn test_j0() {
+    test_all(|x| {
+        check("j0", x, fpmath::j0(x), |y| y.j0_round(Round::Nearest));
+    });
+}
+
+#[test]
+fn test_j1() {
+    test_all(|x| {
+        let actual = fpmath::j1(x);
+        if x.is_infinite() {
+            // MPFR returns +0 for both infinities
+            assert_eq!(actual, bf16::ZERO, "j1({x:e}) = {actual:e}");
+        } else {
+            check("j1", x, actual, |y| y.j1_round(Round::Nearest));
+        }
+    });
+}
+
+#[test]
+fn test_jn() {
+    for n in [-6, -5, 2, 3, 7, 20] {
+        test_with_short(|x| {
+            let actual = fpmath::jn(n, x);
+            let mut y = to_rug(super::PREC, x);
+            let dir = y.jn_round(n, Round::Nearest);
+            let expected = from_rug(y, dir);
+            assert!(
+                same_result(actual, expected),
+                "jn({n}, {x:e}) = {actual:e} (expected {expected:e})",
+            );
+        });
+    }
+}
+
+#[test]
+fn test_y0() {
+    test_all(|x| {
+        check("y0", x, fpmath::y0(x), |y| y.y0_round(Round::Nearest));
+    });
+}
+
+#[test]
+fn test_y1() {
+    test_all(|x| {
+        check("y1", x, fpmath::y1(x), |y| y.y1_round(Round::Nearest));
+    });
+}
+
+#[test]
+fn test_yn() {
+    for n in [-6, -5, 2, 3, 7, 20] {
+        test_with_short(|x| {
+            let actual = fpmath::yn(n, x);
+            let mut y = to_rug(super::PREC, x);
+            let dir = y.yn_round(n, Round::Nearest);
+            let expected = from_rug(y, dir);
+            assert!(
+                same_result(actual, expected),
+                "yn({n}, {x:e}) = {actual:e} (expected {expected:e})",
+            );
+        });
+    }
+}
+
+/// Calls `f` with every `bf16` value with a magnitude below 1024, since
+/// MPFR gets too slow with larger arguments
+fn test_with_short(mut f: impl FnMut(bf16)) {
+    test_all(|x| {
+        if x.to_f32().abs() < 1024.0 {
+            f(x);
+        }
+    });
+}
@@ -0,0 +1,174 @@
+use half::bf16;
+use rand::RngExt as _;
+
+use super::{from_rug_prec, mkfloat, same_result};
+use crate::create_prng;
+use crate::f64::beta::{beta_inc_inv_ref, beta_inc_ref, ln_beta_ref};
+
+#[test]
+fn test_beta() {
+    test_with(|a, b| {
+        let (ln_expected, expected_sign) = ln_beta_ref(a.to_f64(), b.to_f64());
+        let mut expected = ln_expected.exp();
+        if expected_sign < 0 {
+            expected = -expected;
+        }
+        let expected = from_rug_prec(&expected);
+        let actual = fpmath::beta(a, b);
+        assert!(
+            same_result(actual, expected),
+            "beta({a:e}, {b:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+#[test]
+fn test_ln_beta() {
+    test_with(|a, b| {
+        let (expected, expected_sign) = ln_beta_ref(a.to_f64(), b.to_f64());
+        let expected = from_rug_prec(&expected);
+        let (actual, actual_sign) = fpmath::ln_beta(a, b);
+        assert!(
+            same_result(actual, expected) && actual_sign == expected_sign,
+            "ln_beta({a:e}, {b:e}) = ({actual:e}, {actual_sign}) (expected ({expected:e}, {expected_sign}))",
+        );
+    });
+}
+
+#[test]
+fn test_beta_inc() {
+    test_with_inc(|a, b, x| {
+        let (expected, _) = beta_inc_ref(a.to_f64(), b.to_f64(), x.to_f64());
+        let expected = from_rug_prec_ties(&expected);
+        let actual = fpmath::beta_inc(a, b, x);
+        assert!(
+            same_result(actual, expected),
+            "beta_inc({a:e}, {b:e}, {x:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+#[test]
+fn test_beta_inc_inv() {
+    test_with_inc(|a, b, x| {
+        let p = fpmath::beta_inc(a, b, x);
+        if p == bf16::ZERO || p == bf16::ONE {
+            return;
+        }
+
+        let actual = fpmath::beta_inc_inv(a, b, p);
+        let expected = from_rug_prec(&beta_inc_inv_ref(
+            a.to_f64(),
+            b.to_f64(),
+            p.to_f64(),
+            actual.to_f64(),
+        ));
+        assert!(
+            same_result(actual, expected),
+            "beta_inc_inv({a:e}, {b:e}, {p:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+/// Like [`from_rug_prec`], but treats `x` as a halfway point between two
+/// `bf16` values when it is extremely close to one
+///
+/// `I_x(a, b)` can be exactly halfway (e.g., `I_0.25(1.5, 3) = 407 / 1024`),
+/// which the reference cannot represent exactly.
+fn from_rug_prec_ties(x: &rug::Float) -> bf16 {
+    let mid = rug::Float::with_val(super::PREC + 1, x);
+    if mid.is_normal() {
+        let dif = rug::Float::with_val(x.prec(), x - &mid);
+        if dif.is_zero() || dif.get_exp().unwrap() < mid.get_exp().unwrap() - 110 {
+            return from_rug_prec(&mid);
+        }
+    }
+    from_rug_prec(x)
+}
+
+fn test_with(mut f: impl FnMut(bf16, bf16)) {
+    let mut rng = create_prng();
+
+    for ea in -40..=40 {
+        for eb in (-40..=80).step_by(2) {
+            for _ in 0..5 {
+                let a = mkfloat(rng.random::<u16>(), ea, false);
+                let b = mkfloat(rng.random::<u16>(), eb, false);
+                f(a, b);
+            }
+        }
+    }
+
+    for i in 1..=100 {
+        for j in 1..=100 {
+            let a = bf16::from_f32((i as f32) / 10.0);
+            let b = bf16::from_f32((j as f32) / 10.0);
+            f(a, b);
+        }
+    }
+
+    // Negative arguments, away from the poles of the gamma function
+    // of `a`, `b` and `a + b`
+    for _ in 0..10000 {
+        let a = -bf16::from_f32(rng.random_range(0.0..20.0f32));
+        let b = bf16::from_f32(rng.random_range(-20.0..200.0f32));
+        let away_from_int = |x: f64| (x - x.round()).abs() > 0.1;
+        let (a64, b64) = (a.to_f64(), b.to_f64());
+        if away_from_int(a64) && away_from_int(b64) && away_from_int(a64 + b64) {
+            f(a, b);
+        }
+    }
+}
+
+fn test_with_inc(mut f: impl FnMut(bf16, bf16, bf16)) {
+    let mut rng = create_prng();
+
+    for ea in (-20..=20).step_by(2) {
+        for eb in (-20..=20).step_by(2) {
+            for ex in (-40..=-1).step_by(3) {
+                for _ in 0..2 {
+                    let a = mkfloat(rng.random::<u16>(), ea, false);
+                    let b = mkfloat(rng.random::<u16>(), eb, false);
+                    let x = mkfloat(rng.random::<u16>(), ex, false);
+                    f(a, b, x);
+                    // Close to one
+                    f(a, b, bf16::ONE - x);
+                }
+            }
+        }
+    }
+
+    // Tiny `x`, where the `f64` result can be halfway between two `bf16`
+    // values while the exact result is not
+    for ex in -90..=-53 {
+        for a in 1..=3 {
+            for b in 1..=3 {
+                for _ in 0..5 {
+                    let x = mkfloat(rng.random::<u16>(), ex, false);
+                    f(bf16::from_f32(a as f32), bf16::from_f32(b as f32), x);
+                }
+            }
+        }
+    }
+
+    for i in 1..=20 {
+        for j in 1..=20 {
+            for k in 1..20 {
+                let a = bf16::from_f32((i as f32) / 2.0);
+                let b = bf16::from_f32((j as f32) / 2.0);
+                let x = bf16::from_f32((k as f32) / 20.0);
+                f(a, b, x);
+            }
+        }
+    }
+
+    // Close to the mean, where `I` and `1 - I` are not small
+    for _ in 0..2000 {
+        let a = bf16::from_f32(rng.random_range(1.0..10000.0f32));
+        let b = bf16::from_f32(rng.random_range(1.0..10000.0f32));
+        let x = a.to_f32() / (a.to_f32() + b.to_f32()) * rng.random_range(0.9..1.1f32);
+        if x < 1.0 {
+            f(a, b, bf16::from_f32(x));
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use rug::float::Round;
+
+use super::{check, test_all};
+
+#[test]
+fn test_cbrt() {
+    test_all(|x| {
+        check("cbrt", x, fpmath::cbrt(x), |y| y.cbrt_round(Round::Nearest));
+    });
+}
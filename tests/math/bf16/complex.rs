@@ -0,0 +1,138 @@
+use fpmath::complex::Complex;
+use half::bf16;
+use rand::RngExt as _;
+
+use super::{HI_RUG_PREC, calc_error_hi_lo_ulp, mkfloat};
+use crate::create_prng;
+
+fn to_f64(z: Complex<bf16>) -> Complex<f64> {
+    Complex::new(z.re.to_f64(), z.im.to_f64())
+}
+
+/// Calculates the error of each part in ULP of that part and returns the
+/// largest one.
+fn calc_error(actual: Complex<bf16>, expected: Complex<f64>) -> f64 {
+    let calc = |actual: bf16, expected: f64| {
+        calc_error_hi_lo_ulp(
+            (actual, bf16::ZERO),
+            &rug::Float::with_val(HI_RUG_PREC, expected),
+        )
+    };
+    calc(actual.re, expected.re).max(calc(actual.im, expected.im))
+}
+
+fn test_unary(
+    name: &str,
+    f: impl Fn(Complex<bf16>) -> Complex<bf16>,
+    f64_f: impl Fn(Complex<f64>) -> Complex<f64>,
+) {
+    let mut max_error: f64 = 0.0;
+    test_with(|z| {
+        let expected = f64_f(to_f64(z));
+        let actual = f(z);
+
+        let err = calc_error(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.51,
+            "{name}({:e} + {:e}i) = {:e} + {:e}i (error = {err} ULP)",
+            z.re,
+            z.im,
+            actual.re,
+            actual.im,
+        );
+    });
+    eprintln!("max complex {name} error = {max_error}");
+}
+
+#[test]
+fn test_exp() {
+    test_unary("exp", Complex::exp, Complex::exp);
+}
+
+#[test]
+fn test_ln() {
+    test_unary("ln", Complex::ln, Complex::ln);
+}
+
+#[test]
+fn test_sqrt() {
+    test_unary("sqrt", Complex::sqrt, Complex::sqrt);
+}
+
+#[test]
+fn test_trigonometric() {
+    test_unary("sin", Complex::sin, Complex::sin);
+    test_unary("cos", Complex::cos, Complex::cos);
+    test_unary("tan", Complex::tan, Complex::tan);
+}
+
+#[test]
+fn test_hyperbolic() {
+    test_unary("sinh", Complex::sinh, Complex::sinh);
+    test_unary("cosh", Complex::cosh, Complex::cosh);
+    test_unary("tanh", Complex::tanh, Complex::tanh);
+}
+
+#[test]
+fn test_inv_trigonometric() {
+    test_unary("asin", Complex::asin, Complex::asin);
+    test_unary("acos", Complex::acos, Complex::acos);
+    test_unary("atan", Complex::atan, Complex::atan);
+}
+
+#[test]
+fn test_inv_hyperbolic() {
+    test_unary("asinh", Complex::asinh, Complex::asinh);
+    test_unary("acosh", Complex::acosh, Complex::acosh);
+    test_unary("atanh", Complex::atanh, Complex::atanh);
+}
+
+#[test]
+fn test_mul_div_pow() {
+    let mut rng = create_prng();
+    for _ in 0..100000 {
+        let [ea, eb, ec, ed] = [(); 4].map(|()| rng.random_range(-126..=127));
+        let z = Complex::new(rand_float(&mut rng, ea), rand_float(&mut rng, eb));
+        let w = Complex::new(rand_float(&mut rng, ec), rand_float(&mut rng, ed));
+        // the error of pow grows with the magnitude of w
+        let [ec, ed] = [(); 2].map(|()| rng.random_range(-126..=4));
+        let wp = Complex::new(rand_float(&mut rng, ec), rand_float(&mut rng, ed));
+        for (name, w, actual, expected) in [
+            ("mul", w, z * w, to_f64(z) * to_f64(w)),
+            ("div", w, z / w, to_f64(z) / to_f64(w)),
+            ("pow", wp, z.pow(wp), to_f64(z).pow(to_f64(wp))),
+        ] {
+            let err = calc_error(actual, expected);
+            assert!(
+                err < 0.51,
+                "{name}({:e} + {:e}i, {:e} + {:e}i) = {:e} + {:e}i (error = {err} ULP)",
+                z.re,
+                z.im,
+                w.re,
+                w.im,
+                actual.re,
+                actual.im,
+            );
+        }
+    }
+}
+
+fn test_with(mut f: impl FnMut(Complex<bf16>)) {
+    let mut rng = create_prng();
+
+    for ex in -126..=127 {
+        for ey in -126..=127 {
+            for _ in 0..2 {
+                let x = rand_float(&mut rng, ex);
+                let y = rand_float(&mut rng, ey);
+                f(Complex::new(x, y));
+            }
+        }
+    }
+}
+
+fn rand_float(rng: &mut impl rand::Rng, e: i16) -> bf16 {
+    mkfloat(rng.random::<u16>(), e, rng.random::<bool>())
+}
@@ -0,0 +1,157 @@
+use half::bf16;
+use rug::float::Round;
+use rug::ops::PowAssignRound as _;
+
+use super::{check, eval_rug, same_result, test_all, to_rug};
+
+#[test]
+fn test_exp() {
+    test_all(|x| {
+        check("exp", x, fpmath::cr::exp(x), |y| {
+            y.exp_round(Round::Nearest)
+        });
+        check("exp_m1", x, fpmath::cr::exp_m1(x), |y| {
+            y.exp_m1_round(Round::Nearest)
+        });
+        check("exp2", x, fpmath::cr::exp2(x), |y| {
+            y.exp2_round(Round::Nearest)
+        });
+        check("exp10", x, fpmath::cr::exp10(x), |y| {
+            y.exp10_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_log() {
+    test_all(|x| {
+        check("ln", x, fpmath::cr::ln(x), |y| y.ln_round(Round::Nearest));
+        check("ln_1p", x, fpmath::cr::ln_1p(x), |y| {
+            y.ln_1p_round(Round::Nearest)
+        });
+        check("log2", x, fpmath::cr::log2(x), |y| {
+            y.log2_round(Round::Nearest)
+        });
+        check("log10", x, fpmath::cr::log10(x), |y| {
+            y.log10_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_trigonometric() {
+    test_all(|x| {
+        check("sin", x, fpmath::cr::sin(x), |y| {
+            y.sin_round(Round::Nearest)
+        });
+        check("cos", x, fpmath::cr::cos(x), |y| {
+            y.cos_round(Round::Nearest)
+        });
+        check("tan", x, fpmath::cr::tan(x), |y| {
+            y.tan_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_inverse_trigonometric() {
+    test_all(|x| {
+        check("asin", x, fpmath::cr::asin(x), |y| {
+            y.asin_round(Round::Nearest)
+        });
+        check("acos", x, fpmath::cr::acos(x), |y| {
+            y.acos_round(Round::Nearest)
+        });
+        check("atan", x, fpmath::cr::atan(x), |y| {
+            y.atan_round(Round::Nearest)
+        });
+    });
+
+    super::inv_trigonometric::test_with_atan2(|y, x| {
+        let actual = fpmath::cr::atan2(y, x);
+        let rx = to_rug(super::PREC, x);
+        let expected = eval_rug(y, |ry| ry.atan2_round(&rx, Round::Nearest));
+        assert!(
+            same_result(actual, expected),
+            "atan2({y:e}, {x:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+#[test]
+fn test_hyperbolic() {
+    test_all(|x| {
+        check("sinh", x, fpmath::cr::sinh(x), |y| {
+            y.sinh_round(Round::Nearest)
+        });
+        check("cosh", x, fpmath::cr::cosh(x), |y| {
+            y.cosh_round(Round::Nearest)
+        });
+        check("tanh", x, fpmath::cr::tanh(x), |y| {
+            y.tanh_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_cbrt_hypot() {
+    test_all(|x| {
+        check("cbrt", x, fpmath::cr::cbrt(x), |y| {
+            y.cbrt_round(Round::Nearest)
+        });
+    });
+
+    super::hypot::test_with(|x, y| {
+        let actual = fpmath::cr::hypot(x, y);
+        let ry = to_rug(super::PREC, y);
+        let expected = eval_rug(x, |rx| rx.hypot_round(&ry, Round::Nearest));
+        assert!(
+            same_result(actual, expected),
+            "hypot({x:e}, {y:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+#[test]
+fn test_hard_cases() {
+    // exactly halfway between two `bf16` values
+    let f = bf16::from_f32;
+    assert_eq!(
+        fpmath::cr::pow(f(17.0), f(2.0)).to_bits(),
+        f(288.0).to_bits()
+    );
+    assert_eq!(
+        fpmath::cr::pow(f(19.0), f(2.0)).to_bits(),
+        f(360.0).to_bits()
+    );
+    assert_eq!(
+        fpmath::cr::pow(f(2.0), f(-134.0)).to_bits(),
+        f(0.0).to_bits()
+    );
+    assert_eq!(
+        fpmath::cr::hypot(f(25.0), f(312.0)).to_bits(),
+        f(312.0).to_bits()
+    );
+
+    // `y / x` is exactly halfway between two subnormals, and `atan2(y, x)`
+    // is slightly closer to zero
+    let x = f(2.0f32.powi(100));
+    let y = f(3.0 * 2.0f32.powi(-34));
+    assert_eq!(fpmath::cr::atan2(y, x).to_bits(), 1);
+    assert_eq!(fpmath::cr::atan2(-y, x).to_bits(), 0x8001);
+    let y = f(7.0 * 2.0f32.powi(-34));
+    assert_eq!(fpmath::cr::atan2(y, x).to_bits(), 3);
+}
+
+#[test]
+fn test_pow() {
+    super::pow::test_pow_with(|x, y| {
+        let actual = fpmath::cr::pow(x, y);
+        let ry = to_rug(super::PREC, y);
+        let expected = eval_rug(x, |rx| rx.pow_assign_round(&ry, Round::Nearest));
+        assert!(
+            same_result(actual, expected),
+            "pow({x:e}, {y:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
@@ -0,0 +1,17 @@
+use rug::float::Round;
+
+use super::{check, test_all};
+
+#[test]
+fn test_erf() {
+    test_all(|x| {
+        check("erf", x, fpmath::erf(x), |y| y.erf_round(Round::Nearest));
+    });
+}
+
+#[test]
+fn test_erfc() {
+    test_all(|x| {
+        check("erfc", x, fpmath::erfc(x), |y| y.erfc_round(Round::Nearest));
+    });
+}
@@ -0,0 +1,50 @@
+use half::bf16;
+
+use super::{from_rug_prec, same_result, test_all};
+use crate::f64::erfinv::{erfcinv_ref, erfinv_ref};
+
+#[test]
+fn test_erfinv() {
+    test_all(|x| {
+        if fpmath::abs(x) >= bf16::ONE {
+            return;
+        }
+
+        let actual = fpmath::erfinv(x);
+        let expected = from_rug_prec(&erfinv_ref(x.to_f64(), actual.to_f64()));
+        assert!(
+            same_result(actual, expected),
+            "erfinv({x:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+#[test]
+fn test_erfcinv() {
+    test_all(|x| {
+        if x <= bf16::ZERO || x >= bf16::from_f32(2.0) {
+            return;
+        }
+
+        let actual = fpmath::erfcinv(x);
+        let expected = from_rug_prec(&erfcinv_ref(
+            rug::Float::with_val(super::PREC, x.to_f32()),
+            actual.to_f64(),
+        ));
+        assert!(
+            same_result(actual, expected),
+            "erfcinv({x:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+#[test]
+fn test_special() {
+    let two = bf16::from_f32(2.0);
+    assert_eq!(fpmath::erfinv(bf16::ONE), bf16::INFINITY);
+    assert_eq!(fpmath::erfinv(bf16::NEG_ONE), bf16::NEG_INFINITY);
+    assert!(fpmath::erfinv(two).is_nan());
+    assert_eq!(fpmath::erfcinv(bf16::ZERO), bf16::INFINITY);
+    assert_eq!(fpmath::erfcinv(two), bf16::NEG_INFINITY);
+    assert!(fpmath::erfcinv(bf16::NEG_ONE).is_nan());
+}
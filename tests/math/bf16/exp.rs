@@ -0,0 +1,73 @@
+use half::bf16;
+use rug::float::Round;
+
+use super::{HI_RUG_PREC, calc_error_hi_lo_ulp, check, test_all, to_rug};
+
+#[test]
+fn test_exp() {
+    test_all(|x| {
+        check("exp", x, fpmath::exp(x), |y| y.exp_round(Round::Nearest));
+        check("exp_m1", x, fpmath::exp_m1(x), |y| {
+            y.exp_m1_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_exp_hi_lo() {
+    let mut max_error: f64 = 0.0;
+    test_all(|x| {
+        let expected = to_rug(HI_RUG_PREC, x).exp();
+        let actual = fpmath::exp_hi_lo(x);
+
+        let err = calc_error_hi_lo_ulp(actual, &expected);
+
+        // `lo` loses precision close to the subnormal range
+        let threshold = if actual.0 < bf16::MIN_POSITIVE / bf16::EPSILON {
+            1.0
+        } else {
+            max_error = max_error.max(err);
+            0.01
+        };
+        assert!(
+            err < threshold,
+            "exp_hi_lo({x:e}) = {actual:?} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max exp_hi_lo error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_exp2() {
+    test_all(|x| {
+        check("exp2", x, fpmath::exp2(x), |y| y.exp2_round(Round::Nearest));
+    });
+}
+
+#[test]
+fn test_exp10() {
+    test_all(|x| {
+        check("exp10", x, fpmath::exp10(x), |y| {
+            y.exp10_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_exp2_m1() {
+    test_all(|x| {
+        check("exp2_m1", x, fpmath::exp2_m1(x), |y| {
+            y.exp2_m1_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_exp10_m1() {
+    test_all(|x| {
+        check("exp10_m1", x, fpmath::exp10_m1(x), |y| {
+            y.exp10_m1_round(Round::Nearest)
+        });
+    });
+}
@@ -0,0 +1,72 @@
+use half::bf16;
+use rand::RngExt as _;
+use rug::float::Round;
+
+use super::{PREC, eval_rug, mkfloat, same_result, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_fma() {
+    let mut rng = create_prng();
+
+    for _ in 0..200_000 {
+        let ex = rng.random_range(-127..=127);
+        let ey = rng.random_range(-127..=127);
+        let ez = (ex + ey + rng.random_range(-30..=30)).clamp(-127, 127);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        let z = mkfloat(rng.random(), ez, rng.random());
+        check(x, y, z);
+
+        // cancellation of the highest bits
+        let z = -(x * y);
+        if z.is_finite() {
+            check(x, y, z);
+            check(x, y, bf16::from_bits(z.to_bits() + 1));
+        }
+    }
+
+    // results close to or in the subnormal range
+    for _ in 0..100_000 {
+        let ex = rng.random_range(-127..=-20);
+        let ey = (-150 - ex + rng.random_range(-20..=20)).clamp(-127, 127);
+        let ez = rng.random_range(-127..=-110);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        let z = mkfloat(rng.random(), ez, rng.random());
+        check(x, y, z);
+        check(x, y, bf16::ZERO);
+        check(x, y, bf16::NEG_ZERO);
+    }
+
+    let special = [
+        bf16::ZERO,
+        bf16::NEG_ZERO,
+        bf16::ONE,
+        bf16::NEG_ONE,
+        bf16::MIN_POSITIVE_SUBNORMAL,
+        bf16::MIN_POSITIVE,
+        bf16::MAX,
+        bf16::INFINITY,
+        bf16::NEG_INFINITY,
+        bf16::NAN,
+    ];
+    for x in special {
+        for y in special {
+            for z in special {
+                check(x, y, z);
+            }
+        }
+    }
+}
+
+fn check(x: bf16, y: bf16, z: bf16) {
+    let (ry, rz) = (to_rug(PREC, y), to_rug(PREC, z));
+    let expected = eval_rug(x, |rx| rx.mul_add_round(&ry, &rz, Round::Nearest));
+
+    let actual = fpmath::fma(x, y, z);
+    assert!(
+        same_result(actual, expected),
+        "fma({x:e}, {y:e}, {z:e}) = {actual:e}, expected {expected:e}",
+    );
+}
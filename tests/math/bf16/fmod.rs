@@ -0,0 +1,86 @@
+use half::bf16;
+use rand::RngExt as _;
+
+use super::{PREC, mkfloat, same_result, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_fmod() {
+    test_with(|x, y| {
+        let expected = bf16::from_f32((to_rug(PREC, x) % to_rug(PREC, y)).to_f32());
+        let actual = fpmath::fmod(x, y);
+        assert!(
+            same_result(actual, expected),
+            "fmod({x:e}, {y:e}) = {actual:e}, expected {expected:e}",
+        );
+    });
+}
+
+#[test]
+fn test_remainder() {
+    test_with(|x, y| {
+        let (expected, expected_quo) = to_rug(PREC, x).remainder_quo31(&to_rug(PREC, y));
+        let expected = bf16::from_f32(expected.to_f32());
+
+        let actual = fpmath::remainder(x, y);
+        assert!(
+            same_result(actual, expected),
+            "remainder({x:e}, {y:e}) = {actual:e}, expected {expected:e}",
+        );
+
+        let (actual, actual_quo) = fpmath::remquo(x, y);
+        assert!(
+            same_result(actual, expected) && (actual_quo == expected_quo || expected.is_nan()),
+            "remquo({x:e}, {y:e}) = ({actual:e}, {actual_quo}), expected ({expected:e}, {expected_quo})",
+        );
+    });
+}
+
+fn test_with(mut f: impl FnMut(bf16, bf16)) {
+    let mut rng = create_prng();
+
+    for _ in 0..20000 {
+        let ex = rng.random_range(-127..=127);
+        let ey = rng.random_range(-127..=127);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        f(x, y);
+    }
+
+    // close exponents
+    for _ in 0..20000 {
+        let ey = rng.random_range(-127..=85);
+        let ex = ey + rng.random_range(-2..=40);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        f(x, y);
+    }
+
+    // halfway cases
+    for _ in 0..20000 {
+        let ey = rng.random_range(-120..=120);
+        let n = rng.random_range(0..64);
+        let y = mkfloat(0, ey, rng.random());
+        let x = bf16::from_f32(n as f32 + 0.5) * y;
+        f(x, y);
+        f(-x, y);
+    }
+
+    let special = [
+        bf16::ZERO,
+        bf16::NEG_ZERO,
+        bf16::ONE,
+        bf16::NEG_ONE,
+        bf16::MIN_POSITIVE_SUBNORMAL,
+        bf16::MIN_POSITIVE,
+        bf16::MAX,
+        bf16::INFINITY,
+        bf16::NEG_INFINITY,
+        bf16::NAN,
+    ];
+    for x in special {
+        for y in special {
+            f(x, y);
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use half::bf16;
+use rand::RngExt as _;
+use rug::float::Round;
+
+use super::{check, from_rug, from_rug_prec, mkfloat, same_result, test_all, to_rug};
+use crate::create_prng;
+use crate::f64::gamma::{inc_gamma_inv_ref, inc_gamma_ref};
+
+#[test]
+fn test_gamma() {
+    test_all(|x| {
+        check("gamma", x, fpmath::gamma(x), |y| {
+            y.gamma_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_ln_gamma() {
+    test_all(|x| {
+        let (actual, actual_sign) = fpmath::ln_gamma(x);
+        if x == bf16::NEG_INFINITY {
+            assert!(actual.is_nan() && actual_sign == 0);
+            return;
+        }
+
+        let mut y = to_rug(super::PREC, x);
+        let (ord, dir) = y.ln_abs_gamma_round(Round::Nearest);
+        let expected = from_rug(y, dir);
+        let expected_sign = if x < bf16::ZERO && x.to_f32().fract() == 0.0 {
+            0
+        } else {
+            ord as i8
+        };
+
+        assert!(
+            same_result(actual, expected) && actual_sign == expected_sign,
+            "ln_gamma({x:e}) = ({actual:e}, {actual_sign}) (expected ({expected:e}, {expected_sign}))",
+        );
+    });
+}
+
+#[test]
+fn test_gamma_p() {
+    test_with_inc(|a, x| {
+        let actual = fpmath::gamma_p(a, x);
+        let expected = from_rug_prec(&inc_gamma_ref(a.to_f64(), x.to_f64()).0);
+        assert!(
+            same_result(actual, expected),
+            "gamma_p({a:e}, {x:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+#[test]
+fn test_gamma_q() {
+    test_with_inc(|a, x| {
+        let actual = fpmath::gamma_q(a, x);
+        let expected = from_rug_prec(&inc_gamma_ref(a.to_f64(), x.to_f64()).1);
+        assert!(
+            same_result(actual, expected),
+            "gamma_q({a:e}, {x:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+#[test]
+fn test_gamma_p_inv() {
+    test_with_inc(|a, x| {
+        let p = fpmath::gamma_p(a, x);
+        if p == bf16::ZERO || p == bf16::ONE {
+            return;
+        }
+
+        let actual = fpmath::gamma_p_inv(a, p);
+        let expected = from_rug_prec(&inc_gamma_inv_ref(
+            a.to_f64(),
+            p.to_f64(),
+            actual.to_f64(),
+            false,
+        ));
+        assert!(
+            same_result(actual, expected),
+            "gamma_p_inv({a:e}, {p:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+#[test]
+fn test_gamma_q_inv() {
+    test_with_inc(|a, x| {
+        let q = fpmath::gamma_q(a, x);
+        if q == bf16::ZERO || q == bf16::ONE {
+            return;
+        }
+
+        let actual = fpmath::gamma_q_inv(a, q);
+        let expected = from_rug_prec(&inc_gamma_inv_ref(
+            a.to_f64(),
+            q.to_f64(),
+            actual.to_f64(),
+            true,
+        ));
+        assert!(
+            same_result(actual, expected),
+            "gamma_q_inv({a:e}, {q:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+fn test_with_inc(mut f: impl FnMut(bf16, bf16)) {
+    let mut rng = create_prng();
+
+    for ea in -30..=16 {
+        for ex in -40..=20 {
+            for _ in 0..5 {
+                let a = mkfloat(rng.random::<u16>(), ea, false);
+                let x = mkfloat(rng.random::<u16>(), ex, false);
+                f(a, x);
+            }
+        }
+    }
+
+    for i in 1..=100 {
+        for j in 1..=100 {
+            let a = bf16::from_f32((i as f32) / 10.0);
+            let x = bf16::from_f32((j as f32) / 5.0);
+            f(a, x);
+        }
+    }
+
+    // Close to the mean, where `P` and `Q` are not small
+    for _ in 0..2000 {
+        let a = bf16::from_f32(rng.random_range(1.0..10000.0f32));
+        let x = bf16::from_f32(a.to_f32() * rng.random_range(0.5..1.5f32));
+        f(a, x);
+    }
+}
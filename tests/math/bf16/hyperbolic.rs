@@ -0,0 +1,25 @@
+use rug::float::Round;
+
+use super::{check, test_all};
+
+#[test]
+fn test_sinh_cosh() {
+    test_all(|x| {
+        let (actual_sinh, actual_cosh) = fpmath::sinh_cosh(x);
+        check("sinh", x, fpmath::sinh(x), |y| y.sinh_round(Round::Nearest));
+        check("sinh_cosh.0", x, actual_sinh, |y| {
+            y.sinh_round(Round::Nearest)
+        });
+        check("cosh", x, fpmath::cosh(x), |y| y.cosh_round(Round::Nearest));
+        check("sinh_cosh.1", x, actual_cosh, |y| {
+            y.cosh_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_tanh() {
+    test_all(|x| {
+        check("tanh", x, fpmath::tanh(x), |y| y.tanh_round(Round::Nearest));
+    });
+}
@@ -0,0 +1,50 @@
+use half::bf16;
+use rand::RngExt as _;
+use rug::float::Round;
+
+use super::{eval_rug, mkfloat, same_result, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_hypot() {
+    test_with(|x, y| {
+        let actual = fpmath::hypot(x, y);
+        let ry = to_rug(super::PREC, y);
+        let expected = eval_rug(x, |rx| rx.hypot_round(&ry, Round::Nearest));
+        assert!(
+            same_result(actual, expected),
+            "hypot({x:e}, {y:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+pub(super) fn test_with(mut f: impl FnMut(bf16, bf16)) {
+    let mut rng = create_prng();
+
+    for ex in -126..=127 {
+        for ey in -126..=127 {
+            for _ in 0..10 {
+                let mx = rng.random::<u16>();
+                let sx = rng.random::<bool>();
+                let my = rng.random::<u16>();
+                let sy = rng.random::<bool>();
+                f(mkfloat(mx, ex, sx), mkfloat(my, ey, sy));
+            }
+        }
+    }
+
+    // subnormals
+    for _ in 0..10000 {
+        let x = bf16::from_bits(rng.random_range(0..0x0080));
+        let y = bf16::from_bits(rng.random_range(0..0x0080));
+        f(x, y);
+        f(x, mkfloat(rng.random::<u16>(), -126, false));
+    }
+
+    // exact results, including halfway points
+    for x in 1..=255u16 {
+        for y in 1..=255u16 {
+            f(bf16::from_f32(f32::from(x)), bf16::from_f32(f32::from(y)));
+        }
+    }
+}
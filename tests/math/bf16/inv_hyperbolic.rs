@@ -0,0 +1,30 @@
+use rug::float::Round;
+
+use super::{check, test_all};
+
+#[test]
+fn test_asinh() {
+    test_all(|x| {
+        check("asinh", x, fpmath::asinh(x), |y| {
+            y.asinh_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_acosh() {
+    test_all(|x| {
+        check("acosh", x, fpmath::acosh(x), |y| {
+            y.acosh_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_atanh() {
+    test_all(|x| {
+        check("atanh", x, fpmath::atanh(x), |y| {
+            y.atanh_round(Round::Nearest)
+        });
+    });
+}
@@ -0,0 +1,125 @@
+use half::bf16;
+use rand::RngExt as _;
+use rug::float::Round;
+
+use super::{check, eval_rug, mkfloat, same_result, test_all, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_asin_acos() {
+    test_all(|x| {
+        check("asin", x, fpmath::asin(x), |y| y.asin_round(Round::Nearest));
+        check("acos", x, fpmath::acos(x), |y| y.acos_round(Round::Nearest));
+    });
+}
+
+#[test]
+fn test_asind_acosd() {
+    test_all(|x| {
+        check("asind", x, fpmath::asind(x), |y| {
+            y.asin_u_round(360, Round::Nearest)
+        });
+        check("acosd", x, fpmath::acosd(x), |y| {
+            y.acos_u_round(360, Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_asinpi_acospi() {
+    test_all(|x| {
+        check("asinpi", x, fpmath::asinpi(x), |y| {
+            y.asin_pi_round(Round::Nearest)
+        });
+        check("acospi", x, fpmath::acospi(x), |y| {
+            y.acos_pi_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_atan() {
+    test_all(|x| {
+        check("atan", x, fpmath::atan(x), |y| y.atan_round(Round::Nearest));
+        check("atand", x, fpmath::atand(x), |y| {
+            y.atan_u_round(360, Round::Nearest)
+        });
+        check("atanpi", x, fpmath::atanpi(x), |y| {
+            y.atan_pi_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_atan2() {
+    test_with_atan2(|y, x| {
+        let rx = to_rug(super::PREC, x);
+        check_atan2("atan2", fpmath::atan2, y, x, |ry| {
+            ry.atan2_round(&rx, Round::Nearest)
+        });
+        check_atan2("atan2d", fpmath::atan2d, y, x, |ry| {
+            ry.atan2_u_round(&rx, 360, Round::Nearest)
+        });
+        check_atan2("atan2pi", fpmath::atan2pi, y, x, |ry| {
+            ry.atan2_pi_round(&rx, Round::Nearest)
+        });
+    });
+}
+
+fn check_atan2(
+    name: &str,
+    f: impl Fn(bf16, bf16) -> bf16,
+    y: bf16,
+    x: bf16,
+    expected_f: impl FnOnce(&mut rug::Float) -> std::cmp::Ordering,
+) {
+    let actual = f(y, x);
+    let expected = eval_rug(y, expected_f);
+    assert!(
+        same_result(actual, expected),
+        "{name}({y:e}, {x:e}) = {actual:e} (expected {expected:e})",
+    );
+}
+
+pub(super) fn test_with_atan2(mut f: impl FnMut(bf16, bf16)) {
+    let mut rng = create_prng();
+
+    for ey in -126..=127 {
+        for ex in -126..=127 {
+            for _ in 0..10 {
+                let my = rng.random::<u16>();
+                let sy = rng.random::<bool>();
+                let mx = rng.random::<u16>();
+                let sx = rng.random::<bool>();
+                f(mkfloat(my, ey, sy), mkfloat(mx, ex, sx));
+            }
+        }
+    }
+
+    // subnormals, zeros and infinities
+    let specials = [
+        bf16::ZERO,
+        bf16::NEG_ZERO,
+        bf16::INFINITY,
+        bf16::NEG_INFINITY,
+        bf16::NAN,
+    ];
+    for _ in 0..10000 {
+        let x = bf16::from_bits(rng.random_range(0..0x0080));
+        let y = mkfloat(rng.random::<u16>(), rng.random_range(-126..=127), false);
+        f(x, y);
+        f(y, x);
+    }
+    for &x in specials.iter() {
+        for &y in specials.iter() {
+            f(x, y);
+        }
+        for _ in 0..100 {
+            let y = mkfloat(rng.random::<u16>(), rng.random_range(-126..=127), false);
+            f(x, y);
+            f(y, x);
+            f(x, -y);
+            f(-y, x);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use half::bf16;
+
+use super::{from_rug_prec, same_result, test_all};
+use crate::f64::lambert_w::lambert_w_ref;
+
+#[test]
+fn test_lambert_w0() {
+    test_all(|x| {
+        let actual = fpmath::lambert_w0(x);
+        let expected = calc_expected(x, fpmath::lambert_w0(x.to_f64()));
+        assert!(
+            same_result(actual, expected),
+            "lambert_w0({x:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+#[test]
+fn test_lambert_wm1() {
+    test_all(|x| {
+        let actual = fpmath::lambert_wm1(x);
+        let expected = calc_expected(x, fpmath::lambert_wm1(x.to_f64()));
+        assert!(
+            same_result(actual, expected),
+            "lambert_wm1({x:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+/// Refines `w`, the result for `f64`, to get `W(x)` correctly rounded to
+/// `bf16`
+fn calc_expected(x: bf16, w: f64) -> bf16 {
+    if w.is_finite() && w != 0.0 {
+        from_rug_prec(&lambert_w_ref(x.to_f64(), w))
+    } else {
+        // NaN, infinities and zeros are exact
+        bf16::from_f64(w)
+    }
+}
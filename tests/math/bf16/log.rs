@@ -0,0 +1,98 @@
+use half::bf16;
+use rug::float::Round;
+
+use super::{HI_RUG_PREC, calc_error_hi_lo_ulp, check, test_all, to_rug};
+
+#[test]
+fn test_ln() {
+    test_all(|x| {
+        check("ln", x, fpmath::ln(x), |y| y.ln_round(Round::Nearest));
+        check("ln_1p", x, fpmath::ln_1p(x), |y| {
+            y.ln_1p_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_ln_hi_lo() {
+    let mut max_error: f64 = 0.0;
+    test_all(|x| {
+        let expected = to_rug(HI_RUG_PREC, x).ln();
+        let actual = fpmath::ln_hi_lo(x);
+
+        let err = calc_error_hi_lo_ulp(actual, &expected);
+
+        // `lo` loses precision close to the subnormal range
+        let threshold = if fpmath::abs(actual.0) < bf16::MIN_POSITIVE / bf16::EPSILON {
+            1.0
+        } else {
+            max_error = max_error.max(err);
+            0.01
+        };
+        assert!(
+            err < threshold,
+            "ln_hi_lo({x:e}) = {actual:?} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max ln_hi_lo error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_ln_1p_hi_lo() {
+    let mut max_error: f64 = 0.0;
+    test_all(|x| {
+        let expected = to_rug(HI_RUG_PREC, x).ln_1p();
+        let actual = fpmath::ln_1p_hi_lo(x);
+
+        let err = calc_error_hi_lo_ulp(actual, &expected);
+
+        // `lo` loses precision close to the subnormal range
+        let threshold = if fpmath::abs(actual.0) < bf16::MIN_POSITIVE / bf16::EPSILON {
+            1.0
+        } else {
+            max_error = max_error.max(err);
+            0.01
+        };
+        assert!(
+            err < threshold,
+            "ln_1p_hi_lo({x:e}) = {actual:?} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max ln_1p_hi_lo error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_log2() {
+    test_all(|x| {
+        check("log2", x, fpmath::log2(x), |y| y.log2_round(Round::Nearest));
+    });
+}
+
+#[test]
+fn test_log10() {
+    test_all(|x| {
+        check("log10", x, fpmath::log10(x), |y| {
+            y.log10_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_log2_1p() {
+    test_all(|x| {
+        check("log2_1p", x, fpmath::log2_1p(x), |y| {
+            y.log2_1p_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_log10_1p() {
+    test_all(|x| {
+        check("log10_1p", x, fpmath::log10_1p(x), |y| {
+            y.log10_1p_round(Round::Nearest)
+        });
+    });
+}
@@ -0,0 +1,138 @@
+use std::cmp::Ordering;
+
+use half::bf16;
+use rug::float::Round;
+
+mod bessel;
+mod beta;
+mod cbrt;
+mod complex;
+mod cr;
+mod erf;
+mod erfinv;
+mod exp;
+mod fma;
+mod fmod;
+mod gamma;
+mod hyperbolic;
+mod hypot;
+mod inv_hyperbolic;
+mod inv_trigonometric;
+mod lambert_w;
+mod log;
+mod polygamma;
+mod pow;
+mod round;
+mod slice;
+mod sqrt;
+mod trigonometric;
+mod zeta;
+
+const PREC: u32 = 8;
+
+// Enough precision to make the double rounding in `from_rug_prec` harmless
+// for the functions that MPFR does not provide directly
+const HI_RUG_PREC: u32 = 8 * 20;
+
+fn mkfloat(m: u16, e: i16, s: bool) -> bf16 {
+    let m = m >> (16 - 7);
+    let e = ((e + 127) as u16) << 7;
+    let s = u16::from(s) << 15;
+    bf16::from_bits(m | e | s)
+}
+
+/// Converts `x` to a `rug::Float` with precision `prec`
+fn to_rug(prec: u32, x: bf16) -> rug::Float {
+    rug::Float::with_val(prec, x.to_f32())
+}
+
+/// Converts `x`, with precision 8 and rounded in the direction given by
+/// `dir`, to `bf16`, emulating subnormals.
+fn from_rug(mut x: rug::Float, dir: Ordering) -> bf16 {
+    assert_eq!(x.prec(), PREC);
+    x.subnormalize_round(-125, dir, Round::Nearest);
+    // exact, `f32` has the same exponent range as `bf16`
+    bf16::from_f32(x.to_f32())
+}
+
+/// Rounds `x` to the nearest `bf16`
+fn from_rug_prec(x: &rug::Float) -> bf16 {
+    let (y, dir) = rug::Float::with_val_round(PREC, x, Round::Nearest);
+    from_rug(y, dir)
+}
+
+/// Evaluates a function with MPFR, correctly rounded to `bf16`
+///
+/// `f` takes the argument with precision 8 and overwrites it with the
+/// result, returning the rounding direction.
+fn eval_rug(x: bf16, f: impl FnOnce(&mut rug::Float) -> Ordering) -> bf16 {
+    let mut y = to_rug(PREC, x);
+    let dir = f(&mut y);
+    from_rug(y, dir)
+}
+
+fn same_result(actual: bf16, expected: bf16) -> bool {
+    actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan())
+}
+
+/// Asserts that `actual` is `f(x)` correctly rounded, with `f` evaluated
+/// by [`eval_rug`]
+#[track_caller]
+fn check(name: &str, x: bf16, actual: bf16, f: impl FnOnce(&mut rug::Float) -> Ordering) {
+    let expected = eval_rug(x, f);
+    assert!(
+        same_result(actual, expected),
+        "{name}({x:e}) = {actual:e} (expected {expected:e})",
+    );
+}
+
+/// Like [`check`], but ignores the sign of exact zeros and infinities
+/// (e.g., `cospi(0.5)` or `tanpi(1.5)`), which is not always the same as
+/// MPFR
+#[track_caller]
+fn check_unsigned_zero(
+    name: &str,
+    x: bf16,
+    actual: bf16,
+    f: impl FnOnce(&mut rug::Float) -> Ordering,
+) {
+    let expected = eval_rug(x, f);
+    assert!(
+        same_result(actual, expected)
+            || (actual == bf16::ZERO && expected == bf16::ZERO)
+            || (actual.is_infinite() && expected.is_infinite()),
+        "{name}({x:e}) = {actual:e} (expected {expected:e})",
+    );
+}
+
+/// Calculates the error of `hi + lo` in ULP of `hi`
+///
+/// `(hi, lo)` must be normalized.
+fn calc_error_hi_lo_ulp((hi, lo): (bf16, bf16), expected: &rug::Float) -> f64 {
+    if !hi.is_finite() || hi == bf16::ZERO {
+        if lo == bf16::ZERO && same_result(hi, from_rug_prec(expected)) {
+            0.0
+        } else {
+            f64::INFINITY
+        }
+    } else {
+        assert_eq!(hi + lo, hi, "({hi:e}, {lo:e}) is not normalized");
+        let exp = expected
+            .get_exp()
+            .map(|e| (e - 1).max(-126))
+            .unwrap_or(-126);
+        let actual = to_rug(HI_RUG_PREC, hi) + lo.to_f32();
+        let dif = (actual - expected).abs() >> (exp - 7);
+        dif.to_f64()
+    }
+}
+
+/// Calls `f` with every `bf16` value, except NaN
+fn test_all(mut f: impl FnMut(bf16)) {
+    for bits in 0..=u16::MAX {
+        let x = bf16::from_bits(bits);
+        if !x.is_nan() {
+            f(x);
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use rug::float::Round;
+
+use super::{check, from_rug_prec, same_result, test_all};
+use crate::f64::polygamma::polygamma_ref;
+
+#[test]
+fn test_digamma() {
+    test_all(|x| {
+        check("digamma", x, fpmath::digamma(x), |y| {
+            y.digamma_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_polygamma() {
+    for n in [1, 2, 3, 4, 7, 20] {
+        // Every `bf16` with a magnitude of at least 256 is an integer, so
+        // the reference does not need to sum many terms for negative values
+        test_all(|x| {
+            let actual = fpmath::polygamma(n, x);
+            let expected = from_rug_prec(&polygamma_ref(n, x.to_f64()));
+            assert!(
+                same_result(actual, expected),
+                "polygamma({n}, {x:e}) = {actual:e} (expected {expected:e})",
+            );
+        });
+    }
+}
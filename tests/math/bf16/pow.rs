@@ -0,0 +1,183 @@
+use half::bf16;
+use rand::RngExt as _;
+use rug::float::Round;
+use rug::ops::PowAssignRound as _;
+
+use super::{eval_rug, from_rug, mkfloat, same_result, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_pow() {
+    test_pow_with(|x, y| {
+        let actual = fpmath::pow(x, y);
+        let ry = to_rug(super::PREC, y);
+        let expected = eval_rug(x, |rx| rx.pow_assign_round(&ry, Round::Nearest));
+        assert!(
+            same_result(actual, expected),
+            "pow({x:e}, {y:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+pub(super) fn test_pow_with(mut f: impl FnMut(bf16, bf16)) {
+    let mut rng = create_prng();
+
+    // MIN <= |x|^y <= MAX
+    // log2(MIN) / log2(|x|) <= y <= log2(MAX) / log2(|x|)
+    for bits in 0..0x7F80 {
+        let x = bf16::from_bits(bits);
+        let log2_x = fpmath::log2(x.to_f64());
+        if !log2_x.is_finite() || log2_x == 0.0 {
+            continue;
+        }
+        let (min_y, max_y) = if log2_x > 0.0 {
+            (-134.0 / log2_x, 128.0 / log2_x)
+        } else {
+            (128.0 / log2_x, -134.0 / log2_x)
+        };
+        for _ in 0..20 {
+            let y = bf16::from_f32(rng.random_range(min_y..=max_y) as f32);
+            f(x, y);
+            f(-x, fpmath::round(y));
+        }
+    }
+
+    for ex in -7..=-1 {
+        for ey in 1..=127 {
+            for _ in 0..100 {
+                let mx = rng.random::<u16>();
+                let sx = rng.random::<bool>();
+                let my = rng.random::<u16>();
+                let sy = rng.random::<bool>();
+                f(bf16::ONE + mkfloat(mx, ex, sx), mkfloat(my, ey, sy));
+            }
+        }
+    }
+
+    // exact results, including halfway points
+    for x in 1..=255u16 {
+        let x = bf16::from_f32(f32::from(x));
+        for y in [2.0, 3.0, 0.5, -1.0, -2.0] {
+            f(x, bf16::from_f32(y));
+            f(x * x, bf16::from_f32(0.5));
+        }
+    }
+
+    // special cases
+    let specials = [
+        bf16::ZERO,
+        bf16::NEG_ZERO,
+        bf16::ONE,
+        bf16::NEG_ONE,
+        bf16::INFINITY,
+        bf16::NEG_INFINITY,
+        bf16::NAN,
+    ];
+    for &x in specials.iter() {
+        for &y in specials.iter() {
+            f(x, y);
+        }
+        for y in [-3.0, -2.0, -0.5, 0.5, 2.0, 3.0] {
+            f(x, bf16::from_f32(y));
+            f(bf16::from_f32(y), x);
+        }
+    }
+}
+
+#[test]
+fn test_powi() {
+    for bits in 0..=u16::MAX {
+        let x = bf16::from_bits(bits);
+        for y in (-20..=20).chain([-300, -127, -126, 127, 128, 300]) {
+            let actual = fpmath::powi(x, y);
+            let expected = eval_rug(x, |rx| rx.pow_assign_round(y, Round::Nearest));
+            assert!(
+                same_result(actual, expected),
+                "powi({x:e}, {y}) = {actual:e} (expected {expected:e})",
+            );
+        }
+    }
+}
+
+#[test]
+fn test_powr() {
+    test_pow_with(|x, y| {
+        let actual = fpmath::powr(x, y);
+        let is_nan = x.is_nan()
+            || y.is_nan()
+            || x < bf16::ZERO
+            || (x == bf16::ZERO && y == bf16::ZERO)
+            || (x == bf16::INFINITY && y == bf16::ZERO)
+            || (x == bf16::ONE && y.is_infinite());
+        let expected = if is_nan {
+            bf16::NAN
+        } else {
+            // `x` can be negative zero
+            let ry = to_rug(super::PREC, y);
+            eval_rug(x, |rx| {
+                rx.abs_mut();
+                rx.pow_assign_round(&ry, Round::Nearest)
+            })
+        };
+        assert!(
+            same_result(actual, expected),
+            "powr({x:e}, {y:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+#[test]
+fn test_pown() {
+    for bits in 0..=u16::MAX {
+        let x = bf16::from_bits(bits);
+        for y in (-20..=20).chain([i64::MIN, -(1 << 60) - 1, -300, 300, (1 << 53) + 1, i64::MAX]) {
+            let actual = fpmath::pown(x, y);
+            let expected = eval_rug(x, |rx| rx.pow_assign_round(y, Round::Nearest));
+            assert!(
+                same_result(actual, expected),
+                "pown({x:e}, {y}) = {actual:e} (expected {expected:e})",
+            );
+        }
+    }
+}
+
+#[test]
+fn test_rootn() {
+    for bits in 0..=u16::MAX {
+        let x = bf16::from_bits(bits);
+        for n in (-20..=20).chain([-1001, 12345, i32::MIN + 1, i32::MAX]) {
+            let actual = fpmath::rootn(x, i64::from(n));
+            let expected = eval_rug(x, |rx| rx.root_i_round(n, Round::Nearest));
+            assert!(
+                same_result(actual, expected),
+                "rootn({x:e}, {n}) = {actual:e} (expected {expected:e})",
+            );
+        }
+    }
+}
+
+#[test]
+fn test_compound() {
+    for bits in 0..=u16::MAX {
+        let x = bf16::from_bits(bits);
+        for n in (-20..=20).chain([i64::MIN, -(1 << 40) - 1, -300, 300, 1 << 40, i64::MAX]) {
+            let actual = fpmath::compound(x, n);
+            let expected = if x < -bf16::ONE {
+                bf16::NAN
+            } else {
+                // 1 + x is exact with this precision
+                let xp1 = to_rug(300, x) + 1;
+                let (y, dir) = rug::Float::with_val_round(
+                    super::PREC,
+                    rug::ops::Pow::pow(&xp1, n),
+                    Round::Nearest,
+                );
+                from_rug(y, dir)
+            };
+            assert!(
+                same_result(actual, expected),
+                "compound({x:e}, {n}) = {actual:e} (expected {expected:e})",
+            );
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use super::test_all;
+
+#[test]
+fn test_round() {
+    test_all(|arg| {
+        let expected = fpmath::round(arg.to_f32());
+        let actual = fpmath::round(arg);
+
+        assert!(expected == actual.to_f32(), "round({arg:e}) = {actual:e}");
+    });
+}
+
+#[test]
+fn test_floor() {
+    test_all(|arg| {
+        let expected = fpmath::floor(arg.to_f32());
+        let actual = fpmath::floor(arg);
+
+        assert!(expected == actual.to_f32(), "floor({arg:e}) = {actual:e}");
+    });
+}
+
+#[test]
+fn test_ceil() {
+    test_all(|arg| {
+        let expected = fpmath::ceil(arg.to_f32());
+        let actual = fpmath::ceil(arg);
+
+        assert!(expected == actual.to_f32(), "ceil({arg:e}) = {actual:e}");
+    });
+}
+
+#[test]
+fn test_trunc() {
+    test_all(|arg| {
+        let expected = fpmath::trunc(arg.to_f32());
+        let actual = fpmath::trunc(arg);
+
+        assert!(expected == actual.to_f32(), "trunc({arg:e}) = {actual:e}");
+    });
+}
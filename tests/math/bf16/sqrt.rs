@@ -0,0 +1,29 @@
+use half::bf16;
+use rug::float::Round;
+
+use super::{check, test_all};
+
+#[test]
+fn test_sqrt() {
+    test_all(|x| {
+        check("sqrt", x, fpmath::sqrt(x), |y| y.sqrt_round(Round::Nearest));
+    });
+}
+
+#[test]
+fn test_rsqrt() {
+    test_all(|x| {
+        if x == bf16::ZERO && x.is_sign_negative() {
+            // MPFR returns +inf, IEEE 754 requires -inf
+            let actual = fpmath::rsqrt(x);
+            assert!(
+                actual == bf16::NEG_INFINITY,
+                "rsqrt({x:e}) = {actual:e} (expected -inf)",
+            );
+        } else {
+            check("rsqrt", x, fpmath::rsqrt(x), |y| {
+                y.recip_sqrt_round(Round::Nearest)
+            });
+        }
+    });
+}
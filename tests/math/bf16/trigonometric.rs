@@ -0,0 +1,198 @@
+use half::bf16;
+use rug::float::Round;
+
+use super::{
+    HI_RUG_PREC, calc_error_hi_lo_ulp, check, check_unsigned_zero, from_rug_prec, same_result,
+    test_all, to_rug,
+};
+
+#[test]
+fn test_sin_cos() {
+    test_all(|x| {
+        let (actual_sin, actual_cos) = fpmath::sin_cos(x);
+        check("sin", x, fpmath::sin(x), |y| y.sin_round(Round::Nearest));
+        check("sin_cos.0", x, actual_sin, |y| y.sin_round(Round::Nearest));
+        check("cos", x, fpmath::cos(x), |y| y.cos_round(Round::Nearest));
+        check("sin_cos.1", x, actual_cos, |y| y.cos_round(Round::Nearest));
+    });
+}
+
+#[test]
+fn test_sin_cos_hi_lo() {
+    let mut max_error: f64 = 0.0;
+    test_all(|x| {
+        let expected_sin = to_rug(HI_RUG_PREC, x).sin();
+        let expected_cos = to_rug(HI_RUG_PREC, x).cos();
+        let (actual_sin, actual_cos) = fpmath::sin_cos_hi_lo(x);
+
+        let sin_err = calc_error_hi_lo_ulp(actual_sin, &expected_sin);
+        let cos_err = calc_error_hi_lo_ulp(actual_cos, &expected_cos);
+
+        // `lo` loses precision close to the subnormal range
+        let sin_threshold = if fpmath::abs(actual_sin.0) < bf16::MIN_POSITIVE / bf16::EPSILON {
+            1.0
+        } else {
+            max_error = max_error.max(sin_err);
+            0.01
+        };
+        assert!(
+            sin_err < sin_threshold,
+            "sin_cos_hi_lo({x:e}).0 = {actual_sin:?} (error = {sin_err} ULP)",
+        );
+
+        max_error = max_error.max(cos_err);
+        assert!(
+            cos_err < 0.01,
+            "sin_cos_hi_lo({x:e}).1 = {actual_cos:?} (error = {cos_err} ULP)",
+        );
+    });
+    eprintln!("max sin_cos_hi_lo error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_tan() {
+    test_all(|x| {
+        check("tan", x, fpmath::tan(x), |y| y.tan_round(Round::Nearest));
+    });
+}
+
+#[test]
+fn test_rem_pio2() {
+    let mut max_error: f64 = 0.0;
+    test_all(|x| {
+        let (n, y_hi, y_lo) = fpmath::rem_pio2(x);
+        if !x.is_finite() {
+            assert!(n == 0 && y_hi.is_nan() && y_lo == bf16::ZERO);
+            return;
+        }
+        assert!((0..=3).contains(&n), "rem_pio2({x:e}).0 = {n}");
+
+        let expected = reduce_rug(x, n, HI_RUG_PREC);
+        let frac_pi_4 = rug::Float::with_val(64, rug::float::Constant::Pi) / 4u8;
+        assert!(
+            expected.clone().abs() <= frac_pi_4 * 1.0001,
+            "rem_pio2({x:e}) = {:?} (expected {expected})",
+            (n, y_hi, y_lo),
+        );
+
+        let err = calc_error_hi_lo_ulp((y_hi, y_lo), &expected);
+        // `lo` loses precision close to the subnormal range
+        let threshold = if fpmath::abs(y_hi) < bf16::MIN_POSITIVE / bf16::EPSILON {
+            1.0
+        } else {
+            max_error = max_error.max(err);
+            0.002
+        };
+        assert!(
+            err < threshold,
+            "rem_pio2({x:e}) = {:?} (error = {err} ULP)",
+            (n, y_hi, y_lo),
+        );
+    });
+    eprintln!("max rem_pio2 error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_wrap_angle() {
+    test_all(|x| {
+        let actual = fpmath::wrap_angle(x);
+        if !x.is_finite() {
+            assert!(actual.is_nan());
+            return;
+        }
+
+        let expected = from_rug_prec(&reduce_rug(x, 0, HI_RUG_PREC));
+        // π and -π are both valid
+        let alt_expected = if fpmath::abs(expected) > bf16::from_f32(3.0) {
+            let two_pi = rug::Float::with_val(HI_RUG_PREC, rug::float::Constant::Pi) * 2u8;
+            let y = reduce_rug(x, 0, HI_RUG_PREC);
+            from_rug_prec(&if y.is_sign_negative() {
+                y + two_pi
+            } else {
+                y - two_pi
+            })
+        } else {
+            expected
+        };
+        assert!(
+            same_result(actual, expected) || same_result(actual, alt_expected),
+            "wrap_angle({x:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+/// Calculates `x - π/2*n - 2*π*M`, with `M` chosen to minimize the result,
+/// with `prec` bits of precision
+fn reduce_rug(x: bf16, n: i32, prec: u32) -> rug::Float {
+    let x = to_rug(8, x);
+    if x.is_zero() {
+        // keep the sign of zero
+        return x;
+    }
+    // enough bits to keep `prec` bits after cancellation, which can lose
+    // more bits than the precision of the argument
+    let work_prec = x.get_exp().unwrap_or(0).max(0) as u32 + prec + 128;
+    let pi = rug::Float::with_val(work_prec, rug::float::Constant::Pi);
+    let two_pi = pi.clone() * 2u8;
+    let y = rug::Float::with_val(work_prec, &x - pi * n / 2u8);
+    let m = (y.clone() / &two_pi).round();
+    rug::Float::with_val(prec, y - m * two_pi)
+}
+
+#[test]
+fn test_sind_cosd() {
+    test_all(|x| {
+        let (actual_sin, actual_cos) = fpmath::sind_cosd(x);
+        check_unsigned_zero("sind", x, fpmath::sind(x), |y| {
+            y.sin_u_round(360, Round::Nearest)
+        });
+        check_unsigned_zero("sind_cosd.0", x, actual_sin, |y| {
+            y.sin_u_round(360, Round::Nearest)
+        });
+        check_unsigned_zero("cosd", x, fpmath::cosd(x), |y| {
+            y.cos_u_round(360, Round::Nearest)
+        });
+        check_unsigned_zero("sind_cosd.1", x, actual_cos, |y| {
+            y.cos_u_round(360, Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_tand() {
+    test_all(|x| {
+        check_unsigned_zero("tand", x, fpmath::tand(x), |y| {
+            y.tan_u_round(360, Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_sinpi_cospi() {
+    test_all(|x| {
+        let (actual_sin, actual_cos) = fpmath::sinpi_cospi(x);
+        check_unsigned_zero("sinpi", x, fpmath::sinpi(x), |y| {
+            y.sin_pi_round(Round::Nearest)
+        });
+        check_unsigned_zero("sinpi_cospi.0", x, actual_sin, |y| {
+            y.sin_pi_round(Round::Nearest)
+        });
+        check_unsigned_zero("cospi", x, fpmath::cospi(x), |y| {
+            y.cos_pi_round(Round::Nearest)
+        });
+        check_unsigned_zero("sinpi_cospi.1", x, actual_cos, |y| {
+            y.cos_pi_round(Round::Nearest)
+        });
+    });
+}
+
+#[test]
+fn test_tanpi() {
+    test_all(|x| {
+        check_unsigned_zero("tanpi", x, fpmath::tanpi(x), |y| {
+            y.tan_pi_round(Round::Nearest)
+        });
+    });
+}
@@ -0,0 +1,47 @@
+use half::bf16;
+use rand::RngExt as _;
+use rug::float::Round;
+
+use super::{check, from_rug_prec, mkfloat, same_result, test_all};
+use crate::create_prng;
+use crate::f64::zeta::hurwitz_zeta_ref;
+
+#[test]
+fn test_zeta() {
+    test_all(|s| {
+        check("zeta", s, fpmath::zeta(s), |y| y.zeta_round(Round::Nearest));
+    });
+}
+
+#[test]
+fn test_hurwitz_zeta() {
+    test_with_hurwitz_zeta(|s, q| {
+        let expected = from_rug_prec(&hurwitz_zeta_ref(s.to_f64(), q.to_f64()));
+        let actual = fpmath::hurwitz_zeta(s, q);
+        assert!(
+            same_result(actual, expected),
+            "hurwitz_zeta({s:e}, {q:e}) = {actual:e} (expected {expected:e})",
+        );
+    });
+}
+
+fn test_with_hurwitz_zeta(mut f: impl FnMut(bf16, bf16)) {
+    let mut rng = create_prng();
+
+    for es in -7..=7 {
+        for eq in (-126..=127).step_by(3) {
+            for _ in 0..20 {
+                let s = bf16::ONE + mkfloat(rng.random::<u16>(), es, false);
+                let q = mkfloat(rng.random::<u16>(), eq, false);
+                f(s, q);
+            }
+        }
+    }
+
+    for s in 2..=20 {
+        for i in 1..=1000 {
+            let q = bf16::from_f32((i as f32) / 100.0);
+            f(bf16::from_f32(s as f32), q);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use rand::RngExt as _;
+
+use super::{RUG_PREC, calc_error_ulp, fmt, mkfloat, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_cbrt() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = to_rug(RUG_PREC, x).cbrt();
+        let actual = fpmath::cbrt(x);
+        assert_eq!(fpmath::cbrt(-x), -actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "cbrt({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual)
+        );
+    });
+    eprintln!("max cbrt error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_with(mut f: impl FnMut(f128)) {
+    let mut rng = create_prng();
+
+    for e in -16382..=16383 {
+        f(mkfloat(0, e, false));
+        f(mkfloat(u128::MAX, e, false));
+
+        for _ in 0..20 {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, false));
+        }
+    }
+    for e in -200..=200 {
+        for _ in 0..2000 {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, false));
+        }
+    }
+
+    for arg in 1..=10000 {
+        f(arg as f128);
+    }
+
+    f(f128::MIN_POSITIVE);
+    f(f128::MAX);
+
+    // subnormals
+    for i in 0..112 {
+        f(f128::from_bits(1 << i));
+        f(f128::from_bits((1 << (i + 1)) - 1));
+    }
+}
@@ -0,0 +1,247 @@
+use fpmath::complex::Complex;
+use rand::RngExt as _;
+use rug::ops::Pow as _;
+
+use super::{RUG_PREC, calc_error_ulp, fmt, mkfloat, to_rug};
+use crate::create_prng;
+
+/// Calculates the error of each part in ULP of that part and returns the
+/// largest one.
+fn calc_error(actual: Complex<f128>, expected: &rug::Complex) -> f64 {
+    let re = calc_error_ulp(actual.re, expected.real().clone());
+    let im = calc_error_ulp(actual.im, expected.imag().clone());
+    re.max(im)
+}
+
+/// Calculates the error of each part in ULP of the largest part of the
+/// expected value and returns the largest one.
+fn calc_norm_error(actual: Complex<f128>, expected: &rug::Complex) -> f64 {
+    let (e_re, e_im) = (expected.real(), expected.imag());
+    if !e_re.is_finite() || !e_im.is_finite() || (e_re.is_zero() && e_im.is_zero()) {
+        return calc_error(actual, expected);
+    }
+    let mag = rug::Float::with_val(RUG_PREC, e_re.abs_ref()).max(&e_im.clone().abs());
+    let exp = (mag.get_exp().unwrap() - 1).max(-16382);
+    let part_error = |actual: f128, expected: &rug::Float| {
+        if actual.is_finite() && expected.get_exp().is_none_or(|e| e <= 16384) {
+            (rug::Float::with_val(RUG_PREC, expected - to_rug(113, actual)).abs() >> (exp - 112))
+                .to_f64()
+        } else {
+            calc_error_ulp(actual, expected.clone())
+        }
+    };
+    part_error(actual.re, e_re).max(part_error(actual.im, e_im))
+}
+
+fn complex_to_rug(z: Complex<f128>) -> rug::Complex {
+    rug::Complex::with_val(RUG_PREC, (to_rug(RUG_PREC, z.re), to_rug(RUG_PREC, z.im)))
+}
+
+fn fmt_complex(z: Complex<f128>) -> String {
+    format!("{} + {}i", fmt(z.re), fmt(z.im))
+}
+
+fn test_unary(
+    name: &str,
+    f: impl Fn(Complex<f128>) -> Complex<f128>,
+    f_rug: impl Fn(rug::Complex) -> rug::Complex,
+    threshold: f64,
+) {
+    let mut max_error: f64 = 0.0;
+    test_with(|z| {
+        let expected = f_rug(complex_to_rug(z));
+        let actual = f(z);
+
+        let err = calc_error(actual, &expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < threshold,
+            "{name}({}) = {} (error = {err} ULP)",
+            fmt_complex(z),
+            fmt_complex(actual),
+        );
+    });
+    eprintln!("max complex {name} error = {max_error}");
+}
+
+#[test]
+fn test_exp() {
+    test_unary("exp", Complex::exp, |z| z.exp(), 2.5);
+}
+
+#[test]
+fn test_ln() {
+    test_unary("ln", Complex::ln, |z| z.ln(), 0.9);
+}
+
+#[test]
+fn test_sqrt() {
+    test_unary("sqrt", Complex::sqrt, |z| z.sqrt(), 2.0);
+}
+
+#[test]
+fn test_trigonometric() {
+    test_unary("sin", Complex::sin, |z| z.sin(), 2.5);
+    test_unary("cos", Complex::cos, |z| z.cos(), 2.5);
+    test_unary("tan", Complex::tan, tan_ref, 5.0);
+}
+
+#[test]
+fn test_hyperbolic() {
+    test_unary("sinh", Complex::sinh, |z| z.sinh(), 2.5);
+    test_unary("cosh", Complex::cosh, |z| z.cosh(), 2.5);
+    test_unary("tanh", Complex::tanh, tanh_ref, 5.0);
+}
+
+#[test]
+fn test_inv_trigonometric() {
+    test_unary("asin", Complex::asin, |z| z.asin(), 3.5);
+    test_unary("acos", Complex::acos, |z| z.acos(), 3.5);
+    test_unary("atan", Complex::atan, atan_ref, 3.5);
+}
+
+#[test]
+fn test_inv_hyperbolic() {
+    test_unary("asinh", Complex::asinh, |z| z.asinh(), 3.5);
+    test_unary("acosh", Complex::acosh, |z| z.acosh(), 3.5);
+    test_unary("atanh", Complex::atanh, atanh_ref, 3.5);
+}
+
+/// MPC is very slow for `tanh(x + iy)` with a large `|x|`, so use
+/// `(sinh(2x) + i * sin(2y)) / (cosh(2x) + cos(2y))` when `|x| >= 1`.
+fn tanh_ref(z: rug::Complex) -> rug::Complex {
+    let (x, y) = z.into_real_imag();
+    if x.clone().abs() < 1 {
+        rug::Complex::with_val(RUG_PREC, (x, y)).tanh()
+    } else if x.clone().abs() > 16384 {
+        // the imaginary part is far below the smallest subnormal
+        let im = (y * 2u8).sin() * 0.0;
+        rug::Complex::with_val(RUG_PREC, (x.signum(), im))
+    } else {
+        let x2 = x * 2u8;
+        let y2 = y * 2u8;
+        let d = x2.clone().cosh() + y2.clone().cos();
+        let re = x2.sinh() / &d;
+        let im = y2.sin() / &d;
+        rug::Complex::with_val(RUG_PREC, (re, im))
+    }
+}
+
+/// `tan(z) = -i * tanh(i * z)`
+fn tan_ref(z: rug::Complex) -> rug::Complex {
+    let (x, y) = z.into_real_imag();
+    let (re, im) = tanh_ref(rug::Complex::with_val(RUG_PREC, (-y, x))).into_real_imag();
+    rug::Complex::with_val(RUG_PREC, (im, -re))
+}
+
+/// MPC is very slow for `atanh(z)` with a tiny `|z|`, so use
+/// `z + z^3 / 3` when `|z| < 2^-64`.
+fn atanh_ref(z: rug::Complex) -> rug::Complex {
+    let tiny = |x: &rug::Float| x.get_exp().is_none_or(|e| e < -64);
+    if tiny(z.real()) && tiny(z.imag()) {
+        let z3 = rug::Complex::with_val(RUG_PREC, z.square_ref()) * &z;
+        z + z3 / 3u8
+    } else {
+        z.atanh()
+    }
+}
+
+/// `atan(z) = -i * atanh(i * z)`
+fn atan_ref(z: rug::Complex) -> rug::Complex {
+    let (x, y) = z.into_real_imag();
+    let (re, im) = atanh_ref(rug::Complex::with_val(RUG_PREC, (-y, x))).into_real_imag();
+    rug::Complex::with_val(RUG_PREC, (im, -re))
+}
+
+#[test]
+fn test_mul_div_pow() {
+    let mut max_mul_error: f64 = 0.0;
+    let mut max_div_error: f64 = 0.0;
+    let mut max_pow_error: f64 = 0.0;
+    let mut rng = create_prng();
+    for i in 0..4000 {
+        let range = if i % 2 == 0 { -16382..=16383 } else { -60..=60 };
+        let [ea, eb, ec, ed] = [(); 4].map(|()| rng.random_range(range.clone()));
+        let z = Complex::new(rand_float(&mut rng, ea), rand_float(&mut rng, eb));
+        let w = Complex::new(rand_float(&mut rng, ec), rand_float(&mut rng, ed));
+        let (rz, rw) = (complex_to_rug(z), complex_to_rug(w));
+
+        let err = calc_error(z * w, &(rz.clone() * &rw));
+        max_mul_error = max_mul_error.max(err);
+        assert!(
+            err < 0.9,
+            "{} * {} (error = {err} ULP)",
+            fmt_complex(z),
+            fmt_complex(w)
+        );
+
+        let err = calc_error(z / w, &(rz / &rw));
+        max_div_error = max_div_error.max(err);
+        assert!(
+            err < 0.9,
+            "{} / {} (error = {err} ULP)",
+            fmt_complex(z),
+            fmt_complex(w)
+        );
+
+        // keep the magnitude of w * ln(z) reasonable
+        let [ea, eb] = [(); 2].map(|()| rng.random_range(-60..=60));
+        let [ec, ed] = [(); 2].map(|()| rng.random_range(-60..=4));
+        let z = Complex::new(rand_float(&mut rng, ea), rand_float(&mut rng, eb));
+        let w = Complex::new(rand_float(&mut rng, ec), rand_float(&mut rng, ed));
+        let actual = z.pow(w);
+        let err = calc_norm_error(actual, &complex_to_rug(z).pow(complex_to_rug(w)));
+        max_pow_error = max_pow_error.max(err);
+        assert!(
+            err < 6.0,
+            "pow({}, {}) = {} (error = {err} ULP)",
+            fmt_complex(z),
+            fmt_complex(w),
+            fmt_complex(actual),
+        );
+    }
+    eprintln!("max complex mul error = {max_mul_error}");
+    eprintln!("max complex div error = {max_div_error}");
+    eprintln!("max complex pow error = {max_pow_error}");
+}
+
+fn test_with(mut f: impl FnMut(Complex<f128>)) {
+    let mut rng = create_prng();
+
+    for ex in (-16382..=16383).step_by(2047) {
+        for ey in (-16382..=16383).step_by(2047) {
+            let x = rand_float(&mut rng, ex);
+            let y = rand_float(&mut rng, ey);
+            f(Complex::new(x, y));
+        }
+    }
+    for ex in (-60..=60).step_by(3) {
+        for ey in (-60..=60).step_by(3) {
+            let x = rand_float(&mut rng, ex);
+            let y = rand_float(&mut rng, ey);
+            f(Complex::new(x, y));
+        }
+    }
+    for e in (-120..=-1).step_by(3) {
+        for _ in 0..5 {
+            // near the unit circle
+            let r = 1.0 + rand_float(&mut rng, e);
+            let t = rand_float(&mut rng, 1);
+            let (s, c) = fpmath::sin_cos(t);
+            f(Complex::new(r * c, r * s));
+
+            // near ±1 and ±i
+            let sign = if rng.random::<bool>() { -1.0 } else { 1.0 };
+            let a = sign * (1.0 + rand_float(&mut rng, e));
+            let eb = rng.random_range(-300..=0);
+            let b = rand_float(&mut rng, eb);
+            f(Complex::new(a, b));
+            f(Complex::new(b, a));
+        }
+    }
+}
+
+fn rand_float(rng: &mut impl rand::Rng, e: i16) -> f128 {
+    mkfloat(rng.random::<u128>(), e, rng.random::<bool>())
+}
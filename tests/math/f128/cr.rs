@@ -0,0 +1,389 @@
+use rand::RngExt as _;
+use rug::ops::Pow as _;
+
+use super::{fmt, from_rug, mkfloat, to_rug};
+use crate::create_prng;
+
+// Enough precision to make the double rounding in `from_rug` harmless
+const CR_RUG_PREC: u32 = 113 * 5;
+
+#[test]
+fn test_exp() {
+    let mut rng = create_prng();
+    for e in -120..=13 {
+        for _ in 0..200 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("exp", fpmath::cr::exp, rug::Float::exp, x);
+            check("exp2", fpmath::cr::exp2, rug::Float::exp2, x * 1.5);
+            check("exp10", fpmath::cr::exp10, rug::Float::exp10, x * 0.5);
+        }
+    }
+
+    // results close to or in the subnormal range
+    for _ in 0..500 {
+        let x = rng.random_range(-11433.0f64..-11300.0) as f128;
+        check("exp", fpmath::cr::exp, rug::Float::exp, x);
+        check("exp2", fpmath::cr::exp2, rug::Float::exp2, x * 1.45);
+        check("exp10", fpmath::cr::exp10, rug::Float::exp10, x * 0.435);
+    }
+
+    // exact results
+    for i in -16494..=16383 {
+        check("exp2", fpmath::cr::exp2, rug::Float::exp2, i as f128);
+    }
+    for i in -48..=48 {
+        check("exp10", fpmath::cr::exp10, rug::Float::exp10, i as f128);
+    }
+}
+
+#[test]
+fn test_exp_m1() {
+    let mut rng = create_prng();
+    for e in -120..=13 {
+        for _ in 0..200 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("exp_m1", fpmath::cr::exp_m1, rug::Float::exp_m1, x);
+        }
+    }
+}
+
+#[test]
+fn test_log() {
+    let mut rng = create_prng();
+    for e in -16382..=16383 {
+        for _ in 0..5 {
+            let x = mkfloat(rng.random(), e, false);
+            check("ln", fpmath::cr::ln, rug::Float::ln, x);
+            check("log2", fpmath::cr::log2, rug::Float::log2, x);
+            check("log10", fpmath::cr::log10, rug::Float::log10, x);
+        }
+    }
+
+    // close to 1
+    for e in -120..=-1 {
+        for _ in 0..50 {
+            let s = rng.random::<bool>();
+            let x = 1.0 + mkfloat(rng.random(), e, s);
+            check("ln", fpmath::cr::ln, rug::Float::ln, x);
+            check("log2", fpmath::cr::log2, rug::Float::log2, x);
+            check("log10", fpmath::cr::log10, rug::Float::log10, x);
+        }
+    }
+
+    // subnormals
+    for i in 0..112 {
+        let x = f128::from_bits((1 << (i + 1)) - 1);
+        check("ln", fpmath::cr::ln, rug::Float::ln, x);
+        check("log2", fpmath::cr::log2, rug::Float::log2, x);
+        check("log10", fpmath::cr::log10, rug::Float::log10, x);
+    }
+
+    // exact results
+    for i in 0..112 {
+        check(
+            "log2",
+            fpmath::cr::log2,
+            rug::Float::log2,
+            f128::from_bits(1 << i),
+        );
+    }
+    for e in -16382..=16383 {
+        check(
+            "log2",
+            fpmath::cr::log2,
+            rug::Float::log2,
+            mkfloat(0, e, false),
+        );
+    }
+    let mut x: f128 = 1.0;
+    for _ in 0..=48 {
+        check("log10", fpmath::cr::log10, rug::Float::log10, x);
+        x *= 10.0;
+    }
+}
+
+#[test]
+fn test_ln_1p() {
+    let mut rng = create_prng();
+    for e in -120..=16383 {
+        for _ in 0..5 {
+            let x = mkfloat(rng.random(), e, false);
+            check("ln_1p", fpmath::cr::ln_1p, rug::Float::ln_1p, x);
+        }
+    }
+    for e in -120..=-1 {
+        for _ in 0..50 {
+            let x = mkfloat(rng.random(), e, true);
+            check("ln_1p", fpmath::cr::ln_1p, rug::Float::ln_1p, x);
+        }
+    }
+}
+
+#[test]
+fn test_trigonometric() {
+    let mut rng = create_prng();
+    for e in -60..=16383 {
+        let n = if e <= 120 { 100 } else { 2 };
+        for _ in 0..n {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("sin", fpmath::cr::sin, rug::Float::sin, x);
+            check("cos", fpmath::cr::cos, rug::Float::cos, x);
+            check("tan", fpmath::cr::tan, rug::Float::tan, x);
+        }
+    }
+
+    // close to multiples of π/2
+    for k in 1..=2000 {
+        let x = k as f128 * std::f128::consts::FRAC_PI_2;
+        for x in [x, x.next_up(), x.next_down()] {
+            check("sin", fpmath::cr::sin, rug::Float::sin, x);
+            check("cos", fpmath::cr::cos, rug::Float::cos, x);
+            check("tan", fpmath::cr::tan, rug::Float::tan, x);
+        }
+    }
+}
+
+#[test]
+fn test_inverse_trigonometric() {
+    let mut rng = create_prng();
+    for e in -60..=120 {
+        for _ in 0..20 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("atan", fpmath::cr::atan, rug::Float::atan, x);
+            if e < 0 {
+                check("asin", fpmath::cr::asin, rug::Float::asin, x);
+                check("acos", fpmath::cr::acos, rug::Float::acos, x);
+            }
+        }
+    }
+
+    // close to ±1
+    for e in -113..=-2 {
+        for _ in 0..5 {
+            let s = rng.random::<bool>();
+            let x = (1.0 - mkfloat(rng.random(), e, false)) * if s { -1.0 } else { 1.0 };
+            check("asin", fpmath::cr::asin, rug::Float::asin, x);
+            check("acos", fpmath::cr::acos, rug::Float::acos, x);
+        }
+    }
+
+    for ey in -120..=120 {
+        for _ in 0..5 {
+            let ex = rng.random_range(-120..=120);
+            let y = mkfloat(rng.random(), ey, rng.random());
+            let x = mkfloat(rng.random(), ex, rng.random());
+            check2("atan2", fpmath::cr::atan2, rug::Float::atan2, y, x);
+        }
+    }
+
+    // results close to or in the subnormal range
+    for ey in -16383..=-16260 {
+        for _ in 0..2 {
+            let y = mkfloat(rng.random(), ey, rng.random());
+            let x = mkfloat(rng.random(), rng.random_range(0..=120), false);
+            check2("atan2", fpmath::cr::atan2, rug::Float::atan2, y, x);
+        }
+    }
+}
+
+#[test]
+fn test_hyperbolic() {
+    let mut rng = create_prng();
+    for e in -60..=13 {
+        for _ in 0..50 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("sinh", fpmath::cr::sinh, rug::Float::sinh, x);
+            check("cosh", fpmath::cr::cosh, rug::Float::cosh, x);
+            check("tanh", fpmath::cr::tanh, rug::Float::tanh, x);
+        }
+    }
+
+    // results close to overflow
+    for _ in 0..500 {
+        let x = rng.random_range(11350.0f64..11357.0) as f128;
+        check("sinh", fpmath::cr::sinh, rug::Float::sinh, x);
+        check("cosh", fpmath::cr::cosh, rug::Float::cosh, x);
+    }
+}
+
+#[test]
+fn test_cbrt_hypot() {
+    let mut rng = create_prng();
+    for e in (-16383..=16383).step_by(16) {
+        for _ in 0..10 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("cbrt", fpmath::cr::cbrt, rug::Float::cbrt, x);
+
+            let ey = (e + rng.random_range(-80..=80)).clamp(-16383, 16383);
+            let y = mkfloat(rng.random(), ey, rng.random());
+            check2("hypot", fpmath::cr::hypot, rug::Float::hypot, x, y);
+        }
+    }
+
+    // exact results
+    for _ in 0..500 {
+        let a = rng.random_range(1..(1u64 << 37)) as f128;
+        check("cbrt", fpmath::cr::cbrt, rug::Float::cbrt, a * a * a);
+        check(
+            "cbrt",
+            fpmath::cr::cbrt,
+            rug::Float::cbrt,
+            -a * a * a * 0.125,
+        );
+    }
+
+    // hypot(m^2 - n^2, 2 * m * n) = m^2 + n^2, which is halfway between
+    // two floats when it is odd and has 114 bits
+    for _ in 0..500 {
+        let n = rng.random_range((1u128 << 56)..(1u128 << 56) * 7 / 5);
+        let m = n + 2 * rng.random_range(0..1000) + 1;
+        let a = (m * m - n * n) as f128;
+        let b = (2 * m * n) as f128;
+        check2("hypot", fpmath::cr::hypot, rug::Float::hypot, a, b);
+        check2(
+            "hypot",
+            fpmath::cr::hypot,
+            rug::Float::hypot,
+            a * 0.5,
+            b * 0.5,
+        );
+    }
+}
+
+#[test]
+fn test_pow() {
+    let mut rng = create_prng();
+    for ex in -20..=20 {
+        for ey in -20..=13 {
+            for _ in 0..5 {
+                let sy = rng.random::<bool>();
+                let x = mkfloat(rng.random(), ex, false);
+                let y = mkfloat(rng.random(), ey, sy);
+                check_pow(x, y);
+            }
+        }
+    }
+
+    // negative x with integer y
+    for _ in 0..500 {
+        let x = -rng.random_range(0.0f64..100.0) as f128;
+        let y = rng.random_range(-100..=100) as f128;
+        check_pow(x, y);
+    }
+
+    // results close to or in the subnormal range
+    for _ in 0..500 {
+        let x = rng.random_range(0.5f64..2.0);
+        let y = -rng.random_range(11300.0..11433.0) / x.ln();
+        check_pow(x as f128, y as f128);
+    }
+
+    // exact results and results halfway between two floats
+    for _ in 0..500 {
+        let a = rng.random_range(1..(1u64 << 56)) as f128;
+        check_pow(a * a, 0.5);
+        check_pow(a, 2.0);
+        check_pow(a * 0.125, 2.0);
+    }
+}
+
+#[test]
+fn test_hard_cases() {
+    // exactly halfway between two floats
+    let a = (2.0f128).powi(57) - 1.0;
+    check_pow(a, 2.0);
+    let a = (2.0f128).powi(38) - 1.0;
+    check_pow(a * a, 1.5);
+    check_pow(2.0, -16495.0);
+    check_pow(4.0, -8247.5);
+    check("exp10", fpmath::cr::exp10, rug::Float::exp10, 49.0);
+    let n = (1u128 << 56) + 12345;
+    let m = n + 1;
+    check2(
+        "hypot",
+        fpmath::cr::hypot,
+        rug::Float::hypot,
+        (m * m - n * n) as f128,
+        (2 * m * n) as f128,
+    );
+
+    // y / x is exactly halfway between two subnormals, and atan2(y, x) is
+    // slightly closer to zero (too close for the reference)
+    let x = (2.0f128).powi(16000);
+    let y = 3.0 * (2.0f128).powi(-495);
+    assert_eq!(fpmath::cr::atan2(y, x).to_bits(), 1);
+    let y = 7.0 * (2.0f128).powi(-495);
+    assert_eq!(fpmath::cr::atan2(y, x).to_bits(), 3);
+
+    // very close to halfway between two floats, which needs more than
+    // the first multi-precision attempt
+    check(
+        "exp",
+        fpmath::cr::exp,
+        rug::Float::exp,
+        (2.0f128).powi(-113),
+    );
+    check(
+        "exp",
+        fpmath::cr::exp,
+        rug::Float::exp,
+        -(2.0f128).powi(-114),
+    );
+    check_pow(1.0 + f128::EPSILON, 0.5);
+    check_pow(1.0 - f128::EPSILON / 2.0, 0.5);
+}
+
+fn check(
+    name: &str,
+    actual_f: impl Fn(f128) -> f128,
+    expected_f: impl Fn(rug::Float) -> rug::Float,
+    x: f128,
+) {
+    let actual = actual_f(x);
+    let expected = from_rug(&expected_f(to_rug(CR_RUG_PREC, x)));
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "{name}({}) = {} (expected {})",
+        fmt(x),
+        fmt(actual),
+        fmt(expected),
+    );
+}
+
+fn check2(
+    name: &str,
+    actual_f: impl Fn(f128, f128) -> f128,
+    expected_f: impl Fn(rug::Float, &rug::Float) -> rug::Float,
+    x: f128,
+    y: f128,
+) {
+    let actual = actual_f(x, y);
+    let expected = from_rug(&expected_f(to_rug(CR_RUG_PREC, x), &to_rug(CR_RUG_PREC, y)));
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "{name}({}, {}) = {} (expected {})",
+        fmt(x),
+        fmt(y),
+        fmt(actual),
+        fmt(expected),
+    );
+}
+
+fn check_pow(x: f128, y: f128) {
+    let actual = fpmath::cr::pow(x, y);
+    let expected = from_rug(&to_rug(CR_RUG_PREC, x).pow(to_rug(CR_RUG_PREC, y)));
+    assert!(
+        actual.to_bits() == expected.to_bits(),
+        "pow({}, {}) = {} (expected {})",
+        fmt(x),
+        fmt(y),
+        fmt(actual),
+        fmt(expected),
+    );
+}
@@ -0,0 +1,281 @@
+use fpmath::DoubleFloat;
+use rand::RngExt as _;
+
+use super::{fmt, mkfloat, to_rug};
+use crate::create_prng;
+
+const DD_RUG_PREC: u32 = 226 + 20;
+
+#[test]
+fn test_arith() {
+    let mut max_add_error: f64 = 0.0;
+    let mut max_sub_error: f64 = 0.0;
+    let mut max_mul_error: f64 = 0.0;
+    let mut max_div_error: f64 = 0.0;
+
+    let mut rng = create_prng();
+    for ex in (-6400..=6400).step_by(256) {
+        for ey in (-240..=240).step_by(8) {
+            for _ in 0..10 {
+                let (sx, sy) = rng.random::<(bool, bool)>();
+                let x = mkdouble(&mut rng, ex, sx);
+                let y = mkdouble(&mut rng, ex + ey, sy);
+
+                let rx = dd_to_rug(x);
+                let ry = dd_to_rug(y);
+
+                let add_err = calc_error_dd_ulp(x + y, rx.clone() + &ry);
+                let sub_err = calc_error_dd_ulp(x - y, rx.clone() - &ry);
+                let mul_err = calc_error_dd_ulp(x * y, rx.clone() * &ry);
+                let div_err = calc_error_dd_ulp(x / y, rx / ry);
+
+                max_add_error = max_add_error.max(add_err);
+                max_sub_error = max_sub_error.max(sub_err);
+                max_mul_error = max_mul_error.max(mul_err);
+                max_div_error = max_div_error.max(div_err);
+
+                assert!(
+                    add_err < 1.5,
+                    "{} + {} (error = {add_err} ULP)",
+                    fmt_dd(x),
+                    fmt_dd(y)
+                );
+                assert!(
+                    sub_err < 1.5,
+                    "{} - {} (error = {sub_err} ULP)",
+                    fmt_dd(x),
+                    fmt_dd(y)
+                );
+                assert!(
+                    mul_err < 5.0,
+                    "{} * {} (error = {mul_err} ULP)",
+                    fmt_dd(x),
+                    fmt_dd(y)
+                );
+                assert!(
+                    div_err < 4.0,
+                    "{} / {} (error = {div_err} ULP)",
+                    fmt_dd(x),
+                    fmt_dd(y)
+                );
+            }
+        }
+    }
+
+    eprintln!("max add error = {max_add_error}");
+    eprintln!("max sub error = {max_sub_error}");
+    eprintln!("max mul error = {max_mul_error}");
+    eprintln!("max div error = {max_div_error}");
+    assert!(max_add_error > 0.5);
+    assert!(max_sub_error > 0.5);
+    assert!(max_mul_error > 0.5);
+    assert!(max_div_error > 0.5);
+}
+
+#[test]
+fn test_sqrt() {
+    let mut max_error: f64 = 0.0;
+
+    let mut rng = create_prng();
+    let mut test = |x: DoubleFloat<f128>| {
+        let expected = dd_to_rug(x).sqrt();
+        let actual = x.sqrt();
+
+        let err = calc_error_dd_ulp(actual, expected);
+        max_error = max_error.max(err);
+        assert!(
+            err < 2.5,
+            "sqrt({}) = {} (error = {err} ULP)",
+            fmt_dd(x),
+            fmt_dd(actual)
+        );
+    };
+
+    for e in (-16260..=16383).step_by(113) {
+        for _ in 0..50 {
+            test(mkdouble(&mut rng, e, false));
+        }
+    }
+
+    // subnormals
+    for i in 0..112 {
+        test(DoubleFloat::from(f128::from_bits(1 << i)));
+        test(DoubleFloat::from(f128::from_bits((1 << (i + 1)) - 1)));
+    }
+
+    eprintln!("max sqrt error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_exp() {
+    let mut max_error: f64 = 0.0;
+
+    let mut rng = create_prng();
+    let mut test = |x: DoubleFloat<f128>| {
+        let expected = dd_to_rug(x).exp();
+        let actual = x.exp();
+
+        let err = calc_error_dd_ulp(actual, expected);
+        max_error = max_error.max(err);
+        assert!(
+            err < 1.5,
+            "exp({}) = {} (error = {err} ULP)",
+            fmt_dd(x),
+            fmt_dd(actual)
+        );
+    };
+
+    for e in -120..=13 {
+        for _ in 0..500 {
+            let s = rng.random::<bool>();
+            let x = mkdouble(&mut rng, e, s);
+            // keep the result far from the subnormal range
+            if x.hi() > -11300.0 && x.hi() < 11356.0 {
+                test(x);
+            }
+        }
+    }
+
+    eprintln!("max exp error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_ln() {
+    let mut max_error: f64 = 0.0;
+
+    let mut rng = create_prng();
+    let mut test = |x: DoubleFloat<f128>| {
+        let expected = dd_to_rug(x).ln();
+        let actual = x.ln();
+
+        let err = calc_error_dd_ulp(actual, expected);
+        max_error = max_error.max(err);
+        assert!(
+            err < 2.5,
+            "ln({}) = {} (error = {err} ULP)",
+            fmt_dd(x),
+            fmt_dd(actual)
+        );
+    };
+
+    for e in -16382..=16383 {
+        for _ in 0..10 {
+            test(mkdouble(&mut rng, e, false));
+        }
+    }
+
+    // close to 1
+    for e in -230..=-1 {
+        for _ in 0..100 {
+            let s = rng.random::<bool>();
+            let d = mkdouble(&mut rng, e, s);
+            test(DoubleFloat::from(1.0) + d);
+        }
+    }
+
+    // subnormals
+    for i in 0..112 {
+        test(DoubleFloat::from(f128::from_bits(1 << i)));
+        test(DoubleFloat::from(f128::from_bits((1 << (i + 1)) - 1)));
+    }
+
+    eprintln!("max ln error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_sin_cos() {
+    let mut max_sin_error: f64 = 0.0;
+    let mut max_cos_error: f64 = 0.0;
+
+    let mut rng = create_prng();
+    let mut test = |x: DoubleFloat<f128>| {
+        let (expected_sin, expected_cos) = dd_to_rug(x).sin_cos(rug::Float::new(DD_RUG_PREC));
+        let (actual_sin, actual_cos) = x.sin_cos();
+
+        let sin_err = calc_error_dd_ulp(actual_sin, expected_sin);
+        let cos_err = calc_error_dd_ulp(actual_cos, expected_cos);
+        max_sin_error = max_sin_error.max(sin_err);
+        max_cos_error = max_cos_error.max(cos_err);
+        assert!(
+            sin_err < 3.0,
+            "sin({}) = {} (error = {sin_err} ULP)",
+            fmt_dd(x),
+            fmt_dd(actual_sin),
+        );
+        assert!(
+            cos_err < 3.0,
+            "cos({}) = {} (error = {cos_err} ULP)",
+            fmt_dd(x),
+            fmt_dd(actual_cos),
+        );
+    };
+
+    for e in -120..=100 {
+        for _ in 0..200 {
+            let s = rng.random::<bool>();
+            test(mkdouble(&mut rng, e, s));
+        }
+    }
+    for e in 101..=16383 {
+        for _ in 0..5 {
+            let s = rng.random::<bool>();
+            test(mkdouble(&mut rng, e, s));
+        }
+    }
+
+    eprintln!("max sin error = {max_sin_error}");
+    eprintln!("max cos error = {max_cos_error}");
+    assert!(max_sin_error > 0.5);
+    assert!(max_cos_error > 0.5);
+}
+
+/// Creates a random double-float with exponent `e` and sign `s`
+fn mkdouble(rng: &mut impl rand::Rng, e: i16, s: bool) -> DoubleFloat<f128> {
+    let hi = mkfloat(rng.random::<u128>(), e, s);
+    let lo = hi * (rng.random::<f64>() as f128 - 0.5) * f128::EPSILON;
+    DoubleFloat::new(hi, lo)
+}
+
+fn dd_to_rug(x: DoubleFloat<f128>) -> rug::Float {
+    let hi = to_rug(113, x.hi());
+    let lo = to_rug(113, x.lo());
+    // Use enough precision to represent the sum exactly
+    let prec = match (hi.get_exp(), lo.get_exp()) {
+        (Some(hi_exp), Some(lo_exp)) => hi_exp.abs_diff(lo_exp) + 113 + 1,
+        _ => 113,
+    };
+    rug::Float::with_val(prec.max(DD_RUG_PREC), hi) + lo
+}
+
+/// Calculates the error in units of 2^-225 relative to the
+/// exponent of `expected`
+fn calc_error_dd_ulp(actual: DoubleFloat<f128>, expected: rug::Float) -> f64 {
+    match expected.classify() {
+        std::num::FpCategory::Nan => {
+            if actual.hi().is_nan() {
+                0.0
+            } else {
+                f64::INFINITY
+            }
+        }
+        std::num::FpCategory::Zero => {
+            if actual.hi() == 0.0 {
+                0.0
+            } else {
+                f64::INFINITY
+            }
+        }
+        _ => {
+            let exp = expected.get_exp().unwrap() - 1;
+            let dif = (expected - dd_to_rug(actual)).abs() >> (exp - 225);
+            dif.to_f64()
+        }
+    }
+}
+
+fn fmt_dd(x: DoubleFloat<f128>) -> String {
+    format!("({}, {})", fmt(x.hi()), fmt(x.lo()))
+}
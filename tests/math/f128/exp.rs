@@ -0,0 +1,184 @@
+use rand::RngExt as _;
+
+use super::{
+    HI_LO_RUG_PREC, RUG_PREC, calc_error_hi_lo_ulp, calc_error_ulp, fmt, mkfloat, select_threshold,
+    to_rug,
+};
+use crate::create_prng;
+
+#[test]
+fn test_exp() {
+    let mut max_exp_error: f64 = 0.0;
+    let mut max_expm1_error: f64 = 0.0;
+    test_with(|x| {
+        let expected_exp = to_rug(RUG_PREC, x).exp();
+        let expected_expm1 = to_rug(RUG_PREC, x).exp_m1();
+
+        let actual_exp = fpmath::exp(x);
+        let actual_expm1 = fpmath::exp_m1(x);
+
+        let exp_err = calc_error_ulp(actual_exp, expected_exp);
+        let expm1_err = calc_error_ulp(actual_expm1, expected_expm1);
+
+        max_exp_error = max_exp_error.max(exp_err);
+        max_expm1_error = max_expm1_error.max(expm1_err);
+
+        let exp_threshold = select_threshold(actual_exp, 0.9, 1.9);
+        assert!(
+            exp_err < exp_threshold,
+            "exp({}) = {} (error = {exp_err} ULP)",
+            fmt(x),
+            fmt(actual_exp),
+        );
+
+        let expm1_threshold = select_threshold(actual_expm1, 0.9, 1.9);
+        assert!(
+            expm1_err < expm1_threshold,
+            "expm1({}) = {} (error = {expm1_err} ULP)",
+            fmt(x),
+            fmt(actual_expm1),
+        );
+    });
+    eprintln!("max exp error = {max_exp_error}");
+    eprintln!("max expm1 error = {max_expm1_error}");
+    assert!(max_exp_error > 0.5);
+    assert!(max_expm1_error > 0.5);
+}
+
+#[test]
+fn test_exp_hi_lo() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = to_rug(HI_LO_RUG_PREC, x).exp();
+        let actual = fpmath::exp_hi_lo(x);
+
+        let err = calc_error_hi_lo_ulp(actual, expected);
+
+        // `lo` loses precision close to the subnormal range
+        let threshold = if actual.0 < f128::MIN_POSITIVE / f128::EPSILON {
+            1.0
+        } else {
+            max_error = max_error.max(err);
+            0.07
+        };
+        assert!(
+            err < threshold,
+            "exp_hi_lo({}) = ({}, {}) (error = {err} ULP)",
+            fmt(x),
+            fmt(actual.0),
+            fmt(actual.1),
+        );
+    });
+    eprintln!("max exp_hi_lo error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_exp2() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = to_rug(RUG_PREC, x).exp2();
+        let actual = fpmath::exp2(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "exp2({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual),
+        );
+    });
+    eprintln!("max exp2 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_exp10() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = to_rug(RUG_PREC, x).exp10();
+        let actual = fpmath::exp10(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "exp10({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual),
+        );
+    });
+    eprintln!("max exp10 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_exp2_m1() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = to_rug(RUG_PREC, x).exp2_m1();
+        let actual = fpmath::exp2_m1(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "exp2_m1({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual),
+        );
+    });
+    eprintln!("max exp2_m1 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_exp10_m1() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = to_rug(RUG_PREC, x).exp10_m1();
+        let actual = fpmath::exp10_m1(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "exp10_m1({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual),
+        );
+    });
+    eprintln!("max exp10_m1 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_with(mut f: impl FnMut(f128)) {
+    let mut rng = create_prng();
+
+    for e in -16382..=14 {
+        f(mkfloat(0, e, false));
+        f(mkfloat(0, e, true));
+        f(mkfloat(u128::MAX, e, false));
+        f(mkfloat(u128::MAX, e, true));
+
+        let n = if e >= -120 { 2000 } else { 10 };
+        for _ in 0..n {
+            let m = rng.random::<u128>();
+            let s = rng.random::<bool>();
+            f(mkfloat(m, e, s));
+        }
+    }
+
+    for arg in -16600..=16600 {
+        f(arg as f128);
+    }
+}
@@ -0,0 +1,80 @@
+use rand::RngExt as _;
+use rug::float::Round;
+
+use super::{fmt, from_rug, mkfloat, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_fma() {
+    let mut rng = create_prng();
+
+    for _ in 0..200_000 {
+        let ex: i16 = rng.random_range(-16383..=16383);
+        let ey: i16 = rng.random_range(-16383..=16383);
+        let ez = i32::from(ex) + i32::from(ey) + rng.random_range(-150..=150);
+        let ez = ez.clamp(-16383, 16383) as i16;
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        let z = mkfloat(rng.random(), ez, rng.random());
+        check(x, y, z);
+
+        // cancellation of the highest bits
+        let z = -(x * y);
+        if z.is_finite() {
+            check(x, y, z);
+            check(x, y, f128::from_bits(z.to_bits() + 1));
+        }
+    }
+
+    // results close to or in the subnormal range
+    for _ in 0..100_000 {
+        let ex = rng.random_range(-16383..=-120);
+        let ey = (-16494 - ex + rng.random_range(-20..=20)).clamp(-16383, 16383);
+        let ez = rng.random_range(-16383..=-16300);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        let z = mkfloat(rng.random(), ez, rng.random());
+        check(x, y, z);
+        check(x, y, 0.0);
+        check(x, y, -0.0);
+    }
+
+    let special = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        f128::from_bits(1),
+        f128::MIN_POSITIVE,
+        f128::MAX,
+        f128::INFINITY,
+        f128::NEG_INFINITY,
+        f128::NAN,
+    ];
+    for x in special {
+        for y in special {
+            for z in special {
+                check(x, y, z);
+            }
+        }
+    }
+}
+
+fn check(x: f128, y: f128, z: f128) {
+    let mut expected = to_rug(113, x);
+    let dir = expected.mul_add_round(&to_rug(113, y), &to_rug(113, z), Round::Nearest);
+    expected.subnormalize_ieee_round(dir, Round::Nearest);
+    // exact
+    let expected = from_rug(&expected);
+
+    let actual = fpmath::fma(x, y, z);
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "fma({}, {}, {}) = {}, expected {}",
+        fmt(x),
+        fmt(y),
+        fmt(z),
+        fmt(actual),
+        fmt(expected),
+    );
+}
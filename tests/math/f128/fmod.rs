@@ -0,0 +1,101 @@
+use rand::RngExt as _;
+
+use super::{fmt, from_rug, mkfloat, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_fmod() {
+    test_with(|x, y| {
+        let expected = from_rug(&(to_rug(113, x) % to_rug(113, y)));
+        let actual = fpmath::fmod(x, y);
+        assert!(
+            same_result(actual, expected),
+            "fmod({}, {}) = {}, expected {}",
+            fmt(x),
+            fmt(y),
+            fmt(actual),
+            fmt(expected),
+        );
+    });
+}
+
+#[test]
+fn test_remainder() {
+    test_with(|x, y| {
+        let (expected, expected_quo) = to_rug(113, x).remainder_quo31(&to_rug(113, y));
+        let expected = from_rug(&expected);
+
+        let actual = fpmath::remainder(x, y);
+        assert!(
+            same_result(actual, expected),
+            "remainder({}, {}) = {}, expected {}",
+            fmt(x),
+            fmt(y),
+            fmt(actual),
+            fmt(expected),
+        );
+
+        let (actual, actual_quo) = fpmath::remquo(x, y);
+        assert!(
+            same_result(actual, expected) && (actual_quo == expected_quo || expected.is_nan()),
+            "remquo({}, {}) = ({}, {actual_quo}), expected ({}, {expected_quo})",
+            fmt(x),
+            fmt(y),
+            fmt(actual),
+            fmt(expected),
+        );
+    });
+}
+
+fn same_result(actual: f128, expected: f128) -> bool {
+    actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan())
+}
+
+fn test_with(mut f: impl FnMut(f128, f128)) {
+    let mut rng = create_prng();
+
+    for _ in 0..20000 {
+        let ex: i16 = rng.random_range(-16383..=16383);
+        let ey = rng.random_range(-16383..=16383);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        f(x, y);
+    }
+
+    // close exponents
+    for _ in 0..20000 {
+        let ey = rng.random_range(-16383..=16340);
+        let ex = ey + rng.random_range(-2..=40);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        f(x, y);
+    }
+
+    // halfway cases
+    for _ in 0..20000 {
+        let ey = rng.random_range(-16000..=16000);
+        let n = rng.random_range(0..256);
+        let y = mkfloat(0, ey, rng.random());
+        let x = (n as f128 + 0.5) * y;
+        f(x, y);
+        f(-x, y);
+    }
+
+    let special = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        f128::from_bits(1),
+        f128::MIN_POSITIVE,
+        f128::MAX,
+        f128::INFINITY,
+        f128::NEG_INFINITY,
+        f128::NAN,
+    ];
+    for x in special {
+        for y in special {
+            f(x, y);
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use rand::RngExt as _;
+
+use super::{RUG_PREC, calc_error_ulp, fmt, mkfloat, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_sinh_cosh() {
+    let mut max_sin1_error: f64 = 0.0;
+    let mut max_sin2_error: f64 = 0.0;
+    let mut max_cos1_error: f64 = 0.0;
+    let mut max_cos2_error: f64 = 0.0;
+    test_with(|x| {
+        let (expected_sin, expected_cos) = to_rug(RUG_PREC, x).sinh_cosh(rug::Float::new(RUG_PREC));
+
+        let actual_sin1 = fpmath::sinh(x);
+        let actual_cos1 = fpmath::cosh(x);
+        let (actual_sin2, actual_cos2) = fpmath::sinh_cosh(x);
+        assert_eq!(fpmath::sinh(-x), -actual_sin1);
+        assert_eq!(fpmath::cosh(-x), actual_cos1);
+        assert_eq!(fpmath::sinh_cosh(-x), (-actual_sin2, actual_cos2));
+
+        let sin1_err = calc_error_ulp(actual_sin1, expected_sin.clone());
+        let sin2_err = calc_error_ulp(actual_sin2, expected_sin);
+        let cos1_err = calc_error_ulp(actual_cos1, expected_cos.clone());
+        let cos2_err = calc_error_ulp(actual_cos2, expected_cos);
+
+        max_sin1_error = max_sin1_error.max(sin1_err);
+        max_sin2_error = max_sin2_error.max(sin2_err);
+        max_cos1_error = max_cos1_error.max(cos1_err);
+        max_cos2_error = max_cos2_error.max(cos2_err);
+
+        assert!(
+            sin1_err < 0.9,
+            "sinh({}) = {} (error = {sin1_err} ULP)",
+            fmt(x),
+            fmt(actual_sin1),
+        );
+        assert!(
+            sin2_err < 0.9,
+            "sinh({}) = {} (error = {sin2_err} ULP)",
+            fmt(x),
+            fmt(actual_sin2),
+        );
+
+        assert!(
+            cos1_err < 0.9,
+            "cosh({}) = {} (error = {cos1_err} ULP)",
+            fmt(x),
+            fmt(actual_cos1),
+        );
+        assert!(
+            cos2_err < 0.9,
+            "cosh({}) = {} (error = {cos2_err} ULP)",
+            fmt(x),
+            fmt(actual_cos2),
+        );
+    });
+    eprintln!("max sinh1 error = {max_sin1_error}");
+    eprintln!("max sinh2 error = {max_sin2_error}");
+    eprintln!("max cosh1 error = {max_cos1_error}");
+    eprintln!("max cosh2 error = {max_cos2_error}");
+    assert!(max_sin1_error > 0.5);
+    assert!(max_sin2_error > 0.5);
+    assert!(max_cos1_error > 0.5);
+    assert!(max_cos2_error > 0.5);
+}
+
+#[test]
+fn test_tanh() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = to_rug(RUG_PREC, x).tanh();
+        let actual = fpmath::tanh(x);
+        assert_eq!(fpmath::tanh(-x), -actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "tanh({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual)
+        );
+    });
+    eprintln!("max tanh error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_with(mut f: impl FnMut(f128)) {
+    let mut rng = create_prng();
+
+    for e in -16382..-200 {
+        f(mkfloat(0, e, false));
+        f(mkfloat(u128::MAX, e, false));
+
+        for _ in 0..5 {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, false));
+        }
+    }
+    for e in -200..=14 {
+        f(mkfloat(0, e, false));
+        f(mkfloat(u128::MAX, e, false));
+
+        for _ in 0..500 {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, false));
+        }
+    }
+
+    for arg in 1..=12000 {
+        f(arg as f128);
+    }
+}
@@ -0,0 +1,75 @@
+use rand::RngExt as _;
+
+use super::{RUG_PREC, calc_error_ulp, fmt, mkfloat, select_threshold, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_hypot() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x, y| {
+        let expected = to_rug(RUG_PREC, x).hypot(&to_rug(RUG_PREC, y));
+        let actual = fpmath::hypot(x, y);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "hypot({}, {}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(y),
+            fmt(actual),
+        );
+    });
+    eprintln!("max hypot error = {max_error}");
+    assert!(max_error > 0.49);
+}
+
+fn test_with(mut f: impl FnMut(f128, f128)) {
+    let mut rng = create_prng();
+
+    for ex in (-16382..=16383).step_by(127) {
+        for ey in (-16382..=16383).step_by(127) {
+            for _ in 0..2 {
+                let mx = rng.random::<u128>();
+                let sx = rng.random::<bool>();
+                let my = rng.random::<u128>();
+                let sy = rng.random::<bool>();
+                f(mkfloat(mx, ex, sx), mkfloat(my, ey, sy));
+            }
+        }
+    }
+
+    for ex in -130..=130 {
+        for ey in -130..=130 {
+            let mx = rng.random::<u128>();
+            let sx = rng.random::<bool>();
+            let my = rng.random::<u128>();
+            let sy = rng.random::<bool>();
+            f(mkfloat(mx, ex, sx), mkfloat(my, ey, sy));
+        }
+    }
+
+    for e in -16382..=16383 {
+        for _ in 0..10 {
+            let mx = rng.random::<u128>();
+            let sx = rng.random::<bool>();
+            let my = rng.random::<u128>();
+            let sy = rng.random::<bool>();
+            f(mkfloat(mx, e, sx), mkfloat(my, e, sy));
+
+            let mx = rng.random::<u128>();
+            let sx = rng.random::<bool>();
+            let my = rng.random::<u128>();
+            let sy = rng.random::<bool>();
+            f(mkfloat(mx, 0, sx), mkfloat(my, e, sy));
+
+            let mx = rng.random::<u128>();
+            let sx = rng.random::<bool>();
+            let my = rng.random::<u128>();
+            let sy = rng.random::<bool>();
+            f(mkfloat(mx, e, sx), mkfloat(my, 0, sy));
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use rand::RngExt as _;
+
+use super::{RUG_PREC, calc_error_ulp, fmt, mkfloat, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_asinh() {
+    let mut max_error: f64 = 0.0;
+    test_asinh_with(|x| {
+        let expected = to_rug(RUG_PREC, x).asinh();
+        let actual = fpmath::asinh(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "asinh({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual)
+        );
+    });
+    eprintln!("max asinh error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_asinh_with(mut f: impl FnMut(f128)) {
+    let mut rng = create_prng();
+
+    for e in -100..=100 {
+        for _ in 0..1000 {
+            let m = rng.random::<u128>();
+            let s = rng.random::<bool>();
+            f(mkfloat(m, e, s));
+        }
+    }
+    for e in -16382..=16383 {
+        f(mkfloat(0, e, false));
+        f(mkfloat(0, e, true));
+        f(mkfloat(u128::MAX, e, false));
+        f(mkfloat(u128::MAX, e, true));
+
+        for _ in 0..10 {
+            let m = rng.random::<u128>();
+            let s = rng.random::<bool>();
+            f(mkfloat(m, e, s));
+        }
+    }
+}
+
+#[test]
+fn test_acosh() {
+    let mut max_error: f64 = 0.0;
+    test_acosh_with(|x| {
+        let expected = to_rug(RUG_PREC, x).acosh();
+        let actual = fpmath::acosh(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "acosh({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual)
+        );
+    });
+    eprintln!("max acosh error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_acosh_with(mut f: impl FnMut(f128)) {
+    let mut rng = create_prng();
+
+    for e in 0..=100 {
+        f(mkfloat(0, e, false));
+        f(mkfloat(u128::MAX, e, false));
+
+        for _ in 0..1000 {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, false));
+        }
+    }
+
+    for e in 0..=16383 {
+        f(mkfloat(0, e, false));
+        f(mkfloat(u128::MAX, e, false));
+
+        for _ in 0..20 {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, false));
+        }
+    }
+}
+
+#[test]
+fn test_atanh() {
+    let mut max_error: f64 = 0.0;
+    test_atanh_with(|x| {
+        let expected = to_rug(RUG_PREC, x).atanh();
+        let actual = fpmath::atanh(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "atanh({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual)
+        );
+    });
+    eprintln!("max atanh error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_atanh_with(mut f: impl FnMut(f128)) {
+    let mut rng = create_prng();
+
+    for e in -16382..=-1 {
+        f(mkfloat(0, e, false));
+        f(mkfloat(0, e, true));
+        f(mkfloat(u128::MAX, e, false));
+        f(mkfloat(u128::MAX, e, true));
+
+        let n = if e >= -120 { 2000 } else { 10 };
+        for _ in 0..n {
+            let m = rng.random::<u128>();
+            let s = rng.random::<bool>();
+            f(mkfloat(m, e, s));
+        }
+    }
+}
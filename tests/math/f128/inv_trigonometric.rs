@@ -0,0 +1,340 @@
+use rand::RngExt as _;
+
+use super::{RUG_PREC, calc_error_ulp, fmt, mkfloat, select_threshold, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_asin_acos() {
+    let mut max_asin_error: f64 = 0.0;
+    let mut max_acos_error: f64 = 0.0;
+    test_asin_acos_with(|x| {
+        let expected_asin = to_rug(RUG_PREC, x).asin();
+        let expected_acos = to_rug(RUG_PREC, x).acos();
+
+        let actual_asin = fpmath::asin(x);
+        let actual_acos = fpmath::acos(x);
+
+        let asin_err = calc_error_ulp(actual_asin, expected_asin);
+        let acos_err = calc_error_ulp(actual_acos, expected_acos);
+
+        max_asin_error = max_asin_error.max(asin_err);
+        max_acos_error = max_acos_error.max(acos_err);
+
+        let asin_threshold = select_threshold(actual_asin, 0.9, 1.9);
+        assert!(
+            asin_err < asin_threshold,
+            "asin({}) = {} (error = {asin_err} ULP)",
+            fmt(x),
+            fmt(actual_asin),
+        );
+
+        let acos_threshold = select_threshold(actual_acos, 0.9, 1.9);
+        assert!(
+            acos_err < acos_threshold,
+            "acos({}) = {} (error = {acos_err} ULP)",
+            fmt(x),
+            fmt(actual_acos),
+        );
+    });
+    eprintln!("max asin error = {max_asin_error}");
+    eprintln!("max acos error = {max_acos_error}");
+    assert!(max_asin_error > 0.5);
+    assert!(max_acos_error > 0.5);
+}
+
+#[test]
+fn test_asind_acosd() {
+    let mut max_asin_error: f64 = 0.0;
+    let mut max_acos_error: f64 = 0.0;
+    test_asin_acos_with(|x| {
+        let expected_asin = to_rug(RUG_PREC, x).asin_u(360);
+        let expected_acos = to_rug(RUG_PREC, x).acos_u(360);
+
+        let actual_asin = fpmath::asind(x);
+        let actual_acos = fpmath::acosd(x);
+
+        let asin_err = calc_error_ulp(actual_asin, expected_asin);
+        let acos_err = calc_error_ulp(actual_acos, expected_acos);
+
+        max_asin_error = max_asin_error.max(asin_err);
+        max_acos_error = max_acos_error.max(acos_err);
+
+        let asin_threshold = select_threshold(actual_asin, 0.9, 1.9);
+        assert!(
+            asin_err < asin_threshold,
+            "asind({}) = {} (error = {asin_err} ULP)",
+            fmt(x),
+            fmt(actual_asin),
+        );
+
+        let acos_threshold = select_threshold(actual_acos, 0.9, 1.9);
+        assert!(
+            acos_err < acos_threshold,
+            "acosd({}) = {} (error = {acos_err} ULP)",
+            fmt(x),
+            fmt(actual_acos),
+        );
+    });
+    eprintln!("max asind error = {max_asin_error}");
+    eprintln!("max acosd error = {max_acos_error}");
+    assert!(max_asin_error > 0.5);
+    assert!(max_acos_error > 0.5);
+}
+
+#[test]
+fn test_asinpi_acospi() {
+    let mut max_asin_error: f64 = 0.0;
+    let mut max_acos_error: f64 = 0.0;
+    test_asin_acos_with(|x| {
+        let expected_asin = to_rug(RUG_PREC, x).asin_pi();
+        let expected_acos = to_rug(RUG_PREC, x).acos_pi();
+
+        let actual_asin = fpmath::asinpi(x);
+        let actual_acos = fpmath::acospi(x);
+
+        let asin_err = calc_error_ulp(actual_asin, expected_asin);
+        let acos_err = calc_error_ulp(actual_acos, expected_acos);
+
+        max_asin_error = max_asin_error.max(asin_err);
+        max_acos_error = max_acos_error.max(acos_err);
+
+        let asin_threshold = select_threshold(actual_asin, 0.9, 1.9);
+        assert!(
+            asin_err < asin_threshold,
+            "asinpi({}) = {} (error = {asin_err} ULP)",
+            fmt(x),
+            fmt(actual_asin),
+        );
+
+        let acos_threshold = select_threshold(actual_acos, 0.9, 1.9);
+        assert!(
+            acos_err < acos_threshold,
+            "acospi({}) = {} (error = {acos_err} ULP)",
+            fmt(x),
+            fmt(actual_acos),
+        );
+    });
+    eprintln!("max asinpi error = {max_asin_error}");
+    eprintln!("max acospi error = {max_acos_error}");
+    assert!(max_asin_error > 0.5);
+    assert!(max_acos_error > 0.5);
+}
+
+fn test_asin_acos_with(mut f: impl FnMut(f128)) {
+    let mut rng = create_prng();
+
+    for e in -16382..=-1 {
+        f(mkfloat(0, e, false));
+        f(mkfloat(0, e, true));
+        f(mkfloat(u128::MAX, e, false));
+        f(mkfloat(u128::MAX, e, true));
+
+        let n = if e >= -120 { 2000 } else { 10 };
+        for _ in 0..n {
+            let m = rng.random::<u128>();
+            let s = rng.random::<bool>();
+            f(mkfloat(m, e, s));
+        }
+    }
+
+    for i in 1..=1000 {
+        let x = (i as f128) / 1000.0;
+        f(x);
+        f(-x);
+    }
+}
+
+#[test]
+fn test_atan() {
+    let mut max_error: f64 = 0.0;
+    test_atan_with(|x| {
+        let expected = to_rug(RUG_PREC, x).atan();
+        let actual = fpmath::atan(x);
+        assert_eq!(fpmath::atan(-x), -actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "atan({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual)
+        );
+    });
+    eprintln!("max atan error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_atand() {
+    let mut max_error: f64 = 0.0;
+    test_atan_with(|x| {
+        let expected = to_rug(RUG_PREC, x).atan_u(360);
+        let actual = fpmath::atand(x);
+        assert_eq!(fpmath::atand(-x), -actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "atand({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual)
+        );
+    });
+    eprintln!("max atand error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_atanpi() {
+    let mut max_error: f64 = 0.0;
+    test_atan_with(|x| {
+        let expected = to_rug(RUG_PREC, x).atan_pi();
+        let actual = fpmath::atanpi(x);
+        assert_eq!(fpmath::atanpi(-x), -actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "atanpi({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual),
+        );
+    });
+    eprintln!("max atanpi error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_atan_with(mut f: impl FnMut(f128)) {
+    let mut rng = create_prng();
+
+    for e in -16382..=16383 {
+        f(mkfloat(0, e, false));
+        f(mkfloat(u128::MAX, e, false));
+
+        let n = if matches!(e, -120..=120) { 1000 } else { 10 };
+        for _ in 0..n {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, false));
+        }
+    }
+}
+
+#[test]
+fn test_atan2() {
+    let mut max_error: f64 = 0.0;
+    test_atan2_with(|y, x| {
+        let expected = to_rug(RUG_PREC, y).atan2(&to_rug(RUG_PREC, x));
+        let actual = fpmath::atan2(y, x);
+        assert_eq!(fpmath::atan2(-y, x), -actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "atan2({}, {}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(y),
+            fmt(actual),
+        );
+    });
+    eprintln!("max atan2 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_atan2d() {
+    let mut max_error: f64 = 0.0;
+    test_atan2_with(|y, x| {
+        let expected = to_rug(RUG_PREC, y).atan2_u(&to_rug(RUG_PREC, x), 360);
+        let actual = fpmath::atan2d(y, x);
+        assert_eq!(fpmath::atan2d(-y, x), -actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "atan2d({}, {}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(y),
+            fmt(actual),
+        );
+    });
+    eprintln!("max atan2d error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_atan2pi() {
+    let mut max_error: f64 = 0.0;
+    test_atan2_with(|y, x| {
+        let expected = to_rug(RUG_PREC, y).atan2_pi(&to_rug(RUG_PREC, x));
+        let actual = fpmath::atan2pi(y, x);
+        assert_eq!(fpmath::atan2pi(-y, x), -actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.95, 1.9);
+        assert!(
+            err < threshold,
+            "atan2pi({}, {}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(y),
+            fmt(actual),
+        );
+    });
+    eprintln!("max atan2pi error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_atan2_with(mut f: impl FnMut(f128, f128)) {
+    let mut rng = create_prng();
+
+    for ey in (-16382..=16383).step_by(127) {
+        for ex in (-16382..=16383).step_by(127) {
+            let my = rng.random::<u128>();
+            let mx = rng.random::<u128>();
+            f(mkfloat(my, ey, false), mkfloat(mx, ex, false));
+            f(mkfloat(my, ey, false), mkfloat(mx, ex, true));
+        }
+    }
+
+    for ey in -150..=150 {
+        for ex in -150..=150 {
+            let my = rng.random::<u128>();
+            let mx = rng.random::<u128>();
+            f(mkfloat(my, ey, false), mkfloat(mx, ex, false));
+            f(mkfloat(my, ey, false), mkfloat(mx, ex, true));
+        }
+    }
+
+    for e in -16382..=16383 {
+        for _ in 0..10 {
+            let my = rng.random::<u128>();
+            let mx = rng.random::<u128>();
+            let sx = rng.random::<bool>();
+            f(mkfloat(my, e, false), mkfloat(mx, e, sx));
+
+            let my = rng.random::<u128>();
+            let mx = rng.random::<u128>();
+            let sx = rng.random::<bool>();
+            f(mkfloat(my, 0, false), mkfloat(mx, e, sx));
+
+            let my = rng.random::<u128>();
+            let mx = rng.random::<u128>();
+            let sx = rng.random::<bool>();
+            f(mkfloat(my, e, false), mkfloat(mx, 0, sx));
+        }
+    }
+}
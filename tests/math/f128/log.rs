@@ -0,0 +1,254 @@
+use rand::RngExt as _;
+
+use super::{HI_LO_RUG_PREC, RUG_PREC, calc_error_hi_lo_ulp, calc_error_ulp, fmt, mkfloat, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_ln() {
+    let mut max_error: f64 = 0.0;
+    test_log_with(|x| {
+        let expected = to_rug(RUG_PREC, x).ln();
+        let actual = fpmath::ln(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "ln({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual)
+        );
+    });
+    eprintln!("max log error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_ln_1p() {
+    let mut max_error: f64 = 0.0;
+    test_log1p_with(|x| {
+        let expected = to_rug(RUG_PREC, x).ln_1p();
+        let actual = fpmath::ln_1p(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "ln_1p({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual)
+        );
+    });
+    eprintln!("max ln_1p error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_ln_hi_lo() {
+    let mut max_error: f64 = 0.0;
+    test_log_with(|x| {
+        let expected = to_rug(HI_LO_RUG_PREC, x).ln();
+        let actual = fpmath::ln_hi_lo(x);
+
+        let err = calc_error_hi_lo_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.001,
+            "ln_hi_lo({}) = ({}, {}) (error = {err} ULP)",
+            fmt(x),
+            fmt(actual.0),
+            fmt(actual.1),
+        );
+    });
+    eprintln!("max ln_hi_lo error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_ln_1p_hi_lo() {
+    let mut max_error: f64 = 0.0;
+    test_log1p_with(|x| {
+        let expected = to_rug(HI_LO_RUG_PREC, x).ln_1p();
+        let actual = fpmath::ln_1p_hi_lo(x);
+
+        let err = calc_error_hi_lo_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.001,
+            "ln_1p_hi_lo({}) = ({}, {}) (error = {err} ULP)",
+            fmt(x),
+            fmt(actual.0),
+            fmt(actual.1),
+        );
+    });
+    eprintln!("max ln_1p_hi_lo error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_log2() {
+    let mut max_error: f64 = 0.0;
+    test_log_with(|x| {
+        let expected = to_rug(RUG_PREC, x).log2();
+        let actual = fpmath::log2(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "log2({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual)
+        );
+    });
+    eprintln!("max log2 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_log10() {
+    let mut max_error: f64 = 0.0;
+    test_log_with(|x| {
+        let expected = to_rug(RUG_PREC, x).log10();
+        let actual = fpmath::log10(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "log10({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual)
+        );
+    });
+    eprintln!("max log10 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_log2_1p() {
+    let mut max_error: f64 = 0.0;
+    test_log1p_with(|x| {
+        let expected = to_rug(RUG_PREC, x).log2_1p();
+        let actual = fpmath::log2_1p(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "log2_1p({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual)
+        );
+    });
+    eprintln!("max log2_1p error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_log10_1p() {
+    let mut max_error: f64 = 0.0;
+    test_log1p_with(|x| {
+        let expected = to_rug(RUG_PREC, x).log10_1p();
+        let actual = fpmath::log10_1p(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "log10_1p({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual)
+        );
+    });
+    eprintln!("max log10_1p error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_log_with(mut f: impl FnMut(f128)) {
+    let mut rng = create_prng();
+
+    for e in -100..=100 {
+        f(mkfloat(0, e, false));
+        f(mkfloat(u128::MAX, e, false));
+
+        for _ in 0..1000 {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, false));
+        }
+    }
+    for e in -16382..=16383 {
+        for _ in 0..10 {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, false));
+        }
+    }
+
+    for arg in 1..=10000 {
+        f(arg as f128);
+    }
+
+    f(f128::MIN_POSITIVE);
+    f(f128::MAX);
+
+    // subnormals
+    for i in 0..112 {
+        f(f128::from_bits(1 << i));
+        f(f128::from_bits((1 << (i + 1)) - 1));
+    }
+}
+
+fn test_log1p_with(mut f: impl FnMut(f128)) {
+    let mut rng = create_prng();
+
+    for e in -100..=100 {
+        f(mkfloat(0, e, false));
+        f(mkfloat(u128::MAX, e, false));
+
+        for _ in 0..1000 {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, false));
+        }
+    }
+    for e in -16382..=16383 {
+        for _ in 0..10 {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, false));
+        }
+    }
+
+    for arg in 1..=10000 {
+        f(arg as f128);
+    }
+
+    f(f128::MIN_POSITIVE);
+    f(f128::MAX);
+
+    // 1 < x < 0
+    for e in -16382..=-1 {
+        f(mkfloat(0, e, true));
+        f(mkfloat(u128::MAX, e, true));
+
+        let n = if e >= -120 { 1000 } else { 10 };
+        for _ in 0..n {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    // subnormals
+    for i in 0..112 {
+        f(f128::from_bits(1 << i));
+        f(-f128::from_bits(1 << i));
+        f(f128::from_bits((1 << (i + 1)) - 1));
+        f(-f128::from_bits((1 << (i + 1)) - 1));
+    }
+}
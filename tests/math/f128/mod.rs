@@ -0,0 +1,147 @@
+mod cbrt;
+mod complex;
+mod cr;
+mod double_float;
+mod exp;
+mod fma;
+mod fmod;
+mod hyperbolic;
+mod hypot;
+mod inv_hyperbolic;
+mod inv_trigonometric;
+mod log;
+mod pow;
+mod round;
+mod slice;
+mod sqrt;
+mod trigonometric;
+
+fn mkfloat(m: u128, e: i16, s: bool) -> f128 {
+    let m = m >> (128 - 112);
+    let e = u128::from((e + 16383) as u16) << 112;
+    let s = u128::from(s) << 127;
+    f128::from_bits(m | e | s)
+}
+
+fn select_threshold(actual: f128, normal_th: f64, subnormal_th: f64) -> f64 {
+    if actual == 0.0 || actual.is_subnormal() {
+        subnormal_th
+    } else {
+        normal_th
+    }
+}
+
+const RUG_PREC: u32 = 113 + 20;
+const HI_LO_RUG_PREC: u32 = 113 * 2 + 20;
+
+/// Converts `x` to a `rug::Float` with precision `prec`
+///
+/// `rug` does not support `f128`, so the value is built from its bits.
+fn to_rug(prec: u32, x: f128) -> rug::Float {
+    let bits = x.to_bits();
+    let sign = (bits >> 127) != 0;
+    let raw_exp = ((bits >> 112) & 0x7FFF) as i32;
+    let raw_mant = bits & ((1 << 112) - 1);
+
+    let abs = if raw_exp == 0x7FFF {
+        if raw_mant == 0 {
+            rug::Float::with_val(prec, rug::float::Special::Infinity)
+        } else {
+            rug::Float::with_val(prec, rug::float::Special::Nan)
+        }
+    } else if raw_exp == 0 {
+        rug::Float::with_val(prec, raw_mant) >> 16494
+    } else {
+        rug::Float::with_val(prec, raw_mant | (1 << 112)) >> (16495 - raw_exp)
+    };
+
+    if sign { -abs } else { abs }
+}
+
+/// Rounds `x` to the nearest `f128`
+fn from_rug(x: &rug::Float) -> f128 {
+    let (mut y, dir) = rug::Float::with_val_round(113, x, rug::float::Round::Nearest);
+    y.subnormalize_ieee_round(dir, rug::float::Round::Nearest);
+
+    let abs = if y.is_nan() {
+        return f128::NAN;
+    } else if y.is_infinite() || y.get_exp().is_some_and(|e| e > 16384) {
+        f128::INFINITY
+    } else if y.is_zero() {
+        0.0
+    } else {
+        let (m, e) = y.to_integer_exp().unwrap();
+        fpmath::scalbn(m.abs().to_u128().unwrap() as f128, e)
+    };
+
+    if y.is_sign_negative() { -abs } else { abs }
+}
+
+/// Formats `x` in decimal, for error messages
+fn fmt(x: f128) -> String {
+    if x.is_nan() {
+        "NaN".into()
+    } else {
+        to_rug(113, x).to_string_radix(10, Some(36))
+    }
+}
+
+fn calc_error_ulp(actual: f128, expected: rug::Float) -> f64 {
+    match expected.classify() {
+        std::num::FpCategory::Nan => {
+            if actual.is_nan() {
+                0.0
+            } else {
+                f64::INFINITY
+            }
+        }
+        std::num::FpCategory::Infinite => {
+            if actual.is_infinite() && actual.is_sign_positive() == expected.is_sign_positive() {
+                0.0
+            } else {
+                f64::INFINITY
+            }
+        }
+        std::num::FpCategory::Subnormal => unreachable!(),
+        _ if actual.is_infinite() => {
+            if expected.get_exp().is_some_and(|e| e > 16383)
+                && actual.is_sign_positive() == expected.is_sign_positive()
+            {
+                0.0
+            } else {
+                f64::INFINITY
+            }
+        }
+        _ if actual.is_nan() => f64::INFINITY,
+        _ => {
+            let exp = expected
+                .get_exp()
+                .map(|e| (e - 1).max(-16382))
+                .unwrap_or(-16382);
+            let dif = (expected - to_rug(113, actual)).abs() >> (exp - 112);
+            dif.to_f64()
+        }
+    }
+}
+
+/// Calculates the error of `hi + lo` in ULP of `hi`
+///
+/// `(hi, lo)` must be normalized.
+fn calc_error_hi_lo_ulp((hi, lo): (f128, f128), expected: rug::Float) -> f64 {
+    if !hi.is_finite() || hi == 0.0 {
+        if lo == 0.0 {
+            calc_error_ulp(hi, expected)
+        } else {
+            f64::INFINITY
+        }
+    } else {
+        assert_eq!(hi + lo, hi, "({}, {}) is not normalized", fmt(hi), fmt(lo),);
+        let exp = expected
+            .get_exp()
+            .map(|e| (e - 1).max(-16382))
+            .unwrap_or(-16382);
+        let actual = to_rug(HI_LO_RUG_PREC, hi) + to_rug(113, lo);
+        let dif = (expected - actual).abs() >> (exp - 112);
+        dif.to_f64()
+    }
+}
@@ -0,0 +1,366 @@
+use rand::RngExt as _;
+
+use super::{RUG_PREC, calc_error_ulp, fmt, mkfloat, select_threshold, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_pow() {
+    let mut max_error: f64 = 0.0;
+    test_pow_with(|x, y| {
+        let bigx = to_rug(113, x);
+        let bigy = to_rug(113, y);
+        let expected = rug::Float::with_val(RUG_PREC, rug::ops::Pow::pow(&bigx, &bigy));
+        let actual = fpmath::pow(x, y);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "pow({}, {}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(y),
+            fmt(actual),
+        );
+    });
+    eprintln!("max pow error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_pow_with(mut f: impl FnMut(f128, f128)) {
+    let mut rng = create_prng();
+
+    // x = sx * mx * 2^ex
+    // log2(|x|) = log2(mx) + ex
+    // ex <= log2(|x|) <= ex + 1
+
+    // MIN <= |x|^y <= MAX
+    // log2(MIN) / log2(|x|) <= y <= log2(MAX) / log2(|x|)
+
+    for ex in -16382..=16383 {
+        if !matches!(ex, -64..=64) && ex % 17 != 0 {
+            continue; // speed up tests
+        }
+        let (min_y, max_y) = if ex == 0 {
+            (-16382, 16383)
+        } else {
+            let a = 16383 / ex;
+            let b = -a;
+            ((a.min(b) - 3).max(-16382), (a.max(b) + 3).min(16383))
+        };
+        let step = ((max_y - min_y) / 200).max(1);
+
+        for yi in (min_y..=max_y).step_by(step as usize) {
+            for _ in 0..5 {
+                let mx = rng.random::<u128>();
+                let sx = false;
+                let x = mkfloat(mx, ex, sx);
+
+                let y = (rng.random::<f64>() as f128 - 0.5) + (yi as f128);
+                f(x, y);
+            }
+        }
+    }
+
+    for ex in -111..=-1 {
+        for ey in 1..=130 {
+            for _ in 0..5 {
+                let mx = rng.random::<u128>();
+                let sx = rng.random::<bool>();
+                let my = rng.random::<u128>();
+                let sy = rng.random::<bool>();
+                f(1.0 + mkfloat(mx, ex, sx), mkfloat(my, ey, sy));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_powi() {
+    let mut max_error: f64 = 0.0;
+    test_powi_with(|x, y| {
+        let bigx = to_rug(113, x);
+        let expected = rug::Float::with_val(RUG_PREC, rug::ops::Pow::pow(&bigx, y));
+        let actual = fpmath::powi(x, y);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "powi({}, {y}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual),
+        );
+    });
+    eprintln!("max pow error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_powi_with(mut f: impl FnMut(f128, i32)) {
+    let mut rng = create_prng();
+
+    for ex in -16382..=16383 {
+        if !matches!(ex, -64..=64) && ex % 17 != 0 {
+            continue; // speed up tests
+        }
+        let (min_y, max_y) = if ex == 0 {
+            (-16382, 16383)
+        } else {
+            let a = 16383 / ex;
+            let b = -a;
+            ((a.min(b) - 3).max(-16382), (a.max(b) + 3).min(16383))
+        };
+        let step = ((max_y - min_y) / 200).max(1);
+
+        for y in (min_y..=max_y).step_by(step as usize) {
+            for _ in 0..5 {
+                let mx = rng.random::<u128>();
+                let sx = false;
+                let x = mkfloat(mx, ex, sx);
+
+                f(x, i32::from(y));
+            }
+        }
+    }
+
+    for ex in -111..=-1 {
+        for i in (1..=31).rev() {
+            for _ in 0..20 {
+                let mx = rng.random::<u128>();
+                let sx = rng.random::<bool>();
+                let x = 1.0 + mkfloat(mx, ex, sx);
+                let y = ((rng.random::<u32>() | 0x8000_0000) >> i) as i32;
+
+                f(x, y);
+                f(x, -y);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_powr() {
+    let mut max_error: f64 = 0.0;
+    test_pow_with(|x, y| {
+        let bigx = to_rug(113, x);
+        let bigy = to_rug(113, y);
+        let expected = rug::Float::with_val(RUG_PREC, rug::ops::Pow::pow(&bigx, &bigy));
+        let actual = fpmath::powr(x, y);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "powr({}, {}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(y),
+            fmt(actual),
+        );
+    });
+    eprintln!("max powr error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_pown() {
+    let mut max_error: f64 = 0.0;
+    test_pown_with(|x, y| {
+        let bigx = to_rug(113, x);
+        let expected = rug::Float::with_val(RUG_PREC, rug::ops::Pow::pow(&bigx, y));
+        let actual = fpmath::pown(x, y);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "pown({}, {y}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual),
+        );
+    });
+    eprintln!("max pown error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_pown_with(mut f: impl FnMut(f128, i64)) {
+    let mut rng = create_prng();
+
+    test_powi_with(|x, y| f(x, i64::from(y)));
+
+    // exponents that do not fit in `i32`
+    for ex in -111..=-1 {
+        for i in (1..=31).rev() {
+            for _ in 0..5 {
+                let mx = rng.random::<u128>();
+                let sx = rng.random::<bool>();
+                let x = 1.0 + mkfloat(mx, ex, sx);
+                let y = ((rng.random::<u64>() | 0x8000_0000_0000_0000) >> i) as i64;
+
+                f(x, y);
+                f(x, -y);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rootn() {
+    let mut max_error: f64 = 0.0;
+    test_rootn_with(|x, n| {
+        let bigx = to_rug(113, x);
+        let expected = if let Ok(n) = i32::try_from(n) {
+            rug::Float::with_val(RUG_PREC, bigx.root_i_ref(n))
+        } else if x < 0.0 && n % 2 == 0 {
+            rug::Float::with_val(RUG_PREC, f64::NAN)
+        } else {
+            // |x|^(1/n), where the error of 1/n is negligible
+            let inv_n = rug::Float::with_val(RUG_PREC * 2, n).recip();
+            let absx = to_rug(113, x.abs());
+            let r = rug::Float::with_val(RUG_PREC, rug::ops::Pow::pow(&absx, &inv_n));
+            if x < 0.0 { -r } else { r }
+        };
+        let actual = fpmath::rootn(x, n);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "rootn({}, {n}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual),
+        );
+    });
+    eprintln!("max rootn error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_rootn_with(mut f: impl FnMut(f128, i64)) {
+    let mut rng = create_prng();
+
+    let ns = [
+        -1,
+        1,
+        2,
+        -2,
+        3,
+        -3,
+        4,
+        5,
+        -5,
+        7,
+        17,
+        -100,
+        123456,
+        -(1 << 30) - 1,
+        (1 << 40) + 1,
+        i64::MAX,
+    ];
+
+    for e in -16382..=16383 {
+        if !matches!(e, -64..=64) && e % 17 != 0 {
+            continue; // speed up tests
+        }
+        for _ in 0..2 {
+            let m = rng.random::<u128>();
+            let s = rng.random::<bool>();
+            let x = mkfloat(m, e, s);
+            for &n in ns.iter() {
+                f(x, n);
+            }
+        }
+    }
+
+    // subnormals
+    for i in (0..112).step_by(7) {
+        for &n in ns.iter() {
+            f(f128::from_bits(1 << i), n);
+            f(-f128::from_bits((1 << (i + 1)) - 1), n);
+        }
+    }
+}
+
+#[test]
+fn test_compound() {
+    let mut max_error: f64 = 0.0;
+    test_compound_with(|x, n| {
+        let expected = if x < -1.0 {
+            rug::Float::with_val(RUG_PREC, f64::NAN)
+        } else {
+            // 1 + x is exact with this precision
+            let xp1 = to_rug(1024, x) + 1;
+            rug::Float::with_val(RUG_PREC, rug::ops::Pow::pow(&xp1, n))
+        };
+        let actual = fpmath::compound(x, n);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "compound({}, {n}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual),
+        );
+    });
+    eprintln!("max compound error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_compound_with(mut f: impl FnMut(f128, i64)) {
+    let mut rng = create_prng();
+
+    // tiny rates over many periods
+    for ex in -130..=-1 {
+        for i in (1..=63).step_by(3) {
+            for _ in 0..5 {
+                let mx = rng.random::<u128>();
+                let sx = rng.random::<bool>();
+                let x = mkfloat(mx, ex, sx);
+                let n = ((rng.random::<u64>() | 0x8000_0000_0000_0000) >> i) as i64;
+
+                f(x, n);
+                f(x, -n);
+            }
+        }
+    }
+
+    // (1 + x)^n does not overflow or underflow when
+    // |n| <= 16382 / |log2(1 + x)|
+    for ex in -800..=800 {
+        for _ in 0..50 {
+            let mx = rng.random::<u128>();
+            let sx = ex < 0 && rng.random::<bool>();
+            let x = mkfloat(mx, ex, sx);
+            let log2_xp1 = fpmath::log2_1p(x as f64).abs();
+            let max_n = (16382.0 / log2_xp1).min(1e18) as i64 + 2;
+            let n = rng.random_range(-max_n..=max_n);
+
+            f(x, n);
+        }
+    }
+
+    // special cases
+    for x in [
+        -2.0,
+        -1.0,
+        -0.0,
+        0.0,
+        f128::INFINITY,
+        f128::NEG_INFINITY,
+        f128::NAN,
+    ] {
+        for n in [i64::MIN, -3, -2, -1, 0, 1, 2, 3, i64::MAX] {
+            f(x, n);
+        }
+    }
+}
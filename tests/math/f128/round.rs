@@ -0,0 +1,107 @@
+use super::{fmt, mkfloat, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_round() {
+    test_round_with(|arg| {
+        let expected = to_rug(128, arg).round();
+        let actual = fpmath::round(arg);
+
+        assert!(
+            expected == to_rug(113, actual),
+            "round({}) = {}",
+            fmt(arg),
+            fmt(actual)
+        );
+    });
+}
+
+#[test]
+fn test_floor() {
+    test_round_with(|arg| {
+        let expected = to_rug(128, arg).floor();
+        let actual = fpmath::floor(arg);
+
+        assert!(
+            expected == to_rug(113, actual),
+            "floor({}) = {}",
+            fmt(arg),
+            fmt(actual)
+        );
+    });
+}
+
+#[test]
+fn test_ceil() {
+    test_round_with(|arg| {
+        let expected = to_rug(128, arg).ceil();
+        let actual = fpmath::ceil(arg);
+
+        assert!(
+            expected == to_rug(113, actual),
+            "ceil({}) = {}",
+            fmt(arg),
+            fmt(actual)
+        );
+    });
+}
+
+#[test]
+fn test_trunc() {
+    test_round_with(|arg| {
+        let expected = to_rug(128, arg).trunc();
+        let actual = fpmath::trunc(arg);
+
+        assert!(
+            expected == to_rug(113, actual),
+            "trunc({}) = {}",
+            fmt(arg),
+            fmt(actual)
+        );
+    });
+}
+
+fn test_round_with(test_f: fn(f128)) {
+    use rand::RngExt as _;
+
+    let mut rng = create_prng();
+
+    for e in -16382..=16383 {
+        test_f(mkfloat(0, e, false));
+        test_f(mkfloat(0, e, true));
+        test_f(mkfloat(u128::MAX, e, false));
+        test_f(mkfloat(u128::MAX, e, true));
+
+        for _ in 0..20 {
+            let m = rng.random::<u128>();
+            test_f(mkfloat(m, e, true));
+            test_f(mkfloat(m, e, false));
+        }
+    }
+
+    for arg in 1..=100_000 {
+        let arg = arg as f128;
+        test_f(arg);
+        test_f(-arg);
+        test_f(arg + 0.25);
+        test_f(-arg + 0.25);
+        test_f(arg + 0.5);
+        test_f(-arg + 0.5);
+        test_f(arg + 0.75);
+        test_f(-arg + 0.75);
+    }
+
+    for e in 0..=112 {
+        for delta in -100..=100 {
+            let arg = mkfloat(0, e, false) + delta as f128;
+            test_f(arg);
+            test_f(-arg);
+            test_f(arg + 0.25);
+            test_f(-arg + 0.25);
+            test_f(arg + 0.5);
+            test_f(-arg + 0.5);
+            test_f(arg + 0.75);
+            test_f(-arg + 0.75);
+        }
+    }
+}
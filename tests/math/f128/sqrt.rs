@@ -0,0 +1,65 @@
+use rand::RngExt as _;
+
+use super::{fmt, mkfloat, to_rug};
+use crate::create_prng;
+
+#[test]
+fn test_sqrt() {
+    test_with(|x| {
+        let actual = fpmath::sqrt(x);
+        let expected = to_rug(113, x).sqrt();
+        assert!(
+            to_rug(113, actual) == expected,
+            "sqrt({}) = {} (expected {expected})",
+            fmt(x),
+            fmt(actual),
+        );
+    });
+}
+
+#[test]
+fn test_rsqrt() {
+    test_with(|x| {
+        let actual = fpmath::rsqrt(x);
+        let expected = to_rug(113, x).recip_sqrt();
+        assert!(
+            to_rug(113, actual) == expected,
+            "rsqrt({}) = {} (expected {expected})",
+            fmt(x),
+            fmt(actual),
+        );
+    });
+}
+
+fn test_with(mut f: impl FnMut(f128)) {
+    let mut rng = create_prng();
+
+    for e in -200..=200 {
+        f(mkfloat(0, e, false));
+        f(mkfloat(u128::MAX, e, false));
+
+        for _ in 0..2000 {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, false));
+        }
+    }
+    for e in -16382..=16383 {
+        for _ in 0..20 {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, false));
+        }
+    }
+
+    for arg in 1..=10000 {
+        f(arg as f128);
+    }
+
+    f(f128::MIN_POSITIVE);
+    f(f128::MAX);
+
+    // subnormals
+    for i in 0..112 {
+        f(f128::from_bits(1 << i));
+        f(f128::from_bits((1 << (i + 1)) - 1));
+    }
+}
@@ -0,0 +1,466 @@
+use rand::RngExt as _;
+
+use super::{
+    HI_LO_RUG_PREC, RUG_PREC, calc_error_hi_lo_ulp, calc_error_ulp, fmt, mkfloat, select_threshold,
+    to_rug,
+};
+use crate::create_prng;
+
+#[test]
+fn test_sin_cos() {
+    let mut max_sin1_error: f64 = 0.0;
+    let mut max_sin2_error: f64 = 0.0;
+    let mut max_cos1_error: f64 = 0.0;
+    let mut max_cos2_error: f64 = 0.0;
+    test_with(|x| {
+        let (expected_sin, expected_cos) = to_rug(RUG_PREC, x).sin_cos(rug::Float::new(RUG_PREC));
+
+        let actual_sin1 = fpmath::sin(x);
+        let actual_cos1 = fpmath::cos(x);
+        let (actual_sin2, actual_cos2) = fpmath::sin_cos(x);
+        assert_eq!(fpmath::sin(-x), -actual_sin1);
+        assert_eq!(fpmath::cos(-x), actual_cos1);
+        assert_eq!(fpmath::sin_cos(-x), (-actual_sin2, actual_cos2));
+
+        let sin1_err = calc_error_ulp(actual_sin1, expected_sin.clone());
+        let sin2_err = calc_error_ulp(actual_sin2, expected_sin);
+        let cos1_err = calc_error_ulp(actual_cos1, expected_cos.clone());
+        let cos2_err = calc_error_ulp(actual_cos2, expected_cos);
+
+        max_sin1_error = max_sin1_error.max(sin1_err);
+        max_sin2_error = max_sin2_error.max(sin2_err);
+        max_cos1_error = max_cos1_error.max(cos1_err);
+        max_cos2_error = max_cos2_error.max(cos2_err);
+
+        assert!(
+            sin1_err < 0.9,
+            "sin({}) = {} (error = {sin1_err} ULP)",
+            fmt(x),
+            fmt(actual_sin1),
+        );
+        assert!(
+            sin2_err < 0.9,
+            "sin({}) = {} (error = {sin2_err} ULP)",
+            fmt(x),
+            fmt(actual_sin2),
+        );
+
+        assert!(
+            cos1_err < 0.9,
+            "cos({}) = {} (error = {cos1_err} ULP)",
+            fmt(x),
+            fmt(actual_cos1),
+        );
+        assert!(
+            cos2_err < 0.9,
+            "cos({}) = {} (error = {cos2_err} ULP)",
+            fmt(x),
+            fmt(actual_cos2),
+        );
+    });
+    eprintln!("max sin1 error = {max_sin1_error}");
+    eprintln!("max sin2 error = {max_sin2_error}");
+    eprintln!("max cos1 error = {max_cos1_error}");
+    eprintln!("max cos2 error = {max_cos2_error}");
+    assert!(max_sin1_error > 0.5);
+    assert!(max_sin2_error > 0.5);
+    assert!(max_cos1_error > 0.5);
+    assert!(max_cos2_error > 0.5);
+}
+
+#[test]
+fn test_sin_cos_hi_lo() {
+    let mut max_sin_error: f64 = 0.0;
+    let mut max_cos_error: f64 = 0.0;
+    test_with(|x| {
+        let (expected_sin, expected_cos) =
+            to_rug(HI_LO_RUG_PREC, x).sin_cos(rug::Float::new(HI_LO_RUG_PREC));
+
+        let (actual_sin, actual_cos) = fpmath::sin_cos_hi_lo(x);
+        let (neg_sin, neg_cos) = fpmath::sin_cos_hi_lo(-x);
+        assert_eq!(neg_sin, (-actual_sin.0, -actual_sin.1));
+        assert_eq!(neg_cos, actual_cos);
+
+        let sin_err = calc_error_hi_lo_ulp(actual_sin, expected_sin);
+        let cos_err = calc_error_hi_lo_ulp(actual_cos, expected_cos);
+
+        max_sin_error = max_sin_error.max(sin_err);
+        max_cos_error = max_cos_error.max(cos_err);
+
+        assert!(
+            sin_err < 0.15,
+            "sin_hi_lo({}) = ({}, {}) (error = {sin_err} ULP)",
+            fmt(x),
+            fmt(actual_sin.0),
+            fmt(actual_sin.1),
+        );
+        assert!(
+            cos_err < 0.15,
+            "cos_hi_lo({}) = ({}, {}) (error = {cos_err} ULP)",
+            fmt(x),
+            fmt(actual_cos.0),
+            fmt(actual_cos.1),
+        );
+    });
+    eprintln!("max sin_hi_lo error = {max_sin_error}");
+    eprintln!("max cos_hi_lo error = {max_cos_error}");
+    assert!(max_sin_error > 0.0);
+    assert!(max_cos_error > 0.0);
+}
+
+#[test]
+fn test_rem_pio2() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let (n, y_hi, y_lo) = fpmath::rem_pio2(x);
+        assert!((0..=3).contains(&n), "rem_pio2({}).0 = {n}", fmt(x));
+        let (neg_n, neg_hi, neg_lo) = fpmath::rem_pio2(-x);
+        assert_eq!(neg_n, (4 - n) & 3);
+        assert_eq!((neg_hi, neg_lo), (-y_hi, -y_lo));
+
+        let expected = reduce_rug(x, n, HI_LO_RUG_PREC);
+        let frac_pi_4 = rug::Float::with_val(64, rug::float::Constant::Pi) / 4u8;
+        assert!(
+            expected.clone().abs() <= frac_pi_4 * 1.000001,
+            "rem_pio2({}) = ({n}, {}, {}) (expected {expected})",
+            fmt(x),
+            fmt(y_hi),
+            fmt(y_lo),
+        );
+
+        let err = calc_error_hi_lo_ulp((y_hi, y_lo), expected);
+        max_error = max_error.max(err);
+        assert!(
+            err < 0.03,
+            "rem_pio2({}) = ({n}, {}, {}) (error = {err} ULP)",
+            fmt(x),
+            fmt(y_hi),
+            fmt(y_lo),
+        );
+    });
+    eprintln!("max rem_pio2 error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_wrap_angle() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let actual = fpmath::wrap_angle(x);
+        assert_eq!(fpmath::wrap_angle(-x), -actual);
+
+        let mut expected = reduce_rug(x, 0, RUG_PREC);
+        if expected.clone().abs() > 3.0 && expected.is_sign_negative() != actual.is_sign_negative()
+        {
+            // π and -π are both valid
+            let two_pi = rug::Float::with_val(RUG_PREC, rug::float::Constant::Pi) * 2u8;
+            if actual.is_sign_negative() {
+                expected -= two_pi;
+            } else {
+                expected += two_pi;
+            }
+        }
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+        assert!(
+            err < 0.9,
+            "wrap_angle({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual),
+        );
+    });
+    eprintln!("max wrap_angle error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+/// Calculates `x - π/2*n - 2*π*M`, with `M` chosen to minimize the result,
+/// with `prec` bits of precision
+fn reduce_rug(x: f128, n: i32, prec: u32) -> rug::Float {
+    let x = to_rug(113, x);
+    // enough bits to keep `prec` bits after cancellation, which can lose
+    // more bits than the precision of the argument
+    let work_prec = x.get_exp().unwrap_or(0).max(0) as u32 + prec + 512;
+    let pi = rug::Float::with_val(work_prec, rug::float::Constant::Pi);
+    let two_pi = pi.clone() * 2u8;
+    let y = rug::Float::with_val(work_prec, &x - pi * n / 2u8);
+    let m = (y.clone() / &two_pi).round();
+    rug::Float::with_val(prec, y - m * two_pi)
+}
+
+#[test]
+fn test_sind_cosd() {
+    let mut max_sin1_error: f64 = 0.0;
+    let mut max_sin2_error: f64 = 0.0;
+    let mut max_cos1_error: f64 = 0.0;
+    let mut max_cos2_error: f64 = 0.0;
+    test_with(|x| {
+        let expected_sin = to_rug(RUG_PREC, x).sin_u(360);
+        let expected_cos = to_rug(RUG_PREC, x).cos_u(360);
+
+        let actual_sin1 = fpmath::sind(x);
+        let actual_cos1 = fpmath::cosd(x);
+        let (actual_sin2, actual_cos2) = fpmath::sind_cosd(x);
+        assert_eq!(fpmath::sind(-x), -actual_sin1);
+        assert_eq!(fpmath::cosd(-x), actual_cos1);
+        assert_eq!(fpmath::sind_cosd(-x), (-actual_sin2, actual_cos2));
+
+        let sin1_err = calc_error_ulp(actual_sin1, expected_sin.clone());
+        let sin2_err = calc_error_ulp(actual_sin2, expected_sin);
+        let cos1_err = calc_error_ulp(actual_cos1, expected_cos.clone());
+        let cos2_err = calc_error_ulp(actual_cos2, expected_cos);
+
+        max_sin1_error = max_sin1_error.max(sin1_err);
+        max_sin2_error = max_sin2_error.max(sin2_err);
+        max_cos1_error = max_cos1_error.max(cos1_err);
+        max_cos2_error = max_cos2_error.max(cos2_err);
+
+        let sin1_threshold = select_threshold(actual_sin1, 0.9, 1.9);
+        assert!(
+            sin1_err < sin1_threshold,
+            "sind({}) = {} (error = {sin1_err} ULP)",
+            fmt(x),
+            fmt(actual_sin1),
+        );
+        let sin2_threshold = select_threshold(actual_sin2, 0.9, 1.9);
+        assert!(
+            sin2_err < sin2_threshold,
+            "sind({}) = {} (error = {sin2_err} ULP)",
+            fmt(x),
+            fmt(actual_sin2),
+        );
+
+        let cos1_threshold = select_threshold(actual_cos1, 0.9, 1.9);
+        assert!(
+            cos1_err < cos1_threshold,
+            "cosd({}) = {} (error = {cos1_err} ULP)",
+            fmt(x),
+            fmt(actual_cos1),
+        );
+        let cos2_threshold = select_threshold(actual_cos2, 0.9, 1.9);
+        assert!(
+            cos2_err < cos2_threshold,
+            "cosd({}) = {} (error = {cos2_err} ULP)",
+            fmt(x),
+            fmt(actual_cos2),
+        );
+    });
+    eprintln!("max sind1 error = {max_sin1_error}");
+    eprintln!("max sind2 error = {max_sin2_error}");
+    eprintln!("max cosd1 error = {max_cos1_error}");
+    eprintln!("max cosd2 error = {max_cos2_error}");
+    assert!(max_sin1_error > 0.5);
+    assert!(max_sin2_error > 0.5);
+    assert!(max_cos1_error > 0.5);
+    assert!(max_cos2_error > 0.5);
+}
+
+#[test]
+fn test_sinpi_cospi() {
+    let mut max_sin1_error: f64 = 0.0;
+    let mut max_sin2_error: f64 = 0.0;
+    let mut max_cos1_error: f64 = 0.0;
+    let mut max_cos2_error: f64 = 0.0;
+    test_with(|x| {
+        let expected_sin = to_rug(RUG_PREC, x).sin_pi();
+        let expected_cos = to_rug(RUG_PREC, x).cos_pi();
+
+        let actual_sin1 = fpmath::sinpi(x);
+        let actual_cos1 = fpmath::cospi(x);
+        let (actual_sin2, actual_cos2) = fpmath::sinpi_cospi(x);
+        assert_eq!(fpmath::sinpi(-x), -actual_sin1);
+        assert_eq!(fpmath::cospi(-x), actual_cos1);
+        assert_eq!(fpmath::sinpi_cospi(-x), (-actual_sin2, actual_cos2));
+
+        let sin1_err = calc_error_ulp(actual_sin1, expected_sin.clone());
+        let sin2_err = calc_error_ulp(actual_sin2, expected_sin);
+        let cos1_err = calc_error_ulp(actual_cos1, expected_cos.clone());
+        let cos2_err = calc_error_ulp(actual_cos2, expected_cos);
+
+        max_sin1_error = max_sin1_error.max(sin1_err);
+        max_sin2_error = max_sin2_error.max(sin2_err);
+        max_cos1_error = max_cos1_error.max(cos1_err);
+        max_cos2_error = max_cos2_error.max(cos2_err);
+
+        let sin1_threshold = select_threshold(actual_sin1, 0.9, 1.9);
+        assert!(
+            sin1_err < sin1_threshold,
+            "sinpi({}) = {} (error = {sin1_err} ULP)",
+            fmt(x),
+            fmt(actual_sin1),
+        );
+        let sin2_threshold = select_threshold(actual_sin2, 0.9, 1.9);
+        assert!(
+            sin2_err < sin2_threshold,
+            "sinpi({}) = {} (error = {sin2_err} ULP)",
+            fmt(x),
+            fmt(actual_sin2),
+        );
+
+        let cos1_threshold = select_threshold(actual_cos1, 0.9, 1.9);
+        assert!(
+            cos1_err < cos1_threshold,
+            "cospi({}) = {} (error = {cos1_err} ULP)",
+            fmt(x),
+            fmt(actual_cos1),
+        );
+        let cos2_threshold = select_threshold(actual_cos2, 0.9, 1.9);
+        assert!(
+            cos2_err < cos2_threshold,
+            "cospi({}) = {} (error = {cos2_err} ULP)",
+            fmt(x),
+            fmt(actual_cos2),
+        );
+    });
+    eprintln!("max sinpi1 error = {max_sin1_error}");
+    eprintln!("max sinpi2 error = {max_sin2_error}");
+    eprintln!("max cospi1 error = {max_cos1_error}");
+    eprintln!("max cospi2 error = {max_cos2_error}");
+    assert!(max_sin1_error > 0.5);
+    assert!(max_sin2_error > 0.5);
+    assert!(max_cos1_error > 0.5);
+    assert!(max_cos2_error > 0.5);
+}
+
+#[test]
+fn test_tan() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = to_rug(RUG_PREC, x).tan();
+        let actual = fpmath::tan(x);
+        assert_eq!(fpmath::tan(-x), -actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "tan({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual)
+        );
+    });
+    eprintln!("max tan error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_tand() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let mut expected = to_rug(RUG_PREC, x).tan_u(360);
+        if expected.is_infinite() {
+            rug::Assign::assign(
+                &mut expected,
+                if x.is_sign_positive() {
+                    rug::float::Special::Infinity
+                } else {
+                    rug::float::Special::NegInfinity
+                },
+            );
+        }
+        let actual = fpmath::tand(x);
+        assert_eq!(fpmath::tand(-x), -actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "tand({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual),
+        );
+    });
+    eprintln!("max tand error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_tanpi() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let mut expected = to_rug(RUG_PREC, x).tan_pi();
+        if expected.is_infinite() {
+            rug::Assign::assign(
+                &mut expected,
+                if x.is_sign_positive() {
+                    rug::float::Special::Infinity
+                } else {
+                    rug::float::Special::NegInfinity
+                },
+            );
+        }
+        if expected.clone().abs() > to_rug(113, f128::MAX) {
+            rug::Assign::assign(
+                &mut expected,
+                if x.is_sign_positive() {
+                    rug::float::Special::Infinity
+                } else {
+                    rug::float::Special::NegInfinity
+                },
+            );
+        }
+        let actual = fpmath::tanpi(x);
+        assert_eq!(fpmath::tanpi(-x), -actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "tanpi({}) = {} (error = {err} ULP)",
+            fmt(x),
+            fmt(actual),
+        );
+    });
+    eprintln!("max tanpi error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_with(mut f: impl FnMut(f128)) {
+    let mut rng = create_prng();
+
+    for e in -16382..=16383 {
+        f(mkfloat(0, e, false));
+        f(mkfloat(u128::MAX, e, false));
+
+        let n = if matches!(e, -120..=120) { 500 } else { 10 };
+        for _ in 0..n {
+            let m = rng.random::<u128>();
+            f(mkfloat(m, e, false));
+        }
+    }
+
+    for arg in 1..=20_000 {
+        f(arg as f128);
+    }
+
+    // Problematic value in
+    // "ARGUMENT REDUCTION FOR HUGE ARGUMENTS: Good to the Last Bit"
+    f(1.0e22);
+
+    // Magic values from rust-libm tests
+    f(3.141592025756836);
+    f(3.141592033207416);
+    f(3.141592144966125);
+    f(3.141592979431152);
+    f(3054214.5490637687);
+    f(917340800458.2274);
+
+    let f2s = [1.0, (1 << 20) as f128];
+    let f3s = [
+        1.0, 1.1, 1.01, 1.001, 1.0001, 1.00001, 1.000001, 1.0000001, 0.9, 0.99, 0.999, 0.9999,
+        0.99999, 0.999999, 0.9999999,
+    ];
+
+    for f1 in 1..=100 {
+        for f2 in f2s {
+            for f3 in f3s {
+                f(std::f128::consts::FRAC_PI_8 * (f1 as f128) * f2 * f3);
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use super::{calc_error_ulp, purify, select_threshold, test_all};
+
+#[test]
+fn test_j0() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::j0(f32::from(x));
+        let actual = fpmath::j0(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold || calc_error_amp(actual, expected, x) < AMP_THRESHOLD,
+            "j0({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max j0 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_j1() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::j1(f32::from(x));
+        let actual = fpmath::j1(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold || calc_error_amp(actual, expected, x) < AMP_THRESHOLD,
+            "j1({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max j1 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_jn() {
+    let mut max_error: f32 = 0.0;
+    for n in [-6, -5, 2, 3, 7, 20] {
+        test_all(|x| {
+            let expected = fpmath::jn(n, f32::from(x));
+            let actual = fpmath::jn(n, x);
+
+            let err = calc_error_ulp(actual, expected);
+            max_error = max_error.max(err);
+
+            assert!(
+                err < 32.0 || calc_error_amp(actual, expected, x) < 32.0,
+                "jn({n}, {x:e}) = {actual:e} (error = {err} ULP)",
+            );
+        });
+    }
+    eprintln!("max jn error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_y0() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::y0(f32::from(x));
+        let actual = fpmath::y0(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold || calc_error_amp(actual, expected, x) < AMP_THRESHOLD,
+            "y0({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max y0 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_y1() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::y1(f32::from(x));
+        let actual = fpmath::y1(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold || calc_error_amp(actual, expected, x) < AMP_THRESHOLD,
+            "y1({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max y1 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_yn() {
+    let mut max_error: f32 = 0.0;
+    for n in [-6, -5, 2, 3, 7, 20] {
+        test_all(|x| {
+            let expected = fpmath::yn(n, f32::from(x));
+            let actual = fpmath::yn(n, x);
+
+            let err = calc_error_ulp(actual, expected);
+            max_error = max_error.max(err);
+
+            assert!(
+                err < 32.0 || calc_error_amp(actual, expected, x) < 32.0,
+                "yn({n}, {x:e}) = {actual:e} (error = {err} ULP)",
+            );
+        });
+    }
+    eprintln!("max yn error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+/// Threshold for `calc_error_amp`, larger than for `f32` and `f64` because
+/// the low parts of the polynomial coefficients are subnormal in `f16`.
+const AMP_THRESHOLD: f32 = 1.0 / 8.0;
+
+/// Calculates the error relative to the amplitude of the oscillations,
+/// `min(1, √(2 / (π * |x|)))`, in units of `f16::EPSILON`.
+///
+/// Near the zeros of the functions, the relative error can be large even
+/// when the absolute error is small.
+fn calc_error_amp(actual: f16, expected: f32, x: f16) -> f32 {
+    let amp = (2.0 / (std::f32::consts::PI * f32::from(x).abs()))
+        .sqrt()
+        .min(1.0);
+    let dif = (expected - f32::from(purify(actual))).abs();
+    dif / amp / f32::from(f16::EPSILON)
+}
@@ -0,0 +1,18 @@
+use super::{calc_error_ulp, test_all_positive};
+
+#[test]
+fn test_cbrt() {
+    let mut max_error: f32 = 0.0;
+    test_all_positive(|x| {
+        let expected = fpmath::cbrt(f32::from(x));
+        let actual = fpmath::cbrt(x);
+        assert_eq!(fpmath::cbrt(-x), -actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "cbrt({x:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max cbrt error = {max_error}");
+    assert!(max_error > 0.4);
+}
@@ -0,0 +1,133 @@
+use fpmath::complex::Complex;
+use rand::RngExt as _;
+
+use super::{calc_error_ulp, mkfloat};
+use crate::create_prng;
+
+fn to_f64(z: Complex<f16>) -> Complex<f64> {
+    Complex::new(f64::from(z.re), f64::from(z.im))
+}
+
+/// Calculates the error of each part in ULP of that part and returns the
+/// largest one.
+fn calc_error(actual: Complex<f16>, expected: Complex<f64>) -> f32 {
+    let re = calc_error_ulp(actual.re, expected.re as f32);
+    let im = calc_error_ulp(actual.im, expected.im as f32);
+    re.max(im)
+}
+
+fn test_unary(
+    name: &str,
+    f: impl Fn(Complex<f16>) -> Complex<f16>,
+    f64_f: impl Fn(Complex<f64>) -> Complex<f64>,
+) {
+    let mut max_error: f32 = 0.0;
+    test_with(|z| {
+        let expected = f64_f(to_f64(z));
+        let actual = f(z);
+
+        let err = calc_error(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.51,
+            "{name}({:e} + {:e}i) = {:e} + {:e}i (error = {err} ULP)",
+            z.re,
+            z.im,
+            actual.re,
+            actual.im,
+        );
+    });
+    eprintln!("max complex {name} error = {max_error}");
+}
+
+#[test]
+fn test_exp() {
+    test_unary("exp", Complex::exp, Complex::exp);
+}
+
+#[test]
+fn test_ln() {
+    test_unary("ln", Complex::ln, Complex::ln);
+}
+
+#[test]
+fn test_sqrt() {
+    test_unary("sqrt", Complex::sqrt, Complex::sqrt);
+}
+
+#[test]
+fn test_trigonometric() {
+    test_unary("sin", Complex::sin, Complex::sin);
+    test_unary("cos", Complex::cos, Complex::cos);
+    test_unary("tan", Complex::tan, Complex::tan);
+}
+
+#[test]
+fn test_hyperbolic() {
+    test_unary("sinh", Complex::sinh, Complex::sinh);
+    test_unary("cosh", Complex::cosh, Complex::cosh);
+    test_unary("tanh", Complex::tanh, Complex::tanh);
+}
+
+#[test]
+fn test_inv_trigonometric() {
+    test_unary("asin", Complex::asin, Complex::asin);
+    test_unary("acos", Complex::acos, Complex::acos);
+    test_unary("atan", Complex::atan, Complex::atan);
+}
+
+#[test]
+fn test_inv_hyperbolic() {
+    test_unary("asinh", Complex::asinh, Complex::asinh);
+    test_unary("acosh", Complex::acosh, Complex::acosh);
+    test_unary("atanh", Complex::atanh, Complex::atanh);
+}
+
+#[test]
+fn test_mul_div_pow() {
+    let mut rng = create_prng();
+    for _ in 0..100000 {
+        let [ea, eb, ec, ed] = [(); 4].map(|()| rng.random_range(-14..=15));
+        let z = Complex::new(rand_float(&mut rng, ea), rand_float(&mut rng, eb));
+        let w = Complex::new(rand_float(&mut rng, ec), rand_float(&mut rng, ed));
+        // the error of pow grows with the magnitude of w
+        let [ec, ed] = [(); 2].map(|()| rng.random_range(-14..=4));
+        let wp = Complex::new(rand_float(&mut rng, ec), rand_float(&mut rng, ed));
+        for (name, w, actual, expected) in [
+            ("mul", w, z * w, to_f64(z) * to_f64(w)),
+            ("div", w, z / w, to_f64(z) / to_f64(w)),
+            ("pow", wp, z.pow(wp), to_f64(z).pow(to_f64(wp))),
+        ] {
+            let err = calc_error(actual, expected);
+            assert!(
+                err < 0.51,
+                "{name}({:e} + {:e}i, {:e} + {:e}i) = {:e} + {:e}i (error = {err} ULP)",
+                z.re,
+                z.im,
+                w.re,
+                w.im,
+                actual.re,
+                actual.im,
+            );
+        }
+    }
+}
+
+fn test_with(mut f: impl FnMut(Complex<f16>)) {
+    let mut rng = create_prng();
+
+    for ex in -14..=15 {
+        for ey in -14..=15 {
+            for _ in 0..20 {
+                let x = rand_float(&mut rng, ex);
+                let y = rand_float(&mut rng, ey);
+                f(Complex::new(x, y));
+            }
+        }
+    }
+}
+
+fn rand_float(rng: &mut impl rand::Rng, e: i16) -> f16 {
+    mkfloat(rng.random::<u16>(), e, rng.random::<bool>())
+}
@@ -0,0 +1,184 @@
+use rand::RngExt as _;
+
+use super::{mkfloat, purify, test_all, test_all_positive};
+use crate::create_prng;
+
+// The `f64` functions are correctly rounded, so the double rounding when
+// converting their results to `f16` is harmless unless the exact result is
+// extremely close to the midpoint between two `f16` values.
+
+#[test]
+fn test_exp() {
+    test_all(|x| {
+        check("exp", fpmath::cr::exp, fpmath::cr::exp, x);
+        check("exp_m1", fpmath::cr::exp_m1, fpmath::cr::exp_m1, x);
+        check("exp2", fpmath::cr::exp2, fpmath::cr::exp2, x);
+        check("exp10", fpmath::cr::exp10, fpmath::cr::exp10, x);
+    });
+}
+
+#[test]
+fn test_log() {
+    test_all(|x| {
+        check("ln", fpmath::cr::ln, fpmath::cr::ln, x);
+        check("ln_1p", fpmath::cr::ln_1p, fpmath::cr::ln_1p, x);
+        check("log2", fpmath::cr::log2, fpmath::cr::log2, x);
+        check("log10", fpmath::cr::log10, fpmath::cr::log10, x);
+    });
+}
+
+#[test]
+fn test_trigonometric() {
+    test_all(|x| {
+        check("sin", fpmath::cr::sin, fpmath::cr::sin, x);
+        check("cos", fpmath::cr::cos, fpmath::cr::cos, x);
+        check("tan", fpmath::cr::tan, fpmath::cr::tan, x);
+    });
+}
+
+#[test]
+fn test_inverse_trigonometric() {
+    test_all(|x| {
+        check("asin", fpmath::cr::asin, fpmath::cr::asin, x);
+        check("acos", fpmath::cr::acos, fpmath::cr::acos, x);
+        check("atan", fpmath::cr::atan, fpmath::cr::atan, x);
+    });
+
+    let mut rng = create_prng();
+    test_with2(|y, x| check2("atan2", fpmath::cr::atan2, fpmath::cr::atan2, y, x));
+    for _ in 0..10000 {
+        let y = f16::from_bits(rng.random_range(0..0x0400));
+        let x = mkfloat(rng.random::<u16>(), rng.random_range(-14..=15), false);
+        check2("atan2", fpmath::cr::atan2, fpmath::cr::atan2, y, x);
+    }
+}
+
+#[test]
+fn test_hyperbolic() {
+    test_all(|x| {
+        check("sinh", fpmath::cr::sinh, fpmath::cr::sinh, x);
+        check("cosh", fpmath::cr::cosh, fpmath::cr::cosh, x);
+        check("tanh", fpmath::cr::tanh, fpmath::cr::tanh, x);
+    });
+}
+
+#[test]
+fn test_cbrt_hypot() {
+    test_all(|x| {
+        check("cbrt", fpmath::cr::cbrt, fpmath::cr::cbrt, x);
+    });
+
+    test_with2(|x, y| check2("hypot", fpmath::cr::hypot, fpmath::cr::hypot, x, y));
+
+    // exact results, including halfway points
+    for x in 1..=255u8 {
+        for y in 1..=255u8 {
+            let (x, y) = (f16::from(x), f16::from(y));
+            check2("hypot", fpmath::cr::hypot, fpmath::cr::hypot, x, y);
+        }
+    }
+}
+
+#[test]
+fn test_pow() {
+    let mut rng = create_prng();
+    test_all_positive(|x| {
+        for _ in 0..10 {
+            let y = rng.random_range(-30.0f32..30.0) as f16;
+            check_pow(x, y);
+            check_pow(-x, fpmath::round(y));
+        }
+    });
+
+    // exact results and results halfway between two `f16` values
+    for a in 1..=255u8 {
+        let a = f16::from(a);
+        check_pow(a * a, 0.5);
+        check_pow(a, 2.0);
+        check_pow(a * 0.125, 2.0);
+    }
+}
+
+#[test]
+fn test_hard_cases() {
+    // exactly halfway between two `f16` values
+    assert_eq!(fpmath::cr::pow(47.0f16, 2.0).to_bits(), 2208.0f16.to_bits());
+    assert_eq!(
+        fpmath::cr::pow(169.0f16, 1.5).to_bits(),
+        2196.0f16.to_bits()
+    );
+    assert_eq!(fpmath::cr::pow(2.0f16, -25.0).to_bits(), 0.0f16.to_bits());
+    assert_eq!(fpmath::cr::exp10(5.0f16), f16::INFINITY);
+    assert_eq!(
+        fpmath::cr::hypot(65.0f16, 2112.0f16).to_bits(),
+        2112.0f16.to_bits()
+    );
+
+    // `y / x` is exactly halfway between two subnormals, and `atan2(y, x)`
+    // is slightly closer to zero
+    let x = 8192.0f16;
+    let y = 3.0 * 0.000244140625f16;
+    assert_eq!(fpmath::cr::atan2(y, x).to_bits(), 1);
+    assert_eq!(fpmath::cr::atan2(7.0 * 0.000244140625f16, x).to_bits(), 3);
+    assert_eq!(fpmath::cr::atan2(-y, x).to_bits(), 0x8001);
+}
+
+fn check(name: &str, actual_f: impl Fn(f16) -> f16, expected_f: impl Fn(f64) -> f64, x: f16) {
+    let actual = purify(actual_f(x));
+    let expected = expected_f(f64::from(x)) as f16;
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "{name}({x:e}) = {actual:e} (expected {expected:e})",
+    );
+}
+
+fn check2(
+    name: &str,
+    actual_f: impl Fn(f16, f16) -> f16,
+    expected_f: impl Fn(f64, f64) -> f64,
+    x: f16,
+    y: f16,
+) {
+    let actual = purify(actual_f(x, y));
+    let expected = expected_f(f64::from(x), f64::from(y)) as f16;
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "{name}({x:e}, {y:e}) = {actual:e} (expected {expected:e})",
+    );
+}
+
+fn check_pow(x: f16, y: f16) {
+    let actual = purify(fpmath::cr::pow(x, y));
+    let expected = fpmath::cr::pow(f64::from(x), f64::from(y)) as f16;
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "pow({x:e}, {y:e}) = {actual:e} (expected {expected:e})",
+    );
+}
+
+fn test_with2(mut f: impl FnMut(f16, f16)) {
+    let mut rng = create_prng();
+
+    for ex in -14..=15 {
+        for ey in -14..=15 {
+            for _ in 0..100 {
+                let mx = rng.random::<u16>();
+                let sx = rng.random::<bool>();
+                let my = rng.random::<u16>();
+                let sy = rng.random::<bool>();
+                f(mkfloat(mx, ex, sx), mkfloat(my, ey, sy));
+            }
+        }
+    }
+
+    // subnormals, zeros and infinities
+    let special = [0.0, -0.0, f16::INFINITY, f16::NEG_INFINITY, f16::NAN];
+    for _ in 0..10000 {
+        let x = f16::from_bits(rng.random_range(0..0x0400));
+        let y = f16::from_bits(rng.random_range(0..0x0400));
+        f(x, y);
+        f(x, mkfloat(rng.random::<u16>(), -14, true));
+        f(special[rng.random_range(0..special.len())], x);
+        f(x, special[rng.random_range(0..special.len())]);
+    }
+}
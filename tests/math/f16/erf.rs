@@ -0,0 +1,41 @@
+use super::{calc_error_ulp, select_threshold, test_all};
+
+#[test]
+fn test_erf() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::erf(f32::from(x));
+        let actual = fpmath::erf(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "erf({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max erf error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_erfc() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::erfc(f32::from(x));
+        let actual = fpmath::erfc(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "erfc({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max erfc error = {max_error}");
+    assert!(max_error > 0.5);
+}
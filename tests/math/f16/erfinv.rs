@@ -0,0 +1,41 @@
+use super::{calc_error_ulp, select_threshold, test_all};
+
+#[test]
+fn test_erfinv() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::erfinv(f32::from(x));
+        let actual = fpmath::erfinv(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "erfinv({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max erfinv error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_erfcinv() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::erfcinv(f32::from(x));
+        let actual = fpmath::erfcinv(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "erfcinv({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max erfcinv error = {max_error}");
+    assert!(max_error > 0.5);
+}
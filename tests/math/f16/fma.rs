@@ -0,0 +1,77 @@
+use rand::RngExt as _;
+use rug::float::Round;
+
+use super::mkfloat;
+use crate::create_prng;
+
+#[test]
+fn test_fma() {
+    let mut rng = create_prng();
+
+    for _ in 0..200_000 {
+        let ex = rng.random_range(-15..=15);
+        let ey = rng.random_range(-15..=15);
+        let ez = (ex + ey + rng.random_range(-15..=15)).clamp(-15, 15);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        let z = mkfloat(rng.random(), ez, rng.random());
+        check(x, y, z);
+
+        // cancellation of the highest bits
+        let z = -(x * y);
+        if z.is_finite() {
+            check(x, y, z);
+            check(x, y, f16::from_bits(z.to_bits() + 1));
+        }
+    }
+
+    // results close to or in the subnormal range
+    for _ in 0..100_000 {
+        let ex = rng.random_range(-15..=-5);
+        let ey = (-25 - ex + rng.random_range(-20..=20)).clamp(-15, 15);
+        let ez = rng.random_range(-15..=-12);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        let z = mkfloat(rng.random(), ez, rng.random());
+        check(x, y, z);
+        check(x, y, 0.0);
+        check(x, y, -0.0);
+    }
+
+    let special = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        f16::from_bits(1),
+        f16::MIN_POSITIVE,
+        f16::MAX,
+        f16::INFINITY,
+        f16::NEG_INFINITY,
+        f16::NAN,
+    ];
+    for x in special {
+        for y in special {
+            for z in special {
+                check(x, y, z);
+            }
+        }
+    }
+}
+
+fn check(x: f16, y: f16, z: f16) {
+    let mut expected = rug::Float::with_val(11, f32::from(x));
+    let dir = expected.mul_add_round(
+        &rug::Float::with_val(11, f32::from(y)),
+        &rug::Float::with_val(11, f32::from(z)),
+        Round::Nearest,
+    );
+    expected.subnormalize_ieee_round(dir, Round::Nearest);
+    let expected = expected.to_f32() as f16;
+
+    let actual = fpmath::fma(x, y, z);
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "fma({x:e}, {y:e}, {z:e}) = {actual:e}, expected {expected:e}",
+    );
+}
@@ -0,0 +1,92 @@
+use rand::RngExt as _;
+
+use super::mkfloat;
+use crate::create_prng;
+
+#[test]
+fn test_fmod() {
+    test_with(|x, y| {
+        let expected = (rug::Float::with_val(11, f32::from(x))
+            % rug::Float::with_val(11, f32::from(y)))
+        .to_f32() as f16;
+        let actual = fpmath::fmod(x, y);
+        assert!(
+            same_result(actual, expected),
+            "fmod({x:e}, {y:e}) = {actual:e}, expected {expected:e}",
+        );
+    });
+}
+
+#[test]
+fn test_remainder() {
+    test_with(|x, y| {
+        let (expected, expected_quo) = rug::Float::with_val(11, f32::from(x))
+            .remainder_quo31(&rug::Float::with_val(11, f32::from(y)));
+        let expected = expected.to_f32() as f16;
+
+        let actual = fpmath::remainder(x, y);
+        assert!(
+            same_result(actual, expected),
+            "remainder({x:e}, {y:e}) = {actual:e}, expected {expected:e}",
+        );
+
+        let (actual, actual_quo) = fpmath::remquo(x, y);
+        assert!(
+            same_result(actual, expected) && (actual_quo == expected_quo || expected.is_nan()),
+            "remquo({x:e}, {y:e}) = ({actual:e}, {actual_quo}), expected ({expected:e}, {expected_quo})",
+        );
+    });
+}
+
+fn same_result(actual: f16, expected: f16) -> bool {
+    actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan())
+}
+
+fn test_with(mut f: impl FnMut(f16, f16)) {
+    let mut rng = create_prng();
+
+    for _ in 0..20000 {
+        let ex = rng.random_range(-15..=15);
+        let ey = rng.random_range(-15..=15);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        f(x, y);
+    }
+
+    // close exponents
+    for _ in 0..20000 {
+        let ey = rng.random_range(-15..=5);
+        let ex = ey + rng.random_range(-2..=10);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        f(x, y);
+    }
+
+    // halfway cases
+    for _ in 0..20000 {
+        let ey = rng.random_range(-10..=5);
+        let n = rng.random_range(0..256);
+        let y = mkfloat(0, ey, rng.random());
+        let x = (n as f16 + 0.5) * y;
+        f(x, y);
+        f(-x, y);
+    }
+
+    let special = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        f16::from_bits(1),
+        f16::MIN_POSITIVE,
+        f16::MAX,
+        f16::INFINITY,
+        f16::NEG_INFINITY,
+        f16::NAN,
+    ];
+    for x in special {
+        for y in special {
+            f(x, y);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use rand::RngExt as _;
+
+use super::{calc_error_ulp, mkfloat, purify, select_threshold, test_all};
+use crate::create_prng;
+
+#[test]
+fn test_gamma() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::gamma(f32::from(x));
+        let actual = fpmath::gamma(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = if x < 0.5 { 1.9 } else { 0.9 };
+        assert!(
+            err < threshold,
+            "gamma({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max gamma error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_ln_gamma() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let (expected, expected_sign) = fpmath::ln_gamma(f32::from(x));
+        let (actual, actual_sign) = fpmath::ln_gamma(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = if (-5.0..=-2.0).contains(&x) {
+            // FIXME
+            400.0
+        } else if (0.5..=7.0).contains(&x) {
+            1.5
+        } else {
+            1.9
+        };
+        assert_eq!(expected_sign, actual_sign);
+        assert!(
+            err < threshold,
+            "ln_gamma({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max ln_gamma error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_gamma_p() {
+    let mut max_error: f32 = 0.0;
+    test_with_inc(|a, x| {
+        let expected = fpmath::gamma_p(f32::from(a), f32::from(x));
+        let actual = fpmath::gamma_p(a, x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 1.9, 2.9);
+        assert!(
+            err < threshold,
+            "gamma_p({a:e}, {x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max gamma_p error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_gamma_q() {
+    let mut max_error: f32 = 0.0;
+    test_with_inc(|a, x| {
+        let expected = fpmath::gamma_q(f32::from(a), f32::from(x));
+        let actual = fpmath::gamma_q(a, x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 1.9, 2.9);
+        assert!(
+            err < threshold,
+            "gamma_q({a:e}, {x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max gamma_q error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_gamma_p_inv() {
+    let mut max_error: f32 = 0.0;
+    test_with_inc(|a, x| {
+        let p = fpmath::gamma_p(a, x);
+        // The accuracy is not guaranteed when `p` is subnormal
+        if p == 0.0 || p == 1.0 || p.is_subnormal() {
+            return;
+        }
+
+        let expected = fpmath::gamma_p_inv(f32::from(a), f32::from(p));
+        let actual = fpmath::gamma_p_inv(a, p);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "gamma_p_inv({a:e}, {p:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max gamma_p_inv error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_gamma_q_inv() {
+    let mut max_error: f32 = 0.0;
+    test_with_inc(|a, x| {
+        let q = fpmath::gamma_q(a, x);
+        // The accuracy is not guaranteed when `q` is subnormal
+        if q == 0.0 || q == 1.0 || q.is_subnormal() {
+            return;
+        }
+
+        let expected = fpmath::gamma_q_inv(f32::from(a), f32::from(q));
+        let actual = fpmath::gamma_q_inv(a, q);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "gamma_q_inv({a:e}, {q:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max gamma_q_inv error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+fn test_with_inc(mut f: impl FnMut(f16, f16)) {
+    let mut rng = create_prng();
+
+    for ea in -14..=15 {
+        for ex in -14..=15 {
+            for _ in 0..50 {
+                let a = mkfloat(rng.random::<u16>(), ea, false);
+                let x = mkfloat(rng.random::<u16>(), ex, false);
+                f(a, x);
+            }
+        }
+    }
+
+    for i in 1..=100 {
+        for j in 1..=100 {
+            let a = purify((i as f16) / 10.0);
+            let x = purify((j as f16) / 5.0);
+            f(a, x);
+        }
+    }
+
+    // Close to the mean, where `P` and `Q` are not small
+    for _ in 0..20000 {
+        let a = rng.random_range(1.0..60000.0f32) as f16;
+        let x = (f32::from(a) * rng.random_range(0.5..1.5f32)) as f16;
+        f(a, x);
+    }
+
+    // Exactly at the mean
+    for bits in 0x3C00..0x7C00 {
+        let a = f16::from_bits(bits);
+        f(a, a);
+    }
+}
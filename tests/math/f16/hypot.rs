@@ -0,0 +1,48 @@
+use rand::RngExt as _;
+
+use super::{calc_error_ulp, mkfloat, select_threshold};
+use crate::create_prng;
+
+#[test]
+fn test_hypot() {
+    let mut max_error: f32 = 0.0;
+    test_with(|x, y| {
+        let expected = fpmath::hypot(f32::from(x), f32::from(y));
+        let actual = fpmath::hypot(x, y);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "hypot({x:e}, {y:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max hypot error = {max_error}");
+    assert!(max_error > 0.49);
+}
+
+fn test_with(mut f: impl FnMut(f16, f16)) {
+    let mut rng = create_prng();
+
+    for ex in -14..=15 {
+        for ey in -14..=15 {
+            for _ in 0..500 {
+                let mx = rng.random::<u16>();
+                let sx = rng.random::<bool>();
+                let my = rng.random::<u16>();
+                let sy = rng.random::<bool>();
+                f(mkfloat(mx, ex, sx), mkfloat(my, ey, sy));
+            }
+        }
+    }
+
+    // subnormals
+    for _ in 0..10000 {
+        let x = f16::from_bits(rng.random_range(0..0x0400));
+        let y = f16::from_bits(rng.random_range(0..0x0400));
+        f(x, y);
+        f(x, mkfloat(rng.random::<u16>(), -14, false));
+    }
+}
@@ -0,0 +1,49 @@
+use super::{calc_error_ulp, test_all};
+
+#[test]
+fn test_asinh() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::asinh(f32::from(x));
+        let actual = fpmath::asinh(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "asinh({x:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max asinh error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_acosh() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::acosh(f32::from(x));
+        let actual = fpmath::acosh(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "acosh({x:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max acosh error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_atanh() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::atanh(f32::from(x));
+        let actual = fpmath::atanh(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "atanh({x:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max atanh error = {max_error}");
+    assert!(max_error > 0.4);
+}
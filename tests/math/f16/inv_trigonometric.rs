@@ -0,0 +1,255 @@
+use rand::RngExt as _;
+
+use super::{calc_error_ulp, mkfloat, purify, select_threshold, test_all, test_all_positive};
+use crate::create_prng;
+
+#[test]
+fn test_asin_acos() {
+    let mut max_asin_error: f32 = 0.0;
+    let mut max_acos_error: f32 = 0.0;
+    test_all(|x| {
+        let expected_asin = fpmath::asin(f32::from(x));
+        let expected_acos = fpmath::acos(f32::from(x));
+
+        let actual_asin = fpmath::asin(x);
+        let actual_acos = fpmath::acos(x);
+
+        let asin_err = calc_error_ulp(actual_asin, expected_asin);
+        let acos_err = calc_error_ulp(actual_acos, expected_acos);
+
+        max_asin_error = max_asin_error.max(asin_err);
+        max_acos_error = max_acos_error.max(acos_err);
+
+        let asin_threshold = select_threshold(actual_asin, 0.9, 1.9);
+        assert!(
+            asin_err < asin_threshold,
+            "asin({x:e}) = {actual_asin:e} (error = {asin_err} ULP)",
+        );
+
+        let acos_threshold = select_threshold(actual_acos, 0.9, 1.9);
+        assert!(
+            acos_err < acos_threshold,
+            "acos({x:e}) = {actual_acos:e} (error = {acos_err} ULP)",
+        );
+    });
+    eprintln!("max asin error = {max_asin_error}");
+    eprintln!("max acos error = {max_acos_error}");
+    assert!(max_asin_error > 0.5);
+    assert!(max_acos_error > 0.5);
+}
+
+#[test]
+fn test_asind_acosd() {
+    let mut max_asin_error: f32 = 0.0;
+    let mut max_acos_error: f32 = 0.0;
+    test_all(|x| {
+        let expected_asin = fpmath::asind(f32::from(x));
+        let expected_acos = fpmath::acosd(f32::from(x));
+
+        let actual_asin = fpmath::asind(x);
+        let actual_acos = fpmath::acosd(x);
+
+        let asin_err = calc_error_ulp(actual_asin, expected_asin);
+        let acos_err = calc_error_ulp(actual_acos, expected_acos);
+
+        max_asin_error = max_asin_error.max(asin_err);
+        max_acos_error = max_acos_error.max(acos_err);
+
+        let asin_threshold = select_threshold(actual_asin, 0.9, 1.9);
+        assert!(
+            asin_err < asin_threshold,
+            "asind({x:e}) = {actual_asin:e} (error = {asin_err} ULP)",
+        );
+
+        let acos_threshold = select_threshold(actual_acos, 0.9, 1.9);
+        assert!(
+            acos_err < acos_threshold,
+            "acosd({x:e}) = {actual_acos:e} (error = {acos_err} ULP)",
+        );
+    });
+    eprintln!("max asind error = {max_asin_error}");
+    eprintln!("max acosd error = {max_acos_error}");
+    assert!(max_asin_error > 0.5);
+    assert!(max_acos_error > 0.5);
+}
+
+#[test]
+fn test_asinpi_acospi() {
+    let mut max_asin_error: f32 = 0.0;
+    let mut max_acos_error: f32 = 0.0;
+    test_all(|x| {
+        let expected_asin = fpmath::asinpi(f32::from(x));
+        let expected_acos = fpmath::acospi(f32::from(x));
+
+        let actual_asin = fpmath::asinpi(x);
+        let actual_acos = fpmath::acospi(x);
+
+        let asin_err = calc_error_ulp(actual_asin, expected_asin);
+        let acos_err = calc_error_ulp(actual_acos, expected_acos);
+
+        max_asin_error = max_asin_error.max(asin_err);
+        max_acos_error = max_acos_error.max(acos_err);
+
+        let asin_threshold = select_threshold(actual_asin, 0.9, 1.9);
+        assert!(
+            asin_err < asin_threshold,
+            "asinpi({x:e}) = {actual_asin:e} (error = {asin_err} ULP)",
+        );
+
+        let acos_threshold = select_threshold(actual_acos, 0.9, 1.9);
+        assert!(
+            acos_err < acos_threshold,
+            "acospi({x:e}) = {actual_acos:e} (error = {acos_err} ULP)",
+        );
+    });
+    eprintln!("max asinpi error = {max_asin_error}");
+    eprintln!("max acospi error = {max_acos_error}");
+    assert!(max_asin_error > 0.5);
+    assert!(max_acos_error > 0.5);
+}
+
+#[test]
+fn test_atan() {
+    let mut max_error: f32 = 0.0;
+    test_all_positive(|x| {
+        let expected = fpmath::atan(f32::from(x));
+        let actual = fpmath::atan(x);
+        assert_eq!(purify(fpmath::atan(-x)), purify(-actual));
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "atan({x:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max atan error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_atand() {
+    let mut max_error: f32 = 0.0;
+    test_all_positive(|x| {
+        let expected = fpmath::atand(f32::from(x));
+        let actual = fpmath::atand(x);
+        assert_eq!(purify(fpmath::atand(-x)), purify(-actual));
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "atand({x:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max atand error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_atanpi() {
+    let mut max_error: f32 = 0.0;
+    test_all_positive(|x| {
+        let expected = fpmath::atanpi(f32::from(x));
+        let actual = fpmath::atanpi(x);
+        assert_eq!(purify(fpmath::atanpi(-x)), purify(-actual));
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "atanpi({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max atanpi error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_atan2() {
+    let mut max_error: f32 = 0.0;
+    test_atan2_with(|y, x| {
+        let expected = fpmath::atan2(f32::from(y), f32::from(x));
+        let actual = fpmath::atan2(y, x);
+        assert_eq!(purify(fpmath::atan2(-y, x)), purify(-actual));
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "atan2({x:e}, {y:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max atan2 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_atan2d() {
+    let mut max_error: f32 = 0.0;
+    test_atan2_with(|y, x| {
+        let expected = fpmath::atan2d(f32::from(y), f32::from(x));
+        let actual = fpmath::atan2d(y, x);
+        assert_eq!(purify(fpmath::atan2d(-y, x)), purify(-actual));
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "atan2d({x:e}, {y:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max atan2d error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_atan2pi() {
+    let mut max_error: f32 = 0.0;
+    test_atan2_with(|y, x| {
+        let expected = fpmath::atan2pi(f32::from(y), f32::from(x));
+        let actual = fpmath::atan2pi(y, x);
+        assert_eq!(purify(fpmath::atan2pi(-y, x)), purify(-actual));
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.95, 1.9);
+        assert!(
+            err < threshold,
+            "atan2pi({x:e}, {y:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max atan2pi error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+fn test_atan2_with(mut f: impl FnMut(f16, f16)) {
+    let mut rng = create_prng();
+
+    for ey in -14..=15 {
+        for ex in -14..=15 {
+            for _ in 0..100 {
+                let my = rng.random::<u16>();
+                let mx = rng.random::<u16>();
+                f(mkfloat(my, ey, false), mkfloat(mx, ex, false));
+                f(mkfloat(my, ey, false), mkfloat(mx, ex, true));
+            }
+        }
+    }
+
+    // subnormals
+    for i in 0..10 {
+        for e in -14..=15 {
+            let my = rng.random::<u16>();
+            let mx = rng.random::<u16>();
+            let sx = rng.random::<bool>();
+            let sub = f16::from_bits(1 << i);
+            f(sub, mkfloat(mx, e, sx));
+            f(mkfloat(my, e, false), sub);
+            f(mkfloat(my, e, false), -sub);
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use super::{calc_error_ulp, test_all};
+
+#[test]
+fn test_lambert_w0() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::lambert_w0(f32::from(x));
+        let actual = fpmath::lambert_w0(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "lambert_w0({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max lambert_w0 error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_lambert_wm1() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::lambert_wm1(f32::from(x));
+        let actual = fpmath::lambert_wm1(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "lambert_wm1({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max lambert_wm1 error = {max_error}");
+    assert!(max_error > 0.4);
+}
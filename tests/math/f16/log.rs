@@ -0,0 +1,150 @@
+use super::{calc_error_hi_lo_ulp, calc_error_ulp, test_all};
+
+#[test]
+fn test_ln() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::ln(f32::from(x));
+        let actual = fpmath::ln(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "ln({x:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max ln error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_ln_1p() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::ln_1p(f32::from(x));
+        let actual = fpmath::ln_1p(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "ln_1p({x:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max ln_1p error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_ln_hi_lo() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::ln(f32::from(x));
+        let actual = fpmath::ln_hi_lo(x);
+
+        let err = calc_error_hi_lo_ulp(actual, expected);
+
+        // `lo` loses precision close to the subnormal range
+        let threshold = if actual.0.abs() < f16::MIN_POSITIVE / f16::EPSILON {
+            1.0
+        } else {
+            max_error = max_error.max(err);
+            0.01
+        };
+        assert!(
+            err < threshold,
+            "ln_hi_lo({x:e}) = {actual:?} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max ln_hi_lo error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_ln_1p_hi_lo() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::ln_1p(f32::from(x));
+        let actual = fpmath::ln_1p_hi_lo(x);
+
+        let err = calc_error_hi_lo_ulp(actual, expected);
+
+        // `lo` loses precision close to the subnormal range
+        let threshold = if actual.0.abs() < f16::MIN_POSITIVE / f16::EPSILON {
+            1.0
+        } else {
+            max_error = max_error.max(err);
+            0.01
+        };
+        assert!(
+            err < threshold,
+            "ln_1p_hi_lo({x:e}) = {actual:?} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max ln_1p_hi_lo error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_log2() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::log2(f32::from(x));
+        let actual = fpmath::log2(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "log2({x:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max log2 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_log10() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::log10(f32::from(x));
+        let actual = fpmath::log10(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "log10({x:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max log10 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_log2_1p() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::log2_1p(f32::from(x));
+        let actual = fpmath::log2_1p(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "log2_1p({x:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max log2_1p error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_log10_1p() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::log10_1p(f32::from(x));
+        let actual = fpmath::log10_1p(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "log10_1p({x:e}) = {actual:e} (error = {err} ULP)"
+        );
+    });
+    eprintln!("max log10_1p error = {max_error}");
+    assert!(max_error > 0.4);
+}
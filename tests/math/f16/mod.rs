@@ -0,0 +1,117 @@
+mod bessel;
+mod beta;
+mod cbrt;
+mod complex;
+mod cr;
+mod erf;
+mod erfinv;
+mod exp;
+mod fma;
+mod fmod;
+mod gamma;
+mod hyperbolic;
+mod hypot;
+mod inv_hyperbolic;
+mod inv_trigonometric;
+mod lambert_w;
+mod log;
+mod polygamma;
+mod pow;
+mod round;
+mod slice;
+mod sqrt;
+mod trigonometric;
+mod zeta;
+
+fn mkfloat(m: u16, e: i16, s: bool) -> f16 {
+    let m = m >> (16 - 10);
+    let e = ((e + 15) as u16) << 10;
+    let s = u16::from(s) << 15;
+    f16::from_bits(m | e | s)
+}
+
+fn calc_error_ulp(actual: f16, expected: f32) -> f32 {
+    let actual = purify(actual);
+
+    // values above this round to infinity
+    let overflow_th = 65520.0;
+
+    if expected.is_nan() {
+        if actual.is_nan() { 0.0 } else { f32::INFINITY }
+    } else if expected >= overflow_th {
+        if actual == f16::INFINITY {
+            0.0
+        } else {
+            f32::INFINITY
+        }
+    } else if expected <= -overflow_th {
+        if actual == f16::NEG_INFINITY {
+            0.0
+        } else {
+            f32::INFINITY
+        }
+    } else if actual.is_infinite() {
+        f32::INFINITY
+    } else {
+        let exp = if expected == 0.0 {
+            -14
+        } else {
+            (fpmath::frexp(expected).1 - 1).max(-14)
+        };
+        fpmath::scalbn((expected - f32::from(actual)).abs(), 10 - exp)
+    }
+}
+
+/// Calculates the error of `hi + lo` in ULP of `hi`
+///
+/// `(hi, lo)` must be normalized.
+fn calc_error_hi_lo_ulp((hi, lo): (f16, f16), expected: f32) -> f32 {
+    let (hi, lo) = purify2((hi, lo));
+
+    if !hi.is_finite() || hi == 0.0 {
+        if lo == 0.0 {
+            calc_error_ulp(hi, expected)
+        } else {
+            f32::INFINITY
+        }
+    } else {
+        assert_eq!(purify(hi + lo), hi, "({hi:e}, {lo:e}) is not normalized");
+        let exp = (fpmath::frexp(expected).1 - 1).max(-14);
+        let actual = f32::from(hi) + f32::from(lo);
+        fpmath::scalbn((expected - actual).abs(), 10 - exp)
+    }
+}
+
+fn select_threshold(actual: f16, normal_th: f32, subnormal_th: f32) -> f32 {
+    if actual == 0.0 || actual.is_subnormal() {
+        subnormal_th
+    } else {
+        normal_th
+    }
+}
+
+/// Calls `f` with every `f16` value, except NaN
+fn test_all(mut f: impl FnMut(f16)) {
+    for bits in 0..=u16::MAX {
+        let x = f16::from_bits(bits);
+        if !x.is_nan() {
+            f(x);
+        }
+    }
+}
+
+/// Calls `f` with every positive finite `f16` value, including zero
+fn test_all_positive(mut f: impl FnMut(f16)) {
+    for bits in 0..0x7C00 {
+        f(f16::from_bits(bits));
+    }
+}
+
+// Workaround X87 compiler bugs
+fn purify(x: f16) -> f16 {
+    std::hint::black_box(x)
+}
+
+fn purify2((x, y): (f16, f16)) -> (f16, f16) {
+    (std::hint::black_box(x), std::hint::black_box(y))
+}
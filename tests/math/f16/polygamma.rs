@@ -0,0 +1,70 @@
+use super::{calc_error_ulp, purify, select_threshold, test_all};
+
+#[test]
+fn test_digamma() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        let expected = fpmath::digamma(f32::from(x));
+        let actual = fpmath::digamma(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        if x > 0.0 {
+            let threshold = select_threshold(actual, 0.9, 1.9);
+            assert!(
+                err < threshold,
+                "digamma({x:e}) = {actual:e} (error = {err} ULP)",
+            );
+        } else {
+            // ψ(x) = ψ(1 - x) - π / tan(πx), which has cancellation near
+            // the zeros of the function
+            let scale = fpmath::digamma(1.0 - f32::from(x));
+            assert!(
+                err < 1.9 || calc_error_scaled(actual, expected, scale) < 0.25,
+                "digamma({x:e}) = {actual:e} (error = {err} ULP)",
+            );
+        }
+    });
+    eprintln!("max digamma error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_polygamma() {
+    let mut max_error: f32 = 0.0;
+    for n in [1, 2, 3, 4, 7, 20] {
+        test_all(|x| {
+            let expected = fpmath::polygamma(n, f32::from(x));
+            let actual = fpmath::polygamma(n, x);
+
+            let err = calc_error_ulp(actual, expected);
+            max_error = max_error.max(err);
+
+            if x > 0.0 || n % 2 == 1 {
+                let threshold = select_threshold(actual, 0.9, 1.9);
+                assert!(
+                    err < threshold,
+                    "polygamma({n}, {x:e}) = {actual:e} (error = {err} ULP)",
+                );
+            } else {
+                // Terms with opposite signs can cancel near the zeros of
+                // the function when `n` is even
+                let f = f32::from(x).fract().abs();
+                let scale = fpmath::polygamma(n, 1.0 - f).abs() + fpmath::polygamma(n, f).abs();
+                assert!(
+                    err < 1.9 || calc_error_scaled(actual, expected, scale) < 0.25,
+                    "polygamma({n}, {x:e}) = {actual:e} (error = {err} ULP)",
+                );
+            }
+        });
+    }
+    eprintln!("max polygamma error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+/// Calculates the error relative to `scale`, in units of `f16::EPSILON`.
+fn calc_error_scaled(actual: f16, expected: f32, scale: f32) -> f32 {
+    let dif = (expected - f32::from(purify(actual))).abs();
+    dif / scale.abs() / f32::from(f16::EPSILON)
+}
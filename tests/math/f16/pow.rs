@@ -0,0 +1,171 @@
+use rand::RngExt as _;
+
+use super::{calc_error_ulp, mkfloat, purify, select_threshold, test_all, test_all_positive};
+use crate::create_prng;
+
+#[test]
+fn test_pow() {
+    let mut max_error: f32 = 0.0;
+    test_pow_with(|x, y| {
+        let expected = fpmath::pow(f32::from(x), f32::from(y));
+        let actual = fpmath::pow(x, y);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "pow({x:e}, {y:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max pow error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+fn test_pow_with(mut f: impl FnMut(f16, f16)) {
+    let mut rng = create_prng();
+
+    // MIN <= |x|^y <= MAX
+    // log2(MIN) / log2(|x|) <= y <= log2(MAX) / log2(|x|)
+    test_all_positive(|x| {
+        let log2_x = fpmath::log2(f32::from(x));
+        if !log2_x.is_finite() || log2_x == 0.0 {
+            return;
+        }
+        let (min_y, max_y) = if log2_x > 0.0 {
+            (-24.0 / log2_x, 16.0 / log2_x)
+        } else {
+            (16.0 / log2_x, -24.0 / log2_x)
+        };
+
+        for _ in 0..20 {
+            let y = rng.random_range(min_y..=max_y) as f16;
+            f(x, y);
+            f(-x, fpmath::round(y));
+        }
+    });
+
+    for ex in -10..=-1 {
+        for ey in 1..=15 {
+            for _ in 0..500 {
+                let mx = rng.random::<u16>();
+                let sx = rng.random::<bool>();
+                let my = rng.random::<u16>();
+                let sy = rng.random::<bool>();
+                f(purify(1.0 + mkfloat(mx, ex, sx)), mkfloat(my, ey, sy));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_powi() {
+    let mut max_error: f32 = 0.0;
+    test_all_positive(|x| {
+        for y in -30..=30 {
+            let expected = fpmath::powi(f32::from(x), y);
+            let actual = fpmath::powi(x, y);
+
+            let err = calc_error_ulp(actual, expected);
+            max_error = max_error.max(err);
+
+            let threshold = select_threshold(actual, 0.9, 1.9);
+            assert!(
+                err < threshold,
+                "powi({x:e}, {y}) = {actual:e} (error = {err} ULP)",
+            );
+        }
+    });
+    eprintln!("max pow error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_powr() {
+    let mut max_error: f32 = 0.0;
+    test_pow_with(|x, y| {
+        let expected = fpmath::powr(f32::from(x), f32::from(y));
+        let actual = fpmath::powr(x, y);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "powr({x:e}, {y:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max powr error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_pown() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        for y in (-30..=30).chain([i64::MIN, -(1 << 40) - 1, 1 << 40, i64::MAX]) {
+            let expected = fpmath::pown(f32::from(x), y);
+            let actual = fpmath::pown(x, y);
+
+            let err = calc_error_ulp(actual, expected);
+            max_error = max_error.max(err);
+
+            let threshold = select_threshold(actual, 0.9, 1.9);
+            assert!(
+                err < threshold,
+                "pown({x:e}, {y}) = {actual:e} (error = {err} ULP)",
+            );
+        }
+    });
+    eprintln!("max pown error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_rootn() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        for n in (-10..=10).chain([100, -1001, 1 << 40, i64::MAX]) {
+            if n == 0 {
+                continue;
+            }
+            let expected = fpmath::rootn(f32::from(x), n);
+            let actual = fpmath::rootn(x, n);
+
+            let err = calc_error_ulp(actual, expected);
+            max_error = max_error.max(err);
+
+            let threshold = select_threshold(actual, 0.9, 1.9);
+            assert!(
+                err < threshold,
+                "rootn({x:e}, {n}) = {actual:e} (error = {err} ULP)",
+            );
+        }
+    });
+    eprintln!("max rootn error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_compound() {
+    let mut max_error: f32 = 0.0;
+    test_all(|x| {
+        for n in (-30..=30).chain([i64::MIN, -(1 << 40) - 1, 1 << 40, i64::MAX]) {
+            let expected = fpmath::compound(f32::from(x), n);
+            let actual = fpmath::compound(x, n);
+
+            let err = calc_error_ulp(actual, expected);
+            max_error = max_error.max(err);
+
+            let threshold = select_threshold(actual, 0.9, 1.9);
+            assert!(
+                err < threshold,
+                "compound({x:e}, {n}) = {actual:e} (error = {err} ULP)",
+            );
+        }
+    });
+    eprintln!("max compound error = {max_error}");
+    assert!(max_error > 0.4);
+}
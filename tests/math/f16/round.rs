@@ -0,0 +1,41 @@
+use super::test_all;
+
+#[test]
+fn test_round() {
+    test_all(|arg| {
+        let expected = fpmath::round(f32::from(arg));
+        let actual = fpmath::round(arg);
+
+        assert!(expected == f32::from(actual), "round({arg:e}) = {actual:e}");
+    });
+}
+
+#[test]
+fn test_floor() {
+    test_all(|arg| {
+        let expected = fpmath::floor(f32::from(arg));
+        let actual = fpmath::floor(arg);
+
+        assert!(expected == f32::from(actual), "floor({arg:e}) = {actual:e}");
+    });
+}
+
+#[test]
+fn test_ceil() {
+    test_all(|arg| {
+        let expected = fpmath::ceil(f32::from(arg));
+        let actual = fpmath::ceil(arg);
+
+        assert!(expected == f32::from(actual), "ceil({arg:e}) = {actual:e}");
+    });
+}
+
+#[test]
+fn test_trunc() {
+    test_all(|arg| {
+        let expected = fpmath::trunc(f32::from(arg));
+        let actual = fpmath::trunc(arg);
+
+        assert!(expected == f32::from(actual), "trunc({arg:e}) = {actual:e}");
+    });
+}
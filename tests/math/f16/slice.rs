@@ -0,0 +1,107 @@
+use rand::RngExt as _;
+
+use super::mkfloat;
+use crate::create_prng;
+
+const SPECIAL: [f16; 8] = [
+    0.0,
+    -0.0,
+    f16::INFINITY,
+    f16::NEG_INFINITY,
+    f16::NAN,
+    f16::MIN_POSITIVE / 3.0,
+    -1.0,
+    6.0e4,
+];
+
+#[test]
+fn test_exp() {
+    let x = gen_args(-14..=3);
+    check("exp", fpmath::slice::exp, fpmath::exp, &x);
+    check_into("exp", fpmath::slice::exp_into, fpmath::exp, &x);
+}
+
+#[test]
+fn test_ln() {
+    let x = gen_args(-14..=15);
+    check("ln", fpmath::slice::ln, fpmath::ln, &x);
+    check_into("ln", fpmath::slice::ln_into, fpmath::ln, &x);
+}
+
+#[test]
+fn test_sin_cos() {
+    let x = gen_args(-14..=15);
+    check("sin", fpmath::slice::sin, fpmath::sin, &x);
+    check_into("sin", fpmath::slice::sin_into, fpmath::sin, &x);
+    check("cos", fpmath::slice::cos, fpmath::cos, &x);
+    check_into("cos", fpmath::slice::cos_into, fpmath::cos, &x);
+
+    let check_sin_cos = |x: &[f16]| {
+        let mut sin = vec![0.0; x.len()];
+        let mut cos = vec![0.0; x.len()];
+        fpmath::slice::sin_cos_into(x, &mut sin, &mut cos);
+        for ((&x, &sin), &cos) in x.iter().zip(sin.iter()).zip(cos.iter()) {
+            let (expected_sin, expected_cos) = fpmath::sin_cos(x);
+            assert_same("sin_cos", x, sin, expected_sin);
+            assert_same("sin_cos", x, cos, expected_cos);
+        }
+    };
+    for_each_sub_slice(&x, check_sin_cos);
+}
+
+/// Generates random arguments with exponents in `exp_range`, mixed with
+/// special values
+fn gen_args(exp_range: core::ops::RangeInclusive<i16>) -> Vec<f16> {
+    let mut rng = create_prng();
+    (0..20000)
+        .map(|_| {
+            if rng.random_ratio(1, 64) {
+                SPECIAL[rng.random_range(0..SPECIAL.len())]
+            } else {
+                let e = rng.random_range(exp_range.clone());
+                mkfloat(rng.random(), e, rng.random())
+            }
+        })
+        .collect()
+}
+
+/// Calls `f` with `x` and with sub-slices of `x` of lengths that are not
+/// multiples of the group size
+fn for_each_sub_slice(x: &[f16], mut f: impl FnMut(&[f16])) {
+    f(x);
+    let mut start = 0;
+    for len in (0..=17).cycle() {
+        if start + len > x.len() {
+            break;
+        }
+        f(&x[start..(start + len)]);
+        start += len;
+    }
+}
+
+fn check(name: &str, slice_f: impl Fn(&mut [f16]), f: impl Fn(f16) -> f16, x: &[f16]) {
+    for_each_sub_slice(x, |x| {
+        let mut y = x.to_vec();
+        slice_f(&mut y);
+        for (&x, &y) in x.iter().zip(y.iter()) {
+            assert_same(name, x, y, f(x));
+        }
+    });
+}
+
+fn check_into(name: &str, slice_f: impl Fn(&[f16], &mut [f16]), f: impl Fn(f16) -> f16, x: &[f16]) {
+    for_each_sub_slice(x, |x| {
+        let mut y = vec![0.0; x.len()];
+        slice_f(x, &mut y);
+        for (&x, &y) in x.iter().zip(y.iter()) {
+            assert_same(name, x, y, f(x));
+        }
+    });
+}
+
+fn assert_same(name: &str, x: f16, actual: f16, expected: f16) {
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "{name}({x:e}) = {actual:e}, expected {expected:e}",
+    );
+}
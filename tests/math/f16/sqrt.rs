@@ -0,0 +1,21 @@
+use super::test_all_positive;
+
+#[test]
+fn test_sqrt() {
+    test_all_positive(|x| {
+        let actual = fpmath::sqrt(x);
+        // `f32` has enough precision to make the double rounding harmless
+        let expected = fpmath::sqrt(f32::from(x)) as f16;
+        assert_eq!(actual, expected);
+    });
+}
+
+#[test]
+fn test_rsqrt() {
+    test_all_positive(|x| {
+        let actual = fpmath::rsqrt(x);
+        // the result is never subnormal and `f32` represents it exactly
+        let expected = rug::Float::with_val(11, f32::from(x)).recip_sqrt().to_f32() as f16;
+        assert_eq!(actual, expected, "rsqrt({x:e})");
+    });
+}
@@ -0,0 +1,361 @@
+use super::{
+    calc_error_hi_lo_ulp, calc_error_ulp, purify, purify2, select_threshold, test_all_positive,
+};
+
+#[test]
+fn test_sin_cos() {
+    let mut max_sin1_error: f32 = 0.0;
+    let mut max_sin2_error: f32 = 0.0;
+    let mut max_cos1_error: f32 = 0.0;
+    let mut max_cos2_error: f32 = 0.0;
+    test_all_positive(|x| {
+        let (expected_sin, expected_cos) = fpmath::sin_cos(f32::from(x));
+
+        let actual_sin1 = fpmath::sin(x);
+        let actual_cos1 = fpmath::cos(x);
+        let (actual_sin2, actual_cos2) = fpmath::sin_cos(x);
+        assert_eq!(purify(fpmath::sin(-x)), purify(-actual_sin1));
+        assert_eq!(purify(fpmath::cos(-x)), purify(actual_cos1));
+        assert_eq!(
+            purify2(fpmath::sin_cos(-x)),
+            purify2((-actual_sin2, actual_cos2))
+        );
+
+        let sin1_err = calc_error_ulp(actual_sin1, expected_sin);
+        let sin2_err = calc_error_ulp(actual_sin2, expected_sin);
+        let cos1_err = calc_error_ulp(actual_cos1, expected_cos);
+        let cos2_err = calc_error_ulp(actual_cos2, expected_cos);
+
+        max_sin1_error = max_sin1_error.max(sin1_err);
+        max_sin2_error = max_sin2_error.max(sin2_err);
+        max_cos1_error = max_cos1_error.max(cos1_err);
+        max_cos2_error = max_cos2_error.max(cos2_err);
+
+        assert!(
+            sin1_err < 0.9,
+            "sin({x:e}) = {actual_sin1:e} (error = {sin1_err} ULP)",
+        );
+
+        assert!(
+            sin2_err < 0.9,
+            "sin({x:e}) = {actual_sin2:e} (error = {sin2_err} ULP)",
+        );
+
+        assert!(
+            cos1_err < 0.9,
+            "cos({x:e}) = {actual_cos1:e} (error = {cos1_err} ULP)",
+        );
+
+        assert!(
+            cos2_err < 0.9,
+            "cos({x:e}) = {actual_cos2:e} (error = {cos2_err} ULP)",
+        );
+    });
+    eprintln!("max sin1 error = {max_sin1_error}");
+    eprintln!("max sin2 error = {max_sin2_error}");
+    eprintln!("max cos1 error = {max_cos1_error}");
+    eprintln!("max cos2 error = {max_cos2_error}");
+    assert!(max_sin1_error > 0.5);
+    assert!(max_sin2_error > 0.5);
+    assert!(max_cos1_error > 0.5);
+    assert!(max_cos2_error > 0.5);
+}
+
+#[test]
+fn test_sin_cos_hi_lo() {
+    let mut max_sin_error: f32 = 0.0;
+    let mut max_cos_error: f32 = 0.0;
+    test_all_positive(|x| {
+        let (expected_sin, expected_cos) = fpmath::sin_cos(f32::from(x));
+
+        let (actual_sin, actual_cos) = fpmath::sin_cos_hi_lo(x);
+        let (neg_sin, neg_cos) = fpmath::sin_cos_hi_lo(-x);
+        assert_eq!(purify2(neg_sin), purify2((-actual_sin.0, -actual_sin.1)));
+        assert_eq!(purify2(neg_cos), purify2(actual_cos));
+
+        let sin_err = calc_error_hi_lo_ulp(actual_sin, expected_sin);
+        let cos_err = calc_error_hi_lo_ulp(actual_cos, expected_cos);
+
+        // `lo` loses precision close to the subnormal range
+        let sin_threshold = if actual_sin.0.abs() < f16::MIN_POSITIVE / f16::EPSILON {
+            1.0
+        } else {
+            max_sin_error = max_sin_error.max(sin_err);
+            0.15
+        };
+        let cos_threshold = if actual_cos.0.abs() < f16::MIN_POSITIVE / f16::EPSILON {
+            1.0
+        } else {
+            max_cos_error = max_cos_error.max(cos_err);
+            0.15
+        };
+
+        assert!(
+            sin_err < sin_threshold,
+            "sin_hi_lo({x:e}) = {actual_sin:?} (error = {sin_err} ULP)",
+        );
+        assert!(
+            cos_err < cos_threshold,
+            "cos_hi_lo({x:e}) = {actual_cos:?} (error = {cos_err} ULP)",
+        );
+    });
+    eprintln!("max sin_hi_lo error = {max_sin_error}");
+    eprintln!("max cos_hi_lo error = {max_cos_error}");
+    assert!(max_sin_error > 0.0);
+    assert!(max_cos_error > 0.0);
+}
+
+#[test]
+fn test_rem_pio2() {
+    let mut max_error: f32 = 0.0;
+    test_all_positive(|x| {
+        let (n, y_hi, y_lo) = fpmath::rem_pio2(x);
+        assert!((0..=3).contains(&n), "rem_pio2({x:e}).0 = {n}");
+        let (neg_n, neg_hi, neg_lo) = fpmath::rem_pio2(-x);
+        assert_eq!(neg_n, (4 - n) & 3);
+        assert_eq!(purify2((neg_hi, neg_lo)), purify2((-y_hi, -y_lo)));
+
+        let expected = reduce_rug(x, n);
+        assert!(
+            expected.abs() <= std::f32::consts::FRAC_PI_4 * 1.01,
+            "rem_pio2({x:e}) = {:?} (expected {expected:e})",
+            (n, y_hi, y_lo),
+        );
+
+        let err = calc_error_hi_lo_ulp((y_hi, y_lo), expected);
+        // `lo` loses precision close to the subnormal range
+        let threshold = if y_hi.abs() < f16::MIN_POSITIVE / f16::EPSILON {
+            1.0
+        } else {
+            max_error = max_error.max(err);
+            0.002
+        };
+        assert!(
+            err < threshold,
+            "rem_pio2({x:e}) = {:?} (error = {err} ULP)",
+            (n, y_hi, y_lo),
+        );
+    });
+    eprintln!("max rem_pio2 error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_wrap_angle() {
+    let mut max_error: f32 = 0.0;
+    test_all_positive(|x| {
+        let actual = fpmath::wrap_angle(x);
+        assert_eq!(purify(fpmath::wrap_angle(-x)), purify(-actual));
+
+        let mut expected = reduce_rug(x, 0);
+        if expected.abs() > 3.0 && expected.is_sign_negative() != actual.is_sign_negative() {
+            // π and -π are both valid
+            expected += std::f32::consts::TAU.copysign(f32::from(actual));
+        }
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+        assert!(
+            err < select_threshold(actual, 0.9, 0.9),
+            "wrap_angle({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max wrap_angle error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+/// Calculates `x - π/2*n - 2*π*M`, with `M` chosen to minimize the result
+fn reduce_rug(x: f16, n: i32) -> f32 {
+    // enough bits to keep the precision of `f32` after cancellation
+    let prec = 16 + 24 + 64;
+    let pi = rug::Float::with_val(prec, rug::float::Constant::Pi);
+    let two_pi = pi.clone() * 2u8;
+    let y = rug::Float::with_val(prec, f32::from(x) - pi * n / 2u8);
+    let m = (y.clone() / &two_pi).round();
+    (y - m * two_pi).to_f32()
+}
+
+#[test]
+fn test_sind_cosd() {
+    let mut max_sin1_error: f32 = 0.0;
+    let mut max_sin2_error: f32 = 0.0;
+    let mut max_cos1_error: f32 = 0.0;
+    let mut max_cos2_error: f32 = 0.0;
+    test_all_positive(|x| {
+        let (expected_sin, expected_cos) = fpmath::sind_cosd(f32::from(x));
+
+        let actual_sin1 = fpmath::sind(x);
+        let actual_cos1 = fpmath::cosd(x);
+        let (actual_sin2, actual_cos2) = fpmath::sind_cosd(x);
+        assert_eq!(purify(fpmath::sind(-x)), purify(-actual_sin1));
+        assert_eq!(purify(fpmath::cosd(-x)), purify(actual_cos1));
+        assert_eq!(
+            purify2(fpmath::sind_cosd(-x)),
+            purify2((-actual_sin2, actual_cos2))
+        );
+
+        let sin1_err = calc_error_ulp(actual_sin1, expected_sin);
+        let sin2_err = calc_error_ulp(actual_sin2, expected_sin);
+        let cos1_err = calc_error_ulp(actual_cos1, expected_cos);
+        let cos2_err = calc_error_ulp(actual_cos2, expected_cos);
+
+        max_sin1_error = max_sin1_error.max(sin1_err);
+        max_sin2_error = max_sin2_error.max(sin2_err);
+        max_cos1_error = max_cos1_error.max(cos1_err);
+        max_cos2_error = max_cos2_error.max(cos2_err);
+
+        let sin1_threshold = select_threshold(actual_sin1, 0.9, 1.9);
+        assert!(
+            sin1_err < sin1_threshold,
+            "sind({x:e}) = {actual_sin1:e} (error = {sin1_err} ULP)",
+        );
+
+        let sin2_threshold = select_threshold(actual_sin2, 0.9, 1.9);
+        assert!(
+            sin2_err < sin2_threshold,
+            "sind({x:e}) = {actual_sin2:e} (error = {sin2_err} ULP)",
+        );
+
+        let cos1_threshold = select_threshold(actual_cos1, 0.9, 1.9);
+        assert!(
+            cos1_err < cos1_threshold,
+            "cosd({x:e}) = {actual_cos1:e} (error = {cos1_err} ULP)",
+        );
+
+        let cos2_threshold = select_threshold(actual_cos2, 0.9, 1.9);
+        assert!(
+            cos2_err < cos2_threshold,
+            "cosd({x:e}) = {actual_cos2:e} (error = {cos2_err} ULP)",
+        );
+    });
+    eprintln!("max sind1 error = {max_sin1_error}");
+    eprintln!("max sind2 error = {max_sin2_error}");
+    eprintln!("max cosd1 error = {max_cos1_error}");
+    eprintln!("max cosd2 error = {max_cos2_error}");
+    assert!(max_sin1_error > 0.5);
+    assert!(max_sin2_error > 0.5);
+    assert!(max_cos1_error > 0.5);
+    assert!(max_cos2_error > 0.5);
+}
+
+#[test]
+fn test_sinpi_cospi() {
+    let mut max_sin1_error: f32 = 0.0;
+    let mut max_sin2_error: f32 = 0.0;
+    let mut max_cos1_error: f32 = 0.0;
+    let mut max_cos2_error: f32 = 0.0;
+    test_all_positive(|x| {
+        let (expected_sin, expected_cos) = fpmath::sinpi_cospi(f32::from(x));
+
+        let actual_sin1 = fpmath::sinpi(x);
+        let actual_cos1 = fpmath::cospi(x);
+        let (actual_sin2, actual_cos2) = fpmath::sinpi_cospi(x);
+        assert_eq!(purify(fpmath::sinpi(-x)), purify(-actual_sin1));
+        assert_eq!(purify(fpmath::cospi(-x)), purify(actual_cos1));
+        assert_eq!(
+            purify2(fpmath::sinpi_cospi(-x)),
+            purify2((-actual_sin2, actual_cos2))
+        );
+
+        let sin1_err = calc_error_ulp(actual_sin1, expected_sin);
+        let sin2_err = calc_error_ulp(actual_sin2, expected_sin);
+        let cos1_err = calc_error_ulp(actual_cos1, expected_cos);
+        let cos2_err = calc_error_ulp(actual_cos2, expected_cos);
+
+        max_sin1_error = max_sin1_error.max(sin1_err);
+        max_sin2_error = max_sin2_error.max(sin2_err);
+        max_cos1_error = max_cos1_error.max(cos1_err);
+        max_cos2_error = max_cos2_error.max(cos2_err);
+
+        let sin1_threshold = select_threshold(actual_sin1, 0.9, 1.9);
+        assert!(
+            sin1_err < sin1_threshold,
+            "sinpi({x:e}) = {actual_sin1:e} (error = {sin1_err} ULP)",
+        );
+
+        let sin2_threshold = select_threshold(actual_sin2, 0.9, 1.9);
+        assert!(
+            sin2_err < sin2_threshold,
+            "sinpi({x:e}) = {actual_sin2:e} (error = {sin2_err} ULP)",
+        );
+
+        let cos1_threshold = select_threshold(actual_cos1, 0.9, 1.9);
+        assert!(
+            cos1_err < cos1_threshold,
+            "cospi({x:e}) = {actual_cos1:e} (error = {cos1_err} ULP)",
+        );
+
+        let cos2_threshold = select_threshold(actual_cos2, 0.9, 1.9);
+        assert!(
+            cos2_err < cos2_threshold,
+            "cospi({x:e}) = {actual_cos2:e} (error = {cos2_err} ULP)",
+        );
+    });
+    eprintln!("max sinpi1 error = {max_sin1_error}");
+    eprintln!("max sinpi2 error = {max_sin2_error}");
+    eprintln!("max cospi1 error = {max_cos1_error}");
+    eprintln!("max cospi2 error = {max_cos2_error}");
+    assert!(max_sin1_error > 0.5);
+    assert!(max_sin2_error > 0.5);
+    assert!(max_cos1_error > 0.5);
+    assert!(max_cos2_error > 0.5);
+}
+
+#[test]
+fn test_tan() {
+    let mut max_error: f32 = 0.0;
+    test_all_positive(|x| {
+        let expected = fpmath::tan(f32::from(x));
+        let actual = fpmath::tan(x);
+        assert_eq!(purify(fpmath::tan(-x)), purify(-actual));
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "tan({x:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max tan error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_tand() {
+    let mut max_error: f32 = 0.0;
+    test_all_positive(|x| {
+        let expected = fpmath::tand(f32::from(x));
+        let actual = fpmath::tand(x);
+        assert_eq!(purify(fpmath::tand(-x)), purify(-actual));
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "tand({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max tand error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_tanpi() {
+    let mut max_error: f32 = 0.0;
+    test_all_positive(|x| {
+        let expected = fpmath::tanpi(f32::from(x));
+        let actual = fpmath::tanpi(x);
+        assert_eq!(purify(fpmath::tanpi(-x)), purify(-actual));
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "tanpi({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max tanpi error = {max_error}");
+    assert!(max_error > 0.5);
+}
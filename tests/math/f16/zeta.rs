@@ -0,0 +1,61 @@
+use rand::RngExt as _;
+
+use super::{calc_error_ulp, mkfloat, purify, select_threshold, test_all};
+use crate::create_prng;
+
+#[test]
+fn test_zeta() {
+    let mut max_error: f32 = 0.0;
+    test_all(|s| {
+        let expected = fpmath::zeta(f32::from(s));
+        let actual = fpmath::zeta(s);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "zeta({s:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max zeta error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+#[test]
+fn test_hurwitz_zeta() {
+    let mut max_error: f32 = 0.0;
+    test_with_hurwitz_zeta(|s, q| {
+        let expected = fpmath::hurwitz_zeta(f32::from(s), f32::from(q));
+        let actual = fpmath::hurwitz_zeta(s, q);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "hurwitz_zeta({s:e}, {q:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max hurwitz_zeta error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+fn test_with_hurwitz_zeta(mut f: impl FnMut(f16, f16)) {
+    let mut rng = create_prng();
+
+    for es in -10..=4 {
+        for eq in -14..=15 {
+            for _ in 0..100 {
+                let s = purify(1.0 + mkfloat(rng.random::<u16>(), es, false));
+                let q = mkfloat(rng.random::<u16>(), eq, false);
+                f(s, q);
+            }
+        }
+    }
+
+    for s in 2..=10 {
+        for i in 1..=1000 {
+            let q = purify((i as f16) / 100.0);
+            f(s as f16, q);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use rand::RngExt as _;
+
+use super::{calc_error_ulp, mkfloat, purify, select_threshold};
+use crate::create_prng;
+
+#[test]
+fn test_j0() {
+    let mut max_error: f32 = 0.0;
+    test_with(|x| {
+        let expected = fpmath::j0(f64::from(x));
+        let actual = fpmath::j0(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold || calc_error_amp(actual, expected, x) < 1.0 / 64.0,
+            "j0({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max j0 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_j1() {
+    let mut max_error: f32 = 0.0;
+    test_with(|x| {
+        let expected = fpmath::j1(f64::from(x));
+        let actual = fpmath::j1(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold || calc_error_amp(actual, expected, x) < 1.0 / 64.0,
+            "j1({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max j1 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_jn() {
+    let mut max_error: f32 = 0.0;
+    for n in [-6, -5, 2, 3, 7, 20] {
+        test_with_short(|x| {
+            let expected = fpmath::jn(n, f64::from(x));
+            let actual = fpmath::jn(n, x);
+
+            let err = calc_error_ulp(actual, expected);
+            max_error = max_error.max(err);
+
+            assert!(
+                err < 32.0 || calc_error_amp(actual, expected, x) < 32.0,
+                "jn({n}, {x:e}) = {actual:e} (error = {err} ULP)",
+            );
+        });
+    }
+    eprintln!("max jn error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_y0() {
+    let mut max_error: f32 = 0.0;
+    test_with(|x| {
+        let expected = fpmath::y0(f64::from(x));
+        let actual = fpmath::y0(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold || calc_error_amp(actual, expected, x) < 1.0 / 64.0,
+            "y0({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max y0 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_y1() {
+    let mut max_error: f32 = 0.0;
+    test_with(|x| {
+        let expected = fpmath::y1(f64::from(x));
+        let actual = fpmath::y1(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold || calc_error_amp(actual, expected, x) < 1.0 / 64.0,
+            "y1({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max y1 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_yn() {
+    let mut max_error: f32 = 0.0;
+    for n in [-6, -5, 2, 3, 7, 20] {
+        test_with_short(|x| {
+            let expected = fpmath::yn(n, f64::from(x));
+            let actual = fpmath::yn(n, x);
+
+            let err = calc_error_ulp(actual, expected);
+            max_error = max_error.max(err);
+
+            assert!(
+                err < 32.0 || calc_error_amp(actual, expected, x) < 32.0,
+                "yn({n}, {x:e}) = {actual:e} (error = {err} ULP)",
+            );
+        });
+    }
+    eprintln!("max yn error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+/// Calculates the error relative to the amplitude of the oscillations,
+/// `min(1, √(2 / (π * |x|)))`, in units of `f32::EPSILON`.
+///
+/// Near the zeros of the functions, the relative error can be large even
+/// when the absolute error is small.
+fn calc_error_amp(actual: f32, expected: f64, x: f32) -> f32 {
+    let amp = (2.0 / (std::f64::consts::PI * f64::from(x).abs()))
+        .sqrt()
+        .min(1.0);
+    let dif = (expected - f64::from(purify(actual))).abs();
+    (dif / amp / f64::from(f32::EPSILON)) as f32
+}
+
+fn test_with(mut f: impl FnMut(f32)) {
+    let mut rng = create_prng();
+
+    for e in -126..=127 {
+        for _ in 0..3000 {
+            let m = rng.random::<u32>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    for i in 0..5000 {
+        let x = purify((i as f32) / 100.0);
+        f(x);
+        f(-x);
+    }
+
+    // subnormals
+    for i in 0..23 {
+        let x = f32::from_bits(1 << i);
+        f(x);
+        f(-x);
+        let x = f32::from_bits((1 << (i + 1)) - 1);
+        f(x);
+        f(-x);
+    }
+}
+
+fn test_with_short(mut f: impl FnMut(f32)) {
+    let mut rng = create_prng();
+
+    for e in -30..=30 {
+        for _ in 0..1000 {
+            let m = rng.random::<u32>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    for i in 0..5000 {
+        let x = purify((i as f32) / 100.0);
+        f(x);
+        f(-x);
+    }
+}
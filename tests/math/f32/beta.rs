@@ -0,0 +1,177 @@
+use rand::RngExt as _;
+
+use super::{calc_error_ulp, mkfloat, purify, select_threshold};
+use crate::create_prng;
+
+#[test]
+fn test_beta() {
+    let mut max_error: f32 = 0.0;
+    test_with(|a, b| {
+        let expected = fpmath::beta(f64::from(a), f64::from(b));
+        let actual = fpmath::beta(a, b);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = if a > 0.0 && b > 0.0 {
+            select_threshold(actual, 1.9, 2.9)
+        } else {
+            select_threshold(actual, 3.9, 4.9)
+        };
+        assert!(
+            err < threshold,
+            "beta({a:e}, {b:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max beta error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_ln_beta() {
+    let mut max_error: f32 = 0.0;
+    test_with(|a, b| {
+        let (expected, expected_sign) = fpmath::ln_beta(f64::from(a), f64::from(b));
+        let (actual, actual_sign) = fpmath::ln_beta(a, b);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        // The absolute error is kept small, but the relative error can be
+        // larger when the result is close to zero
+        let threshold = if a > 0.0 && b > 0.0 { 1.9 } else { 3.9 };
+        assert_eq!(expected_sign, actual_sign);
+        assert!(
+            err < threshold || calc_error_abs(actual, expected) < 0.5,
+            "ln_beta({a:e}, {b:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max ln_beta error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_beta_inc() {
+    let mut max_error: f32 = 0.0;
+    test_with_inc(|a, b, x| {
+        let expected = fpmath::beta_inc(f64::from(a), f64::from(b), f64::from(x));
+        let actual = fpmath::beta_inc(a, b, x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "beta_inc({a:e}, {b:e}, {x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max beta_inc error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_beta_inc_inv() {
+    let mut max_error: f32 = 0.0;
+    test_with_inc(|a, b, x| {
+        let p = fpmath::beta_inc(a, b, x);
+        // The accuracy is not guaranteed when `p` is subnormal or when `a`
+        // and `b` are both small
+        if p == 0.0 || p == 1.0 || p.is_subnormal() || a + b < 0.5 {
+            return;
+        }
+
+        let expected = fpmath::beta_inc_inv(f64::from(a), f64::from(b), f64::from(p));
+        let actual = fpmath::beta_inc_inv(a, b, p);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 1.9, 2.9);
+        assert!(
+            err < threshold,
+            "beta_inc_inv({a:e}, {b:e}, {p:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max beta_inc_inv error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+/// Calculates the absolute error in units of `f32::EPSILON`.
+fn calc_error_abs(actual: f32, expected: f64) -> f32 {
+    let dif = (expected - f64::from(purify(actual))).abs();
+    (dif / f64::from(f32::EPSILON)) as f32
+}
+
+fn test_with(mut f: impl FnMut(f32, f32)) {
+    let mut rng = create_prng();
+
+    for ea in (-40..=40).step_by(2) {
+        for eb in (-40..=80).step_by(4) {
+            for _ in 0..10 {
+                let a = mkfloat(rng.random::<u32>(), ea, false);
+                let b = mkfloat(rng.random::<u32>(), eb, false);
+                f(a, b);
+            }
+        }
+    }
+
+    for i in 1..=100 {
+        for j in 1..=100 {
+            let a = purify((i as f32) / 10.0);
+            let b = purify((j as f32) / 10.0);
+            f(a, b);
+        }
+    }
+
+    // Negative arguments, away from the poles of the gamma function
+    // of `a`, `b` and `a + b`
+    for _ in 0..10000 {
+        let a = -rng.random_range(0.0..20.0f32);
+        let b = rng.random_range(-20.0..200.0f32);
+        let away_from_int = |x: f32| (x - x.round()).abs() > 0.1;
+        if away_from_int(a) && away_from_int(b) && away_from_int(a + b) {
+            f(a, b);
+        }
+    }
+}
+
+fn test_with_inc(mut f: impl FnMut(f32, f32, f32)) {
+    let mut rng = create_prng();
+
+    for ea in (-20..=20).step_by(4) {
+        for eb in (-20..=20).step_by(4) {
+            for ex in (-40..=-1).step_by(3) {
+                for _ in 0..5 {
+                    let a = mkfloat(rng.random::<u32>(), ea, false);
+                    let b = mkfloat(rng.random::<u32>(), eb, false);
+                    let x = mkfloat(rng.random::<u32>(), ex, false);
+                    f(a, b, x);
+                    // Close to one
+                    f(a, b, purify(1.0 - x));
+                }
+            }
+        }
+    }
+
+    for i in 1..=20 {
+        for j in 1..=20 {
+            for k in 1..20 {
+                let a = purify((i as f32) / 2.0);
+                let b = purify((j as f32) / 2.0);
+                let x = purify((k as f32) / 20.0);
+                f(a, b, x);
+            }
+        }
+    }
+
+    // Close to the mean, where `I` and `1 - I` are not small
+    for _ in 0..20000 {
+        let a = rng.random_range(1.0..100000.0f32);
+        let b = rng.random_range(1.0..100000.0f32);
+        let x = purify(a / (a + b) * rng.random_range(0.9..1.1f32));
+        if x < 1.0 {
+            f(a, b, x);
+        }
+    }
+}
@@ -0,0 +1,283 @@
+use fpmath::complex::Complex;
+use rand::RngExt as _;
+
+use super::{calc_error_ulp, mkfloat};
+use crate::create_prng;
+
+fn to_f64(z: Complex<f32>) -> Complex<f64> {
+    Complex::new(f64::from(z.re), f64::from(z.im))
+}
+
+/// Calculates the error of each part in ULP of that part and returns the
+/// largest one.
+fn calc_error(actual: Complex<f32>, expected: Complex<f64>) -> f32 {
+    let re = calc_error_ulp(actual.re, expected.re);
+    let im = calc_error_ulp(actual.im, expected.im);
+    re.max(im)
+}
+
+/// Calculates the error of each part in ULP of the largest part of the
+/// expected value and returns the largest one.
+fn calc_norm_error(actual: Complex<f32>, expected: Complex<f64>) -> f32 {
+    let mag = expected.re.abs().max(expected.im.abs());
+    if !mag.is_finite() || mag == 0.0 {
+        return calc_error(actual, expected);
+    }
+    let exp = (fpmath::frexp(mag).1 - 1).max(-126);
+    let part_error = |actual: f32, expected: f64| {
+        if actual.is_finite() && expected.abs() <= f64::from(f32::MAX) {
+            fpmath::scalbn((expected - f64::from(actual)).abs(), 23 - exp) as f32
+        } else {
+            calc_error_ulp(actual, expected)
+        }
+    };
+    part_error(actual.re, expected.re).max(part_error(actual.im, expected.im))
+}
+
+fn test_unary(
+    name: &str,
+    f: impl Fn(Complex<f32>) -> Complex<f32>,
+    f64_f: impl Fn(Complex<f64>) -> Complex<f64>,
+    threshold: f32,
+) {
+    let mut max_error: f32 = 0.0;
+    test_with(|z| {
+        let expected = f64_f(to_f64(z));
+        let actual = f(z);
+
+        let err = calc_error(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < threshold,
+            "{name}({:e} + {:e}i) = {:e} + {:e}i (error = {err} ULP)",
+            z.re,
+            z.im,
+            actual.re,
+            actual.im,
+        );
+    });
+    eprintln!("max complex {name} error = {max_error}");
+}
+
+#[test]
+fn test_exp() {
+    test_unary("exp", Complex::exp, Complex::exp, 2.5);
+}
+
+#[test]
+fn test_ln() {
+    test_unary("ln", Complex::ln, Complex::ln, 0.9);
+}
+
+#[test]
+fn test_sqrt() {
+    test_unary("sqrt", Complex::sqrt, Complex::sqrt, 2.5);
+}
+
+#[test]
+fn test_sin() {
+    test_unary("sin", Complex::sin, Complex::sin, 3.0);
+}
+
+#[test]
+fn test_cos() {
+    test_unary("cos", Complex::cos, Complex::cos, 3.0);
+}
+
+#[test]
+fn test_tan() {
+    test_unary("tan", Complex::tan, Complex::tan, 5.0);
+}
+
+#[test]
+fn test_sinh() {
+    test_unary("sinh", Complex::sinh, Complex::sinh, 3.0);
+}
+
+#[test]
+fn test_cosh() {
+    test_unary("cosh", Complex::cosh, Complex::cosh, 3.0);
+}
+
+#[test]
+fn test_tanh() {
+    test_unary("tanh", Complex::tanh, Complex::tanh, 5.0);
+}
+
+#[test]
+fn test_asin() {
+    test_unary("asin", Complex::asin, Complex::asin, 3.0);
+}
+
+#[test]
+fn test_acos() {
+    test_unary("acos", Complex::acos, Complex::acos, 3.0);
+}
+
+#[test]
+fn test_atan() {
+    test_unary("atan", Complex::atan, Complex::atan, 3.0);
+}
+
+#[test]
+fn test_asinh() {
+    test_unary("asinh", Complex::asinh, Complex::asinh, 3.0);
+}
+
+#[test]
+fn test_acosh() {
+    test_unary("acosh", Complex::acosh, Complex::acosh, 3.0);
+}
+
+#[test]
+fn test_atanh() {
+    test_unary("atanh", Complex::atanh, Complex::atanh, 3.0);
+}
+
+fn test_binary(
+    name: &str,
+    f: impl Fn(Complex<f32>, Complex<f32>) -> Complex<f32>,
+    f64_f: impl Fn(Complex<f64>, Complex<f64>) -> Complex<f64>,
+    threshold: f32,
+) {
+    let mut max_error: f32 = 0.0;
+    test_binary_with(|z, w| {
+        let expected = f64_f(to_f64(z), to_f64(w));
+        let actual = f(z, w);
+
+        let err = calc_error(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < threshold,
+            "{name}({:e} + {:e}i, {:e} + {:e}i) = {:e} + {:e}i (error = {err} ULP)",
+            z.re,
+            z.im,
+            w.re,
+            w.im,
+            actual.re,
+            actual.im,
+        );
+    });
+    eprintln!("max complex {name} error = {max_error}");
+}
+
+#[test]
+fn test_mul() {
+    test_binary("mul", |z, w| z * w, |z, w| z * w, 0.9);
+}
+
+#[test]
+fn test_div() {
+    test_binary("div", |z, w| z / w, |z, w| z / w, 0.9);
+}
+
+#[test]
+fn test_pow() {
+    let mut max_error: f32 = 0.0;
+    test_pow_with(|z, w| {
+        let expected = to_f64(z).pow(to_f64(w));
+        let actual = z.pow(w);
+
+        let err = calc_norm_error(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 6.0,
+            "pow({:e} + {:e}i, {:e} + {:e}i) = {:e} + {:e}i (error = {err} ULP)",
+            z.re,
+            z.im,
+            w.re,
+            w.im,
+            actual.re,
+            actual.im,
+        );
+    });
+    eprintln!("max complex pow error = {max_error}");
+}
+
+fn test_with(mut f: impl FnMut(Complex<f32>)) {
+    let mut rng = create_prng();
+
+    for ex in -126..=127 {
+        for ey in -126..=127 {
+            for _ in 0..2 {
+                let x = rand_float(&mut rng, ex);
+                let y = rand_float(&mut rng, ey);
+                f(Complex::new(x, y));
+            }
+        }
+    }
+    for e in -30..=-1 {
+        for _ in 0..100 {
+            // near the unit circle
+            let r = 1.0 + rand_float(&mut rng, e);
+            let t = rand_float(&mut rng, 1);
+            f(Complex::new(r * t.cos(), r * t.sin()));
+
+            // near ±1 and ±i
+            let a = rand_float(&mut rng, 0).signum() * (1.0 + rand_float(&mut rng, e));
+            let eb = rng.random_range(-126..=0);
+            let b = rand_float(&mut rng, eb);
+            f(Complex::new(a, b));
+            f(Complex::new(b, a));
+        }
+    }
+}
+
+fn test_binary_with(mut f: impl FnMut(Complex<f32>, Complex<f32>)) {
+    let mut rng = create_prng();
+
+    for _ in 0..50000 {
+        let [ea, eb, ec, ed] = [(); 4].map(|()| rng.random_range(-126..=127));
+        let a = rand_float(&mut rng, ea);
+        let b = rand_float(&mut rng, eb);
+        let c = rand_float(&mut rng, ec);
+        let d = rand_float(&mut rng, ed);
+        f(Complex::new(a, b), Complex::new(c, d));
+    }
+    for _ in 0..50000 {
+        let [ea, eb, ec, ed] = [(); 4].map(|()| rng.random_range(-20..=20));
+        let a = rand_float(&mut rng, ea);
+        let b = rand_float(&mut rng, eb);
+        let c = rand_float(&mut rng, ec);
+        let d = rand_float(&mut rng, ed);
+        f(Complex::new(a, b), Complex::new(c, d));
+
+        // a * c - b * d or a * c + b * d cancel
+        let e = rng.random_range(-30..=-1);
+        let c = b * (1.0 + rand_float(&mut rng, e));
+        let d = a * rand_float(&mut rng, 0).signum();
+        f(Complex::new(a, b), Complex::new(c, d));
+        f(Complex::new(a, b), Complex::new(d, c));
+    }
+}
+
+fn test_pow_with(mut f: impl FnMut(Complex<f32>, Complex<f32>)) {
+    let mut rng = create_prng();
+
+    for _ in 0..50000 {
+        let [ea, eb] = [(); 2].map(|()| rng.random_range(-20..=20));
+        let [ec, ed] = [(); 2].map(|()| rng.random_range(-20..=3));
+        let a = rand_float(&mut rng, ea);
+        let b = rand_float(&mut rng, eb);
+        let c = rand_float(&mut rng, ec);
+        let d = rand_float(&mut rng, ed);
+        f(Complex::new(a, b), Complex::new(c, d));
+    }
+    for _ in 0..10000 {
+        // real and integer exponents
+        let ea = rng.random_range(-20..=20);
+        let a = rand_float(&mut rng, ea);
+        let b = rand_float(&mut rng, ea);
+        let n = rng.random_range(-10i16..=10);
+        f(Complex::new(a, b), Complex::new(f32::from(n), 0.0));
+        let c = rand_float(&mut rng, 2);
+        f(Complex::new(a, b), Complex::new(c, 0.0));
+    }
+}
+
+fn rand_float(rng: &mut impl rand::Rng, e: i16) -> f32 {
+    mkfloat(rng.random::<u32>(), e, rng.random::<bool>())
+}
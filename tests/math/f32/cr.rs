@@ -0,0 +1,326 @@
+use rand::RngExt as _;
+use rug::ops::Pow as _;
+
+use super::{mkfloat, purify};
+use crate::create_prng;
+
+// Enough precision to make the double rounding in `to_f32` harmless
+const CR_RUG_PREC: u32 = 24 * 5;
+
+#[test]
+fn test_exp() {
+    let mut rng = create_prng();
+    for e in -30..=7 {
+        for _ in 0..20000 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("exp", fpmath::cr::exp, rug::Float::exp, x);
+            check("exp_m1", fpmath::cr::exp_m1, rug::Float::exp_m1, x);
+            check("exp2", fpmath::cr::exp2, rug::Float::exp2, x * 1.5);
+            check("exp10", fpmath::cr::exp10, rug::Float::exp10, x * 0.5);
+        }
+    }
+
+    // results close to or in the subnormal range
+    for _ in 0..50000 {
+        let x = rng.random_range(-104.0..-85.0);
+        check("exp", fpmath::cr::exp, rug::Float::exp, x);
+        check("exp2", fpmath::cr::exp2, rug::Float::exp2, x * 1.45);
+        check("exp10", fpmath::cr::exp10, rug::Float::exp10, x * 0.435);
+    }
+
+    // exact results
+    for i in -149..=127 {
+        check("exp2", fpmath::cr::exp2, rug::Float::exp2, i as f32);
+    }
+    for i in -12..=12 {
+        check("exp10", fpmath::cr::exp10, rug::Float::exp10, i as f32);
+    }
+}
+
+#[test]
+fn test_log() {
+    let mut rng = create_prng();
+    for e in -126..=127 {
+        for _ in 0..5000 {
+            let x = mkfloat(rng.random(), e, false);
+            check("ln", fpmath::cr::ln, rug::Float::ln, x);
+            check("log2", fpmath::cr::log2, rug::Float::log2, x);
+            check("log10", fpmath::cr::log10, rug::Float::log10, x);
+            check("ln_1p", fpmath::cr::ln_1p, rug::Float::ln_1p, x);
+        }
+    }
+
+    // close to 1
+    for e in -30..=-1 {
+        for _ in 0..5000 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("ln", fpmath::cr::ln, rug::Float::ln, 1.0 + x);
+            check("log2", fpmath::cr::log2, rug::Float::log2, 1.0 + x);
+            check("log10", fpmath::cr::log10, rug::Float::log10, 1.0 + x);
+            check("ln_1p", fpmath::cr::ln_1p, rug::Float::ln_1p, x);
+        }
+    }
+
+    // subnormals
+    for i in 0..23 {
+        let x = f32::from_bits((1 << (i + 1)) - 1);
+        check("ln", fpmath::cr::ln, rug::Float::ln, x);
+        check("log2", fpmath::cr::log2, rug::Float::log2, x);
+        check("log10", fpmath::cr::log10, rug::Float::log10, x);
+    }
+
+    // exact results
+    for i in -149..=127 {
+        check("log2", fpmath::cr::log2, rug::Float::log2, 2f32.powi(i));
+    }
+    for i in 0..=10 {
+        check("log10", fpmath::cr::log10, rug::Float::log10, 10f32.powi(i));
+    }
+}
+
+#[test]
+fn test_trigonometric() {
+    let mut rng = create_prng();
+    for e in -16..=127 {
+        let n = if e <= 24 { 20000 } else { 2000 };
+        for _ in 0..n {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("sin", fpmath::cr::sin, rug::Float::sin, x);
+            check("cos", fpmath::cr::cos, rug::Float::cos, x);
+            check("tan", fpmath::cr::tan, rug::Float::tan, x);
+        }
+    }
+
+    // close to multiples of π/2
+    for k in 1..=2000 {
+        let x = k as f32 * std::f32::consts::FRAC_PI_2;
+        for x in [x, x.next_up(), x.next_down()] {
+            check("sin", fpmath::cr::sin, rug::Float::sin, x);
+            check("cos", fpmath::cr::cos, rug::Float::cos, x);
+            check("tan", fpmath::cr::tan, rug::Float::tan, x);
+        }
+    }
+}
+
+#[test]
+fn test_inverse_trigonometric() {
+    let mut rng = create_prng();
+    for e in -16..=30 {
+        for _ in 0..5000 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("atan", fpmath::cr::atan, rug::Float::atan, x);
+            if e < 0 {
+                check("asin", fpmath::cr::asin, rug::Float::asin, x);
+                check("acos", fpmath::cr::acos, rug::Float::acos, x);
+            }
+        }
+    }
+
+    // close to ±1
+    for e in -24..=-2 {
+        for _ in 0..2000 {
+            let s = rng.random::<bool>();
+            let x = (1.0 - mkfloat(rng.random(), e, false)) * if s { -1.0 } else { 1.0 };
+            check("asin", fpmath::cr::asin, rug::Float::asin, x);
+            check("acos", fpmath::cr::acos, rug::Float::acos, x);
+        }
+    }
+
+    for ey in -30..=30 {
+        for _ in 0..1000 {
+            let ex = rng.random_range(-30..=30);
+            let y = mkfloat(rng.random(), ey, rng.random());
+            let x = mkfloat(rng.random(), ex, rng.random());
+            check2("atan2", fpmath::cr::atan2, rug::Float::atan2, y, x);
+        }
+    }
+
+    // results close to or in the subnormal range
+    for ey in -127..=-100 {
+        for _ in 0..200 {
+            let y = mkfloat(rng.random(), ey, rng.random());
+            let x = mkfloat(rng.random(), rng.random_range(0..=30), false);
+            check2("atan2", fpmath::cr::atan2, rug::Float::atan2, y, x);
+        }
+    }
+}
+
+#[test]
+fn test_hyperbolic() {
+    let mut rng = create_prng();
+    for e in -16..=6 {
+        for _ in 0..10000 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("sinh", fpmath::cr::sinh, rug::Float::sinh, x);
+            check("cosh", fpmath::cr::cosh, rug::Float::cosh, x);
+            check("tanh", fpmath::cr::tanh, rug::Float::tanh, x);
+        }
+    }
+
+    // results close to overflow
+    for _ in 0..20000 {
+        let x = rng.random_range(85.0f32..90.0);
+        check("sinh", fpmath::cr::sinh, rug::Float::sinh, x);
+        check("cosh", fpmath::cr::cosh, rug::Float::cosh, x);
+    }
+}
+
+#[test]
+fn test_cbrt_hypot() {
+    let mut rng = create_prng();
+    for e in -127..=127 {
+        for _ in 0..1000 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("cbrt", fpmath::cr::cbrt, rug::Float::cbrt, x);
+
+            let ey = (e + rng.random_range(-20..=20)).clamp(-127, 127);
+            let y = mkfloat(rng.random(), ey, rng.random());
+            check2("hypot", fpmath::cr::hypot, rug::Float::hypot, x, y);
+        }
+    }
+
+    // exact results
+    for a in 1..(1u32 << 8) {
+        let a = a as f32;
+        check("cbrt", fpmath::cr::cbrt, rug::Float::cbrt, a * a * a);
+        check(
+            "cbrt",
+            fpmath::cr::cbrt,
+            rug::Float::cbrt,
+            -a * a * a * 0.125,
+        );
+    }
+
+    // hypot(m^2 - n^2, 2 * m * n) = m^2 + n^2, which is halfway between
+    // two floats when it is odd and has 25 bits
+    for n in 2897..4000u32 {
+        for d in 0..10 {
+            let m = n + 2 * d + 1;
+            let a = (m * m - n * n) as f32;
+            let b = (2 * m * n) as f32;
+            check2("hypot", fpmath::cr::hypot, rug::Float::hypot, a, b);
+            check2(
+                "hypot",
+                fpmath::cr::hypot,
+                rug::Float::hypot,
+                a * 0.5,
+                b * 0.5,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_pow() {
+    let mut rng = create_prng();
+    for ex in -10..=10 {
+        for ey in -10..=5 {
+            for _ in 0..500 {
+                let sy = rng.random::<bool>();
+                let x = mkfloat(rng.random(), ex, false);
+                let y = mkfloat(rng.random(), ey, sy);
+                check_pow(x, y);
+            }
+        }
+    }
+
+    // negative x with integer y
+    for _ in 0..20000 {
+        let x = -rng.random_range(0.0..20.0);
+        let y = rng.random_range(-30..=30) as f32;
+        check_pow(x, y);
+    }
+
+    // results close to or in the subnormal range
+    for _ in 0..20000 {
+        let x = rng.random_range(0.5f32..2.0);
+        let y = -rng.random_range(85.0..104.0) / x.ln();
+        check_pow(x, y);
+    }
+
+    // exact results and results halfway between two floats
+    for _ in 0..20000 {
+        let a = rng.random_range(1..(1u32 << 12)) as f32;
+        check_pow(a * a, 0.5);
+        check_pow(a, 2.0);
+        check_pow(a * 0.125, 2.0);
+    }
+}
+
+#[test]
+fn test_hard_cases() {
+    // exactly halfway between two floats
+    check_pow(4097.0, 2.0); // (2^12 + 1)^2
+    check_pow(66049.0, 1.5); // (2^8 + 1)^3
+    check_pow(2.0, -150.0);
+    check_pow(4.0, -75.5);
+    check2(
+        "hypot",
+        fpmath::cr::hypot,
+        rug::Float::hypot,
+        5795.0,
+        16790812.0,
+    );
+
+    // y / x is exactly halfway between two subnormals, and atan2(y, x) is
+    // slightly closer to zero (too close for the reference)
+    let x = 2f32.powi(110);
+    assert_eq!(fpmath::cr::atan2(3.0 * 2f32.powi(-40), x).to_bits(), 1);
+    assert_eq!(fpmath::cr::atan2(7.0 * 2f32.powi(-40), x).to_bits(), 3);
+
+    // very close to halfway between two floats
+    check("exp", fpmath::cr::exp, rug::Float::exp, 2f32.powi(-24));
+    check("exp", fpmath::cr::exp, rug::Float::exp, -(2f32.powi(-25)));
+    check_pow(1.0 + f32::EPSILON, 0.5);
+    check_pow(1.0 - f32::EPSILON / 2.0, 0.5);
+}
+
+fn check(
+    name: &str,
+    actual_f: impl Fn(f32) -> f32,
+    expected_f: impl Fn(rug::Float) -> rug::Float,
+    x: f32,
+) {
+    let actual = purify(actual_f(x));
+    let expected = expected_f(rug::Float::with_val(CR_RUG_PREC, x)).to_f32();
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "{name}({x:e}) = {actual:e} (expected {expected:e})",
+    );
+}
+
+fn check2(
+    name: &str,
+    actual_f: impl Fn(f32, f32) -> f32,
+    expected_f: impl Fn(rug::Float, &rug::Float) -> rug::Float,
+    x: f32,
+    y: f32,
+) {
+    let actual = purify(actual_f(x, y));
+    let expected = expected_f(
+        rug::Float::with_val(CR_RUG_PREC, x),
+        &rug::Float::with_val(CR_RUG_PREC, y),
+    )
+    .to_f32();
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "{name}({x:e}, {y:e}) = {actual:e} (expected {expected:e})",
+    );
+}
+
+fn check_pow(x: f32, y: f32) {
+    let actual = purify(fpmath::cr::pow(x, y));
+    let expected = rug::Float::with_val(CR_RUG_PREC, x)
+        .pow(rug::Float::with_val(CR_RUG_PREC, y))
+        .to_f32();
+    assert!(
+        actual.to_bits() == expected.to_bits(),
+        "pow({x:e}, {y:e}) = {actual:e} (expected {expected:e})",
+    );
+}
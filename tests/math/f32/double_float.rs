@@ -0,0 +1,232 @@
+use fpmath::DoubleFloat;
+use rand::RngExt as _;
+
+use super::mkfloat;
+use crate::create_prng;
+
+const DD_RUG_PREC: u32 = 48 + 20;
+
+#[test]
+fn test_arith() {
+    let mut max_add_error: f64 = 0.0;
+    let mut max_sub_error: f64 = 0.0;
+    let mut max_mul_error: f64 = 0.0;
+    let mut max_div_error: f64 = 0.0;
+
+    let mut rng = create_prng();
+    for ex in (-30..=30).step_by(2) {
+        for ey in (-30..=30).step_by(2) {
+            for _ in 0..50 {
+                let (sx, sy) = rng.random::<(bool, bool)>();
+                let x = mkdouble(&mut rng, ex, sx);
+                let y = mkdouble(&mut rng, ex + ey, sy);
+
+                let rx = dd_to_rug(x);
+                let ry = dd_to_rug(y);
+
+                let add_err = calc_error_dd_ulp(x + y, rx.clone() + &ry);
+                let sub_err = calc_error_dd_ulp(x - y, rx.clone() - &ry);
+                let mul_err = calc_error_dd_ulp(x * y, rx.clone() * &ry);
+                let div_err = calc_error_dd_ulp(x / y, rx / ry);
+
+                max_add_error = max_add_error.max(add_err);
+                max_sub_error = max_sub_error.max(sub_err);
+                max_mul_error = max_mul_error.max(mul_err);
+                max_div_error = max_div_error.max(div_err);
+
+                assert!(add_err < 1.5, "{x:?} + {y:?} (error = {add_err} ULP)");
+                assert!(sub_err < 1.5, "{x:?} - {y:?} (error = {sub_err} ULP)");
+                assert!(mul_err < 5.0, "{x:?} * {y:?} (error = {mul_err} ULP)");
+                assert!(div_err < 4.0, "{x:?} / {y:?} (error = {div_err} ULP)");
+            }
+        }
+    }
+
+    eprintln!("max add error = {max_add_error}");
+    eprintln!("max sub error = {max_sub_error}");
+    eprintln!("max mul error = {max_mul_error}");
+    eprintln!("max div error = {max_div_error}");
+    assert!(max_add_error > 0.5);
+    assert!(max_sub_error > 0.5);
+    assert!(max_mul_error > 0.5);
+    assert!(max_div_error > 0.5);
+}
+
+#[test]
+fn test_sqrt() {
+    let mut max_error: f64 = 0.0;
+
+    let mut rng = create_prng();
+    let mut test = |x: DoubleFloat<f32>| {
+        let expected = dd_to_rug(x).sqrt();
+        let actual = x.sqrt();
+
+        let err = calc_error_dd_ulp(actual, expected);
+        max_error = max_error.max(err);
+        assert!(err < 2.5, "sqrt({x:?}) = {actual:?} (error = {err} ULP)");
+    };
+
+    for e in -126..=127 {
+        for _ in 0..200 {
+            test(mkdouble(&mut rng, e, false));
+        }
+    }
+
+    // subnormals
+    for i in 0..23 {
+        test(DoubleFloat::from(f32::from_bits(1 << i)));
+        test(DoubleFloat::from(f32::from_bits((1 << (i + 1)) - 1)));
+    }
+
+    eprintln!("max sqrt error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_exp() {
+    let mut max_error: f64 = 0.0;
+
+    let mut rng = create_prng();
+    let mut test = |x: DoubleFloat<f32>| {
+        let expected = dd_to_rug(x).exp();
+        let actual = x.exp();
+
+        let err = calc_error_dd_ulp(actual, expected);
+        max_error = max_error.max(err);
+        assert!(err < 1.5, "exp({x:?}) = {actual:?} (error = {err} ULP)");
+    };
+
+    for e in -30..=6 {
+        for _ in 0..20000 {
+            let s = rng.random::<bool>();
+            let x = mkdouble(&mut rng, e, s);
+            // keep the result far from the subnormal range
+            if x.hi() > -60.0 && x.hi() < 88.0 {
+                test(x);
+            }
+        }
+    }
+
+    eprintln!("max exp error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_ln() {
+    let mut max_error: f64 = 0.0;
+
+    let mut rng = create_prng();
+    let mut test = |x: DoubleFloat<f32>| {
+        let expected = dd_to_rug(x).ln();
+        let actual = x.ln();
+
+        let err = calc_error_dd_ulp(actual, expected);
+        max_error = max_error.max(err);
+        assert!(err < 2.5, "ln({x:?}) = {actual:?} (error = {err} ULP)");
+    };
+
+    for e in -126..=127 {
+        for _ in 0..2000 {
+            test(mkdouble(&mut rng, e, false));
+        }
+    }
+
+    // close to 1
+    for e in -50..=-1 {
+        for _ in 0..1000 {
+            let s = rng.random::<bool>();
+            let d = mkdouble(&mut rng, e, s);
+            test(DoubleFloat::from(1.0f32) + d);
+        }
+    }
+
+    // subnormals
+    for i in 0..23 {
+        test(DoubleFloat::from(f32::from_bits(1 << i)));
+        test(DoubleFloat::from(f32::from_bits((1 << (i + 1)) - 1)));
+    }
+
+    eprintln!("max ln error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_sin_cos() {
+    let mut max_sin_error: f64 = 0.0;
+    let mut max_cos_error: f64 = 0.0;
+
+    let mut rng = create_prng();
+    let mut test = |x: DoubleFloat<f32>| {
+        let (expected_sin, expected_cos) = dd_to_rug(x).sin_cos(rug::Float::new(DD_RUG_PREC + 256));
+        let (actual_sin, actual_cos) = x.sin_cos();
+
+        let sin_err = calc_error_dd_ulp(actual_sin, expected_sin);
+        let cos_err = calc_error_dd_ulp(actual_cos, expected_cos);
+        max_sin_error = max_sin_error.max(sin_err);
+        max_cos_error = max_cos_error.max(cos_err);
+        assert!(
+            sin_err < 3.0,
+            "sin({x:?}) = {actual_sin:?} (error = {sin_err} ULP)",
+        );
+        assert!(
+            cos_err < 3.0,
+            "cos({x:?}) = {actual_cos:?} (error = {cos_err} ULP)",
+        );
+    };
+
+    for e in -30..=30 {
+        for _ in 0..10000 {
+            let s = rng.random::<bool>();
+            test(mkdouble(&mut rng, e, s));
+        }
+    }
+    for e in 31..=127 {
+        for _ in 0..1000 {
+            let s = rng.random::<bool>();
+            test(mkdouble(&mut rng, e, s));
+        }
+    }
+
+    eprintln!("max sin error = {max_sin_error}");
+    eprintln!("max cos error = {max_cos_error}");
+    assert!(max_sin_error > 0.5);
+    assert!(max_cos_error > 0.5);
+}
+
+/// Creates a random double-float with exponent `e` and sign `s`
+fn mkdouble(rng: &mut impl rand::Rng, e: i16, s: bool) -> DoubleFloat<f32> {
+    let hi = mkfloat(rng.random::<u32>(), e, s);
+    let lo = hi * (rng.random::<f32>() - 0.5) * f32::EPSILON;
+    DoubleFloat::new(hi, lo)
+}
+
+fn dd_to_rug(x: DoubleFloat<f32>) -> rug::Float {
+    // Use enough precision to represent the sum exactly
+    rug::Float::with_val(DD_RUG_PREC + 256, x.hi()) + x.lo()
+}
+
+/// Calculates the error in units of 2^-47 relative to the
+/// exponent of `expected`
+fn calc_error_dd_ulp(actual: DoubleFloat<f32>, expected: rug::Float) -> f64 {
+    match expected.classify() {
+        std::num::FpCategory::Nan => {
+            if actual.hi().is_nan() {
+                0.0
+            } else {
+                f64::INFINITY
+            }
+        }
+        std::num::FpCategory::Zero => {
+            if actual.hi() == 0.0 {
+                0.0
+            } else {
+                f64::INFINITY
+            }
+        }
+        _ => {
+            let exp = expected.get_exp().unwrap() - 1;
+            let dif = (expected - dd_to_rug(actual)).abs() >> (exp - 47);
+            dif.to_f64()
+        }
+    }
+}
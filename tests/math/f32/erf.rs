@@ -0,0 +1,72 @@
+use rand::RngExt as _;
+
+use super::{calc_error_ulp, mkfloat, purify, select_threshold};
+use crate::create_prng;
+
+#[test]
+fn test_erf() {
+    let mut max_error: f32 = 0.0;
+    test_with(|x| {
+        let expected = fpmath::erf(f64::from(x));
+        let actual = fpmath::erf(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "erf({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max erf error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_erfc() {
+    let mut max_error: f32 = 0.0;
+    test_with(|x| {
+        let expected = fpmath::erfc(f64::from(x));
+        let actual = fpmath::erfc(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "erfc({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max erfc error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_with(mut f: impl FnMut(f32)) {
+    let mut rng = create_prng();
+
+    for e in -126..=127 {
+        for _ in 0..6000 {
+            let m = rng.random::<u32>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    for i in 0..3000 {
+        let x = purify((i as f32) / 100.0);
+        f(x);
+        f(-x);
+    }
+
+    // subnormals
+    for i in 0..23 {
+        let x = f32::from_bits(1 << i);
+        f(x);
+        f(-x);
+        let x = f32::from_bits((1 << (i + 1)) - 1);
+        f(x);
+        f(-x);
+    }
+}
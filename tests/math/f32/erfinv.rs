@@ -0,0 +1,112 @@
+use rand::RngExt as _;
+
+use super::{calc_error_ulp, mkfloat, purify, select_threshold};
+use crate::create_prng;
+
+#[test]
+fn test_erfinv() {
+    let mut max_error: f32 = 0.0;
+    test_with_erfinv(|x| {
+        let expected = fpmath::erfinv(f64::from(x));
+        let actual = fpmath::erfinv(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "erfinv({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max erfinv error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_erfcinv() {
+    let mut max_error: f32 = 0.0;
+    test_with_erfcinv(|x| {
+        let expected = fpmath::erfcinv(f64::from(x));
+        let actual = fpmath::erfcinv(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "erfcinv({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max erfcinv error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_with_erfinv(mut f: impl FnMut(f32)) {
+    let mut rng = create_prng();
+
+    for e in -126..=-1 {
+        for _ in 0..6000 {
+            let m = rng.random::<u32>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    // close to ±1
+    for e in -24..=-2 {
+        for _ in 0..6000 {
+            let m = rng.random::<u32>();
+            let x = purify(1.0 - mkfloat(m, e, false));
+            f(x);
+            f(-x);
+        }
+    }
+
+    for i in 1..1000 {
+        let x = purify((i as f32) / 1000.0);
+        f(x);
+        f(-x);
+    }
+
+    // subnormals
+    for i in 0..23 {
+        let x = f32::from_bits(1 << i);
+        f(x);
+        f(-x);
+        let x = f32::from_bits((1 << (i + 1)) - 1);
+        f(x);
+        f(-x);
+    }
+}
+
+fn test_with_erfcinv(mut f: impl FnMut(f32)) {
+    let mut rng = create_prng();
+
+    for e in -126..=0 {
+        for _ in 0..6000 {
+            let m = rng.random::<u32>();
+            f(mkfloat(m, e, false));
+        }
+    }
+
+    // close to 2
+    for e in -23..=-2 {
+        for _ in 0..6000 {
+            let m = rng.random::<u32>();
+            f(purify(2.0 - mkfloat(m, e, false)));
+        }
+    }
+
+    for i in 1..2000 {
+        let x = purify((i as f32) / 1000.0);
+        f(x);
+    }
+
+    // subnormals
+    for i in 0..23 {
+        f(f32::from_bits(1 << i));
+        f(f32::from_bits((1 << (i + 1)) - 1));
+    }
+}
@@ -0,0 +1,77 @@
+use rand::RngExt as _;
+use rug::float::Round;
+
+use super::mkfloat;
+use crate::create_prng;
+
+#[test]
+fn test_fma() {
+    let mut rng = create_prng();
+
+    for _ in 0..200_000 {
+        let ex = rng.random_range(-127..=127);
+        let ey = rng.random_range(-127..=127);
+        let ez = (ex + ey + rng.random_range(-30..=30)).clamp(-127, 127);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        let z = mkfloat(rng.random(), ez, rng.random());
+        check(x, y, z);
+
+        // cancellation of the highest bits
+        let z = -(x * y);
+        if z.is_finite() {
+            check(x, y, z);
+            check(x, y, f32::from_bits(z.to_bits() + 1));
+        }
+    }
+
+    // results close to or in the subnormal range
+    for _ in 0..100_000 {
+        let ex = rng.random_range(-127..=-20);
+        let ey = (-150 - ex + rng.random_range(-20..=20)).clamp(-127, 127);
+        let ez = rng.random_range(-127..=-110);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        let z = mkfloat(rng.random(), ez, rng.random());
+        check(x, y, z);
+        check(x, y, 0.0);
+        check(x, y, -0.0);
+    }
+
+    let special = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        f32::from_bits(1),
+        f32::MIN_POSITIVE,
+        f32::MAX,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+    ];
+    for x in special {
+        for y in special {
+            for z in special {
+                check(x, y, z);
+            }
+        }
+    }
+}
+
+fn check(x: f32, y: f32, z: f32) {
+    let mut expected = rug::Float::with_val(24, x);
+    let dir = expected.mul_add_round(
+        &rug::Float::with_val(24, y),
+        &rug::Float::with_val(24, z),
+        Round::Nearest,
+    );
+    expected.subnormalize_ieee_round(dir, Round::Nearest);
+    let expected = expected.to_f32();
+
+    let actual = fpmath::fma(x, y, z);
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "fma({x:e}, {y:e}, {z:e}) = {actual:e}, expected {expected:e}",
+    );
+}
@@ -0,0 +1,90 @@
+use rand::RngExt as _;
+
+use super::mkfloat;
+use crate::create_prng;
+
+#[test]
+fn test_fmod() {
+    test_with(|x, y| {
+        let expected = (rug::Float::with_val(24, x) % rug::Float::with_val(24, y)).to_f32();
+        let actual = fpmath::fmod(x, y);
+        assert!(
+            same_result(actual, expected),
+            "fmod({x:e}, {y:e}) = {actual:e}, expected {expected:e}",
+        );
+    });
+}
+
+#[test]
+fn test_remainder() {
+    test_with(|x, y| {
+        let (expected, expected_quo) =
+            rug::Float::with_val(24, x).remainder_quo31(&rug::Float::with_val(24, y));
+        let expected = expected.to_f32();
+
+        let actual = fpmath::remainder(x, y);
+        assert!(
+            same_result(actual, expected),
+            "remainder({x:e}, {y:e}) = {actual:e}, expected {expected:e}",
+        );
+
+        let (actual, actual_quo) = fpmath::remquo(x, y);
+        assert!(
+            same_result(actual, expected) && (actual_quo == expected_quo || expected.is_nan()),
+            "remquo({x:e}, {y:e}) = ({actual:e}, {actual_quo}), expected ({expected:e}, {expected_quo})",
+        );
+    });
+}
+
+fn same_result(actual: f32, expected: f32) -> bool {
+    actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan())
+}
+
+fn test_with(mut f: impl FnMut(f32, f32)) {
+    let mut rng = create_prng();
+
+    for _ in 0..20000 {
+        let ex = rng.random_range(-127..=127);
+        let ey = rng.random_range(-127..=127);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        f(x, y);
+    }
+
+    // close exponents
+    for _ in 0..20000 {
+        let ey = rng.random_range(-127..=85);
+        let ex = ey + rng.random_range(-2..=40);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        f(x, y);
+    }
+
+    // halfway cases
+    for _ in 0..20000 {
+        let ey = rng.random_range(-120..=120);
+        let n = rng.random_range(0..256);
+        let y = mkfloat(0, ey, rng.random());
+        let x = (n as f32 + 0.5) * y;
+        f(x, y);
+        f(-x, y);
+    }
+
+    let special = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        f32::from_bits(1),
+        f32::MIN_POSITIVE,
+        f32::MAX,
+        f32::INFINITY,
+        f32::NEG_INFINITY,
+        f32::NAN,
+    ];
+    for x in special {
+        for y in special {
+            f(x, y);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use rand::RngExt as _;
 
-use super::{calc_error_ulp, mkfloat, purify};
+use super::{calc_error_ulp, mkfloat, purify, select_threshold};
 use crate::create_prng;
 
 #[test]
@@ -51,6 +51,98 @@ fn test_ln_gamma() {
     assert!(max_error > 0.5);
 }
 
+#[test]
+fn test_gamma_p() {
+    let mut max_error: f32 = 0.0;
+    test_with_inc(|a, x| {
+        let expected = fpmath::gamma_p(f64::from(a), f64::from(x));
+        let actual = fpmath::gamma_p(a, x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 1.9, 2.9);
+        assert!(
+            err < threshold,
+            "gamma_p({a:e}, {x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max gamma_p error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_gamma_q() {
+    let mut max_error: f32 = 0.0;
+    test_with_inc(|a, x| {
+        let expected = fpmath::gamma_q(f64::from(a), f64::from(x));
+        let actual = fpmath::gamma_q(a, x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 1.9, 2.9);
+        assert!(
+            err < threshold,
+            "gamma_q({a:e}, {x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max gamma_q error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_gamma_p_inv() {
+    let mut max_error: f32 = 0.0;
+    test_with_inc(|a, x| {
+        let p = fpmath::gamma_p(a, x);
+        // The accuracy is not guaranteed when `p` is subnormal
+        if p == 0.0 || p == 1.0 || p.is_subnormal() {
+            return;
+        }
+
+        let expected = fpmath::gamma_p_inv(f64::from(a), f64::from(p));
+        let actual = fpmath::gamma_p_inv(a, p);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "gamma_p_inv({a:e}, {p:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max gamma_p_inv error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_gamma_q_inv() {
+    let mut max_error: f32 = 0.0;
+    test_with_inc(|a, x| {
+        let q = fpmath::gamma_q(a, x);
+        // The accuracy is not guaranteed when `q` is subnormal
+        if q == 0.0 || q == 1.0 || q.is_subnormal() {
+            return;
+        }
+
+        let expected = fpmath::gamma_q_inv(f64::from(a), f64::from(q));
+        let actual = fpmath::gamma_q_inv(a, q);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "gamma_q_inv({a:e}, {q:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max gamma_q_inv error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
 fn test_with(mut f: impl FnMut(f32)) {
     let mut rng = create_prng();
 
@@ -68,3 +160,40 @@ fn test_with(mut f: impl FnMut(f32)) {
         f(-x);
     }
 }
+
+fn test_with_inc(mut f: impl FnMut(f32, f32)) {
+    let mut rng = create_prng();
+
+    for ea in (-30..=20).step_by(2) {
+        for ex in (-40..=24).step_by(2) {
+            for _ in 0..20 {
+                let a = mkfloat(rng.random::<u32>(), ea, false);
+                let x = mkfloat(rng.random::<u32>(), ex, false);
+                f(a, x);
+            }
+        }
+    }
+
+    for i in 1..=100 {
+        for j in 1..=100 {
+            let a = purify((i as f32) / 10.0);
+            let x = purify((j as f32) / 5.0);
+            f(a, x);
+        }
+    }
+
+    // Close to the mean, where `P` and `Q` are not small
+    for _ in 0..20000 {
+        let a = rng.random_range(1.0..100000.0f32);
+        let x = purify(a * rng.random_range(0.5..1.5f32));
+        f(a, x);
+    }
+
+    // Exactly at the mean, with large `a`
+    for ea in 10..=40 {
+        for _ in 0..20 {
+            let a = mkfloat(rng.random::<u32>(), ea, false);
+            f(a, a);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use rand::RngExt as _;
+
+use super::{calc_error_ulp, mkfloat};
+use crate::create_prng;
+
+/// Largest `f32` that is not greater than `1/e`
+const FRAC_1_E: f32 = f32::from_bits(0x3EBC5AB1);
+
+#[test]
+fn test_lambert_w0() {
+    let mut max_error: f32 = 0.0;
+    test_with_w0(|x| {
+        let expected = fpmath::lambert_w0(f64::from(x));
+        let actual = fpmath::lambert_w0(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "lambert_w0({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max lambert_w0 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_lambert_wm1() {
+    let mut max_error: f32 = 0.0;
+    test_with_wm1(|x| {
+        let expected = fpmath::lambert_wm1(f64::from(x));
+        let actual = fpmath::lambert_wm1(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "lambert_wm1({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max lambert_wm1 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_with_w0(mut f: impl FnMut(f32)) {
+    let mut rng = create_prng();
+
+    for e in -126..=127 {
+        for _ in 0..5000 {
+            let m = rng.random::<u32>();
+            f(mkfloat(m, e, false));
+        }
+    }
+
+    // -1/e <= x < 0
+    for e in -126..=-2 {
+        for _ in 0..5000 {
+            let m = rng.random::<u32>();
+            let x = mkfloat(m, e, true);
+            if x >= -FRAC_1_E {
+                f(x);
+            }
+        }
+    }
+
+    test_with_branch_point(&mut f);
+
+    f(f32::MIN_POSITIVE);
+    f(-f32::MIN_POSITIVE);
+    f(f32::MAX);
+
+    // subnormals
+    for i in 0..23 {
+        f(f32::from_bits(1 << i));
+        f(-f32::from_bits(1 << i));
+        f(f32::from_bits((1 << (i + 1)) - 1));
+        f(-f32::from_bits((1 << (i + 1)) - 1));
+    }
+}
+
+fn test_with_wm1(mut f: impl FnMut(f32)) {
+    let mut rng = create_prng();
+
+    // -1/e <= x < 0
+    for e in -126..=-2 {
+        for _ in 0..10000 {
+            let m = rng.random::<u32>();
+            let x = mkfloat(m, e, true);
+            if x >= -FRAC_1_E {
+                f(x);
+            }
+        }
+    }
+
+    test_with_branch_point(&mut f);
+
+    f(-f32::MIN_POSITIVE);
+
+    // subnormals
+    for i in 0..23 {
+        f(-f32::from_bits(1 << i));
+        f(-f32::from_bits((1 << (i + 1)) - 1));
+    }
+}
+
+/// Calls `f` with every `f32` in `[-1/e, -0.35]`
+fn test_with_branch_point(mut f: impl FnMut(f32)) {
+    let end = 0.35f32.to_bits();
+    for bits in end..=FRAC_1_E.to_bits() {
+        f(-f32::from_bits(bits));
+    }
+}
@@ -1,6 +1,6 @@
 use rand::RngExt as _;
 
-use super::{calc_error_ulp, mkfloat};
+use super::{calc_error_hi_lo_ulp, calc_error_ulp, mkfloat};
 use crate::create_prng;
 
 #[test]
@@ -35,6 +35,44 @@ fn test_ln_1p() {
     assert!(max_error > 0.5);
 }
 
+#[test]
+fn test_ln_hi_lo() {
+    let mut max_error: f32 = 0.0;
+    test_log_with(|x| {
+        let expected = fpmath::ln(f64::from(x));
+        let actual = fpmath::ln_hi_lo(x);
+
+        let err = calc_error_hi_lo_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.001,
+            "ln_hi_lo({x:e}) = {actual:?} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max ln_hi_lo error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_ln_1p_hi_lo() {
+    let mut max_error: f32 = 0.0;
+    test_log1p_with(|x| {
+        let expected = fpmath::ln_1p(f64::from(x));
+        let actual = fpmath::ln_1p_hi_lo(x);
+
+        let err = calc_error_hi_lo_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.001,
+            "ln_1p_hi_lo({x:e}) = {actual:?} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max ln_1p_hi_lo error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
 #[test]
 fn test_log2() {
     let mut max_error: f32 = 0.0;
@@ -67,6 +105,41 @@ fn test_log10() {
     assert!(max_error > 0.5);
 }
 
+#[test]
+fn test_log2_1p() {
+    let mut max_error: f32 = 0.0;
+    test_log1p_with(|x| {
+        let expected = fpmath::log2_1p(f64::from(x));
+        let actual = fpmath::log2_1p(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "log2_1p({x:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max log2_1p error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_log10_1p() {
+    let mut max_error: f32 = 0.0;
+    test_log1p_with(|x| {
+        let expected = fpmath::log10_1p(f64::from(x));
+        let actual = fpmath::log10_1p(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "log10_1p({x:e}) = {actual:e} (error = {err} ULP)"
+        );
+    });
+    eprintln!("max log10_1p error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
 fn test_log_with(mut f: impl FnMut(f32)) {
     let mut rng = create_prng();
 
@@ -1,15 +1,31 @@
+mod bessel;
+mod beta;
 mod cbrt;
+mod complex;
+mod cr;
+mod double_float;
+mod erf;
+mod erfinv;
 mod exp;
+mod fma;
+mod fmod;
 mod gamma;
 mod hyperbolic;
 mod hypot;
 mod inv_hyperbolic;
 mod inv_trigonometric;
+mod lambert_w;
 mod log;
+mod polygamma;
 mod pow;
 mod round;
+mod simd;
+mod slice;
 mod sqrt;
 mod trigonometric;
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+mod x86_64;
+mod zeta;
 
 fn mkfloat(m: u32, e: i16, s: bool) -> f32 {
     let m = m >> (32 - 23);
@@ -48,6 +64,27 @@ fn calc_error_ulp(actual: f32, expected: f64) -> f32 {
     }
 }
 
+/// Calculates the error of `hi + lo` in ULP of `hi`
+///
+/// `(hi, lo)` must be normalized.
+fn calc_error_hi_lo_ulp((hi, lo): (f32, f32), expected: f64) -> f32 {
+    let (hi, lo) = purify2((hi, lo));
+
+    if !hi.is_finite() || hi == 0.0 {
+        if lo == 0.0 {
+            calc_error_ulp(hi, expected)
+        } else {
+            f32::INFINITY
+        }
+    } else {
+        assert_eq!(purify(hi + lo), hi, "({hi:e}, {lo:e}) is not normalized");
+        let exp = (fpmath::frexp(expected).1 - 1).max(-126);
+        let actual = f64::from(hi) + f64::from(lo);
+        let dif = fpmath::scalbn((expected - actual).abs(), 23 - exp);
+        dif as f32
+    }
+}
+
 fn select_threshold(actual: f32, normal_th: f32, subnormal_th: f32) -> f32 {
     if actual == 0.0 || actual.is_subnormal() {
         subnormal_th
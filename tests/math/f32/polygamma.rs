@@ -0,0 +1,119 @@
+use rand::RngExt as _;
+
+use super::{calc_error_ulp, mkfloat, purify, select_threshold};
+use crate::create_prng;
+
+#[test]
+fn test_digamma() {
+    let mut max_error: f32 = 0.0;
+    test_with(|x| {
+        let expected = fpmath::digamma(f64::from(x));
+        let actual = fpmath::digamma(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        if x > 0.0 {
+            let threshold = select_threshold(actual, 0.9, 1.9);
+            assert!(
+                err < threshold,
+                "digamma({x:e}) = {actual:e} (error = {err} ULP)",
+            );
+        } else {
+            // ψ(x) = ψ(1 - x) - π / tan(πx), which has cancellation near
+            // the zeros of the function
+            let scale = fpmath::digamma(1.0 - f64::from(x));
+            assert!(
+                err < 1.9 || calc_error_scaled(actual, expected, scale) < 0.25,
+                "digamma({x:e}) = {actual:e} (error = {err} ULP)",
+            );
+        }
+    });
+    eprintln!("max digamma error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_polygamma() {
+    let mut max_error: f32 = 0.0;
+    for n in [1, 2, 3, 4, 7, 20] {
+        test_with_short(|x| {
+            let expected = fpmath::polygamma(n, f64::from(x));
+            let actual = fpmath::polygamma(n, x);
+
+            let err = calc_error_ulp(actual, expected);
+            max_error = max_error.max(err);
+
+            if x > 0.0 || n % 2 == 1 {
+                let threshold = select_threshold(actual, 0.9, 1.9);
+                assert!(
+                    err < threshold,
+                    "polygamma({n}, {x:e}) = {actual:e} (error = {err} ULP)",
+                );
+            } else {
+                // Terms with opposite signs can cancel near the zeros of
+                // the function when `n` is even
+                let f = f64::from(x.fract().abs());
+                let scale = fpmath::polygamma(n, 1.0 - f).abs() + fpmath::polygamma(n, f).abs();
+                assert!(
+                    err < 1.9 || calc_error_scaled(actual, expected, scale) < 0.25,
+                    "polygamma({n}, {x:e}) = {actual:e} (error = {err} ULP)",
+                );
+            }
+        });
+    }
+    eprintln!("max polygamma error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+/// Calculates the error relative to `scale`, in units of `f32::EPSILON`.
+fn calc_error_scaled(actual: f32, expected: f64, scale: f64) -> f32 {
+    let dif = (expected - f64::from(purify(actual))).abs();
+    (dif / scale.abs() / f64::from(f32::EPSILON)) as f32
+}
+
+fn test_with(mut f: impl FnMut(f32)) {
+    let mut rng = create_prng();
+
+    for e in -126..=127 {
+        for _ in 0..3000 {
+            let m = rng.random::<u32>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    for i in 0..5000 {
+        let x = purify((i as f32) / 100.0);
+        f(x);
+        f(-x);
+    }
+
+    // subnormals
+    for i in 0..23 {
+        let x = f32::from_bits(1 << i);
+        f(x);
+        f(-x);
+        let x = f32::from_bits((1 << (i + 1)) - 1);
+        f(x);
+        f(-x);
+    }
+}
+
+fn test_with_short(mut f: impl FnMut(f32)) {
+    let mut rng = create_prng();
+
+    for e in -30..=30 {
+        for _ in 0..1000 {
+            let m = rng.random::<u32>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    for i in 0..5000 {
+        let x = purify((i as f32) / 100.0);
+        f(x);
+        f(-x);
+    }
+}
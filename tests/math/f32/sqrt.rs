@@ -12,6 +12,18 @@ fn test_sqrt() {
     });
 }
 
+#[test]
+fn test_rsqrt() {
+    test_with(|x| {
+        let actual = fpmath::rsqrt(x);
+        let expected = rug::Float::with_val(24, x).recip_sqrt();
+        assert!(
+            actual == expected,
+            "rsqrt({x:e}) = {actual:e} (expected {expected})"
+        );
+    });
+}
+
 fn test_with(mut f: impl FnMut(f32)) {
     let mut rng = create_prng();
 
@@ -1,6 +1,6 @@
 use rand::RngExt as _;
 
-use super::{calc_error_ulp, mkfloat, purify, purify2, select_threshold};
+use super::{calc_error_hi_lo_ulp, calc_error_ulp, mkfloat, purify, purify2, select_threshold};
 use crate::create_prng;
 
 #[test]
@@ -62,6 +62,103 @@ fn test_sin_cos() {
     assert!(max_cos2_error > 0.5);
 }
 
+#[test]
+fn test_sin_cos_hi_lo() {
+    let mut max_sin_error: f32 = 0.0;
+    let mut max_cos_error: f32 = 0.0;
+    test_with(|x| {
+        let (expected_sin, expected_cos) = fpmath::sin_cos(f64::from(x));
+
+        let (actual_sin, actual_cos) = fpmath::sin_cos_hi_lo(x);
+        let (neg_sin, neg_cos) = fpmath::sin_cos_hi_lo(-x);
+        assert_eq!(purify2(neg_sin), purify2((-actual_sin.0, -actual_sin.1)));
+        assert_eq!(purify2(neg_cos), purify2(actual_cos));
+
+        let sin_err = calc_error_hi_lo_ulp(actual_sin, expected_sin);
+        let cos_err = calc_error_hi_lo_ulp(actual_cos, expected_cos);
+
+        max_sin_error = max_sin_error.max(sin_err);
+        max_cos_error = max_cos_error.max(cos_err);
+
+        assert!(
+            sin_err < 0.15,
+            "sin_hi_lo({x:e}) = {actual_sin:?} (error = {sin_err} ULP)",
+        );
+        assert!(
+            cos_err < 0.15,
+            "cos_hi_lo({x:e}) = {actual_cos:?} (error = {cos_err} ULP)",
+        );
+    });
+    eprintln!("max sin_hi_lo error = {max_sin_error}");
+    eprintln!("max cos_hi_lo error = {max_cos_error}");
+    assert!(max_sin_error > 0.0);
+    assert!(max_cos_error > 0.0);
+}
+
+#[test]
+fn test_rem_pio2() {
+    let mut max_error: f32 = 0.0;
+    test_with(|x| {
+        let (n, y_hi, y_lo) = fpmath::rem_pio2(x);
+        assert!((0..=3).contains(&n), "rem_pio2({x:e}).0 = {n}");
+        let (neg_n, neg_hi, neg_lo) = fpmath::rem_pio2(-x);
+        assert_eq!(neg_n, (4 - n) & 3);
+        assert_eq!(purify2((neg_hi, neg_lo)), purify2((-y_hi, -y_lo)));
+
+        let expected = reduce_rug(x, n);
+        assert!(
+            expected.abs() <= std::f64::consts::FRAC_PI_4 * 1.0001,
+            "rem_pio2({x:e}) = {:?} (expected {expected:e})",
+            (n, y_hi, y_lo),
+        );
+
+        let err = calc_error_hi_lo_ulp((y_hi, y_lo), expected);
+        max_error = max_error.max(err);
+        assert!(
+            err < 0.02,
+            "rem_pio2({x:e}) = {:?} (error = {err} ULP)",
+            (n, y_hi, y_lo),
+        );
+    });
+    eprintln!("max rem_pio2 error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_wrap_angle() {
+    let mut max_error: f32 = 0.0;
+    test_with(|x| {
+        let actual = fpmath::wrap_angle(x);
+        assert_eq!(purify(fpmath::wrap_angle(-x)), purify(-actual));
+
+        let mut expected = reduce_rug(x, 0);
+        if expected.abs() > 3.0 && expected.is_sign_negative() != actual.is_sign_negative() {
+            // π and -π are both valid
+            expected += std::f64::consts::TAU.copysign(f64::from(actual));
+        }
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+        assert!(
+            err < 0.9,
+            "wrap_angle({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max wrap_angle error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+/// Calculates `x - π/2*n - 2*π*M`, with `M` chosen to minimize the result
+fn reduce_rug(x: f32, n: i32) -> f64 {
+    // enough bits to keep the precision of `f64` after cancellation
+    let prec = 128 + 53 + 128;
+    let pi = rug::Float::with_val(prec, rug::float::Constant::Pi);
+    let two_pi = pi.clone() * 2u8;
+    let y = rug::Float::with_val(prec, x - pi * n / 2u8);
+    let m = (y.clone() / &two_pi).round();
+    (y - m * two_pi).to_f64()
+}
+
 #[test]
 fn test_sind_cosd() {
     let mut max_sin1_error: f32 = 0.0;
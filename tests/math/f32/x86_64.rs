@@ -0,0 +1,122 @@
+use core::arch::x86_64::__m256;
+
+use rand::RngExt as _;
+
+use super::mkfloat;
+use crate::create_prng;
+
+const SPECIAL: [f32; 10] = [
+    0.0,
+    -0.0,
+    f32::INFINITY,
+    f32::NEG_INFINITY,
+    f32::NAN,
+    f32::MIN_POSITIVE / 3.0,
+    -1.0,
+    1.0,
+    -2.0,
+    1.0e30,
+];
+
+#[test]
+fn test_exp() {
+    let x = gen_args(-30..=7);
+    check1("exp", fpmath::x86_64::exp_ps, fpmath::exp, &x);
+}
+
+#[test]
+fn test_ln() {
+    let x = gen_args(-126..=127);
+    check1("ln", fpmath::x86_64::ln_ps, fpmath::ln, &x);
+}
+
+#[test]
+fn test_sin_cos() {
+    let x = gen_args(-30..=30);
+    for x in x.chunks_exact(8) {
+        let (sin, cos) = fpmath::x86_64::sin_cos_ps(load(x));
+        for ((&x, sin), cos) in x.iter().zip(store(sin)).zip(store(cos)) {
+            let (expected_sin, expected_cos) = fpmath::sin_cos(x);
+            assert_same("sin_cos", &[x], sin, expected_sin);
+            assert_same("sin_cos", &[x], cos, expected_cos);
+        }
+    }
+}
+
+#[test]
+fn test_atan2() {
+    let y = gen_args(-126..=127);
+    let x = gen_args(-126..=127);
+    check2("atan2", fpmath::x86_64::atan2_ps, fpmath::atan2, &y, &x);
+
+    // Close exponents
+    let y = gen_args(-10..=10);
+    let x = gen_args(-10..=10);
+    check2("atan2", fpmath::x86_64::atan2_ps, fpmath::atan2, &y, &x);
+}
+
+#[test]
+fn test_pow() {
+    let x = gen_args(-30..=30);
+    let y = gen_args(-10..=10);
+    check2("pow", fpmath::x86_64::pow_ps, fpmath::pow, &x, &y);
+
+    // Integer exponents
+    let y: Vec<_> = y.iter().map(|y| y.round()).collect();
+    check2("pow", fpmath::x86_64::pow_ps, fpmath::pow, &x, &y);
+}
+
+/// Generates random arguments with exponents in `exp_range`, mixed with
+/// special values
+fn gen_args(exp_range: core::ops::RangeInclusive<i16>) -> Vec<f32> {
+    let mut rng = create_prng();
+    (0..20000)
+        .map(|_| {
+            if rng.random_ratio(1, 64) {
+                SPECIAL[rng.random_range(0..SPECIAL.len())]
+            } else {
+                let e = rng.random_range(exp_range.clone());
+                mkfloat(rng.random(), e, rng.random())
+            }
+        })
+        .collect()
+}
+
+fn load(x: &[f32]) -> __m256 {
+    fpmath::x86_64::from_array_ps(x.try_into().unwrap())
+}
+
+fn store(x: __m256) -> [f32; 8] {
+    fpmath::x86_64::to_array_ps(x)
+}
+
+fn check1(name: &str, simd_f: impl Fn(__m256) -> __m256, f: impl Fn(f32) -> f32, x: &[f32]) {
+    for x in x.chunks_exact(8) {
+        let y = store(simd_f(load(x)));
+        for (&x, y) in x.iter().zip(y) {
+            assert_same(name, &[x], y, f(x));
+        }
+    }
+}
+
+fn check2(
+    name: &str,
+    simd_f: impl Fn(__m256, __m256) -> __m256,
+    f: impl Fn(f32, f32) -> f32,
+    x1: &[f32],
+    x2: &[f32],
+) {
+    for (x1, x2) in x1.chunks_exact(8).zip(x2.chunks_exact(8)) {
+        let y = store(simd_f(load(x1), load(x2)));
+        for ((&x1, &x2), y) in x1.iter().zip(x2).zip(y) {
+            assert_same(name, &[x1, x2], y, f(x1, x2));
+        }
+    }
+}
+
+fn assert_same(name: &str, args: &[f32], actual: f32, expected: f32) {
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "{name}{args:?} = {actual:e}, expected {expected:e}",
+    );
+}
@@ -0,0 +1,120 @@
+use rand::RngExt as _;
+
+use super::{calc_error_ulp, mkfloat, select_threshold};
+use crate::create_prng;
+
+#[test]
+fn test_zeta() {
+    let mut max_error: f32 = 0.0;
+    test_with_zeta(|s| {
+        let expected = fpmath::zeta(f64::from(s));
+        let actual = fpmath::zeta(s);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "zeta({s:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max zeta error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_hurwitz_zeta() {
+    let mut max_error: f32 = 0.0;
+    test_with_hurwitz_zeta(|s, q| {
+        let expected = fpmath::hurwitz_zeta(f64::from(s), f64::from(q));
+        let actual = fpmath::hurwitz_zeta(s, q);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "hurwitz_zeta({s:e}, {q:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max hurwitz_zeta error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_with_zeta(mut f: impl FnMut(f32)) {
+    let mut rng = create_prng();
+
+    for e in -126..=127 {
+        for _ in 0..1000 {
+            let m = rng.random::<u32>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    for e in -5..=7 {
+        for _ in 0..10000 {
+            let m = rng.random::<u32>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    // close to the pole
+    for e in -24..=-1 {
+        for _ in 0..1000 {
+            let m = rng.random::<u32>();
+            f(1.0 + mkfloat(m, e, false));
+            f(1.0 - mkfloat(m, e, false));
+        }
+    }
+
+    // close to the zeros
+    for n in 1..=30 {
+        for e in -30..=-1 {
+            for _ in 0..20 {
+                let m = rng.random::<u32>();
+                f(-2.0 * n as f32 + mkfloat(m, e, false));
+                f(-2.0 * n as f32 - mkfloat(m, e, false));
+            }
+        }
+    }
+
+    for i in -2000..=2000 {
+        f(i as f32 / 20.0);
+    }
+
+    // subnormals
+    for i in 0..23 {
+        let x = f32::from_bits(1 << i);
+        f(x);
+        f(-x);
+        let x = f32::from_bits((1 << (i + 1)) - 1);
+        f(x);
+        f(-x);
+    }
+}
+
+fn test_with_hurwitz_zeta(mut f: impl FnMut(f32, f32)) {
+    let mut rng = create_prng();
+
+    for es in -23..=7 {
+        for _ in 0..2000 {
+            let s = 1.0 + mkfloat(rng.random::<u32>(), es, false);
+            let eq = rng.random_range(-126..=127);
+            let q = mkfloat(rng.random::<u32>(), eq, false);
+            f(s, q);
+        }
+        for eq in -8..=8 {
+            for _ in 0..200 {
+                let s = 1.0 + mkfloat(rng.random::<u32>(), es, false);
+                let q = mkfloat(rng.random::<u32>(), eq, false);
+                f(s, q);
+            }
+        }
+    }
+
+    for s in 2..=20 {
+        for i in 1..=1000 {
+            f(s as f32, i as f32 / 100.0);
+        }
+    }
+}
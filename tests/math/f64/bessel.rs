@@ -0,0 +1,227 @@
+use rand::RngExt as _;
+
+use super::{RUG_PREC, calc_error_ulp, mkfloat, purify, select_threshold};
+use crate::create_prng;
+
+#[test]
+fn test_j0() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = rug::Float::with_val(RUG_PREC, x).j0();
+        let actual = fpmath::j0(x);
+
+        let err = calc_error_ulp(actual, expected.clone());
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold || calc_error_amp(actual, expected, x) < 1.0 / 64.0,
+            "j0({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max j0 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_j1() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = rug::Float::with_val(RUG_PREC, x).j1();
+        let actual = fpmath::j1(x);
+
+        let err = calc_error_ulp(actual, expected.clone());
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold || calc_error_amp(actual, expected, x) < 1.0 / 64.0,
+            "j1({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max j1 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_jn() {
+    let mut max_error: f64 = 0.0;
+    for n in [-6, -5, 2, 3, 7, 20] {
+        test_with_short(|x| {
+            let expected = rug::Float::with_val(RUG_PREC, x).jn(n);
+            let actual = fpmath::jn(n, x);
+
+            let err = calc_error_ulp(actual, expected.clone());
+            max_error = max_error.max(err);
+
+            assert!(
+                err < 32.0 || calc_error_amp(actual, expected, x) < 32.0,
+                "jn({n}, {x:e}) = {actual:e} (error = {err} ULP)",
+            );
+        });
+    }
+    eprintln!("max jn error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_y0() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = rug::Float::with_val(RUG_PREC, x).y0();
+        let actual = fpmath::y0(x);
+
+        let err = calc_error_ulp(actual, expected.clone());
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold || calc_error_amp(actual, expected, x) < 1.0 / 64.0,
+            "y0({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max y0 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_y1() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = rug::Float::with_val(RUG_PREC, x).y1();
+        let actual = fpmath::y1(x);
+
+        let err = calc_error_ulp(actual, expected.clone());
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold || calc_error_amp(actual, expected, x) < 1.0 / 64.0,
+            "y1({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max y1 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_yn() {
+    let mut max_error: f64 = 0.0;
+    for n in [-6, -5, 2, 3, 7, 20] {
+        test_with_short(|x| {
+            let expected = rug::Float::with_val(RUG_PREC, x).yn(n);
+            let actual = fpmath::yn(n, x);
+
+            let err = calc_error_ulp(actual, expected.clone());
+            max_error = max_error.max(err);
+
+            assert!(
+                err < 32.0 || calc_error_amp(actual, expected, x) < 32.0,
+                "yn({n}, {x:e}) = {actual:e} (error = {err} ULP)",
+            );
+        });
+    }
+    eprintln!("max yn error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_extreme_n() {
+    for n in [i32::MAX, i32::MAX - 1, i32::MIN, i32::MIN + 1, 1_000_000] {
+        let odd = (n & 1) != 0;
+        for x in [1.5f64, 100.0, 1.0e-300, 5.0e-324] {
+            // the results underflow, with the sign of (-1)^n for n < 0 or x < 0
+            let expected = if odd && n < 0 { -0.0f64 } else { 0.0 };
+            assert_eq!(
+                fpmath::jn(n, x).to_bits(),
+                expected.to_bits(),
+                "jn({n}, {x:e})"
+            );
+            let expected = if odd && n > 0 { -0.0f64 } else { 0.0 };
+            assert_eq!(
+                fpmath::jn(n, -x).to_bits(),
+                expected.to_bits(),
+                "jn({n}, {:e})",
+                -x
+            );
+
+            // the results overflow, with the sign of (-1)^(n + 1) for n < 0
+            let expected = if odd && n < 0 {
+                f64::INFINITY
+            } else {
+                f64::NEG_INFINITY
+            };
+            assert_eq!(fpmath::yn(n, x), expected, "yn({n}, {x:e})");
+        }
+    }
+
+    // close to the underflow threshold
+    for n in [150, 300, 500, 1000] {
+        for x in [1.0f64, 10.0, 50.0, 100.0, 140.0] {
+            let expected = rug::Float::with_val(RUG_PREC, x).jn(n);
+            let actual = fpmath::jn(n, x);
+            let err = calc_error_ulp(actual, expected);
+            assert!(
+                err < 32.0,
+                "jn({n}, {x:e}) = {actual:e} (error = {err} ULP)"
+            );
+        }
+    }
+}
+
+/// Calculates the error relative to the amplitude of the oscillations,
+/// `min(1, √(2 / (π * |x|)))`, in units of `f64::EPSILON`.
+///
+/// Near the zeros of the functions, the relative error can be large even
+/// when the absolute error is small.
+fn calc_error_amp(actual: f64, expected: rug::Float, x: f64) -> f64 {
+    let amp = (2.0 / (std::f64::consts::PI * x.abs())).sqrt().min(1.0);
+    let dif = (expected - purify(actual)).abs().to_f64();
+    dif / amp / f64::EPSILON
+}
+
+fn test_with(mut f: impl FnMut(f64)) {
+    let mut rng = create_prng();
+
+    for e in -1022..=1023 {
+        for _ in 0..1000 {
+            let m = rng.random::<u64>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    for i in 0..5000 {
+        let x = (i as f64) / 100.0;
+        f(x);
+        f(-x);
+    }
+
+    // subnormals
+    for i in 0..52 {
+        let x = f64::from_bits(1 << i);
+        f(x);
+        f(-x);
+        let x = f64::from_bits((1 << (i + 1)) - 1);
+        f(x);
+        f(-x);
+    }
+}
+
+fn test_with_short(mut f: impl FnMut(f64)) {
+    let mut rng = create_prng();
+
+    for e in -30..=30 {
+        for _ in 0..1000 {
+            let m = rng.random::<u64>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    for i in 0..5000 {
+        let x = (i as f64) / 100.0;
+        f(x);
+        f(-x);
+    }
+}
@@ -0,0 +1,316 @@
+use rand::RngExt as _;
+
+use super::{RUG_PREC, calc_error_ulp, mkfloat, purify, select_threshold};
+use crate::create_prng;
+
+#[test]
+fn test_beta() {
+    let mut max_error: f64 = 0.0;
+    test_with(|a, b| {
+        let (ln_expected, expected_sign) = ln_beta_ref(a, b);
+        let mut expected = ln_expected.exp();
+        if expected_sign < 0 {
+            expected = -expected;
+        }
+        let actual = fpmath::beta(a, b);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = if a > 0.0 && b > 0.0 {
+            select_threshold(actual, 1.9, 2.9)
+        } else {
+            select_threshold(actual, 3.9, 4.9)
+        };
+        assert!(
+            err < threshold,
+            "beta({a:e}, {b:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max beta error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_ln_beta() {
+    let mut max_error: f64 = 0.0;
+    test_with(|a, b| {
+        let (expected, expected_sign) = ln_beta_ref(a, b);
+        let (actual, actual_sign) = fpmath::ln_beta(a, b);
+
+        let err = calc_error_ulp(actual, expected.clone());
+        max_error = max_error.max(err);
+
+        // The absolute error is kept small, but the relative error can be
+        // larger when the result is close to zero
+        let threshold = if a > 0.0 && b > 0.0 { 1.9 } else { 3.9 };
+        assert_eq!(expected_sign, actual_sign);
+        assert!(
+            err < threshold || calc_error_abs(actual, expected) < 0.5,
+            "ln_beta({a:e}, {b:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max ln_beta error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_beta_inc() {
+    let mut max_error: f64 = 0.0;
+    test_with_inc(|a, b, x| {
+        let (expected, _) = beta_inc_ref(a, b, x);
+        let actual = fpmath::beta_inc(a, b, x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "beta_inc({a:e}, {b:e}, {x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max beta_inc error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_beta_inc_inv() {
+    let mut max_error: f64 = 0.0;
+    test_with_inc(|a, b, x| {
+        let p = fpmath::beta_inc(a, b, x);
+        // The accuracy is not guaranteed when `p` is subnormal or when `a`
+        // and `b` are both small
+        if p == 0.0 || p == 1.0 || p.is_subnormal() || a + b < 0.5 {
+            return;
+        }
+
+        let actual = fpmath::beta_inc_inv(a, b, p);
+        let expected = beta_inc_inv_ref(a, b, p, actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 1.9, 2.9);
+        assert!(
+            err < threshold,
+            "beta_inc_inv({a:e}, {b:e}, {p:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max beta_inc_inv error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+/// Calculates the working precision needed to compensate the cancellation
+/// in the exponent of `x^a * (1 - x)^b / B(a, b)` and in `1 - I_x(a, b)`
+/// when `a` or `b` is small.
+fn beta_inc_ref_prec(a: f64, b: f64) -> u32 {
+    let exp = |x: f64| rug::Float::with_val(53, x).get_exp().unwrap();
+    let (ea, eb) = (exp(a), exp(b));
+    RUG_PREC
+        + 64
+        + ea.max(eb).max(0).unsigned_abs()
+        + ea.abs_diff(eb)
+        + ea.min(eb).min(0).unsigned_abs()
+}
+
+/// Calculates `I_x(a, b)` and `1 - I_x(a, b)`.
+pub(crate) fn beta_inc_ref(a: f64, b: f64, x: f64) -> (rug::Float, rug::Float) {
+    let prec = beta_inc_ref_prec(a, b);
+    beta_inc_ref_with(
+        &rug::Float::with_val(prec, a),
+        &rug::Float::with_val(prec, b),
+        &rug::Float::with_val(prec, x),
+    )
+}
+
+/// Calculates `I_x(a, b)` and `1 - I_x(a, b)` with the precision of `a`,
+/// using the continued fraction of `I_x(a, b)` when
+/// `x < (a + 1) / (a + b + 2)` and the one of `I_(1 - x)(b, a)` otherwise.
+/// `a` and `b` must be positive and `0 <= x <= 1`.
+fn beta_inc_ref_with(a: &rug::Float, b: &rug::Float, x: &rug::Float) -> (rug::Float, rug::Float) {
+    let prec = a.prec();
+    let y = rug::Float::with_val(prec, 1u32 - x);
+
+    let mean = rug::Float::with_val(prec, a + 1u32) / (rug::Float::with_val(prec, a + b) + 2u32);
+    if *x < mean {
+        let p = beta_inc_cont_frac_ref(a, b, x, &y);
+        let q = rug::Float::with_val(prec, 1u32 - &p);
+        (p, q)
+    } else {
+        let q = beta_inc_cont_frac_ref(b, a, &y, x);
+        let p = rug::Float::with_val(prec, 1u32 - &q);
+        (p, q)
+    }
+}
+
+/// Calculates `I_x(a, b)` with its continued fraction, where `y = 1 - x`,
+/// evaluated with the modified Lentz's method
+fn beta_inc_cont_frac_ref(
+    a: &rug::Float,
+    b: &rug::Float,
+    x: &rug::Float,
+    y: &rug::Float,
+) -> rug::Float {
+    let prec = a.prec();
+    if x.is_zero() {
+        return rug::Float::new(prec);
+    }
+
+    let c = rug::Float::with_val(prec, a + b);
+    let one = rug::Float::with_val(prec, 1);
+    let mut d = (one.clone() - c.clone() * x / (a.clone() + 1u32)).recip();
+    let mut e = one;
+    let mut f = d.clone();
+    let mut m = 0u32;
+    loop {
+        m += 1;
+        // d_2m = m * (b - m) * x / ((a + 2m - 1) * (a + 2m))
+        let an = (b.clone() - m) * m * x / ((a.clone() + (2 * m - 1)) * (a.clone() + 2 * m));
+        d = (an.clone() * &d + 1u32).recip();
+        e = an / e + 1u32;
+        f *= rug::Float::with_val(prec, &d * &e);
+
+        // d_2m+1 = -(a + m) * (a + b + m) * x / ((a + 2m) * (a + 2m + 1))
+        let an = -(a.clone() + m) * (c.clone() + m) * x
+            / ((a.clone() + 2 * m) * (a.clone() + (2 * m + 1)));
+        d = (an.clone() * &d + 1u32).recip();
+        e = an / e + 1u32;
+        let delta = rug::Float::with_val(prec, &d * &e);
+        f *= &delta;
+        let err = delta - 1u32;
+        if err.is_zero() || err.get_exp().unwrap() < -(prec as i32) {
+            break;
+        }
+    }
+
+    // x^a * (1 - x)^b / (a * B(a, b))
+    let ln_beta = a.clone().ln_gamma() + b.clone().ln_gamma() - c.ln_gamma();
+    let pre = (a.clone() * x.clone().ln() + b.clone() * y.clone().ln() - ln_beta).exp() / a;
+    pre * f
+}
+
+/// Calculates the inverse of `I_x(a, b)` by refining the approximation `x0`
+/// with Newton's method.
+pub(crate) fn beta_inc_inv_ref(a: f64, b: f64, p: f64, x0: f64) -> rug::Float {
+    let prec = beta_inc_ref_prec(a, b) + RUG_PREC;
+    let a = rug::Float::with_val(prec, a);
+    let b = rug::Float::with_val(prec, b);
+    let c = rug::Float::with_val(prec, &a + &b);
+    let ln_beta = a.clone().ln_gamma() + b.clone().ln_gamma() - c.ln_gamma();
+
+    let mut x = rug::Float::with_val(prec, x0);
+    for _ in 0..4 {
+        let (p_x, q_x) = beta_inc_ref_with(&a, &b, &x);
+        // `1 - p` is exact and avoids the cancellation when `p` is close to 1
+        let f = if p > 0.5 { (1.0 - p) - q_x } else { p_x - p };
+        // dI/dx = x^(a - 1) * (1 - x)^(b - 1) / B(a, b)
+        let y = rug::Float::with_val(prec, 1u32 - &x);
+        let deriv =
+            ((a.clone() - 1u32) * x.clone().ln() + (b.clone() - 1u32) * y.ln() - &ln_beta).exp();
+        x -= f / deriv;
+    }
+    x
+}
+
+/// Calculates `ln(|B(a, b)|)` and the sign of `B(a, b)` from the logarithms
+/// of the gamma functions, with enough precision to compensate the
+/// cancellation between them.
+pub(crate) fn ln_beta_ref(a: f64, b: f64) -> (rug::Float, i8) {
+    let exp = |x: f64| rug::Float::with_val(53, x).get_exp().unwrap();
+    let (ea, eb) = (exp(a), exp(b));
+    let prec = RUG_PREC + 64 + ea.max(eb).max(0).unsigned_abs() + ea.abs_diff(eb);
+
+    let a = rug::Float::with_val(prec, a);
+    let b = rug::Float::with_val(prec, b);
+    let c = rug::Float::with_val(prec, &a + &b);
+
+    let (ln_gamma_a, ord_a) = a.ln_abs_gamma();
+    let (ln_gamma_b, ord_b) = b.ln_abs_gamma();
+    let (ln_gamma_c, ord_c) = c.ln_abs_gamma();
+
+    let sign = |ord: std::cmp::Ordering| if ord.is_lt() { -1 } else { 1 };
+    (
+        ln_gamma_a + ln_gamma_b - ln_gamma_c,
+        sign(ord_a) * sign(ord_b) * sign(ord_c),
+    )
+}
+
+/// Calculates the absolute error in units of `f64::EPSILON`.
+fn calc_error_abs(actual: f64, expected: rug::Float) -> f64 {
+    let dif = (expected - purify(actual)).abs();
+    dif.to_f64() / f64::EPSILON
+}
+
+fn test_with(mut f: impl FnMut(f64, f64)) {
+    let mut rng = create_prng();
+
+    for ea in (-40..=40).step_by(2) {
+        for eb in (-40..=80).step_by(4) {
+            for _ in 0..10 {
+                let a = mkfloat(rng.random::<u64>(), ea, false);
+                let b = mkfloat(rng.random::<u64>(), eb, false);
+                f(a, b);
+            }
+        }
+    }
+
+    for i in 1..=100 {
+        for j in 1..=100 {
+            let a = (i as f64) / 10.0;
+            let b = (j as f64) / 10.0;
+            f(a, b);
+        }
+    }
+
+    // Negative arguments, away from the poles of the gamma function
+    // of `a`, `b` and `a + b`
+    for _ in 0..10000 {
+        let a = -rng.random_range(0.0..20.0f64);
+        let b = rng.random_range(-20.0..200.0f64);
+        let away_from_int = |x: f64| (x - x.round()).abs() > 0.1;
+        if away_from_int(a) && away_from_int(b) && away_from_int(a + b) {
+            f(a, b);
+        }
+    }
+}
+
+fn test_with_inc(mut f: impl FnMut(f64, f64, f64)) {
+    let mut rng = create_prng();
+
+    for ea in (-20..=20).step_by(4) {
+        for eb in (-20..=20).step_by(4) {
+            for ex in (-40..=-1).step_by(3) {
+                for _ in 0..2 {
+                    let a = mkfloat(rng.random::<u64>(), ea, false);
+                    let b = mkfloat(rng.random::<u64>(), eb, false);
+                    let x = mkfloat(rng.random::<u64>(), ex, false);
+                    f(a, b, x);
+                    // Close to one
+                    f(a, b, 1.0 - x);
+                }
+            }
+        }
+    }
+
+    for i in 1..=20 {
+        for j in 1..=20 {
+            for k in 1..20 {
+                let a = (i as f64) / 2.0;
+                let b = (j as f64) / 2.0;
+                let x = (k as f64) / 20.0;
+                f(a, b, x);
+            }
+        }
+    }
+
+    // Close to the mean, where `I` and `1 - I` are not small
+    for _ in 0..2000 {
+        let a = rng.random_range(1.0..100000.0f64);
+        let b = rng.random_range(1.0..100000.0f64);
+        let x = a / (a + b) * rng.random_range(0.9..1.1f64);
+        if x < 1.0 {
+            f(a, b, x);
+        }
+    }
+}
@@ -0,0 +1,699 @@
+use fpmath::complex::Complex;
+use rand::RngExt as _;
+use rug::ops::Pow as _;
+
+use super::{RUG_PREC, calc_error_ulp, mkfloat};
+use crate::create_prng;
+
+/// Calculates the error of each part in ULP of that part and returns the
+/// largest one.
+fn calc_error(actual: Complex<f64>, expected: &rug::Complex) -> f64 {
+    let re = calc_error_ulp(actual.re, expected.real().clone());
+    let im = calc_error_ulp(actual.im, expected.imag().clone());
+    re.max(im)
+}
+
+/// Calculates the error of each part in ULP of the largest part of the
+/// expected value and returns the largest one.
+fn calc_norm_error(actual: Complex<f64>, expected: &rug::Complex) -> f64 {
+    let (e_re, e_im) = (expected.real(), expected.imag());
+    if !e_re.is_finite() || !e_im.is_finite() || (e_re.is_zero() && e_im.is_zero()) {
+        return calc_error(actual, expected);
+    }
+    let mag = rug::Float::with_val(RUG_PREC, e_re.abs_ref()).max(&e_im.clone().abs());
+    let exp = (mag.get_exp().unwrap() - 1).max(-1022);
+    let part_error = |actual: f64, expected: &rug::Float| {
+        if actual.is_finite() && expected.get_exp().is_none_or(|e| e <= 1024) {
+            (rug::Float::with_val(RUG_PREC, expected - actual).abs() >> (exp - 52)).to_f64()
+        } else {
+            calc_error_ulp(actual, expected.clone())
+        }
+    };
+    part_error(actual.re, e_re).max(part_error(actual.im, e_im))
+}
+
+fn to_rug(z: Complex<f64>) -> rug::Complex {
+    rug::Complex::with_val(RUG_PREC, (z.re, z.im))
+}
+
+fn test_unary(
+    name: &str,
+    f: impl Fn(Complex<f64>) -> Complex<f64>,
+    f_rug: impl Fn(rug::Complex) -> rug::Complex,
+    norm: bool,
+    threshold: f64,
+) {
+    let mut max_error: f64 = 0.0;
+    test_with(|z| {
+        let expected = f_rug(to_rug(z));
+        let actual = f(z);
+
+        let err = if norm {
+            calc_norm_error(actual, &expected)
+        } else {
+            calc_error(actual, &expected)
+        };
+        max_error = max_error.max(err);
+
+        assert!(
+            err < threshold,
+            "{name}({:e} + {:e}i) = {:e} + {:e}i (error = {err} ULP)",
+            z.re,
+            z.im,
+            actual.re,
+            actual.im,
+        );
+    });
+    eprintln!("max complex {name} error = {max_error}");
+}
+
+#[test]
+fn test_exp() {
+    test_unary("exp", Complex::exp, |z| z.exp(), false, 2.5);
+}
+
+#[test]
+fn test_ln() {
+    test_unary("ln", Complex::ln, |z| z.ln(), false, 0.9);
+}
+
+#[test]
+fn test_sqrt() {
+    test_unary("sqrt", Complex::sqrt, |z| z.sqrt(), false, 2.0);
+}
+
+#[test]
+fn test_sin() {
+    test_unary("sin", Complex::sin, |z| z.sin(), false, 2.5);
+}
+
+#[test]
+fn test_cos() {
+    test_unary("cos", Complex::cos, |z| z.cos(), false, 2.5);
+}
+
+#[test]
+fn test_tan() {
+    test_unary("tan", Complex::tan, tan_ref, false, 5.0);
+}
+
+#[test]
+fn test_sinh() {
+    test_unary("sinh", Complex::sinh, |z| z.sinh(), false, 2.5);
+}
+
+#[test]
+fn test_cosh() {
+    test_unary("cosh", Complex::cosh, |z| z.cosh(), false, 2.5);
+}
+
+#[test]
+fn test_tanh() {
+    test_unary("tanh", Complex::tanh, tanh_ref, false, 5.0);
+}
+
+/// MPC is very slow for `tanh(x + iy)` with a large `|x|`, so use
+/// `(sinh(2x) + i * sin(2y)) / (cosh(2x) + cos(2y))` when `|x| >= 1`.
+fn tanh_ref(z: rug::Complex) -> rug::Complex {
+    let (x, y) = z.into_real_imag();
+    if x.clone().abs() < 1 {
+        rug::Complex::with_val(RUG_PREC, (x, y)).tanh()
+    } else if x.clone().abs() > 1024 {
+        // the imaginary part is far below the smallest subnormal
+        let im = (y * 2u8).sin() * 0.0;
+        rug::Complex::with_val(RUG_PREC, (x.signum(), im))
+    } else {
+        let x2 = x * 2u8;
+        let y2 = y * 2u8;
+        let d = x2.clone().cosh() + y2.clone().cos();
+        let re = x2.sinh() / &d;
+        let im = y2.sin() / &d;
+        rug::Complex::with_val(RUG_PREC, (re, im))
+    }
+}
+
+/// `tan(z) = -i * tanh(i * z)`
+fn tan_ref(z: rug::Complex) -> rug::Complex {
+    let (x, y) = z.into_real_imag();
+    let (re, im) = tanh_ref(rug::Complex::with_val(RUG_PREC, (-y, x))).into_real_imag();
+    rug::Complex::with_val(RUG_PREC, (im, -re))
+}
+
+#[test]
+fn test_asin() {
+    test_unary("asin", Complex::asin, |z| z.asin(), false, 3.5);
+}
+
+#[test]
+fn test_acos() {
+    test_unary("acos", Complex::acos, |z| z.acos(), false, 3.5);
+}
+
+#[test]
+fn test_atan() {
+    test_unary("atan", Complex::atan, |z| z.atan(), false, 3.5);
+}
+
+#[test]
+fn test_asinh() {
+    test_unary("asinh", Complex::asinh, |z| z.asinh(), false, 3.5);
+}
+
+#[test]
+fn test_acosh() {
+    test_unary("acosh", Complex::acosh, |z| z.acosh(), false, 3.5);
+}
+
+#[test]
+fn test_atanh() {
+    test_unary("atanh", Complex::atanh, |z| z.atanh(), false, 3.5);
+}
+
+fn test_binary(
+    name: &str,
+    f: impl Fn(Complex<f64>, Complex<f64>) -> Complex<f64>,
+    f_rug: impl Fn(rug::Complex, rug::Complex) -> rug::Complex,
+    norm: bool,
+    threshold: f64,
+) {
+    let mut max_error: f64 = 0.0;
+    test_binary_with(|z, w| {
+        let expected = f_rug(to_rug(z), to_rug(w));
+        let actual = f(z, w);
+
+        let err = if norm {
+            calc_norm_error(actual, &expected)
+        } else {
+            calc_error(actual, &expected)
+        };
+        max_error = max_error.max(err);
+
+        assert!(
+            err < threshold,
+            "{name}({:e} + {:e}i, {:e} + {:e}i) = {:e} + {:e}i (error = {err} ULP)",
+            z.re,
+            z.im,
+            w.re,
+            w.im,
+            actual.re,
+            actual.im,
+        );
+    });
+    eprintln!("max complex {name} error = {max_error}");
+}
+
+#[test]
+fn test_mul() {
+    test_binary("mul", |z, w| z * w, |z, w| z * w, false, 0.9);
+}
+
+#[test]
+fn test_div() {
+    test_binary("div", |z, w| z / w, |z, w| z / w, false, 0.9);
+}
+
+#[test]
+fn test_pow() {
+    let mut max_error: f64 = 0.0;
+    test_pow_with(|z, w| {
+        let expected = to_rug(z).pow(to_rug(w));
+        let actual = z.pow(w);
+
+        let err = calc_norm_error(actual, &expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 6.0,
+            "pow({:e} + {:e}i, {:e} + {:e}i) = {:e} + {:e}i (error = {err} ULP)",
+            z.re,
+            z.im,
+            w.re,
+            w.im,
+            actual.re,
+            actual.im,
+        );
+    });
+    eprintln!("max complex pow error = {max_error}");
+}
+
+fn test_binary_with(mut f: impl FnMut(Complex<f64>, Complex<f64>)) {
+    let mut rng = create_prng();
+
+    for _ in 0..20000 {
+        let [ea, eb, ec, ed] = [(); 4].map(|()| rng.random_range(-1022..=1023));
+        let a = rand_float(&mut rng, ea);
+        let b = rand_float(&mut rng, eb);
+        let c = rand_float(&mut rng, ec);
+        let d = rand_float(&mut rng, ed);
+        f(Complex::new(a, b), Complex::new(c, d));
+    }
+    for _ in 0..20000 {
+        let [ea, eb, ec, ed] = [(); 4].map(|()| rng.random_range(-30..=30));
+        let a = rand_float(&mut rng, ea);
+        let b = rand_float(&mut rng, eb);
+        let c = rand_float(&mut rng, ec);
+        let d = rand_float(&mut rng, ed);
+        f(Complex::new(a, b), Complex::new(c, d));
+
+        // a * c - b * d or a * c + b * d cancel
+        let e = rng.random_range(-60..=-1);
+        let c = b * (1.0 + rand_float(&mut rng, e));
+        let d = a * rand_float(&mut rng, 0).signum();
+        f(Complex::new(a, b), Complex::new(c, d));
+        f(Complex::new(a, b), Complex::new(d, c));
+    }
+}
+
+fn test_pow_with(mut f: impl FnMut(Complex<f64>, Complex<f64>)) {
+    let mut rng = create_prng();
+
+    for _ in 0..20000 {
+        let [ea, eb] = [(); 2].map(|()| rng.random_range(-30..=30));
+        let [ec, ed] = [(); 2].map(|()| rng.random_range(-30..=4));
+        let a = rand_float(&mut rng, ea);
+        let b = rand_float(&mut rng, eb);
+        let c = rand_float(&mut rng, ec);
+        let d = rand_float(&mut rng, ed);
+        f(Complex::new(a, b), Complex::new(c, d));
+    }
+    for _ in 0..5000 {
+        // real and integer exponents
+        let ea = rng.random_range(-30..=30);
+        let a = rand_float(&mut rng, ea);
+        let b = rand_float(&mut rng, ea);
+        let n = f64::from(rng.random_range(-20..=20));
+        f(Complex::new(a, b), Complex::new(n, 0.0));
+        let c = rand_float(&mut rng, 2);
+        f(Complex::new(a, b), Complex::new(c, 0.0));
+    }
+}
+
+fn test_with(mut f: impl FnMut(Complex<f64>)) {
+    let mut rng = create_prng();
+
+    for ex in (-1022..=1023).step_by(16) {
+        for ey in (-1022..=1023).step_by(16) {
+            let x = rand_float(&mut rng, ex);
+            let y = rand_float(&mut rng, ey);
+            f(Complex::new(x, y));
+        }
+    }
+    for ex in -20..=20 {
+        for ey in -20..=20 {
+            for _ in 0..3 {
+                let x = rand_float(&mut rng, ex);
+                let y = rand_float(&mut rng, ey);
+                f(Complex::new(x, y));
+            }
+        }
+    }
+    for e in -60..=-1 {
+        for _ in 0..10 {
+            // near the unit circle
+            let r = 1.0 + rand_float(&mut rng, e);
+            let t = rand_float(&mut rng, 1);
+            f(Complex::new(r * t.cos(), r * t.sin()));
+
+            // near ±1 and ±i
+            let a = rand_float(&mut rng, 0).signum() * (1.0 + rand_float(&mut rng, e));
+            let eb = rng.random_range(-1022..=0);
+            let b = rand_float(&mut rng, eb);
+            f(Complex::new(a, b));
+            f(Complex::new(b, a));
+        }
+    }
+}
+
+fn rand_float(rng: &mut impl rand::Rng, e: i16) -> f64 {
+    mkfloat(rng.random::<u64>(), e, rng.random::<bool>())
+}
+
+const INF: f64 = f64::INFINITY;
+const NAN: f64 = f64::NAN;
+const PI: f64 = std::f64::consts::PI;
+const FRAC_PI_2: f64 = std::f64::consts::FRAC_PI_2;
+const FRAC_PI_4: f64 = std::f64::consts::FRAC_PI_4;
+const FRAC_3PI_4: f64 = 3.0 * FRAC_PI_4;
+
+/// Special value: `(x, y, re, im, any_sign)`, where `any_sign` means
+/// that the signs of the result are not specified.
+type Special = (f64, f64, f64, f64, bool);
+
+#[derive(Copy, Clone, PartialEq)]
+enum Symmetry {
+    None,
+    Odd,
+    Even,
+}
+
+fn same(actual: f64, expected: f64, any_sign: bool) -> bool {
+    if expected.is_nan() {
+        actual.is_nan()
+    } else if any_sign {
+        same(actual.abs(), expected.abs(), false)
+    } else if expected == 0.0 || expected.is_infinite() {
+        actual == expected && actual.is_sign_negative() == expected.is_sign_negative()
+    } else {
+        (actual - expected).abs() <= expected.abs() * f64::EPSILON
+    }
+}
+
+/// Checks the special values of C99 Annex G, which are given for the upper
+/// half plane (or the first quadrant) and extended by symmetry
+fn check_special(
+    name: &str,
+    f: impl Fn(Complex<f64>) -> Complex<f64>,
+    symmetry: Symmetry,
+    table: &[Special],
+) {
+    for &(x, y, re, im, any_sign) in table {
+        let mut cases = vec![(x, y, re, im), (x, -y, re, -im)];
+        match symmetry {
+            Symmetry::None => {}
+            Symmetry::Odd => cases.extend([(-x, -y, -re, -im), (-x, y, -re, im)]),
+            Symmetry::Even => cases.extend([(-x, -y, re, im), (-x, y, re, -im)]),
+        }
+        for (x, y, re, im) in cases {
+            let r = f(Complex::new(x, y));
+            assert!(
+                same(r.re, re, any_sign) && same(r.im, im, any_sign),
+                "{name}({x:e} + {y:e}i) = {:e} + {:e}i, expected {re:e} + {im:e}i",
+                r.re,
+                r.im,
+            );
+        }
+    }
+}
+
+#[test]
+fn test_special_exp() {
+    check_special(
+        "exp",
+        Complex::exp,
+        Symmetry::None,
+        &[
+            (0.0, 0.0, 1.0, 0.0, false),
+            (-0.0, 0.0, 1.0, 0.0, false),
+            (2.0, INF, NAN, NAN, false),
+            (-0.0, INF, NAN, NAN, false),
+            (2.0, NAN, NAN, NAN, false),
+            (INF, 0.0, INF, 0.0, false),
+            (-INF, 0.5, 0.0, 0.0, false),
+            (-INF, 2.0, -0.0, 0.0, false),
+            (INF, 0.5, INF, INF, false),
+            (INF, 2.0, -INF, INF, false),
+            (-INF, INF, 0.0, 0.0, true),
+            (INF, INF, INF, NAN, true),
+            (-INF, NAN, 0.0, 0.0, true),
+            (INF, NAN, INF, NAN, true),
+            (NAN, 0.0, NAN, 0.0, false),
+            (NAN, 2.0, NAN, NAN, false),
+            (NAN, NAN, NAN, NAN, false),
+        ],
+    );
+}
+
+#[test]
+fn test_special_ln() {
+    check_special(
+        "ln",
+        Complex::ln,
+        Symmetry::None,
+        &[
+            (-0.0, 0.0, -INF, PI, false),
+            (0.0, 0.0, -INF, 0.0, false),
+            (2.0, INF, INF, FRAC_PI_2, false),
+            (-0.0, INF, INF, FRAC_PI_2, false),
+            (2.0, NAN, NAN, NAN, false),
+            (-INF, 2.0, INF, PI, false),
+            (INF, 2.0, INF, 0.0, false),
+            (-INF, INF, INF, FRAC_3PI_4, false),
+            (INF, INF, INF, FRAC_PI_4, false),
+            (INF, NAN, INF, NAN, false),
+            (-INF, NAN, INF, NAN, false),
+            (NAN, 2.0, NAN, NAN, false),
+            (NAN, INF, INF, NAN, false),
+            (NAN, NAN, NAN, NAN, false),
+        ],
+    );
+}
+
+#[test]
+fn test_special_sqrt() {
+    check_special(
+        "sqrt",
+        Complex::sqrt,
+        Symmetry::None,
+        &[
+            (0.0, 0.0, 0.0, 0.0, false),
+            (-0.0, 0.0, 0.0, 0.0, false),
+            (2.0, INF, INF, INF, false),
+            (-INF, INF, INF, INF, false),
+            (NAN, INF, INF, INF, false),
+            (2.0, NAN, NAN, NAN, false),
+            (-INF, 2.0, 0.0, INF, false),
+            (INF, 2.0, INF, 0.0, false),
+            (-INF, NAN, NAN, INF, true),
+            (INF, NAN, INF, NAN, false),
+            (NAN, 2.0, NAN, NAN, false),
+            (NAN, NAN, NAN, NAN, false),
+        ],
+    );
+}
+
+#[test]
+fn test_special_sinh() {
+    check_special(
+        "sinh",
+        Complex::sinh,
+        Symmetry::Odd,
+        &[
+            (0.0, 0.0, 0.0, 0.0, false),
+            (0.0, INF, 0.0, NAN, true),
+            (0.0, NAN, 0.0, NAN, true),
+            (2.0, INF, NAN, NAN, false),
+            (2.0, NAN, NAN, NAN, false),
+            (INF, 0.0, INF, 0.0, false),
+            (INF, 0.5, INF, INF, false),
+            (INF, 2.0, -INF, INF, false),
+            (INF, INF, INF, NAN, true),
+            (INF, NAN, INF, NAN, true),
+            (NAN, 0.0, NAN, 0.0, false),
+            (NAN, 2.0, NAN, NAN, false),
+            (NAN, NAN, NAN, NAN, false),
+        ],
+    );
+}
+
+#[test]
+fn test_special_cosh() {
+    check_special(
+        "cosh",
+        Complex::cosh,
+        Symmetry::Even,
+        &[
+            (0.0, 0.0, 1.0, 0.0, false),
+            (0.0, INF, NAN, 0.0, true),
+            (0.0, NAN, NAN, 0.0, true),
+            (2.0, INF, NAN, NAN, false),
+            (2.0, NAN, NAN, NAN, false),
+            (INF, 0.0, INF, 0.0, false),
+            (INF, 0.5, INF, INF, false),
+            (INF, 2.0, -INF, INF, false),
+            (INF, INF, INF, NAN, true),
+            (INF, NAN, INF, NAN, false),
+            (NAN, 0.0, NAN, 0.0, true),
+            (NAN, 2.0, NAN, NAN, false),
+            (NAN, NAN, NAN, NAN, false),
+        ],
+    );
+}
+
+#[test]
+fn test_special_tanh() {
+    let sin4 = 4.0f64.sin();
+    check_special(
+        "tanh",
+        Complex::tanh,
+        Symmetry::Odd,
+        &[
+            (0.0, 0.0, 0.0, 0.0, false),
+            (0.0, INF, 0.0, NAN, false),
+            (2.0, INF, NAN, NAN, false),
+            (0.0, NAN, 0.0, NAN, false),
+            (2.0, NAN, NAN, NAN, false),
+            (INF, 0.5, 1.0, 0.0, false),
+            (INF, 2.0, 1.0, 0.0 * sin4, false),
+            (INF, INF, 1.0, 0.0, true),
+            (INF, NAN, 1.0, 0.0, true),
+            (NAN, 0.0, NAN, 0.0, false),
+            (NAN, 2.0, NAN, NAN, false),
+            (NAN, NAN, NAN, NAN, false),
+        ],
+    );
+}
+
+#[test]
+fn test_special_acos() {
+    check_special(
+        "acos",
+        Complex::acos,
+        Symmetry::None,
+        &[
+            (0.0, 0.0, FRAC_PI_2, -0.0, false),
+            (-0.0, 0.0, FRAC_PI_2, -0.0, false),
+            (0.0, NAN, FRAC_PI_2, NAN, false),
+            (-0.0, NAN, FRAC_PI_2, NAN, false),
+            (2.0, INF, FRAC_PI_2, -INF, false),
+            (-0.0, INF, FRAC_PI_2, -INF, false),
+            (2.0, NAN, NAN, NAN, false),
+            (-INF, 2.0, PI, -INF, false),
+            (INF, 2.0, 0.0, -INF, false),
+            (-INF, INF, FRAC_3PI_4, -INF, false),
+            (INF, INF, FRAC_PI_4, -INF, false),
+            (INF, NAN, NAN, INF, true),
+            (-INF, NAN, NAN, INF, true),
+            (NAN, 2.0, NAN, NAN, false),
+            (NAN, INF, NAN, -INF, false),
+            (NAN, NAN, NAN, NAN, false),
+        ],
+    );
+}
+
+#[test]
+fn test_special_asinh() {
+    check_special(
+        "asinh",
+        Complex::asinh,
+        Symmetry::Odd,
+        &[
+            (0.0, 0.0, 0.0, 0.0, false),
+            (2.0, INF, INF, FRAC_PI_2, false),
+            (0.0, NAN, NAN, NAN, false),
+            (2.0, NAN, NAN, NAN, false),
+            (INF, 2.0, INF, 0.0, false),
+            (INF, INF, INF, FRAC_PI_4, false),
+            (INF, NAN, INF, NAN, false),
+            (NAN, 0.0, NAN, 0.0, false),
+            (NAN, 2.0, NAN, NAN, false),
+            (NAN, INF, INF, NAN, true),
+            (NAN, NAN, NAN, NAN, false),
+        ],
+    );
+}
+
+#[test]
+fn test_special_acosh() {
+    check_special(
+        "acosh",
+        Complex::acosh,
+        Symmetry::None,
+        &[
+            (0.0, 0.0, 0.0, FRAC_PI_2, false),
+            (-0.0, 0.0, 0.0, FRAC_PI_2, false),
+            (2.0, INF, INF, FRAC_PI_2, false),
+            (-0.0, INF, INF, FRAC_PI_2, false),
+            (2.0, NAN, NAN, NAN, false),
+            (0.0, NAN, NAN, NAN, false),
+            (-INF, 2.0, INF, PI, false),
+            (INF, 2.0, INF, 0.0, false),
+            (-INF, INF, INF, FRAC_3PI_4, false),
+            (INF, INF, INF, FRAC_PI_4, false),
+            (INF, NAN, INF, NAN, false),
+            (-INF, NAN, INF, NAN, false),
+            (NAN, 2.0, NAN, NAN, false),
+            (NAN, INF, INF, NAN, false),
+            (NAN, NAN, NAN, NAN, false),
+        ],
+    );
+}
+
+#[test]
+fn test_special_atanh() {
+    check_special(
+        "atanh",
+        Complex::atanh,
+        Symmetry::Odd,
+        &[
+            (0.0, 0.0, 0.0, 0.0, false),
+            (0.0, NAN, 0.0, NAN, false),
+            (1.0, 0.0, INF, 0.0, false),
+            (2.0, INF, 0.0, FRAC_PI_2, false),
+            (2.0, NAN, NAN, NAN, false),
+            (INF, 2.0, 0.0, FRAC_PI_2, false),
+            (INF, INF, 0.0, FRAC_PI_2, false),
+            (INF, NAN, 0.0, NAN, false),
+            (NAN, 2.0, NAN, NAN, false),
+            (NAN, INF, 0.0, FRAC_PI_2, true),
+            (NAN, NAN, NAN, NAN, false),
+        ],
+    );
+}
+
+/// Checks that the trigonometric functions are related to the hyperbolic
+/// ones as Annex G specifies, also for special values
+#[test]
+fn test_special_trigonometric() {
+    let values = [0.0, -0.0, 0.5, -0.5, 2.0, -2.0, INF, -INF, NAN];
+    let mul_i = |z: Complex<f64>| Complex::new(-z.im, z.re);
+    let mul_neg_i = |z: Complex<f64>| Complex::new(z.im, -z.re);
+    for x in values {
+        for y in values {
+            let z = Complex::new(x, y);
+            let check = |name: &str, actual: Complex<f64>, expected: Complex<f64>| {
+                // the signs are not specified when a part is NaN
+                let any_sign = expected.re.is_nan() || expected.im.is_nan();
+                assert!(
+                    same(actual.re, expected.re, any_sign)
+                        && same(actual.im, expected.im, any_sign),
+                    "{name}({x:e} + {y:e}i) = {:e} + {:e}i, expected {:e} + {:e}i",
+                    actual.re,
+                    actual.im,
+                    expected.re,
+                    expected.im,
+                );
+            };
+            check("sin", z.sin(), mul_neg_i(mul_i(z).sinh()));
+            check("cos", z.cos(), mul_i(z).cosh());
+            check("tan", z.tan(), mul_neg_i(mul_i(z).tanh()));
+            check("asin", z.asin(), mul_neg_i(mul_i(z).asinh()));
+            check("atan", z.atan(), mul_neg_i(mul_i(z).atanh()));
+        }
+    }
+}
+
+#[test]
+fn test_special_mul_div() {
+    let is_inf = |z: Complex<f64>| z.re.is_infinite() || z.im.is_infinite();
+    let is_zero = |z: Complex<f64>| z.re == 0.0 && z.im == 0.0;
+    let infs = [(INF, NAN), (NAN, -INF), (INF, 2.0), (-0.0, -INF)];
+    let finites = [(2.0, 0.0), (-0.0, 0.5), (2.0, -0.5)];
+    for (a, b) in infs {
+        for (c, d) in finites {
+            let (x, y) = (Complex::new(a, b), Complex::new(c, d));
+            assert!(
+                is_inf(x * y),
+                "({a:e} + {b:e}i) * ({c:e} + {d:e}i) is not infinite"
+            );
+            assert!(
+                is_inf(y * x),
+                "({c:e} + {d:e}i) * ({a:e} + {b:e}i) is not infinite"
+            );
+            assert!(
+                is_inf(x / y),
+                "({a:e} + {b:e}i) / ({c:e} + {d:e}i) is not infinite"
+            );
+            assert!(
+                is_zero(y / x),
+                "({c:e} + {d:e}i) / ({a:e} + {b:e}i) is not zero"
+            );
+        }
+    }
+    for (a, b) in finites.into_iter().chain(infs) {
+        let x = Complex::new(a, b);
+        assert!(
+            is_inf(x / Complex::new(0.0, -0.0)),
+            "({a:e} + {b:e}i) / 0 is not infinite",
+        );
+    }
+}
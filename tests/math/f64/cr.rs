@@ -0,0 +1,371 @@
+use rand::RngExt as _;
+use rug::ops::Pow as _;
+
+use super::{mkfloat, purify};
+use crate::create_prng;
+
+// Enough precision to make the double rounding in `to_f64` harmless
+const CR_RUG_PREC: u32 = 53 * 5;
+
+#[test]
+fn test_exp() {
+    let mut rng = create_prng();
+    for e in -60..=9 {
+        for _ in 0..2000 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("exp", fpmath::cr::exp, rug::Float::exp, x);
+            check("exp2", fpmath::cr::exp2, rug::Float::exp2, x * 1.5);
+            check("exp10", fpmath::cr::exp10, rug::Float::exp10, x * 0.5);
+        }
+    }
+
+    // results close to or in the subnormal range
+    for _ in 0..5000 {
+        let x = rng.random_range(-746.0f64..-700.0);
+        check("exp", fpmath::cr::exp, rug::Float::exp, x);
+        check("exp2", fpmath::cr::exp2, rug::Float::exp2, x * 1.45);
+        check("exp10", fpmath::cr::exp10, rug::Float::exp10, x * 0.435);
+    }
+
+    // exact results
+    for i in -1074..=1023 {
+        check("exp2", fpmath::cr::exp2, rug::Float::exp2, f64::from(i));
+    }
+    for i in -30..=30 {
+        check("exp10", fpmath::cr::exp10, rug::Float::exp10, f64::from(i));
+    }
+}
+
+#[test]
+fn test_exp_m1() {
+    let mut rng = create_prng();
+    for e in -60..=9 {
+        for _ in 0..2000 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("exp_m1", fpmath::cr::exp_m1, rug::Float::exp_m1, x);
+        }
+    }
+}
+
+#[test]
+fn test_log() {
+    let mut rng = create_prng();
+    for e in -1022..=1023 {
+        for _ in 0..50 {
+            let x = mkfloat(rng.random(), e, false);
+            check("ln", fpmath::cr::ln, rug::Float::ln, x);
+            check("log2", fpmath::cr::log2, rug::Float::log2, x);
+            check("log10", fpmath::cr::log10, rug::Float::log10, x);
+        }
+    }
+
+    // close to 1
+    for e in -60..=-1 {
+        for _ in 0..200 {
+            let s = rng.random::<bool>();
+            let x = 1.0 + mkfloat(rng.random(), e, s);
+            check("ln", fpmath::cr::ln, rug::Float::ln, x);
+            check("log2", fpmath::cr::log2, rug::Float::log2, x);
+            check("log10", fpmath::cr::log10, rug::Float::log10, x);
+        }
+    }
+
+    // subnormals
+    for i in 0..52 {
+        let x = f64::from_bits((1 << (i + 1)) - 1);
+        check("ln", fpmath::cr::ln, rug::Float::ln, x);
+        check("log2", fpmath::cr::log2, rug::Float::log2, x);
+        check("log10", fpmath::cr::log10, rug::Float::log10, x);
+    }
+
+    // exact results
+    for i in -1074..=1023 {
+        check("log2", fpmath::cr::log2, rug::Float::log2, 2f64.powi(i));
+    }
+    for i in 0..=22 {
+        check("log10", fpmath::cr::log10, rug::Float::log10, 10f64.powi(i));
+    }
+}
+
+#[test]
+fn test_ln_1p() {
+    let mut rng = create_prng();
+    for e in -60..=1023 {
+        for _ in 0..100 {
+            let x = mkfloat(rng.random(), e, false);
+            check("ln_1p", fpmath::cr::ln_1p, rug::Float::ln_1p, x);
+        }
+    }
+    for e in -60..=-1 {
+        for _ in 0..500 {
+            let x = mkfloat(rng.random(), e, true);
+            check("ln_1p", fpmath::cr::ln_1p, rug::Float::ln_1p, x);
+        }
+    }
+}
+
+#[test]
+fn test_trigonometric() {
+    let mut rng = create_prng();
+    for e in -30..=1023 {
+        let n = if e <= 40 { 500 } else { 20 };
+        for _ in 0..n {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("sin", fpmath::cr::sin, rug::Float::sin, x);
+            check("cos", fpmath::cr::cos, rug::Float::cos, x);
+            check("tan", fpmath::cr::tan, rug::Float::tan, x);
+        }
+    }
+
+    // close to multiples of π/2
+    for k in 1..=2000 {
+        let x = f64::from(k) * std::f64::consts::FRAC_PI_2;
+        for x in [x, x.next_up(), x.next_down()] {
+            check("sin", fpmath::cr::sin, rug::Float::sin, x);
+            check("cos", fpmath::cr::cos, rug::Float::cos, x);
+            check("tan", fpmath::cr::tan, rug::Float::tan, x);
+        }
+    }
+    // x = 6381956970095103 * 2^797 is the closest double to a multiple of π/2
+    let x = 6381956970095103.0 * 2f64.powi(797);
+    check("sin", fpmath::cr::sin, rug::Float::sin, x);
+    check("cos", fpmath::cr::cos, rug::Float::cos, x);
+    check("tan", fpmath::cr::tan, rug::Float::tan, x);
+}
+
+#[test]
+fn test_inverse_trigonometric() {
+    let mut rng = create_prng();
+    for e in -30..=60 {
+        for _ in 0..500 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("atan", fpmath::cr::atan, rug::Float::atan, x);
+            if e < 0 {
+                check("asin", fpmath::cr::asin, rug::Float::asin, x);
+                check("acos", fpmath::cr::acos, rug::Float::acos, x);
+            }
+        }
+    }
+
+    // close to ±1
+    for e in -53..=-2 {
+        for _ in 0..200 {
+            let s = rng.random::<bool>();
+            let x = (1.0 - mkfloat(rng.random(), e, false)) * if s { -1.0 } else { 1.0 };
+            check("asin", fpmath::cr::asin, rug::Float::asin, x);
+            check("acos", fpmath::cr::acos, rug::Float::acos, x);
+        }
+    }
+
+    for ey in -60..=60 {
+        for _ in 0..50 {
+            let ex = rng.random_range(-60..=60);
+            let y = mkfloat(rng.random(), ey, rng.random());
+            let x = mkfloat(rng.random(), ex, rng.random());
+            check2("atan2", fpmath::cr::atan2, rug::Float::atan2, y, x);
+        }
+    }
+
+    // results close to or in the subnormal range
+    for ey in -1023..=-960 {
+        for _ in 0..20 {
+            let y = mkfloat(rng.random(), ey, rng.random());
+            let x = mkfloat(rng.random(), rng.random_range(0..=60), false);
+            check2("atan2", fpmath::cr::atan2, rug::Float::atan2, y, x);
+        }
+    }
+}
+
+#[test]
+fn test_hyperbolic() {
+    let mut rng = create_prng();
+    for e in -30..=9 {
+        for _ in 0..1000 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("sinh", fpmath::cr::sinh, rug::Float::sinh, x);
+            check("cosh", fpmath::cr::cosh, rug::Float::cosh, x);
+            check("tanh", fpmath::cr::tanh, rug::Float::tanh, x);
+        }
+    }
+
+    // results close to overflow
+    for _ in 0..2000 {
+        let x = rng.random_range(700.0f64..711.0);
+        check("sinh", fpmath::cr::sinh, rug::Float::sinh, x);
+        check("cosh", fpmath::cr::cosh, rug::Float::cosh, x);
+    }
+}
+
+#[test]
+fn test_cbrt_hypot() {
+    let mut rng = create_prng();
+    for e in -1023..=1023 {
+        for _ in 0..50 {
+            let s = rng.random::<bool>();
+            let x = mkfloat(rng.random(), e, s);
+            check("cbrt", fpmath::cr::cbrt, rug::Float::cbrt, x);
+
+            let ey = (e + rng.random_range(-40..=40)).clamp(-1023, 1023);
+            let y = mkfloat(rng.random(), ey, rng.random());
+            check2("hypot", fpmath::cr::hypot, rug::Float::hypot, x, y);
+        }
+    }
+
+    // exact results
+    for _ in 0..5000 {
+        let a = rng.random_range(1..(1u64 << 17)) as f64;
+        check("cbrt", fpmath::cr::cbrt, rug::Float::cbrt, a * a * a);
+        check(
+            "cbrt",
+            fpmath::cr::cbrt,
+            rug::Float::cbrt,
+            -a * a * a * 0.125,
+        );
+    }
+
+    // hypot(m^2 - n^2, 2 * m * n) = m^2 + n^2, which is halfway between
+    // two doubles when it is odd and has 54 bits
+    for _ in 0..5000 {
+        let n = rng.random_range((1u64 << 26)..94_000_000);
+        let m = n + 2 * rng.random_range(0..1000) + 1;
+        let a = (m * m - n * n) as f64;
+        let b = (2 * m * n) as f64;
+        check2("hypot", fpmath::cr::hypot, rug::Float::hypot, a, b);
+        check2(
+            "hypot",
+            fpmath::cr::hypot,
+            rug::Float::hypot,
+            a * 0.5,
+            b * 0.5,
+        );
+    }
+}
+
+#[test]
+fn test_pow() {
+    let mut rng = create_prng();
+    for ex in -20..=20 {
+        for ey in -20..=8 {
+            for _ in 0..30 {
+                let sy = rng.random::<bool>();
+                let x = mkfloat(rng.random(), ex, false);
+                let y = mkfloat(rng.random(), ey, sy);
+                check_pow(x, y);
+            }
+        }
+    }
+
+    // negative x with integer y
+    for _ in 0..5000 {
+        let x = -rng.random_range(0.0f64..100.0);
+        let y = f64::from(rng.random_range(-100..=100));
+        check_pow(x, y);
+    }
+
+    // results close to or in the subnormal range
+    for _ in 0..5000 {
+        let x = rng.random_range(0.5f64..2.0);
+        let y = -rng.random_range(700.0..746.0) / x.ln();
+        check_pow(x, y);
+    }
+
+    // exact results and results halfway between two doubles
+    for _ in 0..5000 {
+        let a = rng.random_range(1..(1u64 << 27)) as f64;
+        check_pow(a * a, 0.5);
+        check_pow(a, 2.0);
+        check_pow(a * 0.125, 2.0);
+    }
+    check_pow(94906267.0, 2.0);
+}
+
+#[test]
+fn test_hard_cases() {
+    // exactly halfway between two doubles
+    check_pow(134217727.0, 2.0); // (2^27 - 1)^2
+    check_pow(68718952449.0, 1.5); // (2^18 - 1)^3
+    check_pow(3.0, 34.0);
+    check_pow(2.0, -1075.0);
+    check_pow(4.0, -537.5);
+    check_pow(0.25, 537.5);
+    check("exp10", fpmath::cr::exp10, rug::Float::exp10, 23.0);
+    check2(
+        "hypot",
+        fpmath::cr::hypot,
+        rug::Float::hypot,
+        134217731.0,
+        9007199657394180.0,
+    );
+    check2(
+        "hypot",
+        fpmath::cr::hypot,
+        rug::Float::hypot,
+        134217731.0 * 2f64.powi(-1074),
+        9007199657394180.0 * 2f64.powi(-1074),
+    );
+
+    // y / x is exactly halfway between two subnormals, and atan2(y, x) is
+    // slightly closer to zero (too close for the reference)
+    let x = 2f64.powi(1016);
+    assert_eq!(fpmath::cr::atan2(3.0 * 2f64.powi(-59), x).to_bits(), 1);
+    assert_eq!(fpmath::cr::atan2(7.0 * 2f64.powi(-59), x).to_bits(), 3);
+    assert_eq!(
+        fpmath::cr::atan2(-7.0 * 2f64.powi(-59), x).to_bits(),
+        (-f64::from_bits(3)).to_bits(),
+    );
+
+    // very close to halfway between two doubles
+    check("exp", fpmath::cr::exp, rug::Float::exp, 2f64.powi(-53));
+    check("exp", fpmath::cr::exp, rug::Float::exp, -(2f64.powi(-54)));
+    check_pow(1.0 + f64::EPSILON, 0.5);
+    check_pow(1.0 - f64::EPSILON / 2.0, 0.5);
+}
+
+fn check(
+    name: &str,
+    actual_f: impl Fn(f64) -> f64,
+    expected_f: impl Fn(rug::Float) -> rug::Float,
+    x: f64,
+) {
+    let actual = purify(actual_f(x));
+    let expected = expected_f(rug::Float::with_val(CR_RUG_PREC, x)).to_f64();
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "{name}({x:e}) = {actual:e} (expected {expected:e})",
+    );
+}
+
+fn check2(
+    name: &str,
+    actual_f: impl Fn(f64, f64) -> f64,
+    expected_f: impl Fn(rug::Float, &rug::Float) -> rug::Float,
+    x: f64,
+    y: f64,
+) {
+    let actual = purify(actual_f(x, y));
+    let expected = expected_f(
+        rug::Float::with_val(CR_RUG_PREC, x),
+        &rug::Float::with_val(CR_RUG_PREC, y),
+    )
+    .to_f64();
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "{name}({x:e}, {y:e}) = {actual:e} (expected {expected:e})",
+    );
+}
+
+fn check_pow(x: f64, y: f64) {
+    let actual = purify(fpmath::cr::pow(x, y));
+    let expected = rug::Float::with_val(CR_RUG_PREC, x)
+        .pow(rug::Float::with_val(CR_RUG_PREC, y))
+        .to_f64();
+    assert!(
+        actual.to_bits() == expected.to_bits(),
+        "pow({x:e}, {y:e}) = {actual:e} (expected {expected:e})",
+    );
+}
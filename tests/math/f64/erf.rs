@@ -0,0 +1,72 @@
+use rand::RngExt as _;
+
+use super::{RUG_PREC, calc_error_ulp, mkfloat, select_threshold};
+use crate::create_prng;
+
+#[test]
+fn test_erf() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = rug::Float::with_val(RUG_PREC, x).erf();
+        let actual = fpmath::erf(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "erf({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max erf error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_erfc() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = rug::Float::with_val(RUG_PREC, x).erfc();
+        let actual = fpmath::erfc(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "erfc({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max erfc error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_with(mut f: impl FnMut(f64)) {
+    let mut rng = create_prng();
+
+    for e in -1022..=1023 {
+        for _ in 0..3000 {
+            let m = rng.random::<u64>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    for i in 0..3000 {
+        let x = (i as f64) / 100.0;
+        f(x);
+        f(-x);
+    }
+
+    // subnormals
+    for i in 0..52 {
+        let x = f64::from_bits(1 << i);
+        f(x);
+        f(-x);
+        let x = f64::from_bits((1 << (i + 1)) - 1);
+        f(x);
+        f(-x);
+    }
+}
@@ -0,0 +1,157 @@
+use rand::RngExt as _;
+
+use super::{RUG_PREC, calc_error_ulp, mkfloat, select_threshold};
+use crate::create_prng;
+
+#[test]
+fn test_erfinv() {
+    let mut max_error: f64 = 0.0;
+    test_with_erfinv(|x| {
+        let actual = fpmath::erfinv(x);
+        let expected = erfinv_ref(x, actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "erfinv({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max erfinv error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_erfcinv() {
+    let mut max_error: f64 = 0.0;
+    test_with_erfcinv(|x| {
+        let actual = fpmath::erfcinv(x);
+        let expected = erfcinv_ref(rug::Float::with_val(RUG_PREC, x), actual);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "erfcinv({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max erfcinv error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+/// Calculates `erfinv(x)` by refining the approximation `y0` with
+/// Newton's method.
+pub(crate) fn erfinv_ref(x: f64, y0: f64) -> rug::Float {
+    let prec = RUG_PREC * 2;
+    if x < 0.0 {
+        -erfinv_ref(-x, -y0)
+    } else if x > 0.5 {
+        // erfinv(x) = erfcinv(1 - x), where 1 - x is exact
+        erfcinv_ref(rug::Float::with_val(prec, 1.0 - x), y0)
+    } else {
+        // y = y - (erf(y) - x) / (2 / √π * exp(-y^2))
+        let mut y = rug::Float::with_val(prec, y0);
+        for _ in 0..3 {
+            let f = y.clone().erf() - x;
+            y -= f / erf_deriv(&y);
+        }
+        y
+    }
+}
+
+/// Calculates `erfcinv(x)` by refining the approximation `y0` with
+/// Newton's method.
+pub(crate) fn erfcinv_ref(x: rug::Float, y0: f64) -> rug::Float {
+    let prec = RUG_PREC * 2;
+    if x > 1 {
+        // erfcinv(x) = -erfcinv(2 - x)
+        -erfcinv_ref(2u8 - x, -y0)
+    } else {
+        // y = y + (erfc(y) - x) / (2 / √π * exp(-y^2))
+        let mut y = rug::Float::with_val(prec, y0);
+        for _ in 0..3 {
+            let f = y.clone().erfc() - &x;
+            y += f / erf_deriv(&y);
+        }
+        y
+    }
+}
+
+/// Calculates `2 / √π * exp(-y^2)`
+fn erf_deriv(y: &rug::Float) -> rug::Float {
+    let prec = y.prec();
+    let sqrt_pi = rug::Float::with_val(prec, rug::float::Constant::Pi).sqrt();
+    (-y.clone().square()).exp() * 2u8 / sqrt_pi
+}
+
+fn test_with_erfinv(mut f: impl FnMut(f64)) {
+    let mut rng = create_prng();
+
+    for e in -1022..=-1 {
+        for _ in 0..1000 {
+            let m = rng.random::<u64>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    // close to ±1
+    for e in -53..=-2 {
+        for _ in 0..1000 {
+            let m = rng.random::<u64>();
+            let x = 1.0 - mkfloat(m, e, false);
+            f(x);
+            f(-x);
+        }
+    }
+
+    for i in 1..1000 {
+        let x = (i as f64) / 1000.0;
+        f(x);
+        f(-x);
+    }
+
+    // subnormals
+    for i in 0..52 {
+        let x = f64::from_bits(1 << i);
+        f(x);
+        f(-x);
+        let x = f64::from_bits((1 << (i + 1)) - 1);
+        f(x);
+        f(-x);
+    }
+}
+
+fn test_with_erfcinv(mut f: impl FnMut(f64)) {
+    let mut rng = create_prng();
+
+    for e in -1022..=0 {
+        for _ in 0..1000 {
+            let m = rng.random::<u64>();
+            f(mkfloat(m, e, false));
+        }
+    }
+
+    // close to 2
+    for e in -52..=-2 {
+        for _ in 0..1000 {
+            let m = rng.random::<u64>();
+            f(2.0 - mkfloat(m, e, false));
+        }
+    }
+
+    for i in 1..2000 {
+        let x = (i as f64) / 1000.0;
+        f(x);
+    }
+
+    // subnormals
+    for i in 0..52 {
+        f(f64::from_bits(1 << i));
+        f(f64::from_bits((1 << (i + 1)) - 1));
+    }
+}
@@ -1,6 +1,8 @@
 use rand::RngExt as _;
 
-use super::{RUG_PREC, calc_error_ulp, mkfloat, select_threshold};
+use super::{
+    HI_LO_RUG_PREC, RUG_PREC, calc_error_hi_lo_ulp, calc_error_ulp, mkfloat, select_threshold,
+};
 use crate::create_prng;
 
 #[test]
@@ -38,6 +40,31 @@ fn test_exp() {
     assert!(max_expm1_error > 0.5);
 }
 
+#[test]
+fn test_exp_hi_lo() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = rug::Float::with_val(HI_LO_RUG_PREC, x).exp();
+        let actual = fpmath::exp_hi_lo(x);
+
+        let err = calc_error_hi_lo_ulp(actual, expected);
+
+        // `lo` loses precision close to the subnormal range
+        let threshold = if actual.0 < f64::MIN_POSITIVE / f64::EPSILON {
+            1.0
+        } else {
+            max_error = max_error.max(err);
+            0.07
+        };
+        assert!(
+            err < threshold,
+            "exp_hi_lo({x:e}) = {actual:?} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max exp_hi_lo error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
 #[test]
 fn test_exp2() {
     let mut max_error: f64 = 0.0;
@@ -78,6 +105,46 @@ fn test_exp10() {
     assert!(max_error > 0.5);
 }
 
+#[test]
+fn test_exp2_m1() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = rug::Float::with_val(RUG_PREC, x).exp2_m1();
+        let actual = fpmath::exp2_m1(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "exp2_m1({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max exp2_m1 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_exp10_m1() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = rug::Float::with_val(RUG_PREC, x).exp10_m1();
+        let actual = fpmath::exp10_m1(x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "exp10_m1({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max exp10_m1 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
 fn test_with(mut f: impl FnMut(f64)) {
     let mut rng = create_prng();
 
@@ -0,0 +1,77 @@
+use rand::RngExt as _;
+use rug::float::Round;
+
+use super::mkfloat;
+use crate::create_prng;
+
+#[test]
+fn test_fma() {
+    let mut rng = create_prng();
+
+    for _ in 0..200_000 {
+        let ex = rng.random_range(-1023..=1023);
+        let ey = rng.random_range(-1023..=1023);
+        let ez = (ex + ey + rng.random_range(-60..=60)).clamp(-1023, 1023);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        let z = mkfloat(rng.random(), ez, rng.random());
+        check(x, y, z);
+
+        // cancellation of the highest bits
+        let z = -(x * y);
+        if z.is_finite() {
+            check(x, y, z);
+            check(x, y, f64::from_bits(z.to_bits() + 1));
+        }
+    }
+
+    // results close to or in the subnormal range
+    for _ in 0..100_000 {
+        let ex = rng.random_range(-1023..=-40);
+        let ey = (-1060 - ex + rng.random_range(-20..=20)).clamp(-1023, 1023);
+        let ez = rng.random_range(-1023..=-1000);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        let z = mkfloat(rng.random(), ez, rng.random());
+        check(x, y, z);
+        check(x, y, 0.0);
+        check(x, y, -0.0);
+    }
+
+    let special = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        f64::from_bits(1),
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+    for x in special {
+        for y in special {
+            for z in special {
+                check(x, y, z);
+            }
+        }
+    }
+}
+
+fn check(x: f64, y: f64, z: f64) {
+    let mut expected = rug::Float::with_val(53, x);
+    let dir = expected.mul_add_round(
+        &rug::Float::with_val(53, y),
+        &rug::Float::with_val(53, z),
+        Round::Nearest,
+    );
+    expected.subnormalize_ieee_round(dir, Round::Nearest);
+    let expected = expected.to_f64();
+
+    let actual = fpmath::fma(x, y, z);
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "fma({x:e}, {y:e}, {z:e}) = {actual:e}, expected {expected:e}",
+    );
+}
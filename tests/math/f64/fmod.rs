@@ -0,0 +1,90 @@
+use rand::RngExt as _;
+
+use super::mkfloat;
+use crate::create_prng;
+
+#[test]
+fn test_fmod() {
+    test_with(|x, y| {
+        let expected = (rug::Float::with_val(53, x) % rug::Float::with_val(53, y)).to_f64();
+        let actual = fpmath::fmod(x, y);
+        assert!(
+            same_result(actual, expected),
+            "fmod({x:e}, {y:e}) = {actual:e}, expected {expected:e}",
+        );
+    });
+}
+
+#[test]
+fn test_remainder() {
+    test_with(|x, y| {
+        let (expected, expected_quo) =
+            rug::Float::with_val(53, x).remainder_quo31(&rug::Float::with_val(53, y));
+        let expected = expected.to_f64();
+
+        let actual = fpmath::remainder(x, y);
+        assert!(
+            same_result(actual, expected),
+            "remainder({x:e}, {y:e}) = {actual:e}, expected {expected:e}",
+        );
+
+        let (actual, actual_quo) = fpmath::remquo(x, y);
+        assert!(
+            same_result(actual, expected) && (actual_quo == expected_quo || expected.is_nan()),
+            "remquo({x:e}, {y:e}) = ({actual:e}, {actual_quo}), expected ({expected:e}, {expected_quo})",
+        );
+    });
+}
+
+fn same_result(actual: f64, expected: f64) -> bool {
+    actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan())
+}
+
+fn test_with(mut f: impl FnMut(f64, f64)) {
+    let mut rng = create_prng();
+
+    for _ in 0..20000 {
+        let ex = rng.random_range(-1023..=1023);
+        let ey = rng.random_range(-1023..=1023);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        f(x, y);
+    }
+
+    // close exponents
+    for _ in 0..20000 {
+        let ey = rng.random_range(-1023..=980);
+        let ex = ey + rng.random_range(-2..=40);
+        let x = mkfloat(rng.random(), ex, rng.random());
+        let y = mkfloat(rng.random(), ey, rng.random());
+        f(x, y);
+    }
+
+    // halfway cases
+    for _ in 0..20000 {
+        let ey = rng.random_range(-1000..=1000);
+        let n = rng.random_range(0..256);
+        let y = mkfloat(0, ey, rng.random());
+        let x = (f64::from(n) + 0.5) * y;
+        f(x, y);
+        f(-x, y);
+    }
+
+    let special = [
+        0.0,
+        -0.0,
+        1.0,
+        -1.0,
+        f64::from_bits(1),
+        f64::MIN_POSITIVE,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ];
+    for x in special {
+        for y in special {
+            f(x, y);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use rand::RngExt as _;
 
-use super::{RUG_PREC, calc_error_ulp, mkfloat};
+use super::{RUG_PREC, calc_error_ulp, mkfloat, select_threshold};
 use crate::create_prng;
 
 #[test]
@@ -56,6 +56,229 @@ fn test_ln_gamma() {
     assert!(max_error > 0.5);
 }
 
+#[test]
+fn test_gamma_p() {
+    let mut max_error: f64 = 0.0;
+    test_with_inc(|a, x| {
+        let (expected, _) = inc_gamma_ref(a, x);
+        let actual = fpmath::gamma_p(a, x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 1.9, 2.9);
+        assert!(
+            err < threshold,
+            "gamma_p({a:e}, {x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max gamma_p error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_gamma_q() {
+    let mut max_error: f64 = 0.0;
+    test_with_inc(|a, x| {
+        let (_, expected) = inc_gamma_ref(a, x);
+        let actual = fpmath::gamma_q(a, x);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 1.9, 2.9);
+        assert!(
+            err < threshold,
+            "gamma_q({a:e}, {x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max gamma_q error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_gamma_p_inv() {
+    let mut max_error: f64 = 0.0;
+    test_with_inc(|a, x| {
+        let p = fpmath::gamma_p(a, x);
+        // The accuracy is not guaranteed when `p` is subnormal
+        if p == 0.0 || p == 1.0 || p.is_subnormal() {
+            return;
+        }
+
+        let actual = fpmath::gamma_p_inv(a, p);
+        let expected = inc_gamma_inv_ref(a, p, actual, false);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "gamma_p_inv({a:e}, {p:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max gamma_p_inv error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_gamma_q_inv() {
+    let mut max_error: f64 = 0.0;
+    test_with_inc(|a, x| {
+        let q = fpmath::gamma_q(a, x);
+        // The accuracy is not guaranteed when `q` is subnormal
+        if q == 0.0 || q == 1.0 || q.is_subnormal() {
+            return;
+        }
+
+        let actual = fpmath::gamma_q_inv(a, q);
+        let expected = inc_gamma_inv_ref(a, q, actual, true);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "gamma_q_inv({a:e}, {q:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max gamma_q_inv error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+/// Calculates the working precision needed to compensate the cancellation
+/// in the exponent of `x^a * e^-x / Γ(a)` and in `1 - P(a, x)` when `a`
+/// is small.
+fn inc_gamma_ref_prec(a: f64, x: f64) -> u32 {
+    let exp = |x: f64| rug::Float::with_val(53, x).get_exp().unwrap();
+    RUG_PREC + 64 + exp(a).max(exp(x)).max(0).unsigned_abs() + exp(a).min(0).unsigned_abs()
+}
+
+/// Calculates `P(a, x)` and `Q(a, x)`.
+pub(crate) fn inc_gamma_ref(a: f64, x: f64) -> (rug::Float, rug::Float) {
+    let prec = inc_gamma_ref_prec(a, x);
+    inc_gamma_ref_with(
+        &rug::Float::with_val(prec, a),
+        &rug::Float::with_val(prec, x),
+    )
+}
+
+/// Calculates `P(a, x)` and `Q(a, x)` with the precision of `a`, using the
+/// series of `P(a, x)` when `x < a + 1` and the continued fraction of
+/// `Q(a, x)` otherwise. `x` must be positive.
+fn inc_gamma_ref_with(a: &rug::Float, x: &rug::Float) -> (rug::Float, rug::Float) {
+    let prec = a.prec();
+    let ln_x = x.clone().ln();
+
+    if *x < a.clone() + 1u32 {
+        // P(a, x) = x^a * e^-x / Γ(a + 1) * (1 + x / (a + 1) + x^2 / ((a + 1) * (a + 2)) + ...)
+        let mut sum = rug::Float::with_val(prec, 1);
+        let mut term = rug::Float::with_val(prec, 1);
+        let mut n = a.clone();
+        loop {
+            n += 1u32;
+            term *= x;
+            term /= &n;
+            sum += &term;
+            if term.get_exp().unwrap() < sum.get_exp().unwrap() - prec as i32 {
+                break;
+            }
+        }
+
+        let ln_gamma_a1 = (a.clone() + 1u32).ln_gamma();
+        let pre = (a.clone() * ln_x - x - ln_gamma_a1).exp();
+        let p = pre * sum;
+        let q = rug::Float::with_val(prec, 1) - &p;
+        (p, q)
+    } else {
+        // Q(a, x) = x^a * e^-x / Γ(a) / (x + 1 - a - 1 * (1 - a) / (x + 3 - a - 2 * (2 - a) / ...)),
+        // evaluated with the modified Lentz's method
+        let b0 = rug::Float::with_val(prec, x - a) + 1u32;
+        let mut f = b0.clone();
+        let mut c = b0;
+        let mut d = rug::Float::new(prec);
+        let mut n = 0u32;
+        loop {
+            n += 1;
+            let an = (a.clone() - n) * n;
+            let bn = rug::Float::with_val(prec, x - a) + (2 * n + 1);
+            d = (d * &an + &bn).recip();
+            c = an / c + bn;
+            let delta = rug::Float::with_val(prec, &c * &d);
+            f *= &delta;
+            let err = delta - 1u32;
+            if err.is_zero() || err.get_exp().unwrap() < -(prec as i32) {
+                break;
+            }
+        }
+
+        let ln_gamma_a = a.clone().ln_gamma();
+        let pre = (a.clone() * ln_x - x - ln_gamma_a).exp();
+        let q = pre / f;
+        let p = rug::Float::with_val(prec, 1) - &q;
+        (p, q)
+    }
+}
+
+/// Calculates the inverse of `P(a, x)` (or `Q(a, x)` when `upper` is true)
+/// by refining the approximation `x0` with Newton's method.
+pub(crate) fn inc_gamma_inv_ref(a: f64, p: f64, x0: f64, upper: bool) -> rug::Float {
+    let prec = inc_gamma_ref_prec(a, x0) + RUG_PREC;
+    let a = rug::Float::with_val(prec, a);
+    let ln_gamma_a = a.clone().ln_gamma();
+
+    let mut x = rug::Float::with_val(prec, x0);
+    for _ in 0..4 {
+        let (p_x, q_x) = inc_gamma_ref_with(&a, &x);
+        let f = if upper { p - q_x } else { p_x - p };
+        // dP/dx = x^(a - 1) * e^-x / Γ(a)
+        let deriv = ((a.clone() - 1u32) * x.clone().ln() - &x - &ln_gamma_a).exp();
+        x -= f / deriv;
+    }
+    x
+}
+
+fn test_with_inc(mut f: impl FnMut(f64, f64)) {
+    let mut rng = create_prng();
+
+    for ea in (-30..=20).step_by(2) {
+        for ex in (-40..=24).step_by(2) {
+            for _ in 0..5 {
+                let a = mkfloat(rng.random::<u64>(), ea, false);
+                let x = mkfloat(rng.random::<u64>(), ex, false);
+                f(a, x);
+            }
+        }
+    }
+
+    for i in 1..=100 {
+        for j in 1..=100 {
+            let a = (i as f64) / 10.0;
+            let x = (j as f64) / 5.0;
+            f(a, x);
+        }
+    }
+
+    // Far below the mean, where `P` is so small that the Wilson-Hilferty
+    // approximation of the inverse is close to zero
+    for i in 1..=100 {
+        for j in 1..=100 {
+            let a = (i as f64) / 4.0;
+            let x = a * (j as f64) / 400.0;
+            f(a, x);
+        }
+    }
+
+    // Close to the mean, where `P` and `Q` are not small
+    for _ in 0..2000 {
+        let a = rng.random_range(1.0..100000.0f64);
+        let x = a * rng.random_range(0.5..1.5f64);
+        f(a, x);
+    }
+}
+
 fn test_with(mut f: impl FnMut(f64)) {
     let mut rng = create_prng();
 
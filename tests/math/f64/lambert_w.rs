@@ -0,0 +1,165 @@
+use rand::RngExt as _;
+
+use super::{RUG_PREC, calc_error_ulp, mkfloat};
+use crate::create_prng;
+
+/// Largest `f64` that is not greater than `1/e`
+const FRAC_1_E: f64 = f64::from_bits(0x3FD78B56362CEF37);
+
+#[test]
+fn test_lambert_w0() {
+    let mut max_error: f64 = 0.0;
+    test_with_w0(|x| {
+        let actual = fpmath::lambert_w0(x);
+        let expected = lambert_w_ref(x, actual);
+        assert!(
+            expected >= -1,
+            "lambert_w0({x:e}) = {actual:e} (wrong branch)"
+        );
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "lambert_w0({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max lambert_w0 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_lambert_wm1() {
+    let mut max_error: f64 = 0.0;
+    test_with_wm1(|x| {
+        let actual = fpmath::lambert_wm1(x);
+        let expected = lambert_w_ref(x, actual);
+        assert!(
+            expected <= -1,
+            "lambert_wm1({x:e}) = {actual:e} (wrong branch)"
+        );
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "lambert_wm1({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max lambert_wm1 error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_special() {
+    assert!(fpmath::lambert_w0(f64::NAN).is_nan());
+    assert!(fpmath::lambert_w0(f64::NEG_INFINITY).is_nan());
+    assert!(fpmath::lambert_w0(-f64::from_bits(FRAC_1_E.to_bits() + 1)).is_nan());
+    assert_eq!(fpmath::lambert_w0(f64::INFINITY), f64::INFINITY);
+    assert!(fpmath::lambert_wm1(f64::NAN).is_nan());
+    assert!(fpmath::lambert_wm1(f64::NEG_INFINITY).is_nan());
+    assert!(fpmath::lambert_wm1(-f64::from_bits(FRAC_1_E.to_bits() + 1)).is_nan());
+    assert!(fpmath::lambert_wm1(f64::MIN_POSITIVE).is_nan());
+    assert_eq!(fpmath::lambert_wm1(0.0), f64::NEG_INFINITY);
+}
+
+/// Calculates `W(x)` by refining the approximation `w0` with Newton's
+/// method, which converges to the root on the same branch as `w0`.
+pub(crate) fn lambert_w_ref(x: f64, w0: f64) -> rug::Float {
+    let prec = RUG_PREC * 2;
+    // w = w - (w * e^w - x) / (e^w * (1 + w))
+    let mut w = rug::Float::with_val(prec, w0);
+    for _ in 0..5 {
+        let exp_w = w.clone().exp();
+        let f = w.clone() * &exp_w - x;
+        w -= f / (exp_w * (w.clone() + 1u8));
+    }
+    w
+}
+
+fn test_with_w0(mut f: impl FnMut(f64)) {
+    let mut rng = create_prng();
+
+    for e in -1022..=1023 {
+        for _ in 0..1000 {
+            let m = rng.random::<u64>();
+            f(mkfloat(m, e, false));
+        }
+    }
+
+    // -1/e < x < 0
+    for e in -1022..=-2 {
+        for _ in 0..1000 {
+            let m = rng.random::<u64>();
+            let x = mkfloat(m, e, true);
+            if x >= -FRAC_1_E {
+                f(x);
+            }
+        }
+    }
+
+    test_with_branch_point(&mut f);
+
+    for i in 1..=10000 {
+        f(i as f64 / 1000.0);
+    }
+
+    f(f64::MIN_POSITIVE);
+    f(-f64::MIN_POSITIVE);
+    f(f64::MAX);
+
+    // subnormals
+    for i in 0..52 {
+        f(f64::from_bits(1 << i));
+        f(-f64::from_bits(1 << i));
+        f(f64::from_bits((1 << (i + 1)) - 1));
+        f(-f64::from_bits((1 << (i + 1)) - 1));
+    }
+}
+
+fn test_with_wm1(mut f: impl FnMut(f64)) {
+    let mut rng = create_prng();
+
+    // -1/e < x < 0
+    for e in -1022..=-2 {
+        for _ in 0..2000 {
+            let m = rng.random::<u64>();
+            let x = mkfloat(m, e, true);
+            if x >= -FRAC_1_E {
+                f(x);
+            }
+        }
+    }
+
+    test_with_branch_point(&mut f);
+
+    for i in 1..=3678 {
+        f(-(i as f64) / 10000.0);
+    }
+
+    f(-f64::MIN_POSITIVE);
+
+    // subnormals
+    for i in 0..52 {
+        f(-f64::from_bits(1 << i));
+        f(-f64::from_bits((1 << (i + 1)) - 1));
+    }
+}
+
+/// Calls `f` with values close to the branch point `-1/e`
+fn test_with_branch_point(mut f: impl FnMut(f64)) {
+    let mut rng = create_prng();
+
+    for i in 0..10000 {
+        f(-f64::from_bits(FRAC_1_E.to_bits() - i));
+    }
+
+    for e in -54..=-3 {
+        for _ in 0..1000 {
+            let m = rng.random::<u64>();
+            f(-FRAC_1_E + mkfloat(m, e, false));
+        }
+    }
+}
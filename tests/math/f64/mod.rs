@@ -1,15 +1,31 @@
+mod bessel;
+pub(crate) mod beta;
 mod cbrt;
+mod complex;
+mod cr;
+mod double_float;
+mod erf;
+pub(crate) mod erfinv;
 mod exp;
-mod gamma;
+mod fma;
+mod fmod;
+pub(crate) mod gamma;
 mod hyperbolic;
 mod hypot;
 mod inv_hyperbolic;
 mod inv_trigonometric;
+pub(crate) mod lambert_w;
 mod log;
+pub(crate) mod polygamma;
 mod pow;
 mod round;
+mod simd;
+mod slice;
 mod sqrt;
 mod trigonometric;
+#[cfg(all(target_arch = "x86_64", target_feature = "sse2"))]
+mod x86_64;
+pub(crate) mod zeta;
 
 fn mkfloat(m: u64, e: i16, s: bool) -> f64 {
     let m = m >> (64 - 52);
@@ -27,6 +43,7 @@ fn select_threshold(actual: f64, normal_th: f64, subnormal_th: f64) -> f64 {
 }
 
 const RUG_PREC: u32 = 53 + 20;
+const HI_LO_RUG_PREC: u32 = 53 * 2 + 20;
 
 fn calc_error_ulp(actual: f64, expected: rug::Float) -> f64 {
     let actual = purify(actual);
@@ -67,6 +84,30 @@ fn calc_error_ulp(actual: f64, expected: rug::Float) -> f64 {
     }
 }
 
+/// Calculates the error of `hi + lo` in ULP of `hi`
+///
+/// `(hi, lo)` must be normalized.
+fn calc_error_hi_lo_ulp((hi, lo): (f64, f64), expected: rug::Float) -> f64 {
+    let (hi, lo) = purify2((hi, lo));
+
+    if !hi.is_finite() || hi == 0.0 {
+        if lo == 0.0 {
+            calc_error_ulp(hi, expected)
+        } else {
+            f64::INFINITY
+        }
+    } else {
+        assert_eq!(purify(hi + lo), hi, "({hi:e}, {lo:e}) is not normalized");
+        let exp = expected
+            .get_exp()
+            .map(|e| (e - 1).max(-1022))
+            .unwrap_or(-1022);
+        let actual = rug::Float::with_val(HI_LO_RUG_PREC, hi) + lo;
+        let dif = (expected - actual).abs() >> (exp - 52);
+        dif.to_f64()
+    }
+}
+
 // Workaround X87 compiler bugs
 fn purify(x: f64) -> f64 {
     std::hint::black_box(x)
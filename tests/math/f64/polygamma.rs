@@ -0,0 +1,239 @@
+use std::cell::RefCell;
+
+use rand::RngExt as _;
+use rug::ops::Pow as _;
+
+use super::{RUG_PREC, calc_error_ulp, mkfloat, purify, select_threshold};
+use crate::create_prng;
+
+#[test]
+fn test_digamma() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let expected = rug::Float::with_val(RUG_PREC, x).digamma();
+        let actual = fpmath::digamma(x);
+
+        let err = calc_error_ulp(actual, expected.clone());
+        max_error = max_error.max(err);
+
+        if x > 0.0 {
+            let threshold = select_threshold(actual, 0.9, 1.9);
+            assert!(
+                err < threshold,
+                "digamma({x:e}) = {actual:e} (error = {err} ULP)",
+            );
+        } else {
+            // ψ(x) = ψ(1 - x) - π / tan(πx), which has cancellation near
+            // the zeros of the function
+            let scale = (-(rug::Float::with_val(RUG_PREC, x) - 1u8)).digamma();
+            assert!(
+                err < 1.9 || calc_error_scaled(actual, expected, scale) < 1.0 / 16.0,
+                "digamma({x:e}) = {actual:e} (error = {err} ULP)",
+            );
+        }
+    });
+    eprintln!("max digamma error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_polygamma() {
+    let mut max_error: f64 = 0.0;
+    for n in [1, 2, 3, 4, 7, 20] {
+        test_with_short(|x| {
+            let expected = polygamma_ref(n, x);
+            let actual = fpmath::polygamma(n, x);
+
+            let err = calc_error_ulp(actual, expected.clone());
+            max_error = max_error.max(err);
+
+            if x > 0.0 || n % 2 == 1 {
+                let threshold = select_threshold(actual, 0.9, 1.9);
+                assert!(
+                    err < threshold,
+                    "polygamma({n}, {x:e}) = {actual:e} (error = {err} ULP)",
+                );
+            } else {
+                // Terms with opposite signs can cancel near the zeros of
+                // the function when `n` is even
+                let scale = polygamma_ref(n, 1.0 - x.fract().abs()).abs()
+                    + polygamma_ref(n, x.fract().abs()).abs();
+                assert!(
+                    err < 1.9 || calc_error_scaled(actual, expected, scale) < 1.0 / 16.0,
+                    "polygamma({n}, {x:e}) = {actual:e} (error = {err} ULP)",
+                );
+            }
+        });
+    }
+    eprintln!("max polygamma error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+/// Calculates the polygamma function of order `n >= 1` as
+/// `(-1)^(n + 1) * n! * ζ(n + 1, x)`, where `ζ` is the Hurwitz zeta function
+pub(crate) fn polygamma_ref(n: i32, x: f64) -> rug::Float {
+    use rug::float::Special;
+
+    if x == f64::INFINITY {
+        // ψ^(n)(x) ~= (-1)^(n + 1) * (n - 1)! / x^n for large x
+        let zero = if n % 2 == 1 {
+            Special::Zero
+        } else {
+            Special::NegZero
+        };
+        return rug::Float::with_val(RUG_PREC, zero);
+    }
+    if x == 0.0 {
+        // ψ^(n)(x) ~= (-1)^(n + 1) * n! / x^(n + 1) near zero
+        let inf = if n % 2 == 0 && x.is_sign_positive() {
+            Special::NegInfinity
+        } else {
+            Special::Infinity
+        };
+        return rug::Float::with_val(RUG_PREC, inf);
+    }
+    if x == f64::NEG_INFINITY {
+        return rug::Float::with_val(RUG_PREC, Special::Nan);
+    }
+    if x < 0.0 && x.fract() == 0.0 {
+        return if n % 2 == 1 {
+            rug::Float::with_val(RUG_PREC, Special::Infinity)
+        } else {
+            rug::Float::with_val(RUG_PREC, Special::Nan)
+        };
+    }
+
+    let s = n as u32 + 1;
+    let fact = rug::Float::with_val(RUG_PREC, rug::Float::factorial(n as u32));
+    let zeta = hurwitz_zeta(s, x);
+    if n % 2 == 1 {
+        fact * zeta
+    } else {
+        -(fact * zeta)
+    }
+}
+
+/// Calculates the Hurwitz zeta function `ζ(s, x)` by summing terms until
+/// reaching the range of the Euler-Maclaurin summation formula
+fn hurwitz_zeta(s: u32, x: f64) -> rug::Float {
+    // Extra precision to account for cancellation with negative `x`, where
+    // the terms can be as large as `δ^-s` (`δ` is the distance from `x` to
+    // the nearest integer) and the result as small as `(1 - x)^-s`
+    let extra = if x < 0.0 {
+        let exp = |x: f64| rug::Float::with_val(53, x).get_exp().unwrap();
+        let delta = (x - x.round()).abs();
+        s * (exp(1.0 - x) - exp(delta)).unsigned_abs()
+    } else {
+        0
+    };
+    let prec = RUG_PREC + 64 + extra;
+    let threshold = f64::from(s) + 64.0;
+
+    let mut a = rug::Float::with_val(prec, x);
+    let mut sum = rug::Float::new(prec);
+    while a < threshold {
+        sum += a.clone().recip().pow(s);
+        a += 1u8;
+    }
+
+    // ζ(s, a) ~= a^(1 - s) / (s - 1) + a^(-s) / 2
+    //   + sum(B_2j / (2j)! * s * ... * (s + 2j - 2) * a^(1 - s - 2j))
+    let a_inv = a.clone().recip();
+    let a_pow = a_inv.clone().pow(s);
+    sum += a.clone() * &a_pow / (s - 1);
+    sum += a_pow.clone() / 2u8;
+
+    let a_inv_sq = a_inv.clone().square();
+    let mut f = a_pow * a_inv * s;
+    for (j, b) in (1..).zip(em_coeffs(prec)) {
+        sum += &b * &f;
+        f *= (s + 2 * j - 1) * (s + 2 * j);
+        f *= &a_inv_sq;
+    }
+
+    sum
+}
+
+/// Returns the coefficients `B_2j / (2j)!` of the Euler-Maclaurin summation
+/// formula for `j = 1..=40` with at least precision `prec`
+///
+/// They are cached, since calculating them dominates the cost of
+/// [`hurwitz_zeta`].
+pub(crate) fn em_coeffs(prec: u32) -> Vec<rug::Float> {
+    thread_local! {
+        static CACHE: RefCell<Vec<rug::Float>> = const { RefCell::new(Vec::new()) };
+    }
+
+    CACHE.with_borrow_mut(|cache| {
+        if cache.first().is_none_or(|c| c.prec() < prec) {
+            let two_pi = rug::Float::with_val(prec, rug::float::Constant::Pi) * 2u8;
+            let two_pi_sq = two_pi.square();
+            let mut two_pi_pow = rug::Float::with_val(prec, 1u8);
+            *cache = (1..=40u32)
+                .map(|j| {
+                    // B_2j / (2j)! = (-1)^(j + 1) * 2 * ζ(2j) / (2π)^(2j)
+                    two_pi_pow *= &two_pi_sq;
+                    let zeta = rug::Float::with_val(prec, rug::Float::zeta_u(2 * j));
+                    let b = zeta * 2u8 / &two_pi_pow;
+                    if j % 2 == 1 { b } else { -b }
+                })
+                .collect();
+        }
+        cache.clone()
+    })
+}
+
+/// Calculates the error relative to `scale`, in units of `f64::EPSILON`.
+fn calc_error_scaled(actual: f64, expected: rug::Float, scale: rug::Float) -> f64 {
+    let dif = (expected - purify(actual)).abs();
+    (dif / scale).to_f64() / f64::EPSILON
+}
+
+fn test_with(mut f: impl FnMut(f64)) {
+    let mut rng = create_prng();
+
+    for e in -1022..=1023 {
+        for _ in 0..1000 {
+            let m = rng.random::<u64>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    for i in 0..5000 {
+        let x = (i as f64) / 100.0;
+        f(x);
+        f(-x);
+    }
+
+    // subnormals
+    for i in 0..52 {
+        let x = f64::from_bits(1 << i);
+        f(x);
+        f(-x);
+        let x = f64::from_bits((1 << (i + 1)) - 1);
+        f(x);
+        f(-x);
+    }
+}
+
+fn test_with_short(mut f: impl FnMut(f64)) {
+    let mut rng = create_prng();
+
+    for e in -30..=30 {
+        for _ in 0..200 {
+            let m = rng.random::<u64>();
+            f(mkfloat(m, e, false));
+            // The reference sums terms one by one for negative values
+            if e < 10 {
+                f(mkfloat(m, e, true));
+            }
+        }
+    }
+
+    for i in 0..2000 {
+        let x = (i as f64) / 100.0;
+        f(x);
+        f(-x);
+    }
+}
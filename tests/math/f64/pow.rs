@@ -127,3 +127,228 @@ fn test_powi_with(mut f: impl FnMut(f64, i32)) {
         }
     }
 }
+
+#[test]
+fn test_powr() {
+    let mut max_error: f64 = 0.0;
+    test_pow_with(|x, y| {
+        let bigx = rug::Float::with_val(53, x);
+        let bigy = rug::Float::with_val(53, y);
+        let expected = rug::Float::with_val(RUG_PREC, rug::ops::Pow::pow(&bigx, &bigy));
+        let actual = fpmath::powr(x, y);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "powr({x:e}, {y:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max powr error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_pown() {
+    let mut max_error: f64 = 0.0;
+    test_pown_with(|x, y| {
+        let bigx = rug::Float::with_val(53, x);
+        let expected = rug::Float::with_val(RUG_PREC, rug::ops::Pow::pow(&bigx, y));
+        let actual = fpmath::pown(x, y);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "pown({x:e}, {y}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max pown error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_pown_with(mut f: impl FnMut(f64, i64)) {
+    let mut rng = create_prng();
+
+    test_powi_with(|x, y| f(x, i64::from(y)));
+
+    // exponents that do not fit in `i32`
+    for ex in -51..=-1 {
+        for i in (1..=31).rev() {
+            for _ in 0..100 {
+                let mx = rng.random::<u64>();
+                let sx = rng.random::<bool>();
+                let x = 1.0 + mkfloat(mx, ex, sx);
+                let y = ((rng.random::<u64>() | 0x8000_0000_0000_0000) >> i) as i64;
+
+                f(x, y);
+                f(x, -y);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_rootn() {
+    let mut max_error: f64 = 0.0;
+    test_rootn_with(|x, n| {
+        let bigx = rug::Float::with_val(53, x);
+        let expected = if let Ok(n) = i32::try_from(n) {
+            rug::Float::with_val(RUG_PREC, bigx.root_i_ref(n))
+        } else if x < 0.0 && n % 2 == 0 {
+            rug::Float::with_val(RUG_PREC, f64::NAN)
+        } else {
+            // |x|^(1/n), where the error of 1/n is negligible
+            let inv_n = rug::Float::with_val(RUG_PREC * 2, n).recip();
+            let absx = rug::Float::with_val(53, x.abs());
+            let r = rug::Float::with_val(RUG_PREC, rug::ops::Pow::pow(&absx, &inv_n));
+            if x < 0.0 { -r } else { r }
+        };
+        let actual = fpmath::rootn(x, n);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "rootn({x:e}, {n}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max rootn error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_rootn_with(mut f: impl FnMut(f64, i64)) {
+    let mut rng = create_prng();
+
+    let ns = [
+        -1,
+        1,
+        2,
+        -2,
+        3,
+        -3,
+        4,
+        5,
+        -5,
+        7,
+        10,
+        17,
+        -100,
+        1001,
+        123456,
+        -(1 << 30) - 1,
+        (1 << 40) + 1,
+        -(1 << 60),
+        i64::MAX,
+    ];
+
+    for e in -1022..=1023 {
+        for _ in 0..10 {
+            let m = rng.random::<u64>();
+            let s = rng.random::<bool>();
+            let x = mkfloat(m, e, s);
+            for &n in ns.iter() {
+                f(x, n);
+            }
+        }
+    }
+
+    // subnormals
+    for i in 0..52 {
+        for &n in ns.iter() {
+            f(f64::from_bits(1 << i), n);
+            f(-f64::from_bits((1 << (i + 1)) - 1), n);
+        }
+    }
+
+    // exact results
+    for x in 1..=100 {
+        let x = f64::from(x);
+        for n in 2..=7 {
+            f(x.powi(n), i64::from(n));
+            f(-x.powi(n), i64::from(n));
+            f(x.powi(n).recip(), -i64::from(n));
+        }
+    }
+}
+
+#[test]
+fn test_compound() {
+    let mut max_error: f64 = 0.0;
+    test_compound_with(|x, n| {
+        let expected = if x < -1.0 {
+            rug::Float::with_val(RUG_PREC, f64::NAN)
+        } else {
+            // 1 + x is exact with this precision
+            let xp1 = rug::Float::with_val(1024 + 1074, x) + 1;
+            rug::Float::with_val(RUG_PREC, rug::ops::Pow::pow(&xp1, n))
+        };
+        let actual = fpmath::compound(x, n);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        let threshold = select_threshold(actual, 0.9, 1.9);
+        assert!(
+            err < threshold,
+            "compound({x:e}, {n}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max compound error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+fn test_compound_with(mut f: impl FnMut(f64, i64)) {
+    let mut rng = create_prng();
+
+    // tiny rates over many periods
+    for ex in -70..=-1 {
+        for i in (1..=63).rev() {
+            for _ in 0..100 {
+                let mx = rng.random::<u64>();
+                let sx = rng.random::<bool>();
+                let x = mkfloat(mx, ex, sx);
+                let n = ((rng.random::<u64>() | 0x8000_0000_0000_0000) >> i) as i64;
+
+                f(x, n);
+                f(x, -n);
+            }
+        }
+    }
+
+    // (1 + x)^n does not overflow or underflow when
+    // |n| <= 1022 / |log2(1 + x)|
+    for ex in -1022..=1023 {
+        for _ in 0..500 {
+            let mx = rng.random::<u64>();
+            let sx = ex < 0 && rng.random::<bool>();
+            let x = mkfloat(mx, ex, sx);
+            let log2_xp1 = fpmath::log2_1p(x).abs();
+            let max_n = (1022.0 / log2_xp1).min(1e18) as i64 + 2;
+            let n = rng.random_range(-max_n..=max_n);
+
+            f(x, n);
+        }
+    }
+
+    // special cases
+    for x in [
+        -2.0,
+        -1.0,
+        -0.0,
+        0.0,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+        f64::NAN,
+    ] {
+        for n in [i64::MIN, -3, -2, -1, 0, 1, 2, 3, i64::MAX] {
+            f(x, n);
+        }
+    }
+}
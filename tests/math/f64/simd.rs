@@ -0,0 +1,421 @@
+use fpmath::simd::{F64x2, F64x4};
+use rand::RngExt as _;
+
+use super::mkfloat;
+use crate::create_prng;
+
+const SPECIAL: [f64; 10] = [
+    0.0,
+    -0.0,
+    f64::INFINITY,
+    f64::NEG_INFINITY,
+    f64::NAN,
+    f64::MIN_POSITIVE / 3.0,
+    -1.0,
+    1.0,
+    -2.0,
+    1.0e300,
+];
+
+#[test]
+fn test_exp() {
+    let x = gen_args(-30..=10);
+    check1("exp", |x| F64x2::from(x).exp().into(), fpmath::exp, &x);
+    check1("exp", |x| F64x4::from(x).exp().into(), fpmath::exp, &x);
+}
+
+#[test]
+fn test_ln() {
+    let x = gen_args(-1022..=1023);
+    check1("ln", |x| F64x2::from(x).ln().into(), fpmath::ln, &x);
+    check1("ln", |x| F64x4::from(x).ln().into(), fpmath::ln, &x);
+}
+
+#[test]
+fn test_sin_cos() {
+    let x = gen_args(-30..=30);
+    check1("sin", |x| F64x2::from(x).sin().into(), fpmath::sin, &x);
+    check1("sin", |x| F64x4::from(x).sin().into(), fpmath::sin, &x);
+    check1("cos", |x| F64x2::from(x).cos().into(), fpmath::cos, &x);
+    check1("cos", |x| F64x4::from(x).cos().into(), fpmath::cos, &x);
+
+    for x in x.chunks_exact(4) {
+        let (sin, cos) = F64x4::from_array(x.try_into().unwrap()).sin_cos();
+        for ((&x, sin), cos) in x.iter().zip(sin.to_array()).zip(cos.to_array()) {
+            let (expected_sin, expected_cos) = fpmath::sin_cos(x);
+            assert_same("sin_cos", &[x], sin, expected_sin);
+            assert_same("sin_cos", &[x], cos, expected_cos);
+        }
+    }
+}
+
+#[test]
+fn test_atan2() {
+    let y = gen_args(-1022..=1023);
+    let x = gen_args(-1022..=1023);
+    check2(
+        "atan2",
+        |y, x| F64x2::from(y).atan2(x.into()).into(),
+        fpmath::atan2,
+        &y,
+        &x,
+    );
+    check2(
+        "atan2",
+        |y, x| F64x4::from(y).atan2(x.into()).into(),
+        fpmath::atan2,
+        &y,
+        &x,
+    );
+
+    // Close exponents
+    let y = gen_args(-10..=10);
+    let x = gen_args(-10..=10);
+    check2(
+        "atan2",
+        |y, x| F64x2::from(y).atan2(x.into()).into(),
+        fpmath::atan2,
+        &y,
+        &x,
+    );
+    check2(
+        "atan2",
+        |y, x| F64x4::from(y).atan2(x.into()).into(),
+        fpmath::atan2,
+        &y,
+        &x,
+    );
+}
+
+#[test]
+fn test_pow() {
+    let x = gen_args(-30..=30);
+    let y = gen_args(-10..=10);
+    check2(
+        "pow",
+        |x, y| F64x2::from(x).pow(y.into()).into(),
+        fpmath::pow,
+        &x,
+        &y,
+    );
+    check2(
+        "pow",
+        |x, y| F64x4::from(x).pow(y.into()).into(),
+        fpmath::pow,
+        &x,
+        &y,
+    );
+
+    // Integer exponents
+    let y: Vec<_> = y.iter().map(|y| y.round()).collect();
+    check2(
+        "pow",
+        |x, y| F64x2::from(x).pow(y.into()).into(),
+        fpmath::pow,
+        &x,
+        &y,
+    );
+    check2(
+        "pow",
+        |x, y| F64x4::from(x).pow(y.into()).into(),
+        fpmath::pow,
+        &x,
+        &y,
+    );
+}
+
+#[test]
+fn test_lane_wise() {
+    // some of these functions are slow, so fewer arguments are tested
+    let x = &gen_args(-10..=10)[..1024];
+    let y = &gen_args(-10..=10)[1024..2048];
+    let z = &gen_args(-10..=10)[2048..3072];
+    macro_rules! check1 {
+        ($($f:ident),*) => {
+            $(
+                check1(stringify!($f), |x| F64x2::from(x).$f().into(), fpmath::$f, x);
+                check1(stringify!($f), |x| F64x4::from(x).$f().into(), fpmath::$f, x);
+            )*
+        };
+    }
+    macro_rules! check2 {
+        ($($f:ident),*) => {
+            $(
+                check2(
+                    stringify!($f),
+                    |x, y| F64x2::from(x).$f(y.into()).into(),
+                    fpmath::$f,
+                    x,
+                    y,
+                );
+                check2(
+                    stringify!($f),
+                    |x, y| F64x4::from(x).$f(y.into()).into(),
+                    fpmath::$f,
+                    x,
+                    y,
+                );
+            )*
+        };
+    }
+
+    check1!(
+        abs,
+        round,
+        trunc,
+        ceil,
+        floor,
+        sqrt,
+        rsqrt,
+        cbrt,
+        exp_m1,
+        exp2,
+        exp2_m1,
+        exp10,
+        exp10_m1,
+        ln_1p,
+        log2,
+        log2_1p,
+        log10,
+        log10_1p,
+        tan,
+        wrap_angle,
+        sind,
+        cosd,
+        tand,
+        sinpi,
+        cospi,
+        tanpi,
+        asin,
+        acos,
+        atan,
+        asind,
+        acosd,
+        atand,
+        asinpi,
+        acospi,
+        atanpi,
+        sinh,
+        cosh,
+        tanh,
+        asinh,
+        acosh,
+        atanh,
+        gamma,
+        digamma,
+        zeta,
+        erf,
+        erfc,
+        erfinv,
+        erfcinv,
+        j0,
+        j1,
+        y0,
+        y1,
+        lambert_w0,
+        lambert_wm1
+    );
+    check2!(
+        copysign,
+        fmod,
+        remainder,
+        hypot,
+        powr,
+        atan2d,
+        atan2pi,
+        gamma_p,
+        gamma_q,
+        gamma_p_inv,
+        gamma_q_inv,
+        hurwitz_zeta,
+        beta
+    );
+
+    macro_rules! check_pair {
+        ($($f:ident),*) => {
+            $(
+                for x in x.chunks_exact(4) {
+                    let (a, b) = F64x4::from_array(x.try_into().unwrap()).$f();
+                    for ((&x, a), b) in x.iter().zip(a.to_array()).zip(b.to_array()) {
+                        let (expected_a, expected_b) = fpmath::$f(x);
+                        assert_same(stringify!($f), &[x], a, expected_a);
+                        assert_same(stringify!($f), &[x], b, expected_b);
+                    }
+                }
+            )*
+        };
+    }
+    check_pair!(
+        exp_hi_lo,
+        ln_hi_lo,
+        ln_1p_hi_lo,
+        sind_cosd,
+        sinpi_cospi,
+        sinh_cosh
+    );
+
+    check1(
+        "scalbn",
+        |x| F64x4::from(x).scalbn(-3).into(),
+        |x| fpmath::scalbn(x, -3),
+        x,
+    );
+    check1(
+        "powi",
+        |x| F64x4::from(x).powi(5).into(),
+        |x| fpmath::powi(x, 5),
+        x,
+    );
+    check1(
+        "pown",
+        |x| F64x4::from(x).pown(-4).into(),
+        |x| fpmath::pown(x, -4),
+        x,
+    );
+    check1(
+        "rootn",
+        |x| F64x4::from(x).rootn(3).into(),
+        |x| fpmath::rootn(x, 3),
+        x,
+    );
+    check1(
+        "compound",
+        |x| F64x4::from(x).compound(7).into(),
+        |x| fpmath::compound(x, 7),
+        x,
+    );
+    check1(
+        "polygamma",
+        |x| F64x4::from(x).polygamma(2).into(),
+        |x| fpmath::polygamma(2, x),
+        x,
+    );
+    check1(
+        "jn",
+        |x| F64x4::from(x).jn(3).into(),
+        |x| fpmath::jn(3, x),
+        x,
+    );
+    check1(
+        "yn",
+        |x| F64x4::from(x).yn(3).into(),
+        |x| fpmath::yn(3, x),
+        x,
+    );
+
+    for ((x, y), z) in x
+        .chunks_exact(4)
+        .zip(y.chunks_exact(4))
+        .zip(z.chunks_exact(4))
+    {
+        let vx = F64x4::from_array(x.try_into().unwrap());
+        let vy = F64x4::from_array(y.try_into().unwrap());
+        let vz = F64x4::from_array(z.try_into().unwrap());
+
+        let fma = vx.fma(vy, vz).to_array();
+        let beta_inc = vx.beta_inc(vy, vz).to_array();
+        let beta_inc_inv = vx.beta_inc_inv(vy, vz).to_array();
+        let (frexp_m, frexp_e) = vx.frexp();
+        let (remquo_r, remquo_q) = vx.remquo(vy);
+        let (ln_gamma, ln_gamma_s) = vx.ln_gamma();
+        let (ln_beta, ln_beta_s) = vx.ln_beta(vy);
+        let (rem_n, rem_hi, rem_lo) = vx.rem_pio2();
+        let ((sin_hi, sin_lo), (cos_hi, cos_lo)) = vx.sin_cos_hi_lo();
+
+        for i in 0..4 {
+            let (x, y, z) = (x[i], y[i], z[i]);
+            assert_same("fma", &[x, y, z], fma[i], fpmath::fma(x, y, z));
+            assert_same(
+                "beta_inc",
+                &[x, y, z],
+                beta_inc[i],
+                fpmath::beta_inc(x, y, z),
+            );
+            assert_same(
+                "beta_inc_inv",
+                &[x, y, z],
+                beta_inc_inv[i],
+                fpmath::beta_inc_inv(x, y, z),
+            );
+
+            let (m, e) = fpmath::frexp(x);
+            assert_same("frexp", &[x], frexp_m.to_array()[i], m);
+            assert_eq!(frexp_e[i], e, "frexp({x:e})");
+
+            let (r, q) = fpmath::remquo(x, y);
+            assert_same("remquo", &[x, y], remquo_r.to_array()[i], r);
+            assert_eq!(remquo_q[i], q, "remquo({x:e}, {y:e})");
+
+            let (r, s) = fpmath::ln_gamma(x);
+            assert_same("ln_gamma", &[x], ln_gamma.to_array()[i], r);
+            assert_eq!(ln_gamma_s[i], s, "ln_gamma({x:e})");
+
+            let (r, s) = fpmath::ln_beta(x, y);
+            assert_same("ln_beta", &[x, y], ln_beta.to_array()[i], r);
+            assert_eq!(ln_beta_s[i], s, "ln_beta({x:e}, {y:e})");
+
+            let (n, hi, lo) = fpmath::rem_pio2(x);
+            assert_eq!(rem_n[i], n, "rem_pio2({x:e})");
+            assert_same("rem_pio2", &[x], rem_hi.to_array()[i], hi);
+            assert_same("rem_pio2", &[x], rem_lo.to_array()[i], lo);
+
+            let ((s_hi, s_lo), (c_hi, c_lo)) = fpmath::sin_cos_hi_lo(x);
+            assert_same("sin_cos_hi_lo", &[x], sin_hi.to_array()[i], s_hi);
+            assert_same("sin_cos_hi_lo", &[x], sin_lo.to_array()[i], s_lo);
+            assert_same("sin_cos_hi_lo", &[x], cos_hi.to_array()[i], c_hi);
+            assert_same("sin_cos_hi_lo", &[x], cos_lo.to_array()[i], c_lo);
+        }
+    }
+}
+
+/// Generates random arguments with exponents in `exp_range`, mixed with
+/// special values
+fn gen_args(exp_range: core::ops::RangeInclusive<i16>) -> Vec<f64> {
+    let mut rng = create_prng();
+    (0..20000)
+        .map(|_| {
+            if rng.random_ratio(1, 64) {
+                SPECIAL[rng.random_range(0..SPECIAL.len())]
+            } else {
+                let e = rng.random_range(exp_range.clone());
+                mkfloat(rng.random(), e, rng.random())
+            }
+        })
+        .collect()
+}
+
+fn check1<const N: usize>(
+    name: &str,
+    simd_f: impl Fn([f64; N]) -> [f64; N],
+    f: impl Fn(f64) -> f64,
+    x: &[f64],
+) {
+    for x in x.chunks_exact(N) {
+        let y = simd_f(x.try_into().unwrap());
+        for (&x, y) in x.iter().zip(y) {
+            assert_same(name, &[x], y, f(x));
+        }
+    }
+}
+
+fn check2<const N: usize>(
+    name: &str,
+    simd_f: impl Fn([f64; N], [f64; N]) -> [f64; N],
+    f: impl Fn(f64, f64) -> f64,
+    x1: &[f64],
+    x2: &[f64],
+) {
+    for (x1, x2) in x1.chunks_exact(N).zip(x2.chunks_exact(N)) {
+        let y = simd_f(x1.try_into().unwrap(), x2.try_into().unwrap());
+        for ((&x1, &x2), y) in x1.iter().zip(x2).zip(y) {
+            assert_same(name, &[x1, x2], y, f(x1, x2));
+        }
+    }
+}
+
+fn assert_same(name: &str, args: &[f64], actual: f64, expected: f64) {
+    assert!(
+        actual.to_bits() == expected.to_bits() || (actual.is_nan() && expected.is_nan()),
+        "{name}{args:?} = {actual:e}, expected {expected:e}",
+    );
+}
@@ -1,6 +1,9 @@
 use rand::RngExt as _;
 
-use super::{RUG_PREC, calc_error_ulp, mkfloat, purify, purify2, select_threshold};
+use super::{
+    HI_LO_RUG_PREC, RUG_PREC, calc_error_hi_lo_ulp, calc_error_ulp, mkfloat, purify, purify2,
+    select_threshold,
+};
 use crate::create_prng;
 
 #[test]
@@ -61,6 +64,114 @@ fn test_sin_cos() {
     assert!(max_cos2_error > 0.5);
 }
 
+#[test]
+fn test_sin_cos_hi_lo() {
+    let mut max_sin_error: f64 = 0.0;
+    let mut max_cos_error: f64 = 0.0;
+    test_with(|x| {
+        let (expected_sin, expected_cos) =
+            rug::Float::with_val(HI_LO_RUG_PREC, x).sin_cos(rug::Float::new(HI_LO_RUG_PREC));
+
+        let (actual_sin, actual_cos) = fpmath::sin_cos_hi_lo(x);
+        let (neg_sin, neg_cos) = fpmath::sin_cos_hi_lo(-x);
+        assert_eq!(purify2(neg_sin), purify2((-actual_sin.0, -actual_sin.1)));
+        assert_eq!(purify2(neg_cos), purify2(actual_cos));
+
+        let sin_err = calc_error_hi_lo_ulp(actual_sin, expected_sin);
+        let cos_err = calc_error_hi_lo_ulp(actual_cos, expected_cos);
+
+        max_sin_error = max_sin_error.max(sin_err);
+        max_cos_error = max_cos_error.max(cos_err);
+
+        assert!(
+            sin_err < 0.15,
+            "sin_hi_lo({x:e}) = {actual_sin:?} (error = {sin_err} ULP)",
+        );
+        assert!(
+            cos_err < 0.15,
+            "cos_hi_lo({x:e}) = {actual_cos:?} (error = {cos_err} ULP)",
+        );
+    });
+    eprintln!("max sin_hi_lo error = {max_sin_error}");
+    eprintln!("max cos_hi_lo error = {max_cos_error}");
+    assert!(max_sin_error > 0.0);
+    assert!(max_cos_error > 0.0);
+}
+
+#[test]
+fn test_rem_pio2() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let (n, y_hi, y_lo) = fpmath::rem_pio2(x);
+        assert!((0..=3).contains(&n), "rem_pio2({x:e}).0 = {n}");
+        let (neg_n, neg_hi, neg_lo) = fpmath::rem_pio2(-x);
+        assert_eq!(neg_n, (4 - n) & 3);
+        assert_eq!(purify2((neg_hi, neg_lo)), purify2((-y_hi, -y_lo)));
+
+        let expected = reduce_rug(x, n, HI_LO_RUG_PREC);
+        let frac_pi_4 = rug::Float::with_val(64, rug::float::Constant::Pi) / 4u8;
+        assert!(
+            expected.clone().abs() <= frac_pi_4 * 1.000001,
+            "rem_pio2({x:e}) = {:?} (expected {expected:e})",
+            (n, y_hi, y_lo),
+        );
+
+        let err = calc_error_hi_lo_ulp((y_hi, y_lo), expected);
+        max_error = max_error.max(err);
+        assert!(
+            err < 2f64.powi(-17),
+            "rem_pio2({x:e}) = {:?} (error = {err} ULP)",
+            (n, y_hi, y_lo),
+        );
+    });
+    eprintln!("max rem_pio2 error = {max_error}");
+    assert!(max_error > 0.0);
+}
+
+#[test]
+fn test_wrap_angle() {
+    let mut max_error: f64 = 0.0;
+    test_with(|x| {
+        let actual = fpmath::wrap_angle(x);
+        assert_eq!(purify(fpmath::wrap_angle(-x)), purify(-actual));
+
+        let mut expected = reduce_rug(x, 0, RUG_PREC);
+        if expected.clone().abs() > 3.0 && expected.is_sign_negative() != actual.is_sign_negative()
+        {
+            // π and -π are both valid
+            let two_pi = rug::Float::with_val(RUG_PREC, rug::float::Constant::Pi) * 2u8;
+            if actual.is_sign_negative() {
+                expected -= two_pi;
+            } else {
+                expected += two_pi;
+            }
+        }
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+        assert!(
+            err < 0.9,
+            "wrap_angle({x:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max wrap_angle error = {max_error}");
+    assert!(max_error > 0.4);
+}
+
+/// Calculates `x - π/2*n - 2*π*M`, with `M` chosen to minimize the result,
+/// with `prec` bits of precision
+fn reduce_rug(x: f64, n: i32, prec: u32) -> rug::Float {
+    let x = rug::Float::with_val(53, x);
+    // enough bits to keep `prec` bits after cancellation, which can lose
+    // more bits than the precision of the argument
+    let work_prec = x.get_exp().unwrap_or(0).max(0) as u32 + prec + 256;
+    let pi = rug::Float::with_val(work_prec, rug::float::Constant::Pi);
+    let two_pi = pi.clone() * 2u8;
+    let y = rug::Float::with_val(work_prec, &x - pi * n / 2u8);
+    let m = (y.clone() / &two_pi).round();
+    rug::Float::with_val(prec, y - m * two_pi)
+}
+
 #[test]
 fn test_sind_cosd() {
     let mut max_sin1_error: f64 = 0.0;
@@ -0,0 +1,184 @@
+use rand::RngExt as _;
+use rug::ops::Pow as _;
+
+use super::polygamma::em_coeffs;
+use super::{RUG_PREC, calc_error_ulp, mkfloat};
+use crate::create_prng;
+
+#[test]
+fn test_zeta() {
+    let mut max_error: f64 = 0.0;
+    test_with_zeta(|s| {
+        let expected = rug::Float::with_val(RUG_PREC, s).zeta();
+        let actual = fpmath::zeta(s);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(err < 0.9, "zeta({s:e}) = {actual:e} (error = {err} ULP)");
+    });
+    eprintln!("max zeta error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+#[test]
+fn test_hurwitz_zeta() {
+    let mut max_error: f64 = 0.0;
+    test_with_hurwitz_zeta(|s, q| {
+        let expected = hurwitz_zeta_ref(s, q);
+        let actual = fpmath::hurwitz_zeta(s, q);
+
+        let err = calc_error_ulp(actual, expected);
+        max_error = max_error.max(err);
+
+        assert!(
+            err < 0.9,
+            "hurwitz_zeta({s:e}, {q:e}) = {actual:e} (error = {err} ULP)",
+        );
+    });
+    eprintln!("max hurwitz_zeta error = {max_error}");
+    assert!(max_error > 0.5);
+}
+
+/// Calculates `ζ(s, q)` for `s > 1` and `q > 0`
+pub(crate) fn hurwitz_zeta_ref(s: f64, q: f64) -> rug::Float {
+    let prec = RUG_PREC + 64;
+    let s = rug::Float::with_val(prec, s);
+    let neg_s = -s.clone();
+    let s_m1 = s.clone() - 1u8;
+    let threshold = s.to_f64() + 64.0;
+
+    let mut a = rug::Float::with_val(prec, q);
+    let mut sum = rug::Float::new(prec);
+    while a < threshold {
+        let term = a.clone().pow(&neg_s);
+        sum += &term;
+
+        // The sum of the remaining terms is less than `term * a / (s - 1)`
+        if term * &a / &s_m1 < sum.clone() >> (prec + 8) {
+            return sum;
+        }
+
+        a += 1u8;
+    }
+
+    // ζ(s, a) ~= a^(1 - s) / (s - 1) + a^(-s) / 2
+    //   + sum(B_2j / (2j)! * s * ... * (s + 2j - 2) * a^(1 - s - 2j))
+    let a_inv = a.clone().recip();
+    let a_pow = a.clone().pow(&neg_s);
+    sum += a * &a_pow / &s_m1;
+    sum += a_pow.clone() / 2u8;
+
+    let a_inv_sq = a_inv.clone().square();
+    let mut f = a_pow * a_inv * &s;
+    for (j, b) in (1u32..).zip(em_coeffs(prec)) {
+        sum += &b * &f;
+        f *= (s.clone() + (2 * j - 1)) * (s.clone() + 2 * j);
+        f *= &a_inv_sq;
+    }
+
+    sum
+}
+
+fn test_with_zeta(mut f: impl FnMut(f64)) {
+    let mut rng = create_prng();
+
+    for e in -1022..=1023 {
+        for _ in 0..200 {
+            let m = rng.random::<u64>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    for e in -8..=8 {
+        for _ in 0..5000 {
+            let m = rng.random::<u64>();
+            f(mkfloat(m, e, false));
+            f(mkfloat(m, e, true));
+        }
+    }
+
+    // close to the pole
+    for e in -53..=-1 {
+        for _ in 0..1000 {
+            let m = rng.random::<u64>();
+            f(1.0 + mkfloat(m, e, false));
+            f(1.0 - mkfloat(m, e, false));
+        }
+    }
+
+    // close to the zeros
+    for n in 1..=100 {
+        for e in -60..=-1 {
+            for _ in 0..20 {
+                let m = rng.random::<u64>();
+                f(-2.0 * f64::from(n) + mkfloat(m, e, false));
+                f(-2.0 * f64::from(n) - mkfloat(m, e, false));
+            }
+        }
+    }
+
+    for i in -4000..=4000 {
+        f(i as f64 / 20.0);
+    }
+
+    // subnormals
+    for i in 0..52 {
+        let x = f64::from_bits(1 << i);
+        f(x);
+        f(-x);
+        let x = f64::from_bits((1 << (i + 1)) - 1);
+        f(x);
+        f(-x);
+    }
+}
+
+fn test_with_hurwitz_zeta(mut f: impl FnMut(f64, f64)) {
+    let mut rng = create_prng();
+
+    for es in -52..=20 {
+        for _ in 0..1000 {
+            let s = 1.0 + mkfloat(rng.random::<u64>(), es, false);
+            let eq = rng.random_range(-1022..=1023i16);
+            let q = mkfloat(rng.random::<u64>(), eq, false);
+            f(s, q);
+        }
+        for eq in -8..=8 {
+            for _ in 0..100 {
+                let s = 1.0 + mkfloat(rng.random::<u64>(), es, false);
+                let q = mkfloat(rng.random::<u64>(), eq, false);
+                f(s, q);
+            }
+        }
+    }
+
+    for s in 2..=20 {
+        for i in 1..=1000 {
+            f(f64::from(s), f64::from(i) / 100.0);
+        }
+    }
+
+    // Large s, where |ln(q) * (1 - s)| is large
+    for es in 3..=7 {
+        for eq in 9..=20 {
+            for _ in 0..100 {
+                let s = mkfloat(rng.random::<u64>(), es, false);
+                let q = mkfloat(rng.random::<u64>(), eq, false);
+                f(s, q);
+            }
+        }
+    }
+    for (s, q) in [
+        (30.0, 1024.0),
+        (31.57, 811.37),
+        (28.0, 2048.0),
+        (44.33, 990.4),
+    ] {
+        f(s, q);
+    }
+
+    f(2.0, f64::MIN_POSITIVE);
+    f(2.0, f64::MAX);
+    f(1.5, f64::from_bits(1));
+}
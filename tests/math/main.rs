@@ -6,7 +6,15 @@
     unused_qualifications
 )]
 #![forbid(unsafe_code)]
+#![cfg_attr(feature = "f16", feature(f16, f32_from_f16))]
+#![cfg_attr(feature = "f128", feature(f128))]
 
+#[cfg(feature = "bf16")]
+mod bf16;
+#[cfg(feature = "f128")]
+mod f128;
+#[cfg(feature = "f16")]
+mod f16;
 mod f32;
 mod f64;
 